@@ -0,0 +1,111 @@
+use {
+    crate::utils::AppInstant,
+    eframe::egui::Context,
+    serde::{Deserialize, Serialize},
+    std::time::Duration,
+};
+
+/// When the app counts as idle or in the background, and how hard it works then. The ticker
+/// otherwise repaints every frame, which keeps a core busy even when nobody is looking.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct IdleSettings {
+    pub enabled: bool,
+    /// Focused but no input for this long counts as idle
+    pub idle_after_secs: u64,
+    /// Repaint interval while idle
+    pub idle_repaint_ms: u64,
+    /// Repaint interval while unfocused or minimized
+    pub background_repaint_ms: u64,
+    /// Hold price- and candle-triggered recalcs while unfocused or minimized; they catch up
+    /// on focus
+    pub pause_recalcs_in_background: bool,
+}
+
+impl Default for IdleSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            idle_after_secs: 120,
+            idle_repaint_ms: 500,
+            background_repaint_ms: 2_000,
+            pause_recalcs_in_background: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum Activity {
+    #[default]
+    Active,
+    /// Focused, but no recent input
+    Idle,
+    /// Unfocused or minimized
+    Background,
+}
+
+impl Activity {
+    pub(crate) fn classify(
+        settings: &IdleSettings,
+        focused: bool,
+        minimized: bool,
+        since_input: Duration,
+    ) -> Self {
+        if !settings.enabled {
+            Self::Active
+        } else if minimized || !focused {
+            Self::Background
+        } else if since_input >= Duration::from_secs(settings.idle_after_secs) {
+            Self::Idle
+        } else {
+            Self::Active
+        }
+    }
+
+    /// Heartbeat repaint while not active (`None` = the UI drives repaints as usual).
+    pub(crate) fn repaint_interval(&self, settings: &IdleSettings) -> Option<Duration> {
+        match self {
+            Self::Active => None,
+            Self::Idle => Some(Duration::from_millis(settings.idle_repaint_ms)),
+            Self::Background => Some(Duration::from_millis(settings.background_repaint_ms)),
+        }
+    }
+
+    pub(crate) fn holds_recalcs(&self, settings: &IdleSettings) -> bool {
+        *self == Self::Background && settings.pause_recalcs_in_background
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct IdleDetector {
+    last_input: Option<AppInstant>,
+    activity: Activity,
+}
+
+impl IdleDetector {
+    /// Call once per frame. Any input event (including focus changes) makes the app active
+    /// again straight away.
+    pub(crate) fn observe(&mut self, ctx: &Context, settings: &IdleSettings) -> Activity {
+        let (had_input, focused, minimized) = ctx.input(|i| {
+            let viewport = i.viewport();
+            (
+                !i.events.is_empty(),
+                viewport.focused.unwrap_or(true),
+                viewport.minimized.unwrap_or(false),
+            )
+        });
+        let now = AppInstant::now();
+        if had_input || self.last_input.is_none() {
+            self.last_input = Some(now);
+        }
+        let since_input = self
+            .last_input
+            .map_or(Duration::ZERO, |t| now.duration_since(t));
+        self.activity = Activity::classify(settings, focused, minimized, since_input);
+        self.activity
+    }
+
+    pub(crate) fn activity(&self) -> Activity {
+        self.activity
+    }
+}
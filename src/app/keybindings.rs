@@ -0,0 +1,250 @@
+use {
+    crate::ui::UI_TEXT,
+    eframe::egui::{InputState, Key},
+    serde::{Deserialize, Serialize},
+    std::collections::BTreeMap,
+    strum::IntoEnumIterator,
+    strum_macros::EnumIter,
+};
+
+/// Everything the global hotkey handler can do. Order here is the order of the help listing.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, EnumIter,
+)]
+pub(crate) enum HotkeyAction {
+    CloseAllPanes,
+    ToggleHelp,
+    ToggleSticky,
+    ToggleLowWicks,
+    ToggleHighWicks,
+    ToggleBackground,
+    ToggleCandles,
+    ToggleSeparators,
+    ToggleHorizonLines,
+    TogglePriceLine,
+    ToggleOpportunities,
+    ToggleTimeMachine,
+    ToggleAdaptiveEditor,
+    ToggleZoneSettings,
+    ToggleSessionStats,
+    ToggleEngineSettings,
+    ToggleGlossary,
+    ToggleDiagnostics,
+    ToggleStress,
+    ToggleHeatCalendar,
+    ToggleCacheInspector,
+    ToggleIndicatorEditor,
+    ToggleBasket,
+    ToggleSectors,
+    PrevRow,
+    NextRow,
+    PrevStation,
+    NextStation,
+    ApplyStation,
+    CancelStation,
+    UndoParams,
+    RedoParams,
+    PrevSegment,
+    NextSegment,
+    ZoomInY,
+    ZoomOutY,
+    ResetView,
+}
+
+impl HotkeyAction {
+    pub(crate) fn description(&self) -> &'static str {
+        match self {
+            Self::CloseAllPanes => &UI_TEXT.kbs_close_all_panes,
+            Self::ToggleHelp => &UI_TEXT.kbs_open_close,
+            Self::ToggleSticky => &UI_TEXT.kbs_toolbar_shortcut_hvz,
+            Self::ToggleLowWicks => &UI_TEXT.kbs_toolbar_shortcut_low_wick,
+            Self::ToggleHighWicks => &UI_TEXT.kbs_toolbar_shortcut_high_wick,
+            Self::ToggleBackground => &UI_TEXT.kbs_toolbar_shortcut_histogram,
+            Self::ToggleCandles => &UI_TEXT.kbs_toolbar_shortcut_candles,
+            Self::ToggleSeparators => &UI_TEXT.kbs_toolbar_shortcut_gap,
+            Self::ToggleHorizonLines => &UI_TEXT.kbs_toolbar_shortcut_price_limits,
+            Self::TogglePriceLine => &UI_TEXT.kbs_toolbar_shortcut_live_price,
+            Self::ToggleOpportunities => &UI_TEXT.kbs_toolbar_shortcut_targets,
+            Self::ToggleTimeMachine => &UI_TEXT.kbs_view_time_machine,
+            Self::ToggleAdaptiveEditor => &UI_TEXT.kbs_view_adaptive_editor,
+            Self::ToggleZoneSettings => &UI_TEXT.kbs_view_zone_settings,
+            Self::ToggleSessionStats => &UI_TEXT.kbs_view_session_stats,
+            Self::ToggleEngineSettings => &UI_TEXT.kbs_view_engine_settings,
+            Self::ToggleGlossary => &UI_TEXT.kbs_view_glossary,
+            Self::ToggleDiagnostics => &UI_TEXT.kbs_view_diagnostics,
+            Self::ToggleStress => &UI_TEXT.kbs_view_stress,
+            Self::ToggleHeatCalendar => &UI_TEXT.kbs_view_heat_calendar,
+            Self::ToggleCacheInspector => &UI_TEXT.kbs_view_cache_inspector,
+            Self::ToggleIndicatorEditor => &UI_TEXT.kbs_view_indicator_editor,
+            Self::ToggleBasket => &UI_TEXT.kbs_view_basket,
+            Self::ToggleSectors => &UI_TEXT.kbs_view_sectors,
+            Self::PrevRow => &UI_TEXT.kbs_tf_prev_row,
+            Self::NextRow => &UI_TEXT.kbs_tf_next_row,
+            Self::PrevStation => &UI_TEXT.kbs_tuner_prev_station,
+            Self::NextStation => &UI_TEXT.kbs_tuner_next_station,
+            Self::ApplyStation => &UI_TEXT.kbs_tuner_apply_station,
+            Self::CancelStation => &UI_TEXT.kbs_tuner_cancel_station,
+            Self::UndoParams => &UI_TEXT.kbs_undo_params,
+            Self::RedoParams => &UI_TEXT.kbs_redo_params,
+            Self::PrevSegment => &UI_TEXT.kbs_plot_segment_prev,
+            Self::NextSegment => &UI_TEXT.kbs_plot_segment_next,
+            Self::ZoomInY => &UI_TEXT.kbs_plot_zoom_in_y,
+            Self::ZoomOutY => &UI_TEXT.kbs_plot_zoom_out_y,
+            Self::ResetView => &UI_TEXT.kbs_plot_reset,
+        }
+    }
+
+    pub(crate) fn default_chords(&self) -> &'static [KeyChord] {
+        match self {
+            Self::CloseAllPanes => &[KeyChord::plain(Key::Escape)],
+            Self::ToggleHelp => &[KeyChord::plain(Key::K), KeyChord::plain(Key::H)],
+            Self::ToggleSticky => &[KeyChord::plain(Key::Num1)],
+            Self::ToggleLowWicks => &[KeyChord::plain(Key::Num2)],
+            Self::ToggleHighWicks => &[KeyChord::plain(Key::Num3)],
+            Self::ToggleBackground => &[KeyChord::plain(Key::Num4)],
+            Self::ToggleCandles => &[KeyChord::plain(Key::Num5)],
+            Self::ToggleSeparators => &[KeyChord::plain(Key::Num6)],
+            Self::ToggleHorizonLines => &[KeyChord::plain(Key::Num7)],
+            Self::TogglePriceLine => &[KeyChord::plain(Key::Num8)],
+            Self::ToggleOpportunities => &[KeyChord::plain(Key::Num9)],
+            Self::ToggleTimeMachine => &[KeyChord::plain(Key::T)],
+            Self::ToggleAdaptiveEditor => &[KeyChord::plain(Key::A)],
+            Self::ToggleZoneSettings => &[KeyChord::plain(Key::Z)],
+            Self::ToggleSessionStats => &[KeyChord::plain(Key::S)],
+            Self::ToggleEngineSettings => &[KeyChord::plain(Key::E)],
+            Self::ToggleGlossary => &[KeyChord::plain(Key::G)],
+            Self::ToggleDiagnostics => &[KeyChord::plain(Key::D)],
+            Self::ToggleStress => &[KeyChord::plain(Key::X)],
+            Self::ToggleHeatCalendar => &[KeyChord::plain(Key::W)],
+            Self::ToggleCacheInspector => &[KeyChord::plain(Key::C)],
+            Self::ToggleIndicatorEditor => &[KeyChord::plain(Key::I)],
+            Self::ToggleBasket => &[KeyChord::plain(Key::P)],
+            Self::ToggleSectors => &[KeyChord::plain(Key::B)],
+            Self::PrevRow => &[KeyChord::plain(Key::ArrowUp)],
+            Self::NextRow => &[KeyChord::plain(Key::ArrowDown)],
+            // Ctrl-held so they don't fire on list navigation or Enter-to-save in editors
+            Self::PrevStation => &[KeyChord::command(Key::ArrowLeft, false)],
+            Self::NextStation => &[KeyChord::command(Key::ArrowRight, false)],
+            Self::ApplyStation => &[KeyChord::command(Key::Enter, false)],
+            Self::CancelStation => &[KeyChord::command(Key::Backspace, false)],
+            Self::UndoParams => &[KeyChord::command(Key::Z, false)],
+            Self::RedoParams => &[KeyChord::command(Key::Z, true)],
+            Self::PrevSegment => &[KeyChord::plain(Key::PageUp)],
+            Self::NextSegment => &[KeyChord::plain(Key::PageDown)],
+            Self::ZoomInY => &[KeyChord::plain(Key::Plus), KeyChord::plain(Key::Equals)],
+            Self::ZoomOutY => &[KeyChord::plain(Key::Minus)],
+            Self::ResetView => &[KeyChord::plain(Key::Home)],
+        }
+    }
+}
+
+/// A key plus the modifiers that must be held. Plain chords ignore Shift so symbols that
+/// need it on some layouts (`+`) still work.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub(crate) struct KeyChord {
+    pub key: Key,
+    /// Ctrl, or Cmd on macOS
+    pub command: bool,
+    pub shift: bool,
+}
+
+impl KeyChord {
+    pub(crate) const fn plain(key: Key) -> Self {
+        Self {
+            key,
+            command: false,
+            shift: false,
+        }
+    }
+
+    pub(crate) const fn command(key: Key, shift: bool) -> Self {
+        Self {
+            key,
+            command: true,
+            shift,
+        }
+    }
+
+    pub(crate) fn pressed(&self, i: &InputState) -> bool {
+        let plain = !self.command && !self.shift;
+        i.key_pressed(self.key)
+            && i.modifiers.command == self.command
+            && (plain || i.modifiers.shift == self.shift)
+    }
+
+    pub(crate) fn label(&self) -> String {
+        let mut label = String::new();
+        if self.command {
+            label.push_str("Ctrl+");
+        }
+        if self.shift {
+            label.push_str("Shift+");
+        }
+        label.push_str(self.key.symbol_or_name());
+        label
+    }
+}
+
+/// User overrides on top of the default hotkey map. Only changed actions are stored, so new
+/// actions pick up their defaults after an upgrade.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct KeyBindings {
+    overrides: BTreeMap<HotkeyAction, Vec<KeyChord>>,
+    /// Action waiting for its new key in the shortcuts window
+    #[serde(skip)]
+    pub capturing: Option<HotkeyAction>,
+}
+
+impl KeyBindings {
+    pub(crate) fn chords(&self, action: HotkeyAction) -> &[KeyChord] {
+        self.overrides
+            .get(&action)
+            .map_or(action.default_chords(), Vec::as_slice)
+    }
+
+    pub(crate) fn label(&self, action: HotkeyAction) -> String {
+        let chords = self.chords(action);
+        if chords.is_empty() {
+            return "—".to_string();
+        }
+        chords
+            .iter()
+            .map(KeyChord::label)
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+
+    pub(crate) fn bind(&mut self, action: HotkeyAction, chord: KeyChord) {
+        if action.default_chords() == [chord] {
+            self.overrides.remove(&action);
+        } else {
+            self.overrides.insert(action, vec![chord]);
+        }
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.overrides.clear();
+    }
+
+    pub(crate) fn is_customized(&self) -> bool {
+        !self.overrides.is_empty()
+    }
+
+    /// Actions triggered this frame, in listing order. Every action on a shared chord fires;
+    /// the shortcuts window flags such conflicts.
+    pub(crate) fn pressed(&self, i: &InputState) -> Vec<HotkeyAction> {
+        HotkeyAction::iter()
+            .filter(|a| self.chords(*a).iter().any(|c| c.pressed(i)))
+            .collect()
+    }
+
+    /// Other actions sharing a chord with `action`.
+    pub(crate) fn conflicts(&self, action: HotkeyAction) -> Vec<HotkeyAction> {
+        let mine = self.chords(action);
+        HotkeyAction::iter()
+            .filter(|other| *other != action)
+            .filter(|other| self.chords(*other).iter().any(|c| mine.contains(c)))
+            .collect()
+    }
+}
@@ -1,19 +1,28 @@
+mod idle;
+mod keybindings;
 mod root;
 mod state;
 mod types;
 
+pub(crate) use idle::{Activity, IdleDetector, IdleSettings};
+pub(crate) use keybindings::{HotkeyAction, KeyBindings, KeyChord};
+
 pub(crate) use state::{
     AppState, AutoScaleY, BootstrapState, PersistedSelection, PhaseView, ProgressEvent,
     RunningState, Selection, SortDirection, SyncStatus, TuningState,
 };
 
+#[cfg(target_arch = "wasm32")]
+pub(crate) use state::DemoSelectState;
+
 pub(crate) use types::{
-    AroiPct, BaseVol, CandleResolution, ClosePrice, DurationMs, HighPrice, JourneySettings,
-    LowPrice, MomentumPct, OpenPrice, OptimalSearchSettings, Pct, PhPct, PriceRange, Prob,
-    QuoteVol, RoiPct, Sigma, SimilaritySettings, StopPrice, TargetPrice, TradeProfile, VolRatio,
-    VolatilityPct, Weight, ZoneClassificationConfig, ZoneParams,
+    AroiPct, BaseVol, CandleResolution, ClosePrice, CoverageBand, CoverageTargets, DurationMs,
+    EvidenceSettings, HighPrice, JourneySettings, LowPrice, MomentumPct, OpenPrice,
+    OptimalSearchSettings, Pct, PhPct, PriceRange, Prob, QuoteVol, RoiPct, Sigma,
+    SimilaritySettings, StickyScore, StopManagement, StopMode, StopPrice, TargetPrice,
+    TradeProfile, VolRatio, VolatilityPct, Weight, ZoneClassificationConfig, ZoneParams,
 };
 
 pub use root::{App, BASE_INTERVAL};
 
-pub use types::{Price, PriceLike};
+pub use types::{Price, PriceLike, TimestampMs};
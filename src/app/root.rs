@@ -2,16 +2,26 @@ use std::time::Duration;
 /// Base interval for historic candle width (don't think app will work if we change this. Needs lots of unit tests anyway + refactoring)
 pub const BASE_INTERVAL: Duration = Duration::from_secs(5 * 60);
 
+/// Y range multiplier per +/- key press
+const PLOT_ZOOM_STEP: f64 = 0.8;
+/// Repaint floor while running and active; events repaint sooner
+const RUNNING_HEARTBEAT: Duration = Duration::from_secs(1);
+/// Refresh cadence and size of the snapshot served to secondary instances
+#[cfg(not(target_arch = "wasm32"))]
+const INSTANCE_STATUS_INTERVAL: Duration = Duration::from_secs(2);
+#[cfg(not(target_arch = "wasm32"))]
+const INSTANCE_STATUS_TOP: usize = 25;
+
 use {
     eframe::{
         Frame, Storage,
         egui::{
-            CentralPanel, Context, FontData, FontDefinitions, FontFamily, Key, ProgressBar, Visuals,
+            CentralPanel, Context, FontData, FontDefinitions, FontFamily, ProgressBar, Visuals,
         },
     },
     serde::{Deserialize, Serialize},
     std::{
-        collections::{HashMap, HashSet},
+        collections::{BTreeSet, HashMap, HashSet},
         mem,
         sync::{Arc, mpsc, mpsc::Receiver},
     },
@@ -20,27 +30,70 @@ use {
 use crate::{
     Cli,
     app::{
-        AppState, AutoScaleY, BootstrapState, CandleResolution, PersistedSelection, PhPct,
-        PhaseView, ProgressEvent, RunningState, Selection, SortDirection, SyncStatus, TuningState,
+        Activity, AppState, AutoScaleY, BootstrapState, CandleResolution, HotkeyAction,
+        IdleDetector, IdleSettings, KeyBindings, PersistedSelection, PhPct, PhaseView,
+        ProgressEvent, RunningState, Selection, SortDirection, SyncStatus, TuningState,
     },
-    data::{TimeSeriesCollection, fetch_pair_data},
-    engine::SniperEngine,
-    models::{TradeOpportunity, restore_engine_ledger},
-    shared::SharedConfiguration,
+    config::{DEFAULT_PROFILE, active_profile},
+    data::{EconEvent, ExternalSignal, TimeSeriesCollection, fetch_pair_data},
+    domain::PairCategory,
+    engine::{BasketConstraints, SectorReport, SniperEngine, StressReport},
+    models::{
+        IndicatorScript, OpportunityAnnotation, OpportunityScript, PurgeSummary, ScoreType,
+        TradeOpportunity, restore_engine_ledger,
+    },
+    shared::{ParamHistory, SharedConfiguration, UIEngineSharedData},
     ui::{
-        NavigationState, NavigationTarget, PlotView, PlotVisibility, ScrollBehavior, SortColumn,
-        TickerState, UI_CONFIG, render_bootstrap,
+        AdaptiveEditorState, AnnotationDraft, BasketState, CorrelatedWhatIf, CustomZoneDraft,
+        GlossaryState, HeatCalendarState, JourneyBrowserState, NavigationState, NavigationTarget,
+        NotificationCenter, PairMultiSelect, PanelLayout, PhSliderState, PlotView, PlotVisibility,
+        PriceMarks, PriceWhatIf, RecalcHistoryState, ScriptAlerts, ScrollBehavior,
+        SegmentStatsPopover, SortColumn, StationPreviewState, StatusBarSettings, TickerState,
+        TimeAxisMode, TradeFinderRefresh, TunerAction, UI_CONFIG, render_bootstrap,
+        render_glossary, render_indicator_editor, render_notifications, render_recalc_history,
     },
     utils::AppInstant,
 };
 
+#[cfg(target_arch = "wasm32")]
+use {
+    crate::{
+        app::DemoSelectState,
+        config::DEMO,
+        data::{import_kline_files, web_storage},
+        ui::{UI_TEXT, render_demo_select},
+    },
+    eframe::egui::{Align2, Color32, FontId, Id, LayerId, Order, Window},
+    std::sync::atomic::{AtomicBool, Ordering},
+};
+
+#[cfg(all(target_arch = "wasm32", feature = "wasm-slim"))]
+use crate::data::fetch_bytes;
+
 #[cfg(not(target_arch = "wasm32"))]
 use {
-    crate::{app::Pct, data::save_ledger},
-    std::thread,
+    crate::{
+        app::Pct,
+        app::PriceLike,
+        config::set_active_profile,
+        data::{
+            CrossCheckReport, HousekeepingSettings, InstanceOpportunity, InstanceStatus,
+            MockSettings, SIGNAL_WEBHOOK, SignalWebhookSettings, enable_mock_exchange,
+            load_profiles, mock_exchange, publish_instance_status, save_ledger, save_profiles,
+            serving_instance_status, validate_profile_name,
+        },
+        ui::{
+            ApiKeyForm, CacheInspector, HousekeepingJob, LedgerTransferForm, ProfileMenu,
+            SignalWebhook, render_cache_inspector,
+        },
+    },
+    std::{path::PathBuf, thread},
     tokio::runtime::Runtime,
 };
 
+#[cfg(all(feature = "econ_calendar", not(target_arch = "wasm32")))]
+use crate::data::fetch_econ_calendar;
+
 #[cfg(debug_assertions)]
 use crate::config::{DF, LOG_PERFORMANCE};
 
@@ -65,15 +118,70 @@ pub struct App {
     pub(crate) valid_session_pairs: HashSet<String>,
     pub(crate) persisted_selection: PersistedSelection,
     pub(crate) plot_visibility: PlotVisibility,
+    pub(crate) background_score_type: ScoreType,
+    /// Second score type for the split A/B background (None = single histogram)
+    pub(crate) compare_score_type: Option<ScoreType>,
+    /// Pairs starred via the trade finder bulk actions
+    pub(crate) watchlist: BTreeSet<String>,
+    /// Lines and alerts placed from the plot context menu, per pair
+    pub(crate) price_marks: HashMap<String, PriceMarks>,
+    /// Notes pinned to opportunities from the plot context menu, by opportunity id
+    pub(crate) annotations: HashMap<String, Vec<OpportunityAnnotation>>,
+    /// Scripted indicator lines drawn over the candles, per pair
+    pub(crate) indicator_scripts: HashMap<String, Vec<IndicatorScript>>,
     pub(crate) show_debug_help: bool,
     pub(crate) show_ph_help: bool,
     pub(crate) candle_resolution: CandleResolution,
+    pub(crate) time_axis_mode: TimeAxisMode,
     pub(crate) show_candle_range: bool,
+    pub(crate) show_adaptive_editor: bool,
+    pub(crate) show_zone_settings: bool,
+    pub(crate) show_session_stats: bool,
+    pub(crate) show_engine_settings: bool,
+    pub(crate) show_diagnostics: bool,
+    pub(crate) show_stress: bool,
+    pub(crate) show_heat_calendar: bool,
+    pub(crate) show_cache_inspector: bool,
+    pub(crate) show_journey_browser: bool,
+    pub(crate) show_indicator_editor: bool,
+    pub(crate) show_basket: bool,
+    pub(crate) show_sectors: bool,
+    /// Limits the suggested basket is built within
+    pub(crate) basket_constraints: BasketConstraints,
     pub(crate) tf_scope_match_base: bool,
+    /// Dense one-line-per-opportunity Trade Finder rows
+    pub(crate) tf_compact: bool,
+    /// Hide opportunities whose live AROI does not clear the benchmark rate
+    pub(crate) tf_beats_benchmark: bool,
+    /// Hide opportunities with zero or negative expectancy
+    pub(crate) tf_positive_expectancy: bool,
+    /// Only show pairs in this sector (None = all)
+    pub(crate) tf_category: Option<PairCategory>,
+    /// Order Trade Finder rows by sector before the sort column
+    pub(crate) tf_group_by_category: bool,
+    /// Trade Finder re-sort cadence and held row order
+    pub(crate) tf_refresh: TradeFinderRefresh,
+    /// User filter and score expressions over opportunity fields
+    pub(crate) opportunity_script: OpportunityScript,
+    pub(crate) status_bar: StatusBarSettings,
+    pub(crate) panel_layout: PanelLayout,
+    pub(crate) idle_settings: IdleSettings,
+    pub(crate) keybindings: KeyBindings,
     pub(crate) tf_sort_col: SortColumn,
     pub(crate) tf_sort_dir: SortDirection,
     #[serde(skip)]
     pub(crate) scroll_target: Option<NavigationTarget>,
+    /// Trade finder row under the pointer this frame (drives model prefetch)
+    #[serde(skip)]
+    pub(crate) tf_hovered_pair: Option<String>,
+    /// Row step asked for by the arrow keys, applied by the trade finder (-1 up, 1 down)
+    #[serde(skip)]
+    pub(crate) tf_row_step: Option<i32>,
+    /// Row after the keyboard selection in the direction of travel (drives model prefetch)
+    #[serde(skip)]
+    pub(crate) tf_nav_ahead: Option<String>,
+    #[serde(skip)]
+    pub(crate) tf_multi_select: PairMultiSelect,
     #[serde(skip)]
     pub(crate) engine: Option<SniperEngine>,
     #[serde(skip)]
@@ -90,6 +198,131 @@ pub struct App {
     pub(crate) auto_scale_y: AutoScaleY,
     #[serde(skip)]
     pub(crate) ticker_state: TickerState,
+    /// Ticker moves pairs whose best opportunity just improved to the front
+    pub(crate) ticker_promote_improved: bool,
+    #[serde(skip)]
+    pub(crate) export_status: Option<String>,
+    /// Note being typed for an opportunity (editor window open while Some)
+    #[serde(skip)]
+    pub(crate) annotation_draft: Option<AnnotationDraft>,
+    /// Custom zone being drawn (editor window open while Some)
+    #[serde(skip)]
+    pub(crate) custom_zone_draft: Option<CustomZoneDraft>,
+    /// Open "what-if at prices" window
+    #[serde(skip)]
+    pub(crate) price_what_if: Option<PriceWhatIf>,
+    /// Open "correlated move" window
+    #[serde(skip)]
+    pub(crate) correlated_what_if: Option<CorrelatedWhatIf>,
+    /// Whether the live price was inside each alerting custom zone last frame, by (pair, id)
+    #[serde(skip)]
+    pub(crate) custom_zone_inside: HashMap<(String, u64), bool>,
+    /// Opportunities the script filter matched on its last alert check
+    #[serde(skip)]
+    pub(crate) script_alerts: ScriptAlerts,
+    /// Stats window for a candle range segment, opened from its info icon
+    #[serde(skip)]
+    pub(crate) segment_stats: Option<SegmentStatsPopover>,
+    /// Result of the last diagnostics bundle export
+    #[serde(skip)]
+    pub(crate) diagnostics_status: Option<String>,
+    /// Outcome of the last manual ledger purge, shown in engine settings
+    #[serde(skip)]
+    pub(crate) last_purge: Option<PurgeSummary>,
+    /// Last stress scenario run from the stress panel
+    #[serde(skip)]
+    pub(crate) stress_report: Option<StressReport>,
+    /// Last basket suggested from the basket panel and the correlations behind it
+    #[serde(skip)]
+    pub(crate) basket: BasketState,
+    /// Sector dashboard aggregates, rebuilt while the dashboard is open
+    #[serde(skip)]
+    pub(crate) sector_report: Option<SectorReport>,
+    #[serde(skip)]
+    pub(crate) heat_calendar: HeatCalendarState,
+    #[serde(skip)]
+    pub(crate) journey_browser: JourneyBrowserState,
+    #[serde(skip)]
+    pub(crate) adaptive_editor: AdaptiveEditorState,
+    #[serde(skip)]
+    pub(crate) glossary: GlossaryState,
+    #[serde(skip)]
+    pub(crate) recalc_history: RecalcHistoryState,
+    #[serde(skip)]
+    pub(crate) notifications: NotificationCenter,
+    /// Last zone config change still waiting for its global recalc (ms timestamp)
+    #[serde(skip)]
+    pub(crate) zone_recalc_since_ms: Option<i64>,
+    #[serde(skip)]
+    pub(crate) param_history: ParamHistory,
+    #[serde(skip)]
+    pub(crate) ph_slider: PhSliderState,
+    #[serde(skip)]
+    pub(crate) station_preview: StationPreviewState,
+    #[serde(skip)]
+    pub(crate) idle: IdleDetector,
+    /// PH requested via `--ph` / `?ph=`, applied once tuning completes
+    #[serde(skip)]
+    startup_ph: Option<(String, PhPct)>,
+    /// `--journal`: file the engine records its jobs to
+    #[cfg(not(target_arch = "wasm32"))]
+    #[serde(skip)]
+    journal_path: Option<PathBuf>,
+    /// Binance key entry in engine settings. Skipped so keys never reach saved settings or
+    /// the diagnostics bundle.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[serde(skip)]
+    pub(crate) api_key_form: ApiKeyForm,
+    /// Ledger export / import in engine settings
+    #[cfg(not(target_arch = "wasm32"))]
+    #[serde(skip)]
+    pub(crate) ledger_transfer: LedgerTransferForm,
+    /// Kline retention and compaction, run from engine settings or on startup
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) housekeeping_settings: HousekeepingSettings,
+    #[cfg(not(target_arch = "wasm32"))]
+    #[serde(skip)]
+    pub(crate) housekeeping: HousekeepingJob,
+    /// Kline cache inspector window state (open flag is `show_cache_inspector`)
+    #[cfg(not(target_arch = "wasm32"))]
+    #[serde(skip)]
+    pub(crate) cache_inspector: CacheInspector,
+    /// Local listener for external trade signals, configured in engine settings
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) signal_webhook_settings: SignalWebhookSettings,
+    #[cfg(not(target_arch = "wasm32"))]
+    #[serde(skip)]
+    pub(crate) signal_webhook: SignalWebhook,
+    /// Signals received this session, oldest first
+    #[serde(skip)]
+    pub(crate) external_signals: Vec<ExternalSignal>,
+    /// Profile switcher in the top toolbar; owns the profile registry
+    #[cfg(not(target_arch = "wasm32"))]
+    #[serde(skip)]
+    pub(crate) profiles: ProfileMenu,
+    #[cfg(not(target_arch = "wasm32"))]
+    #[serde(skip)]
+    last_status_publish: Option<AppInstant>,
+    /// Candle cross-check running in the background (diagnostics panel)
+    #[cfg(not(target_arch = "wasm32"))]
+    #[serde(skip)]
+    pub(crate) cross_check_rx: Option<Receiver<CrossCheckReport>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    #[serde(skip)]
+    pub(crate) cross_check_report: Option<CrossCheckReport>,
+    /// Scheduled macro events, sorted by time (empty unless `econ_calendar` is enabled)
+    #[serde(skip)]
+    pub(crate) econ_events: Vec<EconEvent>,
+    #[cfg(all(feature = "econ_calendar", not(target_arch = "wasm32")))]
+    #[serde(skip)]
+    econ_rx: Option<Receiver<Vec<EconEvent>>>,
+    #[cfg(target_arch = "wasm32")]
+    #[serde(skip)]
+    import_error: Option<String>,
+    /// Web build: settings and drawings read back from IndexedDB during bootstrap
+    #[cfg(target_arch = "wasm32")]
+    #[serde(skip)]
+    web_state_rx: Option<Receiver<Vec<u8>>>,
 }
 
 impl Default for App {
@@ -99,7 +332,13 @@ impl Default for App {
             persisted_selection: PersistedSelection::None,
             shared_config: SharedConfiguration::new(),
             plot_visibility: PlotVisibility::default(),
+            background_score_type: ScoreType::default(),
+            compare_score_type: None,
             valid_session_pairs: HashSet::new(),
+            watchlist: BTreeSet::new(),
+            price_marks: HashMap::new(),
+            annotations: HashMap::new(),
+            indicator_scripts: HashMap::new(),
             show_debug_help: false,
             show_ph_help: false,
             engine: None,
@@ -108,12 +347,99 @@ impl Default for App {
             progress_rx: None,
             data_rx: None,
             scroll_target: None,
+            tf_hovered_pair: None,
+            tf_row_step: None,
+            tf_nav_ahead: None,
+            tf_multi_select: PairMultiSelect::default(),
             nav_states: HashMap::new(),
             candle_resolution: CandleResolution::default(),
+            time_axis_mode: TimeAxisMode::default(),
             auto_scale_y: AutoScaleY::default(),
             ticker_state: TickerState::default(),
+            ticker_promote_improved: false,
+            export_status: None,
+            annotation_draft: None,
+            custom_zone_draft: None,
+            price_what_if: None,
+            correlated_what_if: None,
+            custom_zone_inside: HashMap::new(),
+            script_alerts: ScriptAlerts::default(),
+            segment_stats: None,
+            diagnostics_status: None,
+            last_purge: None,
+            stress_report: None,
+            basket: BasketState::default(),
+            sector_report: None,
+            basket_constraints: BasketConstraints::default(),
+            heat_calendar: HeatCalendarState::default(),
+            journey_browser: JourneyBrowserState::default(),
+            adaptive_editor: AdaptiveEditorState::default(),
+            glossary: GlossaryState::default(),
+            recalc_history: RecalcHistoryState::default(),
+            notifications: NotificationCenter::default(),
+            zone_recalc_since_ms: None,
+            param_history: ParamHistory::default(),
+            ph_slider: PhSliderState::default(),
+            station_preview: StationPreviewState::default(),
+            idle: IdleDetector::default(),
+            startup_ph: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            journal_path: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            api_key_form: ApiKeyForm::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            ledger_transfer: LedgerTransferForm::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            housekeeping_settings: HousekeepingSettings::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            housekeeping: HousekeepingJob::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            cache_inspector: CacheInspector::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            signal_webhook_settings: SignalWebhookSettings::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            signal_webhook: SignalWebhook::default(),
+            external_signals: Vec::new(),
+            #[cfg(not(target_arch = "wasm32"))]
+            profiles: ProfileMenu::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            last_status_publish: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            cross_check_rx: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            cross_check_report: None,
+            econ_events: Vec::new(),
+            #[cfg(all(feature = "econ_calendar", not(target_arch = "wasm32")))]
+            econ_rx: None,
+            #[cfg(target_arch = "wasm32")]
+            import_error: None,
+            #[cfg(target_arch = "wasm32")]
+            web_state_rx: None,
             tf_scope_match_base: false,
+            tf_compact: false,
+            tf_beats_benchmark: false,
+            tf_positive_expectancy: false,
+            tf_category: None,
+            tf_group_by_category: false,
+            tf_refresh: TradeFinderRefresh::default(),
+            opportunity_script: OpportunityScript::default(),
+            status_bar: StatusBarSettings::default(),
+            panel_layout: PanelLayout::default(),
+            idle_settings: IdleSettings::default(),
+            keybindings: KeyBindings::default(),
             show_candle_range: false,
+            show_adaptive_editor: false,
+            show_zone_settings: false,
+            show_session_stats: false,
+            show_engine_settings: false,
+            show_diagnostics: false,
+            show_stress: false,
+            show_heat_calendar: false,
+            show_cache_inspector: false,
+            show_journey_browser: false,
+            show_indicator_editor: false,
+            show_basket: false,
+            show_sectors: false,
             tf_sort_col: SortColumn::default(),
             tf_sort_dir: SortDirection::default(),
         }
@@ -122,22 +448,212 @@ impl Default for App {
 
 impl App {
     pub(crate) fn new(cc: &eframe::CreationContext<'_>, args: Cli) -> Self {
-        let mut app: App = if let Some(storage) = cc.storage {
-            eframe::get_value(storage, eframe::APP_KEY).unwrap_or_default()
-        } else {
-            Self::default()
-        };
+        #[cfg(not(target_arch = "wasm32"))]
+        let profiles = Self::open_startup_profile(&args);
+        let restored: Option<App> = cc.storage.and_then(|storage| {
+            eframe::get_value(storage, &profile_storage_key(&active_profile()))
+        });
+        let mut app = restored.unwrap_or_default();
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            app.profiles = profiles;
+        }
 
         Self::configure_fonts(&cc.egui_ctx);
 
         app.plot_view = PlotView::new();
         app.state = AppState::Bootstrapping(BootstrapState::default());
 
+        app.apply_startup_args(&args);
+
+        #[cfg(target_arch = "wasm32")]
+        if args.demo_dataset.is_none() && DEMO.datasets.len() > 1 {
+            let saved_import = Arc::new(AtomicBool::new(false));
+            let flag = saved_import.clone();
+            let ctx = cc.egui_ctx.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                if web_storage::has_imported_klines().await {
+                    flag.store(true, Ordering::Relaxed);
+                    ctx.request_repaint();
+                }
+            });
+            app.state = AppState::DemoSelect(DemoSelectState { args, saved_import });
+            return app;
+        }
+
+        app.start_data_load(args);
+        app
+    }
+
+    /// Activates `--profile` (registered if new) or else the profile used last.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn open_startup_profile(args: &Cli) -> ProfileMenu {
+        let mut registry = load_profiles();
+        let requested = match args.profile.as_deref().map(validate_profile_name) {
+            Some(Ok(name)) => name,
+            Some(Err(e)) => {
+                log::error!("Ignoring --profile: {:#}", e);
+                registry.last.clone()
+            }
+            None => registry.last.clone(),
+        };
+        let name = registry.select(&requested);
+        if let Err(e) = save_profiles(&registry) {
+            log::error!("Failed to save profiles: {:#}", e);
+        }
+        set_active_profile(&name);
+        log::info!("Profile: {}", name);
+        ProfileMenu::new(registry)
+    }
+
+    /// Takes in what the webhook received. Signals sent without a price get the live one so
+    /// they can be placed on the plot.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn receive_external_signals(&mut self) {
+        for mut signal in self.signal_webhook.drain() {
+            if signal.price.is_none() {
+                signal.price = self.engine.as_ref().and_then(|e| e.get_price(&signal.pair));
+            }
+            if !self.valid_session_pairs.contains(&signal.pair) {
+                log::warn!("External signal for {}, which is not loaded", signal.pair);
+            }
+            self.external_signals.push(signal);
+        }
+        let excess = self
+            .external_signals
+            .len()
+            .saturating_sub(SIGNAL_WEBHOOK.max_signals);
+        self.external_signals.drain(..excess);
+    }
+
+    /// Refreshes the snapshot secondary instances read. Only the lock owner serves one.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn publish_instance_status(&mut self) {
+        if !serving_instance_status()
+            || self
+                .last_status_publish
+                .is_some_and(|t| t.elapsed() < INSTANCE_STATUS_INTERVAL)
+        {
+            return;
+        }
+        let Some(engine) = &self.engine else {
+            return;
+        };
+        self.last_status_publish = Some(AppInstant::now());
+        let mut ranked: Vec<(f64, &TradeOpportunity)> = engine
+            .engine_ledger
+            .opportunities
+            .values()
+            .map(|op| (op.calc_quality_score(), op))
+            .collect();
+        ranked.sort_by(|a, b| b.0.total_cmp(&a.0));
+        publish_instance_status(InstanceStatus {
+            profile: active_profile(),
+            live_pairs: self.valid_session_pairs.len(),
+            opportunities: ranked
+                .into_iter()
+                .take(INSTANCE_STATUS_TOP)
+                .map(|(quality, op)| InstanceOpportunity {
+                    pair: op.pair_name.clone(),
+                    direction: op.direction.to_string(),
+                    target: op.target_price.format_price(),
+                    roi: op.expected_roi().to_string(),
+                    quality,
+                })
+                .collect(),
+        });
+    }
+
+    /// Saves the current profile, then loads `name`'s settings and ledger into the running
+    /// engine. Candles and live prices carry over; every pair is re-tuned for the new settings.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn switch_profile(&mut self, name: &str, storage: &mut dyn Storage) {
+        if self.engine.is_none() {
+            log::warn!("Profile switch ignored until market data has loaded");
+            return;
+        }
+        eframe::App::save(self, storage);
+        storage.flush();
+
+        let mut profiles = mem::take(&mut self.profiles);
+        let name = profiles.registry.select(name);
+        if let Err(e) = save_profiles(&profiles.registry) {
+            log::error!("Failed to save profiles: {:#}", e);
+        }
+        set_active_profile(&name);
+        log::info!("Switched to profile {}", name);
+
+        let mut next: App =
+            eframe::get_value(storage, &profile_storage_key(&name)).unwrap_or_default();
+        let pairs: Vec<String> = self.valid_session_pairs.iter().cloned().collect();
+        next.shared_config.ensure_all_stations_initialized(&pairs);
+        next.shared_config
+            .ensure_all_phs_initialized(&pairs, PhPct::default());
+        if let Some(mut engine) = self.engine.take() {
+            engine.switch_profile(
+                next.shared_config.clone(),
+                restore_engine_ledger(&self.valid_session_pairs),
+            );
+            next.engine = Some(engine);
+        }
+        next.valid_session_pairs = mem::take(&mut self.valid_session_pairs);
+        next.profiles = profiles;
+        next.housekeeping = mem::take(&mut self.housekeeping);
+        next.cache_inspector = mem::take(&mut self.cache_inspector);
+        // The listener is re-synced to the new profile's settings on the next frame
+        next.signal_webhook = mem::take(&mut self.signal_webhook);
+        next.external_signals = mem::take(&mut self.external_signals);
+        next.journal_path = self.journal_path.take();
+        next.econ_events = mem::take(&mut self.econ_events);
+        // In-flight fetches would otherwise be dropped and never retried
+        next.cross_check_rx = self.cross_check_rx.take();
+        #[cfg(feature = "econ_calendar")]
+        {
+            next.econ_rx = self.econ_rx.take();
+        }
+        next.restore_initial_selection();
+        next.state = AppState::Tuning(TuningState {
+            total: pairs.len(),
+            completed: 0,
+            todo_list: pairs,
+        });
+        *self = next;
+    }
+
+    /// `--pair` / `--ph` (or the web demo's `?pair=&ph=`) override the restored selection.
+    fn apply_startup_args(&mut self, args: &Cli) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.journal_path = args.journal.clone();
+            if args.mock_exchange {
+                let settings = MockSettings::from_args(args.mock_seed, &args.mock_regime)
+                    .unwrap_or_else(|e| {
+                        log::error!("Bad --mock-regime ({:#}); using the default schedule", e);
+                        MockSettings {
+                            seed: args.mock_seed.unwrap_or(MockSettings::default().seed),
+                            ..MockSettings::default()
+                        }
+                    });
+                enable_mock_exchange(settings);
+            }
+        }
+        let Some(pair) = &args.pair else {
+            return;
+        };
+        self.persisted_selection = PersistedSelection::Pair(pair.clone());
+        self.startup_ph = args
+            .ph
+            .filter(|ph| *ph > 0.0 && *ph <= 1.0)
+            .map(|ph| (pair.clone(), PhPct::new(ph)));
+    }
+
+    /// Kicks off the market data fetch; results arrive on `data_rx` during bootstrap.
+    fn start_data_load(&mut self, args: Cli) {
         let (data_tx, data_rx) = mpsc::channel();
         let (prog_tx, prog_rx) = mpsc::channel();
 
-        app.data_rx = Some(data_rx);
-        app.progress_rx = Some(prog_rx);
+        self.data_rx = Some(data_rx);
+        self.progress_rx = Some(prog_rx);
 
         #[cfg(not(target_arch = "wasm32"))]
         {
@@ -150,19 +666,119 @@ impl App {
                     let _ = data_tx.send((data, sig));
                 });
             });
+
+            // The mock exchange promises no network
+            #[cfg(feature = "econ_calendar")]
+            if !args.mock_exchange {
+                let (econ_tx, econ_rx) = mpsc::channel();
+                self.econ_rx = Some(econ_rx);
+                thread::spawn(move || {
+                    let rt = Runtime::new().expect("Failed to create runtime");
+                    let _ = econ_tx.send(rt.block_on(fetch_econ_calendar()));
+                });
+            }
         }
 
         #[cfg(target_arch = "wasm32")]
         {
             let _ = prog_tx;
             let args_clone = args.clone();
+            let (state_tx, state_rx) = mpsc::channel();
+            self.web_state_rx = Some(state_rx);
             wasm_bindgen_futures::spawn_local(async move {
+                // Sent before the data so bootstrap always sees it
+                if let Some(bytes) = web_storage::load_app_state().await {
+                    let _ = state_tx.send(bytes);
+                }
                 let (data, sig) = fetch_pair_data(0, &args_clone, None).await;
                 let _ = data_tx.send((data, sig));
             });
         }
+    }
 
-        app
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) fn tick_demo_select_state(
+        &mut self,
+        ctx: &Context,
+        state: &mut DemoSelectState,
+    ) -> AppState {
+        match render_demo_select(ctx, state.saved_import.load(Ordering::Relaxed)) {
+            Some(id) => {
+                let mut args = state.args.clone();
+                args.demo_dataset = Some(id.to_string());
+                self.start_data_load(args);
+                AppState::Bootstrapping(BootstrapState::default())
+            }
+            None => AppState::DemoSelect(state.clone()),
+        }
+    }
+
+    /// Snapshots analysis parameters before a user change so it can be undone.
+    pub(crate) fn record_params(&mut self, kind: &'static str) {
+        self.param_history
+            .record(self.shared_config.snapshot(), kind);
+    }
+
+    pub(crate) fn undo_params(&mut self) {
+        if let Some(previous) = self.param_history.undo(self.shared_config.snapshot()) {
+            self.restore_params(previous);
+        }
+    }
+
+    pub(crate) fn redo_params(&mut self) {
+        if let Some(next) = self.param_history.redo(self.shared_config.snapshot()) {
+            self.restore_params(next);
+        }
+    }
+
+    /// Applies restored parameters and recalculates only what they affect.
+    fn restore_params(&mut self, target: UIEngineSharedData) {
+        let before = self.shared_config.snapshot();
+        let preview_pair = self.adaptive_editor.preview_pair.take();
+        self.adaptive_editor.draft = Some(target.adaptive.clone());
+        self.shared_config.restore(target.clone());
+
+        let priority_pair = self.selection.pair_owned();
+        let Some(engine) = &mut self.engine else {
+            return;
+        };
+
+        if before.strategy != target.strategy
+            || before.adaptive != target.adaptive
+            || before.exclude_anomalies != target.exclude_anomalies
+            || before.ladder_targets != target.ladder_targets
+            || before.custom_zone_targets != target.custom_zone_targets
+            || before.trade_profile != target.trade_profile
+            || before.zone_config != target.zone_config
+        {
+            engine.trigger_global_recalc(priority_pair.clone());
+        } else {
+            let changed_pairs = target.ph_overrides.keys().filter(|pair| {
+                before.ph_overrides.get(*pair) != target.ph_overrides.get(*pair)
+                    || before.station_overrides.get(*pair) != target.station_overrides.get(*pair)
+                    || (target.custom_zone_targets
+                        && before.custom_zones.get(*pair) != target.custom_zones.get(*pair))
+            });
+            for pair in changed_pairs.chain(preview_pair.iter()) {
+                engine.recalc_pair_from_config(pair, "UNDO/REDO");
+            }
+        }
+
+        let mut reclassify: HashSet<&String> = before
+            .zone_edits
+            .keys()
+            .chain(target.zone_edits.keys())
+            .filter(|pair| before.zone_edits.get(*pair) != target.zone_edits.get(*pair))
+            .collect();
+        if before.zone_config != target.zone_config {
+            reclassify.extend(priority_pair.as_ref());
+        }
+        for pair in reclassify {
+            engine.reclassify_zones(pair);
+            if before.zone_config == target.zone_config {
+                engine.recalc_pair_from_config(pair, "UNDO/REDO ZONE EDIT");
+            }
+        }
     }
 
     /// Change global strategy
@@ -245,50 +861,144 @@ impl App {
     }
 
     pub(crate) fn handle_global_shortcuts(&mut self, ctx: &Context) {
-        if ctx.wants_keyboard_input() {
-            // If the user typing intext box, don't trigger global hotkeys.
+        if ctx.wants_keyboard_input() || self.keybindings.capturing.is_some() {
+            // If the user typing intext box (or rebinding a key), don't trigger global hotkeys.
             return;
         }
 
-        ctx.input(|i| {
-            if i.key_pressed(Key::Num1) {
-                self.plot_visibility.sticky = !self.plot_visibility.sticky;
-            }
-            if i.key_pressed(Key::Num2) {
-                self.plot_visibility.low_wicks = !self.plot_visibility.low_wicks;
+        let actions = ctx.input(|i| self.keybindings.pressed(i));
+        for action in actions {
+            self.apply_hotkey(action);
+        }
+    }
+
+    fn apply_hotkey(&mut self, action: HotkeyAction) {
+        let vis = &mut self.plot_visibility;
+        match action {
+            HotkeyAction::CloseAllPanes => {
+                self.show_debug_help = false;
+                self.glossary.open = false;
+                self.recalc_history.open = false;
+                self.notifications.open = false;
+                self.show_ph_help = false;
+                self.show_journey_browser = false;
+                self.show_adaptive_editor = false;
+                self.show_zone_settings = false;
+                self.show_session_stats = false;
+                self.show_engine_settings = false;
+                self.show_diagnostics = false;
+                self.show_stress = false;
+                self.show_heat_calendar = false;
+                self.show_cache_inspector = false;
+                self.show_indicator_editor = false;
+                self.show_basket = false;
+                self.show_sectors = false;
+                self.station_preview.cancel();
             }
-            if i.key_pressed(Key::Num3) {
-                self.plot_visibility.high_wicks = !self.plot_visibility.high_wicks;
+            HotkeyAction::ToggleHelp => self.show_debug_help = !self.show_debug_help,
+            HotkeyAction::ToggleSticky => vis.sticky = !vis.sticky,
+            HotkeyAction::ToggleLowWicks => vis.low_wicks = !vis.low_wicks,
+            HotkeyAction::ToggleHighWicks => vis.high_wicks = !vis.high_wicks,
+            HotkeyAction::ToggleBackground => vis.background = !vis.background,
+            HotkeyAction::ToggleCandles => vis.candles = !vis.candles,
+            HotkeyAction::ToggleSeparators => vis.separators = !vis.separators,
+            HotkeyAction::ToggleHorizonLines => vis.horizon_lines = !vis.horizon_lines,
+            HotkeyAction::TogglePriceLine => vis.price_line = !vis.price_line,
+            HotkeyAction::ToggleOpportunities => vis.opportunities = !vis.opportunities,
+            HotkeyAction::ToggleTimeMachine => {
+                self.show_candle_range = !self.show_candle_range;
+                if self.show_candle_range {
+                    self.panel_layout.current_mut().right_open = true;
+                }
             }
-            if i.key_pressed(Key::Num4) {
-                self.plot_visibility.background = !self.plot_visibility.background;
+            HotkeyAction::ToggleAdaptiveEditor => {
+                self.show_adaptive_editor = !self.show_adaptive_editor
             }
-            if i.key_pressed(Key::Num5) {
-                self.plot_visibility.candles = !self.plot_visibility.candles;
+            HotkeyAction::ToggleZoneSettings => self.show_zone_settings = !self.show_zone_settings,
+            HotkeyAction::ToggleSessionStats => self.show_session_stats = !self.show_session_stats,
+            HotkeyAction::ToggleEngineSettings => {
+                self.show_engine_settings = !self.show_engine_settings
             }
-            if i.key_pressed(Key::Num6) {
-                self.plot_visibility.separators = !self.plot_visibility.separators;
+            HotkeyAction::ToggleGlossary => self.glossary.open = !self.glossary.open,
+            HotkeyAction::ToggleDiagnostics => self.show_diagnostics = !self.show_diagnostics,
+            HotkeyAction::ToggleStress => self.show_stress = !self.show_stress,
+            HotkeyAction::ToggleHeatCalendar => {
+                self.show_heat_calendar = !self.show_heat_calendar;
             }
-            if i.key_pressed(Key::Num7) {
-                self.plot_visibility.horizon_lines = !self.plot_visibility.horizon_lines;
+            HotkeyAction::ToggleCacheInspector => {
+                self.show_cache_inspector = !self.show_cache_inspector;
             }
-            if i.key_pressed(Key::Num8) {
-                self.plot_visibility.price_line = !self.plot_visibility.price_line;
+            HotkeyAction::ToggleIndicatorEditor => {
+                self.show_indicator_editor = !self.show_indicator_editor;
             }
-            if i.key_pressed(Key::Num9) {
-                self.plot_visibility.opportunities = !self.plot_visibility.opportunities;
+            HotkeyAction::ToggleBasket => self.show_basket = !self.show_basket,
+            HotkeyAction::ToggleSectors => self.show_sectors = !self.show_sectors,
+            HotkeyAction::PrevRow => self.tf_row_step = Some(-1),
+            HotkeyAction::NextRow => self.tf_row_step = Some(1),
+            HotkeyAction::PrevStation | HotkeyAction::NextStation => {
+                let delta = if action == HotkeyAction::NextStation {
+                    1
+                } else {
+                    -1
+                };
+                let active = self
+                    .selection
+                    .pair_owned()
+                    .and_then(|pair| self.shared_config.get_station_opt(Some(pair)));
+                self.station_preview.step(active, delta);
             }
-            if i.key_pressed(Key::K) || i.key_pressed(Key::H) {
-                self.show_debug_help = !self.show_debug_help;
+            HotkeyAction::ApplyStation => {
+                if let Some(station) = self.station_preview.pending {
+                    self.handle_tuner_action(TunerAction::StationSelected(station));
+                }
             }
-            if i.key_pressed(Key::Escape) {
-                self.show_debug_help = false;
-                self.show_ph_help = false;
+            HotkeyAction::CancelStation => self.station_preview.cancel(),
+            HotkeyAction::UndoParams => self.undo_params(),
+            HotkeyAction::RedoParams => self.redo_params(),
+            HotkeyAction::PrevSegment => self.step_segment(-1),
+            HotkeyAction::NextSegment => self.step_segment(1),
+            HotkeyAction::ZoomInY | HotkeyAction::ZoomOutY => {
+                let factor = if action == HotkeyAction::ZoomInY {
+                    PLOT_ZOOM_STEP
+                } else {
+                    1.0 / PLOT_ZOOM_STEP
+                };
+                self.auto_scale_y = AutoScaleY(false);
+                self.plot_view.zoom_y(factor);
             }
-            if i.key_pressed(Key::T) {
-                self.show_candle_range = !self.show_candle_range;
+            HotkeyAction::ResetView => {
+                let mut nav = self.get_nav_state();
+                nav.current_segment_idx = None;
+                self.set_nav_state(nav);
+                self.auto_scale_y = AutoScaleY(true);
             }
-        });
+        }
+    }
+
+    /// Moves the plot to the previous/next segment. From "show all" the first step lands on
+    /// the last viewed segment.
+    fn step_segment(&mut self, delta: i32) {
+        let Some(segment_count) = self
+            .selection
+            .pair()
+            .and_then(|p| self.engine.as_ref()?.get_model(p))
+            .map(|m| m.segments.len())
+        else {
+            return;
+        };
+        if segment_count == 0 {
+            return;
+        }
+        let mut nav = self.get_nav_state();
+        let max_idx = segment_count - 1;
+        let idx = match nav.current_segment_idx {
+            None => nav.last_viewed_segment_idx.min(max_idx),
+            Some(idx) => idx.saturating_add_signed(delta as isize).min(max_idx),
+        };
+        nav.current_segment_idx = Some(idx);
+        nav.last_viewed_segment_idx = idx;
+        self.set_nav_state(nav);
+        self.auto_scale_y = AutoScaleY(true);
     }
 
     pub(crate) fn tick_tuning_state(&mut self, ctx: &Context, state: &mut TuningState) -> AppState {
@@ -334,7 +1044,12 @@ impl App {
 
         state.completed += processed;
         if state.todo_list.is_empty() {
+            let startup_ph = self.startup_ph.take();
             if let Some(e) = &mut self.engine {
+                // Explicit startup PH wins over the tuner's pick
+                if let Some((pair, ph)) = startup_ph {
+                    e.shared_config.insert_ph(pair, ph);
+                }
                 #[cfg(debug_assertions)]
                 if DF.log_tuner {
                     log::info!(">> Global Tuning Complete. Igniting Engine.");
@@ -355,20 +1070,44 @@ impl App {
     /// RUNNING PHASE MAIN LOOP
     pub(crate) fn tick_running_state(&mut self, ctx: &Context) {
         let start = AppInstant::now();
+        let activity = self.idle.observe(ctx, &self.idle_settings);
 
         if let Some(e) = &mut self.engine {
+            e.set_hold_auto_recalcs(activity.holds_recalcs(&self.idle_settings));
+            e.repaint.attach(ctx);
+            e.repaint.set_live(activity == Activity::Active);
+            e.repaint.watch(self.selection.pair());
             let removals = e.update();
             self.clear_selection_if_opportunity_removed(&removals.ids);
         }
 
         self.ensure_valid_selection();
+        self.opportunity_script.refresh();
+        self.check_price_alerts(ctx);
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.publish_instance_status();
+            self.housekeeping.poll(&mut self.housekeeping_settings);
+            self.signal_webhook.sync(&self.signal_webhook_settings, ctx);
+            self.receive_external_signals();
+        }
+        #[cfg(all(feature = "econ_calendar", not(target_arch = "wasm32")))]
+        if let Some(events) = self.econ_rx.as_ref().and_then(|rx| rx.try_recv().ok()) {
+            self.econ_events = events;
+            self.econ_rx = None;
+        }
         let engine_time = start.elapsed().as_micros();
         self.handle_global_shortcuts(ctx);
+        if self.panel_layout.update(ctx.content_rect().width()) {
+            ctx.request_repaint();
+        }
         self.render_top_panel(ctx);
         let start = AppInstant::now();
-        self.render_left_panel(ctx);
+        if self.panel_layout.current().left_open {
+            self.render_left_panel(ctx);
+        }
         let left_panel_time = start.elapsed().as_micros();
-        if self.show_candle_range {
+        if self.show_candle_range && self.panel_layout.current().right_open {
             self.render_right_panel(ctx);
         }
         self.render_ticker_panel(ctx);
@@ -376,7 +1115,44 @@ impl App {
         let start = AppInstant::now();
         self.render_central_panel(ctx);
         let plot_time = start.elapsed().as_micros();
+        self.render_journey_browser(ctx);
+        self.render_adaptive_editor_panel(ctx);
+        self.render_zone_settings_panel(ctx);
+        self.drive_zone_recalc(ctx);
+        self.render_session_stats_panel(ctx);
+        self.render_engine_settings_panel(ctx);
+        self.render_diagnostics_panel(ctx);
+        self.render_stress_panel(ctx);
+        self.render_basket_panel(ctx);
+        self.render_sector_panel(ctx);
+        self.render_heat_calendar(ctx);
+        #[cfg(not(target_arch = "wasm32"))]
+        render_cache_inspector(
+            ctx,
+            &mut self.show_cache_inspector,
+            &mut self.cache_inspector,
+        );
+        render_indicator_editor(
+            ctx,
+            &mut self.show_indicator_editor,
+            self.selection.pair(),
+            &mut self.indicator_scripts,
+        );
+        self.render_annotation_editor(ctx);
+        self.render_custom_zone_editor(ctx);
+        self.render_price_what_if(ctx);
+        self.render_correlated_what_if(ctx);
         self.render_help_panel(ctx);
+        render_glossary(ctx, &mut self.glossary);
+        render_recalc_history(ctx, self.engine.as_ref(), &mut self.recalc_history);
+        render_notifications(ctx, &mut self.notifications);
+        // Price ticks, job results and input wake us; the heartbeat keeps batch flushes,
+        // pruning and alerts going between events (slower while idle / in the background)
+        ctx.request_repaint_after(
+            activity
+                .repaint_interval(&self.idle_settings)
+                .unwrap_or(RUNNING_HEARTBEAT),
+        );
         if engine_time + left_panel_time + plot_time > 500_000 {
             #[cfg(debug_assertions)]
             if LOG_PERFORMANCE {
@@ -390,11 +1166,119 @@ impl App {
         }
     }
 
+    /// Web build: kline CSVs or cache files dropped on the canvas replace the loaded data.
+    #[cfg(target_arch = "wasm32")]
+    fn handle_dropped_files(&mut self, ctx: &Context) {
+        if ctx.input(|i| !i.raw.hovered_files.is_empty()) {
+            let painter = ctx.layer_painter(LayerId::new(Order::Foreground, Id::new("file_drop")));
+            let rect = ctx.content_rect();
+            painter.rect_filled(rect, 0.0, Color32::from_black_alpha(180));
+            painter.text(
+                rect.center(),
+                Align2::CENTER_CENTER,
+                &UI_TEXT.ds_drop_hint,
+                FontId::proportional(22.0),
+                Color32::WHITE,
+            );
+        }
+
+        if let Some(msg) = &self.import_error {
+            let mut open = true;
+            Window::new(&UI_TEXT.ds_import_failed)
+                .open(&mut open)
+                .collapsible(false)
+                .show(ctx, |ui| ui.label(msg));
+            if !open {
+                self.import_error = None;
+            }
+        }
+
+        let files: Vec<(String, Vec<u8>)> = ctx.input(|i| {
+            i.raw
+                .dropped_files
+                .iter()
+                .filter_map(|f| Some((f.name.clone(), f.bytes.as_ref()?.to_vec())))
+                .collect()
+        });
+        if files.is_empty() {
+            return;
+        }
+
+        match import_kline_files(&files) {
+            Ok(timeseries) => {
+                #[cfg(debug_assertions)]
+                if DF.log_wasm_demo {
+                    log::info!(
+                        "Imported {} series from {} dropped files",
+                        timeseries.series_data.len(),
+                        files.len()
+                    );
+                }
+                web_storage::save_imported_klines(&timeseries);
+                self.import_error = None;
+                self.data_rx = None; // Ignore a demo load still in flight
+                self.selection = Selection::None;
+                self.persisted_selection = PersistedSelection::None;
+                self.build_engine(timeseries);
+                self.restore_initial_selection();
+                // After the load, so it never competes with the initial sync for the DB
+                #[cfg(not(target_arch = "wasm32"))]
+                if self.housekeeping_settings.on_startup {
+                    self.housekeeping.start(self.housekeeping_settings.clone());
+                }
+                self.state = AppState::Tuning(TuningState {
+                    total: self.valid_session_pairs.len(),
+                    completed: 0,
+                    todo_list: self.valid_session_pairs.iter().cloned().collect(),
+                });
+            }
+            Err(e) => {
+                log::error!("Kline import failed: {:#}", e);
+                self.import_error = Some(format!("{:#}", e));
+            }
+        }
+    }
+
+    /// Web build: settings and drawings from IndexedDB. They win over egui's localStorage copy,
+    /// which browsers evict sooner and cap at a few MB.
+    #[cfg(target_arch = "wasm32")]
+    fn apply_web_state(&mut self) {
+        let Some(bytes) = self.web_state_rx.take().and_then(|rx| rx.try_recv().ok()) else {
+            return;
+        };
+        match serde_json::from_slice::<App>(&bytes) {
+            Ok(saved) => {
+                #[cfg(debug_assertions)]
+                if DF.log_wasm_demo {
+                    log::info!("Restored app state from IndexedDB ({} bytes)", bytes.len());
+                }
+                self.shared_config = saved.shared_config;
+                self.plot_visibility = saved.plot_visibility;
+                self.watchlist = saved.watchlist;
+                self.price_marks = saved.price_marks;
+                self.annotations = saved.annotations;
+                self.indicator_scripts = saved.indicator_scripts;
+                // A `?pair=` start parameter wins over the stored selection
+                if matches!(self.persisted_selection, PersistedSelection::None) {
+                    self.persisted_selection = saved.persisted_selection;
+                }
+            }
+            Err(e) => log::error!("Ignoring unreadable IndexedDB app state: {}", e),
+        }
+    }
+
     pub(crate) fn finalize_bootstrap_if_ready(&mut self) -> Option<AppState> {
         if let Some(rx) = &self.data_rx {
             if let Ok((timeseries, _sig)) = rx.try_recv() {
+                #[cfg(target_arch = "wasm32")]
+                self.apply_web_state();
                 self.build_engine(timeseries);
                 self.restore_initial_selection();
+                // After the load, so it never competes with the initial sync for the DB
+                #[cfg(not(target_arch = "wasm32"))]
+                if self.housekeeping_settings.on_startup {
+                    self.housekeeping.start(self.housekeeping_settings.clone());
+                }
                 return Some(AppState::Tuning(TuningState {
                     total: self.valid_session_pairs.len(),
                     completed: 0,
@@ -453,7 +1337,7 @@ impl App {
                 opportunity_id,
             } => {
                 if let Some(engine) = &self.engine {
-                    if let Some(op) = engine.engine_ledger.opportunities.get(opportunity_id) {
+                    if let Some(op) = engine.engine_ledger.get(opportunity_id) {
                         Selection::Opportunity(op.clone())
                     } else if self.valid_session_pairs.contains(pair) {
                         // Opportunity expired → fall back to its pair
@@ -485,6 +1369,12 @@ impl App {
             self.valid_session_pairs.iter().cloned().collect(),
         );
         engine.engine_ledger = restore_engine_ledger(&self.valid_session_pairs);
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(path) = &self.journal_path {
+            if let Err(e) = engine.enable_journal(path) {
+                log::error!("Job journal disabled: {:#}", e);
+            }
+        }
         self.engine = Some(engine);
     }
 
@@ -497,13 +1387,39 @@ impl App {
             .ensure_all_phs_initialized(&available_pairs, PhPct::default());
     }
 
+    #[cfg(not(all(target_arch = "wasm32", feature = "wasm-slim")))]
+    fn configure_fonts(ctx: &Context) {
+        Self::install_fonts(
+            ctx,
+            FontData::from_static(include_bytes!("../../fonts/HackNerdFont-Regular.ttf")),
+            FontData::from_static(include_bytes!("../../fonts/HackNerdFontPropo-Regular.ttf")),
+        );
+    }
+
+    /// `wasm-slim`: egui's built-in fonts until the Nerd Fonts arrive over HTTP.
+    #[cfg(all(target_arch = "wasm32", feature = "wasm-slim"))]
     fn configure_fonts(ctx: &Context) {
+        let ctx = ctx.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            let mono = fetch_bytes("fonts/HackNerdFont-Regular.ttf").await;
+            let propo = fetch_bytes("fonts/HackNerdFontPropo-Regular.ttf").await;
+            match (mono, propo) {
+                (Ok(mono), Ok(propo)) => {
+                    Self::install_fonts(
+                        &ctx,
+                        FontData::from_owned(mono),
+                        FontData::from_owned(propo),
+                    );
+                    ctx.request_repaint();
+                }
+                (Err(e), _) | (_, Err(e)) => log::warn!("Fonts unavailable: {:#}", e),
+            }
+        });
+    }
+
+    fn install_fonts(ctx: &Context, mut font_data_mono: FontData, mut font_data_propo: FontData) {
         let mut fonts = FontDefinitions::default();
-        let mut font_data_mono =
-            FontData::from_static(include_bytes!("../../fonts/HackNerdFont-Regular.ttf"));
         font_data_mono.tweak.scale = 0.85;
-        let mut font_data_propo =
-            FontData::from_static(include_bytes!("../../fonts/HackNerdFontPropo-Regular.ttf"));
         font_data_propo.tweak.scale = 0.85;
         fonts
             .font_data
@@ -520,7 +1436,7 @@ impl App {
         ctx.set_fonts(fonts);
     }
 
-    fn clear_selection_if_opportunity_removed(&mut self, removed_ids: &[String]) {
+    pub(crate) fn clear_selection_if_opportunity_removed(&mut self, removed_ids: &[String]) {
         if let Selection::Opportunity(op) = &self.selection {
             if removed_ids.iter().any(|id| id == &op.id) {
                 #[cfg(debug_assertions)]
@@ -711,10 +1627,23 @@ impl App {
 }
 
 impl eframe::App for App {
-    fn update(&mut self, ctx: &Context, _frame: &mut Frame) {
+    fn update(&mut self, ctx: &Context, frame: &mut Frame) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(name) = self.profiles.take_pending() {
+            match frame.storage_mut() {
+                Some(storage) => self.switch_profile(&name, storage),
+                None => log::error!("No settings storage; cannot switch to profile {}", name),
+            }
+        }
+        #[cfg(target_arch = "wasm32")]
+        let _ = frame;
         setup_custom_visuals(ctx);
+        #[cfg(target_arch = "wasm32")]
+        self.handle_dropped_files(ctx);
         let current = mem::take(&mut self.state);
         self.state = match current {
+            #[cfg(target_arch = "wasm32")]
+            AppState::DemoSelect(mut s) => s.tick(self, ctx),
             AppState::Bootstrapping(mut s) => s.tick(self, ctx),
             AppState::Tuning(mut s) => s.tick(self, ctx),
             AppState::Running(mut s) => s.tick(self, ctx),
@@ -722,6 +1651,12 @@ impl eframe::App for App {
     }
 
     fn save(&mut self, storage: &mut dyn Storage) {
+        // The mock exchange promises no writes: neither its synthetic ledger nor the settings
+        // tuned against it replace the real ones
+        #[cfg(not(target_arch = "wasm32"))]
+        if mock_exchange().is_some() {
+            return;
+        }
         self.persisted_selection = match &self.selection {
             Selection::None => PersistedSelection::None,
             Selection::Pair(p) => PersistedSelection::Pair(p.clone()),
@@ -743,7 +1678,12 @@ impl eframe::App for App {
                 log::error!("Failed to save ledger: {}", err);
             }
         }
-        eframe::set_value(storage, eframe::APP_KEY, self);
+        eframe::set_value(storage, &profile_storage_key(&active_profile()), self);
+        #[cfg(target_arch = "wasm32")]
+        match serde_json::to_vec(self) {
+            Ok(bytes) => web_storage::save_app_state(bytes),
+            Err(e) => log::error!("Failed to serialize app state: {}", e),
+        }
     }
 }
 
@@ -758,3 +1698,13 @@ fn setup_custom_visuals(ctx: &Context) {
     ctx.set_visuals(visuals);
     ctx.style_mut(|s| s.interaction.selectable_labels = false);
 }
+
+/// eframe storage key for a profile's settings. The default profile keeps the key used before
+/// profiles existed, so existing settings load unchanged.
+fn profile_storage_key(profile: &str) -> String {
+    if profile == DEFAULT_PROFILE {
+        eframe::APP_KEY.to_string()
+    } else {
+        format!("{}_{}", eframe::APP_KEY, profile)
+    }
+}
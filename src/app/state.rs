@@ -1,8 +1,14 @@
+#[cfg(target_arch = "wasm32")]
+use {
+    crate::Cli,
+    std::sync::{Arc, atomic::AtomicBool},
+};
+
 use {
     crate::{app::App, models::TradeOpportunity},
     eframe::egui::Context,
     serde::{Deserialize, Serialize},
-    std::{collections::BTreeMap, fmt},
+    std::{cmp::Ordering, collections::BTreeMap, fmt},
 };
 
 #[allow(dead_code)]
@@ -35,6 +41,14 @@ impl SortDirection {
             Self::Descending => Self::Ascending,
         }
     }
+
+    /// Applies this direction to an ascending comparison.
+    pub(crate) fn apply(self, ascending: Ordering) -> Ordering {
+        match self {
+            Self::Ascending => ascending,
+            Self::Descending => ascending.reverse(),
+        }
+    }
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -90,6 +104,7 @@ pub(crate) enum PersistedSelection {
     Pair(String),
     Opportunity {
         pair: String,
+        /// May predate stable ids; `OpportunityLedger::get` follows re-keyed ones.
         opportunity_id: String,
     },
 }
@@ -156,7 +171,25 @@ impl PhaseView for BootstrapState {
     }
 }
 
+/// Web demo start screen: waiting for the user to pick a bundled dataset.
+#[cfg(target_arch = "wasm32")]
+#[derive(Clone)]
+pub(crate) struct DemoSelectState {
+    pub(crate) args: Cli,
+    /// Set once IndexedDB confirms a saved import exists
+    pub(crate) saved_import: Arc<AtomicBool>,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl PhaseView for DemoSelectState {
+    fn tick(&mut self, app: &mut App, ctx: &Context) -> AppState {
+        app.tick_demo_select_state(ctx, self)
+    }
+}
+
 pub(crate) enum AppState {
+    #[cfg(target_arch = "wasm32")]
+    DemoSelect(DemoSelectState),
     Bootstrapping(BootstrapState),
     Tuning(TuningState),
     Running(RunningState),
@@ -6,10 +6,12 @@ use {
         ops::{Add, Div, Mul, Sub},
         time::Duration,
     },
-    strum_macros::EnumIter,
+    strum_macros::{EnumCount, EnumIter},
 };
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, EnumIter, Default)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, EnumIter, EnumCount, Default,
+)]
 pub enum CandleResolution {
     M5,
     M15,
@@ -247,7 +249,6 @@ impl Prob {
         Self(v)
     }
 
-    #[cfg(feature = "backtest")]
     pub(crate) fn value(self) -> f64 {
         self.0
     }
@@ -259,7 +260,7 @@ impl fmt::Display for Prob {
     }
 }
 
-use chrono::Duration as ChronoDuration;
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
 
 impl From<DurationMs> for ChronoDuration {
     fn from(d: DurationMs) -> Self {
@@ -269,7 +270,7 @@ impl From<DurationMs> for ChronoDuration {
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize, Default)]
 #[serde(transparent)]
-pub(crate) struct DurationMs(i64);
+pub struct DurationMs(i64);
 
 impl DurationMs {
     pub(crate) const fn new(ms: i64) -> Self {
@@ -308,6 +309,84 @@ impl fmt::Display for DurationMs {
     }
 }
 
+/// A point in time as epoch milliseconds (UTC). Serializes as the bare i64, so caches written
+/// before it existed still load.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Default,
+)]
+#[serde(transparent)]
+pub struct TimestampMs(i64);
+
+impl TimestampMs {
+    /// Open end for time-range queries.
+    pub(crate) const MAX: Self = Self(i64::MAX);
+
+    pub const fn new(ms: i64) -> Self {
+        Self(ms)
+    }
+
+    pub fn value(self) -> i64 {
+        self.0
+    }
+
+    pub(crate) fn now() -> Self {
+        Self(T::now_timestamp_ms())
+    }
+
+    /// Start of the `interval_ms` bucket (epoch-aligned) this instant falls in.
+    pub(crate) fn align_down(self, interval_ms: i64) -> Self {
+        Self(self.0.div_euclid(interval_ms) * interval_ms)
+    }
+
+    pub(crate) fn to_datetime(self) -> DateTime<Utc> {
+        T::ms_to_datetime(self.0)
+    }
+
+    /// "YYYY-MM-DD" (UTC)
+    pub(crate) fn to_datestring(self) -> String {
+        T::ms_to_datestring(self.0)
+    }
+}
+
+impl From<i64> for TimestampMs {
+    fn from(ms: i64) -> Self {
+        Self(ms)
+    }
+}
+
+impl From<DateTime<Utc>> for TimestampMs {
+    fn from(dt: DateTime<Utc>) -> Self {
+        Self(dt.timestamp_millis())
+    }
+}
+
+impl Add<DurationMs> for TimestampMs {
+    type Output = Self;
+    fn add(self, rhs: DurationMs) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub<DurationMs> for TimestampMs {
+    type Output = Self;
+    fn sub(self, rhs: DurationMs) -> Self {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl Sub for TimestampMs {
+    type Output = DurationMs;
+    fn sub(self, rhs: Self) -> DurationMs {
+        DurationMs(self.0 - rhs.0)
+    }
+}
+
+impl fmt::Display for TimestampMs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_datetime().format("%Y-%m-%d %H:%M"))
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize, Default)]
 #[serde(transparent)]
 pub struct VolRatio(f64);
@@ -634,7 +713,6 @@ impl<T: PriceLike> PriceRange<T> {
 pub struct BaseVol(f64);
 
 impl BaseVol {
-    #[cfg(not(target_arch = "wasm32"))]
     pub(crate) const fn new(val: f64) -> Self {
         let v = if val < 0.0 { 0.0 } else { val };
         Self(v)
@@ -706,7 +784,7 @@ impl fmt::Display for Weight {
     }
 }
 
-#[derive(Clone, Debug, Copy)]
+#[derive(Clone, Debug, Copy, PartialEq, Serialize, Deserialize)]
 pub(crate) struct ZoneParams {
     pub smooth_pct: PhPct,
     pub gap_pct: PhPct,
@@ -721,16 +799,103 @@ pub(crate) struct SimilaritySettings {
     pub weight_volume: Weight,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub(crate) struct ZoneClassificationConfig {
     pub sticky: ZoneParams,
-    pub reversal: ZoneParams,
+    pub low_wick: ZoneParams,
+    pub high_wick: ZoneParams,
+    #[serde(default)]
+    pub sticky_score: StickyScore,
 }
 
-#[derive(Clone, Debug)]
+/// Which CVA score the sticky zones are classified from.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, EnumIter)]
+pub(crate) enum StickyScore {
+    /// Total traded volume per zone
+    #[default]
+    Volume,
+    /// Size of the net taker buy/sell imbalance per zone: where aggressive flow concentrated
+    TakerDelta,
+}
+
+impl fmt::Display for StickyScore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Volume => write!(f, "Volume"),
+            Self::TakerDelta => write!(f, "Taker delta"),
+        }
+    }
+}
+
+/// Acceptable zone coverage range (% of all zones) for one zone type.
+#[derive(Clone, Debug, Copy, PartialEq, Serialize, Deserialize)]
+pub(crate) struct CoverageBand {
+    pub min_pct: f64,
+    pub max_pct: f64,
+}
+
+impl CoverageBand {
+    pub(crate) fn contains(&self, pct: f64) -> bool {
+        pct >= self.min_pct && pct <= self.max_pct
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct CoverageTargets {
+    pub sticky: CoverageBand,
+    pub support: CoverageBand,
+    pub resistance: CoverageBand,
+}
+
+/// How the stop-loss of an opportunity is placed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, EnumIter)]
+pub(crate) enum StopMode {
+    /// Sweep the risk:reward ratios and keep the best-scoring stop
+    #[default]
+    RiskReward,
+    /// Just beyond the far edge of the nearest protective zone (falls back to the sweep if none)
+    ZoneAnchored,
+}
+
+impl fmt::Display for StopMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RiskReward => write!(f, "R:R sweep"),
+            Self::ZoneAnchored => write!(f, "Zone anchored"),
+        }
+    }
+}
+
+/// How the stop of an open trade is managed in the journey simulations.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, EnumIter)]
+pub(crate) enum StopManagement {
+    /// The stop stays where it was placed
+    #[default]
+    Fixed,
+    /// The stop moves to entry once the trade is 1R in profit
+    BreakEvenAt1R,
+}
+
+impl fmt::Display for StopManagement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fixed => write!(f, "Fixed stop"),
+            Self::BreakEvenAt1R => write!(f, "Break-even after 1R"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub(crate) struct TradeProfile {
     pub min_roi_pct: RoiPct,
     pub min_aroi_pct: AroiPct,
+    pub stop_mode: StopMode,
+    pub stop_management: StopManagement,
+    /// Distance past the protective zone's far edge for `StopMode::ZoneAnchored`
+    pub zone_stop_buffer: Pct,
+    /// Risk-free yield (e.g. stablecoin lending) that AROI is measured against
+    pub benchmark_aroi_pct: AroiPct,
 }
 
 impl TradeProfile {
@@ -745,6 +910,15 @@ impl TradeProfile {
     pub(crate) fn is_worthwhile(&self, roi_pct: RoiPct, aroi_pct: AroiPct) -> bool {
         roi_pct >= self.min_roi_pct && aroi_pct >= self.min_aroi_pct
     }
+
+    /// AROI above the benchmark rate (negative = the trade earns less than sitting in it).
+    pub(crate) fn excess_aroi(&self, aroi_pct: AroiPct) -> AroiPct {
+        AroiPct::new(aroi_pct.value() - self.benchmark_aroi_pct.value())
+    }
+
+    pub(crate) fn beats_benchmark(&self, aroi_pct: AroiPct) -> bool {
+        self.excess_aroi(aroi_pct).value() > 0.0
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -762,6 +936,34 @@ pub(crate) struct OptimalSearchSettings {
     pub prune_interval_sec: u64,
 }
 
+/// Minimum-evidence rules for simulation results.
+/// Below `min_sample_size` a result is suppressed; below `low_evidence_sample_size` it is demoted and badged.
+#[derive(Clone, Debug)]
+pub(crate) struct EvidenceSettings {
+    pub min_sample_size: usize,
+    pub low_evidence_sample_size: usize,
+    pub low_evidence_penalty: f64,
+}
+
+impl EvidenceSettings {
+    pub(crate) fn is_sufficient(&self, sample_size: usize) -> bool {
+        sample_size >= self.min_sample_size
+    }
+
+    pub(crate) fn is_low_evidence(&self, sample_size: usize) -> bool {
+        sample_size < self.low_evidence_sample_size
+    }
+
+    /// Scales a positive objective score down for thin samples. Negative scores are left alone.
+    pub(crate) fn demote_score(&self, score: f64, sample_size: usize) -> f64 {
+        if score > 0.0 && self.is_low_evidence(sample_size) {
+            score * self.low_evidence_penalty
+        } else {
+            score
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub(crate) struct JourneySettings {
     pub sample_count: usize,
@@ -769,5 +971,6 @@ pub(crate) struct JourneySettings {
     pub min_journey_time: Duration,
     pub max_journey_time: Duration,
     pub profile: TradeProfile,
+    pub evidence: EvidenceSettings,
     pub optimization: OptimalSearchSettings,
 }
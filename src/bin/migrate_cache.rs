@@ -0,0 +1,109 @@
+// `cargo run --bin migrate_cache -- [OPTIONS]`
+//
+// Builds candles for a coarser interval from ones already in `klines.sqlite`
+// (e.g. 1m -> 5m / 15m), validates them against their source and stores them,
+// so changing BASE_INTERVAL does not force a full re-download.
+//
+// Usage examples:
+//   cargo run --bin migrate_cache                               # 1m -> BASE_INTERVAL, all pairs
+//   cargo run --bin migrate_cache -- --from 1m --to 15m
+//   cargo run --bin migrate_cache -- --to 5m --pair BTCUSDT --pair ETHUSDT
+
+#[cfg(not(target_arch = "wasm32"))]
+mod inner {
+    use {
+        anyhow::{Context, Result, anyhow},
+        clap::Parser,
+        zone_sniper::{
+            BASE_INTERVAL, MarketDataStorage, SqliteStorage, TimeUtils, migrate_interval,
+        },
+    };
+
+    #[derive(Parser, Debug)]
+    #[command(
+        name = "migrate_cache",
+        about = "Downsample cached klines into a coarser interval instead of re-downloading"
+    )]
+    struct Cli {
+        /// Interval to read, e.g. 1m
+        #[arg(long, default_value = "1m")]
+        from: String,
+
+        /// Interval to write (defaults to the app's BASE_INTERVAL)
+        #[arg(long)]
+        to: Option<String>,
+
+        /// Pairs to migrate (repeatable); defaults to every pair stored at `--from`
+        #[arg(long)]
+        pair: Vec<String>,
+
+        /// Path to the SQLite kline database
+        #[arg(long, default_value = "klines.sqlite")]
+        db: String,
+    }
+
+    fn parse_interval(s: &str) -> Result<i64> {
+        TimeUtils::string_to_interval(s).ok_or_else(|| anyhow!("Unknown interval '{}'", s))
+    }
+
+    pub async fn run() -> Result<()> {
+        let cli = Cli::parse();
+        let from_ms = parse_interval(&cli.from)?;
+        let to_ms = match &cli.to {
+            Some(to) => parse_interval(to)?,
+            None => BASE_INTERVAL.as_millis() as i64,
+        };
+
+        let storage = SqliteStorage::new(&cli.db)
+            .await
+            .with_context(|| format!("Failed to open {}", cli.db))?;
+        storage.initialize().await?;
+
+        let pairs = if cli.pair.is_empty() {
+            storage.list_pairs(&cli.from).await?
+        } else {
+            cli.pair.iter().map(|p| p.to_uppercase()).collect()
+        };
+        if pairs.is_empty() {
+            return Err(anyhow!("No {} candles found in {}", cli.from, cli.db));
+        }
+
+        println!(
+            "Migrating {} pairs: {} -> {}",
+            pairs.len(),
+            cli.from,
+            TimeUtils::interval_to_string(to_ms)
+        );
+        let mut failed = 0;
+        for pair in &pairs {
+            match migrate_interval(&storage, pair, from_ms, to_ms).await {
+                Ok(m) => println!(
+                    "  ✅ {:<12} {:>8} source -> {:>7} candles ({} incomplete buckets skipped)",
+                    m.pair, m.source_candles, m.migrated_candles, m.incomplete_buckets
+                ),
+                Err(e) => {
+                    failed += 1;
+                    println!("  ❌ {:<12} {:#}", pair, e);
+                }
+            }
+        }
+
+        if failed > 0 {
+            return Err(anyhow!("{} of {} pairs failed", failed, pairs.len()));
+        }
+        println!("Done.");
+        Ok(())
+    }
+}
+
+// ─── main ────────────────────────────────────────────────────────────────────
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("warn")).init();
+    inner::run().await
+}
+
+#[cfg(target_arch = "wasm32")]
+fn main() {}
@@ -2,9 +2,26 @@ pub struct DemoResources {
     pub pairs: &'static [&'static str],
 }
 
+/// A named subset of the embedded demo cache, picked on the web start screen.
+pub struct DemoDataset {
+    pub id: &'static str,
+    pub label: &'static str,
+    pub description: &'static str,
+    pub pairs: &'static [&'static str],
+}
+
 pub struct DemoConfig {
     pub max_pairs: usize,
     pub resources: DemoResources,
+    pub datasets: &'static [DemoDataset],
+    /// Dataset id that loads the last drag & drop import kept in browser storage
+    pub saved_import_id: &'static str,
+}
+
+impl DemoConfig {
+    pub fn dataset(&self, id: &str) -> Option<&DemoDataset> {
+        self.datasets.iter().find(|d| d.id.eq_ignore_ascii_case(id))
+    }
 }
 
 pub const DEMO: DemoConfig = DemoConfig {
@@ -14,4 +31,33 @@ pub const DEMO: DemoConfig = DemoConfig {
             "BTCUSDT", "ETHUSDT", "SOLUSDT", "BNBUSDT", "PAXGUSDT", "DOGEUSDT", "USDCUSDT",
         ],
     },
+    datasets: &[
+        DemoDataset {
+            id: "all",
+            label: "All demo pairs",
+            description: "Every pair in the bundled cache",
+            pairs: &[
+                "BTCUSDT", "ETHUSDT", "SOLUSDT", "BNBUSDT", "PAXGUSDT", "DOGEUSDT", "USDCUSDT",
+            ],
+        },
+        DemoDataset {
+            id: "majors",
+            label: "Majors",
+            description: "Deep, liquid markets",
+            pairs: &["BTCUSDT", "ETHUSDT", "BNBUSDT"],
+        },
+        DemoDataset {
+            id: "volatile",
+            label: "High volatility",
+            description: "Fast movers with wide zones",
+            pairs: &["SOLUSDT", "DOGEUSDT"],
+        },
+        DemoDataset {
+            id: "low-vol",
+            label: "Gold & stables",
+            description: "Tight ranges, small PH",
+            pairs: &["PAXGUSDT", "USDCUSDT"],
+        },
+    ],
+    saved_import_id: "saved",
 };
@@ -9,5 +9,8 @@ pub(crate) use debug::DF;
 
 pub use {
     demo::DEMO,
-    persistence::{PERSISTENCE, kline_cache_filename},
+    persistence::{
+        DEFAULT_PROFILE, PERSISTENCE, active_profile, data_path, init_data_dir,
+        kline_cache_filename, resolve_data_dir, set_active_profile,
+    },
 };
@@ -1,14 +1,28 @@
-use crate::utils::TimeUtils;
+use {
+    crate::utils::TimeUtils,
+    std::{
+        io,
+        path::{Path, PathBuf},
+        sync::{LazyLock, OnceLock, RwLock},
+    },
+};
 
 pub struct KlinePersistenceConfig {
     pub directory: &'static str,
     pub filename_base: &'static str,
     pub version: f64,
+    pub db_path: &'static str,
 }
 
 pub struct AppPersistenceConfig {
     pub state_path: &'static str,
     pub ledger_path: &'static str,
+    pub export_directory: &'static str,
+    pub results_db_path: &'static str,
+    pub profile_directory: &'static str,
+    pub profile_registry_path: &'static str,
+    pub lock_path: &'static str,
+    pub instance_info_path: &'static str,
 }
 
 pub struct PersistenceConfig {
@@ -20,14 +34,117 @@ pub const PERSISTENCE: PersistenceConfig = PersistenceConfig {
     kline: KlinePersistenceConfig {
         directory: "kline_data",
         filename_base: "kd",
-        version: 4.0,
+        version: 5.0,
+        db_path: "klines.sqlite",
     },
     app: AppPersistenceConfig {
         state_path: ".states.json",
         ledger_path: ".ledger.bin",
+        export_directory: "exports",
+        results_db_path: "results.sqlite",
+        profile_directory: "profiles",
+        profile_registry_path: "profiles.json",
+        lock_path: ".instance.lock",
+        instance_info_path: ".instance.info",
     },
 };
 
+/// Root every relative path in [`PERSISTENCE`] resolves against. Unset means the working
+/// directory, which is where state has always landed.
+static DATA_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Picks the data root from the CLI: an explicit `--data-dir` wins, `--portable` uses the
+/// executable's directory, otherwise None (working directory).
+pub fn resolve_data_dir(
+    explicit: Option<&Path>,
+    portable: bool,
+    exe: Option<&Path>,
+) -> Option<PathBuf> {
+    if let Some(dir) = explicit {
+        return Some(dir.to_path_buf());
+    }
+    if !portable {
+        return None;
+    }
+    exe.and_then(Path::parent).map(Path::to_path_buf)
+}
+
+/// Creates the data root and makes it the base for all persisted state. Call once at startup,
+/// before anything reads or writes a persistence path.
+pub fn init_data_dir(dir: PathBuf) -> io::Result<()> {
+    std::fs::create_dir_all(&dir)?;
+    if DATA_DIR.set(dir).is_err() {
+        log::warn!("Data directory already set; ignoring");
+    }
+    Ok(())
+}
+
+/// `relative` under the data root (unchanged if absolute or no root was set).
+pub fn data_path(relative: impl AsRef<Path>) -> PathBuf {
+    match DATA_DIR.get() {
+        Some(dir) => dir.join(relative),
+        None => relative.as_ref().to_path_buf(),
+    }
+}
+
+/// Profile whose files live directly in the data root, where state landed before profiles.
+pub const DEFAULT_PROFILE: &str = "default";
+
+static ACTIVE_PROFILE: LazyLock<RwLock<String>> =
+    LazyLock::new(|| RwLock::new(DEFAULT_PROFILE.to_string()));
+
+/// Profile that per-profile paths (ledger) resolve against. Switch only between saving the old
+/// profile's state and loading the new one's.
+pub fn set_active_profile(name: &str) {
+    *ACTIVE_PROFILE.write().unwrap() = name.to_string();
+}
+
+pub fn active_profile() -> String {
+    ACTIVE_PROFILE.read().unwrap().clone()
+}
+
+/// `relative` inside the active profile's directory (the data root for [`DEFAULT_PROFILE`]).
+pub fn profile_path(relative: impl AsRef<Path>) -> PathBuf {
+    let profile = active_profile();
+    if profile == DEFAULT_PROFILE {
+        return data_path(relative);
+    }
+    data_path(PERSISTENCE.app.profile_directory)
+        .join(profile)
+        .join(relative)
+}
+
+impl PersistenceConfig {
+    pub fn kline_dir(&self) -> PathBuf {
+        data_path(self.kline.directory)
+    }
+
+    pub fn kline_db(&self) -> PathBuf {
+        data_path(self.kline.db_path)
+    }
+
+    pub fn state_file(&self) -> PathBuf {
+        data_path(self.app.state_path)
+    }
+
+    pub fn ledger_file(&self) -> PathBuf {
+        profile_path(self.app.ledger_path)
+    }
+
+    pub fn profile_registry(&self) -> PathBuf {
+        data_path(self.app.profile_registry_path)
+    }
+
+    pub fn export_dir(&self) -> PathBuf {
+        data_path(self.app.export_directory)
+    }
+
+    /// Per profile, so one profile's live trades never mix into another's analysis.
+    pub fn results_db(&self) -> PathBuf {
+        profile_path(self.app.results_db_path)
+    }
+}
+
 pub fn kline_cache_filename(interval_ms: i64) -> String {
     let interval_str = TimeUtils::interval_to_string(interval_ms);
     format!(
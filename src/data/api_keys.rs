@@ -0,0 +1,214 @@
+use {
+    crate::data::BinanceApiConfig,
+    anyhow::{Context, Result, anyhow},
+    binance_sdk::{
+        config::ConfigurationRestApi,
+        spot::{
+            SpotRestApi,
+            rest_api::{GetAccountParams, RestApi},
+        },
+    },
+    serde::{Deserialize, Serialize},
+    std::{
+        fmt,
+        sync::{LazyLock, RwLock},
+    },
+};
+
+/// OS keychain entry holding the Binance key pair (one JSON blob, so both halves move together).
+const KEYRING_SERVICE: &str = "zone-sniper";
+const KEYRING_USER: &str = "binance-api";
+/// Keychain user for the signal webhook's shared secret (same service as the key pair).
+pub(crate) const WEBHOOK_TOKEN_USER: &str = "signal-webhook";
+/// Keychain user for the instance status server's secret, shared by every process of this user.
+pub(crate) const STATUS_TOKEN_USER: &str = "status-server";
+/// Environment overrides, checked before the keychain (handy for headless runs).
+const ENV_API_KEY: &str = "BINANCE_API_KEY";
+const ENV_API_SECRET: &str = "BINANCE_API_SECRET";
+
+/// Credentials REST clients are signed with, read once (outer None = not read yet) and again
+/// after a save / clear, so building a client doesn't touch the keychain.
+static ACTIVE_CREDENTIALS: LazyLock<RwLock<Option<Option<ApiCredentials>>>> =
+    LazyLock::new(|| RwLock::new(None));
+
+/// Binance API key pair. Only ever lives in the OS keychain (or the environment); never in
+/// app settings, logs or diagnostics bundles.
+#[derive(Clone, Serialize, Deserialize)]
+pub(crate) struct ApiCredentials {
+    pub api_key: String,
+    pub api_secret: String,
+}
+
+impl fmt::Debug for ApiCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ApiCredentials")
+            .field("api_key", &mask(&self.api_key))
+            .field("api_secret", &"***")
+            .finish()
+    }
+}
+
+impl ApiCredentials {
+    pub(crate) fn masked_key(&self) -> String {
+        mask(&self.api_key)
+    }
+}
+
+fn mask(key: &str) -> String {
+    let tail: String = key
+        .chars()
+        .rev()
+        .take(4)
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .collect();
+    format!("****{}", tail)
+}
+
+fn keyring_entry() -> Result<keyring::Entry> {
+    keyring::Entry::new(KEYRING_SERVICE, KEYRING_USER).context("Failed to open OS keychain")
+}
+
+/// Environment first, then the keychain. `Ok(None)` means public-only access.
+pub(crate) fn load_api_credentials() -> Result<Option<ApiCredentials>> {
+    let env = |name| std::env::var(name).ok().filter(|v: &String| !v.is_empty());
+    if let (Some(api_key), Some(api_secret)) = (env(ENV_API_KEY), env(ENV_API_SECRET)) {
+        return Ok(Some(ApiCredentials {
+            api_key,
+            api_secret,
+        }));
+    }
+    match keyring_entry()?.get_password() {
+        Ok(json) => serde_json::from_str(&json)
+            .map(Some)
+            .context("Stored API credentials are unreadable"),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(anyhow!(e).context("Failed to read API credentials from OS keychain")),
+    }
+}
+
+/// What REST calls are signed with: [`load_api_credentials`] on first use, then cached.
+fn active_api_credentials() -> Option<ApiCredentials> {
+    if let Some(active) = ACTIVE_CREDENTIALS.read().unwrap().as_ref() {
+        return active.clone();
+    }
+    let loaded = load_api_credentials().unwrap_or_else(|e| {
+        log::warn!("Using public Binance access: {:#}", e);
+        None
+    });
+    *ACTIVE_CREDENTIALS.write().unwrap() = Some(loaded.clone());
+    loaded
+}
+
+pub(crate) fn save_api_credentials(credentials: &ApiCredentials) -> Result<()> {
+    if credentials.api_key.trim().is_empty() || credentials.api_secret.trim().is_empty() {
+        return Err(anyhow!("API key and secret are both required"));
+    }
+    let json = serde_json::to_string(credentials)?;
+    keyring_entry()?
+        .set_password(&json)
+        .context("Failed to store API credentials in OS keychain")?;
+    *ACTIVE_CREDENTIALS.write().unwrap() = None;
+    Ok(())
+}
+
+pub(crate) fn clear_api_credentials() -> Result<()> {
+    match keyring_entry()?.delete_credential() {
+        Ok(()) | Err(keyring::Error::NoEntry) => {
+            *ACTIVE_CREDENTIALS.write().unwrap() = None;
+            Ok(())
+        }
+        Err(e) => Err(anyhow!(e).context("Failed to remove API credentials from OS keychain")),
+    }
+}
+
+fn token_entry(user: &str) -> Result<keyring::Entry> {
+    keyring::Entry::new(KEYRING_SERVICE, user).context("Failed to open OS keychain")
+}
+
+/// Random 32-hex-digit token for a local endpoint.
+pub(crate) fn new_local_token() -> Result<String> {
+    let mut bytes = [0u8; 16];
+    getrandom::fill(&mut bytes).map_err(|e| anyhow!("No randomness for a token: {}", e))?;
+    Ok(bytes.iter().map(|b| format!("{:02x}", b)).collect())
+}
+
+/// Stores `token` as the keychain secret for `user`.
+pub(crate) fn save_local_token(user: &str, token: &str) -> Result<()> {
+    token_entry(user)?
+        .set_password(token)
+        .context("Failed to store token in OS keychain")
+}
+
+/// The keychain secret for `user`, generated and stored on first use.
+pub(crate) fn load_local_token(user: &str) -> Result<String> {
+    match token_entry(user)?.get_password() {
+        Ok(token) if !token.is_empty() => Ok(token),
+        Ok(_) | Err(keyring::Error::NoEntry) => {
+            let token = new_local_token()?;
+            save_local_token(user, &token)?;
+            Ok(token)
+        }
+        Err(e) => Err(anyhow!(e).context("Failed to read token from OS keychain")),
+    }
+}
+
+/// Spot REST client, signed with the stored key pair when there is one.
+pub(crate) fn spot_rest_client() -> Result<RestApi> {
+    let config = BinanceApiConfig::default();
+    let mut builder = ConfigurationRestApi::builder()
+        .timeout(config.timeout_ms)
+        .retries(config.retries)
+        .backoff(config.backoff_ms);
+    if let Some(credentials) = active_api_credentials() {
+        builder = builder
+            .api_key(credentials.api_key)
+            .api_secret(credentials.api_secret);
+    }
+    Ok(SpotRestApi::production(builder.build()?))
+}
+
+/// True when REST calls will be signed.
+pub(crate) fn has_api_credentials() -> bool {
+    active_api_credentials().is_some()
+}
+
+/// Result of a signed account call, for checking a key pair before relying on it.
+#[derive(Debug, Clone)]
+pub(crate) struct AccountCheck {
+    pub can_trade: bool,
+    /// Assets with a non-zero free or locked balance
+    pub funded_assets: usize,
+}
+
+/// Calls the signed account endpoint (user-data access the trading connector will need).
+pub(crate) async fn check_api_credentials() -> Result<AccountCheck> {
+    if !has_api_credentials() {
+        return Err(anyhow!("No API key configured"));
+    }
+    let client = spot_rest_client()?;
+    let account = client
+        .get_account(GetAccountParams::default())
+        .await
+        .context("Account request failed")?
+        .data()
+        .await?;
+    let funded_assets = account
+        .balances
+        .unwrap_or_default()
+        .iter()
+        .filter(|b| {
+            let amount = |s: &Option<String>| {
+                s.as_deref()
+                    .and_then(|v| v.parse::<f64>().ok())
+                    .unwrap_or(0.0)
+            };
+            amount(&b.free) > 0.0 || amount(&b.locked) > 0.0
+        })
+        .count();
+    Ok(AccountCheck {
+        can_trade: account.can_trade.unwrap_or(false),
+        funded_assets,
+    })
+}
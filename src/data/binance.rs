@@ -20,6 +20,9 @@ impl Default for BinanceApiConfig {
 pub struct RestLimits {
     pub klines_limit: i32,
     pub weight_limit_minute: u32,
+    /// Share of `weight_limit_minute` we spend. The limit is counted per IP, so an API key
+    /// does not change it.
+    pub public_budget_fraction: f32,
     pub kline_call_weight: u32,
     pub concurrent_sync_tasks: usize,
 }
@@ -30,6 +33,13 @@ pub struct WsConfig {
     pub initial_reconnect_delay_sec: u64,
 }
 
+/// How often local time is compared with exchange time, and how far apart they may drift
+/// before the status bar warns.
+pub struct ClockCheckConfig {
+    pub check_interval_sec: u64,
+    pub warn_skew_ms: i64,
+}
+
 pub struct ClientDefaults {
     pub timeout_ms: u64,
     pub retries: u32,
@@ -39,6 +49,7 @@ pub struct ClientDefaults {
 pub struct BinanceConfig {
     pub limits: RestLimits,
     pub ws: WsConfig,
+    pub clock: ClockCheckConfig,
     pub client: ClientDefaults,
 }
 
@@ -46,6 +57,7 @@ pub(crate) const BINANCE_API: BinanceConfig = BinanceConfig {
     limits: RestLimits {
         klines_limit: 1000,
         weight_limit_minute: 6000,
+        public_budget_fraction: 0.8,
         kline_call_weight: 2,
         concurrent_sync_tasks: 10,
     },
@@ -54,6 +66,10 @@ pub(crate) const BINANCE_API: BinanceConfig = BinanceConfig {
         max_reconnect_delay_sec: 300, // 5 minutes
         initial_reconnect_delay_sec: 1,
     },
+    clock: ClockCheckConfig {
+        check_interval_sec: 600,
+        warn_skew_ms: 1000,
+    },
     client: ClientDefaults {
         timeout_ms: 5000,
         retries: 5,
@@ -0,0 +1,153 @@
+use {
+    crate::{
+        app::PriceLike,
+        config::PERSISTENCE,
+        data::{
+            BinanceProvider, GlobalRateLimiter, KlineSeries, MarketDataStorage, SqliteStorage,
+            append_new_candles, db_bytes, export_csv,
+        },
+        utils::TimeUtils,
+    },
+    anyhow::{Result, anyhow},
+    std::{
+        fs,
+        path::PathBuf,
+        time::{SystemTime, UNIX_EPOCH},
+    },
+};
+
+const KLINE_CSV_HEADER: &str = "open_time,open,high,low,close,base_vol,quote_vol,taker_buy_vol";
+
+/// A file in the kline cache directory.
+#[derive(Debug, Clone)]
+pub(crate) struct CachedFile {
+    pub name: String,
+    pub bytes: u64,
+    pub modified_ms: Option<i64>,
+}
+
+/// What the kline database and cache directory hold, for the cache inspector.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CacheInventory {
+    pub series: Vec<KlineSeries>,
+    pub files: Vec<CachedFile>,
+    /// Database plus WAL and shared-memory files
+    pub db_bytes: u64,
+}
+
+impl CacheInventory {
+    pub(crate) fn total_candles(&self) -> u64 {
+        self.series.iter().map(|s| s.candles).sum()
+    }
+
+    /// SQLite doesn't report per-table-range sizes; rows are near fixed-width, so a series
+    /// gets the database's size in proportion to its candle count.
+    pub(crate) fn approx_bytes(&self, series: &KlineSeries) -> u64 {
+        share_of_bytes(self.db_bytes, series.candles, self.total_candles())
+    }
+}
+
+pub(crate) fn share_of_bytes(total_bytes: u64, rows: u64, total_rows: u64) -> u64 {
+    if total_rows == 0 {
+        return 0;
+    }
+    (total_bytes as u128 * rows.min(total_rows) as u128 / total_rows as u128) as u64
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CacheAction {
+    /// Fetch candles newer than the stored ones
+    Refresh,
+    Delete,
+    /// Write the stored candles to a CSV in the export directory
+    Export,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum CacheActionOutcome {
+    Refreshed(usize),
+    Deleted(u64),
+    Exported(PathBuf),
+}
+
+pub(crate) async fn inspect_kline_cache() -> Result<CacheInventory> {
+    let db_path = PERSISTENCE.kline_db();
+    let storage = SqliteStorage::new(&db_path.to_string_lossy()).await?;
+    storage.initialize().await?;
+    let series = storage.list_series().await?;
+    storage.close().await;
+
+    let mut files: Vec<CachedFile> = fs::read_dir(PERSISTENCE.kline_dir())
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|entry| {
+                    let meta = entry.metadata().ok().filter(|m| m.is_file())?;
+                    Some(CachedFile {
+                        name: entry.file_name().to_string_lossy().into_owned(),
+                        bytes: meta.len(),
+                        modified_ms: meta.modified().ok().and_then(system_time_ms),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    files.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(CacheInventory {
+        series,
+        files,
+        db_bytes: db_bytes(&db_path),
+    })
+}
+
+/// Runs a row action of the cache inspector against the kline database. The loaded series
+/// keep what they had until the next start. A refresh spends weight from `limiter`.
+pub(crate) async fn apply_cache_action(
+    action: CacheAction,
+    pair: &str,
+    interval: &str,
+    limiter: &GlobalRateLimiter,
+) -> Result<CacheActionOutcome> {
+    let storage = SqliteStorage::new(&PERSISTENCE.kline_db().to_string_lossy()).await?;
+    let outcome = match action {
+        CacheAction::Refresh => {
+            let interval_ms = TimeUtils::string_to_interval(interval)
+                .ok_or_else(|| anyhow!("Unknown interval {}", interval))?;
+            let provider = BinanceProvider::new(limiter.clone());
+            let count = append_new_candles(&storage, &provider, pair, interval_ms).await?;
+            CacheActionOutcome::Refreshed(count)
+        }
+        CacheAction::Delete => {
+            CacheActionOutcome::Deleted(storage.delete_series(pair, interval).await?)
+        }
+        CacheAction::Export => {
+            let candles = storage.load_candles(pair, interval, None).await?;
+            let mut csv = String::from(KLINE_CSV_HEADER);
+            csv.push('\n');
+            for c in &candles {
+                csv.push_str(&format!(
+                    "{},{},{},{},{},{},{},{}\n",
+                    c.timestamp_ms.value(),
+                    c.open_price.value(),
+                    c.high_price.value(),
+                    c.low_price.value(),
+                    c.close_price.value(),
+                    c.base_asset_volume.value(),
+                    c.quote_asset_volume.value(),
+                    c.taker_buy_base_volume.value(),
+                ));
+            }
+            let path = export_csv(&format!("klines_{}_{}", pair, interval), &csv)?;
+            CacheActionOutcome::Exported(path)
+        }
+    };
+    storage.close().await;
+    Ok(outcome)
+}
+
+fn system_time_ms(time: SystemTime) -> Option<i64> {
+    time.duration_since(UNIX_EPOCH)
+        .ok()
+        .map(|d| d.as_millis() as i64)
+}
@@ -0,0 +1,56 @@
+use {
+    crate::{
+        data::{BINANCE_API, spot_rest_client},
+        utils::TimeUtils,
+    },
+    anyhow::{Result, anyhow},
+    binance_sdk::spot::rest_api::RestApi,
+    chrono::Utc,
+    std::time::Duration,
+    tokio::time::sleep,
+};
+
+#[cfg(debug_assertions)]
+use crate::config::DF;
+
+/// Re-measures the offset to Binance server time every `check_interval_sec`, for the life
+/// of the runtime. A failed check keeps the previous offset.
+pub(crate) async fn watch_clock_skew() {
+    let client = match spot_rest_client() {
+        Ok(client) => client,
+        Err(e) => {
+            log::warn!("Clock skew check disabled: {:#}", e);
+            return;
+        }
+    };
+    loop {
+        match measure_offset_ms(&client).await {
+            Ok(offset_ms) => {
+                TimeUtils::set_clock_offset_ms(offset_ms);
+                if offset_ms.abs() > BINANCE_API.clock.warn_skew_ms {
+                    log::warn!("Local clock is {}ms off exchange time", -offset_ms);
+                }
+                #[cfg(debug_assertions)]
+                if DF.log_price_stream_updates {
+                    log::info!("Clock offset to exchange: {}ms", offset_ms);
+                }
+            }
+            Err(e) => log::warn!("Clock skew check failed: {:#}", e),
+        }
+        sleep(Duration::from_secs(BINANCE_API.clock.check_interval_sec)).await;
+    }
+}
+
+/// Exchange time minus local time. Assumes the server stamped its reply halfway through
+/// the round trip.
+async fn measure_offset_ms(client: &RestApi) -> Result<i64> {
+    let sent = Utc::now().timestamp_millis();
+    let response = client.time().await?;
+    let received = Utc::now().timestamp_millis();
+    let server_time = response
+        .data()
+        .await?
+        .server_time
+        .ok_or_else(|| anyhow!("Server time missing from response"))?;
+    Ok(server_time - (sent + received) / 2)
+}
@@ -0,0 +1,259 @@
+use {
+    crate::{
+        app::{PriceLike, TimestampMs},
+        data::{load_kline_at, shared_rate_limiter, spot_rest_client},
+        domain::{Candle, PairInterval},
+    },
+    futures::stream::{self, StreamExt},
+};
+
+pub(crate) struct CrossCheckConfig {
+    pub samples_per_pair: usize,
+    /// Relative difference allowed on prices (Binance prices are exact decimals)
+    pub price_tolerance: f64,
+    /// Relative difference allowed on volumes
+    pub volume_tolerance: f64,
+    /// Parallel REST requests
+    pub concurrency: usize,
+}
+
+pub(crate) const CROSS_CHECK: CrossCheckConfig = CrossCheckConfig {
+    samples_per_pair: 8,
+    price_tolerance: 1e-9,
+    volume_tolerance: 1e-6,
+    concurrency: 4,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CandleField {
+    Open,
+    High,
+    Low,
+    Close,
+    Volume,
+    QuoteVolume,
+    TakerBuyVolume,
+    /// The reference source has no candle at this open time
+    Missing,
+}
+
+impl CandleField {
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            Self::Open => "open",
+            Self::High => "high",
+            Self::Low => "low",
+            Self::Close => "close",
+            Self::Volume => "volume",
+            Self::QuoteVolume => "quote volume",
+            Self::TakerBuyVolume => "taker buy volume",
+            Self::Missing => "missing",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct CandleMismatch {
+    pub pair: String,
+    pub open_time: TimestampMs,
+    pub field: CandleField,
+    pub cached: f64,
+    pub reference: f64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CrossCheckReport {
+    pub ran_at: TimestampMs,
+    pub pairs_checked: usize,
+    pub candles_checked: usize,
+    pub mismatches: Vec<CandleMismatch>,
+    /// (pair, error) for samples that could not be fetched
+    pub errors: Vec<(String, String)>,
+}
+
+impl CrossCheckReport {
+    /// Pairs with at least one mismatching candle.
+    pub(crate) fn suspect_pairs(&self) -> Vec<&str> {
+        let mut pairs: Vec<&str> = self.mismatches.iter().map(|m| m.pair.as_str()).collect();
+        pairs.sort_unstable();
+        pairs.dedup();
+        pairs
+    }
+}
+
+/// Up to `n` indices below `len`: one random pick per equal-width stratum, so samples cover
+/// the whole history rather than clumping.
+pub(crate) fn sample_indices(len: usize, n: usize, seed: u64) -> Vec<usize> {
+    if len == 0 || n == 0 {
+        return Vec::new();
+    }
+    let n = n.min(len);
+    let width = len as f64 / n as f64;
+    (0..n)
+        .map(|i| {
+            let mut z = seed ^ (i as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+            z = (z ^ (z >> 31)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z ^= z >> 29;
+            let offset = (z % 1_000_000) as f64 / 1_000_000.0;
+            ((i as f64 + offset) * width) as usize
+        })
+        .map(|idx| idx.min(len - 1))
+        .collect()
+}
+
+/// Field-by-field differences beyond the configured tolerances.
+pub(crate) fn compare_candles(
+    pair: &str,
+    cached: &Candle,
+    reference: &Candle,
+) -> Vec<CandleMismatch> {
+    let differs = |a: f64, b: f64, tolerance: f64| {
+        (a - b).abs() > tolerance * a.abs().max(b.abs()).max(f64::EPSILON)
+    };
+    [
+        (
+            CandleField::Open,
+            cached.open_price.value(),
+            reference.open_price.value(),
+            CROSS_CHECK.price_tolerance,
+        ),
+        (
+            CandleField::High,
+            cached.high_price.value(),
+            reference.high_price.value(),
+            CROSS_CHECK.price_tolerance,
+        ),
+        (
+            CandleField::Low,
+            cached.low_price.value(),
+            reference.low_price.value(),
+            CROSS_CHECK.price_tolerance,
+        ),
+        (
+            CandleField::Close,
+            cached.close_price.value(),
+            reference.close_price.value(),
+            CROSS_CHECK.price_tolerance,
+        ),
+        (
+            CandleField::Volume,
+            cached.base_asset_volume.value(),
+            reference.base_asset_volume.value(),
+            CROSS_CHECK.volume_tolerance,
+        ),
+        (
+            CandleField::QuoteVolume,
+            cached.quote_asset_volume.value(),
+            reference.quote_asset_volume.value(),
+            CROSS_CHECK.volume_tolerance,
+        ),
+        (
+            CandleField::TakerBuyVolume,
+            cached.taker_buy_base_volume.value(),
+            reference.taker_buy_base_volume.value(),
+            CROSS_CHECK.volume_tolerance,
+        ),
+    ]
+    .into_iter()
+    .filter(|(_, a, b, tolerance)| differs(*a, *b, *tolerance))
+    .map(|(field, cached_value, reference, _)| CandleMismatch {
+        pair: pair.to_string(),
+        open_time: cached.timestamp_ms,
+        field,
+        cached: cached_value,
+        reference,
+    })
+    .collect()
+}
+
+/// Re-fetches each sampled candle from Binance REST and compares it with the cached copy.
+/// `samples` are (pair, interval_ms, cached candles to check).
+pub(crate) async fn cross_check_candles(
+    samples: Vec<(String, i64, Vec<Candle>)>,
+) -> CrossCheckReport {
+    let limiter = shared_rate_limiter();
+    let mut report = CrossCheckReport {
+        ran_at: TimestampMs::now(),
+        pairs_checked: samples.len(),
+        ..Default::default()
+    };
+    let rest_client = match spot_rest_client() {
+        Ok(client) => client,
+        Err(e) => {
+            let error = format!("{:#}", e);
+            report.errors = samples
+                .into_iter()
+                .map(|(name, _, _)| (name, error.clone()))
+                .collect();
+            return report;
+        }
+    };
+    let rest_client = &rest_client;
+    let jobs: Vec<(PairInterval, Candle)> = samples
+        .into_iter()
+        .flat_map(|(name, interval_ms, candles)| {
+            candles.into_iter().map(move |candle| {
+                (
+                    PairInterval {
+                        name: name.clone(),
+                        interval_ms,
+                    },
+                    candle,
+                )
+            })
+        })
+        .collect();
+    let results = stream::iter(jobs)
+        .map(|(pair_interval, cached)| {
+            let limiter = limiter.clone();
+            async move {
+                let reference = load_kline_at(
+                    rest_client,
+                    &pair_interval,
+                    cached.timestamp_ms.value(),
+                    &limiter,
+                )
+                .await;
+                (pair_interval.name, cached, reference)
+            }
+        })
+        .buffer_unordered(CROSS_CHECK.concurrency)
+        .collect::<Vec<_>>()
+        .await;
+
+    for (pair, cached, reference) in results {
+        match reference {
+            Ok(Some(reference)) => {
+                report.candles_checked += 1;
+                report
+                    .mismatches
+                    .extend(compare_candles(&pair, &cached, &reference));
+            }
+            Ok(None) => {
+                report.candles_checked += 1;
+                report.mismatches.push(CandleMismatch {
+                    pair,
+                    open_time: cached.timestamp_ms,
+                    field: CandleField::Missing,
+                    cached: cached.close_price.value(),
+                    reference: 0.0,
+                });
+            }
+            Err(e) => report.errors.push((pair, format!("{:#}", e))),
+        }
+    }
+    report
+        .mismatches
+        .sort_by(|a, b| a.pair.cmp(&b.pair).then(a.open_time.cmp(&b.open_time)));
+    for m in &report.mismatches {
+        log::warn!(
+            "Candle cross-check: {} {} {} cached {} vs Binance {}",
+            m.pair,
+            m.open_time.to_datetime().format("%Y-%m-%d %H:%M"),
+            m.field.name(),
+            m.cached,
+            m.reference
+        );
+    }
+    report
+}
@@ -0,0 +1,218 @@
+use {
+    crate::{
+        DemoArgs,
+        app::{BASE_INTERVAL, PriceLike},
+        config::{DEMO, PERSISTENCE, data_path, kline_cache_filename},
+        data::{
+            CacheFile, MarketDataStorage, PriceStreamManager, SqliteStorage, TimeSeriesCollection,
+            downsample_candles,
+        },
+        domain::PairInterval,
+        models::OhlcvTimeSeries,
+        utils::TimeUtils,
+    },
+    anyhow::{Context, Result, anyhow},
+    chrono::NaiveDate,
+    serde_json::Value,
+    std::{
+        collections::HashMap,
+        path::{Path, PathBuf},
+        thread,
+        time::{Duration, Instant},
+    },
+};
+
+/// What [`build_demo_cache`] wrote.
+#[derive(Debug, Clone)]
+pub struct DemoBuildSummary {
+    pub cache_path: PathBuf,
+    pub prices_path: PathBuf,
+    /// (pair, candles written)
+    pub pairs: Vec<(String, usize)>,
+    pub skipped: Vec<String>,
+}
+
+impl DemoBuildSummary {
+    /// Per-pair results and output paths, as printed by `--make-demo` and `make_demo_cache`.
+    pub fn print(&self) {
+        for (pair, candles) in &self.pairs {
+            println!("  ✅ {:<12} {:>7} candles", pair, candles);
+        }
+        for pair in &self.skipped {
+            println!("  ⚠ {:<12} no data in range", pair);
+        }
+        println!("Demo cache:  {}", self.cache_path.display());
+        println!("Demo prices: {}", self.prices_path.display());
+    }
+}
+
+/// Builds the WASM demo cache and `demo_prices.json` from the local kline database.
+pub async fn build_demo_cache(args: &DemoArgs) -> Result<DemoBuildSummary> {
+    let source_ms = match &args.demo_interval {
+        Some(s) => parse_interval(s)?,
+        None => BASE_INTERVAL.as_millis() as i64,
+    };
+    let output_ms = match &args.demo_downsample {
+        Some(s) => parse_interval(s)?,
+        None => source_ms,
+    };
+    let start_ms = args.demo_from.as_deref().map(parse_date_ms).transpose()?;
+    // `--demo-to` is inclusive of the whole day
+    let end_ms = args
+        .demo_to
+        .as_deref()
+        .map(parse_date_ms)
+        .transpose()?
+        .map(|ms| ms + 86_400_000 - 1);
+    if let (Some(start), Some(end)) = (start_ms, end_ms) {
+        if start > end {
+            return Err(anyhow!("--demo-from is after --demo-to"));
+        }
+    }
+    let pairs: Vec<String> = if args.demo_pairs.is_empty() {
+        DEMO.resources.pairs.iter().map(|p| p.to_string()).collect()
+    } else {
+        args.demo_pairs.iter().map(|p| p.to_uppercase()).collect()
+    };
+    let out_dir = args
+        .demo_out
+        .clone()
+        .unwrap_or_else(|| PERSISTENCE.kline_dir());
+    let source_str = TimeUtils::interval_to_string(source_ms);
+
+    let db_path = data_path(&args.demo_db);
+    log::info!(
+        "Building demo cache from {} ({} -> {}, {} pairs)",
+        db_path.display(),
+        source_str,
+        TimeUtils::interval_to_string(output_ms),
+        pairs.len()
+    );
+    let storage = SqliteStorage::new(&db_path.to_string_lossy())
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to open {}. Run the native app first to populate data!",
+                db_path.display()
+            )
+        })?;
+
+    let mut series_list = Vec::new();
+    let mut summary_pairs = Vec::new();
+    let mut skipped = Vec::new();
+    let mut last_closes = HashMap::new();
+    for pair in &pairs {
+        let mut candles: Vec<_> = storage
+            .load_candles(pair, source_str, start_ms)
+            .await?
+            .into_iter()
+            .filter(|c| end_ms.is_none_or(|end| c.timestamp_ms.value() <= end))
+            .collect();
+        if output_ms != source_ms {
+            candles = downsample_candles(&candles, source_ms, output_ms)
+                .with_context(|| format!("Downsampling {}", pair))?
+                .0;
+        }
+        if candles.is_empty() {
+            log::warn!("No {} data for {} in range. Skipping.", source_str, pair);
+            skipped.push(pair.clone());
+            continue;
+        }
+        if candles.len() > args.demo_max_candles {
+            let start = candles.len() - args.demo_max_candles;
+            candles.drain(..start);
+            log::info!("{}: kept the last {} candles", pair, args.demo_max_candles);
+        }
+        if let Some(last) = candles.last() {
+            last_closes.insert(pair.clone(), last.close_price.value());
+        }
+        summary_pairs.push((pair.clone(), candles.len()));
+        let pair_interval = PairInterval {
+            name: pair.clone(),
+            interval_ms: output_ms,
+        };
+        series_list.push(OhlcvTimeSeries::from_candles(pair_interval, candles));
+    }
+    if series_list.is_empty() {
+        return Err(anyhow!("No data extracted for any pair"));
+    }
+
+    let collection = TimeSeriesCollection {
+        name: "WASM Demo Collection".to_string(),
+        version: 1.0,
+        series_data: series_list,
+    };
+    let cache_path = out_dir.join(format!("demo_{}", kline_cache_filename(output_ms)));
+    CacheFile::new(output_ms, collection, PERSISTENCE.kline.version).save_to_path(&cache_path)?;
+
+    let prices = if args.demo_last_close_prices {
+        last_closes
+    } else {
+        let symbols: Vec<String> = summary_pairs.iter().map(|(p, _)| p.clone()).collect();
+        fetch_live_prices(&symbols)?
+    };
+    let prices_path = out_dir.join(crate::demo_prices_file!());
+    write_demo_prices_json(&prices_path, &prices)?;
+
+    Ok(DemoBuildSummary {
+        cache_path,
+        prices_path,
+        pairs: summary_pairs,
+        skipped,
+    })
+}
+
+fn parse_interval(s: &str) -> Result<i64> {
+    TimeUtils::string_to_interval(s).ok_or_else(|| anyhow!("Unknown interval '{}'", s))
+}
+
+fn parse_date_ms(s: &str) -> Result<i64> {
+    let date = NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .with_context(|| format!("Expected a YYYY-MM-DD date, got '{}'", s))?;
+    Ok(date
+        .and_hms_opt(0, 0, 0)
+        .map(|dt| dt.and_utc().timestamp_millis())
+        .unwrap_or_default())
+}
+
+fn fetch_live_prices(symbols: &[String]) -> Result<HashMap<String, f64>> {
+    const TIMEOUT: Duration = Duration::from_secs(15);
+    const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+    let stream = PriceStreamManager::new();
+    stream.subscribe_all(symbols.to_vec());
+    let start = Instant::now();
+    loop {
+        let prices: HashMap<String, f64> = symbols
+            .iter()
+            .filter_map(|s| stream.get_price(s).map(|p| (s.clone(), p.value())))
+            .collect();
+        if prices.len() == symbols.len() {
+            return Ok(prices);
+        }
+        if start.elapsed() >= TIMEOUT {
+            return Err(anyhow!(
+                "Timed out after {:?} waiting for live prices (got {}/{}). \
+                 Use --demo-last-close-prices to work offline.",
+                TIMEOUT,
+                prices.len(),
+                symbols.len()
+            ));
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn write_demo_prices_json(path: &Path, prices: &HashMap<String, f64>) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    let json_map: HashMap<String, Value> = prices
+        .iter()
+        .map(|(pair, price)| (pair.to_uppercase(), Value::from(*price)))
+        .collect();
+    let json = serde_json::to_string_pretty(&json_map)
+        .context("Failed to serialize demo prices to JSON")?;
+    std::fs::write(path, json).with_context(|| format!("Failed to write {}", path.display()))
+}
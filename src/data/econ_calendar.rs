@@ -0,0 +1,131 @@
+#[cfg(all(feature = "econ_calendar", not(target_arch = "wasm32")))]
+use {
+    crate::config::data_path,
+    anyhow::{Context, Result},
+    chrono::DateTime,
+    serde::Deserialize,
+    std::time::Duration,
+};
+
+/// Scheduled macro / crypto event shown on the plot time axis. Purely informational.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct EconEvent {
+    pub time_ms: i64,
+    pub title: String,
+    pub impact: EventImpact,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum EventImpact {
+    Medium,
+    High,
+}
+
+/// Events falling inside `[start_ms, end_ms]`, in time order (input is kept sorted).
+pub(crate) fn events_in_window(events: &[EconEvent], start_ms: i64, end_ms: i64) -> &[EconEvent] {
+    let from = events.partition_point(|e| e.time_ms < start_ms);
+    let to = events.partition_point(|e| e.time_ms <= end_ms);
+    &events[from..to.max(from)]
+}
+
+#[cfg(all(feature = "econ_calendar", not(target_arch = "wasm32")))]
+pub(crate) struct EconCalendarConfig {
+    /// Weekly macro calendar (JSON list of title / country / date / impact)
+    pub feed_url: &'static str,
+    /// Only events for these countries are kept from the feed
+    pub countries: &'static [&'static str],
+    pub min_impact: EventImpact,
+    /// Optional hand-maintained list (token unlocks etc.), same shape as the feed
+    pub local_file: &'static str,
+    pub timeout_ms: u64,
+}
+
+#[cfg(all(feature = "econ_calendar", not(target_arch = "wasm32")))]
+pub(crate) const ECON_CALENDAR: EconCalendarConfig = EconCalendarConfig {
+    feed_url: "https://nfs.faireconomy.media/ff_calendar_thisweek.json",
+    countries: &["USD"],
+    min_impact: EventImpact::High,
+    local_file: "econ_calendar.json",
+    timeout_ms: 10_000,
+};
+
+#[cfg(all(feature = "econ_calendar", not(target_arch = "wasm32")))]
+#[derive(Deserialize)]
+struct RawEvent {
+    title: String,
+    #[serde(default)]
+    country: Option<String>,
+    /// RFC 3339, e.g. `2025-01-15T08:30:00-05:00`
+    date: String,
+    #[serde(default)]
+    impact: Option<String>,
+}
+
+#[cfg(all(feature = "econ_calendar", not(target_arch = "wasm32")))]
+impl RawEvent {
+    fn into_event(self) -> Option<EconEvent> {
+        let impact = match self.impact.as_deref() {
+            Some("High") | None => EventImpact::High,
+            Some("Medium") => EventImpact::Medium,
+            _ => return None,
+        };
+        let time_ms = DateTime::parse_from_rfc3339(&self.date)
+            .ok()?
+            .timestamp_millis();
+        Some(EconEvent {
+            time_ms,
+            title: self.title,
+            impact,
+        })
+    }
+}
+
+/// Fetches the macro feed and merges the local event file. Either source may fail alone;
+/// the result is sorted by time.
+#[cfg(all(feature = "econ_calendar", not(target_arch = "wasm32")))]
+pub(crate) async fn fetch_econ_calendar() -> Vec<EconEvent> {
+    let mut events = Vec::new();
+
+    match fetch_feed().await {
+        Ok(raw) => events.extend(
+            raw.into_iter()
+                .filter(|r| {
+                    r.country
+                        .as_deref()
+                        .is_some_and(|c| ECON_CALENDAR.countries.contains(&c))
+                })
+                .filter_map(RawEvent::into_event)
+                .filter(|e| e.impact >= ECON_CALENDAR.min_impact),
+        ),
+        Err(e) => log::warn!("Economic calendar feed unavailable: {:#}", e),
+    }
+
+    let local_path = data_path(ECON_CALENDAR.local_file);
+    match std::fs::read(&local_path) {
+        Ok(bytes) => match serde_json::from_slice::<Vec<RawEvent>>(&bytes) {
+            Ok(raw) => events.extend(raw.into_iter().filter_map(RawEvent::into_event)),
+            Err(e) => log::warn!("Ignoring {}: {}", local_path.display(), e),
+        },
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => log::warn!("Could not read {}: {}", local_path.display(), e),
+    }
+
+    events.sort_by_key(|e| e.time_ms);
+    events.dedup_by(|a, b| a.time_ms == b.time_ms && a.title == b.title);
+    events
+}
+
+#[cfg(all(feature = "econ_calendar", not(target_arch = "wasm32")))]
+async fn fetch_feed() -> Result<Vec<RawEvent>> {
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_millis(ECON_CALENDAR.timeout_ms))
+        .build()?;
+    let bytes = client
+        .get(ECON_CALENDAR.feed_url)
+        .send()
+        .await?
+        .error_for_status()?
+        .bytes()
+        .await?;
+    serde_json::from_slice(&bytes).context("Unexpected calendar feed format")
+}
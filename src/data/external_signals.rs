@@ -0,0 +1,387 @@
+use {
+    crate::{app::Price, models::TradeDirection},
+    serde::{Deserialize, Serialize},
+    serde_json::Value,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use {
+    crate::utils::TimeUtils,
+    anyhow::{Result, anyhow},
+    std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::{Ipv4Addr, TcpListener, TcpStream},
+        sync::{
+            Arc, RwLock,
+            atomic::{AtomicBool, Ordering},
+            mpsc::{self, Receiver, Sender},
+        },
+        thread::{self, JoinHandle},
+        time::Duration,
+    },
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) struct SignalWebhookConfig {
+    /// Only POSTs to this path are accepted
+    pub path: &'static str,
+    /// Request line plus headers; more is answered with 431
+    pub max_header_bytes: usize,
+    pub max_body_bytes: usize,
+    pub read_timeout_ms: u64,
+    /// How often the accept loop checks for a stop request
+    pub poll_ms: u64,
+    /// Oldest signals are dropped past this count
+    pub max_signals: usize,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) const SIGNAL_WEBHOOK: SignalWebhookConfig = SignalWebhookConfig {
+    path: "/signal",
+    max_header_bytes: 8 * 1024,
+    max_body_bytes: 64 * 1024,
+    read_timeout_ms: 2_000,
+    poll_ms: 100,
+    max_signals: 500,
+};
+
+/// Engine settings for the signal webhook. Off by default; the listener only binds localhost.
+/// The shared token lives in the OS keychain, not here.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct SignalWebhookSettings {
+    pub enabled: bool,
+    pub port: u16,
+    /// Show received signals as Trade Finder rows
+    pub in_trade_finder: bool,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Default for SignalWebhookSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: 8787,
+            in_trade_finder: true,
+        }
+    }
+}
+
+/// A trade idea POSTed by an outside tool (TradingView alert, own script). Drawn on the plot
+/// and listed in the Trade Finder next to the engine's opportunities; never traded or scored.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ExternalSignal {
+    pub pair: String,
+    pub direction: TradeDirection,
+    /// Price when raised; the live price fills it in when the sender leaves it out
+    pub price: Option<Price>,
+    pub target: Option<Price>,
+    pub stop: Option<Price>,
+    pub time_ms: i64,
+    /// Who sent it ("tradingview", a script name); "webhook" when not given
+    pub source: String,
+    pub note: String,
+}
+
+/// Exchange symbol from what alert tools send: `BINANCE:BTCUSDT.P`, `btc/usdt`, `ETH-USDT`.
+pub(crate) fn normalize_signal_pair(raw: &str) -> String {
+    let symbol = raw.rsplit(':').next().unwrap_or(raw).trim();
+    let symbol = symbol
+        .strip_suffix(".P")
+        .or_else(|| symbol.strip_suffix(".PERP"))
+        .unwrap_or(symbol);
+    symbol
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_uppercase()
+}
+
+fn field<'a>(body: &'a Value, names: &[&str]) -> Option<&'a Value> {
+    names
+        .iter()
+        .find_map(|name| body.get(*name))
+        .filter(|v| !v.is_null())
+}
+
+fn text_field(body: &Value, names: &[&str]) -> Option<String> {
+    field(body, names).map(|v| match v {
+        Value::String(s) => s.trim().to_string(),
+        other => other.to_string(),
+    })
+}
+
+/// Alert templates often paste prices as strings (`"{{close}}"` -> `"0.1234"`).
+fn price_field(body: &Value, names: &[&str]) -> Result<Option<Price>, String> {
+    let Some(value) = field(body, names) else {
+        return Ok(None);
+    };
+    let parsed = match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) if s.trim().is_empty() => return Ok(None),
+        Value::String(s) => s.trim().parse::<f64>().ok(),
+        _ => None,
+    };
+    match parsed {
+        Some(v) if v.is_finite() && v > 0.0 => Ok(Some(Price::new(v))),
+        _ => Err(format!("{} is not a price: {}", names[0], value)),
+    }
+}
+
+/// Reads a webhook body. Field names follow common alert templates: `pair`/`symbol`/`ticker`,
+/// `direction`/`side`/`action` (long, buy, short, sell), `price`/`entry`, `target`/`tp`,
+/// `stop`/`sl`, `source`, `note`/`message`, and `time` in epoch ms (defaults to `now_ms`).
+pub(crate) fn parse_external_signal(body: &str, now_ms: i64) -> Result<ExternalSignal, String> {
+    let body: Value = serde_json::from_str(body).map_err(|e| format!("Body is not JSON: {}", e))?;
+    if !body.is_object() {
+        return Err("Body must be a JSON object".to_string());
+    }
+    let pair = text_field(&body, &["pair", "symbol", "ticker"])
+        .map(|p| normalize_signal_pair(&p))
+        .filter(|p| !p.is_empty())
+        .ok_or("Missing pair")?;
+    let direction = match text_field(&body, &["direction", "side", "action"])
+        .map(|d| d.to_lowercase())
+        .as_deref()
+    {
+        Some("long" | "buy") => TradeDirection::Long,
+        Some("short" | "sell") => TradeDirection::Short,
+        Some(other) => return Err(format!("Unknown direction: {}", other)),
+        None => return Err("Missing direction".to_string()),
+    };
+    let time_ms = match field(&body, &["time", "timestamp"]) {
+        Some(v) => v
+            .as_i64()
+            .or_else(|| v.as_str().and_then(|s| s.trim().parse().ok()))
+            .ok_or_else(|| format!("time is not epoch milliseconds: {}", v))?,
+        None => now_ms,
+    };
+    Ok(ExternalSignal {
+        pair,
+        direction,
+        price: price_field(&body, &["price", "entry"])?,
+        target: price_field(&body, &["target", "tp"])?,
+        stop: price_field(&body, &["stop", "sl"])?,
+        time_ms,
+        source: text_field(&body, &["source"])
+            .filter(|s| !s.is_empty())
+            .unwrap_or_else(|| "webhook".to_string()),
+        note: text_field(&body, &["note", "message", "comment"]).unwrap_or_default(),
+    })
+}
+
+/// Local HTTP endpoint receiving [`ExternalSignal`]s. Binds to localhost only; put a tunnel
+/// in front of it for TradingView. Dropping it (or [`SignalListener::stop`]) frees the port.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) struct SignalListener {
+    pub port: u16,
+    token: Arc<RwLock<String>>,
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+    rx: Receiver<ExternalSignal>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl SignalListener {
+    /// Starts listening on `port`. `token` must come with each request, as the `token` query
+    /// parameter or an `X-Sniper-Token` header. `on_signal` runs after each accepted signal
+    /// (to wake the UI).
+    pub(crate) fn start(
+        port: u16,
+        token: &str,
+        on_signal: impl Fn() + Send + 'static,
+    ) -> Result<Self> {
+        if token.is_empty() {
+            return Err(anyhow!("The webhook needs a token"));
+        }
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))?;
+        listener.set_nonblocking(true)?;
+        let token = Arc::new(RwLock::new(token.to_string()));
+        let stop = Arc::new(AtomicBool::new(false));
+        let (tx, rx) = mpsc::channel();
+        let thread = {
+            let (token, stop) = (token.clone(), stop.clone());
+            thread::spawn(move || {
+                while !stop.load(Ordering::Relaxed) {
+                    match listener.accept() {
+                        Ok((stream, _)) => {
+                            let expected = token.read().unwrap().clone();
+                            if handle_connection(stream, &expected, &tx) {
+                                on_signal();
+                            }
+                        }
+                        Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                            thread::sleep(Duration::from_millis(SIGNAL_WEBHOOK.poll_ms));
+                        }
+                        Err(e) => log::warn!("Signal webhook accept failed: {}", e),
+                    }
+                }
+            })
+        };
+        log::info!(
+            "Signal webhook listening on http://127.0.0.1:{}{}",
+            port,
+            SIGNAL_WEBHOOK.path
+        );
+        Ok(Self {
+            port,
+            token,
+            stop,
+            thread: Some(thread),
+            rx,
+        })
+    }
+
+    pub(crate) fn set_token(&self, token: &str) {
+        *self.token.write().unwrap() = token.to_string();
+    }
+
+    /// Signals received since the last call.
+    pub(crate) fn drain(&self) -> Vec<ExternalSignal> {
+        self.rx.try_iter().collect()
+    }
+
+    /// Stops accepting and waits for the port to be released.
+    pub(crate) fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Drop for SignalListener {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// `?token=...` from a request target, percent-decoding not needed for the tokens we accept.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn query_token(target: &str) -> Option<&str> {
+    let (_, query) = target.split_once('?')?;
+    query
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("token="))
+}
+
+/// Answers one request. Returns whether a signal was accepted.
+#[cfg(not(target_arch = "wasm32"))]
+fn handle_connection(stream: TcpStream, token: &str, tx: &Sender<ExternalSignal>) -> bool {
+    let _ = stream.set_nonblocking(false);
+    let _ = stream.set_read_timeout(Some(Duration::from_millis(SIGNAL_WEBHOOK.read_timeout_ms)));
+    let mut writer = match stream.try_clone() {
+        Ok(writer) => writer,
+        Err(e) => {
+            log::warn!("Signal webhook connection dropped: {}", e);
+            return false;
+        }
+    };
+    let (status, message, accepted) = match read_request(stream, token) {
+        Ok(body) => match parse_external_signal(&body, TimeUtils::now_timestamp_ms()) {
+            Ok(signal) => {
+                log::info!(
+                    "External signal: {} {:?} from {}",
+                    signal.pair,
+                    signal.direction,
+                    signal.source
+                );
+                let _ = tx.send(signal);
+                ("202 Accepted", "accepted".to_string(), true)
+            }
+            Err(e) => ("400 Bad Request", e, false),
+        },
+        Err((status, message)) => (status, message, false),
+    };
+    if !accepted {
+        log::warn!("Signal webhook rejected a request: {} {}", status, message);
+    }
+    let body = serde_json::json!({ "ok": accepted, "message": message }).to_string();
+    let _ = write!(
+        writer,
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    accepted
+}
+
+/// Reads a POST to the webhook path and returns its body, or the status to answer with.
+#[cfg(not(target_arch = "wasm32"))]
+fn read_request(stream: TcpStream, token: &str) -> Result<String, (&'static str, String)> {
+    let bad = |status, message: &str| (status, message.to_string());
+    let too_large = || bad("431 Request Header Fields Too Large", "headers too large");
+    let mut reader = BufReader::new(stream);
+    // The request line and headers are read through a cap, so a client that never sends a
+    // newline cannot make a line grow past the limit before the read timeout
+    let mut head = (&mut reader).take(SIGNAL_WEBHOOK.max_header_bytes as u64 + 1);
+    let mut request_line = String::new();
+    let mut header_bytes = head
+        .read_line(&mut request_line)
+        .map_err(|e| bad("400 Bad Request", &e.to_string()))?;
+    if header_bytes > SIGNAL_WEBHOOK.max_header_bytes {
+        return Err(too_large());
+    }
+    let mut parts = request_line.split_whitespace();
+    let (method, target) = (parts.next().unwrap_or(""), parts.next().unwrap_or(""));
+
+    let mut content_length = 0usize;
+    let mut content_type = String::new();
+    let mut header_token = None;
+    loop {
+        let mut line = String::new();
+        let read = head
+            .read_line(&mut line)
+            .map_err(|e| bad("400 Bad Request", &e.to_string()))?;
+        header_bytes += read;
+        if header_bytes > SIGNAL_WEBHOOK.max_header_bytes {
+            return Err(too_large());
+        }
+        let line = line.trim_end();
+        if read == 0 || line.is_empty() {
+            break;
+        }
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        match name.trim().to_ascii_lowercase().as_str() {
+            "content-length" => content_length = value.trim().parse().unwrap_or(0),
+            "content-type" => content_type = value.trim().to_ascii_lowercase(),
+            "x-sniper-token" => header_token = Some(value.trim().to_string()),
+            _ => {}
+        }
+    }
+
+    let path = target.split('?').next().unwrap_or("");
+    if path != SIGNAL_WEBHOOK.path {
+        return Err(bad("404 Not Found", "unknown path"));
+    }
+    if method != "POST" {
+        return Err(bad("405 Method Not Allowed", "use POST"));
+    }
+    if token.is_empty() || header_token.as_deref().or(query_token(target)) != Some(token) {
+        return Err(bad("401 Unauthorized", "missing or wrong token"));
+    }
+    // `application/json; charset=utf-8` is fine
+    if content_type.split(';').next().map(str::trim) != Some("application/json") {
+        return Err(bad(
+            "415 Unsupported Media Type",
+            "send Content-Type: application/json",
+        ));
+    }
+    if content_length > SIGNAL_WEBHOOK.max_body_bytes {
+        return Err(bad("413 Payload Too Large", "body too large"));
+    }
+    let mut body = vec![0; content_length];
+    reader
+        .read_exact(&mut body)
+        .map_err(|e| bad("400 Bad Request", &e.to_string()))?;
+    String::from_utf8(body).map_err(|_| bad("400 Bad Request", "body is not UTF-8"))
+}
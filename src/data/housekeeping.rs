@@ -0,0 +1,160 @@
+use {
+    crate::{
+        app::BASE_INTERVAL,
+        config::PERSISTENCE,
+        data::{SqliteStorage, read_pairs_file},
+        utils::TimeUtils,
+    },
+    anyhow::Result,
+    serde::{Deserialize, Serialize},
+    std::{collections::BTreeMap, fs, path::Path},
+};
+
+/// What the kline housekeeping job may delete. Runs on startup when enabled, or on demand
+/// from engine settings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct HousekeepingSettings {
+    pub on_startup: bool,
+    /// Days of candles kept per interval ("5m" -> 365); 0 keeps everything
+    pub retention_days: BTreeMap<String, u32>,
+    /// Delete candles of pairs no longer listed in pairs.txt
+    pub remove_orphans: bool,
+    /// VACUUM the database and delete cache files from older cache versions
+    pub compact: bool,
+}
+
+impl Default for HousekeepingSettings {
+    fn default() -> Self {
+        let base = TimeUtils::interval_to_string(BASE_INTERVAL.as_millis() as i64);
+        Self {
+            on_startup: false,
+            retention_days: BTreeMap::from([(base.to_string(), 0)]),
+            remove_orphans: false,
+            compact: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct HousekeepingReport {
+    /// Intervals found in the database
+    pub intervals: Vec<String>,
+    pub expired_candles: u64,
+    pub orphan_pairs: Vec<String>,
+    pub orphan_candles: u64,
+    pub stale_cache_files: usize,
+    pub bytes_before: u64,
+    pub bytes_after: u64,
+}
+
+impl HousekeepingReport {
+    pub(crate) fn reclaimed_bytes(&self) -> u64 {
+        self.bytes_before.saturating_sub(self.bytes_after)
+    }
+}
+
+/// Oldest open time kept for a retention of `days` (None = keep everything).
+pub(crate) fn retention_cutoff_ms(now_ms: i64, days: u32) -> Option<i64> {
+    (days > 0).then(|| now_ms - days as i64 * TimeUtils::MS_IN_D)
+}
+
+/// Kline cache files written by an older cache version (`kd_5m_v4.bin` while on v5).
+pub(crate) fn is_stale_cache_file(name: &str) -> bool {
+    let prefix = format!("{}_", PERSISTENCE.kline.filename_base);
+    let Some(rest) = name
+        .strip_prefix(&prefix)
+        .and_then(|r| r.strip_suffix(".bin"))
+    else {
+        return false;
+    };
+    match rest.rsplit_once("_v") {
+        Some((_, version)) => version
+            .parse::<f64>()
+            .is_ok_and(|v| v != PERSISTENCE.kline.version),
+        None => false,
+    }
+}
+
+/// Applies retention, orphan cleanup and compaction to the kline database and cache
+/// directory. Intervals without a retention entry are reported and keep everything.
+pub(crate) async fn run_housekeeping(
+    settings: &HousekeepingSettings,
+) -> Result<HousekeepingReport> {
+    let db_path = PERSISTENCE.kline_db();
+    let kline_dir = PERSISTENCE.kline_dir();
+    let mut report = HousekeepingReport {
+        bytes_before: db_bytes(&db_path) + dir_bytes(&kline_dir),
+        ..Default::default()
+    };
+    let storage = SqliteStorage::new(&db_path.to_string_lossy()).await?;
+    report.intervals = storage.list_intervals().await?;
+
+    let now_ms = TimeUtils::now_timestamp_ms();
+    for (interval, days) in &settings.retention_days {
+        if let Some(cutoff) = retention_cutoff_ms(now_ms, *days) {
+            report.expired_candles += storage.delete_candles_before(interval, cutoff).await?;
+        }
+    }
+
+    if settings.remove_orphans {
+        // Without pairs.txt the app falls back to defaults; treating the rest as orphans
+        // would wipe the database
+        match read_pairs_file() {
+            Some(listed) => {
+                for pair in storage.list_symbols().await? {
+                    if !listed.contains(&pair) {
+                        report.orphan_candles += storage.delete_pair(&pair).await?;
+                        report.orphan_pairs.push(pair);
+                    }
+                }
+            }
+            None => log::warn!("Housekeeping: no pairs file, orphan cleanup skipped"),
+        }
+    }
+
+    if settings.compact {
+        storage.vacuum().await?;
+        if let Ok(entries) = fs::read_dir(&kline_dir) {
+            for entry in entries.flatten() {
+                let name = entry.file_name();
+                if is_stale_cache_file(&name.to_string_lossy()) {
+                    match fs::remove_file(entry.path()) {
+                        Ok(()) => report.stale_cache_files += 1,
+                        Err(e) => log::warn!("Could not delete {:?}: {}", name, e),
+                    }
+                }
+            }
+        }
+    }
+    storage.close().await;
+
+    report.bytes_after = db_bytes(&db_path) + dir_bytes(&kline_dir);
+    Ok(report)
+}
+
+/// The database plus its WAL and shared-memory files.
+pub(crate) fn db_bytes(db_path: &Path) -> u64 {
+    ["", "-wal", "-shm"]
+        .iter()
+        .filter_map(|suffix| {
+            let mut path = db_path.as_os_str().to_owned();
+            path.push(suffix);
+            fs::metadata(path).ok()
+        })
+        .map(|m| m.len())
+        .sum()
+}
+
+fn dir_bytes(dir: &Path) -> u64 {
+    fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|e| e.metadata().ok())
+                .filter(|m| m.is_file())
+                .map(|m| m.len())
+                .sum()
+        })
+        .unwrap_or(0)
+}
@@ -0,0 +1,170 @@
+use {
+    crate::{
+        config::{PERSISTENCE, data_path},
+        data::{STATUS_TOKEN_USER, load_local_token},
+    },
+    anyhow::{Context, Result, anyhow},
+    serde::{Deserialize, Serialize},
+    std::{
+        fs::{self, File, OpenOptions, TryLockError},
+        io::{BufRead, BufReader, Write},
+        net::{Ipv4Addr, TcpListener, TcpStream},
+        path::PathBuf,
+        sync::{Arc, OnceLock, RwLock},
+        thread,
+        time::Duration,
+    },
+};
+
+const STATUS_READ_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Snapshot of the running instance, served over localhost to secondary instances that send
+/// the keychain token first.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub(crate) struct InstanceStatus {
+    pub profile: String,
+    pub live_pairs: usize,
+    pub opportunities: Vec<InstanceOpportunity>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(crate) struct InstanceOpportunity {
+    pub pair: String,
+    pub direction: String,
+    pub target: String,
+    pub roi: String,
+    pub quality: f64,
+}
+
+/// The data directory lock, held for the life of the GUI process. The OS drops it on exit or
+/// crash, so a leftover lock file never blocks a restart.
+pub struct InstanceGuard {
+    _file: File,
+}
+
+/// The instance that already holds the lock, as far as its info file says.
+#[derive(Debug, Clone)]
+pub struct RunningInstance {
+    pub pid: Option<u32>,
+    /// Localhost port serving its [`InstanceStatus`]
+    pub port: Option<u16>,
+    pub lock_path: PathBuf,
+}
+
+impl RunningInstance {
+    /// Parses the info file: `<pid> [<port>]`. Missing or garbled fields are None.
+    pub(crate) fn from_info(contents: &str, lock_path: PathBuf) -> Self {
+        let mut fields = contents.split_whitespace();
+        Self {
+            pid: fields.next().and_then(|f| f.parse().ok()),
+            port: fields.next().and_then(|f| f.parse().ok()),
+            lock_path,
+        }
+    }
+}
+
+pub enum InstanceLock {
+    Acquired(InstanceGuard),
+    Held(RunningInstance),
+}
+
+static STATUS: OnceLock<Arc<RwLock<InstanceStatus>>> = OnceLock::new();
+
+/// Locks the data directory so a second instance can't share its cache and ledger. The owner
+/// also starts the status server; its pid and port go into a sibling info file (a locked file
+/// can't be read on Windows) for the next comer.
+pub fn lock_data_dir() -> Result<InstanceLock> {
+    let lock_path = data_path(PERSISTENCE.app.lock_path);
+    let info_path = data_path(PERSISTENCE.app.instance_info_path);
+    let file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(&lock_path)
+        .with_context(|| format!("Failed to open {}", lock_path.display()))?;
+    match file.try_lock() {
+        Ok(()) => {}
+        Err(TryLockError::WouldBlock) => {
+            let contents = fs::read_to_string(&info_path).unwrap_or_default();
+            return Ok(InstanceLock::Held(RunningInstance::from_info(
+                &contents, lock_path,
+            )));
+        }
+        Err(TryLockError::Error(e)) => {
+            return Err(e).with_context(|| format!("Failed to lock {}", lock_path.display()));
+        }
+    }
+
+    let port = match serve_status() {
+        Ok(port) => Some(port),
+        Err(e) => {
+            log::warn!("Instance status server disabled: {:#}", e);
+            None
+        }
+    };
+    let info = match port {
+        Some(port) => format!("{} {}", std::process::id(), port),
+        None => std::process::id().to_string(),
+    };
+    fs::write(&info_path, info)
+        .with_context(|| format!("Failed to write {}", info_path.display()))?;
+    Ok(InstanceLock::Acquired(InstanceGuard { _file: file }))
+}
+
+fn serve_status() -> Result<u16> {
+    let token = load_local_token(STATUS_TOKEN_USER)?;
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))?;
+    let port = listener.local_addr()?.port();
+    let status = STATUS
+        .get_or_init(|| Arc::new(RwLock::new(InstanceStatus::default())))
+        .clone();
+    thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            if let Err(e) = answer_status(stream, &token, &status) {
+                log::debug!("Instance status request dropped: {:#}", e);
+            }
+        }
+    });
+    Ok(port)
+}
+
+/// One request: the client's token line, then the snapshot as one JSON line.
+fn answer_status(
+    mut stream: TcpStream,
+    token: &str,
+    status: &RwLock<InstanceStatus>,
+) -> Result<()> {
+    stream.set_read_timeout(Some(STATUS_READ_TIMEOUT))?;
+    let mut line = String::new();
+    BufReader::new(&stream).read_line(&mut line)?;
+    if line.trim_end() != token {
+        return Err(anyhow!("missing or wrong token"));
+    }
+    let snapshot = status.read().unwrap().clone();
+    serde_json::to_writer(&mut stream, &snapshot)?;
+    stream.write_all(b"\n")?;
+    Ok(())
+}
+
+/// Updates what secondary instances see. No-op unless this process owns the lock.
+pub(crate) fn publish_instance_status(status: InstanceStatus) {
+    if let Some(shared) = STATUS.get() {
+        *shared.write().unwrap() = status;
+    }
+}
+
+/// Asks the instance on `port` for its current status (one JSON line per connection).
+pub(crate) fn fetch_instance_status(port: u16) -> Result<InstanceStatus> {
+    let token = load_local_token(STATUS_TOKEN_USER)?;
+    let mut stream = TcpStream::connect((Ipv4Addr::LOCALHOST, port))?;
+    stream.set_read_timeout(Some(STATUS_READ_TIMEOUT))?;
+    stream.write_all(format!("{}\n", token).as_bytes())?;
+    let mut line = String::new();
+    BufReader::new(stream).read_line(&mut line)?;
+    Ok(serde_json::from_str(&line)?)
+}
+
+/// Whether this process owns the lock and serves its status.
+pub(crate) fn serving_instance_status() -> bool {
+    STATUS.get().is_some()
+}
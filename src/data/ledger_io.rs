@@ -1,27 +1,284 @@
 use {
-    crate::{config::PERSISTENCE, models::OpportunityLedger},
-    anyhow::Result,
+    crate::{
+        app::{DurationMs, PhPct, Price, Prob, RoiPct, StopPrice, TargetPrice},
+        config::PERSISTENCE,
+        engine::StationId,
+        models::{
+            EmpiricalOutcomeStats, MarketState, OpportunityLedger, OptimizationStrategy,
+            TradeDirection, TradeOpportunity, TradeVariant, VisualFluff,
+        },
+    },
+    anyhow::{Context, Result, bail},
+    chrono::{DateTime, Utc},
+    serde::{Deserialize, Serialize},
     std::{
-        fs::File,
-        io::{BufReader, BufWriter},
+        collections::HashMap,
+        fs::{self, File},
+        io::{BufReader, BufWriter, Write},
+        path::{Path, PathBuf},
     },
 };
 
+/// Layout version of [`export_ledger`] files; bump on incompatible changes.
+const LEDGER_EXPORT_VERSION: u32 = 1;
+
+/// Leading bytes of a versioned ledger store. Stores written before versioning start with
+/// the bincode map length instead, which never spells this out.
+const LEDGER_MAGIC: [u8; 8] = *b"ZSLEDGER";
+
+/// Layout version of the bincode ledger store. Bincode is not self-describing, so any change
+/// to a persisted type needs a bump here and a legacy layout to migrate from.
+const LEDGER_VERSION: u32 = 2;
+/// Last layout without the re-keyed id map.
+const LEGACY_NO_RENAMES_VERSION: u32 = 1;
+
+/// Portable ledger snapshot. JSON rather than the bincode store, so it can move between
+/// machines and app versions.
+#[derive(Serialize, Deserialize)]
+struct LedgerExport {
+    version: u32,
+    exported_at: DateTime<Utc>,
+    opportunities: Vec<TradeOpportunity>,
+}
+
 pub(crate) fn save_ledger(ledger: &OpportunityLedger) -> Result<()> {
-    let path = PERSISTENCE.app.ledger_path;
+    let path = PERSISTENCE.ledger_file();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
     let file = File::create(path)?;
-    let writer = BufWriter::new(file);
-    bincode::serialize_into(writer, ledger)?;
+    let mut writer = BufWriter::new(file);
+    write_ledger(&mut writer, ledger)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Writes the versioned store: magic, layout version, then the bincode ledger.
+pub(crate) fn write_ledger(mut writer: impl Write, ledger: &OpportunityLedger) -> Result<()> {
+    writer.write_all(&LEDGER_MAGIC)?;
+    bincode::serialize_into(&mut writer, &LEDGER_VERSION)?;
+    bincode::serialize_into(&mut writer, ledger)?;
     Ok(())
 }
 
 pub(crate) fn load_ledger() -> Result<OpportunityLedger> {
-    let path = PERSISTENCE.app.ledger_path;
-    if !std::path::Path::new(path).exists() {
+    let path = PERSISTENCE.ledger_file();
+    if !path.exists() {
         return Ok(OpportunityLedger::new());
     }
-    let file = File::open(path)?;
-    let reader = BufReader::new(file);
-    let ledger = bincode::deserialize_from(reader)?;
+    let bytes = fs::read(&path).context(format!("Failed to open ledger: {:?}", path))?;
+    let mut ledger = ledger_from_bytes(&bytes)?;
+    let rekeyed = ledger.rekey_legacy_ids();
+    if rekeyed > 0 {
+        log::info!(
+            "Migrated {} ledger entries to stable opportunity ids",
+            rekeyed
+        );
+    }
     Ok(ledger)
 }
+
+/// Deserializes a ledger store, upgrading older layouts on the fly.
+pub(crate) fn ledger_from_bytes(bytes: &[u8]) -> Result<OpportunityLedger> {
+    let Some(body) = bytes.strip_prefix(&LEDGER_MAGIC) else {
+        let legacy: OpportunityLedgerV0 =
+            bincode::deserialize(bytes).context("Failed to deserialize unversioned ledger")?;
+        log::info!(
+            "Migrating {} ledger entries from the unversioned layout",
+            legacy.opportunities.len()
+        );
+        return Ok(legacy.into());
+    };
+    let version: u32 = bincode::deserialize(body).context("Failed to read ledger version")?;
+    let payload = &body[std::mem::size_of::<u32>()..];
+    match version {
+        LEDGER_VERSION => bincode::deserialize(payload).context("Failed to deserialize ledger"),
+        LEGACY_NO_RENAMES_VERSION => {
+            let legacy: OpportunityLedgerV1 =
+                bincode::deserialize(payload).context("Failed to deserialize ledger")?;
+            Ok(legacy.into())
+        }
+        _ => bail!(
+            "Ledger version {} is not supported by this app ({})",
+            version,
+            LEDGER_VERSION
+        ),
+    }
+}
+
+/// Renames an unreadable ledger store out of the way so the next save does not overwrite it.
+/// Returns where it went.
+pub(crate) fn set_aside_ledger() -> Result<PathBuf> {
+    let path = PERSISTENCE.ledger_file();
+    let aside = path.with_extension(format!(
+        "unreadable_{}.bin",
+        Utc::now().format("%Y%m%d_%H%M%S")
+    ));
+    fs::rename(&path, &aside)?;
+    Ok(aside)
+}
+
+/// Writes every ledger opportunity (sorted by id) to `ledger_<timestamp>.json` in the export
+/// directory. Returns the path.
+pub(crate) fn export_ledger(ledger: &OpportunityLedger) -> Result<PathBuf> {
+    let dir = PERSISTENCE.export_dir();
+    fs::create_dir_all(&dir)?;
+    let exported_at = Utc::now();
+    let path = dir.join(format!(
+        "ledger_{}.json",
+        exported_at.format("%Y%m%d_%H%M%S")
+    ));
+    let mut opportunities: Vec<TradeOpportunity> = ledger.opportunities.values().cloned().collect();
+    opportunities.sort_by(|a, b| a.id.cmp(&b.id));
+    let export = LedgerExport {
+        version: LEDGER_EXPORT_VERSION,
+        exported_at,
+        opportunities,
+    };
+    serde_json::to_writer_pretty(BufWriter::new(File::create(&path)?), &export)?;
+    Ok(path)
+}
+
+/// Reads the opportunities of a file written by [`export_ledger`]; merging them is up to
+/// [`OpportunityLedger::import`].
+pub(crate) fn import_ledger(path: &Path) -> Result<Vec<TradeOpportunity>> {
+    let export: LedgerExport = serde_json::from_reader(BufReader::new(File::open(path)?))?;
+    if export.version > LEDGER_EXPORT_VERSION {
+        bail!(
+            "Ledger export version {} is newer than this app supports ({})",
+            export.version,
+            LEDGER_EXPORT_VERSION
+        );
+    }
+    Ok(export.opportunities)
+}
+
+/// Outcome stats as stored before the ledger was versioned.
+#[derive(Deserialize)]
+struct EmpiricalOutcomeStatsV0 {
+    success_rate: Prob,
+    avg_candle_count: f64,
+    risk_reward_ratio: f64,
+    sample_size: usize,
+    avg_pnl_pct: RoiPct,
+    market_state: MarketState,
+    return_variance: f64,
+}
+
+#[derive(Deserialize)]
+struct TradeVariantV0 {
+    ratio: f64,
+    roi_pct: RoiPct,
+    simulation: EmpiricalOutcomeStatsV0,
+    stop_price: StopPrice,
+}
+
+#[derive(Deserialize)]
+struct TradeOpportunityV0 {
+    id: String,
+    created_at: DateTime<Utc>,
+    ph_pct: PhPct,
+    pair_name: String,
+    direction: TradeDirection,
+    start_price: Price,
+    target_price: TargetPrice,
+    stop_price: StopPrice,
+    max_duration: DurationMs,
+    avg_duration: DurationMs,
+    strategy: OptimizationStrategy,
+    station_id: StationId,
+    market_state: MarketState,
+    visuals: Option<VisualFluff>,
+    simulation: EmpiricalOutcomeStatsV0,
+    variants: Vec<TradeVariantV0>,
+}
+
+#[derive(Deserialize)]
+struct OpportunityLedgerV0 {
+    opportunities: HashMap<String, TradeOpportunityV0>,
+}
+
+#[derive(Deserialize)]
+struct OpportunityLedgerV1 {
+    opportunities: HashMap<String, TradeOpportunity>,
+}
+
+impl From<OpportunityLedgerV1> for OpportunityLedger {
+    fn from(legacy: OpportunityLedgerV1) -> Self {
+        let mut ledger = OpportunityLedger::new();
+        ledger.opportunities = legacy.opportunities;
+        ledger
+    }
+}
+
+impl From<EmpiricalOutcomeStatsV0> for EmpiricalOutcomeStats {
+    fn from(legacy: EmpiricalOutcomeStatsV0) -> Self {
+        Self {
+            success_rate: legacy.success_rate,
+            avg_candle_count: legacy.avg_candle_count,
+            risk_reward_ratio: legacy.risk_reward_ratio,
+            sample_size: legacy.sample_size,
+            avg_pnl_pct: legacy.avg_pnl_pct,
+            market_state: legacy.market_state,
+            return_variance: legacy.return_variance,
+            // Stops were fixed and hit times untracked back then
+            hit_times: None,
+            break_even_rate: Prob::new(0.0),
+            fixed_success_rate: None,
+            barrier: None,
+        }
+    }
+}
+
+impl From<TradeVariantV0> for TradeVariant {
+    fn from(legacy: TradeVariantV0) -> Self {
+        Self {
+            ratio: legacy.ratio,
+            roi_pct: legacy.roi_pct,
+            simulation: legacy.simulation.into(),
+            stop_price: legacy.stop_price,
+        }
+    }
+}
+
+impl From<TradeOpportunityV0> for TradeOpportunity {
+    fn from(legacy: TradeOpportunityV0) -> Self {
+        Self {
+            id: legacy.id,
+            created_at: legacy.created_at,
+            ph_pct: legacy.ph_pct,
+            pair_name: legacy.pair_name,
+            direction: legacy.direction,
+            start_price: legacy.start_price,
+            target_price: legacy.target_price,
+            stop_price: legacy.stop_price,
+            max_duration: legacy.max_duration,
+            duration_vol_factor: 1.0,
+            avg_duration: legacy.avg_duration,
+            strategy: legacy.strategy,
+            station_id: legacy.station_id,
+            market_state: legacy.market_state,
+            visuals: legacy.visuals,
+            simulation: legacy.simulation.into(),
+            variants: legacy.variants.into_iter().map(Into::into).collect(),
+            // Derived per run; the next recalculation of the pair fills these in
+            ladder: Vec::new(),
+            custom_zone: None,
+            path_density: None,
+            excursions: None,
+            matches: Default::default(),
+        }
+    }
+}
+
+impl From<OpportunityLedgerV0> for OpportunityLedger {
+    fn from(legacy: OpportunityLedgerV0) -> Self {
+        let mut ledger = OpportunityLedger::new();
+        ledger.opportunities = legacy
+            .opportunities
+            .into_iter()
+            .map(|(id, op)| (id, op.into()))
+            .collect();
+        ledger
+    }
+}
@@ -0,0 +1,441 @@
+use {
+    crate::{
+        app::{BaseVol, ClosePrice, HighPrice, LowPrice, OpenPrice, Price, QuoteVol, TimestampMs},
+        data::MarketDataProvider,
+        domain::Candle,
+        models::LiveCandle,
+        utils::TimeUtils,
+    },
+    anyhow::{Result, anyhow},
+    async_trait::async_trait,
+    std::{
+        sync::{Arc, Mutex, OnceLock, mpsc::Sender},
+        thread,
+        time::Duration,
+    },
+};
+
+pub(crate) struct MockExchangeConfig {
+    /// Generated history per pair, ending at the last closed candle
+    pub history_days: i64,
+    /// How often the fake price stream moves prices
+    pub tick_interval: Duration,
+    pub default_seed: u64,
+    /// Regime schedule (regime, candles) used when `--mock-regime` is not given; it repeats
+    pub default_schedule: &'static [(MockRegime, u64)],
+    /// Starting prices for familiar pairs so demos look plausible; others get a hashed price
+    pub start_prices: &'static [(&'static str, f64)],
+    /// Base volume per candle at a volume factor of 1.0, in quote currency
+    pub base_quote_volume: f64,
+}
+
+pub(crate) const MOCK_EXCHANGE: MockExchangeConfig = MockExchangeConfig {
+    history_days: 60,
+    tick_interval: Duration::from_secs(1),
+    default_seed: 42,
+    default_schedule: &[
+        (MockRegime::Range, 2_016),
+        (MockRegime::TrendUp, 1_152),
+        (MockRegime::Volatile, 576),
+        (MockRegime::Crash, 24),
+        (MockRegime::Range, 1_440),
+        (MockRegime::TrendDown, 1_152),
+    ],
+    start_prices: &[
+        ("BTCUSDT", 65_000.0),
+        ("ETHUSDT", 3_200.0),
+        ("SOLUSDT", 150.0),
+        ("BNBUSDT", 580.0),
+        ("PAXGUSDT", 2_400.0),
+        ("DOGEUSDT", 0.15),
+        ("XRPUSDT", 0.55),
+    ],
+    base_quote_volume: 250_000.0,
+};
+
+/// Market behaviour the random walk follows for a stretch of candles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MockRegime {
+    /// No drift, normal volatility
+    Range,
+    TrendUp,
+    TrendDown,
+    /// No drift, roughly triple volatility and heavier volume
+    Volatile,
+    /// Steep decline on very heavy volume
+    Crash,
+}
+
+impl MockRegime {
+    /// Per-candle log drift.
+    fn drift(&self) -> f64 {
+        match self {
+            Self::Range | Self::Volatile => 0.0,
+            Self::TrendUp => 0.000_25,
+            Self::TrendDown => -0.000_25,
+            Self::Crash => -0.006,
+        }
+    }
+
+    /// Per-candle log-return standard deviation before the pair's own scaling.
+    fn volatility(&self) -> f64 {
+        match self {
+            Self::Range | Self::TrendUp | Self::TrendDown => 0.002,
+            Self::Volatile => 0.006,
+            Self::Crash => 0.008,
+        }
+    }
+
+    fn volume_factor(&self) -> f64 {
+        match self {
+            Self::Range => 1.0,
+            Self::TrendUp | Self::TrendDown => 1.4,
+            Self::Volatile => 2.5,
+            Self::Crash => 6.0,
+        }
+    }
+
+    fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "range" => Some(Self::Range),
+            "up" | "trend-up" => Some(Self::TrendUp),
+            "down" | "trend-down" => Some(Self::TrendDown),
+            "volatile" => Some(Self::Volatile),
+            "crash" => Some(Self::Crash),
+            _ => None,
+        }
+    }
+}
+
+/// Seed and regime schedule for the synthetic market. The schedule is anchored to candle open
+/// times (not to when the app started), so a given seed replays the same market shape.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct MockSettings {
+    pub seed: u64,
+    pub schedule: Vec<(MockRegime, u64)>,
+}
+
+impl Default for MockSettings {
+    fn default() -> Self {
+        Self {
+            seed: MOCK_EXCHANGE.default_seed,
+            schedule: MOCK_EXCHANGE.default_schedule.to_vec(),
+        }
+    }
+}
+
+impl MockSettings {
+    /// `spans` look like `up:288` (regime, candles); an empty list keeps the default schedule.
+    pub(crate) fn from_args(seed: Option<u64>, spans: &[String]) -> Result<Self> {
+        let mut settings = Self::default();
+        if let Some(seed) = seed {
+            settings.seed = seed;
+        }
+        if spans.is_empty() {
+            return Ok(settings);
+        }
+        settings.schedule = spans
+            .iter()
+            .map(|span| {
+                let (name, count) = span
+                    .split_once(':')
+                    .ok_or_else(|| anyhow!("Expected regime:candles, got '{}'", span))?;
+                let regime = MockRegime::parse(name.trim()).ok_or_else(|| {
+                    anyhow!(
+                        "Unknown regime '{}' (range, up, down, volatile, crash)",
+                        name
+                    )
+                })?;
+                let count: u64 = count
+                    .trim()
+                    .parse()
+                    .map_err(|_| anyhow!("Bad candle count in '{}'", span))?;
+                if count == 0 {
+                    return Err(anyhow!("Regime span '{}' has no candles", span));
+                }
+                Ok((regime, count))
+            })
+            .collect::<Result<_>>()?;
+        Ok(settings)
+    }
+
+    pub(crate) fn regime_at(&self, open_time_ms: i64, interval_ms: i64) -> MockRegime {
+        let cycle: u64 = self.schedule.iter().map(|(_, n)| n).sum();
+        if cycle == 0 || interval_ms <= 0 {
+            return MockRegime::Range;
+        }
+        let mut pos = (open_time_ms.max(0) / interval_ms) as u64 % cycle;
+        for (regime, n) in &self.schedule {
+            if pos < *n {
+                return *regime;
+            }
+            pos -= n;
+        }
+        MockRegime::Range
+    }
+}
+
+static MOCK_SETTINGS: OnceLock<MockSettings> = OnceLock::new();
+
+/// Switches the data provider and price stream to the synthetic market for this process.
+pub(crate) fn enable_mock_exchange(settings: MockSettings) {
+    if MOCK_SETTINGS.set(settings).is_err() {
+        log::warn!("Mock exchange already enabled; keeping the first settings");
+    }
+}
+
+/// `Some` when running with `--mock-exchange`.
+pub(crate) fn mock_exchange() -> Option<&'static MockSettings> {
+    MOCK_SETTINGS.get()
+}
+
+/// SplitMix64: tiny, seedable and plenty for synthetic prices.
+#[derive(Debug, Clone)]
+struct MockRng(u64);
+
+impl MockRng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in (0, 1).
+    fn uniform(&mut self) -> f64 {
+        ((self.next_u64() >> 11) as f64 + 0.5) / (1u64 << 53) as f64
+    }
+
+    /// Standard normal (Box-Muller).
+    fn normal(&mut self) -> f64 {
+        let (u1, u2) = (self.uniform(), self.uniform());
+        (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+    }
+}
+
+fn hash_pair(seed: u64, pair: &str) -> u64 {
+    // FNV-1a over the pair name, mixed with the seed
+    pair.bytes().fold(0xCBF2_9CE4_8422_2325 ^ seed, |h, b| {
+        (h ^ b as u64).wrapping_mul(0x0100_0000_01B3)
+    })
+}
+
+/// Random walk for one pair. Candles follow the regime scheduled at their open time; the
+/// pair's own volatility scale and start price come from its name, so pairs differ.
+#[derive(Debug, Clone)]
+pub(crate) struct MockWalk {
+    settings: MockSettings,
+    rng: MockRng,
+    price: f64,
+    vol_scale: f64,
+}
+
+impl MockWalk {
+    pub(crate) fn new(settings: &MockSettings, pair: &str) -> Self {
+        let mut rng = MockRng(hash_pair(settings.seed, pair));
+        let price = MOCK_EXCHANGE
+            .start_prices
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(pair))
+            .map(|(_, price)| *price)
+            .unwrap_or_else(|| 10f64.powf(rng.uniform() * 4.0 - 1.0));
+        let vol_scale = 0.6 + rng.uniform();
+        Self {
+            settings: settings.clone(),
+            rng,
+            price,
+            vol_scale,
+        }
+    }
+
+    pub(crate) fn price(&self) -> f64 {
+        self.price
+    }
+
+    /// Moves the price by `fraction` of a candle's drift and variance; returns the new price.
+    fn step(&mut self, regime: MockRegime, fraction: f64) -> f64 {
+        let sigma = regime.volatility() * self.vol_scale * fraction.sqrt();
+        self.price *= (regime.drift() * fraction + sigma * self.rng.normal()).exp();
+        self.price
+    }
+
+    fn volume(&mut self, regime: MockRegime, close: f64, fraction: f64) -> (f64, f64) {
+        let quote = MOCK_EXCHANGE.base_quote_volume
+            * regime.volume_factor()
+            * fraction
+            * (0.5 + self.rng.uniform());
+        (quote / close.max(f64::EPSILON), quote)
+    }
+
+    /// The next whole candle, opening at `open_time_ms`.
+    pub(crate) fn next_candle(&mut self, open_time_ms: i64, interval_ms: i64) -> Candle {
+        let regime = self.settings.regime_at(open_time_ms, interval_ms);
+        let open = self.price;
+        let close = self.step(regime, 1.0);
+        let wick = regime.volatility() * self.vol_scale * 0.5;
+        let high = open.max(close) * (1.0 + wick * self.rng.uniform());
+        let low = open.min(close) * (1.0 - wick * self.rng.uniform());
+        let (base_vol, quote_vol) = self.volume(regime, close, 1.0);
+        // Takers lean with the candle
+        let taker_share = if close >= open { 0.55 } else { 0.45 };
+        Candle::new(
+            TimestampMs::new(open_time_ms),
+            OpenPrice::new(open),
+            HighPrice::new(high),
+            LowPrice::new(low),
+            ClosePrice::new(close),
+            BaseVol::new(base_vol),
+            QuoteVol::new(quote_vol),
+            BaseVol::new(base_vol * taker_share),
+        )
+    }
+}
+
+/// Closed candles up to `now_ms`, starting `history_days` before the current UTC day. Starting
+/// on a day boundary keeps the history and the price stream on the same path.
+pub(crate) fn generate_history(
+    settings: &MockSettings,
+    pair: &str,
+    interval_ms: i64,
+    now_ms: i64,
+) -> (Vec<Candle>, MockWalk) {
+    let mut walk = MockWalk::new(settings, pair);
+    if interval_ms <= 0 {
+        return (Vec::new(), walk);
+    }
+    let day_ms = TimeUtils::MS_IN_D;
+    let start = (now_ms / day_ms - MOCK_EXCHANGE.history_days) * day_ms;
+    let mut candles = Vec::new();
+    let mut open_time = start;
+    while open_time + interval_ms <= now_ms {
+        candles.push(walk.next_candle(open_time, interval_ms));
+        open_time += interval_ms;
+    }
+    (candles, walk)
+}
+
+/// Serves generated history instead of Binance klines.
+pub(crate) struct MockProvider {
+    settings: MockSettings,
+}
+
+impl MockProvider {
+    pub(crate) fn new(settings: &MockSettings) -> Self {
+        Self {
+            settings: settings.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl MarketDataProvider for MockProvider {
+    async fn fetch_candles(
+        &self,
+        pair: &str,
+        interval_ms: i64,
+        start_time: Option<i64>,
+    ) -> Result<Vec<Candle>> {
+        let (mut candles, _) = generate_history(
+            &self.settings,
+            pair,
+            interval_ms,
+            TimeUtils::now_timestamp_ms(),
+        );
+        if let Some(start) = start_time {
+            candles.retain(|c| c.timestamp_ms.value() >= start);
+        }
+        Ok(candles)
+    }
+}
+
+/// Candle being built from ticks.
+struct FormingCandle {
+    symbol: String,
+    walk: MockWalk,
+    candle: Candle,
+}
+
+/// Fake price stream: carries on each pair's walk from the end of its generated history,
+/// ticking prices every `tick_interval` and sending live candles (closed ones on rollover).
+/// `on_tick` receives the uppercase symbol. Runs until the process exits.
+pub(crate) fn spawn_mock_price_stream(
+    settings: &'static MockSettings,
+    symbols: Vec<String>,
+    interval_ms: i64,
+    on_tick: impl Fn(&str, Price) + Send + 'static,
+    suspended: Arc<Mutex<bool>>,
+    candle_tx: Option<Sender<LiveCandle>>,
+) {
+    thread::spawn(move || {
+        let now = TimeUtils::now_timestamp_ms();
+        let open_time = now - now.rem_euclid(interval_ms);
+        let mut forming: Vec<FormingCandle> = symbols
+            .iter()
+            .map(|symbol| {
+                let symbol = symbol.to_uppercase();
+                let (_, walk) = generate_history(settings, &symbol, interval_ms, now);
+                let candle = open_candle(&walk, open_time);
+                FormingCandle {
+                    symbol,
+                    walk,
+                    candle,
+                }
+            })
+            .collect();
+        let fraction = MOCK_EXCHANGE.tick_interval.as_millis() as f64 / interval_ms.max(1) as f64;
+
+        loop {
+            let now = TimeUtils::now_timestamp_ms();
+            let open_time = now - now.rem_euclid(interval_ms);
+            let suspended = *suspended.lock().unwrap();
+            for f in &mut forming {
+                if f.candle.timestamp_ms.value() < open_time {
+                    let closed = LiveCandle::from_candle(&f.symbol, f.candle, true);
+                    candle_tx.iter().for_each(|tx| {
+                        let _ = tx.send(closed.clone());
+                    });
+                    f.candle = open_candle(&f.walk, open_time);
+                }
+                let regime = settings.regime_at(open_time, interval_ms);
+                let price = f.walk.step(regime, fraction);
+                let (base_vol, quote_vol) = f.walk.volume(regime, price, fraction);
+                let c = &mut f.candle;
+                c.high_price = HighPrice::new(c.high_price.value().max(price));
+                c.low_price = LowPrice::new(c.low_price.value().min(price));
+                c.close_price = ClosePrice::new(price);
+                c.base_asset_volume = BaseVol::new(c.base_asset_volume.value() + base_vol);
+                c.quote_asset_volume = QuoteVol::new(c.quote_asset_volume.value() + quote_vol);
+                let taker = if price >= c.open_price.value() {
+                    0.55
+                } else {
+                    0.45
+                };
+                c.taker_buy_base_volume =
+                    BaseVol::new(c.taker_buy_base_volume.value() + base_vol * taker);
+                candle_tx.iter().for_each(|tx| {
+                    let _ = tx.send(LiveCandle::from_candle(&f.symbol, *c, false));
+                });
+                // Like the live stream: candles keep flowing, prices freeze while suspended
+                if !suspended {
+                    on_tick(&f.symbol, Price::new(price));
+                }
+            }
+            thread::sleep(MOCK_EXCHANGE.tick_interval);
+        }
+    });
+}
+
+fn open_candle(walk: &MockWalk, open_time: i64) -> Candle {
+    let price = walk.price();
+    Candle::new(
+        TimestampMs::new(open_time),
+        OpenPrice::new(price),
+        HighPrice::new(price),
+        LowPrice::new(price),
+        ClosePrice::new(price),
+        BaseVol::new(0.0),
+        QuoteVol::new(0.0),
+        BaseVol::new(0.0),
+    )
+}
@@ -1,17 +1,57 @@
+// The browser build only needs the WebSocket settings
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+mod binance;
+#[cfg(not(target_arch = "wasm32"))]
+mod cache_inspector;
+#[cfg(not(target_arch = "wasm32"))]
+mod clock;
+// Events only arrive when the native `econ_calendar` feature fetches them
+#[cfg_attr(
+    not(all(feature = "econ_calendar", not(target_arch = "wasm32"))),
+    allow(dead_code)
+)]
+mod econ_calendar;
+// The webhook listener is native-only; in the browser the signal list stays empty
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+mod external_signals;
 mod pre_main_async;
 mod price_stream;
 mod timeseries;
 
 #[cfg(not(target_arch = "wasm32"))]
-mod binance;
+mod api_keys;
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) mod cross_check;
+#[cfg(not(target_arch = "wasm32"))]
+mod demo_builder;
+#[cfg(not(target_arch = "wasm32"))]
+mod housekeeping;
+#[cfg(not(target_arch = "wasm32"))]
+mod instance;
 #[cfg(not(target_arch = "wasm32"))]
 mod ledger_io;
 #[cfg(not(target_arch = "wasm32"))]
+pub(crate) mod mock_exchange;
+#[cfg(not(target_arch = "wasm32"))]
+mod profiles;
+#[cfg(not(target_arch = "wasm32"))]
 mod provider;
 #[cfg(not(target_arch = "wasm32"))]
+mod report_io;
+#[cfg(not(target_arch = "wasm32"))]
 mod results_repo;
 #[cfg(not(target_arch = "wasm32"))]
 mod storage;
+#[cfg(all(target_arch = "wasm32", feature = "wasm-slim"))]
+mod web_fetch;
+
+pub(crate) use {
+    econ_calendar::{EconEvent, EventImpact, events_in_window},
+    external_signals::ExternalSignal,
+};
+
+#[cfg(all(feature = "econ_calendar", not(target_arch = "wasm32")))]
+pub(crate) use econ_calendar::fetch_econ_calendar;
 
 pub use {
     pre_main_async::fetch_pair_data,
@@ -20,19 +60,62 @@ pub use {
 };
 
 #[cfg(not(target_arch = "wasm32"))]
-pub use storage::{MarketDataStorage, SqliteStorage};
+pub use storage::{
+    IntervalMigration, MarketDataStorage, SqliteStorage, downsample_candles, migrate_interval,
+};
+
+#[cfg(target_arch = "wasm32")]
+pub use timeseries::{WasmDemoData, import_kline_files};
 
 #[cfg(target_arch = "wasm32")]
-pub use timeseries::WasmDemoData;
+pub(crate) use {binance::BINANCE_API, timeseries::web_storage};
+
+#[cfg(all(target_arch = "wasm32", feature = "wasm-slim"))]
+pub(crate) use web_fetch::fetch_bytes;
 
 #[cfg(not(target_arch = "wasm32"))]
-pub use results_repo::{RunSummary, SqliteResultsRepository};
+pub use {
+    demo_builder::{DemoBuildSummary, build_demo_cache},
+    instance::{InstanceGuard, InstanceLock, RunningInstance, lock_data_dir},
+    results_repo::{RunSummary, SqliteResultsRepository},
+};
 
 #[cfg(not(target_arch = "wasm32"))]
 pub(crate) use {
+    api_keys::{
+        AccountCheck, ApiCredentials, STATUS_TOKEN_USER, WEBHOOK_TOKEN_USER, check_api_credentials,
+        clear_api_credentials, load_api_credentials, load_local_token, new_local_token,
+        save_api_credentials, save_local_token, spot_rest_client,
+    },
     binance::{BINANCE_API, BINANCE_MAX_PAIRS, BinanceApiConfig},
-    ledger_io::{load_ledger, save_ledger},
+    cache_inspector::{
+        CacheAction, CacheActionOutcome, CacheInventory, apply_cache_action, inspect_kline_cache,
+    },
+    clock::watch_clock_skew,
+    cross_check::{CROSS_CHECK, CrossCheckReport, cross_check_candles, sample_indices},
+    external_signals::{SIGNAL_WEBHOOK, SignalListener, SignalWebhookSettings},
+    housekeeping::{HousekeepingReport, HousekeepingSettings, db_bytes, run_housekeeping},
+    instance::{
+        InstanceOpportunity, InstanceStatus, fetch_instance_status, publish_instance_status,
+        serving_instance_status,
+    },
+    ledger_io::{export_ledger, import_ledger, load_ledger, save_ledger, set_aside_ledger},
+    mock_exchange::{
+        MockProvider, MockSettings, enable_mock_exchange, mock_exchange, spawn_mock_price_stream,
+    },
+    pre_main_async::{append_new_candles, read_pairs_file},
+    profiles::{ProfileRegistry, load_profiles, save_profiles, validate_profile_name},
     provider::{BinanceProvider, MarketDataProvider},
+    report_io::{export_analysis_report, export_csv, export_diagnostics_bundle},
     results_repo::{ResultsRepositoryTrait, TradeResult},
-    timeseries::{GlobalRateLimiter, load_klines},
+    storage::KlineSeries,
+    timeseries::{GlobalRateLimiter, load_kline_at, load_klines, shared_rate_limiter},
+};
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+pub(crate) use {
+    cache_inspector::share_of_bytes,
+    external_signals::{normalize_signal_pair, parse_external_signal, query_token},
+    housekeeping::{is_stale_cache_file, retention_cutoff_ms},
+    ledger_io::{ledger_from_bytes, write_ledger},
 };
@@ -7,14 +7,18 @@ use {
 };
 
 #[cfg(target_arch = "wasm32")]
-use {crate::config::DEMO, crate::data::WasmDemoData};
+use {
+    crate::config::DEMO,
+    crate::data::{WasmDemoData, web_storage},
+};
 
 #[cfg(not(target_arch = "wasm32"))]
 use {
     crate::app::{BASE_INTERVAL, SyncStatus},
+    crate::config::{PERSISTENCE, data_path},
     crate::data::{
-        BINANCE_API, BINANCE_MAX_PAIRS, BinanceProvider, GlobalRateLimiter, MarketDataProvider,
-        MarketDataStorage, SqliteStorage,
+        BINANCE_API, BINANCE_MAX_PAIRS, BinanceProvider, MarketDataProvider, MarketDataStorage,
+        MockProvider, SqliteStorage, mock_exchange, shared_rate_limiter,
     },
     crate::domain::PairInterval,
     crate::models::OhlcvTimeSeries,
@@ -27,34 +31,65 @@ use {
 #[cfg(all(debug_assertions, not(target_arch = "wasm32")))]
 use crate::config::DF;
 
+/// Pairs listed in pairs.txt (upper-cased, `#` comments stripped), or None without the file.
 #[cfg(not(target_arch = "wasm32"))]
-async fn sync_pair(
-    pair: String,
+pub(crate) fn read_pairs_file() -> Option<Vec<String>> {
+    let content = fs::read_to_string(data_path(BINANCE_PAIRS_FILENAME)).ok()?;
+    Some(
+        content
+            .lines()
+            .map(|line| line.split('#').next().unwrap_or("").trim().to_uppercase())
+            .filter(|s| !s.is_empty())
+            .collect(),
+    )
+}
+
+/// Fetches the candles after the newest stored one and stores them. Returns how many arrived.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn append_new_candles(
+    storage: &SqliteStorage,
+    provider: &dyn MarketDataProvider,
+    pair: &str,
     interval_ms: i64,
-    storage: Arc<SqliteStorage>,
-    provider: Arc<BinanceProvider>,
-) -> Result<(OhlcvTimeSeries, usize)> {
+) -> Result<usize> {
     let interval_str = TimeUtils::interval_to_string(interval_ms);
-
-    // Check DB for last candle
-    let last_time = storage.get_last_candle_time(&pair, interval_str).await?;
+    let last_time = storage.get_last_candle_time(pair, interval_str).await?;
     let start_fetch = last_time.map(|t| t + 1);
 
     let new_candles = provider
-        .fetch_candles(&pair, interval_ms, start_fetch)
+        .fetch_candles(pair, interval_ms, start_fetch)
         .await?;
-    let count = new_candles.len();
     if !new_candles.is_empty() {
         storage
-            .insert_candles(&pair, interval_str, &new_candles)
+            .insert_candles(pair, interval_str, &new_candles)
             .await?;
     }
-    let full_history = storage.load_candles(&pair, interval_str, None).await?;
+    Ok(new_candles.len())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn sync_pair(
+    pair: String,
+    interval_ms: i64,
+    storage: Option<Arc<SqliteStorage>>,
+    provider: Arc<dyn MarketDataProvider>,
+) -> Result<(OhlcvTimeSeries, usize)> {
+    let interval_str = TimeUtils::interval_to_string(interval_ms);
     let pair_interval = PairInterval {
-        name: pair,
+        name: pair.clone(),
         interval_ms,
     };
 
+    // No storage (mock exchange): the provider serves the whole history
+    let Some(storage) = storage else {
+        let candles = provider.fetch_candles(&pair, interval_ms, None).await?;
+        let count = candles.len();
+        return Ok((OhlcvTimeSeries::from_candles(pair_interval, candles), count));
+    };
+
+    let count = append_new_candles(&storage, provider.as_ref(), &pair, interval_ms).await?;
+    let full_history = storage.load_candles(&pair, interval_str, None).await?;
+
     Ok((
         OhlcvTimeSeries::from_candles(pair_interval, full_history),
         count,
@@ -70,11 +105,20 @@ pub async fn fetch_pair_data(
     #[cfg(target_arch = "wasm32")]
     {
         let _ = klines_acceptable_age_secs;
-        let _ = args;
         let _ = progress_tx;
 
-        let mut timeseries_data =
-            WasmDemoData::load().expect("failed to retrieve time series data for WASM");
+        let dataset = args.demo_dataset.as_deref();
+        if dataset.is_some_and(|id| id.eq_ignore_ascii_case(DEMO.saved_import_id)) {
+            match web_storage::load_imported_klines().await {
+                Ok(Some(data)) => return (data, "Browser Storage"),
+                Ok(None) => log::warn!("No saved import in browser storage; using demo data"),
+                Err(e) => log::error!("Failed to load saved import: {:#}", e),
+            }
+        }
+
+        let mut timeseries_data = WasmDemoData::load(dataset)
+            .await
+            .expect("failed to retrieve time series data for WASM");
 
         let original_len = timeseries_data.series_data.len();
         if original_len > DEMO.max_pairs {
@@ -89,32 +133,33 @@ pub async fn fetch_pair_data(
         let _ = klines_acceptable_age_secs;
         let _ = args;
 
-        let db_path = "klines.sqlite";
-        let storage = Arc::new(
-            SqliteStorage::new(db_path)
-                .await
-                .expect("Failed to init DB"),
-        );
-        storage
-            .initialize()
-            .await
-            .expect("Failed to init DB schema");
-
-        let safe_limit = (BINANCE_API.limits.weight_limit_minute as f32 * 0.8) as u32;
-        let limiter = GlobalRateLimiter::new(safe_limit);
+        // Mock candles never touch the kline DB
+        let mock = mock_exchange();
+        let (storage, provider): (_, Arc<dyn MarketDataProvider>) = match mock {
+            Some(settings) => (None, Arc::new(MockProvider::new(settings))),
+            None => {
+                let db_path = PERSISTENCE.kline_db();
+                let storage = Arc::new(
+                    SqliteStorage::new(&db_path.to_string_lossy())
+                        .await
+                        .expect("Failed to init DB"),
+                );
+                storage
+                    .initialize()
+                    .await
+                    .expect("Failed to init DB schema");
 
-        let provider = Arc::new(BinanceProvider::new(limiter));
+                let limiter = shared_rate_limiter();
+                (Some(storage), Arc::new(BinanceProvider::new(limiter)))
+            }
+        };
 
-        let mut supply_pairs: Vec<String> = match fs::read_to_string(BINANCE_PAIRS_FILENAME) {
-            Ok(content) => content
-                .lines()
-                .map(|line| line.split('#').next().unwrap_or("").trim().to_uppercase())
-                .filter(|s| !s.is_empty())
-                .collect(),
-            Err(_) => {
+        let mut supply_pairs: Vec<String> = match read_pairs_file() {
+            Some(pairs) => pairs,
+            None => {
                 log::warn!(
                     "{} not found, using default BTC/ETH",
-                    BINANCE_PAIRS_FILENAME,
+                    data_path(BINANCE_PAIRS_FILENAME).display()
                 );
                 vec!["BTCUSDT".to_string(), "ETHUSDT".to_string()]
             }
@@ -203,13 +248,17 @@ pub async fn fetch_pair_data(
 
         let series_data: Vec<_> = results.into_iter().flatten().collect();
 
+        let (name, source) = match mock {
+            Some(_) => ("Mock Exchange Collection", "Mock Exchange"),
+            None => ("SQLite-Synced Collection", "SQLite + Binance"),
+        };
         (
             TimeSeriesCollection {
-                name: "SQLite-Synced Collection".to_string(),
+                name: name.to_string(),
                 version: 1.0,
                 series_data,
             },
-            "SQLite + Binance",
+            source,
         )
     }
 }
@@ -1,4 +1,4 @@
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", not(feature = "wasm-slim")))]
 const DEMO_PRICES_JSON: &str = include_str!(concat!(
     env!("CARGO_MANIFEST_DIR"),
     "/",
@@ -7,12 +7,20 @@ const DEMO_PRICES_JSON: &str = include_str!(concat!(
     crate::demo_prices_file!()
 ));
 
-use crate::app::{Pct, Price};
+#[cfg(all(target_arch = "wasm32", feature = "wasm-slim"))]
+const DEMO_PRICES_URL: &str = concat!(crate::kline_data_dir!(), "/", crate::demo_prices_file!());
+
+use crate::{
+    app::{Pct, Price},
+    shared::RepaintSignal,
+};
 
 #[cfg(not(target_arch = "wasm32"))]
 use {
     crate::{
-        app::{BaseVol, ClosePrice, HighPrice, LowPrice, OpenPrice, QuoteVol},
+        app::{
+            BaseVol, ClosePrice, DurationMs, HighPrice, LowPrice, OpenPrice, QuoteVol, TimestampMs,
+        },
         models::LiveCandle,
     },
     std::{error, sync::mpsc::Sender, thread, time::Duration},
@@ -24,16 +32,13 @@ use {
 use {
     crate::{
         app::BASE_INTERVAL,
-        data::{BINANCE_API, BinanceApiConfig},
-        utils::TimeUtils,
-    },
-    binance_sdk::{
-        config::ConfigurationRestApi,
-        spot::{
-            SpotRestApi,
-            rest_api::{TickerPriceParams, TickerPriceResponse},
+        data::{
+            BINANCE_API, BinanceProvider, MarketDataProvider, mock_exchange, shared_rate_limiter,
+            spawn_mock_price_stream, spot_rest_client, watch_clock_skew,
         },
+        utils::TimeUtils,
     },
+    binance_sdk::spot::rest_api::{TickerPriceParams, TickerPriceResponse},
     futures::StreamExt,
     std::{
         collections::{HashMap, HashSet},
@@ -43,12 +48,24 @@ use {
 };
 
 #[cfg(target_arch = "wasm32")]
-use {serde_json, std::collections::HashMap};
+use {
+    crate::{
+        app::BASE_INTERVAL,
+        data::{BINANCE_API, TimeSeriesCollection},
+        utils::TimeUtils,
+    },
+    serde_json,
+    std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    },
+    wasm_bindgen::{JsCast, closure::Closure},
+    web_sys::{MessageEvent, WebSocket},
+};
 
-#[cfg(all(debug_assertions, not(target_arch = "wasm32")))]
+#[cfg(debug_assertions)]
 use crate::config::DF;
 
-#[cfg(not(target_arch = "wasm32"))]
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum ConnectionStatus {
     Connected,
@@ -64,9 +81,9 @@ pub struct PriceStreamManager {
     subscribed_symbols: Arc<Mutex<Vec<String>>>,
     suspended: Arc<Mutex<bool>>,
     candle_tx: Option<Sender<LiveCandle>>,
+    repaint: RepaintSignal,
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 fn build_combined_stream_url(symbols: &[String]) -> String {
     let interval = TimeUtils::interval_to_string(BASE_INTERVAL.as_millis() as i64);
     let streams: Vec<String> = symbols
@@ -88,6 +105,7 @@ impl PriceStreamManager {
             subscribed_symbols: Arc::new(Mutex::new(Vec::new())),
             suspended: Arc::new(Mutex::new(false)),
             candle_tx: None,
+            repaint: RepaintSignal::default(),
         }
     }
 
@@ -105,13 +123,40 @@ impl PriceStreamManager {
         let status_arc = self.connection_status.clone();
         let suspended_arc = self.suspended.clone();
         let candle_tx = self.candle_tx.clone();
+        let repaint = self.repaint.clone();
         let symbols_for_warmup = symbols_lower.clone();
 
+        // --mock-exchange: synthetic ticks, no network
+        if let Some(settings) = mock_exchange() {
+            {
+                let mut status_map = status_arc.lock().unwrap();
+                for symbol in &symbols_lower {
+                    status_map.insert(symbol.clone(), ConnectionStatus::Connected);
+                }
+            }
+            spawn_mock_price_stream(
+                settings,
+                symbols,
+                BASE_INTERVAL.as_millis() as i64,
+                move |symbol, price| {
+                    prices_arc
+                        .lock()
+                        .unwrap()
+                        .insert(symbol.to_lowercase(), price);
+                    repaint.price_changed(symbol);
+                },
+                suspended_arc,
+                candle_tx,
+            );
+            return;
+        }
+
         #[cfg(not(target_arch = "wasm32"))]
         {
             thread::spawn(move || {
                 let rt = Runtime::new().expect("Failed to create runtime");
                 rt.block_on(async move {
+                    tokio::spawn(watch_clock_skew());
                     warm_up_prices(prices_arc.clone(), &symbols_for_warmup).await;
                     run_combined_price_stream_with_reconnect(
                         &symbols_lower,
@@ -119,6 +164,7 @@ impl PriceStreamManager {
                         status_arc,
                         suspended_arc,
                         candle_tx, // <--- PASSED HERE
+                        repaint,
                     )
                     .await;
                 });
@@ -130,6 +176,10 @@ impl PriceStreamManager {
         self.candle_tx = Some(tx);
     }
 
+    pub(crate) fn set_repaint_signal(&mut self, repaint: RepaintSignal) {
+        self.repaint = repaint;
+    }
+
     pub fn wait_for_health_threshold(&self, threshold_pct: Pct) {
         loop {
             let health = self.connection_health();
@@ -176,10 +226,16 @@ impl Default for PriceStreamManager {
     }
 }
 
+/// Browser build: starts from the bundled price snapshot, then follows Binance's public
+/// combined kline stream over a `web_sys::WebSocket`. Candles are not forwarded, as the
+/// bundled history is not contiguous with live data.
 #[cfg(target_arch = "wasm32")]
 #[derive(Clone)]
 pub struct PriceStreamManager {
-    prices: HashMap<String, Price>,
+    prices: Arc<Mutex<HashMap<String, Price>>>,
+    connection_status: Arc<Mutex<HashMap<String, ConnectionStatus>>>,
+    suspended: Arc<Mutex<bool>>,
+    repaint: RepaintSignal,
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -192,27 +248,204 @@ impl Default for PriceStreamManager {
 #[cfg(target_arch = "wasm32")]
 impl PriceStreamManager {
     pub fn new() -> Self {
-        let parsed: HashMap<String, Price> =
-            serde_json::from_str(DEMO_PRICES_JSON).unwrap_or_default();
-        let mut prices = HashMap::new();
+        let manager = Self {
+            prices: Arc::new(Mutex::new(HashMap::new())),
+            connection_status: Arc::new(Mutex::new(HashMap::new())),
+            suspended: Arc::new(Mutex::new(false)),
+            repaint: RepaintSignal::default(),
+        };
+
+        #[cfg(not(feature = "wasm-slim"))]
+        manager.seed_snapshot(DEMO_PRICES_JSON);
+        #[cfg(feature = "wasm-slim")]
+        {
+            let manager = manager.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                match crate::data::fetch_bytes(DEMO_PRICES_URL).await {
+                    Ok(bytes) => manager.seed_snapshot(&String::from_utf8_lossy(&bytes)),
+                    Err(e) => log::warn!("Demo price snapshot unavailable: {:#}", e),
+                }
+            });
+        }
+        manager
+    }
+
+    /// Bundled snapshot prices; never overwrites a price the stream already delivered.
+    fn seed_snapshot(&self, json: &str) {
+        let parsed: HashMap<String, Price> = serde_json::from_str(json).unwrap_or_default();
+        let mut prices = self.prices.lock().unwrap();
         for (symbol, price) in parsed {
-            prices.insert(symbol.to_lowercase(), price);
+            prices.entry(symbol.to_lowercase()).or_insert(price);
         }
-        Self { prices }
     }
 
     pub fn get_price(&self, symbol: &str) -> Option<Price> {
         let symbol_lower = symbol.to_lowercase();
-        self.prices.get(&symbol_lower).copied()
+        self.prices.lock().unwrap().get(&symbol_lower).copied()
+    }
+
+    pub(crate) fn set_repaint_signal(&mut self, repaint: RepaintSignal) {
+        self.repaint = repaint;
+    }
+
+    /// Pairs without a bundled demo price (e.g. user-imported data) use their last close.
+    pub fn seed_missing_from_last_close(&mut self, timeseries: &TimeSeriesCollection) {
+        let mut prices = self.prices.lock().unwrap();
+        for ts in &timeseries.series_data {
+            if let Some(close) = ts.close_prices.last() {
+                prices
+                    .entry(ts.pair_interval.name.to_lowercase())
+                    .or_insert_with(|| Price::from(*close));
+            }
+        }
     }
 
     pub fn connection_health(&self) -> Pct {
-        Pct::new(100.0)
+        let status_map = self.connection_status.lock().unwrap();
+        if status_map.is_empty() {
+            return Pct::new(0.0);
+        }
+        let connected = status_map
+            .values()
+            .filter(|&&s| s == ConnectionStatus::Connected)
+            .count();
+        Pct::new(connected as f64 / status_map.len() as f64)
     }
 
-    pub fn subscribe_all(&self, _symbols: Vec<String>) {}
+    pub fn subscribe_all(&self, symbols: Vec<String>) {
+        let symbols_lower: Vec<String> = symbols.iter().map(|s| s.to_lowercase()).collect();
+        if symbols_lower.is_empty() {
+            return;
+        }
+        let link = WsLink {
+            url: build_combined_stream_url(&symbols_lower),
+            symbols: symbols_lower,
+            prices: self.prices.clone(),
+            status: self.connection_status.clone(),
+            suspended: self.suspended.clone(),
+            repaint: self.repaint.clone(),
+        };
+        link.connect(BINANCE_API.ws.initial_reconnect_delay_sec);
+    }
+}
+
+/// Everything one browser WebSocket connection (and its reconnects) needs.
+#[cfg(target_arch = "wasm32")]
+#[derive(Clone)]
+struct WsLink {
+    url: String,
+    symbols: Vec<String>,
+    prices: Arc<Mutex<HashMap<String, Price>>>,
+    status: Arc<Mutex<HashMap<String, ConnectionStatus>>>,
+    suspended: Arc<Mutex<bool>>,
+    repaint: RepaintSignal,
 }
 
+#[cfg(target_arch = "wasm32")]
+impl WsLink {
+    fn set_status(&self, status: ConnectionStatus) {
+        let mut status_map = self.status.lock().unwrap();
+        for symbol in &self.symbols {
+            status_map.insert(symbol.clone(), status);
+        }
+    }
+
+    /// Opens the socket; on close, retries after `reconnect_delay` seconds with the same
+    /// exponential backoff as the native stream.
+    fn connect(self, reconnect_delay: u64) {
+        self.set_status(ConnectionStatus::Connecting);
+        #[cfg(debug_assertions)]
+        if DF.log_price_stream_updates {
+            log::info!("Attempting connection to Binance Stream...");
+        }
+        let ws = match WebSocket::new(&self.url) {
+            Ok(ws) => ws,
+            Err(e) => {
+                log::error!("WebSocket connection failed: {:?}", e);
+                self.schedule_reconnect(reconnect_delay);
+                return;
+            }
+        };
+
+        // Handlers live as long as the socket; each reconnect leaks one small set.
+        let link = self.clone();
+        let on_open = Closure::<dyn FnMut()>::new(move || {
+            link.set_status(ConnectionStatus::Connected);
+        });
+        ws.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+        on_open.forget();
+
+        let link = self.clone();
+        let on_message = Closure::<dyn FnMut(MessageEvent)>::new(move |e: MessageEvent| {
+            if let Some(text) = e.data().as_string() {
+                link.handle_message(&text);
+            }
+        });
+        ws.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+        on_message.forget();
+
+        let link = self;
+        let on_close = Closure::<dyn FnMut()>::new(move || {
+            // A link that got through resets the backoff, as on native
+            let was_connected = link
+                .status
+                .lock()
+                .unwrap()
+                .values()
+                .any(|&s| s == ConnectionStatus::Connected);
+            let delay = if was_connected {
+                BINANCE_API.ws.initial_reconnect_delay_sec
+            } else {
+                reconnect_delay
+            };
+            log::warn!("WebSocket closed. Reconnecting in {}s...", delay);
+            link.set_status(ConnectionStatus::Disconnected);
+            link.clone().schedule_reconnect(delay);
+        });
+        ws.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+        on_close.forget();
+    }
+
+    fn schedule_reconnect(self, delay_sec: u64) {
+        let next_delay = (delay_sec * 2).min(BINANCE_API.ws.max_reconnect_delay_sec);
+        let retry = Closure::once_into_js(move || self.connect(next_delay));
+        if let Some(window) = web_sys::window() {
+            let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(
+                retry.unchecked_ref(),
+                (delay_sec * 1000) as i32,
+            );
+        }
+    }
+
+    fn handle_message(&self, text: &str) {
+        let Ok(v) = serde_json::from_str::<serde_json::Value>(text) else {
+            log::warn!("⚠️ Failed to parse WebSocket JSON message");
+            return;
+        };
+        if v["data"]["e"].as_str() != Some("kline") || *self.suspended.lock().unwrap() {
+            return;
+        }
+        let Some(raw) = v["data"]["k"]["c"]
+            .as_str()
+            .and_then(|c| c.parse::<f64>().ok())
+        else {
+            return;
+        };
+        let symbol = v["data"]["s"].as_str().unwrap_or("").to_lowercase();
+        let price = Price::new(raw);
+        self.prices.lock().unwrap().insert(symbol.clone(), price);
+        self.repaint.price_changed(&symbol);
+        #[cfg(debug_assertions)]
+        if DF.log_price_stream_updates {
+            log::info!("[kline-tick] {} -> {:.6}", symbol, price);
+        }
+    }
+}
+
+/// Newest kline open time seen per symbol (as Binance spells it, e.g. "BTCUSDT").
+#[cfg(not(target_arch = "wasm32"))]
+type LastKlineMap = HashMap<String, TimestampMs>;
+
 #[cfg(not(target_arch = "wasm32"))]
 async fn run_combined_price_stream_with_reconnect(
     symbols: &[String],
@@ -220,9 +453,19 @@ async fn run_combined_price_stream_with_reconnect(
     status_arc: Arc<Mutex<HashMap<String, ConnectionStatus>>>,
     suspended_arc: Arc<Mutex<bool>>,
     candle_tx: Option<Sender<LiveCandle>>,
+    repaint: RepaintSignal,
 ) {
     let mut reconnect_delay = BINANCE_API.ws.initial_reconnect_delay_sec;
     let url = build_combined_stream_url(symbols); // Ensure your build_combined_stream_url includes klines now!
+    // Symbols that see no kline before an outage replay from the kline open at subscription
+    let interval_ms = BASE_INTERVAL.as_millis() as i64;
+    let subscribed_open = TimestampMs::new(TimestampMs::now().value() / interval_ms * interval_ms);
+    let mut last_klines: LastKlineMap = symbols
+        .iter()
+        .map(|symbol| (symbol.to_uppercase(), subscribed_open))
+        .collect();
+    let mut connected_once = false;
+    let provider = BinanceProvider::new(shared_rate_limiter());
 
     loop {
         {
@@ -243,6 +486,10 @@ async fn run_combined_price_stream_with_reconnect(
             status_arc.clone(),
             suspended_arc.clone(),
             candle_tx.clone(), // <--- PASS IT DOWN
+            &mut last_klines,
+            &mut connected_once,
+            &provider,
+            &repaint,
         )
         .await
         {
@@ -279,6 +526,10 @@ async fn run_combined_price_stream(
     status_arc: Arc<Mutex<HashMap<String, ConnectionStatus>>>,
     suspended_arc: Arc<Mutex<bool>>,
     candle_tx: Option<Sender<LiveCandle>>,
+    last_klines: &mut LastKlineMap,
+    connected_once: &mut bool,
+    provider: &BinanceProvider,
+    repaint: &RepaintSignal,
 ) -> Result<(), Box<dyn error::Error + Send + Sync>> {
     let (ws_stream, _) = connect_async(url).await?;
 
@@ -289,6 +540,13 @@ async fn run_combined_price_stream(
         }
     }
 
+    // Stream messages queue on the socket meanwhile, so nothing is lost between the two.
+    // The first connection skips this: the startup sync already covers history.
+    if let (Some(tx), true) = (&candle_tx, *connected_once) {
+        replay_missed_klines(provider, last_klines, tx).await;
+    }
+    *connected_once = true;
+
     let (_write, mut read) = ws_stream.split();
 
     while let Some(msg) = read.next().await {
@@ -299,7 +557,7 @@ async fn run_combined_price_stream(
                     if let Some("kline") = v["data"]["e"].as_str() {
                         // SEND TO ENGINE (History/Heartbeat)
                         if let Some(tx) = &candle_tx {
-                            parse_and_send_kline(&v["data"], tx);
+                            parse_and_send_kline(&v["data"], tx, last_klines);
                         }
 
                         if let Some(k) = v["data"].get("k") {
@@ -311,6 +569,7 @@ async fn run_combined_price_stream(
                                             v["data"]["s"].as_str().unwrap_or("").to_lowercase();
                                         let price = Price::new(raw);
                                         prices_arc.lock().unwrap().insert(symbol.clone(), price);
+                                        repaint.price_changed(&symbol);
                                         #[cfg(debug_assertions)]
                                         if DF.log_price_stream_updates {
                                             log::info!("[kline-tick] {} -> {:.6}", symbol, price);
@@ -345,16 +604,7 @@ async fn warm_up_prices(prices_arc: Arc<Mutex<HashMap<String, Price>>>, symbols:
     if DF.log_price_stream_updates {
         log::info!(">>> PriceStream: Warming up price cache via REST API...");
     }
-    let config = BinanceApiConfig::default();
-
-    let rest_conf = ConfigurationRestApi::builder()
-        .timeout(config.timeout_ms)
-        .retries(config.retries)
-        .backoff(config.backoff_ms)
-        .build()
-        .expect("Failed to build Binance REST config");
-
-    let client = SpotRestApi::production(rest_conf);
+    let client = spot_rest_client().expect("Failed to build Binance REST config");
 
     let params = TickerPriceParams {
         symbol: None,
@@ -410,9 +660,45 @@ async fn warm_up_prices(prices_arc: Arc<Mutex<HashMap<String, Price>>>, symbols:
     }
 }
 
+/// After a reconnect, fetches every kline formed since each symbol's last streamed one and
+/// sends them ahead of fresh stream data, so the outage leaves no hole in the live series.
+#[cfg(not(target_arch = "wasm32"))]
+async fn replay_missed_klines(
+    provider: &BinanceProvider,
+    last_klines: &mut LastKlineMap,
+    tx: &Sender<LiveCandle>,
+) {
+    let interval_ms = BASE_INTERVAL.as_millis() as i64;
+    let interval = DurationMs::new(interval_ms);
+    let now = TimestampMs::now();
+    for (symbol, last_open) in last_klines.iter_mut() {
+        match provider
+            .fetch_candles(symbol, interval_ms, Some(last_open.value()))
+            .await
+        {
+            Ok(candles) => {
+                #[cfg(debug_assertions)]
+                if DF.log_price_stream_updates {
+                    log::info!("Replaying {} missed klines for {}", candles.len(), symbol);
+                }
+                for candle in candles {
+                    let is_closed = candle.timestamp_ms + interval <= now;
+                    *last_open = (*last_open).max(candle.timestamp_ms);
+                    let _ = tx.send(LiveCandle::from_candle(symbol, candle, is_closed));
+                }
+            }
+            Err(e) => log::warn!("Kline replay failed for {}: {:#}", symbol, e),
+        }
+    }
+}
+
 // Helper Function
 #[cfg(not(target_arch = "wasm32"))]
-fn parse_and_send_kline(data: &serde_json::Value, tx: &Sender<LiveCandle>) {
+fn parse_and_send_kline(
+    data: &serde_json::Value,
+    tx: &Sender<LiveCandle>,
+    last_klines: &mut LastKlineMap,
+) {
     // "k" is the kline object in the payload
     let k = &data["k"];
     if k.is_null() {
@@ -423,14 +709,16 @@ fn parse_and_send_kline(data: &serde_json::Value, tx: &Sender<LiveCandle>) {
     let close = k["c"].as_str().unwrap_or("0").parse().unwrap_or(0.0);
     let candle = LiveCandle {
         symbol,
-        open_time: k["t"].as_i64().unwrap_or(0),
+        open_time: TimestampMs::new(k["t"].as_i64().unwrap_or(0)),
         open: OpenPrice::new(k["o"].as_str().unwrap_or("0").parse().unwrap_or(0.0)),
         high: HighPrice::new(k["h"].as_str().unwrap_or("0").parse().unwrap_or(0.0)),
         low: LowPrice::new(k["l"].as_str().unwrap_or("0").parse().unwrap_or(0.0)),
         close: ClosePrice::new(close),
         volume: BaseVol::new(k["v"].as_str().unwrap_or("0").parse().unwrap_or(0.0)),
         quote_vol: QuoteVol::new(k["q"].as_str().unwrap_or("0").parse().unwrap_or(0.0)),
+        taker_buy_vol: BaseVol::new(k["V"].as_str().unwrap_or("0").parse().unwrap_or(0.0)),
         is_closed,
     };
+    last_klines.insert(candle.symbol.clone(), candle.open_time);
     let _ = tx.send(candle);
 }
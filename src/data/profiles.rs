@@ -0,0 +1,112 @@
+use {
+    crate::config::{DEFAULT_PROFILE, PERSISTENCE},
+    anyhow::{Result, bail},
+    serde::{Deserialize, Serialize},
+    std::{
+        fs::{self, File},
+        io::{BufReader, BufWriter},
+    },
+};
+
+const PROFILE_NAME_MAX_LEN: usize = 32;
+
+/// Named profiles and the one opened last, kept in the data root. Each profile has its own
+/// settings and ledger; market data is shared.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub(crate) struct ProfileRegistry {
+    pub profiles: Vec<String>,
+    pub last: String,
+}
+
+impl Default for ProfileRegistry {
+    fn default() -> Self {
+        Self {
+            profiles: vec![DEFAULT_PROFILE.to_string()],
+            last: DEFAULT_PROFILE.to_string(),
+        }
+    }
+}
+
+impl ProfileRegistry {
+    /// Registers a new profile. Returns the cleaned-up name.
+    pub(crate) fn create(&mut self, name: &str) -> Result<String> {
+        let name = validate_profile_name(name)?;
+        if self.contains(&name) {
+            bail!("profile '{}' already exists", name);
+        }
+        self.profiles.push(name.clone());
+        Ok(name)
+    }
+
+    /// Marks `name` as the profile to open next launch, registering it if needed. Returns the
+    /// registered spelling (names match case-insensitively, like most file systems).
+    pub(crate) fn select(&mut self, name: &str) -> String {
+        let name = match self.find(name) {
+            Some(existing) => existing.to_string(),
+            None => {
+                self.profiles.push(name.to_string());
+                name.to_string()
+            }
+        };
+        self.last = name.clone();
+        name
+    }
+
+    pub(crate) fn contains(&self, name: &str) -> bool {
+        self.find(name).is_some()
+    }
+
+    fn find(&self, name: &str) -> Option<&str> {
+        self.profiles
+            .iter()
+            .find(|p| p.eq_ignore_ascii_case(name))
+            .map(String::as_str)
+    }
+}
+
+/// Profile names become directory names: letters, digits, `-` and `_` only.
+pub(crate) fn validate_profile_name(name: &str) -> Result<String> {
+    let name = name.trim();
+    if name.is_empty() {
+        bail!("profile name is empty");
+    }
+    if name.len() > PROFILE_NAME_MAX_LEN {
+        bail!(
+            "profile name is longer than {} characters",
+            PROFILE_NAME_MAX_LEN
+        );
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        bail!("profile name may only use letters, digits, '-' and '_'");
+    }
+    Ok(name.to_string())
+}
+
+pub(crate) fn load_profiles() -> ProfileRegistry {
+    let path = PERSISTENCE.profile_registry();
+    if !path.exists() {
+        return ProfileRegistry::default();
+    }
+    match File::open(&path)
+        .map_err(anyhow::Error::from)
+        .and_then(|f| Ok(serde_json::from_reader(BufReader::new(f))?))
+    {
+        Ok(registry) => registry,
+        Err(e) => {
+            log::error!("Ignoring unreadable {}: {:#}", path.display(), e);
+            ProfileRegistry::default()
+        }
+    }
+}
+
+pub(crate) fn save_profiles(registry: &ProfileRegistry) -> Result<()> {
+    let path = PERSISTENCE.profile_registry();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    serde_json::to_writer_pretty(BufWriter::new(File::create(path)?), registry)?;
+    Ok(())
+}
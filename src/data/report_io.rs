@@ -0,0 +1,68 @@
+use {
+    crate::{config::PERSISTENCE, models::AnalysisReport},
+    anyhow::Result,
+    chrono::Utc,
+    std::{
+        fs::{self, File},
+        io::Write,
+        path::PathBuf,
+    },
+    zip::{ZipWriter, write::SimpleFileOptions},
+};
+
+/// Writes the report as JSON (always) and HTML (optional). Returns the JSON path.
+pub(crate) fn export_analysis_report(report: &AnalysisReport, with_html: bool) -> Result<PathBuf> {
+    let dir = PERSISTENCE.export_dir();
+    fs::create_dir_all(&dir)?;
+
+    let short_id = report
+        .opportunity
+        .id
+        .get(..8)
+        .unwrap_or(&report.opportunity.id);
+    let stem = format!(
+        "{}_{}_{}",
+        report.pair_name,
+        report.generated_at.format("%Y%m%d_%H%M%S"),
+        short_id
+    );
+
+    let json_path = dir.join(format!("{}.json", stem));
+    fs::write(&json_path, report.to_json()?)?;
+
+    if with_html {
+        fs::write(dir.join(format!("{}.html", stem)), report.to_html())?;
+    }
+    Ok(json_path)
+}
+
+/// Writes pre-formatted CSV text to the export directory as `<prefix>_<timestamp>.csv`.
+pub(crate) fn export_csv(prefix: &str, csv: &str) -> Result<PathBuf> {
+    let dir = PERSISTENCE.export_dir();
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!(
+        "{}_{}.csv",
+        prefix,
+        Utc::now().format("%Y%m%d_%H%M%S")
+    ));
+    fs::write(&path, csv)?;
+    Ok(path)
+}
+
+/// Zips `(name, contents)` entries into `diagnostics_<timestamp>.zip` in the export directory.
+pub(crate) fn export_diagnostics_bundle(entries: &[(String, String)]) -> Result<PathBuf> {
+    let dir = PERSISTENCE.export_dir();
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!(
+        "diagnostics_{}.zip",
+        Utc::now().format("%Y%m%d_%H%M%S")
+    ));
+    let mut zip = ZipWriter::new(File::create(&path)?);
+    let options = SimpleFileOptions::default();
+    for (name, contents) in entries {
+        zip.start_file(name.as_str(), options)?;
+        zip.write_all(contents.as_bytes())?;
+    }
+    zip.finish()?;
+    Ok(path)
+}
@@ -1,9 +1,14 @@
 use {
     crate::{
-        app::{BaseVol, ClosePrice, HighPrice, LowPrice, OpenPrice, PriceLike, QuoteVol},
+        app::{
+            BaseVol, ClosePrice, DurationMs, HighPrice, LowPrice, OpenPrice, PriceLike, QuoteVol,
+            TimestampMs,
+        },
         domain::Candle,
+        models::merge_candles,
+        utils::TimeUtils,
     },
-    anyhow::Result,
+    anyhow::{Result, anyhow, bail},
     async_trait::async_trait,
     sqlx::{
         ConnectOptions, Pool, QueryBuilder, Row, Sqlite,
@@ -23,6 +28,18 @@ pub trait MarketDataStorage: Send + Sync {
         interval: &str,
         start_time: Option<i64>,
     ) -> Result<Vec<Candle>>;
+    async fn list_pairs(&self, interval: &str) -> Result<Vec<String>>;
+}
+
+/// One (pair, interval) series in the kline database.
+#[derive(Debug, Clone)]
+pub(crate) struct KlineSeries {
+    pub pair: String,
+    pub interval: String,
+    pub candles: u64,
+    /// Open time of the oldest and newest candle
+    pub first_ms: i64,
+    pub last_ms: i64,
 }
 
 pub struct SqliteStorage {
@@ -45,6 +62,94 @@ impl SqliteStorage {
 
         Ok(Self { pool })
     }
+
+    pub(crate) async fn list_intervals(&self) -> Result<Vec<String>> {
+        let rows = sqlx::query("SELECT DISTINCT interval FROM klines ORDER BY interval ASC")
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows.iter().map(|row| row.get("interval")).collect())
+    }
+
+    /// Symbols with candles in any interval.
+    pub(crate) async fn list_symbols(&self) -> Result<Vec<String>> {
+        let rows = sqlx::query("SELECT DISTINCT symbol FROM klines ORDER BY symbol ASC")
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows.iter().map(|row| row.get("symbol")).collect())
+    }
+
+    /// Deletes `interval` candles opened before `cutoff_ms`. Returns the number deleted.
+    pub(crate) async fn delete_candles_before(
+        &self,
+        interval: &str,
+        cutoff_ms: i64,
+    ) -> Result<u64> {
+        let result = sqlx::query("DELETE FROM klines WHERE interval = ? AND open_time < ?")
+            .bind(interval)
+            .bind(cutoff_ms)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Deletes every candle of `pair`, all intervals. Returns the number deleted.
+    pub(crate) async fn delete_pair(&self, pair: &str) -> Result<u64> {
+        let result = sqlx::query("DELETE FROM klines WHERE symbol = ?")
+            .bind(pair)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Deletes the candles of one (pair, interval). Returns the number deleted.
+    pub(crate) async fn delete_series(&self, pair: &str, interval: &str) -> Result<u64> {
+        let result = sqlx::query("DELETE FROM klines WHERE symbol = ? AND interval = ?")
+            .bind(pair)
+            .bind(interval)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Candle count and open-time range of every (pair, interval) stored.
+    pub(crate) async fn list_series(&self) -> Result<Vec<KlineSeries>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT symbol, interval, COUNT(*) AS candles,
+                   MIN(open_time) AS first_time, MAX(open_time) AS last_time
+            FROM klines
+            GROUP BY symbol, interval
+            ORDER BY symbol ASC, interval ASC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        rows.iter()
+            .map(|row| -> Result<KlineSeries> {
+                let candles: i64 = row.try_get("candles")?;
+                Ok(KlineSeries {
+                    pair: row.try_get("symbol")?,
+                    interval: row.try_get("interval")?,
+                    candles: candles as u64,
+                    first_ms: row.try_get("first_time")?,
+                    last_ms: row.try_get("last_time")?,
+                })
+            })
+            .collect()
+    }
+
+    /// Rebuilds the file without free pages and truncates the WAL, returning space to the OS.
+    pub(crate) async fn vacuum(&self) -> Result<()> {
+        sqlx::query("VACUUM").execute(&self.pool).await?;
+        sqlx::query("PRAGMA wal_checkpoint(TRUNCATE)")
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    pub(crate) async fn close(&self) {
+        self.pool.close().await;
+    }
 }
 
 #[async_trait]
@@ -62,6 +167,7 @@ impl MarketDataStorage for SqliteStorage {
                 close REAL NOT NULL,
                 base_vol REAL NOT NULL,
                 quote_vol REAL NOT NULL,
+                taker_buy_vol REAL,
                 PRIMARY KEY (symbol, interval, open_time)
             );
             "#,
@@ -69,6 +175,19 @@ impl MarketDataStorage for SqliteStorage {
         .execute(&self.pool)
         .await?;
 
+        // Databases created before taker volume was stored: add the column (NULL = unknown)
+        let has_taker_column: i64 = sqlx::query(
+            "SELECT COUNT(*) AS n FROM pragma_table_info('klines') WHERE name = 'taker_buy_vol'",
+        )
+        .fetch_one(&self.pool)
+        .await?
+        .try_get("n")?;
+        if has_taker_column == 0 {
+            sqlx::query("ALTER TABLE klines ADD COLUMN taker_buy_vol REAL")
+                .execute(&self.pool)
+                .await?;
+        }
+
         Ok(())
     }
 
@@ -97,19 +216,20 @@ impl MarketDataStorage for SqliteStorage {
 
         for chunk in candles.chunks(3000) {
             let mut query_builder = QueryBuilder::new(
-                "INSERT OR IGNORE INTO klines (symbol, interval, open_time, open, high, low, close, base_vol, quote_vol) ",
+                "INSERT OR IGNORE INTO klines (symbol, interval, open_time, open, high, low, close, base_vol, quote_vol, taker_buy_vol) ",
             );
 
             query_builder.push_values(chunk, |mut b, c| {
                 b.push_bind(pair)
                     .push_bind(interval)
-                    .push_bind(c.timestamp_ms)
+                    .push_bind(c.timestamp_ms.value())
                     .push_bind(c.open_price.value())
                     .push_bind(c.high_price.value())
                     .push_bind(c.low_price.value())
                     .push_bind(c.close_price.value())
                     .push_bind(c.base_asset_volume.value())
-                    .push_bind(c.quote_asset_volume.value());
+                    .push_bind(c.quote_asset_volume.value())
+                    .push_bind(c.taker_buy_base_volume.value());
             });
 
             query_builder.build().execute(&self.pool).await?;
@@ -126,14 +246,14 @@ impl MarketDataStorage for SqliteStorage {
     ) -> Result<Vec<Candle>> {
         let query_str = if start_time.is_some() {
             r#"
-            SELECT open_time, open, high, low, close, base_vol, quote_vol
+            SELECT open_time, open, high, low, close, base_vol, quote_vol, taker_buy_vol
             FROM klines
             WHERE symbol = ? AND interval = ? AND open_time >= ?
             ORDER BY open_time ASC
             "#
         } else {
             r#"
-            SELECT open_time, open, high, low, close, base_vol, quote_vol
+            SELECT open_time, open, high, low, close, base_vol, quote_vol, taker_buy_vol
             FROM klines
             WHERE symbol = ? AND interval = ?
             ORDER BY open_time ASC
@@ -151,18 +271,166 @@ impl MarketDataStorage for SqliteStorage {
         let candles = rows
             .iter()
             .map(|row| {
+                let base_vol: f64 = row.get("base_vol");
+                // Rows stored before taker volume was recorded are treated as neutral flow
+                let taker_buy_vol: Option<f64> = row.get("taker_buy_vol");
                 Candle::new(
                     row.get("open_time"),
                     OpenPrice::new(row.get("open")),
                     HighPrice::new(row.get("high")),
                     LowPrice::new(row.get("low")),
                     ClosePrice::new(row.get("close")),
-                    BaseVol::new(row.get("base_vol")),
+                    BaseVol::new(base_vol),
                     QuoteVol::new(row.get("quote_vol")),
+                    BaseVol::new(taker_buy_vol.unwrap_or(base_vol / 2.0)),
                 )
             })
             .collect();
 
         Ok(candles)
     }
+
+    async fn list_pairs(&self, interval: &str) -> Result<Vec<String>> {
+        let rows = sqlx::query(
+            "SELECT DISTINCT symbol FROM klines WHERE interval = ? ORDER BY symbol ASC",
+        )
+        .bind(interval)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.iter().map(|row| row.get("symbol")).collect())
+    }
+}
+
+/// Outcome of [`migrate_interval`] for one pair.
+#[derive(Debug, Clone)]
+pub struct IntervalMigration {
+    pub pair: String,
+    pub source_candles: usize,
+    pub migrated_candles: usize,
+    /// Target buckets dropped because a source candle was missing (gaps, or the open bucket)
+    pub incomplete_buckets: usize,
+}
+
+/// Aggregates `candles` (sorted, `from_ms` apart) into `to_ms` candles aligned to epoch.
+/// Only complete buckets are kept; returns them with the number of incomplete ones dropped.
+pub fn downsample_candles(
+    candles: &[Candle],
+    from_ms: i64,
+    to_ms: i64,
+) -> Result<(Vec<Candle>, usize)> {
+    if from_ms <= 0 || to_ms <= from_ms || to_ms % from_ms != 0 {
+        bail!(
+            "Cannot downsample {}ms candles into {}ms (target must be a larger multiple)",
+            from_ms,
+            to_ms
+        );
+    }
+    let per_bucket = (to_ms / from_ms) as usize;
+    let mut out = Vec::with_capacity(candles.len() / per_bucket);
+    let mut incomplete = 0;
+
+    for bucket in candles.chunk_by(|a, b| bucket_start(a, to_ms) == bucket_start(b, to_ms)) {
+        let start = bucket_start(&bucket[0], to_ms);
+        let complete = bucket.len() == per_bucket
+            && bucket
+                .iter()
+                .enumerate()
+                .all(|(k, c)| c.timestamp_ms == start + DurationMs::new(k as i64 * from_ms));
+        if !complete {
+            incomplete += 1;
+            continue;
+        }
+        out.push(merge_candles(start, bucket));
+    }
+    Ok((out, incomplete))
+}
+
+/// Checks every `target` candle against the `source` candles it should summarise.
+/// Catches bad writes and pre-existing target rows that disagree with the source.
+pub fn validate_downsampled(
+    source: &[Candle],
+    target: &[Candle],
+    from_ms: i64,
+    to_ms: i64,
+) -> Result<()> {
+    const VOL_TOLERANCE: f64 = 1e-6;
+    let per_bucket = (to_ms / from_ms) as usize;
+    for t in target {
+        if t.timestamp_ms.align_down(to_ms) != t.timestamp_ms {
+            bail!("Candle at {} is not aligned to {}ms", t.timestamp_ms, to_ms);
+        }
+        let first = source.partition_point(|c| c.timestamp_ms < t.timestamp_ms);
+        let last =
+            source.partition_point(|c| c.timestamp_ms < t.timestamp_ms + DurationMs::new(to_ms));
+        let bucket = &source[first..last];
+        if bucket.len() != per_bucket {
+            bail!(
+                "Candle at {} covers {} source candles, expected {}",
+                t.timestamp_ms,
+                bucket.len(),
+                per_bucket
+            );
+        }
+        let expected = merge_candles(t.timestamp_ms, bucket);
+        let vol_ok = |a: f64, b: f64| (a - b).abs() <= VOL_TOLERANCE * a.abs().max(1.0);
+        let matches = t.open_price == expected.open_price
+            && t.high_price == expected.high_price
+            && t.low_price == expected.low_price
+            && t.close_price == expected.close_price
+            && vol_ok(
+                t.base_asset_volume.value(),
+                expected.base_asset_volume.value(),
+            )
+            && vol_ok(
+                t.quote_asset_volume.value(),
+                expected.quote_asset_volume.value(),
+            );
+        if !matches {
+            return Err(anyhow!(
+                "Candle at {} does not match its source candles",
+                t.timestamp_ms
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Builds `to_ms` candles for `pair` from its stored `from_ms` history, writes them and
+/// validates what ends up in storage, so a changed base interval needs no re-download.
+pub async fn migrate_interval<S: MarketDataStorage + ?Sized>(
+    storage: &S,
+    pair: &str,
+    from_ms: i64,
+    to_ms: i64,
+) -> Result<IntervalMigration> {
+    let from_str = TimeUtils::interval_to_string(from_ms);
+    let to_str = TimeUtils::interval_to_string(to_ms);
+    let source = storage.load_candles(pair, from_str, None).await?;
+    let (migrated, incomplete_buckets) = downsample_candles(&source, from_ms, to_ms)?;
+
+    if let Some(first) = migrated.first() {
+        storage.insert_candles(pair, to_str, &migrated).await?;
+        let last_ts = migrated
+            .last()
+            .map_or(first.timestamp_ms, |c| c.timestamp_ms);
+        let stored: Vec<Candle> = storage
+            .load_candles(pair, to_str, Some(first.timestamp_ms.value()))
+            .await?
+            .into_iter()
+            .filter(|c| c.timestamp_ms <= last_ts)
+            .collect();
+        validate_downsampled(&source, &stored, from_ms, to_ms)
+            .map_err(|e| anyhow!("{} {} -> {}: {}", pair, from_str, to_str, e))?;
+    }
+
+    Ok(IntervalMigration {
+        pair: pair.to_string(),
+        source_candles: source.len(),
+        migrated_candles: migrated.len(),
+        incomplete_buckets,
+    })
+}
+
+fn bucket_start(candle: &Candle, to_ms: i64) -> TimestampMs {
+    candle.timestamp_ms.align_down(to_ms)
 }
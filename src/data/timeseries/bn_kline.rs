@@ -1,20 +1,16 @@
 use {
     anyhow::{Result, bail},
     binance_sdk::{
-        config::ConfigurationRestApi,
         errors::{self, ConnectorError as connection_error},
         models::RestApiRateLimit,
-        spot::{
-            SpotRestApi,
-            rest_api::{KlinesIntervalEnum, KlinesItemInner, KlinesParams, RestApi},
-        },
+        spot::rest_api::{KlinesIntervalEnum, KlinesItemInner, KlinesParams, RestApi},
     },
     std::{collections::HashSet, convert::TryFrom, error::Error, fmt},
 };
 
 pub(crate) use crate::{
     app::{BaseVol, ClosePrice, HighPrice, LowPrice, OpenPrice, QuoteVol},
-    data::{BINANCE_API, BinanceApiConfig, GlobalRateLimiter},
+    data::{BINANCE_API, GlobalRateLimiter, spot_rest_client},
     domain::{Candle, PairInterval},
     utils::TimeUtils,
 };
@@ -65,6 +61,7 @@ pub struct BNKline {
     pub close_price: Option<ClosePrice>,
     pub base_asset_volume: Option<BaseVol>,
     pub quote_asset_volume: Option<QuoteVol>,
+    pub taker_buy_base_volume: Option<BaseVol>,
 }
 
 #[derive(Debug)]
@@ -117,6 +114,8 @@ impl TryFrom<Vec<KlinesItemInner>> for BNKline {
         let volume = convert_kline_item_inner_enum_string_to_float(items.next());
         let _ = items.next(); // TEMP this used to be close_time as we don't use it so skip
         let quote_asset_volume = convert_kline_item_inner_enum_string_to_float(items.next());
+        let _ = items.next(); // number of trades
+        let taker_buy_base_volume = convert_kline_item_inner_enum_string_to_float(items.next());
 
         Ok(BNKline {
             open_timestamp_ms,
@@ -126,6 +125,7 @@ impl TryFrom<Vec<KlinesItemInner>> for BNKline {
             close_price: close_price.map(ClosePrice::new),
             base_asset_volume: volume.map(BaseVol::new),
             quote_asset_volume: quote_asset_volume.map(QuoteVol::new),
+            taker_buy_base_volume: taker_buy_base_volume.map(BaseVol::new),
         })
     }
 }
@@ -134,18 +134,6 @@ fn convert_klines(data: Vec<Vec<KlinesItemInner>>) -> Result<Vec<BNKline>, BNKli
     data.into_iter().map(Vec::try_into).collect()
 }
 
-async fn configure_binance_client() -> Result<RestApi, anyhow::Error> {
-    let config = BinanceApiConfig::default();
-    let rest_conf = ConfigurationRestApi::builder()
-        .timeout(config.timeout_ms)
-        .retries(config.retries)
-        .backoff(config.backoff_ms)
-        .build()?;
-    // Create the Spot REST API client
-    let rest_client = SpotRestApi::production(rest_conf);
-    Ok(rest_client)
-}
-
 fn process_new_klines(
     new_klines: Vec<Vec<KlinesItemInner>>,
     limit_klines_returned: i32,
@@ -288,7 +276,7 @@ pub async fn load_klines(
     start_time: Option<i64>,
     limiter: GlobalRateLimiter, // <--- NEW ARGUMENT
 ) -> Result<AllValidKlines4Pair, anyhow::Error> {
-    let rest_client = configure_binance_client().await?;
+    let rest_client = spot_rest_client()?;
 
     let limit_klines_returned: i32 = 1000;
     let mut end_time: Option<i64> = None;
@@ -336,6 +324,39 @@ pub async fn load_klines(
     }
 }
 
+/// The single kline opening at `open_time_ms`, straight from Binance REST (None if Binance has
+/// no such kline). Used to spot-check cached candles; the caller builds `rest_client` once.
+pub async fn load_kline_at(
+    rest_client: &RestApi,
+    pair_interval: &PairInterval,
+    open_time_ms: i64,
+    limiter: &GlobalRateLimiter,
+) -> Result<Option<Candle>, anyhow::Error> {
+    limiter
+        .acquire(
+            BINANCE_API.limits.kline_call_weight,
+            pair_interval.bn_name(),
+        )
+        .await;
+    let params = KlinesParams::builder(
+        pair_interval.bn_name().to_string(),
+        try_interval_from_ms(pair_interval.interval_ms).map_err(anyhow::Error::msg)?,
+    )
+    .limit(1)
+    .start_time(Some(open_time_ms))
+    .end_time(Some(open_time_ms))
+    .build()?;
+    let (_rate_limits, klines) =
+        fetch_binance_klines_with_limits(rest_client, params, pair_interval).await?;
+    let klines = convert_klines(klines).map_err(|e| {
+        anyhow::Error::new(e).context(format!("{} convert_klines failed", pair_interval))
+    })?;
+    Ok(klines
+        .into_iter()
+        .find(|k| k.open_timestamp_ms == open_time_ms)
+        .map(Candle::from))
+}
+
 fn has_duplicate_kline_open_time(klines: &[BNKline]) -> bool {
     let mut seen_ids = HashSet::new();
     for kline in klines {
@@ -348,14 +369,18 @@ fn has_duplicate_kline_open_time(klines: &[BNKline]) -> bool {
 
 impl From<BNKline> for Candle {
     fn from(bn: BNKline) -> Self {
+        let base_vol = bn.base_asset_volume.unwrap_or_default();
         Candle::new(
             bn.open_timestamp_ms,
             bn.open_price.unwrap_or_default(),
             bn.high_price.unwrap_or_default(),
             bn.low_price.unwrap_or_default(),
             bn.close_price.unwrap_or_default(),
-            bn.base_asset_volume.unwrap_or_default(),
+            base_vol,
             bn.quote_asset_volume.unwrap_or_default(),
+            // Missing taker volume is treated as neutral flow
+            bn.taker_buy_base_volume
+                .unwrap_or(BaseVol::new(base_vol.value() / 2.0)),
         )
     }
 }
@@ -1,18 +1,26 @@
 use {
     crate::{
+        app::{
+            BaseVol, ClosePrice, HighPrice, LowPrice, OpenPrice, QuoteVol, TimestampMs, VolRatio,
+        },
         config::{PERSISTENCE, kline_cache_filename},
         data::TimeSeriesCollection,
+        domain::PairInterval,
+        models::OhlcvTimeSeries,
     },
     anyhow::{Context, Result},
     chrono::Utc,
     serde::{Deserialize, Serialize},
     std::{
         fs::File,
-        io::{BufReader, BufWriter},
+        io::BufWriter,
         path::{Path, PathBuf},
     },
 };
 
+/// Last cache version written before taker-buy volume was stored.
+const LEGACY_NO_TAKER_VERSION: f64 = 4.0;
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct CacheFile {
     pub version: f64,
@@ -32,11 +40,21 @@ impl CacheFile {
     }
 
     pub fn load_from_path(path: &Path) -> Result<Self> {
-        let file = File::open(path).context(format!("Failed to open cache file: {:?}", path))?;
-        let mut reader = BufReader::new(file);
-        let cache = bincode::deserialize_from(&mut reader)
-            .context(format!("Failed to deserialize cache: {:?}", path))?;
-        Ok(cache)
+        let bytes =
+            std::fs::read(path).context(format!("Failed to open cache file: {:?}", path))?;
+        Self::from_bytes(&bytes).context(format!("Failed to deserialize cache: {:?}", path))
+    }
+
+    /// Deserializes a cache, upgrading legacy layouts (no taker volume) on the fly.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let header: CacheHeader =
+            bincode::deserialize(bytes).context("Failed to read cache header")?;
+        if header.version <= LEGACY_NO_TAKER_VERSION {
+            let legacy: CacheFileV4 =
+                bincode::deserialize(bytes).context("Failed to deserialize legacy cache")?;
+            return Ok(legacy.into());
+        }
+        bincode::deserialize(bytes).context("Failed to deserialize cache")
     }
 
     pub fn save_to_path(&self, path: &Path) -> Result<()> {
@@ -52,6 +70,82 @@ impl CacheFile {
     }
 
     pub fn default_cache_path(interval_ms: i64) -> PathBuf {
-        PathBuf::from(PERSISTENCE.kline.directory).join(kline_cache_filename(interval_ms))
+        PERSISTENCE
+            .kline_dir()
+            .join(kline_cache_filename(interval_ms))
+    }
+}
+
+#[derive(Deserialize)]
+struct CacheHeader {
+    version: f64,
+}
+
+#[derive(Deserialize)]
+struct CacheFileV4 {
+    version: f64,
+    timestamp_ms: i64,
+    interval_ms: i64,
+    data: TimeSeriesCollectionV4,
+}
+
+#[derive(Deserialize)]
+struct TimeSeriesCollectionV4 {
+    name: String,
+    version: f64,
+    series_data: Vec<OhlcvTimeSeriesV4>,
+}
+
+#[derive(Deserialize)]
+struct OhlcvTimeSeriesV4 {
+    pair_interval: PairInterval,
+    first_kline_timestamp_ms: TimestampMs,
+    timestamps: Vec<TimestampMs>,
+    open_prices: Vec<OpenPrice>,
+    high_prices: Vec<HighPrice>,
+    low_prices: Vec<LowPrice>,
+    close_prices: Vec<ClosePrice>,
+    base_asset_volumes: Vec<BaseVol>,
+    quote_asset_volumes: Vec<QuoteVol>,
+    relative_volumes: Vec<VolRatio>,
+}
+
+impl From<CacheFileV4> for CacheFile {
+    fn from(legacy: CacheFileV4) -> Self {
+        let series_data = legacy
+            .data
+            .series_data
+            .into_iter()
+            .map(|ts| OhlcvTimeSeries {
+                // Unknown taker flow is treated as neutral (half of base volume)
+                taker_buy_volumes: ts
+                    .base_asset_volumes
+                    .iter()
+                    .map(|v| BaseVol::new(v.value() / 2.0))
+                    .collect(),
+                pair_interval: ts.pair_interval,
+                first_kline_timestamp_ms: ts.first_kline_timestamp_ms,
+                timestamps: ts.timestamps,
+                open_prices: ts.open_prices,
+                high_prices: ts.high_prices,
+                low_prices: ts.low_prices,
+                close_prices: ts.close_prices,
+                base_asset_volumes: ts.base_asset_volumes,
+                quote_asset_volumes: ts.quote_asset_volumes,
+                relative_volumes: ts.relative_volumes,
+                aggregates: Default::default(),
+            })
+            .collect();
+
+        Self {
+            version: legacy.version,
+            timestamp_ms: legacy.timestamp_ms,
+            interval_ms: legacy.interval_ms,
+            data: TimeSeriesCollection {
+                name: legacy.data.name,
+                version: legacy.data.version,
+                series_data,
+            },
+        }
     }
 }
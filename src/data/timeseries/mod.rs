@@ -5,12 +5,19 @@ mod cache_file;
 mod rate_limiter;
 mod time_series_collection;
 #[cfg(target_arch = "wasm32")]
+mod user_import;
+#[cfg(target_arch = "wasm32")]
 mod wasm_demo;
 
 pub use {cache_file::CacheFile, time_series_collection::TimeSeriesCollection};
 
 #[cfg(target_arch = "wasm32")]
-pub use wasm_demo::WasmDemoData;
+pub(crate) use wasm_demo::storage as web_storage;
+#[cfg(target_arch = "wasm32")]
+pub use {user_import::import_kline_files, wasm_demo::WasmDemoData};
 
 #[cfg(not(target_arch = "wasm32"))]
-pub use {bn_kline::load_klines, rate_limiter::GlobalRateLimiter};
+pub use {
+    bn_kline::{load_kline_at, load_klines},
+    rate_limiter::{GlobalRateLimiter, shared_rate_limiter},
+};
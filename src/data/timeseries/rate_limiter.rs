@@ -1,11 +1,26 @@
 use {
+    crate::data::BINANCE_API,
     std::{
-        sync::Arc,
+        sync::{Arc, LazyLock},
         time::{Duration, SystemTime, UNIX_EPOCH},
     },
     tokio::sync::Mutex,
 };
 
+/// One budget for every REST caller in the process: Binance counts request weight per IP,
+/// signed or not.
+static SHARED_LIMITER: LazyLock<GlobalRateLimiter> = LazyLock::new(|| {
+    let limits = &BINANCE_API.limits;
+    GlobalRateLimiter::new(
+        (limits.weight_limit_minute as f32 * limits.public_budget_fraction) as u32,
+    )
+});
+
+/// The app-wide limiter.
+pub(crate) fn shared_rate_limiter() -> GlobalRateLimiter {
+    SHARED_LIMITER.clone()
+}
+
 #[derive(Clone)]
 pub struct GlobalRateLimiter {
     inner: Arc<Mutex<InnerLimiter>>,
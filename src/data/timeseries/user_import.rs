@@ -0,0 +1,130 @@
+use {
+    crate::{
+        app::{BASE_INTERVAL, BaseVol, ClosePrice, HighPrice, LowPrice, OpenPrice, QuoteVol},
+        data::{CacheFile, TimeSeriesCollection},
+        domain::{Candle, PairInterval},
+        models::OhlcvTimeSeries,
+    },
+    anyhow::{Context, Result, bail},
+    std::collections::BTreeMap,
+};
+
+/// Binance public dumps switched to microsecond timestamps in 2025.
+const MICROSECOND_TS_THRESHOLD: i64 = 100_000_000_000_000;
+
+/// Builds a collection from user-supplied files: either a kline cache (`.bin`, the app's own
+/// format) or Binance kline CSVs named `<PAIR>-<interval>-...csv` as published on
+/// data.binance.vision. Several CSVs for the same pair are merged.
+pub fn import_kline_files(files: &[(String, Vec<u8>)]) -> Result<TimeSeriesCollection> {
+    let interval_ms = BASE_INTERVAL.as_millis() as i64;
+    let mut series_data = Vec::new();
+    let mut csv_candles: BTreeMap<String, Vec<Candle>> = BTreeMap::new();
+
+    for (name, bytes) in files {
+        if name.to_lowercase().ends_with(".csv") {
+            let pair = pair_from_file_name(name)?;
+            let text = std::str::from_utf8(bytes).context(format!("{} is not UTF-8", name))?;
+            let candles = parse_kline_csv(text).context(format!("Failed to parse {}", name))?;
+            csv_candles.entry(pair).or_default().extend(candles);
+        } else {
+            let cache = CacheFile::from_bytes(bytes).context(format!("Failed to read {}", name))?;
+            if cache.interval_ms != interval_ms {
+                bail!(
+                    "{} holds {}ms klines; expected {}ms",
+                    name,
+                    cache.interval_ms,
+                    interval_ms
+                );
+            }
+            series_data.extend(cache.data.series_data);
+        }
+    }
+
+    for (pair, mut candles) in csv_candles {
+        candles.sort_by_key(|c| c.timestamp_ms);
+        candles.dedup_by_key(|c| c.timestamp_ms);
+        check_interval(&pair, &candles, interval_ms)?;
+        series_data.push(OhlcvTimeSeries::from_candles(
+            PairInterval {
+                name: pair,
+                interval_ms,
+            },
+            candles,
+        ));
+    }
+
+    if series_data.is_empty() {
+        bail!("No kline data found in dropped files");
+    }
+    Ok(TimeSeriesCollection {
+        name: "User Import".to_string(),
+        version: 1.0,
+        series_data,
+    })
+}
+
+fn pair_from_file_name(name: &str) -> Result<String> {
+    let stem = name.rsplit(['/', '\\']).next().unwrap_or(name);
+    let pair = stem.split(['-', '_', '.']).next().unwrap_or_default();
+    if pair.is_empty() || !pair.chars().all(|c| c.is_ascii_alphanumeric()) {
+        bail!("Cannot infer pair from file name {}", name);
+    }
+    Ok(pair.to_uppercase())
+}
+
+/// Columns: open_time, open, high, low, close, volume, close_time, quote_volume, trades,
+/// taker_buy_base, ... A header row is skipped if present.
+fn parse_kline_csv(text: &str) -> Result<Vec<Candle>> {
+    let mut candles = Vec::new();
+    for (line_no, line) in text.lines().enumerate() {
+        let cols: Vec<&str> = line.trim().split(',').collect();
+        if cols.len() < 10 {
+            continue;
+        }
+        let Ok(mut ts) = cols[0].parse::<i64>() else {
+            if line_no == 0 {
+                continue; // header
+            }
+            bail!("Bad timestamp on line {}", line_no + 1);
+        };
+        if ts > MICROSECOND_TS_THRESHOLD {
+            ts /= 1_000;
+        }
+        let num = |i: usize| -> Result<f64> {
+            cols[i].parse::<f64>().context(format!(
+                "Bad number in column {} on line {}",
+                i + 1,
+                line_no + 1
+            ))
+        };
+        candles.push(Candle::new(
+            ts,
+            OpenPrice::new(num(1)?),
+            HighPrice::new(num(2)?),
+            LowPrice::new(num(3)?),
+            ClosePrice::new(num(4)?),
+            BaseVol::new(num(5)?),
+            QuoteVol::new(num(7)?),
+            BaseVol::new(num(9)?),
+        ));
+    }
+    Ok(candles)
+}
+
+/// The engine assumes BASE_INTERVAL candles; reject other intervals up front.
+fn check_interval(pair: &str, candles: &[Candle], interval_ms: i64) -> Result<()> {
+    let step = candles
+        .windows(2)
+        .map(|w| (w[1].timestamp_ms - w[0].timestamp_ms).value())
+        .filter(|d| *d > 0)
+        .min();
+    match step {
+        Some(step) if step != interval_ms => bail!(
+            "{} klines are {}ms apart; expected {}ms",
+            pair,
+            step,
+            interval_ms
+        ),
+        _ => Ok(()),
+    }
+}
@@ -1,40 +0,0 @@
-use {
-    crate::{
-        config::DEMO,
-        data::{CacheFile, TimeSeriesCollection},
-    },
-    anyhow::{Context, Result},
-};
-
-#[cfg(all(debug_assertions, target_arch = "wasm32"))]
-use crate::config::DF;
-
-const DEMO_CACHE_BYTES: &[u8] = include_bytes!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/",
-    crate::kline_data_dir!(),
-    "/",
-    crate::demo_cache_file!()
-));
-
-pub struct WasmDemoData;
-
-impl WasmDemoData {
-    pub fn load() -> Result<TimeSeriesCollection> {
-        #[cfg(debug_assertions)]
-        if DF.log_wasm_demo {
-            log::info!("Loading embedded WASM demo cache...");
-        }
-
-        let cache: CacheFile = bincode::deserialize(DEMO_CACHE_BYTES)
-            .context("Failed to deserialize embedded demo cache")?;
-
-        let mut data = cache.data;
-
-        if data.series_data.len() > DEMO.max_pairs {
-            data.series_data.truncate(DEMO.max_pairs);
-        }
-
-        Ok(data)
-    }
-}
@@ -0,0 +1,73 @@
+pub(crate) mod storage;
+
+use {
+    crate::{
+        config::DEMO,
+        data::{CacheFile, TimeSeriesCollection},
+    },
+    anyhow::{Context, Result},
+};
+
+#[cfg(all(debug_assertions, target_arch = "wasm32"))]
+use crate::config::DF;
+
+#[cfg(feature = "wasm-slim")]
+use crate::data::fetch_bytes;
+
+#[cfg(not(feature = "wasm-slim"))]
+const DEMO_CACHE_BYTES: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/",
+    crate::kline_data_dir!(),
+    "/",
+    crate::demo_cache_file!()
+));
+
+/// `wasm-slim`: the cache is served next to the bundle instead of embedded in it.
+#[cfg(feature = "wasm-slim")]
+const DEMO_CACHE_URL: &str = concat!(crate::kline_data_dir!(), "/", crate::demo_cache_file!());
+
+pub struct WasmDemoData;
+
+impl WasmDemoData {
+    /// Loads the embedded cache, narrowed to `dataset`'s pairs if one is given.
+    pub async fn load(dataset: Option<&str>) -> Result<TimeSeriesCollection> {
+        #[cfg(debug_assertions)]
+        if DF.log_wasm_demo {
+            log::info!(
+                "Loading embedded WASM demo cache (dataset {:?})...",
+                dataset
+            );
+        }
+
+        // Downloaded once per app version, then read back from IndexedDB
+        #[cfg(feature = "wasm-slim")]
+        let bytes = match storage::load_demo_bundle().await {
+            Some(bytes) => bytes,
+            None => {
+                let bytes = fetch_bytes(DEMO_CACHE_URL)
+                    .await
+                    .context("Failed to download demo cache")?;
+                storage::save_demo_bundle(bytes.clone());
+                bytes
+            }
+        };
+        #[cfg(not(feature = "wasm-slim"))]
+        let bytes = DEMO_CACHE_BYTES;
+
+        let cache = CacheFile::from_bytes(&bytes[..])
+            .context("Failed to deserialize embedded demo cache")?;
+
+        let mut data = cache.data;
+        if let Some(ds) = dataset.and_then(|id| DEMO.dataset(id)) {
+            data.series_data
+                .retain(|ts| ds.pairs.contains(&ts.pair_interval.name.as_str()));
+        }
+
+        if data.series_data.len() > DEMO.max_pairs {
+            data.series_data.truncate(DEMO.max_pairs);
+        }
+
+        Ok(data)
+    }
+}
@@ -0,0 +1,219 @@
+use {
+    crate::{
+        app::BASE_INTERVAL,
+        config::PERSISTENCE,
+        data::{CacheFile, TimeSeriesCollection},
+    },
+    anyhow::{Context, Result, anyhow},
+    js_sys::{Promise, Uint8Array},
+    wasm_bindgen::{JsCast, JsValue, closure::Closure},
+    wasm_bindgen_futures::JsFuture,
+    web_sys::{Event, IdbDatabase, IdbOpenDbRequest, IdbRequest, IdbTransactionMode},
+};
+
+#[cfg(debug_assertions)]
+use crate::config::DF;
+
+const DB_NAME: &str = "zone_sniper";
+const DB_VERSION: u32 = 1;
+
+const STORE_KLINES: &str = "klines";
+const STORE_STATE: &str = "state";
+
+const IMPORTED_KLINES_KEY: &str = "imported";
+const APP_STATE_KEY: &str = "app";
+/// `wasm-slim` demo cache, and the app version that downloaded it
+#[cfg(feature = "wasm-slim")]
+const DEMO_BUNDLE_KEY: &str = "demo";
+#[cfg(feature = "wasm-slim")]
+const DEMO_BUNDLE_VERSION_KEY: &str = "demo_version";
+
+/// Thin async wrapper over the browser's IndexedDB. Values are stored as raw bytes.
+pub struct WebStore {
+    db: IdbDatabase,
+}
+
+impl WebStore {
+    pub async fn open() -> Result<Self> {
+        let factory = web_sys::window()
+            .context("No window")?
+            .indexed_db()
+            .map_err(js_err)?
+            .context("IndexedDB unavailable")?;
+        let request = factory.open_with_u32(DB_NAME, DB_VERSION).map_err(js_err)?;
+
+        let on_upgrade = Closure::once_into_js(move |e: Event| {
+            let db = e
+                .target()
+                .and_then(|t| t.dyn_into::<IdbOpenDbRequest>().ok())
+                .and_then(|r| r.result().ok())
+                .and_then(|r| r.dyn_into::<IdbDatabase>().ok());
+            if let Some(db) = db {
+                for store in [STORE_KLINES, STORE_STATE] {
+                    if !db.object_store_names().contains(store) {
+                        let _ = db.create_object_store(store);
+                    }
+                }
+            }
+        });
+        request.set_onupgradeneeded(Some(on_upgrade.unchecked_ref()));
+
+        let db = await_request(&request)
+            .await?
+            .dyn_into::<IdbDatabase>()
+            .map_err(js_err)?;
+        Ok(Self { db })
+    }
+
+    pub async fn get(&self, store: &str, key: &str) -> Result<Option<Vec<u8>>> {
+        let request = self
+            .db
+            .transaction_with_str(store)
+            .and_then(|tx| tx.object_store(store))
+            .and_then(|s| s.get(&JsValue::from_str(key)))
+            .map_err(js_err)?;
+        let value = await_request(&request).await?;
+        if value.is_undefined() || value.is_null() {
+            return Ok(None);
+        }
+        Ok(Some(Uint8Array::new(&value).to_vec()))
+    }
+
+    pub async fn put(&self, store: &str, key: &str, bytes: &[u8]) -> Result<()> {
+        let request = self
+            .db
+            .transaction_with_str_and_mode(store, IdbTransactionMode::Readwrite)
+            .and_then(|tx| tx.object_store(store))
+            .and_then(|s| s.put_with_key(&Uint8Array::from(bytes), &JsValue::from_str(key)))
+            .map_err(js_err)?;
+        await_request(&request).await.map(|_| ())
+    }
+}
+
+/// Last collection imported via drag & drop, if one was saved on a previous visit.
+pub async fn load_imported_klines() -> Result<Option<TimeSeriesCollection>> {
+    let Some(bytes) = WebStore::open()
+        .await?
+        .get(STORE_KLINES, IMPORTED_KLINES_KEY)
+        .await?
+    else {
+        return Ok(None);
+    };
+    let cache = CacheFile::from_bytes(&bytes).context("Stored import is unreadable")?;
+    Ok(Some(cache.data))
+}
+
+pub async fn has_imported_klines() -> bool {
+    match WebStore::open().await {
+        Ok(store) => matches!(
+            store.get(STORE_KLINES, IMPORTED_KLINES_KEY).await,
+            Ok(Some(_))
+        ),
+        Err(_) => false,
+    }
+}
+
+/// Fire-and-forget: stores an imported collection in the app's own cache format.
+pub fn save_imported_klines(data: &TimeSeriesCollection) {
+    let cache = CacheFile::new(
+        BASE_INTERVAL.as_millis() as i64,
+        data.clone(),
+        PERSISTENCE.kline.version,
+    );
+    let bytes = match bincode::serialize(&cache) {
+        Ok(b) => b,
+        Err(e) => {
+            log::error!("Failed to serialize imported klines: {}", e);
+            return;
+        }
+    };
+    spawn_put(STORE_KLINES, IMPORTED_KLINES_KEY, bytes);
+}
+
+/// `wasm-slim`: the demo cache a previous visit downloaded, unless a newer build has shipped.
+#[cfg(feature = "wasm-slim")]
+pub async fn load_demo_bundle() -> Option<Vec<u8>> {
+    let store = WebStore::open().await.ok()?;
+    let version = store
+        .get(STORE_KLINES, DEMO_BUNDLE_VERSION_KEY)
+        .await
+        .ok()??;
+    if version != env!("CARGO_PKG_VERSION").as_bytes() {
+        return None;
+    }
+    store
+        .get(STORE_KLINES, DEMO_BUNDLE_KEY)
+        .await
+        .ok()
+        .flatten()
+}
+
+/// Fire-and-forget: keeps a downloaded demo cache for the next visit. The version is written
+/// after the bundle, so a failed write never pairs an old bundle with the new version.
+#[cfg(feature = "wasm-slim")]
+pub fn save_demo_bundle(bytes: Vec<u8>) {
+    wasm_bindgen_futures::spawn_local(async move {
+        let result = async {
+            let store = WebStore::open().await?;
+            store.put(STORE_KLINES, DEMO_BUNDLE_KEY, &bytes).await?;
+            store
+                .put(
+                    STORE_KLINES,
+                    DEMO_BUNDLE_VERSION_KEY,
+                    env!("CARGO_PKG_VERSION").as_bytes(),
+                )
+                .await
+        }
+        .await;
+        if let Err(e) = result {
+            log::error!("IndexedDB: demo cache not kept: {:#}", e);
+        }
+    });
+}
+
+/// Serialized `App` saved alongside egui's own (localStorage) persistence.
+pub async fn load_app_state() -> Option<Vec<u8>> {
+    let store = WebStore::open().await.ok()?;
+    store.get(STORE_STATE, APP_STATE_KEY).await.ok().flatten()
+}
+
+pub fn save_app_state(bytes: Vec<u8>) {
+    spawn_put(STORE_STATE, APP_STATE_KEY, bytes);
+}
+
+fn spawn_put(store: &'static str, key: &'static str, bytes: Vec<u8>) {
+    wasm_bindgen_futures::spawn_local(async move {
+        let result = async { WebStore::open().await?.put(store, key, &bytes).await }.await;
+        match result {
+            Ok(()) =>
+            {
+                #[cfg(debug_assertions)]
+                if DF.log_wasm_demo {
+                    log::info!("IndexedDB: saved {}/{} ({} bytes)", store, key, bytes.len());
+                }
+            }
+            Err(e) => log::error!("IndexedDB write {}/{} failed: {:#}", store, key, e),
+        }
+    });
+}
+
+/// Resolves with the request's result on `success`, rejects on `error`.
+async fn await_request(request: &IdbRequest) -> Result<JsValue> {
+    let promise = Promise::new(&mut |resolve, reject| {
+        let req = request.clone();
+        let on_success = Closure::once_into_js(move |_: Event| {
+            let _ = resolve.call1(&JsValue::NULL, &req.result().unwrap_or(JsValue::UNDEFINED));
+        });
+        let on_error = Closure::once_into_js(move |e: Event| {
+            let _ = reject.call1(&JsValue::NULL, &e);
+        });
+        request.set_onsuccess(Some(on_success.unchecked_ref()));
+        request.set_onerror(Some(on_error.unchecked_ref()));
+    });
+    JsFuture::from(promise).await.map_err(js_err)
+}
+
+/// A JS exception or error event as an `anyhow` error.
+pub(crate) fn js_err(e: JsValue) -> anyhow::Error {
+    anyhow!("{:?}", e)
+}
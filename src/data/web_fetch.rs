@@ -0,0 +1,25 @@
+use {
+    crate::data::web_storage::js_err,
+    anyhow::{Context, Result, anyhow},
+    js_sys::Uint8Array,
+    wasm_bindgen::JsCast,
+    wasm_bindgen_futures::JsFuture,
+    web_sys::Response,
+};
+
+/// Fetches a static asset served next to the web bundle (`wasm-slim` builds only).
+pub(crate) async fn fetch_bytes(url: &str) -> Result<Vec<u8>> {
+    let window = web_sys::window().context("No window")?;
+    let response: Response = JsFuture::from(window.fetch_with_str(url))
+        .await
+        .map_err(js_err)?
+        .dyn_into()
+        .map_err(js_err)?;
+    if !response.ok() {
+        return Err(anyhow!("GET {} returned HTTP {}", url, response.status()));
+    }
+    let buffer = JsFuture::from(response.array_buffer().map_err(js_err)?)
+        .await
+        .map_err(js_err)?;
+    Ok(Uint8Array::new(&buffer).to_vec())
+}
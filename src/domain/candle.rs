@@ -1,4 +1,6 @@
-use crate::app::{BaseVol, ClosePrice, HighPrice, LowPrice, OpenPrice, Price, PriceLike, QuoteVol};
+use crate::app::{
+    BaseVol, ClosePrice, HighPrice, LowPrice, OpenPrice, Price, PriceLike, QuoteVol, TimestampMs,
+};
 
 #[derive(Debug, PartialEq)]
 pub(crate) enum CandleType {
@@ -6,8 +8,9 @@ pub(crate) enum CandleType {
     Bearish,
 }
 
+#[derive(Debug, Clone, Copy)]
 pub struct Candle {
-    pub timestamp_ms: i64,
+    pub timestamp_ms: TimestampMs,
 
     pub open_price: OpenPrice,
     pub high_price: HighPrice,
@@ -16,29 +19,38 @@ pub struct Candle {
 
     pub base_asset_volume: BaseVol,
     pub quote_asset_volume: QuoteVol,
+    /// Base volume bought by market (taker) orders
+    pub taker_buy_base_volume: BaseVol,
 }
 
 impl Candle {
     pub fn new(
-        timestamp_ms: i64,
+        timestamp_ms: impl Into<TimestampMs>,
         open: OpenPrice,
         high: HighPrice,
         low: LowPrice,
         close: ClosePrice,
         base_vol: BaseVol,
         quote_vol: QuoteVol,
+        taker_buy_base_vol: BaseVol,
     ) -> Self {
         Candle {
-            timestamp_ms,
+            timestamp_ms: timestamp_ms.into(),
             open_price: open,
             high_price: high,
             low_price: low,
             close_price: close,
             base_asset_volume: base_vol,
             quote_asset_volume: quote_vol,
+            taker_buy_base_volume: taker_buy_base_vol,
         }
     }
 
+    /// Net aggressor flow: taker buys minus taker sells (base units).
+    pub(crate) fn taker_delta(&self) -> f64 {
+        2.0 * self.taker_buy_base_volume.value() - self.base_asset_volume.value()
+    }
+
     fn get_type(&self) -> CandleType {
         if Price::from(self.close_price) >= Price::from(self.open_price) {
             CandleType::Bullish
@@ -1,10 +1,12 @@
 mod candle;
+mod pair_category;
 mod pair_interval;
 mod price_horizon;
 
 pub(crate) use {
     candle::Candle,
-    price_horizon::{auto_select_ranges, calc_price_range},
+    pair_category::PairCategory,
+    price_horizon::{HorizonProfile, auto_select_ranges, calc_price_range},
 };
 
 pub use pair_interval::PairInterval;
@@ -0,0 +1,183 @@
+use {
+    crate::domain::PairInterval,
+    serde::{Deserialize, Serialize},
+    std::{collections::HashMap, fmt, sync::LazyLock},
+    strum_macros::EnumIter,
+};
+
+/// Bundled sector of well-known base assets. Anything missing is `Other`.
+const CATEGORY_TAGS: &[(&str, PairCategory)] = &[
+    // Layer 1
+    ("BTC", PairCategory::Layer1),
+    ("ETH", PairCategory::Layer1),
+    ("SOL", PairCategory::Layer1),
+    ("BNB", PairCategory::Layer1),
+    ("ADA", PairCategory::Layer1),
+    ("AVAX", PairCategory::Layer1),
+    ("DOT", PairCategory::Layer1),
+    ("SUI", PairCategory::Layer1),
+    ("TRX", PairCategory::Layer1),
+    ("NEAR", PairCategory::Layer1),
+    ("SEI", PairCategory::Layer1),
+    ("APT", PairCategory::Layer1),
+    ("ATOM", PairCategory::Layer1),
+    ("TON", PairCategory::Layer1),
+    ("ALGO", PairCategory::Layer1),
+    ("ICP", PairCategory::Layer1),
+    ("HBAR", PairCategory::Layer1),
+    ("ETC", PairCategory::Layer1),
+    ("LTC", PairCategory::Layer1),
+    ("BCH", PairCategory::Layer1),
+    ("LUNA", PairCategory::Layer1),
+    ("LUNC", PairCategory::Layer1),
+    ("FOGO", PairCategory::Layer1),
+    ("SOMI", PairCategory::Layer1),
+    ("XPL", PairCategory::Layer1),
+    ("WBTC", PairCategory::Layer1),
+    // Layer 2
+    ("ARB", PairCategory::Layer2),
+    ("OP", PairCategory::Layer2),
+    ("POL", PairCategory::Layer2),
+    ("STRK", PairCategory::Layer2),
+    ("ZK", PairCategory::Layer2),
+    ("MNT", PairCategory::Layer2),
+    ("IMX", PairCategory::Layer2),
+    ("HEMI", PairCategory::Layer2),
+    // DeFi
+    ("UNI", PairCategory::DeFi),
+    ("AAVE", PairCategory::DeFi),
+    ("CRV", PairCategory::DeFi),
+    ("LDO", PairCategory::DeFi),
+    ("PENDLE", PairCategory::DeFi),
+    ("JUP", PairCategory::DeFi),
+    ("RAY", PairCategory::DeFi),
+    ("CAKE", PairCategory::DeFi),
+    ("COMP", PairCategory::DeFi),
+    ("DYDX", PairCategory::DeFi),
+    ("GMX", PairCategory::DeFi),
+    ("ENA", PairCategory::DeFi),
+    ("ASTER", PairCategory::DeFi),
+    ("MORPHO", PairCategory::DeFi),
+    ("DOLO", PairCategory::DeFi),
+    ("MITO", PairCategory::DeFi),
+    ("WLFI", PairCategory::DeFi),
+    ("BANK", PairCategory::DeFi),
+    ("BARD", PairCategory::DeFi),
+    ("AVNT", PairCategory::DeFi),
+    ("MET", PairCategory::DeFi),
+    ("MMT", PairCategory::DeFi),
+    // Meme
+    ("DOGE", PairCategory::Meme),
+    ("SHIB", PairCategory::Meme),
+    ("PEPE", PairCategory::Meme),
+    ("PENGU", PairCategory::Meme),
+    ("BONK", PairCategory::Meme),
+    ("WIF", PairCategory::Meme),
+    ("FLOKI", PairCategory::Meme),
+    ("TRUMP", PairCategory::Meme),
+    ("BOME", PairCategory::Meme),
+    ("PUMP", PairCategory::Meme),
+    // AI
+    ("FET", PairCategory::Ai),
+    ("TAO", PairCategory::Ai),
+    ("RENDER", PairCategory::Ai),
+    ("WLD", PairCategory::Ai),
+    ("VIRTUAL", PairCategory::Ai),
+    ("0G", PairCategory::Ai),
+    ("KITE", PairCategory::Ai),
+    ("ALLO", PairCategory::Ai),
+    ("SAPIEN", PairCategory::Ai),
+    ("OPEN", PairCategory::Ai),
+    // Infrastructure
+    ("LINK", PairCategory::Infrastructure),
+    ("PYTH", PairCategory::Infrastructure),
+    ("GRT", PairCategory::Infrastructure),
+    ("FIL", PairCategory::Infrastructure),
+    ("AR", PairCategory::Infrastructure),
+    ("TIA", PairCategory::Infrastructure),
+    ("AT", PairCategory::Infrastructure),
+    ("ZKC", PairCategory::Infrastructure),
+    ("ZBT", PairCategory::Infrastructure),
+    // Payments
+    ("XRP", PairCategory::Payments),
+    ("XLM", PairCategory::Payments),
+    // Privacy
+    ("ZEC", PairCategory::Privacy),
+    ("XMR", PairCategory::Privacy),
+    ("DASH", PairCategory::Privacy),
+    // Gaming
+    ("AXS", PairCategory::Gaming),
+    ("SAND", PairCategory::Gaming),
+    ("MANA", PairCategory::Gaming),
+    ("GALA", PairCategory::Gaming),
+    // Real-world assets
+    ("PAXG", PairCategory::Rwa),
+    ("XAUT", PairCategory::Rwa),
+    ("ONDO", PairCategory::Rwa),
+    // Stablecoins
+    ("USDT", PairCategory::Stable),
+    ("USDC", PairCategory::Stable),
+    ("FDUSD", PairCategory::Stable),
+    ("USD1", PairCategory::Stable),
+    ("USDE", PairCategory::Stable),
+    ("DAI", PairCategory::Stable),
+    ("TUSD", PairCategory::Stable),
+];
+
+/// [`CATEGORY_TAGS`] by base asset; sector sorting looks this up on every comparison.
+static CATEGORY_BY_BASE: LazyLock<HashMap<&'static str, PairCategory>> =
+    LazyLock::new(|| CATEGORY_TAGS.iter().copied().collect());
+
+/// Sector of a pair's base asset. Setups often cluster by sector during rotations.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, EnumIter,
+)]
+pub(crate) enum PairCategory {
+    Layer1,
+    Layer2,
+    DeFi,
+    Meme,
+    Ai,
+    Infrastructure,
+    Payments,
+    Privacy,
+    Gaming,
+    Rwa,
+    Stable,
+    Other,
+}
+
+impl PairCategory {
+    /// Looks up the base asset of `pair` (e.g. `PEPE` in `PEPEUSDT`).
+    pub(crate) fn of_pair(pair: &str) -> Self {
+        PairInterval::get_base(pair).map_or(Self::Other, Self::of_base)
+    }
+
+    fn of_base(base: &str) -> Self {
+        CATEGORY_BY_BASE.get(base).copied().unwrap_or(Self::Other)
+    }
+
+    /// Short tag shown next to pair names.
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            Self::Layer1 => "L1",
+            Self::Layer2 => "L2",
+            Self::DeFi => "DeFi",
+            Self::Meme => "Meme",
+            Self::Ai => "AI",
+            Self::Infrastructure => "Infra",
+            Self::Payments => "Payments",
+            Self::Privacy => "Privacy",
+            Self::Gaming => "Gaming",
+            Self::Rwa => "RWA",
+            Self::Stable => "Stable",
+            Self::Other => "Other",
+        }
+    }
+}
+
+impl fmt::Display for PairCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
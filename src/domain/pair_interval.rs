@@ -3,8 +3,17 @@ const BINANCE_QUOTE_ASSETS: &[&str] = &[
     "BRL", "ARS", "MXN",
 ];
 
+/// Quote assets treated as worth one US dollar.
+const USD_QUOTE_ASSETS: &[&str] = &["USDT", "USDC", "FDUSD", "USD", "USD1"];
+
+/// Market used to price other quote assets in dollars.
+const USD_REFERENCE_QUOTE: &str = "USDT";
+
 use {
-    crate::utils::TimeUtils,
+    crate::{
+        app::{Price, PriceLike},
+        utils::TimeUtils,
+    },
     serde::{Deserialize, Serialize},
 };
 
@@ -27,6 +36,27 @@ impl PairInterval {
             .copied()
     }
 
+    /// Dollars per unit of `pair`'s quote asset, e.g. the BTCUSDT price for ADABTC. Looks up
+    /// `{quote}USDT` first, then the inverse `USDT{quote}` for fiat quotes such as TRY.
+    /// None when the quote is unknown or neither market has a price.
+    pub(crate) fn quote_usd_rate(
+        text: &str,
+        price_of: impl Fn(&str) -> Option<Price>,
+    ) -> Option<f64> {
+        let quote = Self::get_quote(text)?;
+        if USD_QUOTE_ASSETS.contains(&quote) {
+            return Some(1.0);
+        }
+        let direct = price_of(&format!("{quote}{USD_REFERENCE_QUOTE}"))
+            .filter(|p| p.is_positive())
+            .map(|p| p.value());
+        direct.or_else(|| {
+            price_of(&format!("{USD_REFERENCE_QUOTE}{quote}"))
+                .filter(|p| p.is_positive())
+                .map(|p| 1.0 / p.value())
+        })
+    }
+
     // The name we pass into the Binance API (not necessarily display name)
     #[cfg(not(target_arch = "wasm32"))]
     pub(crate) fn bn_name(&self) -> &str {
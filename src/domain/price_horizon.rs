@@ -1,5 +1,5 @@
 use crate::{
-    app::{HighPrice, LowPrice, PhPct, Price},
+    app::{HighPrice, LowPrice, PhPct, Price, TimestampMs},
     models::OhlcvTimeSeries,
 };
 
@@ -56,3 +56,112 @@ fn find_relevant_ranges(
     }
     ranges
 }
+
+/// Sorted candle extremes per pair, so "how many candles fall inside this PH" is two binary
+/// searches instead of a full history scan. Extended incrementally as candles arrive.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct HorizonProfile {
+    sorted_lows: Vec<f64>,
+    sorted_highs: Vec<f64>,
+    /// Candles `[0, indexed)` are in the sorted vectors. The newest candle may still be
+    /// updating live, so it is never indexed.
+    indexed: usize,
+    /// Open times of the first and last indexed candles, to spot a replaced series
+    anchors: Option<(TimestampMs, TimestampMs)>,
+}
+
+/// Beyond this many new candles a full re-sort beats sorted inserts.
+const PROFILE_BULK_THRESHOLD: usize = 64;
+
+impl HorizonProfile {
+    /// Brings the profile up to date with `timeseries` (no-op if nothing new has closed).
+    pub(crate) fn sync(&mut self, timeseries: &OhlcvTimeSeries) {
+        let closed = timeseries.klines().saturating_sub(1);
+        if closed < self.indexed || Self::anchors_of(timeseries, self.indexed) != self.anchors {
+            // Series was replaced, truncated or extended backwards
+            *self = Self::default();
+        }
+        let new_count = closed - self.indexed;
+        if new_count == 0 {
+            return;
+        }
+
+        if new_count > PROFILE_BULK_THRESHOLD {
+            for i in self.indexed..closed {
+                let candle = timeseries.get_candle(i);
+                self.sorted_lows.push(candle.low_price.value());
+                self.sorted_highs.push(candle.high_price.value());
+            }
+            self.sorted_lows.sort_by(f64::total_cmp);
+            self.sorted_highs.sort_by(f64::total_cmp);
+        } else {
+            for i in self.indexed..closed {
+                let candle = timeseries.get_candle(i);
+                insert_sorted(&mut self.sorted_lows, candle.low_price.value());
+                insert_sorted(&mut self.sorted_highs, candle.high_price.value());
+            }
+        }
+        self.indexed = closed;
+        self.anchors = Self::anchors_of(timeseries, closed);
+    }
+
+    fn anchors_of(
+        timeseries: &OhlcvTimeSeries,
+        indexed: usize,
+    ) -> Option<(TimestampMs, TimestampMs)> {
+        let last = timeseries.timestamps.get(indexed.checked_sub(1)?)?;
+        Some((*timeseries.timestamps.first()?, *last))
+    }
+
+    /// Same count as summing [`auto_select_ranges`], without scanning history.
+    pub(crate) fn candle_count(
+        &self,
+        timeseries: &OhlcvTimeSeries,
+        current_price: Price,
+        ph_pct: PhPct,
+    ) -> usize {
+        let (price_min, price_max) = calc_price_range(current_price, ph_pct);
+        let (min, max) = (price_min.value(), price_max.value());
+
+        // A candle is outside iff it lies wholly above or wholly below the range
+        let above = self.sorted_lows.len() - self.sorted_lows.partition_point(|&low| low <= max);
+        let below = self.sorted_highs.partition_point(|&high| high < min);
+        let indexed_inside = self.indexed - above - below;
+
+        let tail_inside = timeseries
+            .candles(self.indexed..timeseries.klines())
+            .filter(|c| c.low_price <= price_max && c.high_price >= price_min)
+            .count();
+        indexed_inside + tail_inside
+    }
+
+    /// Smallest PH (up to `max_ph`) whose range still holds `min_candles`, or None if even
+    /// `max_ph` falls short. Bisects on [`Self::candle_count`], which only grows with PH.
+    pub(crate) fn min_viable_ph(
+        &self,
+        timeseries: &OhlcvTimeSeries,
+        current_price: Price,
+        min_candles: usize,
+        max_ph: PhPct,
+    ) -> Option<PhPct> {
+        if self.candle_count(timeseries, current_price, max_ph) < min_candles {
+            return None;
+        }
+        let (mut lo, mut hi) = (0.0, max_ph.value());
+        while hi - lo > 1e-5 {
+            let mid = (lo + hi) / 2.0;
+            if self.candle_count(timeseries, current_price, PhPct::new(mid)) >= min_candles {
+                hi = mid;
+            } else {
+                lo = mid;
+            }
+        }
+        // Round up to the 0.01% the UI displays; rounding up keeps the count viable
+        Some(PhPct::new(((hi * 1e4).ceil() / 1e4).min(max_ph.value())))
+    }
+}
+
+fn insert_sorted(values: &mut Vec<f64>, value: f64) {
+    let idx = values.partition_point(|&v| v < value);
+    values.insert(idx, value);
+}
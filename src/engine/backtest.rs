@@ -17,11 +17,13 @@ use {
     crate::{
         app::{Pct, PhPct, Price, PriceLike},
         data::{ResultsRepositoryTrait, TradeResult},
-        engine::{StationId, run_pathfinder_simulations},
+        engine::{
+            StationId, replay_opportunity_forward, run_pathfinder_simulations, truncate_ohlcv,
+        },
         models::{
-            OhlcvTimeSeries, OptimizationStrategy, TradeDirection, TradeOpportunity, TradeOutcome,
+            AdaptiveCurves, DEFAULT_JOURNEY_SETTINGS, OhlcvTimeSeries, OptimizationStrategy,
+            TradeDirection, TradeOutcome,
         },
-        utils::TimeUtils,
     },
     chrono::{DateTime, Utc},
     rayon::prelude::*,
@@ -133,6 +135,11 @@ pub(crate) fn run_backtest(
                 config.strategy,
                 config.station_id,
                 None,
+                &AdaptiveCurves::default(),
+                &DEFAULT_JOURNEY_SETTINGS.profile,
+                None,
+                None,
+                &[],
             );
 
             if pf_result.opportunities.is_empty() {
@@ -143,7 +150,7 @@ pub(crate) fn run_backtest(
 
             for opp in &pf_result.opportunities {
                 let entry_ts_ms = ohlcv.timestamps[current_idx];
-                let entry_time: DateTime<Utc> = TimeUtils::ms_to_datetime(entry_ts_ms);
+                let entry_time: DateTime<Utc> = entry_ts_ms.to_datetime();
                 let max_duration = opp.max_duration;
                 let expiry_time = entry_time
                     + chrono::Duration::from_std(std::time::Duration::from_millis(
@@ -218,8 +225,8 @@ pub(crate) fn run_backtest(
                     stop_price: opp.stop_price,
                     target_price: opp.target_price,
                     exit_reason: outcome.result,
-                    entry_time: entry_ts_ms,
-                    exit_time: exit_ts_ms,
+                    entry_time: entry_ts_ms.value(),
+                    exit_time: exit_ts_ms.value(),
                     planned_expiry_time: expiry_time.timestamp_millis(),
                     strategy: opp.strategy,
                     station_id: opp.station_id,
@@ -284,94 +291,3 @@ pub(crate) fn run_backtest(
 
     Some(report)
 }
-
-// Resolved outcome of replaying one opportunity forward.
-struct ReplayResult {
-    result: TradeOutcome,
-    exit_candle_idx: usize, // Candle index where trade exited (or last available candle)
-}
-
-// Replay a [`TradeOpportunity`] forward into real OHLCV data start at `start_idx` (first hold-out candle), checking each candle's high/low against target and stop prices, then expiry time.
-// Mirrors pessimistic logic of [`TradeOpportunity::check_exit_condition`]: stop is checked before target on each candle.
-fn replay_opportunity_forward(
-    ohlcv: &OhlcvTimeSeries,
-    opp: &TradeOpportunity,
-    start_idx: usize,
-    expiry_time: DateTime<Utc>,
-) -> ReplayResult {
-    let total = ohlcv.klines();
-    let target_price = Price::from(opp.target_price);
-    let stop_price = Price::from(opp.stop_price);
-
-    for idx in start_idx..total {
-        let c = ohlcv.get_candle(idx);
-        let candle_time: DateTime<Utc> = TimeUtils::ms_to_datetime(c.timestamp_ms);
-
-        if candle_time > expiry_time {
-            return ReplayResult {
-                result: TradeOutcome::Timeout,
-                exit_candle_idx: idx,
-            };
-        }
-
-        let high = Price::from(c.high_price);
-        let low = Price::from(c.low_price);
-
-        match opp.direction {
-            TradeDirection::Long => {
-                // Pessimistic: stop before target
-                if low <= stop_price {
-                    return ReplayResult {
-                        result: TradeOutcome::StopHit,
-                        exit_candle_idx: idx,
-                    };
-                }
-                if high >= target_price {
-                    return ReplayResult {
-                        result: TradeOutcome::TargetHit,
-                        exit_candle_idx: idx,
-                    };
-                }
-            }
-            TradeDirection::Short => {
-                if high >= stop_price {
-                    return ReplayResult {
-                        result: TradeOutcome::StopHit,
-                        exit_candle_idx: idx,
-                    };
-                }
-                if low <= target_price {
-                    return ReplayResult {
-                        result: TradeOutcome::TargetHit,
-                        exit_candle_idx: idx,
-                    };
-                }
-            }
-        }
-    }
-
-    // Reached the end of available data without a resolution.
-    // let _ = entry_time; // suppress unused warning in case it's only used above
-    ReplayResult {
-        result: TradeOutcome::Timeout,
-        exit_candle_idx: total.saturating_sub(1),
-    }
-}
-
-// Create a truncated clone of `ohlcv` containing only `[0, end_idx)`.
-// The clone is bounded by `end_idx` which is at most a few thousand candles larger than training window — acceptable for offline tool.
-fn truncate_ohlcv(ohlcv: &OhlcvTimeSeries, end_idx: usize) -> OhlcvTimeSeries {
-    let n = end_idx.min(ohlcv.klines());
-    OhlcvTimeSeries {
-        pair_interval: ohlcv.pair_interval.clone(),
-        first_kline_timestamp_ms: ohlcv.first_kline_timestamp_ms,
-        timestamps: ohlcv.timestamps[..n].to_vec(),
-        open_prices: ohlcv.open_prices[..n].to_vec(),
-        high_prices: ohlcv.high_prices[..n].to_vec(),
-        low_prices: ohlcv.low_prices[..n].to_vec(),
-        close_prices: ohlcv.close_prices[..n].to_vec(),
-        base_asset_volumes: ohlcv.base_asset_volumes[..n].to_vec(),
-        quote_asset_volumes: ohlcv.quote_asset_volumes[..n].to_vec(),
-        relative_volumes: ohlcv.relative_volumes[..n].to_vec(),
-    }
-}
@@ -1,22 +1,31 @@
 use {
     crate::{
-        app::{BASE_INTERVAL, PhPct, Price, PriceLike, QuoteVol},
+        app::{BASE_INTERVAL, DurationMs, PhPct, Price, PriceLike, QuoteVol, TimestampMs},
         data::{PriceStreamManager, TimeSeriesCollection},
+        domain::{HorizonProfile, PairInterval},
         engine::{
-            JobMode, JobRequest, JobResult, StationId, TUNER_CONFIG, TunerStation, tune_to_station,
+            CandleBatch, CorrelationInput, HeatCalendarInput, JobMode, JobRequest, JobResult,
+            JobTimings, MemoryReport, PairPulse, PriceEvaluation, RetuneInput, STRESS_LEADER_PAIR,
+            SectorReport, SessionStats, StationId, StationPreviewInput, StressReport,
+            StressScenario, TELEMETRY_LOG_INTERVAL, TUNER_CONFIG, TunerStation, WorkerTelemetry,
+            build_sector_strength, evaluate_requests, evaluate_stress, failure_backoff,
+            format_bytes, tune_to_station,
         },
         models::{
-            DEFAULT_JOURNEY_SETTINGS, LiveCandle, OpportunityLedger, OptimizationStrategy,
-            PRICE_RECALC_THRESHOLD_PCT, TradeOpportunity, TradingModel, find_matching_ohlcv,
+            AdaptiveCurves, AnalysisReport, BetaEstimate, DEFAULT_JOURNEY_SETTINGS, JourneyReplay,
+            LiveCandle, MIN_CANDLES_FOR_ANALYSIS, ModelDiff, OpportunityLedger,
+            OptimizationStrategy, PRICE_RECALC_THRESHOLD_PCT, PurgeSummary, ScenarioSimulator,
+            SegmentStats, TradeOpportunity, TradingModel, find_matching_ohlcv,
         },
-        shared::SharedConfiguration,
+        shared::{RepaintSignal, SharedConfiguration},
         ui::TradeFinderRow,
-        utils::{AppInstant, TimeUtils},
+        utils::{AppInstant, TimeUtils, process_rss_bytes},
     },
     std::{
         collections::{HashMap, VecDeque},
         sync::{
             Arc, RwLock,
+            atomic::{AtomicBool, Ordering},
             mpsc::{Receiver, Sender, channel},
         },
     },
@@ -28,10 +37,10 @@ use crate::engine::process_request_sync;
 #[cfg(not(target_arch = "wasm32"))]
 use {
     crate::config::PERSISTENCE,
-    crate::data::{ResultsRepositoryTrait, SqliteResultsRepository, TradeResult},
-    crate::engine::spawn_worker_thread,
-    crate::models::{TradeDirection, TradeOutcome},
-    std::path::Path,
+    crate::data::{ResultsRepositoryTrait, SqliteResultsRepository, TradeResult, mock_exchange},
+    crate::engine::{JobJournal, spawn_worker_thread},
+    crate::models::{LedgerConflict, LedgerImportSummary, TradeDirection, TradeOutcome},
+    std::{fmt::Write, path::Path},
     tokio::runtime::Builder,
 };
 
@@ -41,6 +50,28 @@ use chrono::{TimeZone, Utc};
 #[cfg(debug_assertions)]
 use crate::config::DF;
 
+/// Upper bound when searching for a pair's minimum viable PH. A range of ±100% covers
+/// practically all history, so a pair that fails here simply has too few candles.
+const MIN_VIABLE_PH_CAP: PhPct = PhPct::new(1.0);
+
+/// Recalculation diffs kept for the status bar history (oldest dropped first).
+const RECALC_HISTORY_LEN: usize = 20;
+
+/// Lightweight result of [`SniperEngine::preview_ph`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PhPreview {
+    pub candle_count: usize,
+    pub coverage_pct: f64,
+}
+
+/// One completed recalculation and what it changed against the pair's previous model.
+#[derive(Debug, Clone)]
+pub(crate) struct RecalcRecord {
+    pub pair: String,
+    pub completed_at: TimestampMs,
+    pub diff: ModelDiff,
+}
+
 /// All opportunities removed from the ledger during update cycle (pruning, collision resolution)
 #[derive(Debug, Default)]
 pub(crate) struct LedgerRemovals {
@@ -52,8 +83,16 @@ pub(crate) struct PairRuntime {
     pub model: Option<Arc<TradingModel>>,
 
     pub last_update_price: Price,
+    /// When a price move last queued a recalc (for the per-pair minimum interval)
+    pub last_auto_recalc: Option<AppInstant>,
     pub is_calculating: bool,
     pub last_error: Option<String>,
+    /// Failed recalcs in a row; reset by the next success
+    pub consecutive_failures: u32,
+    /// Automatic triggers skip the pair until then. Explicit recalcs still run.
+    pub retry_after: Option<AppInstant>,
+    /// Smallest PH with enough candles at the last analysed price (None = unknown / never)
+    pub min_viable_ph: Option<PhPct>,
 }
 
 impl PairRuntime {
@@ -61,11 +100,27 @@ impl PairRuntime {
         Self {
             model: None,
             last_update_price: Price::default(),
+            last_auto_recalc: None,
             is_calculating: false,
             last_error: None,
+            consecutive_failures: 0,
+            retry_after: None,
+            min_viable_ph: None,
         }
     }
+
+    fn in_backoff(&self) -> bool {
+        self.retry_after.is_some_and(|t| AppInstant::now() < t)
+    }
+}
+
+/// Speculative job for the pair the user is hovering. `job` is None once dispatched.
+struct Prefetch {
+    pair: String,
+    job: Option<EngineJob>,
+    cancel: Arc<AtomicBool>,
 }
+
 pub(crate) struct EngineJob {
     pub pair: String,
     pub price_override: Option<Price>,
@@ -96,6 +151,36 @@ pub struct SniperEngine {
     result_tx: Sender<JobResult>,
 
     pub(crate) queue: VecDeque<EngineJob>, // job queue runtime
+    horizon_profiles: HashMap<String, HorizonProfile>, // per-pair, built lazily for PH previews
+    prefetch: Option<Prefetch>,
+    candle_batch: CandleBatch,
+    /// App in the background: automatic recalcs wait (batched closes are kept) until focus
+    hold_auto_recalcs: bool,
+    pub(crate) session_stats: SessionStats,
+    /// Newest last, capped at `RECALC_HISTORY_LEN`
+    recalc_history: VecDeque<RecalcRecord>,
+    telemetry: Arc<WorkerTelemetry>,
+    /// Wakes the UI on job results and on price ticks for the pair on screen
+    pub(crate) repaint: RepaintSignal,
+    last_telemetry_log: AppInstant,
+    /// Records dispatched jobs for `--replay` (enabled with `--journal`)
+    #[cfg(not(target_arch = "wasm32"))]
+    journal: Option<JobJournal>,
+}
+
+/// Opens (creating if needed) the active profile's results database.
+#[cfg(not(target_arch = "wasm32"))]
+fn open_results_repo() -> anyhow::Result<SqliteResultsRepository> {
+    let db_path = PERSISTENCE.results_db();
+    if let Some(parent) = db_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let db_path_str = db_path.to_str().unwrap_or(PERSISTENCE.app.results_db_path);
+    let rt = Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("Failed to create Tokio runtime for DB init");
+    rt.block_on(SqliteResultsRepository::new(db_path_str))
 }
 
 impl SniperEngine {
@@ -111,8 +196,10 @@ impl SniperEngine {
         // Create the Thread-Safe Data Structure ONCE. Wraped in RwLock (for writing) and Arc (for sharing)
         let timeseries_arc = Arc::new(RwLock::new(timeseries));
 
+        let telemetry = Arc::new(WorkerTelemetry::default());
+        let repaint = RepaintSignal::default();
         #[cfg(not(target_arch = "wasm32"))]
-        spawn_worker_thread(job_rx, result_tx);
+        spawn_worker_thread(job_rx, result_tx, telemetry.clone(), repaint.clone());
 
         let mut pairs_states = HashMap::new();
         {
@@ -130,11 +217,13 @@ impl SniperEngine {
         }
 
         let price_stream = {
-            #[cfg_attr(target_arch = "wasm32", allow(unused_mut))]
             let mut price_manager = PriceStreamManager::new();
+            price_manager.set_repaint_signal(repaint.clone());
 
             #[cfg(not(target_arch = "wasm32"))]
             price_manager.set_candle_sender(_candle_tx.clone());
+            #[cfg(target_arch = "wasm32")]
+            price_manager.seed_missing_from_last_close(&timeseries_arc.read().unwrap());
 
             let price_stream = Arc::new(price_manager);
             price_stream.subscribe_all(active_engine_pairs.clone());
@@ -142,27 +231,10 @@ impl SniperEngine {
         };
 
         #[cfg(not(target_arch = "wasm32"))]
-        let repo = {
-            let db_path = Path::new(PERSISTENCE.kline.directory)
-                .parent()
-                .unwrap_or(Path::new("."))
-                .join("results.sqlite");
-
-            let db_path_str = db_path.to_str().unwrap_or("results.sqlite");
-            let rt = Builder::new_current_thread()
-                .enable_all()
-                .build()
-                .expect("Failed to create Tokio runtime for DB init");
-
-            rt.block_on(async {
-                SqliteResultsRepository::new(db_path_str)
-                    .await
-                    .unwrap_or_else(|e| {
-                        log::error!("Failed to init results.sqlite: {}", e);
-                        panic!("Critical Error: Results DB init failed");
-                    })
-            })
-        };
+        let repo = open_results_repo().unwrap_or_else(|e| {
+            log::error!("Failed to init results.sqlite: {:#}", e);
+            panic!("Critical Error: Results DB init failed");
+        });
 
         Self {
             active_engine_pairs,
@@ -179,12 +251,30 @@ impl SniperEngine {
             #[cfg(target_arch = "wasm32")]
             result_tx,
             queue: VecDeque::new(),
+            horizon_profiles: HashMap::new(),
+            prefetch: None,
+            candle_batch: CandleBatch::default(),
+            hold_auto_recalcs: false,
+            session_stats: SessionStats::default(),
+            recalc_history: VecDeque::new(),
+            telemetry,
+            repaint,
+            last_telemetry_log: AppInstant::now(),
             #[cfg(not(target_arch = "wasm32"))]
             results_repo: Arc::new(repo),
             last_ledger_maintenance: AppInstant::now(),
+            #[cfg(not(target_arch = "wasm32"))]
+            journal: None,
         }
     }
 
+    /// Starts appending every dispatched job and its result to `path`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn enable_journal(&mut self, path: &Path) -> anyhow::Result<()> {
+        self.journal = Some(JobJournal::open(path)?);
+        Ok(())
+    }
+
     pub(crate) fn tune_pair_with_station(
         &self,
         pair: &str,
@@ -205,8 +295,8 @@ impl SniperEngine {
         crate::trace_time!("Core: Get TradeFinder Rows", 2000, {
             let mut rows = Vec::new();
 
-            let now_ms = TimeUtils::now_timestamp_ms();
-            let day_ms = 86_400_000;
+            let now = TimestampMs::now();
+            let day = DurationMs::new(TimeUtils::MS_IN_D);
 
             // Group Ledger Opportunities by Pair for fast lookup
             let mut ops_by_pair: HashMap<String, Vec<&TradeOpportunity>> = HashMap::new();
@@ -232,19 +322,12 @@ impl SniperEngine {
                     .iter()
                     .find(|t| t.pair_interval.name() == pair)
                 {
-                    let count = ts.klines();
-                    if count > 0 {
-                        let current_idx = count - 1;
-                        for i in (0..=current_idx).rev() {
-                            let c = ts.get_candle(i);
-                            if now_ms - c.timestamp_ms > day_ms {
-                                break;
-                            }
-                            vol_24h += c.quote_asset_volume;
-                        }
+                    for c in ts.candles_between(now - day, TimestampMs::MAX) {
+                        vol_24h += c.quote_asset_volume;
                     }
                 }
 
+                let quote_usd_rate = self.quote_usd_rate(pair);
                 let raw_ops = ops_by_pair.get(pair).map(|v| v.as_slice()).unwrap_or(&[]);
                 let valid_ops: Vec<&TradeOpportunity> = raw_ops
                     .iter()
@@ -259,6 +342,7 @@ impl SniperEngine {
                         rows.push(TradeFinderRow {
                             pair_name: pair.clone(),
                             quote_volume_24h: vol_24h,
+                            quote_usd_rate,
                             market_state: Some(op.market_state),
                             opportunity: Some(op.clone()),
                             current_price: price,
@@ -268,6 +352,7 @@ impl SniperEngine {
                     rows.push(TradeFinderRow {
                         pair_name: pair.clone(),
                         quote_volume_24h: vol_24h,
+                        quote_usd_rate,
                         market_state: None,
                         opportunity: None,
                         current_price: price,
@@ -278,11 +363,60 @@ impl SniperEngine {
         })
     }
 
+    /// Runs ledger maintenance now: collision merging plus the configured ledger policy.
+    pub(crate) fn purge_stale_opportunities(&mut self) -> PurgeSummary {
+        self.engine_ledger.purge(
+            &self.shared_config.get_ledger_policy(),
+            &self.shared_config.get_taken_opportunities(),
+            DEFAULT_JOURNEY_SETTINGS.optimization.fuzzy_match_tolerance,
+            TimeUtils::now_utc(),
+        )
+    }
+
+    /// Merges opportunities exported from another ledger (see [`OpportunityLedger::import`]).
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn import_ledger_opportunities(
+        &mut self,
+        opportunities: Vec<TradeOpportunity>,
+        conflict: LedgerConflict,
+    ) -> LedgerImportSummary {
+        self.engine_ledger.import(opportunities, conflict)
+    }
+
+    /// Swaps in another profile's settings and ledger. Candles, the price stream and the worker
+    /// are kept; models and queued jobs were built from the old settings, so they are dropped.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn switch_profile(
+        &mut self,
+        shared_config: SharedConfiguration,
+        ledger: OpportunityLedger,
+    ) {
+        self.shared_config = shared_config;
+        self.engine_ledger = ledger;
+        self.queue.clear();
+        if let Some(prefetch) = self.prefetch.take() {
+            prefetch.cancel.store(true, Ordering::Relaxed);
+        }
+        for state in self.pairs_states.values_mut() {
+            *state = PairRuntime::new();
+        }
+        self.horizon_profiles.clear();
+        // The active profile has changed, so this now opens the new profile's database
+        match open_results_repo() {
+            Ok(repo) => self.results_repo = Arc::new(repo),
+            Err(e) => log::error!(
+                "Failed to open the profile's results.sqlite, still writing to the previous one: {:#}",
+                e
+            ),
+        }
+    }
+
     pub(crate) fn update(&mut self) -> LedgerRemovals {
         // Ingest Live Data (The Heartbeat)
         let t1 = AppInstant::now();
         let mut removals = LedgerRemovals::default();
         self.tick_process_price_stream_data();
+        self.flush_candle_batch();
 
         // Garbage Collect dead trades.
         #[cfg(not(target_arch = "wasm32"))]
@@ -301,27 +435,29 @@ impl SniperEngine {
         #[cfg(target_arch = "wasm32")]
         {
             if let Ok(req) = self.job_rx.try_recv() {
-                process_request_sync(req, self.result_tx.clone());
+                let tx = self.result_tx.clone();
+                self.telemetry
+                    .time_job(req.mode.clone(), || process_request_sync(req, tx));
             }
         }
 
-        // Maintenance loop - checks for drifting trades that have overlapped and merges them.
-        let journey_settings = &DEFAULT_JOURNEY_SETTINGS;
-
+        // Maintenance loop - merges drifting trades that have overlapped and applies the
+        // user's age / per-pair limits.
         if t1.duration_since(self.last_ledger_maintenance).as_secs()
-            >= journey_settings.optimization.prune_interval_sec
+            >= self.shared_config.get_ledger_policy().prune_interval_secs
         {
-            removals.ids.extend(
-                self.engine_ledger
-                    .prune_collisions(journey_settings.optimization.fuzzy_match_tolerance),
-            );
+            removals.ids.extend(self.purge_stale_opportunities().ids);
             self.last_ledger_maintenance = t1;
         }
+        if !removals.ids.is_empty() {
+            self.shared_config.forget_taken(&removals.ids);
+        }
 
         let t2 = AppInstant::now();
         while let Ok(result) = self.result_rx.try_recv() {
             self.handle_job_result(result);
         }
+        self.observe_live_rois();
         let d2 = t2.elapsed().as_micros();
 
         // Enqueue pairs that have changed price significantly
@@ -333,6 +469,11 @@ impl SniperEngine {
         self.process_queue();
         let d4 = t4.elapsed().as_micros();
 
+        if self.last_telemetry_log.elapsed() >= TELEMETRY_LOG_INTERVAL {
+            self.log_telemetry();
+            self.last_telemetry_log = AppInstant::now();
+        }
+
         let total = d1 + d2 + d3 + d4;
         if total > 100_000 {
             log::warn!(
@@ -346,16 +487,202 @@ impl SniperEngine {
         removals
     }
 
+    /// Approximate memory held in timeseries and per-pair models.
+    pub(crate) fn memory_report(&self) -> MemoryReport {
+        let timeseries_bytes = self
+            .timeseries
+            .read()
+            .map(|ts| ts.series_data.iter().map(|s| s.heap_bytes()).sum())
+            .unwrap_or(0);
+        let mut models: Vec<(String, usize)> = self
+            .pairs_states
+            .iter()
+            .filter_map(|(pair, state)| Some((pair.clone(), state.model.as_ref()?.heap_bytes())))
+            .collect();
+        models.sort_by_key(|(_, bytes)| std::cmp::Reverse(*bytes));
+        MemoryReport {
+            timeseries_bytes,
+            models,
+            process_rss: process_rss_bytes(),
+        }
+    }
+
+    pub(crate) fn job_timings(&self) -> Vec<(JobMode, JobTimings)> {
+        self.telemetry.snapshot()
+    }
+
+    fn log_telemetry(&self) {
+        let memory = self.memory_report();
+        log::info!(
+            "TELEMETRY: timeseries {} | models {} ({} pairs) | RSS {}",
+            format_bytes(memory.timeseries_bytes as u64),
+            format_bytes(memory.models_bytes() as u64),
+            memory.models.len(),
+            memory
+                .process_rss
+                .map_or_else(|| "n/a".to_string(), format_bytes),
+        );
+        for (mode, t) in self.job_timings() {
+            log::info!(
+                "TELEMETRY: {:?} jobs {} | wall {:.1}s (avg {}ms, max {}ms) | cpu {:.1}s",
+                mode,
+                t.jobs,
+                t.wall.as_secs_f64(),
+                t.avg_wall().as_millis(),
+                t.max_wall.as_millis(),
+                t.cpu.as_secs_f64(),
+            );
+        }
+    }
+
+    /// Plain-text engine state for diagnostics bundles.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn diagnostics_summary(&self) -> String {
+        let memory = self.memory_report();
+        let mut out = String::new();
+        let _ = writeln!(out, "pairs: {}", self.pairs_states.len());
+        let _ = writeln!(out, "models: {}", memory.models.len());
+        let _ = writeln!(out, "queue: {}", self.get_queue_len());
+        let _ = writeln!(out, "batched: {}", self.get_batched_count());
+        let _ = writeln!(
+            out,
+            "worker: {}",
+            self.get_worker_status_msg().unwrap_or_default()
+        );
+        let _ = writeln!(
+            out,
+            "ledger opportunities: {}",
+            self.engine_ledger.opportunities.len()
+        );
+        let _ = writeln!(
+            out,
+            "memory: timeseries {} | models {} | RSS {}",
+            format_bytes(memory.timeseries_bytes as u64),
+            format_bytes(memory.models_bytes() as u64),
+            memory
+                .process_rss
+                .map_or_else(|| "n/a".to_string(), format_bytes),
+        );
+        for (mode, t) in self.job_timings() {
+            let _ = writeln!(
+                out,
+                "jobs {:?}: {} | avg {}ms | max {}ms | cpu {:.1}s",
+                mode,
+                t.jobs,
+                t.avg_wall().as_millis(),
+                t.max_wall.as_millis(),
+                t.cpu.as_secs_f64(),
+            );
+        }
+        let mut pairs: Vec<_> = self.pairs_states.keys().collect();
+        pairs.sort();
+        for pair in pairs {
+            let (busy, error) = self.get_pair_status(pair);
+            let _ = writeln!(
+                out,
+                "{:<12} model={} busy={} error={}",
+                pair,
+                self.get_model(pair).is_some(),
+                busy,
+                error.unwrap_or_default()
+            );
+        }
+        out
+    }
+
+    /// Ledger opportunities for `pair`, in no particular order.
+    pub(crate) fn get_pair_opportunities(&self, pair: &str) -> Vec<&TradeOpportunity> {
+        self.engine_ledger
+            .opportunities
+            .values()
+            .filter(|op| op.pair_name == pair)
+            .collect()
+    }
+
     pub(crate) fn get_model(&self, pair: &str) -> Option<Arc<TradingModel>> {
         self.pairs_states
             .get(pair)
             .and_then(|state| state.model.clone())
     }
 
+    /// Snapshot of everything behind an opportunity. Journeys replay the matches of the run that
+    /// produced it; zones and volatility come from the pair's current model.
+    pub(crate) fn build_analysis_report(&self, op: &TradeOpportunity) -> Option<AnalysisReport> {
+        let model = self.get_model(&op.pair_name)?;
+        let ts_guard = self.timeseries.read().unwrap();
+        let ohlcv = find_matching_ohlcv(
+            &ts_guard.series_data,
+            &op.pair_name,
+            BASE_INTERVAL.as_millis() as i64,
+        )
+        .ok()?;
+        Some(AnalysisReport::build(&model, ohlcv, op))
+    }
+
+    /// Statistics for one of the pair's display segments, for the candle range panel.
+    pub(crate) fn segment_stats(&self, pair: &str, segment_idx: usize) -> Option<SegmentStats> {
+        let model = self.get_model(pair)?;
+        let segment = model.segments.get(segment_idx)?;
+        let ts_guard = self.timeseries.read().unwrap();
+        let ohlcv = find_matching_ohlcv(
+            &ts_guard.series_data,
+            pair,
+            BASE_INTERVAL.as_millis() as i64,
+        )
+        .ok()?;
+        SegmentStats::compute(ohlcv, segment, &model.zones)
+    }
+
+    /// Cheap PH preview (no CVA): candles inside the horizon and their share of history.
+    pub(crate) fn preview_ph(&mut self, pair: &str, ph_pct: PhPct) -> Option<PhPreview> {
+        let price = self.get_price(pair)?;
+        let ts_guard = self.timeseries.read().unwrap();
+        let ohlcv = find_matching_ohlcv(
+            &ts_guard.series_data,
+            pair,
+            BASE_INTERVAL.as_millis() as i64,
+        )
+        .ok()?;
+        let profile = self.horizon_profiles.entry(pair.to_string()).or_default();
+        profile.sync(ohlcv);
+        let candle_count = profile.candle_count(ohlcv, price, ph_pct);
+        let total = ohlcv.klines().max(1);
+        Some(PhPreview {
+            candle_count,
+            coverage_pct: candle_count as f64 / total as f64 * 100.0,
+        })
+    }
+
+    /// Replays each matched historical analogue against the opportunity's entry/target/stop.
+    pub(crate) fn replay_journeys(&self, op: &TradeOpportunity) -> Vec<JourneyReplay> {
+        let ts_guard = self.timeseries.read().unwrap();
+        let Ok(ohlcv) = find_matching_ohlcv(
+            &ts_guard.series_data,
+            &op.pair_name,
+            BASE_INTERVAL.as_millis() as i64,
+        ) else {
+            return Vec::new();
+        };
+        ScenarioSimulator::replay_journeys(
+            ohlcv,
+            &op.matches,
+            op.start_price,
+            op.target_price,
+            op.stop_price,
+            op.max_duration_candles(),
+            op.direction,
+        )
+    }
+
     pub(crate) fn get_price(&self, pair: &str) -> Option<Price> {
         self.price_stream.get_price(pair)
     }
 
+    /// Dollars per unit of `pair`'s quote asset at live prices (1.0 for USD stablecoins).
+    pub(crate) fn quote_usd_rate(&self, pair: &str) -> Option<f64> {
+        PairInterval::quote_usd_rate(pair, |p| self.get_price(p))
+    }
+
     pub(crate) fn get_all_pair_names(&self) -> Vec<String> {
         self.timeseries.read().unwrap().unique_pair_names()
     }
@@ -380,6 +707,11 @@ impl SniperEngine {
         }
     }
 
+    /// Recent recalculations with their model diffs, newest last.
+    pub(crate) fn recalc_history(&self) -> &VecDeque<RecalcRecord> {
+        &self.recalc_history
+    }
+
     pub(crate) fn get_pair_status(&self, pair: &str) -> (bool, Option<String>) {
         if let Some(state) = self.pairs_states.get(pair) {
             (state.is_calculating, state.last_error.clone())
@@ -388,6 +720,38 @@ impl SniperEngine {
         }
     }
 
+    /// Consecutive failures and seconds until automatic recalcs resume (0 once due).
+    pub(crate) fn get_pair_backoff(&self, pair: &str) -> Option<(u32, u64)> {
+        let state = self.pairs_states.get(pair)?;
+        let retry_after = state.retry_after?;
+        let remaining = retry_after.saturating_duration_since(AppInstant::now());
+        Some((state.consecutive_failures, remaining.as_secs()))
+    }
+
+    /// Smallest PH that gives the pair enough candles to analyse, as of its last recalc.
+    pub(crate) fn get_min_viable_ph(&self, pair: &str) -> Option<PhPct> {
+        self.pairs_states.get(pair)?.min_viable_ph
+    }
+
+    /// Recomputes the pair's minimum viable PH from its horizon profile at the live price.
+    fn refresh_min_viable_ph(&mut self, pair: &str) {
+        let min_ph = self.get_price(pair).and_then(|price| {
+            let ts_guard = self.timeseries.read().unwrap();
+            let ohlcv = find_matching_ohlcv(
+                &ts_guard.series_data,
+                pair,
+                BASE_INTERVAL.as_millis() as i64,
+            )
+            .ok()?;
+            let profile = self.horizon_profiles.entry(pair.to_string()).or_default();
+            profile.sync(ohlcv);
+            profile.min_viable_ph(ohlcv, price, MIN_CANDLES_FOR_ANALYSIS, MIN_VIABLE_PH_CAP)
+        });
+        if let Some(state) = self.pairs_states.get_mut(pair) {
+            state.min_viable_ph = min_ph;
+        }
+    }
+
     pub(crate) fn trigger_global_recalc(&mut self, priority_pair: Option<String>) {
         self.queue.clear();
 
@@ -435,6 +799,393 @@ impl SniperEngine {
         }
     }
 
+    /// Recalculates one pair with unsaved adaptive curves, leaving every other pair untouched.
+    pub(crate) fn preview_adaptive(&mut self, pair: &str, curves: AdaptiveCurves) {
+        self.shared_config
+            .set_adaptive_preview(Some((pair.to_string(), curves)));
+        self.recalc_pair_from_config(pair, "ADAPTIVE PREVIEW");
+    }
+
+    pub(crate) fn discard_adaptive_preview(&mut self, pair: &str) {
+        self.shared_config.set_adaptive_preview(None);
+        self.recalc_pair_from_config(pair, "ADAPTIVE PREVIEW DISCARDED");
+    }
+
+    /// Makes `curves` the global adaptive mapping and recalculates every pair.
+    pub(crate) fn apply_adaptive(&mut self, curves: AdaptiveCurves, priority_pair: Option<String>) {
+        self.shared_config.set_adaptive(curves);
+        self.trigger_global_recalc(priority_pair);
+    }
+
+    /// Re-classifies zones for one pair from the shared zone config and its manual zone edits
+    /// without a full recalc, so the plot follows at once. Targets, stops and ids still
+    /// reflect the old zones until the recalc the caller queues comes back.
+    pub(crate) fn reclassify_zones(&mut self, pair: &str) {
+        let zone_config = self.shared_config.get_zone_config();
+        let zone_edits = self.shared_config.get_zone_edits(pair);
+        let ts_guard = self.timeseries.read().unwrap();
+        let Ok(ohlcv) = find_matching_ohlcv(
+            &ts_guard.series_data,
+            pair,
+            BASE_INTERVAL.as_millis() as i64,
+        ) else {
+            return;
+        };
+        if let Some(state) = self.pairs_states.get_mut(pair) {
+            if let Some(model) = &state.model {
+                state.model = Some(Arc::new(model.with_zone_config(
+                    &zone_config,
+                    &zone_edits,
+                    ohlcv,
+                )));
+            }
+        }
+    }
+
+    pub(crate) fn recalc_pair_from_config(&mut self, pair: &str, reason: &str) {
+        let (Some(ph_pct), Some(station_id)) = (
+            self.shared_config.get_ph(pair),
+            self.shared_config.get_station(pair),
+        ) else {
+            return;
+        };
+        self.invalidate_pair_and_recalc(
+            pair,
+            None,
+            ph_pct,
+            self.shared_config.get_strategy(),
+            station_id,
+            JobMode::FullAnalysis,
+            reason,
+        );
+    }
+
+    /// Requests behind [`Self::evaluate_at_prices`], for callers that run them off the UI
+    /// thread with [`evaluate_requests`]. Empty if the pair has no PH / station yet.
+    pub(crate) fn price_evaluation_requests(
+        &self,
+        pair: &str,
+        prices: &[Price],
+    ) -> Vec<JobRequest> {
+        let (Some(ph_pct), Some(station_id)) = (
+            self.shared_config.get_ph(pair),
+            self.shared_config.get_station(pair),
+        ) else {
+            return Vec::new();
+        };
+        let strategy = self.shared_config.get_strategy();
+        prices
+            .iter()
+            .map(|&price| {
+                let job = EngineJob {
+                    pair: pair.to_string(),
+                    price_override: Some(price),
+                    ph_pct,
+                    strategy,
+                    station_id,
+                    mode: JobMode::FullAnalysis,
+                };
+                self.build_job_request(job, Some(price), None)
+            })
+            .collect()
+    }
+
+    /// Side-effect-free what-if: the opportunities the pair would get at each hypothetical
+    /// price with the current settings, in `prices` order. Blocks while the analyses run.
+    pub(crate) fn evaluate_at_prices(
+        &self,
+        pair: &str,
+        prices: Vec<Price>,
+    ) -> Vec<PriceEvaluation> {
+        evaluate_requests(self.price_evaluation_requests(pair, &prices))
+    }
+
+    /// Inputs for previewing `station_id` on `pair` without touching its live PH / station
+    /// (see [`crate::engine::preview_station`]). None until the pair has a price.
+    pub(crate) fn station_preview_input(
+        &self,
+        pair: &str,
+        station_id: StationId,
+    ) -> Option<StationPreviewInput> {
+        let station = *TUNER_CONFIG.stations.iter().find(|s| s.id == station_id)?;
+        let price = self.get_price(pair)?;
+        let job = EngineJob {
+            pair: pair.to_string(),
+            price_override: None,
+            ph_pct: self.shared_config.get_ph(pair).unwrap_or_default(),
+            strategy: self.shared_config.get_strategy(),
+            station_id,
+            mode: JobMode::FullAnalysis,
+        };
+        Some(StationPreviewInput {
+            request: self.build_job_request(job, Some(price), None),
+            station,
+            weights: self.shared_config.get_tuner_weights(),
+            min_ph: self.get_min_viable_ph(pair),
+            previous: self.shared_config.get_tuner_scan(pair),
+        })
+    }
+
+    /// Inputs for a heat calendar of `pair` under its current settings (see
+    /// [`crate::engine::build_heat_calendar`]). None until the pair has a PH / station and
+    /// candles.
+    pub(crate) fn heat_calendar_input(&self, pair: &str) -> Option<HeatCalendarInput> {
+        let ph_pct = self.shared_config.get_ph(pair)?;
+        let station_id = self.shared_config.get_station(pair)?;
+        let ohlcv = {
+            let ts_guard = self.timeseries.read().unwrap();
+            find_matching_ohlcv(
+                &ts_guard.series_data,
+                pair,
+                BASE_INTERVAL.as_millis() as i64,
+            )
+            .ok()?
+            .clone()
+        };
+        Some(HeatCalendarInput {
+            ohlcv,
+            ph_pct,
+            strategy: self.shared_config.get_strategy(),
+            station_id,
+            adaptive: self.shared_config.get_adaptive(pair),
+            profile: self.shared_config.get_trade_profile(),
+        })
+    }
+
+    /// Requests for a correlated what-if: `leader` at `price` and every other active pair
+    /// moved by its beta to the leader (e.g. BTC -2% with beta 1.5 puts an alt at -3%). Pairs
+    /// with too little shared history or negligible correlation are left out. Run them with
+    /// [`evaluate_requests`]; like [`Self::price_evaluation_requests`], nothing reaches the
+    /// live models, the ledger or the stats.
+    pub(crate) fn correlated_move_requests(&self, leader: &str, price: Price) -> Vec<JobRequest> {
+        let Some(current) = self.get_price(leader) else {
+            return Vec::new();
+        };
+        if current.value() <= 0.0 {
+            return Vec::new();
+        }
+        let leader_move = price.value() / current.value() - 1.0;
+        let moves: Vec<(String, Price)> = {
+            let ts_guard = self.timeseries.read().unwrap();
+            let interval_ms = BASE_INTERVAL.as_millis() as i64;
+            let Ok(leader_ohlcv) = find_matching_ohlcv(&ts_guard.series_data, leader, interval_ms)
+            else {
+                return Vec::new();
+            };
+            self.active_engine_pairs
+                .iter()
+                .filter(|pair| pair.as_str() != leader)
+                .filter_map(|pair| {
+                    let ohlcv =
+                        find_matching_ohlcv(&ts_guard.series_data, pair, interval_ms).ok()?;
+                    let estimate = BetaEstimate::between(leader_ohlcv, ohlcv)?;
+                    if !estimate.is_meaningful() {
+                        return None;
+                    }
+                    let live = self.get_price(pair)?;
+                    let simulated = live.value() * (1.0 + estimate.beta * leader_move);
+                    (simulated > 0.0).then(|| (pair.clone(), Price::new(simulated)))
+                })
+                .collect()
+        };
+        #[cfg(debug_assertions)]
+        if DF.log_engine_core {
+            log::info!(
+                "ENGINE: correlated simulation {} {:+.2}% -> {} pairs",
+                leader,
+                leader_move * 100.0,
+                moves.len()
+            );
+        }
+        std::iter::once((leader.to_string(), price))
+            .chain(moves)
+            .flat_map(|(pair, simulated)| self.price_evaluation_requests(&pair, &[simulated]))
+            .collect()
+    }
+
+    /// [`Self::correlated_move_requests`] evaluated on the calling thread.
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) fn simulate_correlated_move(
+        &self,
+        leader: &str,
+        price: Price,
+    ) -> Vec<PriceEvaluation> {
+        evaluate_requests(self.correlated_move_requests(leader, price))
+    }
+
+    /// Runs `scenario` against every opportunity in the ledger, entering at the live price.
+    /// Pairs follow the leader path by their beta to `STRESS_LEADER_PAIR` (1.0 when unknown).
+    pub(crate) fn run_stress_scenario(&self, scenario: StressScenario) -> StressReport {
+        let now = TimeUtils::now_utc();
+        let mut betas: HashMap<String, f64> = HashMap::new();
+        {
+            let ts_guard = self.timeseries.read().unwrap();
+            let interval_ms = BASE_INTERVAL.as_millis() as i64;
+            if let Ok(leader) =
+                find_matching_ohlcv(&ts_guard.series_data, STRESS_LEADER_PAIR, interval_ms)
+            {
+                for op in self.engine_ledger.opportunities.values() {
+                    if betas.contains_key(&op.pair_name) {
+                        continue;
+                    }
+                    let beta =
+                        find_matching_ohlcv(&ts_guard.series_data, &op.pair_name, interval_ms)
+                            .ok()
+                            .and_then(|ohlcv| BetaEstimate::between(leader, ohlcv))
+                            .filter(|estimate| estimate.is_meaningful())
+                            .map_or(1.0, |estimate| estimate.beta);
+                    betas.insert(op.pair_name.clone(), beta);
+                }
+            }
+        }
+        let mut rows: Vec<_> = self
+            .engine_ledger
+            .opportunities
+            .values()
+            .map(|op| {
+                let entry = self.get_price(&op.pair_name).unwrap_or(op.start_price);
+                let beta = if op.pair_name == STRESS_LEADER_PAIR {
+                    1.0
+                } else {
+                    betas.get(&op.pair_name).copied().unwrap_or(1.0)
+                };
+                let remaining = (op.expires_at() - now).to_std().unwrap_or_default();
+                evaluate_stress(op, entry, beta, scenario, remaining)
+            })
+            .collect();
+        rows.sort_by(|a, b| a.pnl_pct.total_cmp(&b.pnl_pct));
+        StressReport {
+            scenario,
+            ran_at: TimestampMs::now(),
+            rows,
+        }
+    }
+
+    /// Unexpired ledger opportunities a basket is picked from.
+    pub(crate) fn basket_candidates(&self) -> Vec<TradeOpportunity> {
+        let now = TimeUtils::now_utc();
+        self.engine_ledger
+            .opportunities
+            .values()
+            .filter(|op| op.expires_at() > now)
+            .cloned()
+            .collect()
+    }
+
+    /// Inputs for the return correlations between `pairs` (see
+    /// [`crate::engine::CorrelationMatrix::build`]). Pairs without candles are left out, so
+    /// their correlations stay unknown.
+    pub(crate) fn correlation_input(&self, pairs: &[&str]) -> CorrelationInput {
+        let ts_guard = self.timeseries.read().unwrap();
+        let interval_ms = BASE_INTERVAL.as_millis() as i64;
+        CorrelationInput {
+            pairs: pairs.iter().map(|pair| pair.to_string()).collect(),
+            series: pairs
+                .iter()
+                .filter_map(|pair| {
+                    find_matching_ohlcv(&ts_guard.series_data, pair, interval_ms).ok()
+                })
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Per-sector momentum, volatility and live opportunity counts across the tracked pairs.
+    /// Only unexpired opportunities with a positive expected ROI count.
+    pub(crate) fn sector_report(&self) -> SectorReport {
+        let now = TimeUtils::now_utc();
+        let mut ops_by_pair: HashMap<&str, Vec<&TradeOpportunity>> = HashMap::new();
+        for op in self.engine_ledger.opportunities.values() {
+            if op.expires_at() > now && op.expected_roi().is_positive() {
+                ops_by_pair.entry(&op.pair_name).or_default().push(op);
+            }
+        }
+        let ts_guard = self.timeseries.read().unwrap();
+        let interval_ms = BASE_INTERVAL.as_millis() as i64;
+        let built_at = TimestampMs::now();
+        let pulses: Vec<PairPulse> = self
+            .pairs_states
+            .keys()
+            .map(|pair| {
+                let ops = ops_by_pair
+                    .get(pair.as_str())
+                    .map_or(&[][..], Vec::as_slice);
+                PairPulse {
+                    pair: pair.clone(),
+                    trend: find_matching_ohlcv(&ts_guard.series_data, pair, interval_ms)
+                        .ok()
+                        .and_then(|ts| PairPulse::trend_of(ts, built_at)),
+                    opportunities: ops.len(),
+                    positive_ev: ops
+                        .iter()
+                        .filter(|op| op.simulation.expectancy_r() > 0.0)
+                        .count(),
+                    best_expectancy: ops
+                        .iter()
+                        .map(|op| op.simulation.expectancy_r())
+                        .max_by(f64::total_cmp),
+                }
+            })
+            .collect();
+        SectorReport {
+            sectors: build_sector_strength(&pulses),
+            built_at,
+        }
+    }
+
+    /// Batch form of `recalc_pair_from_config` for multi-select actions.
+    pub(crate) fn recalc_pairs(&mut self, pairs: &[String], reason: &str) {
+        for pair in pairs {
+            self.recalc_pair_from_config(pair, reason);
+        }
+    }
+
+    /// Queues a re-tune job for each pair at its configured station. The worker runs the time
+    /// tuner and recalcs at the PH it finds, which is stored when the result comes back.
+    /// Returns how many pairs were queued.
+    pub(crate) fn retune_pairs(&mut self, pairs: &[String]) -> usize {
+        let mut queued = 0;
+        for pair in pairs {
+            let (Some(ph_pct), Some(station_id)) = (
+                self.shared_config.get_ph(pair),
+                self.shared_config.get_station(pair),
+            ) else {
+                continue;
+            };
+            self.invalidate_pair_and_recalc(
+                pair,
+                None,
+                ph_pct,
+                self.shared_config.get_strategy(),
+                station_id,
+                JobMode::Retune,
+                "BULK RETUNE",
+            );
+            queued += 1;
+        }
+        queued
+    }
+
+    /// Drops cached models, errors, PH profiles and queued jobs for the given pairs, then
+    /// queues a fresh recalc for each.
+    pub(crate) fn purge_pairs(&mut self, pairs: &[String]) {
+        self.queue.retain(|job| !pairs.contains(&job.pair));
+        for pair in pairs {
+            if let Some(state) = self.pairs_states.get_mut(pair) {
+                state.model = None;
+                state.last_error = None;
+                state.consecutive_failures = 0;
+                state.retry_after = None;
+            }
+            self.horizon_profiles.remove(pair);
+        }
+        #[cfg(debug_assertions)]
+        if DF.log_engine_core {
+            log::info!("ENGINE: purged cached state for {} pairs", pairs.len());
+        }
+        self.recalc_pairs(pairs, "BULK PURGE");
+    }
+
     // Force recalculation for single pair.
     pub(crate) fn invalidate_pair_and_recalc(
         &mut self,
@@ -473,12 +1224,19 @@ impl SniperEngine {
             BASE_INTERVAL.as_millis() as i64,
         )
         .ok()?;
-        tune_to_station(
+        let scan = tune_to_station(
             ohlcv,
             price,
             tuner_station,
             self.shared_config.get_strategy(),
-        )
+            &self.shared_config.get_adaptive(pair),
+            &self.shared_config.get_tuner_weights(),
+            self.get_min_viable_ph(pair),
+            self.shared_config.get_tuner_scan(pair).as_ref(),
+        )?;
+        let best = scan.best;
+        self.shared_config.insert_tuner_scan(pair.to_string(), scan);
+        Some(best)
     }
 
     /// Called every tick
@@ -525,52 +1283,85 @@ impl SniperEngine {
                     continue;
                 }
 
+                if let Some(profile) = self.horizon_profiles.get_mut(&candle.symbol) {
+                    profile.sync(series);
+                }
+
                 #[cfg(debug_assertions)]
                 if DF.log_candle_update {
                     log::info!(
-                        "ENGINE: Candle Closed for {}. Triggering Recalc. in process_live_data()",
+                        "ENGINE: Candle Closed for {}. Batching Recalc. in process_live_data()",
                         candle.symbol
                     );
                 }
 
-                let Some(ph_pct) = self.shared_config.get_ph(&candle.symbol) else {
-                    #[cfg(debug_assertions)]
-                    if DF.log_ph_overrides {
-                        log::info!(
-                            "No ph_pct configured for {} - skipping update",
-                            candle.symbol
-                        );
-                    }
-                    continue;
-                };
-
-                let station_id = self
-                    .shared_config
-                    .get_station(&candle.symbol)
-                    .unwrap_or_else(|| {
-                        panic!(
-                            "PAIR {} with ph_pct {} unexpectedly not found in shared_config",
-                            candle.symbol, ph_pct
-                        )
-                    });
+                self.candle_batch.push(&candle.symbol, candle.close.into());
+            }
+        }
+    }
 
+    /// Enqueues a recalc for every pair whose candle batch window has elapsed.
+    /// Pausing drops anything batched; a background hold keeps it. Price triggers catch up
+    /// after either ends.
+    fn flush_candle_batch(&mut self) {
+        let throttle = self.shared_config.get_throttle();
+        if throttle.paused {
+            self.candle_batch.clear();
+            return;
+        }
+        if self.hold_auto_recalcs {
+            return;
+        }
+        for (pair, close) in self.candle_batch.drain_due(throttle.batch_window()) {
+            if self
+                .pairs_states
+                .get(&pair)
+                .is_some_and(PairRuntime::in_backoff)
+            {
+                continue;
+            }
+            let Some(ph_pct) = self.shared_config.get_ph(&pair) else {
                 #[cfg(debug_assertions)]
-                if DF.log_engine_core {
-                    log::info!("Enqueueing job for {} (candle closed)", candle.symbol);
+                if DF.log_ph_overrides {
+                    log::info!("No ph_pct configured for {} - skipping update", pair);
                 }
+                continue;
+            };
 
-                self.enqueue_or_replace(EngineJob {
-                    pair: candle.symbol.clone(),
-                    price_override: Some(candle.close.into()),
-                    ph_pct,
-                    strategy: self.shared_config.get_strategy(),
-                    station_id,
-                    mode: JobMode::FullAnalysis,
-                });
+            let station_id = self.shared_config.get_station(&pair).unwrap_or_else(|| {
+                panic!(
+                    "PAIR {} with ph_pct {} unexpectedly not found in shared_config",
+                    pair, ph_pct
+                )
+            });
+
+            #[cfg(debug_assertions)]
+            if DF.log_engine_core {
+                log::info!("Enqueueing job for {} (candle closed)", pair);
             }
+
+            self.enqueue_or_replace(EngineJob {
+                pair,
+                price_override: Some(close),
+                ph_pct,
+                strategy: self.shared_config.get_strategy(),
+                station_id,
+                mode: JobMode::FullAnalysis,
+            });
         }
     }
 
+    /// Held while the app is in the background; price moves accumulate against the last
+    /// recalc price, so everything due catches up as soon as the hold is released.
+    pub(crate) fn set_hold_auto_recalcs(&mut self, hold: bool) {
+        self.hold_auto_recalcs = hold;
+    }
+
+    /// Closed candles currently held back by the batch window.
+    pub(crate) fn get_batched_count(&self) -> usize {
+        self.candle_batch.len()
+    }
+
     /// Garbage collect the ledger every tick
     /// Return: list of ops that we have removed
     #[cfg(not(target_arch = "wasm32"))]
@@ -660,9 +1451,10 @@ impl SniperEngine {
         // Drop lock before async operations (though we just fire and forget mostly)
         drop(ts_guard);
 
+        // The mock exchange promises no writes: synthetic trades never reach the results DB
         if !dead_trades.is_empty() {
             #[cfg(not(target_arch = "wasm32"))]
-            {
+            if mock_exchange().is_none() {
                 #[cfg(debug_assertions)]
                 if DF.log_results_repo {
                     for t in &dead_trades {
@@ -704,20 +1496,78 @@ impl SniperEngine {
             if DF.log_ledger {
                 log::info!("LEDGER PRUNE: Removing opportunity id {} from ledger", id);
             }
+            if let Some(op) = self.engine_ledger.opportunities.get(id) {
+                self.session_stats.record_expiry(&op.pair_name);
+            }
             self.engine_ledger.remove_from_ledger(id);
         }
         ids_to_remove
     }
 
+    /// Tracks the best / worst live ROI seen across the ledger this session.
+    fn observe_live_rois(&mut self) {
+        for op in self.engine_ledger.opportunities.values() {
+            if let Some(price) = self.price_stream.get_price(&op.pair_name) {
+                self.session_stats
+                    .observe_live_roi(&op.pair_name, op.live_roi(price));
+            }
+        }
+    }
+
     fn handle_job_result(&mut self, result: JobResult) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(journal) = &mut self.journal {
+            journal.record_result(&result);
+        }
+        if result.cancelled {
+            if let Some(state) = self.pairs_states.get_mut(&result.pair_name) {
+                state.is_calculating = false;
+            }
+            return;
+        }
         if let Some(state) = self.pairs_states.get_mut(&result.pair_name) {
+            self.session_stats.recalcs += 1;
             match result.result {
-                Ok(model) => {
+                Ok(mut model) => {
+                    if let Some(scan) = result.tuned {
+                        #[cfg(debug_assertions)]
+                        if DF.log_tuner {
+                            log::info!("🎛️ BULK TUNE [{}] -> PH {}", result.pair_name, scan.best);
+                        }
+                        self.shared_config
+                            .insert_ph(result.pair_name.clone(), scan.best);
+                        self.shared_config
+                            .insert_tuner_scan(result.pair_name.clone(), scan);
+                    }
+                    if let Some(previous) = &state.model {
+                        Arc::make_mut(&mut model).inherit_zone_ids(previous);
+                        if self.recalc_history.len() >= RECALC_HISTORY_LEN {
+                            self.recalc_history.pop_front();
+                        }
+                        self.recalc_history.push_back(RecalcRecord {
+                            pair: result.pair_name.clone(),
+                            completed_at: TimestampMs::now(),
+                            diff: ModelDiff::between(previous, &model),
+                        });
+                    }
                     for op in &model.opportunities {
-                        self.engine_ledger.evolve(
+                        let (is_new, _) = self.engine_ledger.evolve(
                             op.clone(),
                             DEFAULT_JOURNEY_SETTINGS.optimization.fuzzy_match_tolerance,
                         );
+                        if is_new {
+                            self.session_stats.record_birth(&op.pair_name);
+                        }
+                    }
+                    if let (Some(station), Some(ph)) = (
+                        self.shared_config.get_station(&result.pair_name),
+                        self.shared_config.get_ph(&result.pair_name),
+                    ) {
+                        self.shared_config.record_station_stats(
+                            result.pair_name.clone(),
+                            station,
+                            StationStats::from_model(&model, ph),
+                        );
                     }
                     state.model = Some(model.clone());
                     #[cfg(debug_assertions)]
@@ -729,10 +1579,21 @@ impl SniperEngine {
                     }
                     state.is_calculating = false;
                     state.last_error = None;
+                    state.consecutive_failures = 0;
+                    state.retry_after = None;
                 }
                 Err(e) => {
-                    // Failure: Clear Model, Set Error
-                    log::error!("Worker failed for {}: {}", result.pair_name, e);
+                    // Failure: Clear Model, Set Error, back off automatic retries
+                    state.consecutive_failures += 1;
+                    let backoff = failure_backoff(state.consecutive_failures);
+                    state.retry_after = Some(AppInstant::now() + backoff);
+                    log::error!(
+                        "Worker failed for {} ({} in a row, auto-retry in {}s): {}",
+                        result.pair_name,
+                        state.consecutive_failures,
+                        backoff.as_secs(),
+                        e
+                    );
                     state.last_error = Some(e);
                     #[cfg(debug_assertions)]
                     if DF.log_engine_core {
@@ -746,6 +1607,7 @@ impl SniperEngine {
                 }
             }
         }
+        self.refresh_min_viable_ph(&result.pair_name);
     }
 
     #[cfg(debug_assertions)]
@@ -763,6 +1625,11 @@ impl SniperEngine {
     }
 
     fn trigger_recalcs_on_price_changes(&mut self) {
+        let throttle = self.shared_config.get_throttle();
+        if throttle.paused || self.hold_auto_recalcs {
+            return;
+        }
+        let min_interval = throttle.min_interval();
         let threshold = PRICE_RECALC_THRESHOLD_PCT;
         let pairs: Vec<String> = self.active_engine_pairs.to_vec();
         for pair_name in pairs {
@@ -773,6 +1640,9 @@ impl SniperEngine {
                 let Some(state) = self.pairs_states.get_mut(&pair_name) else {
                     continue;
                 };
+                if state.in_backoff() {
+                    continue;
+                }
 
                 if state.last_update_price.value() == 0.0 {
                     #[cfg(debug_assertions)]
@@ -788,7 +1658,11 @@ impl SniperEngine {
                 } else {
                     let pct_diff =
                         PhPct::new(current_price.percent_diff_from_0_1(&state.last_update_price));
-                    let triggered = pct_diff > threshold;
+                    // Within the interval the move keeps accumulating against the old price
+                    let cooling_down = state
+                        .last_auto_recalc
+                        .is_some_and(|t| t.elapsed() < min_interval);
+                    let triggered = pct_diff > threshold && !cooling_down;
 
                     #[cfg(debug_assertions)]
                     if triggered && DF.log_engine_core {
@@ -850,12 +1724,80 @@ impl SniperEngine {
 
             if let Some(state) = self.pairs_states.get_mut(&pair_name) {
                 state.last_update_price = current_price;
+                state.last_auto_recalc = Some(AppInstant::now());
             }
         }
     }
 
+    /// Keeps at most one speculative job alive for the hovered pair. A prefetch for the
+    /// pair that just became selected is kept: a running one finishes as a normal job and one
+    /// still waiting for the worker jumps the queue. Any other stale one is cancelled.
+    pub(crate) fn update_prefetch(&mut self, hovered: Option<&str>, selected: Option<&str>) {
+        if let Some(prefetch) = self.prefetch.take() {
+            if Some(prefetch.pair.as_str()) == selected {
+                if let Some(mut job) = prefetch.job {
+                    job.mode = JobMode::FullAnalysis;
+                    self.queue.push_front(job);
+                }
+            } else if Some(prefetch.pair.as_str()) == hovered {
+                self.prefetch = Some(prefetch);
+                return;
+            } else {
+                prefetch.cancel.store(true, Ordering::Relaxed);
+                #[cfg(debug_assertions)]
+                if DF.log_engine_core {
+                    log::info!("ENGINE PREFETCH: cancelled [{}]", prefetch.pair);
+                }
+            }
+        }
+
+        let Some(pair) = hovered.filter(|p| Some(*p) != selected) else {
+            return;
+        };
+        let Some(state) = self.pairs_states.get(pair) else {
+            return;
+        };
+        if state.model.is_some() || state.is_calculating {
+            return;
+        }
+        // Already queued: just move it to the front
+        if let Some(pos) = self.queue.iter().position(|j| j.pair == pair) {
+            if let Some(job) = self.queue.remove(pos) {
+                self.queue.push_front(job);
+            }
+            return;
+        }
+        let (Some(ph_pct), Some(station_id)) = (
+            self.shared_config.get_ph(pair),
+            self.shared_config.get_station(pair),
+        ) else {
+            return;
+        };
+
+        #[cfg(debug_assertions)]
+        if DF.log_engine_core {
+            log::info!("ENGINE PREFETCH: scheduling [{}]", pair);
+        }
+        self.prefetch = Some(Prefetch {
+            pair: pair.to_string(),
+            job: Some(EngineJob {
+                pair: pair.to_string(),
+                price_override: None,
+                ph_pct,
+                strategy: self.shared_config.get_strategy(),
+                station_id,
+                mode: JobMode::Speculative,
+            }),
+            cancel: Arc::new(AtomicBool::new(false)),
+        });
+    }
+
     fn process_queue(&mut self) {
         if self.queue.is_empty() {
+            // Speculative work only runs when nothing real is waiting
+            if let Some(job) = self.prefetch.as_mut().and_then(|p| p.job.take()) {
+                self.dispatch_job(job);
+            }
             return;
         }
         if let Some(job) = self.queue.pop_front() {
@@ -914,27 +1856,72 @@ impl SniperEngine {
                 state.last_update_price = p;
             }
 
-            let req = JobRequest {
-                pair_name: job.pair,
-                current_price: final_price_opt,
-                timeseries: self.timeseries.clone(),
-                ph_pct: job.ph_pct,
-                strategy: job.strategy,
-                station_id: job.station_id,
-                mode: job.mode,
-            };
+            let cancel = (job.mode == JobMode::Speculative)
+                .then(|| self.prefetch.as_ref().map(|p| p.cancel.clone()))
+                .flatten();
+            let req = self.build_job_request(job, final_price_opt, cancel);
 
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Some(journal) = &mut self.journal {
+                journal.record_request(&req);
+            }
             let _ = self.job_tx.send(req);
         }
     }
 
-    fn enqueue_or_replace(&mut self, job: EngineJob) {
+    /// Worker request for `job` with the current shared settings.
+    fn build_job_request(
+        &self,
+        job: EngineJob,
+        current_price: Option<Price>,
+        cancel: Option<Arc<AtomicBool>>,
+    ) -> JobRequest {
+        JobRequest {
+            cancel,
+            adaptive: self.shared_config.get_adaptive(&job.pair),
+            zone_config: self.shared_config.get_zone_config(),
+            zone_edits: self.shared_config.get_zone_edits(&job.pair),
+            exclude_anomalies: self.shared_config.get_exclude_anomalies(),
+            ladder_targets: self.shared_config.get_ladder_targets(),
+            custom_targets: self.shared_config.get_custom_targets(&job.pair),
+            trade_profile: self.shared_config.get_trade_profile(),
+            retune: (job.mode == JobMode::Retune)
+                .then(|| self.retune_input(&job.pair, job.station_id))
+                .flatten(),
+            pair_name: job.pair,
+            current_price,
+            timeseries: self.timeseries.clone(),
+            ph_pct: job.ph_pct,
+            strategy: job.strategy,
+            station_id: job.station_id,
+            mode: job.mode,
+        }
+    }
+
+    fn retune_input(&self, pair: &str, station_id: StationId) -> Option<RetuneInput> {
+        Some(RetuneInput {
+            station: *TUNER_CONFIG.stations.iter().find(|s| s.id == station_id)?,
+            weights: self.shared_config.get_tuner_weights(),
+            min_ph: self.get_min_viable_ph(pair),
+            previous: self.shared_config.get_tuner_scan(pair),
+        })
+    }
+
+    fn enqueue_or_replace(&mut self, mut job: EngineJob) {
         if let Some(pos) = self.queue.iter().position(|j| j.pair == job.pair) {
             #[cfg(debug_assertions)]
             if DF.log_engine_core {
                 log::info!("ENGINE QUEUE: Replacing queued job for pair [{}]", job.pair);
             }
-            self.queue.remove(pos);
+            // A plain recalc arriving behind a queued re-tune keeps the re-tune
+            if self
+                .queue
+                .remove(pos)
+                .is_some_and(|queued| queued.mode == JobMode::Retune)
+                && job.mode == JobMode::FullAnalysis
+            {
+                job.mode = JobMode::Retune;
+            }
         } else {
             #[cfg(debug_assertions)]
             if DF.log_engine_core {
@@ -0,0 +1,427 @@
+use {
+    crate::{
+        app::{
+            BASE_INTERVAL, PhPct, Price, PriceLike, TimestampMs, TradeProfile,
+            ZoneClassificationConfig,
+        },
+        data::{MarketDataStorage, SqliteStorage, TimeSeriesCollection},
+        domain::PairInterval,
+        engine::{JobMode, JobRequest, JobResult, StationId, process_request_sync},
+        models::{
+            AdaptiveCurves, CustomZone, OhlcvTimeSeries, OptimizationStrategy, TradeDirection,
+            TradingModel, ZoneEdit, find_matching_ohlcv,
+        },
+        utils::TimeUtils,
+    },
+    anyhow::{Context, Result, anyhow},
+    serde::{Deserialize, Serialize},
+    std::{
+        collections::HashMap,
+        fmt,
+        fs::{File, OpenOptions},
+        io::{BufRead, BufReader, Write},
+        panic::{self, AssertUnwindSafe},
+        path::Path,
+        sync::{Arc, RwLock, mpsc::channel},
+    },
+};
+
+/// One line of the job journal (JSON lines, appended as jobs are dispatched and completed).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub(crate) enum JournalRecord {
+    Request(JournalRequest),
+    Result(JournalResult),
+}
+
+/// Everything a worker needs to recompute a job, except the candles themselves: those are
+/// reloaded from the kline DB and cut at `last_candle`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct JournalRequest {
+    pub seq: u64,
+    pub recorded_at: TimestampMs,
+    pub pair_name: String,
+    pub current_price: Option<Price>,
+    pub ph_pct: PhPct,
+    pub strategy: OptimizationStrategy,
+    pub station_id: StationId,
+    pub mode: JobMode,
+    pub adaptive: AdaptiveCurves,
+    pub zone_config: ZoneClassificationConfig,
+    #[serde(default)]
+    pub zone_edits: Vec<ZoneEdit>,
+    pub exclude_anomalies: bool,
+    pub ladder_targets: bool,
+    #[serde(default)]
+    pub custom_targets: Vec<CustomZone>,
+    pub trade_profile: TradeProfile,
+    pub candle_count: usize,
+    pub last_candle: Option<TimestampMs>,
+    /// Hash of the analysis settings above, to spot config drift between runs at a glance
+    pub config_hash: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct JournalResult {
+    pub seq: u64,
+    pub pair_name: String,
+    pub error: Option<String>,
+    pub opportunities: usize,
+    pub result_hash: u64,
+    /// PH a re-tune job settled on (its request records the PH before tuning)
+    #[serde(default)]
+    pub tuned_ph: Option<PhPct>,
+}
+
+impl JournalRequest {
+    fn from_request(seq: u64, req: &JobRequest) -> Self {
+        let (candle_count, last_candle) = req
+            .timeseries
+            .read()
+            .ok()
+            .and_then(|ts| {
+                let series = find_matching_ohlcv(
+                    &ts.series_data,
+                    &req.pair_name,
+                    BASE_INTERVAL.as_millis() as i64,
+                )
+                .ok()?;
+                Some((series.timestamps.len(), series.timestamps.last().copied()))
+            })
+            .unwrap_or((0, None));
+        let mut entry = Self {
+            seq,
+            recorded_at: TimestampMs::now(),
+            pair_name: req.pair_name.clone(),
+            current_price: req.current_price,
+            ph_pct: req.ph_pct,
+            strategy: req.strategy,
+            station_id: req.station_id,
+            mode: req.mode.clone(),
+            adaptive: req.adaptive.clone(),
+            zone_config: req.zone_config.clone(),
+            zone_edits: req.zone_edits.clone(),
+            exclude_anomalies: req.exclude_anomalies,
+            ladder_targets: req.ladder_targets,
+            custom_targets: req.custom_targets.clone(),
+            trade_profile: req.trade_profile.clone(),
+            candle_count,
+            last_candle,
+            config_hash: 0,
+        };
+        entry.config_hash = entry.settings_hash();
+        entry
+    }
+
+    fn settings_hash(&self) -> u64 {
+        let settings = serde_json::to_string(&(
+            self.ph_pct,
+            self.strategy,
+            self.station_id,
+            &self.adaptive,
+            &self.zone_config,
+            self.exclude_anomalies,
+            self.ladder_targets,
+            &self.trade_profile,
+        ))
+        .unwrap_or_default();
+        fnv1a(settings.as_bytes())
+    }
+
+    fn to_job_request(&self, timeseries: Arc<RwLock<TimeSeriesCollection>>) -> JobRequest {
+        JobRequest {
+            pair_name: self.pair_name.clone(),
+            current_price: self.current_price,
+            timeseries,
+            ph_pct: self.ph_pct,
+            strategy: self.strategy,
+            station_id: self.station_id,
+            mode: self.mode.clone(),
+            adaptive: self.adaptive.clone(),
+            zone_config: self.zone_config.clone(),
+            zone_edits: self.zone_edits.clone(),
+            exclude_anomalies: self.exclude_anomalies,
+            ladder_targets: self.ladder_targets,
+            custom_targets: self.custom_targets.clone(),
+            trade_profile: self.trade_profile.clone(),
+            // Re-tunes replay at the PH they recorded, not a fresh scan
+            retune: None,
+            cancel: None,
+        }
+    }
+}
+
+/// FNV-1a; unlike `DefaultHasher` it is stable across builds, so hashes in old journals
+/// stay comparable.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, b| {
+        (hash ^ u64::from(*b)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Hash of a job's numeric output (zones and opportunities), for spotting divergent replays.
+pub(crate) fn result_hash(model: &TradingModel) -> u64 {
+    let mut bytes = Vec::new();
+    let zones = &model.zones;
+    for zone in zones
+        .sticky_superzones
+        .iter()
+        .chain(&zones.low_wicks_superzones)
+        .chain(&zones.high_wicks_superzones)
+    {
+        bytes.extend(zone.price_bottom.value().to_bits().to_le_bytes());
+        bytes.extend(zone.price_top.value().to_bits().to_le_bytes());
+    }
+    for op in &model.opportunities {
+        bytes.push(u8::from(op.direction == TradeDirection::Long));
+        for value in [
+            op.start_price.value(),
+            op.target_price.value(),
+            op.stop_price.value(),
+            op.simulation.success_rate.value(),
+            op.simulation.avg_pnl_pct.value(),
+        ] {
+            bytes.extend(value.to_bits().to_le_bytes());
+        }
+        bytes.extend((op.simulation.sample_size as u64).to_le_bytes());
+    }
+    fnv1a(&bytes)
+}
+
+/// Appends dispatched jobs and their results to a file. Each line is flushed immediately
+/// so the request that crashed a worker is on disk.
+pub(crate) struct JobJournal {
+    file: File,
+    next_seq: u64,
+    /// Latest request seq per pair (one job per pair is in flight at a time)
+    in_flight: HashMap<String, u64>,
+}
+
+impl JobJournal {
+    pub(crate) fn open(path: &Path) -> Result<Self> {
+        let next_seq = read_journal(path)
+            .map(|records| {
+                records
+                    .iter()
+                    .filter_map(|r| match r {
+                        JournalRecord::Request(req) => Some(req.seq + 1),
+                        JournalRecord::Result(_) => None,
+                    })
+                    .max()
+                    .unwrap_or(0)
+            })
+            .unwrap_or(0);
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("Failed to open job journal {}", path.display()))?;
+        Ok(Self {
+            file,
+            next_seq,
+            in_flight: HashMap::new(),
+        })
+    }
+
+    pub(crate) fn record_request(&mut self, req: &JobRequest) {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        self.in_flight.insert(req.pair_name.clone(), seq);
+        self.write(&JournalRecord::Request(JournalRequest::from_request(
+            seq, req,
+        )));
+    }
+
+    pub(crate) fn record_result(&mut self, result: &JobResult) {
+        let Some(seq) = self.in_flight.remove(&result.pair_name) else {
+            return;
+        };
+        if result.cancelled {
+            return;
+        }
+        let (error, opportunities, result_hash) = match &result.result {
+            Ok(model) => (None, model.opportunities.len(), self::result_hash(model)),
+            Err(e) => (Some(e.clone()), 0, 0),
+        };
+        self.write(&JournalRecord::Result(JournalResult {
+            seq,
+            pair_name: result.pair_name.clone(),
+            error,
+            opportunities,
+            result_hash,
+            tuned_ph: result.tuned.as_ref().map(|scan| scan.best),
+        }));
+    }
+
+    fn write(&mut self, record: &JournalRecord) {
+        let written = serde_json::to_string(record)
+            .map_err(anyhow::Error::from)
+            .and_then(|line| Ok(writeln!(self.file, "{}", line)?))
+            .and_then(|_| Ok(self.file.flush()?));
+        if let Err(e) = written {
+            log::error!("Job journal write failed: {:#}", e);
+        }
+    }
+}
+
+fn read_journal(path: &Path) -> Result<Vec<JournalRecord>> {
+    let file = File::open(path)
+        .with_context(|| format!("Failed to open job journal {}", path.display()))?;
+    BufReader::new(file)
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| line.as_ref().is_ok_and(|l| !l.trim().is_empty()))
+        .map(|(i, line)| {
+            serde_json::from_str(&line?).with_context(|| format!("Bad journal line {}", i + 1))
+        })
+        .collect()
+}
+
+/// How a replayed job compared with the recorded run.
+#[derive(Debug, Clone)]
+pub enum ReplayStatus {
+    Match,
+    Diverged {
+        recorded: u64,
+        replayed: u64,
+    },
+    /// The recorded run never reported back (crash, or the app was closed)
+    NoRecordedResult {
+        replayed: u64,
+    },
+    Failed(String),
+    Panicked(String),
+    MissingData(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct ReplayOutcome {
+    pub seq: u64,
+    pub pair: String,
+    pub mode: String,
+    pub status: ReplayStatus,
+}
+
+impl fmt::Display for ReplayOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:<5} {:<12} {:<13} ", self.seq, self.pair, self.mode)?;
+        match &self.status {
+            ReplayStatus::Match => write!(f, "✅ match"),
+            ReplayStatus::Diverged { recorded, replayed } => {
+                write!(f, "❌ diverged ({:016x} -> {:016x})", recorded, replayed)
+            }
+            ReplayStatus::NoRecordedResult { replayed } => {
+                write!(f, "⚠ no recorded result (replay {:016x})", replayed)
+            }
+            ReplayStatus::Failed(e) => write!(f, "❌ failed: {}", e),
+            ReplayStatus::Panicked(msg) => write!(f, "💥 panicked: {}", msg),
+            ReplayStatus::MissingData(msg) => write!(f, "⚠ {}", msg),
+        }
+    }
+}
+
+/// Re-runs journaled jobs (all, or just `only_seq`) on candles from `db_path`, comparing
+/// each result with the recorded one. Worker panics are caught and reported.
+pub async fn replay_journal(
+    path: &Path,
+    only_seq: Option<u64>,
+    db_path: &str,
+) -> Result<Vec<ReplayOutcome>> {
+    let records = read_journal(path)?;
+    let recorded: HashMap<u64, &JournalResult> = records
+        .iter()
+        .filter_map(|r| match r {
+            JournalRecord::Result(res) => Some((res.seq, res)),
+            JournalRecord::Request(_) => None,
+        })
+        .collect();
+    let requests: Vec<&JournalRequest> = records
+        .iter()
+        .filter_map(|r| match r {
+            JournalRecord::Request(req) => Some(req),
+            JournalRecord::Result(_) => None,
+        })
+        .filter(|req| only_seq.is_none_or(|seq| req.seq == seq))
+        .collect();
+    if requests.is_empty() {
+        return Err(anyhow!("No matching job requests in {}", path.display()));
+    }
+
+    let storage = SqliteStorage::new(db_path)
+        .await
+        .with_context(|| format!("Failed to open {}", db_path))?;
+    let interval_ms = BASE_INTERVAL.as_millis() as i64;
+    let interval_str = TimeUtils::interval_to_string(interval_ms);
+
+    let mut outcomes = Vec::new();
+    for req in requests {
+        let candles: Vec<_> = storage
+            .load_candles(&req.pair_name, interval_str, None)
+            .await?
+            .into_iter()
+            .filter(|c| req.last_candle.is_none_or(|last| c.timestamp_ms <= last))
+            .collect();
+        let mut outcome = ReplayOutcome {
+            seq: req.seq,
+            pair: req.pair_name.clone(),
+            mode: format!("{:?}", req.mode),
+            status: ReplayStatus::Match,
+        };
+        if candles.len() != req.candle_count {
+            outcome.status = ReplayStatus::MissingData(format!(
+                "DB has {} candles up to the recorded last candle, job saw {}",
+                candles.len(),
+                req.candle_count
+            ));
+            outcomes.push(outcome);
+            continue;
+        }
+
+        let pair_interval = PairInterval {
+            name: req.pair_name.clone(),
+            interval_ms,
+        };
+        let timeseries = Arc::new(RwLock::new(TimeSeriesCollection {
+            name: "Journal Replay".to_string(),
+            version: 1.0,
+            series_data: vec![OhlcvTimeSeries::from_candles(pair_interval, candles)],
+        }));
+        let (tx, rx) = channel();
+        let mut job = req.to_job_request(timeseries);
+        if let Some(ph) = recorded.get(&req.seq).and_then(|r| r.tuned_ph) {
+            job.ph_pct = ph;
+        }
+        let run = panic::catch_unwind(AssertUnwindSafe(|| process_request_sync(job, tx)));
+        outcome.status = match (run, rx.try_recv()) {
+            (Err(payload), _) => ReplayStatus::Panicked(
+                payload
+                    .downcast_ref::<String>()
+                    .cloned()
+                    .or_else(|| payload.downcast_ref::<&str>().map(|s| s.to_string()))
+                    .unwrap_or_default(),
+            ),
+            (
+                Ok(()),
+                Ok(JobResult {
+                    result: Ok(model), ..
+                }),
+            ) => {
+                let replayed = result_hash(&model);
+                match recorded.get(&req.seq) {
+                    Some(r) if r.error.is_none() && r.result_hash == replayed => {
+                        ReplayStatus::Match
+                    }
+                    Some(r) => ReplayStatus::Diverged {
+                        recorded: r.result_hash,
+                        replayed,
+                    },
+                    None => ReplayStatus::NoRecordedResult { replayed },
+                }
+            }
+            (Ok(()), Ok(JobResult { result: Err(e), .. })) => ReplayStatus::Failed(e),
+            (Ok(()), Err(_)) => ReplayStatus::Failed("worker sent no result".to_string()),
+        };
+        outcomes.push(outcome);
+    }
+    Ok(outcomes)
+}
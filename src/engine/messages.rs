@@ -1,17 +1,25 @@
 use {
     crate::{
-        app::{PhPct, Price},
+        app::{PhPct, Price, TradeProfile, ZoneClassificationConfig},
         data::TimeSeriesCollection,
-        engine::StationId,
-        models::{OptimizationStrategy, TradingModel},
+        engine::{StationId, TunerScan, TunerStation, TunerWeights},
+        models::{AdaptiveCurves, CustomZone, OptimizationStrategy, TradingModel, ZoneEdit},
+    },
+    serde::{Deserialize, Serialize},
+    std::sync::{
+        Arc, RwLock,
+        atomic::{AtomicBool, Ordering},
     },
-    std::sync::{Arc, RwLock},
 };
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub(crate) enum JobMode {
     FullAnalysis,
     ContextOnly,
+    /// Low-priority full analysis for a pair the user is likely to open next (cancellable).
+    Speculative,
+    /// Full analysis at the PH the time tuner finds for the job's station (bulk re-tune).
+    Retune,
 }
 
 /// Job request for pair analysis.
@@ -25,10 +33,58 @@ pub(crate) struct JobRequest {
     pub strategy: OptimizationStrategy,
     pub station_id: StationId,
     pub mode: JobMode,
+    pub adaptive: AdaptiveCurves,
+    pub zone_config: ZoneClassificationConfig,
+    /// Manual zone splits / merges for this pair
+    pub zone_edits: Vec<ZoneEdit>,
+    /// Leave flagged candles out of CVA scoring and journey matching
+    pub exclude_anomalies: bool,
+    /// Attach laddered targets (next zones in the trade direction) to each opportunity
+    pub ladder_targets: bool,
+    /// User-drawn zones to simulate as extra targets (empty unless enabled)
+    pub custom_targets: Vec<CustomZone>,
+    pub trade_profile: TradeProfile,
+    /// Set for `JobMode::Retune`
+    pub retune: Option<RetuneInput>,
+    /// Set by the engine to abandon a speculative job that is no longer wanted.
+    pub cancel: Option<Arc<AtomicBool>>,
+}
+
+/// What a re-tune needs besides the candles. The PH it finds replaces the request's.
+#[derive(Debug, Clone)]
+pub(crate) struct RetuneInput {
+    pub station: TunerStation,
+    pub weights: TunerWeights,
+    pub min_ph: Option<PhPct>,
+    /// Last scan for the pair, to warm-start from
+    pub previous: Option<TunerScan>,
+}
+
+impl JobRequest {
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.cancel
+            .as_ref()
+            .is_some_and(|flag| flag.load(Ordering::Relaxed))
+    }
 }
 
 #[derive(Debug, Clone)]
 pub(crate) struct JobResult {
     pub pair_name: String,
     pub result: Result<Arc<TradingModel>, String>,
+    /// True if the job was abandoned; the engine keeps the pair's previous state.
+    pub cancelled: bool,
+    /// Re-tune jobs: the scan whose best PH the model was built at
+    pub tuned: Option<TunerScan>,
+}
+
+impl JobResult {
+    pub(crate) fn cancelled(req: &JobRequest) -> Self {
+        Self {
+            pair_name: req.pair_name.clone(),
+            result: Err("Cancelled".to_string()),
+            cancelled: true,
+            tuned: None,
+        }
+    }
 }
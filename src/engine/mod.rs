@@ -1,26 +1,67 @@
 #[cfg(feature = "backtest")]
 mod backtest;
 mod core;
+#[cfg(not(target_arch = "wasm32"))]
+mod journal;
 mod messages;
+mod portfolio;
+mod sectors;
+mod session_stats;
+mod stress;
+mod telemetry;
+mod throttle;
 mod tuner;
+mod walk_forward;
+mod what_if;
 mod worker;
 
 pub use core::SniperEngine;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub use journal::{ReplayOutcome, ReplayStatus, replay_journal};
+
+pub(crate) use {
+    core::{PhPreview, RecalcRecord},
+    messages::{JobMode, JobRequest, JobResult, RetuneInput},
+    portfolio::{
+        BASKET_MAX_POSITIONS, Basket, BasketConstraints, BasketLeg, BasketSkip, CorrelationInput,
+        CorrelationMatrix, optimize_basket,
+    },
+    sectors::{PairPulse, SectorReport, SectorStrength, build_sector_strength},
+    session_stats::SessionStats,
+    stress::{
+        STRESS_LEADER_PAIR, StressOutcome, StressReport, StressRow, StressScenario, evaluate_stress,
+    },
+    telemetry::{JobTimings, MemoryReport, TELEMETRY_LOG_INTERVAL, WorkerTelemetry, format_bytes},
+    throttle::{CandleBatch, RecalcThrottle, failure_backoff},
+    tuner::{
+        StationId, StationStats, TUNER_CONFIG, TimeTunerConfig, TunerScan, TunerStation,
+        TunerWeights, score_probes, tune_to_station,
+    },
+    walk_forward::{HeatCalendar, HeatCalendarInput, HeatWeek, RuleBacktest, build_heat_calendar},
+    what_if::{
+        PriceEvaluation, StationPreview, StationPreviewInput, evaluate_requests, preview_station,
+    },
+    worker::{process_request_sync, run_pathfinder_simulations},
+};
+
+#[cfg(test)]
 pub(crate) use {
-    messages::{JobMode, JobRequest, JobResult},
-    tuner::{StationId, TUNER_CONFIG, TimeTunerConfig, TunerStation, tune_to_station},
-    worker::run_pathfinder_simulations,
+    tuner::{TunerFingerprint, TunerProbe},
+    walk_forward::WalkForwardTrade,
 };
 
+#[cfg(feature = "backtest")]
+pub(crate) use walk_forward::{replay_opportunity_forward, truncate_ohlcv};
+
 #[cfg(feature = "backtest")]
 pub(crate) use backtest::{
     BACKTEST_MODEL_DESC, BACKTEST_MODEL_VERSION, BACKTEST_PAIR_COUNT, BACKTEST_SKIP_DB_WRITE,
     BacktestConfig, run_backtest,
 };
 
-#[cfg(target_arch = "wasm32")]
-pub(crate) use worker::process_request_sync;
-
 #[cfg(not(target_arch = "wasm32"))]
-pub(crate) use worker::spawn_worker_thread;
+pub(crate) use {journal::JobJournal, worker::spawn_worker_thread};
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) use walk_forward::HeatCalendarBuilder;
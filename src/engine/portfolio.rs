@@ -0,0 +1,241 @@
+use {
+    crate::{
+        app::TimestampMs,
+        models::{BetaEstimate, OhlcvTimeSeries, TradeDirection, TradeOpportunity},
+    },
+    serde::{Deserialize, Serialize},
+    std::{
+        collections::{HashMap, HashSet},
+        time::Duration,
+    },
+};
+
+/// Most positions the basket panel lets a basket hold.
+pub(crate) const BASKET_MAX_POSITIONS: usize = 10;
+/// A correlation matrix older than this is rebuilt before the next suggestion.
+const CORRELATION_MAX_AGE: Duration = Duration::from_secs(15 * 60);
+
+/// Limits a suggested basket is built within. Each leg is staked at its Kelly fraction, the
+/// same sizing the opportunity panels show; the budget caps the basket as a whole.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct BasketConstraints {
+    pub positions: usize,
+    /// Highest correlation allowed between two legs that move the account the same way (a
+    /// short against a correlated long counts as negative)
+    pub max_correlation: f64,
+    /// Most of the account the legs' Kelly stakes may put at risk together, with correlated
+    /// legs adding up and offsetting ones cancelling out
+    pub stake_budget: f64,
+}
+
+impl Default for BasketConstraints {
+    fn default() -> Self {
+        Self {
+            positions: 5,
+            max_correlation: 0.8,
+            stake_budget: 0.5,
+        }
+    }
+}
+
+/// Everything [`CorrelationMatrix::build`] needs, detached from the engine so it can run on a
+/// background thread.
+#[derive(Clone)]
+pub(crate) struct CorrelationInput {
+    /// Every pair asked for, including those without candles
+    pub pairs: Vec<String>,
+    pub series: Vec<OhlcvTimeSeries>,
+}
+
+/// Return correlations between pairs. Pairs without enough shared history have no entry:
+/// their correlation is unknown, not zero.
+#[derive(Debug, Clone)]
+pub(crate) struct CorrelationMatrix {
+    pairs: HashSet<String>,
+    values: HashMap<(String, String), f64>,
+    pub built_at: TimestampMs,
+}
+
+impl CorrelationMatrix {
+    /// Correlates every pair of series on their recent returns. O(n²) in the series; call off
+    /// the UI thread where possible.
+    pub(crate) fn build(input: &CorrelationInput) -> Self {
+        let mut values = HashMap::new();
+        for (i, a) in input.series.iter().enumerate() {
+            for b in input.series.iter().skip(i + 1) {
+                let Some(estimate) =
+                    BetaEstimate::between(a, b).filter(|estimate| estimate.is_sampled())
+                else {
+                    continue;
+                };
+                values.insert(
+                    Self::key(&a.pair_interval.name, &b.pair_interval.name),
+                    estimate.correlation,
+                );
+            }
+        }
+        Self {
+            pairs: input.pairs.iter().cloned().collect(),
+            values,
+            built_at: TimestampMs::now(),
+        }
+    }
+
+    fn key(a: &str, b: &str) -> (String, String) {
+        if a <= b {
+            (a.to_string(), b.to_string())
+        } else {
+            (b.to_string(), a.to_string())
+        }
+    }
+
+    /// Correlation of the two pairs' returns, None when it could not be estimated.
+    pub(crate) fn get(&self, a: &str, b: &str) -> Option<f64> {
+        self.values.get(&Self::key(a, b)).copied()
+    }
+
+    /// Recent enough to reuse and built with every pair in `pairs`.
+    pub(crate) fn covers<'a>(&self, mut pairs: impl Iterator<Item = &'a str>) -> bool {
+        TimestampMs::now().value() - self.built_at.value() <= CORRELATION_MAX_AGE.as_millis() as i64
+            && pairs.all(|pair| self.pairs.contains(pair))
+    }
+}
+
+/// Why a candidate with an edge was left out of the basket.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum BasketSkip {
+    Correlated {
+        with: String,
+        correlation: f64,
+    },
+    /// No correlation estimate against a leg already in, so the risk can't be bounded
+    UnknownCorrelation {
+        with: String,
+    },
+    OverBudget,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct BasketLeg {
+    pub op: TradeOpportunity,
+    /// Share of the account at risk on this leg (its Kelly fraction)
+    pub stake: f64,
+    /// Highest exposure correlation to a leg picked before it (None for the first leg)
+    pub max_correlation: Option<(String, f64)>,
+}
+
+/// Positions proposed from the opportunity set, best expectancy first.
+#[derive(Debug, Clone)]
+pub(crate) struct Basket {
+    pub legs: Vec<BasketLeg>,
+    pub skipped: Vec<(TradeOpportunity, BasketSkip)>,
+    /// Expected account return over one round of the legs (sum of expectancy × stake)
+    pub expectancy: f64,
+    /// Correlation-aware risk: sqrt of the summed pairwise exposure covariances
+    pub risk: f64,
+    pub constraints: BasketConstraints,
+    pub built_at: TimestampMs,
+}
+
+fn exposure_sign(direction: TradeDirection) -> f64 {
+    match direction {
+        TradeDirection::Long => 1.0,
+        TradeDirection::Short => -1.0,
+    }
+}
+
+/// Greedy pick by expectancy: only positive-expectancy opportunities, the best one per pair,
+/// each taken unless it is too correlated with a leg already in, has no correlation estimate
+/// against one, or would break the risk budget.
+pub(crate) fn optimize_basket(
+    ops: &[TradeOpportunity],
+    correlations: &CorrelationMatrix,
+    constraints: &BasketConstraints,
+) -> Basket {
+    let mut best: HashMap<&str, &TradeOpportunity> = HashMap::new();
+    for op in ops.iter().filter(|op| op.simulation.expectancy_r() > 0.0) {
+        best.entry(&op.pair_name)
+            .and_modify(|b| {
+                if op.simulation.expectancy_r() > b.simulation.expectancy_r() {
+                    *b = op;
+                }
+            })
+            .or_insert(op);
+    }
+    let mut candidates: Vec<&TradeOpportunity> = best.into_values().collect();
+    candidates.sort_by(|a, b| {
+        b.simulation
+            .expectancy_r()
+            .total_cmp(&a.simulation.expectancy_r())
+            .then_with(|| a.pair_name.cmp(&b.pair_name))
+    });
+
+    let mut legs: Vec<BasketLeg> = Vec::new();
+    let mut skipped = Vec::new();
+    // Sum over leg pairs (i, j) of stake_i · stake_j · sign_i · sign_j · correlation_ij
+    let mut risk_sq = 0.0;
+    'candidates: for op in candidates {
+        if legs.len() >= constraints.positions {
+            break;
+        }
+        let stake = op.simulation.kelly_fraction();
+        let mut exposures: Vec<(&BasketLeg, f64)> = Vec::with_capacity(legs.len());
+        for leg in &legs {
+            let Some(c) = correlations.get(&op.pair_name, &leg.op.pair_name) else {
+                skipped.push((
+                    op.clone(),
+                    BasketSkip::UnknownCorrelation {
+                        with: leg.op.pair_name.clone(),
+                    },
+                ));
+                continue 'candidates;
+            };
+            exposures.push((
+                leg,
+                c * exposure_sign(op.direction) * exposure_sign(leg.op.direction),
+            ));
+        }
+        let closest = exposures
+            .iter()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(leg, c)| (leg.op.pair_name.clone(), *c));
+        if let Some((with, c)) = closest
+            .as_ref()
+            .filter(|(_, c)| *c > constraints.max_correlation)
+        {
+            skipped.push((
+                op.clone(),
+                BasketSkip::Correlated {
+                    with: with.clone(),
+                    correlation: *c,
+                },
+            ));
+            continue;
+        }
+        let added = stake * stake
+            + 2.0 * stake * exposures.iter().map(|(leg, c)| leg.stake * c).sum::<f64>();
+        if (risk_sq + added).max(0.0).sqrt() > constraints.stake_budget + 1e-12 {
+            skipped.push((op.clone(), BasketSkip::OverBudget));
+            continue;
+        }
+        risk_sq += added;
+        legs.push(BasketLeg {
+            op: op.clone(),
+            stake,
+            max_correlation: closest,
+        });
+    }
+
+    Basket {
+        expectancy: legs
+            .iter()
+            .map(|l| l.op.simulation.expectancy_r() * l.stake)
+            .sum(),
+        risk: risk_sq.max(0.0).sqrt(),
+        legs,
+        skipped,
+        constraints: *constraints,
+        built_at: TimestampMs::now(),
+    }
+}
@@ -0,0 +1,125 @@
+use {
+    crate::{
+        app::{MomentumPct, PriceLike, TimestampMs, VolatilityPct},
+        domain::PairCategory,
+        models::OhlcvTimeSeries,
+    },
+    std::{collections::HashMap, time::Duration},
+};
+
+/// Candles a pair's momentum and volatility are measured over (2h of 5m candles).
+const SECTOR_TREND_CANDLES: usize = 24;
+
+/// A pair whose last candle is older than this (halted stream, delisting) has no trend.
+const SECTOR_STALE_AFTER: Duration = Duration::from_secs(30 * 60);
+
+/// How often the open sector dashboard re-aggregates.
+const SECTOR_REFRESH: Duration = Duration::from_secs(10);
+
+/// One pair's recent move and live opportunities, before grouping by sector.
+#[derive(Debug, Clone)]
+pub(crate) struct PairPulse {
+    pub pair: String,
+    /// None with too little history
+    pub trend: Option<(MomentumPct, VolatilityPct)>,
+    pub opportunities: usize,
+    /// Opportunities with positive expectancy
+    pub positive_ev: usize,
+    /// Best expectancy (R) among the pair's opportunities
+    pub best_expectancy: Option<f64>,
+}
+
+impl PairPulse {
+    /// Close-to-close move and mean candle range over the last `SECTOR_TREND_CANDLES`, as of
+    /// `now`. None when the series has stopped updating.
+    pub(crate) fn trend_of(
+        ts: &OhlcvTimeSeries,
+        now: TimestampMs,
+    ) -> Option<(MomentumPct, VolatilityPct)> {
+        let end = ts.klines();
+        if end <= SECTOR_TREND_CANDLES {
+            return None;
+        }
+        let last = ts.timestamps[end - 1];
+        if now.value() - last.value() > SECTOR_STALE_AFTER.as_millis() as i64 {
+            return None;
+        }
+        let start = end - 1 - SECTOR_TREND_CANDLES;
+        let momentum = MomentumPct::calculate(
+            ts.close_prices[end - 1].value(),
+            ts.close_prices[start].value(),
+        );
+        Some((momentum, ts.calc_volatility_in_range(start + 1, end)))
+    }
+}
+
+/// Aggregate of every tracked pair in one sector.
+#[derive(Debug, Clone)]
+pub(crate) struct SectorStrength {
+    pub category: PairCategory,
+    pub pairs: usize,
+    /// Means over the pairs with enough history
+    pub momentum: MomentumPct,
+    pub volatility: VolatilityPct,
+    /// Share of those pairs that moved up
+    pub breadth: f64,
+    pub opportunities: usize,
+    pub positive_ev: usize,
+    /// Pair holding the sector's best expectancy, with that expectancy (R)
+    pub best: Option<(String, f64)>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct SectorReport {
+    pub sectors: Vec<SectorStrength>,
+    pub built_at: TimestampMs,
+}
+
+impl SectorReport {
+    pub(crate) fn is_stale(&self) -> bool {
+        TimestampMs::now().value() - self.built_at.value() > SECTOR_REFRESH.as_millis() as i64
+    }
+}
+
+/// Groups `pulses` by sector, most actionable first: more positive-expectancy opportunities,
+/// then more opportunities overall. Sectors with no tracked pair are left out.
+pub(crate) fn build_sector_strength(pulses: &[PairPulse]) -> Vec<SectorStrength> {
+    let mut by_category: HashMap<PairCategory, Vec<&PairPulse>> = HashMap::new();
+    for pulse in pulses {
+        by_category
+            .entry(PairCategory::of_pair(&pulse.pair))
+            .or_default()
+            .push(pulse);
+    }
+    let mut sectors: Vec<SectorStrength> = by_category
+        .into_iter()
+        .map(|(category, pulses)| {
+            let trends: Vec<(MomentumPct, VolatilityPct)> =
+                pulses.iter().filter_map(|p| p.trend).collect();
+            let n = trends.len().max(1) as f64;
+            let rising = trends.iter().filter(|(m, _)| m.value() > 0.0).count();
+            SectorStrength {
+                category,
+                pairs: pulses.len(),
+                momentum: MomentumPct::new(trends.iter().map(|(m, _)| m.value()).sum::<f64>() / n),
+                volatility: VolatilityPct::new(
+                    trends.iter().map(|(_, v)| v.value()).sum::<f64>() / n,
+                ),
+                breadth: rising as f64 / n,
+                opportunities: pulses.iter().map(|p| p.opportunities).sum(),
+                positive_ev: pulses.iter().map(|p| p.positive_ev).sum(),
+                best: pulses
+                    .iter()
+                    .filter_map(|p| p.best_expectancy.map(|e| (p.pair.clone(), e)))
+                    .max_by(|a, b| a.1.total_cmp(&b.1)),
+            }
+        })
+        .collect();
+    sectors.sort_by(|a, b| {
+        b.positive_ev
+            .cmp(&a.positive_ev)
+            .then_with(|| b.opportunities.cmp(&a.opportunities))
+            .then_with(|| a.category.cmp(&b.category))
+    });
+    sectors
+}
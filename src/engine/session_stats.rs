@@ -0,0 +1,73 @@
+use {
+    crate::{app::RoiPct, utils::AppInstant},
+    std::{collections::HashMap, time::Duration},
+};
+
+/// Running tallies for the current session. Nothing here is persisted.
+#[derive(Debug)]
+pub(crate) struct SessionStats {
+    started: AppInstant,
+    pub opportunities_created: usize,
+    pub opportunities_expired: usize,
+    pub recalcs: usize,
+    pub best_live_roi: Option<(String, RoiPct)>,
+    pub worst_live_roi: Option<(String, RoiPct)>,
+    /// Births + expiries per pair
+    churn: HashMap<String, usize>,
+}
+
+impl Default for SessionStats {
+    fn default() -> Self {
+        Self {
+            started: AppInstant::now(),
+            opportunities_created: 0,
+            opportunities_expired: 0,
+            recalcs: 0,
+            best_live_roi: None,
+            worst_live_roi: None,
+            churn: HashMap::new(),
+        }
+    }
+}
+
+impl SessionStats {
+    pub(crate) fn uptime(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    pub(crate) fn record_birth(&mut self, pair: &str) {
+        self.opportunities_created += 1;
+        *self.churn.entry(pair.to_string()).or_default() += 1;
+    }
+
+    pub(crate) fn record_expiry(&mut self, pair: &str) {
+        self.opportunities_expired += 1;
+        *self.churn.entry(pair.to_string()).or_default() += 1;
+    }
+
+    pub(crate) fn observe_live_roi(&mut self, pair: &str, roi: RoiPct) {
+        if self
+            .best_live_roi
+            .as_ref()
+            .is_none_or(|(_, best)| roi > *best)
+        {
+            self.best_live_roi = Some((pair.to_string(), roi));
+        }
+        if self
+            .worst_live_roi
+            .as_ref()
+            .is_none_or(|(_, worst)| roi < *worst)
+        {
+            self.worst_live_roi = Some((pair.to_string(), roi));
+        }
+    }
+
+    /// Pairs with the most opportunity births + expiries, busiest first.
+    pub(crate) fn top_churn(&self, n: usize) -> Vec<(&str, usize)> {
+        let mut pairs: Vec<(&str, usize)> =
+            self.churn.iter().map(|(p, c)| (p.as_str(), *c)).collect();
+        pairs.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        pairs.truncate(n);
+        pairs
+    }
+}
@@ -0,0 +1,185 @@
+use {
+    crate::{
+        app::{Price, PriceLike, TimestampMs},
+        models::{TradeDirection, TradeOpportunity},
+        ui::UI_TEXT,
+    },
+    std::time::Duration,
+    strum_macros::EnumIter,
+};
+
+/// Scenario paths describe this pair; other pairs follow by their beta to it.
+pub(crate) const STRESS_LEADER_PAIR: &str = "BTCUSDT";
+
+const FIVE_MIN: Duration = Duration::from_secs(300);
+const HOUR: Duration = Duration::from_secs(3_600);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter)]
+pub(crate) enum StressScenario {
+    /// −10% in 30 minutes, then a partial bounce
+    FlashCrash,
+    /// +5% in an hour, then a partial fade
+    Squeeze,
+    /// Two days of ±1.5% swings with no trend
+    WeekendChop,
+}
+
+impl StressScenario {
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            Self::FlashCrash => &UI_TEXT.st_flash_crash,
+            Self::Squeeze => &UI_TEXT.st_squeeze,
+            Self::WeekendChop => &UI_TEXT.st_weekend_chop,
+        }
+    }
+
+    pub(crate) fn description(&self) -> &'static str {
+        match self {
+            Self::FlashCrash => &UI_TEXT.st_flash_crash_hover,
+            Self::Squeeze => &UI_TEXT.st_squeeze_hover,
+            Self::WeekendChop => &UI_TEXT.st_weekend_chop_hover,
+        }
+    }
+
+    /// Simulated time between path points.
+    pub(crate) fn step(&self) -> Duration {
+        match self {
+            Self::FlashCrash | Self::Squeeze => FIVE_MIN,
+            Self::WeekendChop => HOUR,
+        }
+    }
+
+    /// Leader move from the start price at each step (0.0 first).
+    pub(crate) fn path(&self) -> Vec<f64> {
+        let ramp = |from: f64, to: f64, steps: usize| {
+            (1..=steps).map(move |i| from + (to - from) * i as f64 / steps as f64)
+        };
+        let mut path = vec![0.0];
+        match self {
+            Self::FlashCrash => {
+                path.extend(ramp(0.0, -0.10, 6));
+                path.extend(ramp(-0.10, -0.04, 18));
+            }
+            Self::Squeeze => {
+                path.extend(ramp(0.0, 0.05, 12));
+                path.extend(ramp(0.05, 0.03, 12));
+            }
+            Self::WeekendChop => {
+                path.extend(
+                    (1..=48).map(|i| 0.015 * (i as f64 * std::f64::consts::PI / 6.0).sin()),
+                );
+            }
+        }
+        path
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum StressOutcome {
+    TargetHit,
+    StopHit,
+    /// Max duration ran out before target or stop
+    Expired,
+    /// Still running when the scenario ended
+    Open,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct StressRow {
+    pub pair: String,
+    pub opportunity_id: String,
+    pub direction: TradeDirection,
+    pub beta: f64,
+    pub outcome: StressOutcome,
+    /// Simulated time into the scenario when the outcome was decided
+    pub decided_after: Duration,
+    /// Trade return at exit (or at scenario end for open / expired trades)
+    pub pnl_pct: f64,
+    /// Worst mark-to-market return along the way
+    pub worst_pct: f64,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct StressReport {
+    pub scenario: StressScenario,
+    pub ran_at: TimestampMs,
+    pub rows: Vec<StressRow>,
+}
+
+impl StressReport {
+    pub(crate) fn count(&self, outcome: StressOutcome) -> usize {
+        self.rows.iter().filter(|r| r.outcome == outcome).count()
+    }
+
+    pub(crate) fn avg_pnl_pct(&self) -> f64 {
+        if self.rows.is_empty() {
+            return 0.0;
+        }
+        self.rows.iter().map(|r| r.pnl_pct).sum::<f64>() / self.rows.len() as f64
+    }
+}
+
+/// Walks `op` along the scenario path from `entry`. Price moves linearly between path points,
+/// so each step's range is its two endpoints; stop is checked before target (pessimistic).
+/// `remaining` is how long the trade has left before it expires.
+pub(crate) fn evaluate_stress(
+    op: &TradeOpportunity,
+    entry: Price,
+    beta: f64,
+    scenario: StressScenario,
+    remaining: Duration,
+) -> StressRow {
+    let path = scenario.path();
+    let step = scenario.step();
+    let sign = match op.direction {
+        TradeDirection::Long => 1.0,
+        TradeDirection::Short => -1.0,
+    };
+    let price_at = |leader_move: f64| (entry.value() * (1.0 + beta * leader_move)).max(0.0);
+    let trade_return = |price: f64| sign * (price - entry.value()) / entry.value();
+    let target = op.target_price.value();
+    let stop = op.stop_price.value();
+
+    let mut row = StressRow {
+        pair: op.pair_name.clone(),
+        opportunity_id: op.id.clone(),
+        direction: op.direction,
+        beta,
+        outcome: StressOutcome::Open,
+        decided_after: step * path.len().saturating_sub(1) as u32,
+        pnl_pct: 0.0,
+        worst_pct: 0.0,
+    };
+    if entry.value() <= 0.0 {
+        return row;
+    }
+    let mut last = entry.value();
+    for (i, window) in path.windows(2).enumerate() {
+        let elapsed = step * (i as u32 + 1);
+        let (a, b) = (price_at(window[0]), price_at(window[1]));
+        let (low, high) = (a.min(b), a.max(b));
+        last = b;
+        row.worst_pct = row.worst_pct.min(trade_return(low)).min(trade_return(high));
+        let (stop_hit, target_hit) = match op.direction {
+            TradeDirection::Long => (low <= stop, high >= target),
+            TradeDirection::Short => (high >= stop, low <= target),
+        };
+        let decided = if stop_hit {
+            Some((StressOutcome::StopHit, stop))
+        } else if target_hit {
+            Some((StressOutcome::TargetHit, target))
+        } else if elapsed > remaining {
+            Some((StressOutcome::Expired, b))
+        } else {
+            None
+        };
+        if let Some((outcome, exit)) = decided {
+            row.outcome = outcome;
+            row.decided_after = elapsed;
+            row.pnl_pct = trade_return(exit);
+            return row;
+        }
+    }
+    row.pnl_pct = trade_return(last);
+    row
+}
@@ -0,0 +1,90 @@
+use {
+    crate::{
+        engine::JobMode,
+        utils::{AppInstant, process_cpu_time},
+    },
+    std::{collections::HashMap, sync::Mutex, time::Duration},
+};
+
+/// How often the engine writes a capacity summary to the log.
+pub(crate) const TELEMETRY_LOG_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Running totals for one job type.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct JobTimings {
+    pub jobs: usize,
+    pub wall: Duration,
+    pub max_wall: Duration,
+    /// Process CPU time while jobs of this type ran (includes rayon workers; zero where
+    /// the platform can't report it)
+    pub cpu: Duration,
+}
+
+impl JobTimings {
+    pub(crate) fn avg_wall(&self) -> Duration {
+        self.wall / self.jobs.max(1) as u32
+    }
+}
+
+/// Written by the worker after every job, read by the engine / diagnostics window.
+#[derive(Debug, Default)]
+pub(crate) struct WorkerTelemetry {
+    by_mode: Mutex<HashMap<JobMode, JobTimings>>,
+}
+
+impl WorkerTelemetry {
+    /// Runs a job and books its wall and CPU time against `mode`.
+    pub(crate) fn time_job<R>(&self, mode: JobMode, job: impl FnOnce() -> R) -> R {
+        let start = AppInstant::now();
+        let cpu_start = process_cpu_time();
+        let result = job();
+        let wall = start.elapsed();
+        let cpu = cpu_start
+            .zip(process_cpu_time())
+            .map_or(Duration::ZERO, |(a, b)| b.saturating_sub(a));
+
+        if let Ok(mut by_mode) = self.by_mode.lock() {
+            let t = by_mode.entry(mode).or_default();
+            t.jobs += 1;
+            t.wall += wall;
+            t.max_wall = t.max_wall.max(wall);
+            t.cpu += cpu;
+        }
+        result
+    }
+
+    pub(crate) fn snapshot(&self) -> Vec<(JobMode, JobTimings)> {
+        let Ok(by_mode) = self.by_mode.lock() else {
+            return Vec::new();
+        };
+        let mut rows: Vec<_> = by_mode.iter().map(|(m, t)| (m.clone(), *t)).collect();
+        rows.sort_by_key(|(_, t)| std::cmp::Reverse(t.wall));
+        rows
+    }
+}
+
+/// Approximate memory held by the engine.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct MemoryReport {
+    pub timeseries_bytes: usize,
+    /// Largest first
+    pub models: Vec<(String, usize)>,
+    pub process_rss: Option<u64>,
+}
+
+impl MemoryReport {
+    pub(crate) fn models_bytes(&self) -> usize {
+        self.models.iter().map(|(_, b)| b).sum()
+    }
+}
+
+pub(crate) fn format_bytes(bytes: u64) -> String {
+    const MB: f64 = 1024.0 * 1024.0;
+    if bytes as f64 >= 1024.0 * MB {
+        format!("{:.2} GB", bytes as f64 / (1024.0 * MB))
+    } else if bytes as f64 >= MB {
+        format!("{:.1} MB", bytes as f64 / MB)
+    } else {
+        format!("{:.0} KB", bytes as f64 / 1024.0)
+    }
+}
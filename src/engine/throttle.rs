@@ -0,0 +1,76 @@
+use {
+    crate::{app::Price, utils::AppInstant},
+    serde::{Deserialize, Serialize},
+    std::{collections::HashMap, time::Duration},
+};
+
+/// How eagerly the engine recomputes on live data. Constant recalcs are costly on battery.
+/// Defaults (all zero / off) keep the engine fully live.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct RecalcThrottle {
+    /// Minimum time between price-triggered recalcs of the same pair (0 = no limit)
+    pub min_interval_secs: u64,
+    /// Closed live candles are held this long so a burst of closes costs one recalc (0 = off)
+    pub batch_window_secs: u64,
+    /// Skip all automatic recalcs; explicit parameter changes still run
+    pub paused: bool,
+}
+
+impl RecalcThrottle {
+    pub(crate) fn min_interval(&self) -> Duration {
+        Duration::from_secs(self.min_interval_secs)
+    }
+
+    pub(crate) fn batch_window(&self) -> Duration {
+        Duration::from_secs(self.batch_window_secs)
+    }
+}
+
+/// First wait after an automatic recalc fails; doubles per consecutive failure.
+const FAILURE_BACKOFF_BASE: Duration = Duration::from_secs(30);
+const FAILURE_BACKOFF_MAX: Duration = Duration::from_secs(30 * 60);
+
+/// How long automatic triggers leave a pair alone after `failures` consecutive errors.
+pub(crate) fn failure_backoff(failures: u32) -> Duration {
+    let doublings = failures.saturating_sub(1).min(16);
+    (FAILURE_BACKOFF_BASE * 2u32.pow(doublings)).min(FAILURE_BACKOFF_MAX)
+}
+
+/// Closed candles waiting out the batch window, per pair. The latest close wins; the window
+/// runs from the first close of the batch.
+#[derive(Debug, Default)]
+pub(crate) struct CandleBatch {
+    pending: HashMap<String, (AppInstant, Price)>,
+}
+
+impl CandleBatch {
+    pub(crate) fn push(&mut self, pair: &str, close: Price) {
+        self.pending
+            .entry(pair.to_string())
+            .and_modify(|(_, latest)| *latest = close)
+            .or_insert((AppInstant::now(), close));
+    }
+
+    /// Removes and returns pairs whose window has elapsed, with their latest close.
+    pub(crate) fn drain_due(&mut self, window: Duration) -> Vec<(String, Price)> {
+        let mut due = Vec::new();
+        self.pending.retain(|pair, (since, close)| {
+            if since.elapsed() >= window {
+                due.push((pair.clone(), *close));
+                false
+            } else {
+                true
+            }
+        });
+        due
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.pending.clear();
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.pending.len()
+    }
+}
@@ -2,9 +2,13 @@ const TUNER_SCAN_STEPS: usize = 4;
 
 use {
     crate::{
-        app::{PhPct, Price},
+        app::{BASE_INTERVAL, PhPct, Price, PriceLike, TimestampMs},
+        domain::HorizonProfile,
         engine::run_pathfinder_simulations,
-        models::{OhlcvTimeSeries, OptimizationStrategy},
+        models::{
+            AdaptiveCurves, DEFAULT_JOURNEY_SETTINGS, OhlcvTimeSeries, OptimizationStrategy,
+            TradeOpportunity, TradingModel,
+        },
         utils::AppInstant,
     },
     serde::{Deserialize, Serialize},
@@ -17,7 +21,7 @@ use crate::config::DF;
 #[cfg(debug_assertions)]
 use crate::app::Pct;
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub(crate) enum StationId {
     Scalp,
     Day,
@@ -99,21 +103,247 @@ pub(crate) const STATIONS: &[TunerStation] = &[
 
 pub const TUNER_CONFIG: TimeTunerConfig = TimeTunerConfig { stations: STATIONS };
 
+/// A saved scan is reused while at most this many candles (one day of 5-min) have arrived since.
+const TUNER_REUSE_MAX_NEW_CANDLES: usize = 288;
+/// ...and the price has moved less than this fraction.
+const TUNER_REUSE_MAX_PRICE_MOVE: f64 = 0.02;
+
+/// Probes need this many matched scenarios on average before sample size stops mattering much.
+const TUNER_SAMPLE_SCALE: f64 = 50.0;
+
+/// How much each objective counts when the tuner ranks the PHs it probed. Relative, not
+/// required to sum to one.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct TunerWeights {
+    /// Strategy score of the best opportunity (the original single objective)
+    pub quality: f64,
+    /// Share of history inside the price horizon
+    pub coverage: f64,
+    /// Mean expectancy (R) of the opportunities found
+    pub expectancy: f64,
+    /// Mean number of matched historical scenarios behind them
+    pub samples: f64,
+}
+
+impl Default for TunerWeights {
+    fn default() -> Self {
+        Self {
+            quality: 1.0,
+            coverage: 0.25,
+            expectancy: 0.5,
+            samples: 0.25,
+        }
+    }
+}
+
+/// A probe's objectives normalized to 0..1 across its scan, and their weighted total.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ProbeScore {
+    pub quality: f64,
+    pub coverage: f64,
+    pub expectancy: f64,
+    pub samples: f64,
+    pub total: f64,
+}
+
+/// What a tuner scan depended on. A saved scan is reused while the current inputs still match.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct TunerFingerprint {
+    pub station: StationId,
+    pub strategy: OptimizationStrategy,
+    pub adaptive: AdaptiveCurves,
+    #[serde(default)]
+    pub weights: TunerWeights,
+    pub min_ph: Option<PhPct>,
+    pub first_candle: TimestampMs,
+    pub candles: usize,
+    pub price: Price,
+}
+
+impl TunerFingerprint {
+    pub(crate) fn new(
+        ohlcv: &OhlcvTimeSeries,
+        price: Price,
+        station: StationId,
+        strategy: OptimizationStrategy,
+        adaptive: &AdaptiveCurves,
+        weights: TunerWeights,
+        min_ph: Option<PhPct>,
+    ) -> Self {
+        Self {
+            station,
+            strategy,
+            adaptive: adaptive.clone(),
+            weights,
+            min_ph,
+            first_candle: ohlcv.timestamps.first().copied().unwrap_or_default(),
+            candles: ohlcv.klines(),
+            price,
+        }
+    }
+
+    /// Close time of the last candle the scan saw.
+    pub(crate) fn data_end(&self) -> TimestampMs {
+        TimestampMs::new(
+            self.first_candle.value() + self.candles as i64 * BASE_INTERVAL.as_millis() as i64,
+        )
+    }
+
+    /// Same station, strategy, curves and weights, so the saved optimum is a good place to start.
+    pub(crate) fn same_settings(&self, saved: &Self) -> bool {
+        self.station == saved.station
+            && self.strategy == saved.strategy
+            && self.adaptive == saved.adaptive
+            && self.weights == saved.weights
+            && self.min_ph == saved.min_ph
+    }
+
+    /// Same settings and the data has barely changed: the saved result still stands.
+    pub(crate) fn matches(&self, saved: &Self) -> bool {
+        let new_candles = self.candles.saturating_sub(saved.candles);
+        let price_move = (self.price.value() - saved.price.value()).abs()
+            / saved.price.value().max(f64::MIN_POSITIVE);
+        self.same_settings(saved)
+            && self.first_candle == saved.first_candle
+            && self.candles >= saved.candles
+            && new_candles <= TUNER_REUSE_MAX_NEW_CANDLES
+            && price_move < TUNER_REUSE_MAX_PRICE_MOVE
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct TunerProbe {
+    pub ph: PhPct,
+    /// Strategy score of the best opportunity
+    pub score: f64,
+    pub duration_hours: f64,
+    /// Fraction of history inside the price horizon
+    #[serde(default)]
+    pub coverage: f64,
+    #[serde(default)]
+    pub expectancy_r: f64,
+    #[serde(default)]
+    pub sample_size: f64,
+}
+
+impl TunerProbe {
+    pub(crate) fn fits(&self, station: &TunerStation) -> bool {
+        self.duration_hours >= station.target_min_hours
+            && self.duration_hours <= station.target_max_hours
+    }
+}
+
+/// Scores each probe against the others in its scan: every objective is min-max normalized
+/// (all equal = 1.0), then weighted. Same order as `probes`.
+pub(crate) fn score_probes(probes: &[TunerProbe], weights: &TunerWeights) -> Vec<ProbeScore> {
+    let normalize = |value: fn(&TunerProbe) -> f64| -> Vec<f64> {
+        let values: Vec<f64> = probes.iter().map(value).collect();
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        values
+            .iter()
+            .map(|v| {
+                if max - min > f64::EPSILON {
+                    (v - min) / (max - min)
+                } else {
+                    1.0
+                }
+            })
+            .collect()
+    };
+    let quality = normalize(|p| p.score);
+    let coverage = normalize(|p| p.coverage);
+    let expectancy = normalize(|p| p.expectancy_r);
+    // Diminishing returns: 50 vs 100 samples matters more than 500 vs 550
+    let samples = normalize(|p| 1.0 - (-p.sample_size / TUNER_SAMPLE_SCALE).exp());
+    let weight_sum = (weights.quality + weights.coverage + weights.expectancy + weights.samples)
+        .max(f64::EPSILON);
+    (0..probes.len())
+        .map(|i| ProbeScore {
+            quality: quality[i],
+            coverage: coverage[i],
+            expectancy: expectancy[i],
+            samples: samples[i],
+            total: (weights.quality * quality[i]
+                + weights.coverage * coverage[i]
+                + weights.expectancy * expectancy[i]
+                + weights.samples * samples[i])
+                / weight_sum,
+        })
+        .collect()
+}
+
+/// Result of tuning one pair to a station, persisted so the next session can warm-start.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct TunerScan {
+    pub fingerprint: TunerFingerprint,
+    /// Probes that found candidates
+    pub probes: Vec<TunerProbe>,
+    pub best: PhPct,
+}
+
+/// What a station last produced for one pair (live analysis or a tuner preview), shown next to
+/// the station buttons so stations can be compared before switching.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub(crate) struct StationStats {
+    pub ph: PhPct,
+    pub opportunities: usize,
+    /// Share of history inside the price horizon (0..1)
+    pub coverage: f64,
+    pub updated_at: TimestampMs,
+}
+
+impl StationStats {
+    pub(crate) fn from_model(model: &TradingModel, ph: PhPct) -> Self {
+        Self {
+            ph,
+            opportunities: model.opportunities.len(),
+            coverage: model.cva.relevant_candle_count as f64
+                / model.cva.total_candles.max(1) as f64,
+            updated_at: TimestampMs::now(),
+        }
+    }
+}
+
 /// Runs "Scan & Fit" algo to find the optimal Price Horizon to produce trades within the Station's target time range.
+/// `min_ph` (the pair's minimum viable PH) lifts the scan range so no probe is doomed to fail.
+/// `previous` is the last saved scan for the pair: returned as-is if its fingerprint still
+/// matches, otherwise (same settings) only PHs around its optimum are probed, falling back to a
+/// full scan if none of them fit.
 pub(crate) fn tune_to_station(
     ohlcv: &OhlcvTimeSeries,
     current_price: Price,
     station: &TunerStation,
     strategy: OptimizationStrategy,
-) -> Option<PhPct> {
-    struct ProbeResult {
-        ph: PhPct,
-        score: f64,
-        duration_hours: f64,
-    }
-
+    adaptive: &AdaptiveCurves,
+    weights: &TunerWeights,
+    min_ph: Option<PhPct>,
+    previous: Option<&TunerScan>,
+) -> Option<TunerScan> {
     let _t_start = AppInstant::now();
     let _pair_name = ohlcv.pair_interval.name();
+    let fingerprint = TunerFingerprint::new(
+        ohlcv,
+        current_price,
+        station.id,
+        strategy,
+        adaptive,
+        *weights,
+        min_ph,
+    );
+
+    if let Some(saved) = previous.filter(|saved| fingerprint.matches(&saved.fingerprint)) {
+        #[cfg(debug_assertions)]
+        if DF.log_tuner {
+            log::info!(
+                "♻️ TUNER REUSE [{}]: data unchanged since last scan, keeping PH {}",
+                _pair_name,
+                saved.best
+            );
+        }
+        return Some(saved.clone());
+    }
 
     #[cfg(debug_assertions)]
     {
@@ -131,20 +361,132 @@ pub(crate) fn tune_to_station(
         }
     }
 
-    let steps = TUNER_SCAN_STEPS;
+    let floor = min_ph.map_or(0.0, |p| p.value());
+    let scan_min = station.scan_ph_min.value().max(floor);
+    let scan_max = station.scan_ph_max.value().max(floor);
+
+    #[cfg(debug_assertions)]
+    if DF.log_tuner && scan_min > station.scan_ph_min.value() {
+        log::info!(
+            "   📡 TUNER FLOOR: scan starts at minimum viable PH {} for {}",
+            Pct::new(scan_min),
+            _pair_name,
+        );
+    }
+
+    let steps = if scan_max > scan_min {
+        TUNER_SCAN_STEPS
+    } else {
+        1
+    };
+    let step_size = if steps > 1 {
+        (scan_max - scan_min) / (steps - 1) as f64
+    } else {
+        0.0
+    };
+
+    let mut horizon = HorizonProfile::default();
+    horizon.sync(ohlcv);
+
+    let warm_start = previous.filter(|saved| fingerprint.same_settings(&saved.fingerprint));
+    if let Some(saved) = warm_start {
+        // Half-step either side of the old optimum
+        let best = saved.best.value();
+        let mut near = vec![best - step_size / 2.0, best, best + step_size / 2.0];
+        for ph in &mut near {
+            *ph = ph.clamp(scan_min, scan_max);
+        }
+        near.dedup_by(|a, b| (*a - *b).abs() < f64::EPSILON);
+
+        #[cfg(debug_assertions)]
+        if DF.log_tuner {
+            log::info!(
+                "   ♻️ TUNER WARM START: probing {} PHs around previous optimum {} for {}",
+                near.len(),
+                saved.best,
+                _pair_name,
+            );
+        }
+
+        let probes = run_probes(
+            ohlcv,
+            &horizon,
+            current_price,
+            station,
+            strategy,
+            adaptive,
+            &near,
+        );
+        if let Some((best, true)) = pick_best(&probes, station, weights) {
+            return Some(TunerScan {
+                fingerprint,
+                probes,
+                best,
+            });
+        }
+
+        #[cfg(debug_assertions)]
+        if DF.log_tuner {
+            log::info!(
+                "   ♻️ TUNER WARM START: no fit near previous optimum, rescanning all for {}",
+                _pair_name,
+            );
+        }
+    }
+
     let mut scan_points = Vec::with_capacity(steps);
     if steps > 1 {
-        let step_size =
-            (station.scan_ph_max.value() - station.scan_ph_min.value()) / (steps - 1) as f64;
         for i in 0..steps {
-            scan_points.push(station.scan_ph_min.value() + (i as f64 * step_size));
+            scan_points.push(scan_min + (i as f64 * step_size));
         }
     } else {
-        scan_points.push(station.scan_ph_min.value()); // Fallback
+        scan_points.push(scan_min); // Fallback
     }
 
-    let mut results: Vec<ProbeResult> = Vec::new();
-    for &ph in &scan_points {
+    let probes = run_probes(
+        ohlcv,
+        &horizon,
+        current_price,
+        station,
+        strategy,
+        adaptive,
+        &scan_points,
+    );
+    let (best, _fits) = pick_best(&probes, station, weights)?;
+
+    #[cfg(debug_assertions)]
+    {
+        let elapsed = _t_start.elapsed();
+        if DF.log_tuner {
+            log::info!(
+                "✅ TUNER LOCKED: {} | Took {:?} for {}",
+                best,
+                elapsed,
+                _pair_name,
+            );
+        }
+    }
+
+    Some(TunerScan {
+        fingerprint,
+        probes,
+        best,
+    })
+}
+
+/// Runs the pathfinder at each PH; probes without candidates are dropped.
+fn run_probes(
+    ohlcv: &OhlcvTimeSeries,
+    horizon: &HorizonProfile,
+    current_price: Price,
+    station: &TunerStation,
+    strategy: OptimizationStrategy,
+    adaptive: &AdaptiveCurves,
+    scan_points: &[f64],
+) -> Vec<TunerProbe> {
+    let _pair_name = ohlcv.pair_interval.name();
+    let mut results = Vec::new();
+    for &ph in scan_points {
         let result = run_pathfinder_simulations(
             ohlcv,
             current_price,
@@ -152,6 +494,11 @@ pub(crate) fn tune_to_station(
             strategy,
             station.id,
             None,
+            adaptive,
+            &DEFAULT_JOURNEY_SETTINGS.profile,
+            None,
+            None,
+            &[],
         );
 
         let count = result.opportunities.len();
@@ -165,12 +512,18 @@ pub(crate) fn tune_to_station(
                 / 3_600_000.0;
 
             let top_score = result.opportunities[0].calc_quality_score();
+            let mean = |value: fn(&TradeOpportunity) -> f64| {
+                result.opportunities.iter().map(value).sum::<f64>() / count as f64
+            };
 
-            results.push(ProbeResult {
+            results.push(TunerProbe {
                 ph: PhPct::new(ph),
                 score: top_score,
                 duration_hours,
-                // count,
+                coverage: horizon.candle_count(ohlcv, current_price, PhPct::new(ph)) as f64
+                    / ohlcv.klines().max(1) as f64,
+                expectancy_r: mean(|o| o.simulation.expectancy_r()),
+                sample_size: mean(|o| o.simulation.sample_size as f64),
             });
 
             #[cfg(debug_assertions)]
@@ -195,65 +548,41 @@ pub(crate) fn tune_to_station(
             }
         }
     }
+    results
+}
 
-    if results.is_empty() {
-        #[cfg(debug_assertions)]
-        if DF.log_tuner {
-            log::warn!(
-                "⚠️ TUNER FAILED: No candidates found across entire range for {}",
-                _pair_name
-            );
-        }
-        return None;
-    }
-
-    let valid_fits: Vec<&ProbeResult> = results
+/// Highest weighted probe whose average duration fits the station (`true`), otherwise the probe
+/// closest to the middle of the station's range (`false`). None if nothing was found.
+fn pick_best(
+    results: &[TunerProbe],
+    station: &TunerStation,
+    weights: &TunerWeights,
+) -> Option<(PhPct, bool)> {
+    let scores = score_probes(results, weights);
+    let best_fit = results
         .iter()
-        .filter(|r| {
-            r.duration_hours >= station.target_min_hours
-                && r.duration_hours <= station.target_max_hours
-        })
-        .collect();
-
-    let best_match = if !valid_fits.is_empty() {
-        valid_fits
-            .into_iter()
-            .max_by(|a, b| a.score.partial_cmp(&b.score).unwrap_or(Ordering::Equal))
-            .unwrap()
-    } else {
-        #[cfg(debug_assertions)]
-        if DF.log_tuner {
-            log::warn!(
-                "   ⚠️ No perfect time fit. Falling back to closest duration for {}",
-                _pair_name
-            );
-        }
-        let target_center = (station.target_min_hours + station.target_max_hours) / 2.0;
-
-        results
-            .iter()
-            .min_by(|a, b| {
-                let dist_a = (a.duration_hours - target_center).abs();
-                let dist_b = (b.duration_hours - target_center).abs();
-                dist_a.partial_cmp(&dist_b).unwrap_or(Ordering::Equal)
-            })
-            .unwrap()
-    };
+        .zip(&scores)
+        .filter(|(r, _)| r.fits(station))
+        .max_by(|(_, a), (_, b)| a.total.partial_cmp(&b.total).unwrap_or(Ordering::Equal));
+    if let Some((best, _)) = best_fit {
+        return Some((best.ph, true));
+    }
 
     #[cfg(debug_assertions)]
-    {
-        let elapsed = _t_start.elapsed();
-        if DF.log_tuner {
-            log::info!(
-                "✅ TUNER LOCKED: {} (Score {:.2}, Duration {:.1}h) | Took {:?} for {}",
-                best_match.ph,
-                best_match.score,
-                best_match.duration_hours,
-                elapsed,
-                _pair_name,
-            );
+    if DF.log_tuner {
+        if results.is_empty() {
+            log::warn!("⚠️ TUNER FAILED: No candidates found across entire range");
+        } else {
+            log::warn!("   ⚠️ No perfect time fit. Falling back to closest duration");
         }
     }
-
-    Some(best_match.ph)
+    let target_center = (station.target_min_hours + station.target_max_hours) / 2.0;
+    results
+        .iter()
+        .min_by(|a, b| {
+            let dist_a = (a.duration_hours - target_center).abs();
+            let dist_b = (b.duration_hours - target_center).abs();
+            dist_a.partial_cmp(&dist_b).unwrap_or(Ordering::Equal)
+        })
+        .map(|r| (r.ph, false))
 }
@@ -0,0 +1,417 @@
+// Walk-forward helpers shared by the backtester and the per-pair heat calendar.
+// Both truncate history at an entry point (no look-ahead), run the pathfinder on the truncated
+// series, then replay each opportunity forward through the candles that actually followed.
+
+/// Weeks covered by the heat calendar, most recent last.
+const HEAT_CALENDAR_WEEKS: usize = 12;
+/// Entry points sampled per week (one a day).
+const HEAT_SAMPLES_PER_WEEK: usize = 7;
+/// History needed before an entry point is worth evaluating (~48 h of 5-min candles).
+const HEAT_MIN_TRAINING_CANDLES: usize = 576;
+
+use {
+    crate::{
+        app::{PhPct, Price, PriceLike, TimestampMs, TradeProfile},
+        engine::{StationId, run_pathfinder_simulations},
+        models::{
+            AdaptiveCurves, OhlcvTimeSeries, OptimizationStrategy, ScriptExpr, ScriptFields,
+            TradeDirection, TradeOpportunity, TradeOutcome,
+        },
+        utils::TimeUtils,
+    },
+    chrono::{DateTime, Utc},
+    rayon::prelude::*,
+};
+
+/// Walk-forward tally for one past week of a pair.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct HeatWeek {
+    pub start: TimestampMs,
+    /// Entry points evaluated (fewer if history starts mid-week)
+    pub samples: usize,
+    pub opportunities: usize,
+    pub wins: usize,
+    pub losses: usize,
+    pub timeouts: usize,
+    /// Still running when the data ends
+    pub open: usize,
+    /// Sum of resolved trade returns, as fractions of entry
+    pub total_return: f64,
+}
+
+impl HeatWeek {
+    pub(crate) fn resolved(&self) -> usize {
+        self.wins + self.losses + self.timeouts
+    }
+
+    pub(crate) fn win_rate(&self) -> Option<f64> {
+        let resolved = self.resolved();
+        (resolved > 0).then(|| self.wins as f64 / resolved as f64)
+    }
+
+    pub(crate) fn avg_return(&self) -> Option<f64> {
+        let resolved = self.resolved();
+        (resolved > 0).then(|| self.total_return / resolved as f64)
+    }
+
+    /// Counts one trade; `None` = still open.
+    pub(crate) fn record(&mut self, trade: Option<(TradeOutcome, f64)>) {
+        self.opportunities += 1;
+        let Some((outcome, trade_return)) = trade else {
+            self.open += 1;
+            return;
+        };
+        match outcome {
+            TradeOutcome::TargetHit => self.wins += 1,
+            TradeOutcome::StopHit => self.losses += 1,
+            TradeOutcome::Timeout | TradeOutcome::ManualClose => self.timeouts += 1,
+        }
+        self.total_return += trade_return;
+    }
+}
+
+/// One opportunity the walk-forward raised, and how it ended.
+#[derive(Debug, Clone)]
+pub(crate) struct WalkForwardTrade {
+    pub opportunity: TradeOpportunity,
+    /// Close of the entry-point candle
+    pub entry: Price,
+    /// Outcome and return; None = still running when the data ends
+    pub result: Option<(TradeOutcome, f64)>,
+}
+
+/// A filter rule replayed over walk-forward trades: the ones it lets through against all of
+/// them and the ones it rejects.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RuleBacktest {
+    pub all: HeatWeek,
+    pub matched: HeatWeek,
+    pub rejected: HeatWeek,
+}
+
+/// How fertile a pair has been for the current settings, week by week.
+#[derive(Debug, Clone)]
+pub(crate) struct HeatCalendar {
+    pub pair: String,
+    pub weeks: Vec<HeatWeek>,
+    /// Every opportunity behind the weekly tallies, oldest entry point first
+    pub trades: Vec<WalkForwardTrade>,
+}
+
+impl HeatCalendar {
+    /// All weeks pooled.
+    pub(crate) fn total(&self) -> HeatWeek {
+        let mut total = HeatWeek::default();
+        for week in &self.weeks {
+            total.samples += week.samples;
+            total.opportunities += week.opportunities;
+            total.wins += week.wins;
+            total.losses += week.losses;
+            total.timeouts += week.timeouts;
+            total.open += week.open;
+            total.total_return += week.total_return;
+        }
+        total
+    }
+
+    /// Evaluates `filter` on each trade as it stood at its entry point. Fields only known
+    /// live (24h volume) are missing here, so comparisons on them fail.
+    pub(crate) fn backtest_rule(&self, filter: &ScriptExpr) -> RuleBacktest {
+        let mut backtest = RuleBacktest::default();
+        for trade in &self.trades {
+            let op = &trade.opportunity;
+            let fields = ScriptFields {
+                op,
+                current_price: trade.entry,
+                volume_usd: None,
+                market_state: Some(op.market_state),
+            };
+            let side = if filter.matches(&fields) {
+                &mut backtest.matched
+            } else {
+                &mut backtest.rejected
+            };
+            side.record(trade.result.clone());
+            backtest.all.record(trade.result.clone());
+        }
+        backtest
+    }
+}
+
+/// Everything [`build_heat_calendar`] needs, detached from the engine so it can run on a
+/// background thread.
+#[derive(Clone)]
+pub(crate) struct HeatCalendarInput {
+    pub ohlcv: OhlcvTimeSeries,
+    pub ph_pct: PhPct,
+    pub strategy: OptimizationStrategy,
+    pub station_id: StationId,
+    pub adaptive: AdaptiveCurves,
+    pub profile: TradeProfile,
+}
+
+/// Walks the last [`HEAT_CALENDAR_WEEKS`] weeks of the pair's history, sampling a daily entry
+/// point, and tallies the opportunities the engine would have raised there and how they ended.
+/// Slow (one pathfinder run per entry point); call off the UI thread where possible.
+pub(crate) fn build_heat_calendar(input: &HeatCalendarInput) -> HeatCalendar {
+    let mut builder = HeatCalendarBuilder::new(input.clone());
+    let results: Vec<(usize, Vec<WalkForwardTrade>)> = builder
+        .entries
+        .par_iter()
+        .map(|&(w, idx)| (w, builder.evaluate(idx)))
+        .collect();
+    for (w, trades) in results {
+        builder.record(w, trades);
+    }
+    builder.next = builder.entries.len();
+    builder.finish()
+}
+
+/// [`build_heat_calendar`] one entry point at a time, for the web build where it has to share
+/// the UI thread.
+pub(crate) struct HeatCalendarBuilder {
+    input: HeatCalendarInput,
+    weeks: Vec<HeatWeek>,
+    /// (week, entry candle) pairs still to evaluate from `next` on
+    entries: Vec<(usize, usize)>,
+    next: usize,
+    trades: Vec<WalkForwardTrade>,
+}
+
+impl HeatCalendarBuilder {
+    pub(crate) fn new(input: HeatCalendarInput) -> Self {
+        let ohlcv = &input.ohlcv;
+        let total = ohlcv.klines();
+        let interval_ms = ohlcv.pair_interval.interval_ms.max(1);
+        let week_candles = ((TimeUtils::MS_IN_W / interval_ms) as usize).max(1);
+        let step = (week_candles / HEAT_SAMPLES_PER_WEEK).max(1);
+        let first = total.saturating_sub(HEAT_CALENDAR_WEEKS * week_candles);
+
+        let weeks: Vec<HeatWeek> = (0..HEAT_CALENDAR_WEEKS)
+            .map(|w| first + w * week_candles)
+            .take_while(|&start_idx| start_idx < total)
+            .map(|start_idx| HeatWeek {
+                start: ohlcv.timestamps[start_idx],
+                ..HeatWeek::default()
+            })
+            .collect();
+
+        let entries: Vec<(usize, usize)> = (0..weeks.len())
+            .flat_map(|w| {
+                let start_idx = first + w * week_candles;
+                (0..HEAT_SAMPLES_PER_WEEK).map(move |k| (w, start_idx + k * step))
+            })
+            .filter(|&(_, idx)| idx < total && idx + 1 >= HEAT_MIN_TRAINING_CANDLES)
+            .collect();
+
+        Self {
+            input,
+            weeks,
+            entries,
+            next: 0,
+            trades: Vec::new(),
+        }
+    }
+
+    /// Entry points evaluated so far, out of all of them.
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) fn progress(&self) -> (usize, usize) {
+        (self.next, self.entries.len())
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) fn is_done(&self) -> bool {
+        self.next >= self.entries.len()
+    }
+
+    /// Evaluates up to `count` more entry points.
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) fn step(&mut self, count: usize) {
+        let end = (self.next + count).min(self.entries.len());
+        while self.next < end {
+            let (w, idx) = self.entries[self.next];
+            let trades = self.evaluate(idx);
+            self.record(w, trades);
+            self.next += 1;
+        }
+    }
+
+    pub(crate) fn finish(self) -> HeatCalendar {
+        HeatCalendar {
+            pair: self.input.ohlcv.pair_interval.name.clone(),
+            weeks: self.weeks,
+            trades: self.trades,
+        }
+    }
+
+    fn evaluate(&self, idx: usize) -> Vec<WalkForwardTrade> {
+        let input = &self.input;
+        let ohlcv = &input.ohlcv;
+        let training = truncate_ohlcv(ohlcv, idx + 1);
+        let entry = Price::from(training.close_prices[idx]);
+        run_pathfinder_simulations(
+            &training,
+            entry,
+            input.ph_pct,
+            input.strategy,
+            input.station_id,
+            None,
+            &input.adaptive,
+            &input.profile,
+            None,
+            None,
+            &[],
+        )
+        .opportunities
+        .into_iter()
+        .map(|opp| WalkForwardTrade {
+            result: resolve_forward(ohlcv, &opp, idx, entry),
+            opportunity: opp,
+            entry,
+        })
+        .collect()
+    }
+
+    fn record(&mut self, w: usize, trades: Vec<WalkForwardTrade>) {
+        let week = &mut self.weeks[w];
+        week.samples += 1;
+        for trade in &trades {
+            week.record(trade.result.clone());
+        }
+        self.trades.extend(trades);
+    }
+}
+
+impl std::fmt::Debug for HeatCalendarBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HeatCalendarBuilder")
+            .field("pair", &self.input.ohlcv.pair_interval.name)
+            .field("next", &self.next)
+            .field("entries", &self.entries.len())
+            .finish()
+    }
+}
+
+/// Replays `opp`, entered at `entry` on the close of candle `entry_idx`, through the candles
+/// after it. Returns the outcome and the trade's return, or None if it was still running when
+/// the data ends.
+pub(crate) fn resolve_forward(
+    ohlcv: &OhlcvTimeSeries,
+    opp: &TradeOpportunity,
+    entry_idx: usize,
+    entry: Price,
+) -> Option<(TradeOutcome, f64)> {
+    let total = ohlcv.klines();
+    let entry_time = ohlcv.timestamps[entry_idx].to_datetime();
+    let expiry_time = entry_time + chrono::Duration::milliseconds(opp.max_duration.value().max(0));
+    let replay = replay_opportunity_forward(ohlcv, opp, entry_idx + 1, expiry_time);
+    let exit = match replay.result {
+        TradeOutcome::TargetHit => Price::from(opp.target_price),
+        TradeOutcome::StopHit => Price::from(opp.stop_price),
+        TradeOutcome::Timeout | TradeOutcome::ManualClose => {
+            let last_time = ohlcv.timestamps[total - 1].to_datetime();
+            if last_time < expiry_time {
+                return None;
+            }
+            Price::from(ohlcv.close_prices[replay.exit_candle_idx])
+        }
+    };
+    let trade_return = match opp.direction {
+        TradeDirection::Long => (exit - entry) / entry,
+        TradeDirection::Short => (entry - exit) / entry,
+    };
+    Some((replay.result, trade_return))
+}
+
+// Resolved outcome of replaying one opportunity forward.
+pub(crate) struct ReplayResult {
+    pub result: TradeOutcome,
+    pub exit_candle_idx: usize, // Candle index where trade exited (or last available candle)
+}
+
+// Replay a [`TradeOpportunity`] forward into real OHLCV data start at `start_idx` (first hold-out candle), checking each candle's high/low against target and stop prices, then expiry time.
+// Mirrors pessimistic logic of [`TradeOpportunity::check_exit_condition`]: stop is checked before target on each candle.
+pub(crate) fn replay_opportunity_forward(
+    ohlcv: &OhlcvTimeSeries,
+    opp: &TradeOpportunity,
+    start_idx: usize,
+    expiry_time: DateTime<Utc>,
+) -> ReplayResult {
+    let total = ohlcv.klines();
+    let target_price = Price::from(opp.target_price);
+    let stop_price = Price::from(opp.stop_price);
+
+    for (idx, c) in ohlcv.indexed_candles(start_idx..total) {
+        let candle_time: DateTime<Utc> = c.timestamp_ms.to_datetime();
+
+        if candle_time > expiry_time {
+            return ReplayResult {
+                result: TradeOutcome::Timeout,
+                exit_candle_idx: idx,
+            };
+        }
+
+        let high = Price::from(c.high_price);
+        let low = Price::from(c.low_price);
+
+        match opp.direction {
+            TradeDirection::Long => {
+                // Pessimistic: stop before target
+                if low <= stop_price {
+                    return ReplayResult {
+                        result: TradeOutcome::StopHit,
+                        exit_candle_idx: idx,
+                    };
+                }
+                if high >= target_price {
+                    return ReplayResult {
+                        result: TradeOutcome::TargetHit,
+                        exit_candle_idx: idx,
+                    };
+                }
+            }
+            TradeDirection::Short => {
+                if high >= stop_price {
+                    return ReplayResult {
+                        result: TradeOutcome::StopHit,
+                        exit_candle_idx: idx,
+                    };
+                }
+                if low <= target_price {
+                    return ReplayResult {
+                        result: TradeOutcome::TargetHit,
+                        exit_candle_idx: idx,
+                    };
+                }
+            }
+        }
+    }
+
+    // Reached the end of available data without a resolution.
+    // let _ = entry_time; // suppress unused warning in case it's only used above
+    ReplayResult {
+        result: TradeOutcome::Timeout,
+        exit_candle_idx: total.saturating_sub(1),
+    }
+}
+
+// Create a truncated clone of `ohlcv` containing only `[0, end_idx)`.
+// Copies up to `end_idx` candles per call; the heat calendar makes one per entry point, so it
+// runs off the UI thread on native and a step per frame on the web.
+pub(crate) fn truncate_ohlcv(ohlcv: &OhlcvTimeSeries, end_idx: usize) -> OhlcvTimeSeries {
+    let n = end_idx.min(ohlcv.klines());
+    OhlcvTimeSeries {
+        pair_interval: ohlcv.pair_interval.clone(),
+        first_kline_timestamp_ms: ohlcv.first_kline_timestamp_ms,
+        timestamps: ohlcv.timestamps[..n].to_vec(),
+        open_prices: ohlcv.open_prices[..n].to_vec(),
+        high_prices: ohlcv.high_prices[..n].to_vec(),
+        low_prices: ohlcv.low_prices[..n].to_vec(),
+        close_prices: ohlcv.close_prices[..n].to_vec(),
+        base_asset_volumes: ohlcv.base_asset_volumes[..n].to_vec(),
+        quote_asset_volumes: ohlcv.quote_asset_volumes[..n].to_vec(),
+        taker_buy_volumes: ohlcv.taker_buy_volumes[..n].to_vec(),
+        relative_volumes: ohlcv.relative_volumes[..n].to_vec(),
+        aggregates: Default::default(),
+    }
+}
@@ -0,0 +1,119 @@
+use {
+    crate::{
+        app::{BASE_INTERVAL, PhPct, Price},
+        engine::{
+            JobRequest, StationId, TunerScan, TunerStation, TunerWeights, process_request_sync,
+            tune_to_station,
+        },
+        models::{TradeOpportunity, TradingModel, find_matching_ohlcv},
+    },
+    std::sync::{Arc, mpsc},
+};
+
+/// What the engine would recommend for a pair if the live price were `price`.
+#[derive(Debug, Clone)]
+pub(crate) struct PriceEvaluation {
+    pub pair: String,
+    pub price: Price,
+    pub result: Result<Vec<TradeOpportunity>, String>,
+}
+
+impl PriceEvaluation {
+    /// Highest-quality opportunity at this price, if any.
+    pub(crate) fn best(&self) -> Option<&TradeOpportunity> {
+        self.result
+            .as_ref()
+            .ok()?
+            .iter()
+            .max_by(|a, b| a.calc_quality_score().total_cmp(&b.calc_quality_score()))
+    }
+}
+
+/// Runs each request through the worker pipeline on the calling thread and collects the
+/// opportunities. Nothing is sent to the engine, so live models, the ledger and the journal are
+/// untouched; safe to call from a background thread. Requests without a price are skipped.
+pub(crate) fn evaluate_requests(requests: Vec<JobRequest>) -> Vec<PriceEvaluation> {
+    let (tx, rx) = mpsc::channel();
+    requests
+        .into_iter()
+        .filter_map(|req| {
+            let price = req.current_price?;
+            let pair = req.pair_name.clone();
+            process_request_sync(req, tx.clone());
+            let result = match rx.recv() {
+                Ok(res) => res.result.map(|model| model.opportunities.clone()),
+                Err(e) => Err(e.to_string()),
+            };
+            Some(PriceEvaluation {
+                pair,
+                price,
+                result,
+            })
+        })
+        .collect()
+}
+
+/// Everything needed to tune and analyse a pair for a station off the UI thread.
+pub(crate) struct StationPreviewInput {
+    /// Analysis request with the pair's live settings; PH and station are replaced by the tune
+    pub request: JobRequest,
+    pub station: TunerStation,
+    pub weights: TunerWeights,
+    pub min_ph: Option<PhPct>,
+    pub previous: Option<TunerScan>,
+}
+
+/// The model a station would produce if applied: its tuner scan and the resulting analysis.
+#[derive(Debug, Clone)]
+pub(crate) struct StationPreview {
+    pub station_id: StationId,
+    pub scan: TunerScan,
+    pub model: Result<Arc<TradingModel>, String>,
+}
+
+/// Tunes the pair for the station and runs the analysis at the winning PH, on the calling
+/// thread. Like [`evaluate_requests`], nothing reaches the engine. None if the tuner finds no
+/// PH (or the pair has no price / candles).
+pub(crate) fn preview_station(input: StationPreviewInput) -> Option<StationPreview> {
+    let StationPreviewInput {
+        mut request,
+        station,
+        weights,
+        min_ph,
+        previous,
+    } = input;
+    let price = request.current_price?;
+    let scan = {
+        let ts_guard = request.timeseries.read().unwrap();
+        let ohlcv = find_matching_ohlcv(
+            &ts_guard.series_data,
+            &request.pair_name,
+            BASE_INTERVAL.as_millis() as i64,
+        )
+        .ok()?;
+        tune_to_station(
+            ohlcv,
+            price,
+            &station,
+            request.strategy,
+            &request.adaptive,
+            &weights,
+            min_ph,
+            previous.as_ref(),
+        )?
+    };
+    request.ph_pct = scan.best;
+    request.station_id = station.id;
+
+    let (tx, rx) = mpsc::channel();
+    process_request_sync(request, tx);
+    let model = match rx.recv() {
+        Ok(res) => res.result,
+        Err(e) => Err(e.to_string()),
+    };
+    Some(StationPreview {
+        station_id: station.id,
+        scan,
+        model,
+    })
+}
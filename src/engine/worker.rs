@@ -2,16 +2,19 @@ use {
     crate::{
         app::{
             BASE_INTERVAL, DurationMs, HighPrice, LowPrice, Pct, PhPct, Price, PriceLike,
-            StopPrice, TargetPrice, TradeProfile,
+            StopManagement, StopMode, StopPrice, TargetPrice, TradeProfile, VolatilityPct,
         },
         data::TimeSeriesCollection,
         domain::{auto_select_ranges, calc_price_range},
-        engine::{JobMode, JobRequest, JobResult, StationId},
+        engine::{
+            JobMode, JobRequest, JobResult, RetuneInput, StationId, TunerScan, tune_to_station,
+        },
         models::{
-            AdaptiveParameters, CVACore, DEFAULT_JOURNEY_SETTINGS, DEFAULT_SIMILARITY,
-            EmpiricalOutcomeStats, MarketState, OhlcvTimeSeries, OptimizationStrategy,
-            ScenarioSimulator, TradeDirection, TradeOpportunity, TradeVariant, TradingModel,
-            VisualFluff, find_matching_ohlcv, pair_analysis_pure,
+            AdaptiveCurves, AdaptiveParameters, AnomalyMask, Approach, CVACore, ClassifiedZones,
+            CustomZone, DEFAULT_JOURNEY_SETTINGS, DEFAULT_SIMILARITY, EmpiricalOutcomeStats,
+            MarketState, OhlcvTimeSeries, OptimizationStrategy, ScenarioSimulator, TargetAnchor,
+            TargetRung, TradeDirection, TradeOpportunity, TradeVariant, TradingModel, VisualFluff,
+            find_matching_ohlcv, pair_analysis_pure,
         },
         utils::TimeUtils,
     },
@@ -20,20 +23,35 @@ use {
         cmp::Ordering,
         sync::{Arc, mpsc::Sender},
     },
-    uuid::Uuid,
 };
 
 #[cfg(not(target_arch = "wasm32"))]
-use std::{sync::mpsc::Receiver, thread};
+use {
+    crate::{engine::WorkerTelemetry, shared::RepaintSignal},
+    std::{sync::mpsc::Receiver, thread},
+};
 
 #[cfg(debug_assertions)]
 use crate::{config::DF, ui::UI_TEXT};
 
+/// Score cut for a target behind the strongest reversal zone (scaled by the zone's strength).
+const BARRIER_PENALTY_MAX: f64 = 0.5;
+
+/// Zones ahead of the entry that get a take-profit rung when laddering is on.
+const LADDER_MAX_RUNGS: usize = 3;
+
 #[cfg(not(target_arch = "wasm32"))]
-pub(crate) fn spawn_worker_thread(rx: Receiver<JobRequest>, tx: Sender<JobResult>) {
+pub(crate) fn spawn_worker_thread(
+    rx: Receiver<JobRequest>,
+    tx: Sender<JobResult>,
+    telemetry: Arc<WorkerTelemetry>,
+    repaint: RepaintSignal,
+) {
     thread::spawn(move || {
         for req in rx {
-            process_request_sync(req, tx.clone());
+            telemetry.time_job(req.mode.clone(), || process_request_sync(req, tx.clone()));
+            // The result is waiting in the channel; wake the UI to drain it
+            repaint.request();
         }
     });
 }
@@ -41,6 +59,7 @@ pub(crate) fn spawn_worker_thread(rx: Receiver<JobRequest>, tx: Sender<JobResult
 #[allow(dead_code)]
 pub struct PathfinderResult {
     pub opportunities: Vec<TradeOpportunity>,
+    pub matches: Vec<(usize, f64)>,
     pub trend_lookback: usize, // Trend_K
     pub sim_duration: usize,   // Sim_K
 }
@@ -59,10 +78,16 @@ pub(crate) fn run_pathfinder_simulations(
     strategy: OptimizationStrategy,
     station_id: StationId,
     cva_opt: Option<&CVACore>,
+    adaptive: &AdaptiveCurves,
+    profile: &TradeProfile,
+    anomalies: Option<&AnomalyMask>,
+    zones: Option<&ClassifiedZones>,
+    custom_targets: &[CustomZone],
 ) -> PathfinderResult {
     if !current_price.is_positive() {
         return PathfinderResult {
             opportunities: Vec::new(),
+            matches: Vec::new(),
             trend_lookback: 0,
             sim_duration: 0,
         };
@@ -78,7 +103,7 @@ pub(crate) fn run_pathfinder_simulations(
     let start_vol = ohlcv.klines().saturating_sub(vol_lookback);
     let avg_volatility = ohlcv.calc_volatility_in_range(start_vol, ohlcv.klines());
 
-    let trend_lookback = AdaptiveParameters::calc_trend_lookback_candles(ph_pct);
+    let trend_lookback = adaptive.trend_lookback_candles(ph_pct);
     let duration = AdaptiveParameters::calc_dynamic_journey_duration(
         ph_pct,
         avg_volatility,
@@ -96,6 +121,7 @@ pub(crate) fn run_pathfinder_simulations(
         DEFAULT_JOURNEY_SETTINGS.sample_count,
         trend_lookback,
         duration_candles,
+        anomalies,
     );
 
     let (matches, current_state) = match matches_opt {
@@ -103,25 +129,38 @@ pub(crate) fn run_pathfinder_simulations(
         None => {
             return PathfinderResult {
                 opportunities: Vec::new(),
+                matches: Vec::new(),
                 trend_lookback,
                 sim_duration: duration_candles,
             };
         }
     };
 
+    // Simulations keep the raw window; only the live time limit is volatility-adjusted
+    let sample_volatility = matched_journey_volatility(ohlcv, &matches, duration_candles);
+    let duration_vol_factor =
+        AdaptiveParameters::vol_duration_factor(avg_volatility, sample_volatility);
+    let time_limit = duration.mul_f64(duration_vol_factor).clamp(
+        DEFAULT_JOURNEY_SETTINGS.min_journey_time,
+        DEFAULT_JOURNEY_SETTINGS.max_journey_time,
+    );
+
     let (price_min, price_max) = calc_price_range(current_price, ph_pct);
 
     let ctx = PathfinderContext {
         pair_name: ohlcv.pair_interval.name(),
         ohlcv,
         cva: cva_opt,
+        zones,
+        profile,
         matches,
         current_state,
         current_price,
         strategy,
         station_id,
         duration_candles,
-        duration: DurationMs::new(duration.as_millis() as i64),
+        duration: DurationMs::new(time_limit.as_millis() as i64),
+        duration_vol_factor,
         ph_pct,
         price_min,
         price_max,
@@ -142,27 +181,84 @@ pub(crate) fn run_pathfinder_simulations(
 
     let scouts = run_scout_phase(&ctx);
     let drill_results = run_drill_phase(&ctx, scouts);
-    let final_opps: Vec<TradeOpportunity> = apply_diversity_filter(
+    let mut final_opps: Vec<TradeOpportunity> = apply_diversity_filter(
         drill_results,
         ctx.pair_name,
         Price::from(ctx.price_min),
         Price::from(ctx.price_max),
         strategy,
     );
+    if !custom_targets.is_empty() {
+        let custom_opps = run_custom_zone_phase(&ctx, custom_targets);
+        // A custom zone landing on an engine target replaces it, so the trade keeps its tag
+        final_opps.retain(|op| custom_opps.iter().all(|c| c.id != op.id));
+        final_opps.extend(custom_opps);
+    }
     PathfinderResult {
         opportunities: final_opps,
+        matches: ctx.matches,
         trend_lookback,
         sim_duration: duration_candles,
     }
 }
 
-pub(crate) fn process_request_sync(req: JobRequest, tx: Sender<JobResult>) {
+/// Positive scores and the success rates shrink by up to `BARRIER_PENALTY_MAX` when the path
+/// to the target crosses a strong reversal zone (support for shorts, resistance for longs).
+/// Strength is judged from the side price would arrive at, so a zone that only ever held the
+/// other way barely counts.
+fn apply_barrier_penalty(
+    ctx: &PathfinderContext,
+    target: TargetPrice,
+    score: f64,
+    result: &mut EmpiricalOutcomeStats,
+) -> f64 {
+    let target = Price::from(target);
+    let barrier = ctx
+        .zones
+        .and_then(|z| z.strongest_barrier(ctx.current_price, target));
+    let Some(zone) = barrier else {
+        return score;
+    };
+    let approach = Approach::toward(ctx.current_price, target);
+    let keep = 1.0 - BARRIER_PENALTY_MAX * zone.barrier_score(approach);
+    result.discount_for_barrier(zone.strength.approach(approach), keep);
+    if score <= 0.0 { score } else { score * keep }
+}
+
+/// Mean volatility over the journey windows of the historical matches.
+fn matched_journey_volatility(
+    ohlcv: &OhlcvTimeSeries,
+    matches: &[(usize, f64)],
+    duration_candles: usize,
+) -> VolatilityPct {
+    let len = ohlcv.klines();
+    let vols: Vec<f64> = matches
+        .iter()
+        .map(|&(idx, _)| {
+            ohlcv
+                .calc_volatility_in_range(idx, (idx + duration_candles).min(len))
+                .value()
+        })
+        .collect();
+    if vols.is_empty() {
+        return VolatilityPct::new(0.0);
+    }
+    VolatilityPct::new(vols.iter().sum::<f64>() / vols.len() as f64)
+}
+
+pub(crate) fn process_request_sync(mut req: JobRequest, tx: Sender<JobResult>) {
+    if req.is_cancelled() {
+        let _ = tx.send(JobResult::cancelled(&req));
+        return;
+    }
     let ts_local = match fetch_local_timeseries(&req) {
         Ok(ts) => ts,
         Err(e) => {
             let _ = tx.send(JobResult {
                 pair_name: req.pair_name.clone(),
                 result: Err(e),
+                cancelled: false,
+                tuned: None,
                 // duration_ms: 0,
                 // cva: None,
                 // candle_count: 0,
@@ -170,7 +266,58 @@ pub(crate) fn process_request_sync(req: JobRequest, tx: Sender<JobResult>) {
             return;
         }
     };
-    perform_standard_analysis(&req, &ts_local, tx);
+    // A re-tune that finds nothing still recalcs at the pair's current PH
+    let tuned = req
+        .retune
+        .take()
+        .and_then(|input| tune_request(&req, &ts_local, &input));
+    if let Some(scan) = &tuned {
+        req.ph_pct = scan.best;
+    }
+    perform_standard_analysis(&req, &ts_local, tuned, tx);
+}
+
+/// `JobMode::Retune`: the tuner scan for the request's station, None if no PH qualifies.
+fn tune_request(
+    req: &JobRequest,
+    ts_collection: &TimeSeriesCollection,
+    input: &RetuneInput,
+) -> Option<TunerScan> {
+    let price = resolve_analysis_price(req, ts_collection).ok()?;
+    let ohlcv = find_matching_ohlcv(
+        &ts_collection.series_data,
+        &req.pair_name,
+        BASE_INTERVAL.as_millis() as i64,
+    )
+    .ok()?;
+    tune_to_station(
+        ohlcv,
+        price,
+        &input.station,
+        req.strategy,
+        &req.adaptive,
+        &input.weights,
+        input.min_ph,
+        input.previous.as_ref(),
+    )
+}
+
+/// Risk:reward ratio that puts the stop at the protective zone (`StopMode::ZoneAnchored`).
+/// None in sweep mode, without zones, or when no zone protects the entry.
+fn zone_anchored_ratio(
+    ctx: &PathfinderContext,
+    target_price: TargetPrice,
+    direction: TradeDirection,
+) -> Option<f64> {
+    if ctx.profile.stop_mode != StopMode::ZoneAnchored {
+        return None;
+    }
+    let stop =
+        ctx.zones?
+            .protective_stop(ctx.current_price, direction, ctx.profile.zone_stop_buffer)?;
+    let stop_dist = (ctx.current_price.value() - stop.value()).abs();
+    let target_dist = (target_price.value() - ctx.current_price.value()).abs();
+    (stop_dist > 0.0).then(|| target_dist / stop_dist)
 }
 
 /// Evaluates a candidate target price via historical replay and returns the highest-scoring stop-loss configuration.
@@ -190,6 +337,10 @@ fn evaluate_target_candidate(
             TradeDirection::Short
         };
 
+        // Zone-anchored stops replace the R:R sweep with the single ratio the zone implies
+        let anchored_ratio = zone_anchored_ratio(ctx, target_price, direction).map(|r| [r]);
+        let risk_tests = anchored_ratio.as_ref().map_or(risk_tests, |r| r.as_slice());
+
         let best_sl_opt = optimize_stop_loss_rr(
             ctx.ohlcv,
             &ctx.matches,
@@ -199,25 +350,62 @@ fn evaluate_target_candidate(
             direction,
             ctx.duration_candles,
             risk_tests,
-            &DEFAULT_JOURNEY_SETTINGS.profile,
+            ctx.profile,
             ctx.strategy,
             interval_duration,
             limit_samples,
             0,
         );
 
-        if let Some((result, stop_price, variants)) = best_sl_opt {
+        if let Some((mut result, stop_price, variants)) = best_sl_opt {
+            let evidence = &DEFAULT_JOURNEY_SETTINGS.evidence;
+            if !evidence.is_sufficient(result.sample_size) {
+                #[cfg(debug_assertions)]
+                if DF.log_pathfinder {
+                    log::info!(
+                        "SUPPRESSED [{}] {}: only {} samples (min {})",
+                        ctx.pair_name,
+                        source_id_suffix,
+                        result.sample_size,
+                        evidence.min_sample_size
+                    );
+                }
+                return None;
+            }
             let avg_duration = interval_duration.scale(result.avg_candle_count);
-            let score = ctx.strategy.objective_score(&result, avg_duration);
+            let score = evidence.demote_score(
+                ctx.strategy.objective_score(&result, avg_duration),
+                result.sample_size,
+            );
+            let score = apply_barrier_penalty(ctx, target_price, score, &mut result);
 
-            let unique_string = format!("{}_{}_{}", ctx.pair_name, source_id_suffix, direction);
-            let uuid = Uuid::new_v5(&Uuid::NAMESPACE_OID, unique_string.as_bytes()).to_string();
+            let anchor = ctx.zones.map_or(TargetAnchor::Price(target_price), |z| {
+                z.target_anchor(target_price)
+            });
+            let id = TradeOpportunity::stable_id(
+                ctx.pair_name,
+                direction,
+                ctx.strategy,
+                ctx.station_id,
+                anchor,
+            );
             let visuals = ctx.cva.map(|core| VisualFluff {
                 volume_profile: core.candle_bodies_vw.clone(),
             });
+            let path_density = ctx
+                .cva
+                .and_then(|core| core.path_density(ctx.current_price, target_price.into()));
+            let excursions = ScenarioSimulator::estimate_excursions(
+                ctx.ohlcv,
+                &ctx.matches[..limit_samples.min(ctx.matches.len())],
+                ctx.current_price,
+                target_price,
+                ctx.duration_candles,
+                direction,
+            );
 
             let opp = TradeOpportunity {
-                id: uuid,
+                id,
                 created_at: TimeUtils::now_utc(),
                 ph_pct: ctx.ph_pct,
                 pair_name: ctx.pair_name.to_string(),
@@ -226,6 +414,7 @@ fn evaluate_target_candidate(
                 target_price,
                 stop_price,
                 max_duration: ctx.duration,
+                duration_vol_factor: ctx.duration_vol_factor,
                 avg_duration,
                 strategy: ctx.strategy,
                 station_id: ctx.station_id,
@@ -233,6 +422,12 @@ fn evaluate_target_candidate(
                 visuals,
                 simulation: result,
                 variants,
+                ladder: Vec::new(),
+                custom_zone: None,
+                path_density,
+                excursions,
+                // Shared by the whole run, attached once the run is complete
+                matches: Default::default(),
             };
 
             return Some(CandidateResult {
@@ -375,6 +570,9 @@ struct PathfinderContext<'a> {
     pair_name: &'a str,
     ohlcv: &'a OhlcvTimeSeries,
     cva: Option<&'a CVACore>,
+    /// Reversal zones with strength scores; targets behind strong barriers are demoted
+    zones: Option<&'a ClassifiedZones>,
+    profile: &'a TradeProfile,
     matches: Vec<(usize, f64)>,
     current_state: MarketState,
     current_price: Price,
@@ -382,6 +580,7 @@ struct PathfinderContext<'a> {
     station_id: StationId,
     duration_candles: usize,
     duration: DurationMs,
+    duration_vol_factor: f64,
     ph_pct: PhPct,
     price_min: LowPrice,
     price_max: HighPrice,
@@ -546,6 +745,36 @@ fn run_scout_phase(ctx: &PathfinderContext) -> Vec<CandidateResult> {
     })
 }
 
+/// Simulates the near edge of each user-drawn zone as a target with the drill phase's full
+/// sample and R:R sweep. Zones are not bound by the PH range or the diversity filter, and a
+/// zone the price is already inside has no edge to reach.
+fn run_custom_zone_phase(ctx: &PathfinderContext, zones: &[CustomZone]) -> Vec<TradeOpportunity> {
+    let full_risks = DEFAULT_JOURNEY_SETTINGS.risk_reward_tests;
+    let full_samples = DEFAULT_JOURNEY_SETTINGS.sample_count;
+    crate::trace_time!("Pathfinder: Custom Zones", 1000, {
+        zones
+            .par_iter()
+            .filter(|zone| !zone.contains(ctx.current_price))
+            .filter_map(|zone| {
+                let edge = if zone.price_bottom > ctx.current_price {
+                    zone.price_bottom
+                } else {
+                    zone.price_top
+                };
+                let mut res = evaluate_target_candidate(
+                    ctx,
+                    TargetPrice::from(edge),
+                    &format!("custom_zone_{}", zone.id),
+                    full_risks,
+                    full_samples,
+                )?;
+                res.opportunity.custom_zone = Some(zone.label.clone());
+                Some(res.opportunity)
+            })
+            .collect()
+    })
+}
+
 fn run_drill_phase(
     ctx: &PathfinderContext,
     mut candidates: Vec<CandidateResult>,
@@ -785,6 +1014,7 @@ fn optimize_stop_loss_rr(
                 candidate_stop,
                 duration_candles,
                 direction,
+                profile.stop_management,
             ) {
                 let roi_pct = result.avg_pnl_pct;
                 let duration_real = duration_ms.scale(result.avg_candle_count);
@@ -865,6 +1095,7 @@ fn fetch_local_timeseries(req: &JobRequest) -> Result<TimeSeriesCollection, Stri
 fn perform_standard_analysis(
     req: &JobRequest,
     ts_collection: &TimeSeriesCollection,
+    tuned: Option<TunerScan>,
     tx: Sender<JobResult>,
 ) {
     let ph_pct = req.ph_pct;
@@ -882,11 +1113,26 @@ fn perform_standard_analysis(
             calc_exact_candle_count(req, ts_collection, price)
         });
         let full_label = format!("{} ({} candles)", base_label, count);
+        let anomalies = find_matching_ohlcv(
+            &ts_collection.series_data,
+            &req.pair_name,
+            BASE_INTERVAL.as_millis() as i64,
+        )
+        .map(AnomalyMask::detect)
+        .unwrap_or_default();
+        let excluded = req.exclude_anomalies.then_some(&anomalies);
         let result_cva = crate::trace_time!(&format!("2. CVA Calc [{}]", full_label), 10_000, {
-            pair_analysis_pure(req.pair_name.clone(), ts_collection, price, ph_pct)
+            pair_analysis_pure(
+                req.pair_name.clone(),
+                ts_collection,
+                price,
+                ph_pct,
+                &req.adaptive,
+                excluded,
+            )
         });
 
-        let response = match result_cva {
+        let mut response = match result_cva {
             Ok(cva) => {
                 if req.mode == JobMode::ContextOnly {
                     JobResult {
@@ -899,15 +1145,26 @@ fn perform_standard_analysis(
                                 BASE_INTERVAL.as_millis() as i64,
                             )
                             .unwrap(),
+                            &req.zone_config,
+                            &req.zone_edits,
+                            anomalies,
                         ))),
+                        cancelled: false,
+                        tuned: None,
                     }
+                } else if req.is_cancelled() {
+                    // CVA done but simulations are the expensive part: bail before them
+                    JobResult::cancelled(req)
                 } else {
-                    build_success_result(req, ts_collection, cva, price)
+                    build_success_result(req, ts_collection, cva, price, anomalies)
                 }
             }
             Err(e) => build_error_result(req, e.to_string()),
         };
 
+        if !response.cancelled {
+            response.tuned = tuned;
+        }
         let _ = tx.send(response);
     });
 }
@@ -916,6 +1173,8 @@ fn build_error_result(req: &JobRequest, error_msg: String) -> JobResult {
     JobResult {
         pair_name: req.pair_name.clone(),
         result: Err(error_msg),
+        cancelled: false,
+        tuned: None,
     }
 }
 
@@ -961,11 +1220,46 @@ fn calc_exact_candle_count(
     }
 }
 
+/// Re-runs the opportunity's journeys against each zone edge ahead of it (same stop, same
+/// window), so every rung carries its own hit probability.
+fn build_target_ladder(
+    ohlcv: &OhlcvTimeSeries,
+    zones: &ClassifiedZones,
+    matches: &[(usize, f64)],
+    op: &TradeOpportunity,
+    duration_candles: usize,
+    management: StopManagement,
+) -> Vec<TargetRung> {
+    zones
+        .ladder_levels(op.start_price, op.direction, LADDER_MAX_RUNGS)
+        .into_iter()
+        .filter_map(|level| {
+            let target_price = TargetPrice::from(level);
+            let simulation = ScenarioSimulator::estimate_empirical_outcome(
+                ohlcv,
+                matches,
+                op.market_state,
+                op.start_price,
+                target_price,
+                op.stop_price,
+                duration_candles,
+                op.direction,
+                management,
+            )?;
+            Some(TargetRung {
+                target_price,
+                simulation,
+            })
+        })
+        .collect()
+}
+
 fn build_success_result(
     req: &JobRequest,
     ts_collection: &TimeSeriesCollection,
     cva: CVACore,
     price: Price,
+    anomalies: AnomalyMask,
 ) -> JobResult {
     let cva_arc = Arc::new(cva);
 
@@ -976,8 +1270,13 @@ fn build_success_result(
     )
     .expect("OHLCV data missing despite CVA success");
 
-    let mut model = TradingModel::from_cva(cva_arc.clone(), ohlcv);
-
+    let mut model = TradingModel::from_cva(
+        cva_arc.clone(),
+        ohlcv,
+        &req.zone_config,
+        &req.zone_edits,
+        anomalies,
+    );
     let pf_result = run_pathfinder_simulations(
         ohlcv,
         price,
@@ -985,10 +1284,34 @@ fn build_success_result(
         req.strategy,
         req.station_id,
         Some(&cva_arc),
+        &req.adaptive,
+        &req.trade_profile,
+        req.exclude_anomalies.then_some(&model.anomalies),
+        Some(&model.zones),
+        &req.custom_targets,
     );
     model.opportunities = pf_result.opportunities;
+    model.matches = pf_result.matches;
+    let matches: Arc<[(usize, f64)]> = Arc::from(model.matches.as_slice());
+    for op in &mut model.opportunities {
+        op.matches = matches.clone();
+    }
+    if req.ladder_targets {
+        for op in &mut model.opportunities {
+            op.ladder = build_target_ladder(
+                ohlcv,
+                &model.zones,
+                &model.matches,
+                op,
+                pf_result.sim_duration,
+                req.trade_profile.stop_management,
+            );
+        }
+    }
     JobResult {
         pair_name: req.pair_name.clone(),
         result: Ok(Arc::new(model)),
+        cancelled: false,
+        tuned: None,
     }
 }
@@ -3,6 +3,10 @@
 #![allow(clippy::collapsible_else_if)]
 #![allow(clippy::type_complexity)]
 #![allow(clippy::too_many_arguments)]
+
+#[cfg(all(feature = "wasm-slim", any(feature = "backtest", feature = "ph_audit")))]
+compile_error!("`wasm-slim` excludes the native-only `backtest` and `ph_audit` analysis");
+
 mod app;
 mod config;
 mod data;
@@ -16,8 +20,8 @@ mod ui;
 mod utils;
 
 pub use {
-    app::{BASE_INTERVAL, Price, PriceLike},
-    config::{DEMO, PERSISTENCE, kline_cache_filename},
+    app::{BASE_INTERVAL, Price, PriceLike, TimestampMs},
+    config::{DEMO, PERSISTENCE, init_data_dir, kline_cache_filename, resolve_data_dir},
     data::{CacheFile, PriceStreamManager, TimeSeriesCollection},
     domain::PairInterval,
     models::OhlcvTimeSeries,
@@ -25,18 +29,136 @@ pub use {
 };
 
 #[cfg(not(target_arch = "wasm32"))]
-pub use data::{MarketDataStorage, RunSummary, SqliteResultsRepository, SqliteStorage};
+pub use data::{
+    DemoBuildSummary, InstanceGuard, InstanceLock, IntervalMigration, MarketDataStorage,
+    RunSummary, RunningInstance, SqliteResultsRepository, SqliteStorage, build_demo_cache,
+    lock_data_dir, migrate_interval,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use {
+    engine::{ReplayOutcome, ReplayStatus, replay_journal},
+    utils::record_log_line,
+};
 
-use clap::Parser;
+use {
+    clap::{Args, Parser},
+    std::path::PathBuf,
+};
 
 #[derive(Parser, Debug, Clone)]
 #[command(author, version, about, long_about = None)]
 pub struct Cli {
     #[arg(long, default_value_t = false)]
     pub prefer_api: bool,
+    /// Pair to open on startup (web demo: `?pair=`)
+    #[arg(long)]
+    pub pair: Option<String>,
+    /// Price horizon for `--pair` as a fraction, e.g. 0.1 (web demo: `?ph=`)
+    #[arg(long)]
+    pub ph: Option<f64>,
+    /// Bundled demo dataset id; web build only (web demo: `?dataset=`)
+    #[arg(long)]
+    pub demo_dataset: Option<String>,
+    /// Build the WASM demo cache and demo_prices.json from the local DB, then exit
+    #[arg(long, default_value_t = false)]
+    pub make_demo: bool,
+    #[command(flatten)]
+    pub demo: DemoArgs,
+    /// Append every engine job and its result to this file (JSON lines) for `--replay`
+    #[arg(long)]
+    pub journal: Option<PathBuf>,
+    /// Re-run the jobs in a `--journal` file against the local DB, then exit
+    #[arg(long)]
+    pub replay: Option<PathBuf>,
+    /// Only replay the job with this sequence number
+    #[arg(long, requires = "replay")]
+    pub replay_seq: Option<u64>,
+    /// Serve synthetic candles and a fake price stream instead of Binance (no network, no DB writes)
+    #[arg(long, default_value_t = false)]
+    pub mock_exchange: bool,
+    /// Seed for `--mock-exchange`; the same seed replays the same market
+    #[arg(long, requires = "mock_exchange")]
+    pub mock_seed: Option<u64>,
+    /// Regime schedule for `--mock-exchange`, repeated: regime:candles, comma separated
+    /// (range, up, down, volatile, crash), e.g. `range:576,up:288,crash:12`
+    #[arg(long, value_delimiter = ',', requires = "mock_exchange")]
+    pub mock_regime: Vec<String>,
+    /// Keep klines, ledger, settings and exports under this directory instead of the working
+    /// directory (one directory per profile)
+    #[arg(long, conflicts_with = "portable")]
+    pub data_dir: Option<PathBuf>,
+    /// Keep all persisted state next to the executable, e.g. to run from a USB stick
+    #[arg(long, default_value_t = false)]
+    pub portable: bool,
+    /// Open this profile (created if missing) instead of the one used last
+    #[arg(long)]
+    pub profile: Option<String>,
+}
+
+// Keeps the WASM binary small (GitHub limit < 100MB)
+const DEMO_MAX_CANDLES: usize = 50_000;
+pub const KLINE_DB_PATH: &str = PERSISTENCE.kline.db_path;
+
+/// Options for `--make-demo` (also used by the `make_demo_cache` binary).
+#[derive(Args, Debug, Clone)]
+#[command(next_help_heading = "Demo cache builder")]
+pub struct DemoArgs {
+    /// Pairs to include (comma separated); defaults to the configured demo pairs
+    #[arg(long, value_delimiter = ',')]
+    pub demo_pairs: Vec<String>,
+    /// First day to include, YYYY-MM-DD (UTC)
+    #[arg(long)]
+    pub demo_from: Option<String>,
+    /// Last day to include, YYYY-MM-DD (UTC)
+    #[arg(long)]
+    pub demo_to: Option<String>,
+    /// Interval to read from the DB, e.g. 5m (defaults to BASE_INTERVAL)
+    #[arg(long)]
+    pub demo_interval: Option<String>,
+    /// Coarser interval to aggregate into before writing, e.g. 15m
+    #[arg(long)]
+    pub demo_downsample: Option<String>,
+    /// Keep at most this many of the latest candles per pair (WASM size limit)
+    #[arg(long, default_value_t = DEMO_MAX_CANDLES)]
+    pub demo_max_candles: usize,
+    /// Output directory (defaults to the kline data directory)
+    #[arg(long)]
+    pub demo_out: Option<PathBuf>,
+    /// Path to the SQLite kline database (relative paths resolve against the data directory)
+    #[arg(long, default_value = KLINE_DB_PATH)]
+    pub demo_db: String,
+    /// Use each pair's last close instead of live prices (offline, reproducible)
+    #[arg(long, default_value_t = false)]
+    pub demo_last_close_prices: bool,
+}
+
+impl Default for DemoArgs {
+    fn default() -> Self {
+        Self {
+            demo_pairs: Vec::new(),
+            demo_from: None,
+            demo_to: None,
+            demo_interval: None,
+            demo_downsample: None,
+            demo_max_candles: DEMO_MAX_CANDLES,
+            demo_out: None,
+            demo_db: KLINE_DB_PATH.to_string(),
+            demo_last_close_prices: false,
+        }
+    }
 }
 
 use crate::app::App as AppInternal;
 pub fn run_app(cc: &eframe::CreationContext<'_>, args: Cli) -> AppInternal {
     AppInternal::new(cc, args)
 }
+
+/// Window shown when another instance already holds the data directory.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn run_instance_viewer(
+    _cc: &eframe::CreationContext<'_>,
+    running: RunningInstance,
+) -> ui::InstanceViewer {
+    ui::InstanceViewer::new(running)
+}
@@ -5,8 +5,12 @@ use zone_sniper::{Cli, run_app};
 use {
     clap::Parser,
     eframe::NativeOptions,
-    std::{panic, path::PathBuf},
-    zone_sniper::PERSISTENCE,
+    std::{io::Write, panic, path::Path},
+    zone_sniper::{
+        DemoArgs, InstanceLock, PERSISTENCE, ReplayStatus, RunningInstance, build_demo_cache,
+        init_data_dir, lock_data_dir, record_log_line, replay_journal, resolve_data_dir,
+        run_instance_viewer,
+    },
 };
 
 #[cfg(target_arch = "wasm32")]
@@ -56,7 +60,30 @@ pub async fn start() -> Result<(), wasm_bindgen::JsValue> {
         .dyn_into::<web_sys::HtmlCanvasElement>()
         .map_err(|_| "the_canvas_id was not a valid HtmlCanvasElement")?;
 
-    let args = Cli { prefer_api: false };
+    // Shared links: ?dataset=majors&pair=BTCUSDT&ph=0.1
+    let params = window
+        .location()
+        .search()
+        .ok()
+        .and_then(|s| web_sys::UrlSearchParams::new_with_str(&s).ok());
+    let param = |key: &str| params.as_ref().and_then(|p| p.get(key));
+    let args = Cli {
+        prefer_api: false,
+        pair: param("pair").map(|p| p.to_uppercase()),
+        ph: param("ph").and_then(|v| v.parse().ok()),
+        demo_dataset: param("dataset"),
+        make_demo: false,
+        demo: zone_sniper::DemoArgs::default(),
+        journal: None,
+        replay: None,
+        replay_seq: None,
+        mock_exchange: false,
+        mock_seed: None,
+        mock_regime: Vec::new(),
+        data_dir: None,
+        portable: false,
+        profile: None,
+    };
 
     eframe::WebRunner::new()
         .start(
@@ -85,11 +112,47 @@ fn main() -> eframe::Result {
     builder
         .filter(None, global_level)
         .filter(Some("zone_sniper"), my_code_level)
+        .format(|buf, record| {
+            // Tee into the in-memory buffer used by diagnostics bundles
+            let line = format!(
+                "[{} {} {}] {}",
+                buf.timestamp(),
+                record.level(),
+                record.target(),
+                record.args()
+            );
+            writeln!(buf, "{}", line)?;
+            record_log_line(line);
+            Ok(())
+        })
         .init();
 
     let args = Cli::parse();
+    let exe = std::env::current_exe().ok();
+    if let Some(dir) = resolve_data_dir(args.data_dir.as_deref(), args.portable, exe.as_deref()) {
+        if let Err(e) = init_data_dir(dir.clone()) {
+            eprintln!("❌ cannot use data directory {}: {}", dir.display(), e);
+            std::process::exit(1);
+        }
+        log::info!("Data directory: {}", dir.display());
+    }
+    if args.make_demo {
+        std::process::exit(make_demo(&args.demo));
+    }
+    if let Some(path) = &args.replay {
+        std::process::exit(replay(path, args.replay_seq));
+    }
+    // Held until the GUI exits; a second instance on the same data would corrupt the cache
+    let _instance = match lock_data_dir() {
+        Ok(InstanceLock::Acquired(guard)) => Some(guard),
+        Ok(InstanceLock::Held(running)) => return show_running_instance(running),
+        Err(e) => {
+            log::error!("Instance lock unavailable ({:#}); starting without it", e);
+            None
+        }
+    };
     let options = NativeOptions {
-        persistence_path: Some(PathBuf::from(PERSISTENCE.app.state_path)),
+        persistence_path: Some(PERSISTENCE.state_file()),
         viewport: eframe::egui::ViewportBuilder::default()
             .with_maximized(true)
             .with_title("Zone Sniper - Scope. Lock. Snipe."),
@@ -112,3 +175,76 @@ fn main() -> eframe::Result {
         Box::new(move |cc| Ok(Box::new(run_app(cc, args)))),
     )
 }
+
+/// Another instance owns the data directory: say so, and offer to follow its state.
+#[cfg(not(target_arch = "wasm32"))]
+fn show_running_instance(running: RunningInstance) -> eframe::Result {
+    let options = NativeOptions {
+        viewport: eframe::egui::ViewportBuilder::default()
+            .with_inner_size([560.0, 420.0])
+            .with_title("Zone Sniper - already running"),
+        ..Default::default()
+    };
+    eframe::run_native(
+        "Zone Sniper Viewer",
+        options,
+        Box::new(move |cc| Ok(Box::new(run_instance_viewer(cc, running)))),
+    )
+}
+
+/// `--make-demo`: builds the WASM demo data instead of starting the GUI. Returns the exit code.
+#[cfg(not(target_arch = "wasm32"))]
+fn make_demo(demo: &DemoArgs) -> i32 {
+    let result = tokio::runtime::Runtime::new()
+        .map_err(anyhow::Error::from)
+        .and_then(|rt| rt.block_on(build_demo_cache(demo)));
+    match result {
+        Ok(summary) => {
+            summary.print();
+            0
+        }
+        Err(e) => {
+            eprintln!("❌ make-demo failed: {:#}", e);
+            1
+        }
+    }
+}
+
+/// `--replay`: re-runs journaled jobs instead of starting the GUI. Returns the exit code
+/// (1 if any job diverged, failed or panicked).
+#[cfg(not(target_arch = "wasm32"))]
+fn replay(path: &Path, seq: Option<u64>) -> i32 {
+    let result = tokio::runtime::Runtime::new()
+        .map_err(anyhow::Error::from)
+        .and_then(|rt| {
+            rt.block_on(replay_journal(
+                path,
+                seq,
+                &PERSISTENCE.kline_db().to_string_lossy(),
+            ))
+        });
+    match result {
+        Ok(outcomes) => {
+            for outcome in &outcomes {
+                println!("{}", outcome);
+            }
+            let bad = outcomes
+                .iter()
+                .filter(|o| {
+                    matches!(
+                        o.status,
+                        ReplayStatus::Diverged { .. }
+                            | ReplayStatus::Failed(_)
+                            | ReplayStatus::Panicked(_)
+                    )
+                })
+                .count();
+            println!("{} jobs replayed, {} problems", outcomes.len(), bad);
+            i32::from(bad > 0)
+        }
+        Err(e) => {
+            eprintln!("❌ replay failed: {:#}", e);
+            1
+        }
+    }
+}
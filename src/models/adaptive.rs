@@ -3,9 +3,106 @@ use {
         app::{BASE_INTERVAL, DurationMs, JourneySettings, PhPct, VolatilityPct},
         utils::{TimeUtils, remap},
     },
+    serde::{Deserialize, Serialize},
     std::time::Duration,
 };
 
+/// Default CVA time decay (per year of history). 1.0 disables decay.
+const DEFAULT_TIME_DECAY_FACTOR: f64 = 1.5;
+
+/// Bounds on the volatility adjustment of an opportunity's time limit.
+const VOL_DURATION_FACTOR_MIN: f64 = 0.5;
+const VOL_DURATION_FACTOR_MAX: f64 = 2.0;
+
+/// Piecewise-linear mapping from PH (as fraction) to a parameter value.
+/// Breakpoints are `[ph, value]`; beyond the ends the outer segments are extrapolated.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct AdaptiveCurve {
+    pub points: Vec<[f64; 2]>,
+}
+
+impl AdaptiveCurve {
+    pub(crate) fn eval(&self, ph: f64) -> f64 {
+        match self.points.as_slice() {
+            [] => 0.0,
+            [p] => p[1],
+            pts => {
+                let i = pts
+                    .windows(2)
+                    .position(|w| ph < w[1][0])
+                    .unwrap_or(pts.len() - 2);
+                let (a, b) = (pts[i], pts[i + 1]);
+                if (b[0] - a[0]).abs() < f64::EPSILON {
+                    return a[1];
+                }
+                remap(ph, a[0], b[0], a[1], b[1])
+            }
+        }
+    }
+
+    /// Sorts breakpoints by PH and drops duplicates / non-finite entries.
+    pub(crate) fn normalize(&mut self) {
+        self.points
+            .retain(|p| p[0].is_finite() && p[1].is_finite() && p[0] >= 0.0);
+        self.points.sort_by(|a, b| a[0].total_cmp(&b[0]));
+        self.points
+            .dedup_by(|a, b| (a[0] - b[0]).abs() < f64::EPSILON);
+    }
+}
+
+/// User-editable PH → parameter mappings. Defaults reproduce the original fixed formulas.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct AdaptiveCurves {
+    /// PH → trend lookback (candles)
+    pub trend_lookback: AdaptiveCurve,
+    /// PH → CVA time decay factor (per year)
+    pub time_decay: AdaptiveCurve,
+}
+
+impl Default for AdaptiveCurves {
+    /// Scalp: 2 h - 1 day, Swing: 1 day - 1 week, Macro: 1 week-1 month+
+    fn default() -> Self {
+        let ms = BASE_INTERVAL.as_millis() as i64;
+        let day_candles = TimeUtils::duration_to_candles(Duration::from_secs(86_400), ms) as f64;
+        let week_candles =
+            TimeUtils::duration_to_candles(Duration::from_secs(86_400 * 7), ms) as f64;
+        let month_candles =
+            TimeUtils::duration_to_candles(Duration::from_secs(86_400 * 30), ms) as f64;
+        Self {
+            trend_lookback: AdaptiveCurve {
+                points: vec![
+                    [0.005, 24.0],
+                    [0.05, day_candles],
+                    [0.15, week_candles],
+                    [0.50, month_candles],
+                ],
+            },
+            time_decay: AdaptiveCurve {
+                points: vec![
+                    [0.005, DEFAULT_TIME_DECAY_FACTOR],
+                    [0.50, DEFAULT_TIME_DECAY_FACTOR],
+                ],
+            },
+        }
+    }
+}
+
+impl AdaptiveCurves {
+    /// Maps price horizon % to trend lookback candle count (at least one).
+    pub(crate) fn trend_lookback_candles(&self, ph_pct: PhPct) -> usize {
+        self.trend_lookback.eval(ph_pct.value()).round().max(1.0) as usize
+    }
+
+    pub(crate) fn time_decay_factor(&self, ph_pct: PhPct) -> f64 {
+        self.time_decay.eval(ph_pct.value()).max(1.0)
+    }
+
+    pub(crate) fn normalize(&mut self) {
+        self.trend_lookback.normalize();
+        self.time_decay.normalize();
+    }
+}
+
 pub struct AdaptiveParameters;
 
 impl AdaptiveParameters {
@@ -29,24 +126,13 @@ impl AdaptiveParameters {
             .clamp(journey.min_journey_time, journey.max_journey_time)
     }
 
-    /// Maps price horizon % to trend lookback candle count.
-    /// Scalp: 2 h - 1 day, Swing: 1 day - 1 week, Macro: 1 week-1 month+
-    /// TEMP why 3 remappings if we have 4 different trades styles. Feels.... wrong. Please look into this one day before wrap-up TEMP
-    pub(crate) fn calc_trend_lookback_candles(ph_threshold: PhPct) -> usize {
-        let ms = BASE_INTERVAL.as_millis() as i64;
-        let day_candles = TimeUtils::duration_to_candles(Duration::from_secs(86_400), ms) as f64;
-        let week_candles =
-            TimeUtils::duration_to_candles(Duration::from_secs(86_400 * 7), ms) as f64;
-        let month_candles =
-            TimeUtils::duration_to_candles(Duration::from_secs(86_400 * 30), ms) as f64;
-        let v = ph_threshold.value();
-        let result = if v < 0.05 {
-            remap(v, 0.005, 0.05, 24.0, day_candles)
-        } else if v < 0.15 {
-            remap(v, 0.05, 0.15, day_candles, week_candles)
-        } else {
-            remap(v, 0.15, 0.50, week_candles, month_candles)
-        };
-        result.round() as usize
+    /// Time limit multiplier: volatility of the matched historical journeys over current
+    /// volatility. Fast markets (current > sample) get shorter windows, dead ones longer.
+    pub(crate) fn vol_duration_factor(current: VolatilityPct, sample: VolatilityPct) -> f64 {
+        if sample.value() < VolatilityPct::MIN_EPSILON {
+            return 1.0;
+        }
+        (sample.value() / current.as_safe_divisor())
+            .clamp(VOL_DURATION_FACTOR_MIN, VOL_DURATION_FACTOR_MAX)
     }
 }
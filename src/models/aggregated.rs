@@ -0,0 +1,157 @@
+use {
+    crate::{
+        app::{
+            BaseVol, CandleResolution, DurationMs, HighPrice, LowPrice, PriceLike, QuoteVol,
+            TimestampMs,
+        },
+        domain::Candle,
+        models::{DisplaySegment, OhlcvTimeSeries},
+        utils::vec_heap_bytes,
+    },
+    std::sync::OnceLock,
+    strum::{EnumCount, IntoEnumIterator},
+};
+
+/// Merges consecutive candles (non-empty, in time order) into one candle stamped `start`.
+pub(crate) fn merge_candles(start: TimestampMs, candles: &[Candle]) -> Candle {
+    let first = &candles[0];
+    let last = &candles[candles.len() - 1];
+    let mut high = f64::MIN;
+    let mut low = f64::MAX;
+    let (mut base, mut quote, mut taker) = (0.0, 0.0, 0.0);
+    for c in candles {
+        high = high.max(c.high_price.value());
+        low = low.min(c.low_price.value());
+        base += c.base_asset_volume.value();
+        quote += c.quote_asset_volume.value();
+        taker += c.taker_buy_base_volume.value();
+    }
+    Candle::new(
+        start,
+        first.open_price,
+        HighPrice::new(high),
+        LowPrice::new(low),
+        last.close_price,
+        BaseVol::new(base),
+        QuoteVol::new(quote),
+        BaseVol::new(taker),
+    )
+}
+
+/// One resolution's worth of epoch-aligned candles. `first_idx[i]` is the base-series index
+/// of the first candle folded into `candles[i]`.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct AggregatedSeries {
+    candles: Vec<Candle>,
+    first_idx: Vec<usize>,
+}
+
+impl AggregatedSeries {
+    fn build(base: &[Candle], bucket_ms: i64) -> Self {
+        let mut out = Self::default();
+        let mut start = 0;
+        while start < base.len() {
+            let bucket_start = base[start].timestamp_ms.align_down(bucket_ms);
+            let bucket_end = bucket_start + DurationMs::new(bucket_ms);
+            let end = start + base[start..].partition_point(|c| c.timestamp_ms < bucket_end);
+            out.candles
+                .push(merge_candles(bucket_start, &base[start..end]));
+            out.first_idx.push(start);
+            start = end;
+        }
+        out
+    }
+
+    /// Buckets overlapping base candles `[start_idx, end_idx)`. Edge buckets are returned
+    /// whole, so they may include base candles just outside the range.
+    fn range(&self, start_idx: usize, end_idx: usize) -> &[Candle] {
+        let lo = self
+            .first_idx
+            .partition_point(|&i| i <= start_idx)
+            .saturating_sub(1);
+        let hi = self.first_idx.partition_point(|&i| i < end_idx);
+        &self.candles[lo..hi.max(lo)]
+    }
+}
+
+/// Higher-timeframe candles, built on first use per resolution. Never serialized.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct AggregateCache {
+    slots: [OnceLock<AggregatedSeries>; CandleResolution::COUNT],
+}
+
+impl AggregateCache {
+    pub(crate) fn get_or_build(
+        &self,
+        resolution: CandleResolution,
+        base: impl FnOnce() -> Vec<Candle>,
+    ) -> &AggregatedSeries {
+        self.slots[resolution as usize].get_or_init(|| {
+            AggregatedSeries::build(&base(), resolution.duration().as_millis() as i64)
+        })
+    }
+
+    /// Re-folds the newest bucket of every built resolution after the last base candle
+    /// changed or was appended, so a live tick doesn't throw the whole cache away.
+    pub(crate) fn refresh_tail(&mut self, series: &OhlcvTimeSeries) {
+        let Some(last_idx) = series.klines().checked_sub(1) else {
+            return;
+        };
+        let last = series.get_candle(last_idx);
+        for (resolution, slot) in CandleResolution::iter().zip(self.slots.iter_mut()) {
+            let Some(agg) = slot.get_mut() else {
+                continue;
+            };
+            let bucket_ms = resolution.duration().as_millis() as i64;
+            let bucket_start = last.timestamp_ms.align_down(bucket_ms);
+            if agg.candles.last().map(|c| c.timestamp_ms) != Some(bucket_start) {
+                agg.candles.push(last);
+                agg.first_idx.push(last_idx);
+            }
+            let first = agg.first_idx[agg.first_idx.len() - 1];
+            let bucket: Vec<Candle> = series.candles(first..last_idx + 1).collect();
+            let tail = agg.candles.len() - 1;
+            agg.candles[tail] = merge_candles(bucket_start, &bucket);
+        }
+    }
+
+    pub(crate) fn heap_bytes(&self) -> usize {
+        self.slots
+            .iter()
+            .filter_map(OnceLock::get)
+            .map(|s| vec_heap_bytes(&s.candles) + vec_heap_bytes(&s.first_idx))
+            .sum()
+    }
+}
+
+impl OhlcvTimeSeries {
+    /// Candles at `resolution` (epoch-aligned), built once and kept current by live updates.
+    // Custom indicators run over this so window functions see across segment gaps
+    pub(crate) fn aggregated(&self, resolution: CandleResolution) -> &[Candle] {
+        &self.aggregated_series(resolution).candles
+    }
+
+    /// Aggregated candles covering base candles `[start_idx, end_idx)`, e.g. one plot segment.
+    pub(crate) fn aggregated_range(
+        &self,
+        resolution: CandleResolution,
+        start_idx: usize,
+        end_idx: usize,
+    ) -> &[Candle] {
+        self.aggregated_series(resolution).range(start_idx, end_idx)
+    }
+
+    /// Aggregated candles covering one display segment.
+    pub(crate) fn aggregated_segment(
+        &self,
+        resolution: CandleResolution,
+        segment: &DisplaySegment,
+    ) -> &[Candle] {
+        self.aggregated_range(resolution, segment.start_idx, segment.end_idx)
+    }
+
+    fn aggregated_series(&self, resolution: CandleResolution) -> &AggregatedSeries {
+        self.aggregates
+            .get_or_build(resolution, || self.candles(0..self.klines()).collect())
+    }
+}
@@ -0,0 +1,290 @@
+use {
+    crate::{
+        models::{
+            JourneyReplay, MarketState, OhlcvTimeSeries, OpportunityAnnotation, Outcome,
+            ScenarioSimulator, SuperZone, TradeOpportunity, TradingModel, VolTermStructure,
+            ZoneCoverageStats,
+        },
+        utils::TimeUtils,
+    },
+    chrono::{DateTime, Utc},
+    serde::Serialize,
+    std::fmt::Write,
+};
+
+/// Zone landscape at the time the opportunity was generated.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct ZoneContext {
+    pub ph_low: f64,
+    pub ph_high: f64,
+    pub sticky: Vec<SuperZone>,
+    pub low_wicks: Vec<SuperZone>,
+    pub high_wicks: Vec<SuperZone>,
+    pub coverage: ZoneCoverageStats,
+}
+
+impl ZoneContext {
+    fn from_model(model: &TradingModel) -> Self {
+        let (ph_low, ph_high) = model.cva.price_range.min_max();
+        Self {
+            ph_low,
+            ph_high,
+            sticky: model.zones.sticky_superzones.clone(),
+            low_wicks: model.zones.low_wicks_superzones.clone(),
+            high_wicks: model.zones.high_wicks_superzones.clone(),
+            coverage: model.coverage.clone(),
+        }
+    }
+}
+
+/// Everything a model produced for one pair, minus the plot-only volume profiles.
+/// Included in diagnostics bundles to reproduce analysis bugs.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct ModelSnapshot {
+    pub generated_at: DateTime<Utc>,
+    pub pair_name: String,
+    pub zones: ZoneContext,
+    pub vol_term: VolTermStructure,
+    pub segment_count: usize,
+    pub match_count: usize,
+    pub opportunities: Vec<TradeOpportunity>,
+}
+
+impl ModelSnapshot {
+    pub(crate) fn build(pair_name: &str, model: &TradingModel) -> Self {
+        Self {
+            generated_at: TimeUtils::now_utc(),
+            pair_name: pair_name.to_string(),
+            zones: ZoneContext::from_model(model),
+            vol_term: model.vol_term.clone(),
+            segment_count: model.segments.len(),
+            match_count: model.matches.len(),
+            opportunities: model
+                .opportunities
+                .iter()
+                .map(|op| TradeOpportunity {
+                    visuals: None,
+                    ..op.clone()
+                })
+                .collect(),
+        }
+    }
+
+    pub(crate) fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+/// Self-contained audit record explaining why an opportunity was recommended.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct AnalysisReport {
+    pub generated_at: DateTime<Utc>,
+    pub pair_name: String,
+    pub opportunity: TradeOpportunity,
+    pub fingerprint: MarketState,
+    pub vol_term: VolTermStructure,
+    pub max_duration_candles: usize,
+    pub journeys: Vec<JourneyReplay>,
+    pub zones: ZoneContext,
+    /// The user's notes on this opportunity (filled in by the UI, which owns them)
+    pub annotations: Vec<OpportunityAnnotation>,
+}
+
+impl AnalysisReport {
+    pub(crate) fn build(
+        model: &TradingModel,
+        ohlcv: &OhlcvTimeSeries,
+        op: &TradeOpportunity,
+    ) -> Self {
+        let max_duration_candles = op.max_duration_candles();
+        let journeys = ScenarioSimulator::replay_journeys(
+            ohlcv,
+            &op.matches,
+            op.start_price,
+            op.target_price,
+            op.stop_price,
+            max_duration_candles,
+            op.direction,
+        );
+        // Volume profile is plot-only and bloats the report
+        let mut opportunity = op.clone();
+        opportunity.visuals = None;
+
+        Self {
+            generated_at: TimeUtils::now_utc(),
+            pair_name: op.pair_name.clone(),
+            fingerprint: op.market_state,
+            vol_term: model.vol_term.clone(),
+            opportunity,
+            max_duration_candles,
+            journeys,
+            zones: ZoneContext::from_model(model),
+            annotations: Vec::new(),
+        }
+    }
+
+    pub(crate) fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    pub(crate) fn to_html(&self) -> String {
+        let op = &self.opportunity;
+        let ms = &self.fingerprint;
+        let mut html = String::new();
+
+        let _ = write!(
+            html,
+            "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>{pair} analysis</title>\
+             <style>body{{font-family:monospace;background:#111;color:#ddd}}\
+             table{{border-collapse:collapse}}\
+             td,th{{border:1px solid #444;padding:2px 8px}}</style></head>\
+             <body><h1>{pair} {dir}</h1><p>Generated {gen}</p>",
+            pair = escape_html(&self.pair_name),
+            dir = op.direction,
+            gen = self.generated_at.format("%Y-%m-%d %H:%M:%S UTC"),
+        );
+
+        let _ = write!(
+            html,
+            "<h2>Trade</h2><table>\
+             <tr><td>Entry</td><td>{}</td></tr><tr><td>Target</td><td>{}</td></tr>\
+             <tr><td>Stop</td><td>{}</td></tr><tr><td>Expected ROI</td><td>{}</td></tr>\
+             <tr><td>Success rate</td><td>{} ±{}</td></tr><tr><td>Samples</td><td>{}</td></tr>\
+             <tr><td>Avg duration</td><td>{}</td></tr>\
+             <tr><td>Time limit</td><td>{} (volatility adjustment ×{:.2})</td></tr>\
+             <tr><td>Strategy</td><td>{}</td></tr><tr><td>PH</td><td>{}</td></tr></table>",
+            op.start_price,
+            op.target_price,
+            op.stop_price,
+            op.expected_roi(),
+            op.simulation.success_rate,
+            op.simulation.success_rate_margin(),
+            op.simulation.sample_size,
+            TimeUtils::format_duration(op.avg_duration.value()),
+            TimeUtils::format_duration(op.max_duration.value()),
+            op.duration_vol_factor,
+            op.strategy,
+            op.ph_pct,
+        );
+        if let Some(label) = &op.custom_zone {
+            let _ = write!(
+                html,
+                "<p>Target is your zone <b>{}</b></p>",
+                escape_html(label)
+            );
+        }
+
+        if !self.annotations.is_empty() {
+            html.push_str(
+                "<h2>Notes</h2><table><tr><th>Added</th><th>Price</th><th>Note</th></tr>",
+            );
+            for note in &self.annotations {
+                let _ = write!(
+                    html,
+                    "<tr><td>{}</td><td>{}</td><td>{}</td></tr>",
+                    note.created_at.to_datetime().format("%Y-%m-%d %H:%M"),
+                    note.price,
+                    escape_html(&note.text),
+                );
+            }
+            html.push_str("</table>");
+        }
+
+        if !op.ladder.is_empty() {
+            html.push_str(
+                "<h2>Target ladder</h2><table>\
+                 <tr><th>#</th><th>Target</th><th>Success rate</th><th>Expected ROI</th>\
+             <th>Samples</th></tr>",
+            );
+            for (i, rung) in op.ladder.iter().enumerate() {
+                let _ = write!(
+                    html,
+                    "<tr><td>{}</td><td>{}</td><td>{} ±{}</td><td>{}</td><td>{}</td></tr>",
+                    i + 1,
+                    rung.target_price,
+                    rung.simulation.success_rate,
+                    rung.simulation.success_rate_margin(),
+                    rung.simulation.avg_pnl_pct,
+                    rung.simulation.sample_size,
+                );
+            }
+            html.push_str("</table>");
+        }
+
+        let _ = write!(
+            html,
+            "<h2>Market fingerprint</h2><table>\
+             <tr><td>Volatility</td><td>{}</td></tr><tr><td>Momentum</td><td>{}</td></tr>\
+             <tr><td>Relative volume</td><td>{:.2}</td></tr>",
+            ms.volatility_pct,
+            ms.momentum_pct,
+            ms.relative_volume.value(),
+        );
+        for point in &self.vol_term.points {
+            let _ = write!(
+                html,
+                "<tr><td>Realized vol {}</td><td>{}</td></tr>",
+                point.label, point.volatility_pct
+            );
+        }
+        html.push_str("</table>");
+
+        html.push_str(
+            "<h2>Matched journeys</h2><table>\
+             <tr><th>#</th><th>Start</th><th>Distance</th><th>Outcome</th><th>Candles</th></tr>",
+        );
+        for (i, j) in self.journeys.iter().enumerate() {
+            let outcome = match j.outcome {
+                Outcome::TargetHit(_) => "TARGET".to_string(),
+                Outcome::StopHit(_) => "STOP".to_string(),
+                Outcome::TimedOut(roi) => format!("TIMEOUT ({})", roi),
+            };
+            let _ = write!(
+                html,
+                "<tr><td>{}</td><td>{}</td><td>{:.4}</td><td>{}</td><td>{}</td></tr>",
+                i + 1,
+                j.start_ts.to_datestring(),
+                j.distance,
+                outcome,
+                j.candle_count(self.max_duration_candles),
+            );
+        }
+        html.push_str("</table>");
+
+        let _ = write!(
+            html,
+            "<h2>Zone context</h2><p>PH range {:.6} - {:.6} | \
+             coverage sticky {:.1}% support {:.1}% resistance {:.1}%</p><table>\
+             <tr><th>Type</th><th>Bottom</th><th>Top</th></tr>",
+            self.zones.ph_low,
+            self.zones.ph_high,
+            self.zones.coverage.sticky_pct,
+            self.zones.coverage.support_pct,
+            self.zones.coverage.resistance_pct,
+        );
+        for (label, zones) in [
+            ("Sticky", &self.zones.sticky),
+            ("Low wick", &self.zones.low_wicks),
+            ("High wick", &self.zones.high_wicks),
+        ] {
+            for z in zones {
+                let _ = write!(
+                    html,
+                    "<tr><td>{}</td><td>{}</td><td>{}</td></tr>",
+                    label, z.price_bottom, z.price_top
+                );
+            }
+        }
+        html.push_str("</table></body></html>");
+        html
+    }
+}
+
+/// Every interpolated string goes through this: notes and zone labels are typed by the user,
+/// pair names come from files the user can edit.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,126 @@
+use crate::{app::PriceLike, models::OhlcvTimeSeries, utils::vec_heap_bytes};
+
+pub(crate) struct AnomalySettings {
+    /// Candles in the trailing average true range a wick is compared against
+    pub atr_window: usize,
+    /// A wick longer than this many ATRs is treated as a flash move
+    pub flash_wick_atr_multiple: f64,
+    /// Consecutive flat candles repeating the previous close before they count as an outage
+    pub frozen_run_min: usize,
+}
+
+pub(crate) const ANOMALY_SETTINGS: AnomalySettings = AnomalySettings {
+    atr_window: 14,
+    flash_wick_atr_multiple: 6.0,
+    frozen_run_min: 3,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum_macros::Display)]
+pub(crate) enum CandleAnomaly {
+    #[strum(serialize = "Flash wick")]
+    FlashWick,
+    #[strum(serialize = "Zero volume")]
+    ZeroVolume,
+    #[strum(serialize = "Frozen price")]
+    FrozenPrice,
+}
+
+/// Candles that look like data errors or one-off liquidity events rather than real trading.
+/// `prefix` holds running counts so window checks are O(1).
+#[derive(Debug, Clone, Default)]
+pub(crate) struct AnomalyMask {
+    flags: Vec<Option<CandleAnomaly>>,
+    prefix: Vec<u32>,
+}
+
+impl AnomalyMask {
+    pub(crate) fn detect(ohlcv: &OhlcvTimeSeries) -> Self {
+        let len = ohlcv.klines();
+        let mut flags = vec![None; len];
+        let mut tr_sum = 0.0;
+        let mut frozen_run = 0;
+
+        for i in 0..len {
+            let open = ohlcv.open_prices[i].value();
+            let high = ohlcv.high_prices[i].value();
+            let low = ohlcv.low_prices[i].value();
+            let close = ohlcv.close_prices[i].value();
+            let prev_close = i.checked_sub(1).map(|p| ohlcv.close_prices[p].value());
+
+            let is_flat = high == low && prev_close == Some(close);
+            frozen_run = if is_flat { frozen_run + 1 } else { 0 };
+
+            // ATR over the window *before* this candle, so the wick can't inflate its own yardstick
+            let window = i.min(ANOMALY_SETTINGS.atr_window);
+            let atr = if window > 0 {
+                tr_sum / window as f64
+            } else {
+                0.0
+            };
+            let wick = (high - open.max(close)).max(open.min(close) - low);
+
+            flags[i] = if ohlcv.base_asset_volumes[i].value() <= 0.0 {
+                Some(CandleAnomaly::ZeroVolume)
+            } else if frozen_run >= ANOMALY_SETTINGS.frozen_run_min {
+                Some(CandleAnomaly::FrozenPrice)
+            } else if window == ANOMALY_SETTINGS.atr_window
+                && atr > 0.0
+                && wick > atr * ANOMALY_SETTINGS.flash_wick_atr_multiple
+            {
+                Some(CandleAnomaly::FlashWick)
+            } else {
+                None
+            };
+
+            tr_sum += true_range(high, low, prev_close);
+            if i >= ANOMALY_SETTINGS.atr_window {
+                let old = i - ANOMALY_SETTINGS.atr_window;
+                let old_prev_close = old.checked_sub(1).map(|p| ohlcv.close_prices[p].value());
+                tr_sum -= true_range(
+                    ohlcv.high_prices[old].value(),
+                    ohlcv.low_prices[old].value(),
+                    old_prev_close,
+                );
+            }
+        }
+
+        let mut prefix = Vec::with_capacity(len + 1);
+        prefix.push(0);
+        for flag in &flags {
+            prefix.push(prefix.last().copied().unwrap_or(0) + flag.is_some() as u32);
+        }
+        Self { flags, prefix }
+    }
+
+    pub(crate) fn get(&self, idx: usize) -> Option<CandleAnomaly> {
+        self.flags.get(idx).copied().flatten()
+    }
+
+    /// True if any candle in `start..end` is flagged.
+    pub(crate) fn any_in(&self, start: usize, end: usize) -> bool {
+        let end = end.min(self.flags.len());
+        start < end && self.prefix[end] > self.prefix[start]
+    }
+
+    pub(crate) fn heap_bytes(&self) -> usize {
+        vec_heap_bytes(&self.flags) + vec_heap_bytes(&self.prefix)
+    }
+
+    pub(crate) fn count(&self) -> usize {
+        self.prefix.last().copied().unwrap_or(0) as usize
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (usize, CandleAnomaly)> + '_ {
+        self.flags
+            .iter()
+            .enumerate()
+            .filter_map(|(i, f)| f.map(|a| (i, a)))
+    }
+}
+
+fn true_range(high: f64, low: f64, prev_close: Option<f64>) -> f64 {
+    match prev_close {
+        Some(pc) => (high - low).max((high - pc).abs()).max((low - pc).abs()),
+        None => high - low,
+    }
+}
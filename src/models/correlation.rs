@@ -0,0 +1,102 @@
+use {
+    crate::{
+        app::{CandleResolution, PriceLike},
+        models::OhlcvTimeSeries,
+    },
+    std::time::Duration,
+};
+
+/// History used to estimate a pair's beta to a leader.
+const BETA_WINDOW: Duration = Duration::from_secs(30 * 86_400);
+/// Returns are taken between these candles' closes to keep 5m microstructure noise out.
+const BETA_RETURN_RESOLUTION: CandleResolution = CandleResolution::H1;
+/// Fewer aligned returns than this and the beta is not trusted.
+const BETA_MIN_SAMPLES: usize = 48;
+/// Below this |correlation| the beta is mostly noise.
+const BETA_MIN_CORRELATION: f64 = 0.2;
+
+/// Sensitivity of `follower` to `leader`, from log returns over the shared recent history.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct BetaEstimate {
+    /// Follower move per unit leader move (cov / var of leader)
+    pub beta: f64,
+    pub correlation: f64,
+    pub samples: usize,
+}
+
+impl BetaEstimate {
+    /// Enough aligned returns for the correlation to be a measurement, however small it is.
+    pub(crate) fn is_sampled(&self) -> bool {
+        self.samples >= BETA_MIN_SAMPLES
+    }
+
+    /// Enough samples and enough correlation for the beta to carry information.
+    pub(crate) fn is_meaningful(&self) -> bool {
+        self.is_sampled() && self.correlation.abs() >= BETA_MIN_CORRELATION
+    }
+
+    /// Compared on hourly candles. Returns None without overlapping history or with a flat
+    /// leader; check [`Self::is_meaningful`] before relying on the beta.
+    pub(crate) fn between(leader: &OhlcvTimeSeries, follower: &OhlcvTimeSeries) -> Option<Self> {
+        let leader = leader.aggregated(BETA_RETURN_RESOLUTION);
+        let follower = follower.aggregated(BETA_RETURN_RESOLUTION);
+        let window_start = leader.last()?.timestamp_ms.value() - BETA_WINDOW.as_millis() as i64;
+
+        // Closes of the hours both series have
+        let mut aligned = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < leader.len() && j < follower.len() {
+            let (lt, ft) = (leader[i].timestamp_ms, follower[j].timestamp_ms);
+            if lt < ft {
+                i += 1;
+            } else if ft < lt {
+                j += 1;
+            } else {
+                if lt.value() >= window_start {
+                    aligned.push((
+                        leader[i].close_price.value(),
+                        follower[j].close_price.value(),
+                    ));
+                }
+                i += 1;
+                j += 1;
+            }
+        }
+
+        let returns: Vec<(f64, f64)> = aligned
+            .windows(2)
+            .filter(|w| w[0].0 > 0.0 && w[0].1 > 0.0 && w[1].0 > 0.0 && w[1].1 > 0.0)
+            .map(|w| ((w[1].0 / w[0].0).ln(), (w[1].1 / w[0].1).ln()))
+            .collect();
+        beta_from_returns(&returns)
+    }
+}
+
+/// `returns` are (leader, follower) log-return pairs.
+pub(crate) fn beta_from_returns(returns: &[(f64, f64)]) -> Option<BetaEstimate> {
+    let n = returns.len();
+    if n < 2 {
+        return None;
+    }
+    let mean_l = returns.iter().map(|r| r.0).sum::<f64>() / n as f64;
+    let mean_f = returns.iter().map(|r| r.1).sum::<f64>() / n as f64;
+    let (mut cov, mut var_l, mut var_f) = (0.0, 0.0, 0.0);
+    for (l, f) in returns {
+        cov += (l - mean_l) * (f - mean_f);
+        var_l += (l - mean_l).powi(2);
+        var_f += (f - mean_f).powi(2);
+    }
+    if var_l <= f64::EPSILON {
+        return None;
+    }
+    let correlation = if var_f > f64::EPSILON {
+        cov / (var_l * var_f).sqrt()
+    } else {
+        0.0
+    };
+    Some(BetaEstimate {
+        beta: cov / var_l,
+        correlation,
+        samples: n,
+    })
+}
@@ -1,11 +1,19 @@
 pub(crate) const PRICE_RECALC_THRESHOLD_PCT: PhPct = PhPct::new(0.01);
 pub(crate) const MIN_CANDLES_FOR_ANALYSIS: usize = 250;
-pub(crate) const SEGMENT_MERGE_TOLERANCE_MS: i64 = TimeUtils::MS_IN_D;
+pub(crate) const SEGMENT_MERGE_TOLERANCE: DurationMs = DurationMs::new(TimeUtils::MS_IN_D);
+/// Path density (traded volume relative to the average zone) below which a path reads as an
+/// air pocket, and above which as congested.
+pub(crate) const PATH_DENSITY_THIN: f64 = 0.5;
+pub(crate) const PATH_DENSITY_DENSE: f64 = 1.5;
 
 use {
     crate::{
-        app::{HighPrice, LowPrice, PhPct, Price, PriceRange, VolatilityPct},
-        utils::TimeUtils,
+        app::{
+            DurationMs, HighPrice, LowPrice, PhPct, Price, PriceLike, PriceRange, TimestampMs,
+            VolatilityPct,
+        },
+        ui::UI_TEXT,
+        utils::{TimeUtils, vec_heap_bytes},
     },
     serde::{Deserialize, Serialize},
     std::fmt,
@@ -18,13 +26,25 @@ pub(crate) struct CVACore {
     pub candle_bodies_vw: Vec<f64>,
     pub low_wick_counts: Vec<f64>,
     pub high_wick_counts: Vec<f64>,
+    /// Signed: net taker buy (+) / sell (-) volume per zone
+    #[serde(default)]
+    pub taker_deltas_vw: Vec<f64>,
+    /// Volume spread across wicks only (body excluded)
+    #[serde(default)]
+    pub wicks_vw: Vec<f64>,
+    /// Volume deposited at the closing price
+    #[serde(default)]
+    pub closes_vw: Vec<f64>,
+    /// Volume spread across the open-close body only
+    #[serde(default)]
+    pub bodies_only_vw: Vec<f64>,
     pub total_candles: usize,
     pub included_ranges: Vec<(usize, usize)>,
     pub pair_name: String,
     pub price_range: PriceRange<Price>,
     pub zone_count: usize,
-    pub start_timestamp_ms: i64,
-    pub end_timestamp_ms: i64,
+    pub start_timestamp_ms: TimestampMs,
+    pub end_timestamp_ms: TimestampMs,
     pub time_decay_factor: f64,
     pub relevant_candle_count: usize,
     pub interval_ms: i64,
@@ -39,6 +59,10 @@ pub(crate) enum ScoreType {
     FullCandleTVW,
     LowWickCount,
     HighWickCount,
+    TakerDeltaTVW,
+    WickOnlyTVW,
+    CloseOnlyTVW,
+    BodyOnlyTVW,
 }
 
 impl fmt::Display for ScoreType {
@@ -47,16 +71,56 @@ impl fmt::Display for ScoreType {
             Self::FullCandleTVW => write!(f, "Full Candle Temporal-Volume Weighted"),
             Self::LowWickCount => write!(f, "Low Wick Count (Rejection Prob. Numerator)"),
             Self::HighWickCount => write!(f, "High Wick Count (Rejection Prob. Numerator)"),
+            Self::TakerDeltaTVW => write!(f, "Net Taker Delta Temporal-Volume Weighted"),
+            Self::WickOnlyTVW => write!(f, "Wick-Only Temporal-Volume Weighted"),
+            Self::CloseOnlyTVW => write!(f, "Close-Only Temporal-Volume Weighted"),
+            Self::BodyOnlyTVW => write!(f, "Body-Only Temporal-Volume Weighted"),
+        }
+    }
+}
+
+impl ScoreType {
+    /// Scores that can go negative (need diverging rather than magnitude display).
+    pub(crate) fn is_signed(&self) -> bool {
+        matches!(self, Self::TakerDeltaTVW)
+    }
+
+    /// Short name for toolbar selectors.
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            Self::FullCandleTVW => &UI_TEXT.score_full_candle,
+            Self::LowWickCount => &UI_TEXT.score_low_wick_count,
+            Self::HighWickCount => &UI_TEXT.score_high_wick_count,
+            Self::TakerDeltaTVW => &UI_TEXT.score_taker_delta,
+            Self::WickOnlyTVW => &UI_TEXT.score_wick_only,
+            Self::CloseOnlyTVW => &UI_TEXT.score_close_only,
+            Self::BodyOnlyTVW => &UI_TEXT.score_body_only,
         }
     }
 }
 
 impl CVACore {
+    /// Approximate heap footprint of the score and range buffers.
+    pub(crate) fn heap_bytes(&self) -> usize {
+        vec_heap_bytes(&self.candle_bodies_vw)
+            + vec_heap_bytes(&self.low_wick_counts)
+            + vec_heap_bytes(&self.high_wick_counts)
+            + vec_heap_bytes(&self.taker_deltas_vw)
+            + vec_heap_bytes(&self.wicks_vw)
+            + vec_heap_bytes(&self.closes_vw)
+            + vec_heap_bytes(&self.bodies_only_vw)
+            + vec_heap_bytes(&self.included_ranges)
+    }
+
     pub(crate) fn get_scores_ref(&self, st: ScoreType) -> &Vec<f64> {
         match st {
             ScoreType::FullCandleTVW => &self.candle_bodies_vw,
             ScoreType::LowWickCount => &self.low_wick_counts,
             ScoreType::HighWickCount => &self.high_wick_counts,
+            ScoreType::TakerDeltaTVW => &self.taker_deltas_vw,
+            ScoreType::WickOnlyTVW => &self.wicks_vw,
+            ScoreType::CloseOnlyTVW => &self.closes_vw,
+            ScoreType::BodyOnlyTVW => &self.bodies_only_vw,
         }
     }
 
@@ -65,6 +129,18 @@ impl CVACore {
             ScoreType::FullCandleTVW => &mut self.candle_bodies_vw,
             ScoreType::LowWickCount => &mut self.low_wick_counts,
             ScoreType::HighWickCount => &mut self.high_wick_counts,
+            ScoreType::TakerDeltaTVW => &mut self.taker_deltas_vw,
+            ScoreType::WickOnlyTVW => &mut self.wicks_vw,
+            ScoreType::CloseOnlyTVW => &mut self.closes_vw,
+            ScoreType::BodyOnlyTVW => &mut self.bodies_only_vw,
+        }
+    }
+
+    /// Adds the whole score to the single zone containing `price`.
+    pub(crate) fn apply_point_score(&mut self, st: ScoreType, price: Price, score: f64) {
+        let idx = self.price_range.chunk_index(price);
+        if let Some(slot) = self.get_scores_mut_ref(st).get_mut(idx) {
+            *slot += score;
         }
     }
 
@@ -131,6 +207,38 @@ impl CVACore {
             .for_each(|count| *count += quantity_per_zone);
     }
 
+    /// Zones crossed between `from` and `to` as `(low, high, density)`, each clipped to the
+    /// path, with density = zone volume / mean zone volume. Parts outside the range are left out.
+    pub(crate) fn path_profile(&self, from: Price, to: Price) -> Vec<(f64, f64, f64)> {
+        let vols = &self.candle_bodies_vw;
+        let mean = vols.iter().sum::<f64>() / vols.len().max(1) as f64;
+        if mean <= 0.0 {
+            return Vec::new();
+        }
+        let (low, high) = (from.value().min(to.value()), from.value().max(to.value()));
+        vols.iter()
+            .enumerate()
+            .filter_map(|(idx, &vol)| {
+                let (zone_low, zone_high) = self.price_range.chunk_bounds(idx);
+                let (a, b) = (zone_low.max(low), zone_high.min(high));
+                (b > a).then_some((a, b, vol / mean))
+            })
+            .collect()
+    }
+
+    /// Length-weighted mean of [`Self::path_profile`]: under 1 the path runs through thinner
+    /// than average volume (tends to travel fast), over 1 through congestion (tends to stall).
+    /// None when no part of the path lies inside the range.
+    pub(crate) fn path_density(&self, from: Price, to: Price) -> Option<f64> {
+        let (len, weighted) = self
+            .path_profile(from, to)
+            .iter()
+            .fold((0.0, 0.0), |(len, w), &(a, b, d)| {
+                (len + (b - a), w + (b - a) * d)
+            });
+        (len > 0.0).then(|| weighted / len)
+    }
+
     pub(crate) fn new(
         min_price: LowPrice,
         max_price: HighPrice,
@@ -150,6 +258,10 @@ impl CVACore {
             candle_bodies_vw: vec![0.0; n_slices],
             low_wick_counts: vec![0.0; n_slices],
             high_wick_counts: vec![0.0; n_slices],
+            taker_deltas_vw: vec![0.0; n_slices],
+            wicks_vw: vec![0.0; n_slices],
+            closes_vw: vec![0.0; n_slices],
+            bodies_only_vw: vec![0.0; n_slices],
             pair_name,
             price_range,
             zone_count,
@@ -158,8 +270,8 @@ impl CVACore {
             interval_ms,
             volatility_pct,
             included_ranges: Vec::new(),
-            start_timestamp_ms: 0,
-            end_timestamp_ms: 0,
+            start_timestamp_ms: TimestampMs::default(),
+            end_timestamp_ms: TimestampMs::default(),
             time_decay_factor,
         }
     }
@@ -0,0 +1,247 @@
+use {
+    crate::{
+        app::PriceLike,
+        domain::Candle,
+        models::script::{BinOp, Expr, Func, Kind, ScriptField, cmp_text, function_names, parse},
+    },
+    serde::{Deserialize, Serialize},
+    std::collections::VecDeque,
+};
+
+/// Candle columns an indicator reads.
+const BAR_FIELDS: [(&str, BarField); 7] = [
+    ("open", BarField::Open),
+    ("high", BarField::High),
+    ("low", BarField::Low),
+    ("close", BarField::Close),
+    ("volume", BarField::Volume),
+    ("quote_volume", BarField::QuoteVolume),
+    ("taker_buy", BarField::TakerBuy),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BarField {
+    Open,
+    High,
+    Low,
+    Close,
+    Volume,
+    QuoteVolume,
+    TakerBuy,
+}
+
+impl ScriptField for BarField {
+    fn kind(self) -> Kind {
+        Kind::Num
+    }
+}
+
+impl BarField {
+    fn column(self, candles: &[Candle]) -> Vec<f64> {
+        candles
+            .iter()
+            .map(|c| match self {
+                Self::Open => c.open_price.value(),
+                Self::High => c.high_price.value(),
+                Self::Low => c.low_price.value(),
+                Self::Close => c.close_price.value(),
+                Self::Volume => c.base_asset_volume.value(),
+                Self::QuoteVolume => c.quote_asset_volume.value(),
+                Self::TakerBuy => c.taker_buy_base_volume.value(),
+            })
+            .collect()
+    }
+}
+
+/// Evaluates `expr` for every candle at once. True/false become 1/0 and text (only ever a
+/// literal here) NaN, so comparisons on it fail like on missing values.
+fn eval_series(expr: &Expr<BarField>, candles: &[Candle]) -> Vec<f64> {
+    let n = candles.len();
+    let bool_num = |b: bool| if b { 1.0 } else { 0.0 };
+    match expr {
+        Expr::Num(v) => vec![*v; n],
+        Expr::Str(_) => vec![f64::NAN; n],
+        Expr::Bool(b) => vec![bool_num(*b); n],
+        Expr::Field(field) => field.column(candles),
+        Expr::Not(e) => eval_series(e, candles)
+            .into_iter()
+            .map(|v| bool_num(v != 1.0))
+            .collect(),
+        Expr::Neg(e) => eval_series(e, candles).into_iter().map(|v| -v).collect(),
+        Expr::Binary(op, l, r) => {
+            if let (Expr::Str(a), Expr::Str(b)) = (l.as_ref(), r.as_ref()) {
+                return vec![bool_num(op.holds(Some(cmp_text(a, b)))); n];
+            }
+            let (l, r) = (eval_series(l, candles), eval_series(r, candles));
+            l.into_iter()
+                .zip(r)
+                .map(|(a, b)| match op {
+                    BinOp::Or => bool_num(a == 1.0 || b == 1.0),
+                    BinOp::And => bool_num(a == 1.0 && b == 1.0),
+                    BinOp::Add | BinOp::Sub | BinOp::Mul | BinOp::Div => op.arithmetic(a, b),
+                    _ => bool_num(op.holds(a.partial_cmp(&b))),
+                })
+                .collect()
+        }
+        Expr::Call(func, args) => {
+            let mut cols = args.iter().map(|a| eval_series(a, candles));
+            let first = cols.next().unwrap_or_else(|| vec![f64::NAN; n]);
+            let second = cols.next().unwrap_or_else(|| vec![f64::NAN; n]);
+            first
+                .into_iter()
+                .zip(second)
+                .map(|(a, b)| match func {
+                    Func::Abs => a.abs(),
+                    Func::Min => a.min(b),
+                    Func::Max => a.max(b),
+                    _ => f64::NAN,
+                })
+                .collect()
+        }
+        Expr::Window(func, e, bars) => window(*func, &eval_series(e, candles), *bars),
+    }
+}
+
+/// Window function over `xs`. NaN until `bars` values are in, and wherever a NaN sits in
+/// the window (sma, stdev) or at the current bar (ema).
+fn window(func: Func, xs: &[f64], bars: usize) -> Vec<f64> {
+    let mut out = vec![f64::NAN; xs.len()];
+    match func {
+        Func::Sma | Func::Stdev => {
+            // Welford's update over the non-NaN values in the window; the textbook
+            // sum_sq / n - mean² loses everything to cancellation at price-sized values
+            let (mut count, mut mean, mut m2, mut nans) = (0usize, 0.0, 0.0, 0usize);
+            for (i, &x) in xs.iter().enumerate() {
+                if x.is_nan() {
+                    nans += 1;
+                } else {
+                    count += 1;
+                    let delta = x - mean;
+                    mean += delta / count as f64;
+                    m2 += delta * (x - mean);
+                }
+                if i >= bars {
+                    let old = xs[i - bars];
+                    if old.is_nan() {
+                        nans -= 1;
+                    } else {
+                        count -= 1;
+                        if count == 0 {
+                            (mean, m2) = (0.0, 0.0);
+                        } else {
+                            let delta = old - mean;
+                            mean -= delta / count as f64;
+                            m2 -= delta * (old - mean);
+                        }
+                    }
+                }
+                if i + 1 < bars || nans > 0 {
+                    continue;
+                }
+                out[i] = if func == Func::Sma {
+                    mean
+                } else {
+                    (m2 / bars as f64).max(0.0).sqrt()
+                };
+            }
+        }
+        Func::Ema => {
+            let alpha = 2.0 / (bars as f64 + 1.0);
+            let mut ema: Option<f64> = None;
+            let mut seen = 0;
+            for (i, &x) in xs.iter().enumerate() {
+                if x.is_nan() {
+                    continue;
+                }
+                let next = ema.map_or(x, |prev| prev + alpha * (x - prev));
+                ema = Some(next);
+                seen += 1;
+                if seen >= bars {
+                    out[i] = next;
+                }
+            }
+        }
+        Func::Highest | Func::Lowest => {
+            let beats = |a: f64, b: f64| {
+                if func == Func::Highest {
+                    a >= b
+                } else {
+                    a <= b
+                }
+            };
+            // Indices of candidate extremes, best first
+            let mut deque: VecDeque<usize> = VecDeque::new();
+            for (i, &x) in xs.iter().enumerate() {
+                if !x.is_nan() {
+                    while deque.back().is_some_and(|&j| beats(x, xs[j])) {
+                        deque.pop_back();
+                    }
+                    deque.push_back(i);
+                }
+                while deque.front().is_some_and(|&j| j + bars <= i) {
+                    deque.pop_front();
+                }
+                if i + 1 >= bars {
+                    out[i] = deque.front().map_or(f64::NAN, |&j| xs[j]);
+                }
+            }
+        }
+        Func::Prev => {
+            if bars < xs.len() {
+                out[bars..].copy_from_slice(&xs[..xs.len() - bars]);
+            }
+        }
+        Func::Abs | Func::Min | Func::Max => {}
+    }
+    out
+}
+
+/// A checked indicator expression over candle columns.
+#[derive(Debug, Clone)]
+pub(crate) struct IndicatorExpr(Expr<BarField>);
+
+impl IndicatorExpr {
+    /// Parses an expression that must come out a number per candle.
+    pub(crate) fn parse(src: &str) -> Result<Self, String> {
+        parse(src, &BAR_FIELDS, true, Kind::Num).map(Self)
+    }
+
+    /// One value per candle; NaN where the indicator has none.
+    pub(crate) fn evaluate(&self, candles: &[Candle]) -> Vec<f64> {
+        eval_series(&self.0, candles)
+    }
+}
+
+/// A user-written line drawn over one pair's candles at the plot's resolution.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct IndicatorScript {
+    pub name: String,
+    pub source: String,
+    /// Index into the plot's indicator palette
+    pub color: usize,
+    pub enabled: bool,
+    /// Editor's last parse of `source`; `None` until it has been checked
+    #[serde(skip)]
+    pub parsed: Option<Result<(), String>>,
+}
+
+impl Default for IndicatorScript {
+    fn default() -> Self {
+        Self {
+            name: "EMA 20".to_string(),
+            source: "ema(close, 20)".to_string(),
+            color: 0,
+            enabled: true,
+            parsed: None,
+        }
+    }
+}
+
+/// Column and function names for the indicator editor's help text.
+pub(crate) fn indicator_field_names() -> impl Iterator<Item = &'static str> {
+    BAR_FIELDS
+        .iter()
+        .map(|(name, _)| *name)
+        .chain(function_names(true))
+}
@@ -1,13 +1,16 @@
 use {
     crate::{
         app::{Pct, PriceLike},
-        models::TradeOpportunity,
+        models::{DEFAULT_JOURNEY_SETTINGS, OPPORTUNITY_ID_PREFIX, TradeOpportunity},
+        ui::UI_TEXT,
     },
+    chrono::{DateTime, Duration as ChronoDuration, Utc},
     serde::{Deserialize, Serialize},
     std::{
         cmp::Ordering,
-        collections::{HashMap, HashSet},
+        collections::{BTreeSet, HashMap, HashSet},
     },
+    strum_macros::EnumIter,
 };
 
 #[cfg(debug_assertions)]
@@ -17,18 +20,129 @@ use {
 };
 
 #[cfg(not(target_arch = "wasm32"))]
-use crate::data::load_ledger;
+use crate::data::{load_ledger, set_aside_ledger};
+
+/// User-tunable ledger housekeeping, applied alongside collision pruning.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct LedgerPolicy {
+    /// How often collisions and the limits below are enforced
+    pub prune_interval_secs: u64,
+    /// Drop opportunities first seen longer ago than this (0 = no limit)
+    pub max_age_hours: u64,
+    /// Keep only the best scoring opportunities of each pair (0 = no limit)
+    pub max_per_pair: usize,
+    /// Opportunities marked as taken are exempt from the age and count limits
+    pub keep_taken: bool,
+}
+
+impl Default for LedgerPolicy {
+    fn default() -> Self {
+        Self {
+            prune_interval_secs: DEFAULT_JOURNEY_SETTINGS.optimization.prune_interval_sec,
+            max_age_hours: 0,
+            max_per_pair: 0,
+            keep_taken: true,
+        }
+    }
+}
+
+/// How an imported opportunity is merged when the ledger already holds the same trade.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, EnumIter)]
+pub(crate) enum LedgerConflict {
+    /// Keep the local figures (they track the live market), adopt the earlier first-seen time
+    #[default]
+    KeepLocal,
+    /// Overwrite with the imported figures, still keeping the earlier first-seen time
+    PreferImported,
+}
+
+impl LedgerConflict {
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            Self::KeepLocal => &UI_TEXT.es_ledger_conflict_keep_local,
+            Self::PreferImported => &UI_TEXT.es_ledger_conflict_prefer_imported,
+        }
+    }
+}
+
+/// What [`OpportunityLedger::import`] did with each incoming opportunity.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct LedgerImportSummary {
+    /// New to this ledger
+    pub added: usize,
+    /// Same trade already present, resolved by [`LedgerConflict`]
+    pub merged: usize,
+    /// Id taken by an unrelated trade, stored under a suffixed id
+    pub renamed: usize,
+}
+
+/// What a purge removed, by reason.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PurgeSummary {
+    pub ids: Vec<String>,
+    pub collisions: usize,
+    pub expired: usize,
+    pub over_limit: usize,
+    pub pairs: BTreeSet<String>,
+}
+
+impl PurgeSummary {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+}
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub(crate) struct OpportunityLedger {
     pub opportunities: HashMap<String, TradeOpportunity>,
+    /// Old id -> new id for entries re-keyed by [`Self::rekey_legacy_ids`], so selections
+    /// and notes saved under an old id still resolve after a restart.
+    renamed: HashMap<String, String>,
 }
 
 impl OpportunityLedger {
     pub(crate) fn new() -> Self {
-        Self {
-            opportunities: HashMap::new(),
+        Self::default()
+    }
+
+    /// Looks up an opportunity, following ids re-keyed since it was stored.
+    pub(crate) fn get(&self, id: &str) -> Option<&TradeOpportunity> {
+        self.opportunities.get(id).or_else(|| {
+            self.renamed
+                .get(id)
+                .and_then(|new_id| self.opportunities.get(new_id))
+        })
+    }
+
+    /// Moves entries stored under a pre-[`TradeOpportunity::stable_id`] scheme to their
+    /// stable ids. Returns how many were re-keyed.
+    pub(crate) fn rekey_legacy_ids(&mut self) -> usize {
+        let legacy: Vec<String> = self
+            .opportunities
+            .keys()
+            .filter(|id| !id.starts_with(OPPORTUNITY_ID_PREFIX))
+            .cloned()
+            .collect();
+        for old_id in &legacy {
+            let Some(mut op) = self.opportunities.remove(old_id) else {
+                continue;
+            };
+            let new_id = self.free_id(&op);
+            op.id = new_id.clone();
+            self.opportunities.insert(new_id.clone(), op);
+            self.renamed.insert(old_id.clone(), new_id);
         }
+        legacy.len()
+    }
+
+    /// `op`'s stable id, or the first `-N` suffixed variant not held by another trade.
+    fn free_id(&self, op: &TradeOpportunity) -> String {
+        let base = op.identity_id();
+        std::iter::once(base.clone())
+            .chain((1..).map(|n| format!("{}-{}", base, n)))
+            .find(|id| !self.opportunities.contains_key(id))
+            .unwrap_or(base)
     }
 
     #[cfg(debug_assertions)]
@@ -75,13 +189,18 @@ impl OpportunityLedger {
     /// Returns (is_new, active_id).
     pub(crate) fn evolve(
         &mut self,
-        new_opp: TradeOpportunity,
+        mut new_opp: TradeOpportunity,
         tolerance_pct: Pct,
     ) -> (bool, String) {
         let exact_id = new_opp.id.clone();
-        if self.opportunities.contains_key(&exact_id) {
-            self.update_existing(&exact_id, new_opp);
-            return (false, exact_id);
+        match self.opportunities.get(&exact_id) {
+            Some(existing) if existing.is_comparable_to(&new_opp) => {
+                self.update_existing(&exact_id, new_opp);
+                return (false, exact_id);
+            }
+            // Id collision with an unrelated trade: never overwrite it
+            Some(_) => new_opp.id = self.free_id(&new_opp),
+            None => {}
         }
 
         let closest_match = self
@@ -222,6 +341,125 @@ impl OpportunityLedger {
         to_remove
     }
 
+    /// Merges opportunities from another ledger (see [`crate::data::import_ledger`]). An id
+    /// held by the same trade is resolved by `conflict`; one held by an unrelated trade gets a
+    /// free suffixed id, so nothing local is ever lost.
+    pub(crate) fn import(
+        &mut self,
+        opportunities: Vec<TradeOpportunity>,
+        conflict: LedgerConflict,
+    ) -> LedgerImportSummary {
+        let mut summary = LedgerImportSummary::default();
+        for mut op in opportunities {
+            match self.opportunities.get_mut(&op.id) {
+                None => summary.added += 1,
+                Some(existing) if existing.is_comparable_to(&op) => {
+                    summary.merged += 1;
+                    let created_at = existing.created_at.min(op.created_at);
+                    if conflict == LedgerConflict::PreferImported {
+                        *existing = op;
+                    }
+                    existing.created_at = created_at;
+                    continue;
+                }
+                Some(_) => {
+                    summary.renamed += 1;
+                    op.id = self.free_id(&op);
+                }
+            }
+            self.opportunities.insert(op.id.clone(), op);
+        }
+        summary
+    }
+
+    /// Merges colliding trades, then applies the age and per-pair limits of `policy`.
+    pub(crate) fn purge(
+        &mut self,
+        policy: &LedgerPolicy,
+        taken: &HashSet<String>,
+        tolerance_pct: Pct,
+        now: DateTime<Utc>,
+    ) -> PurgeSummary {
+        let pair_of: HashMap<String, String> = self
+            .opportunities
+            .iter()
+            .map(|(id, op)| (id.clone(), op.pair_name.clone()))
+            .collect();
+        let mut summary = self.enforce_policy(policy, taken, now);
+        let collided = self.prune_collisions(tolerance_pct);
+        summary.collisions = collided.len();
+        summary
+            .pairs
+            .extend(collided.iter().filter_map(|id| pair_of.get(id).cloned()));
+        summary.ids.extend(collided);
+        summary
+    }
+
+    /// Removes opportunities older than `max_age_hours`, then all but the best `max_per_pair`
+    /// of each pair by quality score.
+    pub(crate) fn enforce_policy(
+        &mut self,
+        policy: &LedgerPolicy,
+        taken: &HashSet<String>,
+        now: DateTime<Utc>,
+    ) -> PurgeSummary {
+        let mut summary = PurgeSummary::default();
+        let exempt = |id: &str| policy.keep_taken && taken.contains(id);
+
+        if policy.max_age_hours > 0 {
+            let cutoff = now - ChronoDuration::hours(policy.max_age_hours as i64);
+            let expired: Vec<String> = self
+                .opportunities
+                .values()
+                .filter(|op| op.created_at < cutoff && !exempt(&op.id))
+                .map(|op| op.id.clone())
+                .collect();
+            summary.expired = expired.len();
+            self.remove_into(&mut summary, expired);
+        }
+
+        if policy.max_per_pair > 0 {
+            let mut by_pair: HashMap<&str, Vec<&TradeOpportunity>> = HashMap::new();
+            for op in self.opportunities.values().filter(|op| !exempt(&op.id)) {
+                by_pair.entry(&op.pair_name).or_default().push(op);
+            }
+            let mut over_limit = Vec::new();
+            for ops in by_pair.values_mut() {
+                if ops.len() <= policy.max_per_pair {
+                    continue;
+                }
+                ops.sort_by(|a, b| {
+                    b.calc_quality_score()
+                        .partial_cmp(&a.calc_quality_score())
+                        .unwrap_or(Ordering::Equal)
+                });
+                over_limit.extend(ops[policy.max_per_pair..].iter().map(|op| op.id.clone()));
+            }
+            summary.over_limit = over_limit.len();
+            self.remove_into(&mut summary, over_limit);
+        }
+
+        #[cfg(debug_assertions)]
+        if DF.log_ledger && !summary.is_empty() {
+            log::info!(
+                "🧹 LEDGER POLICY: {} expired, {} over per-pair limit ({:?})",
+                summary.expired,
+                summary.over_limit,
+                summary.pairs
+            );
+        }
+        summary
+    }
+
+    fn remove_into(&mut self, summary: &mut PurgeSummary, ids: Vec<String>) {
+        for id in ids {
+            if let Some(op) = self.opportunities.remove(&id) {
+                summary.pairs.insert(op.pair_name);
+                summary.ids.push(id);
+            }
+        }
+    }
+
     fn update_existing(&mut self, existing_id: &str, mut new_opp: TradeOpportunity) {
         if let Some(existing) = self.opportunities.get(existing_id) {
             #[cfg(debug_assertions)]
@@ -273,9 +511,20 @@ pub(crate) fn restore_engine_ledger(valid_session_pairs: &HashSet<String>) -> Op
                     }
                     l
                 }
-                Err(_e) => {
-                    #[cfg(debug_assertions)]
-                    log::error!("Failed to load ledger (starting fresh): {}", _e);
+                Err(e) => {
+                    // Keep the unreadable store so the first save cannot destroy its history
+                    match set_aside_ledger() {
+                        Ok(aside) => log::error!(
+                            "Failed to load ledger (starting fresh, kept the old one at {:?}): {:#}",
+                            aside,
+                            e
+                        ),
+                        Err(move_err) => log::error!(
+                            "Failed to load ledger (starting fresh): {:#}; could not set it aside: {}",
+                            e,
+                            move_err
+                        ),
+                    }
                     OpportunityLedger::new()
                 }
             }
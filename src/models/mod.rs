@@ -2,37 +2,65 @@
 mod tests;
 
 mod adaptive;
+mod aggregated;
+mod analysis_report;
+mod anomaly;
+mod correlation;
 mod cva;
+mod indicator_script;
 mod ledger;
 mod market_state;
+mod model_diff;
 mod ohlcv;
+mod opportunity_script;
 mod optimization_strategy;
 mod pair_analysis;
 mod range_gap_finder;
 mod scenario_simulator;
+mod script;
 mod trade_opportunity;
 mod trading_model;
+mod vol_term;
+mod zone_edits;
 
 pub use ohlcv::OhlcvTimeSeries;
 
 pub(crate) use {
-    adaptive::AdaptiveParameters,
+    adaptive::{AdaptiveCurve, AdaptiveCurves, AdaptiveParameters},
+    aggregated::{AggregateCache, merge_candles},
+    analysis_report::{AnalysisReport, ModelSnapshot},
+    anomaly::{AnomalyMask, CandleAnomaly},
+    correlation::BetaEstimate,
     cva::{
-        CVACore, MIN_CANDLES_FOR_ANALYSIS, PRICE_RECALC_THRESHOLD_PCT, SEGMENT_MERGE_TOLERANCE_MS,
-        ScoreType,
+        CVACore, MIN_CANDLES_FOR_ANALYSIS, PATH_DENSITY_DENSE, PATH_DENSITY_THIN,
+        PRICE_RECALC_THRESHOLD_PCT, SEGMENT_MERGE_TOLERANCE, ScoreType,
+    },
+    indicator_script::{IndicatorExpr, IndicatorScript, indicator_field_names},
+    ledger::{
+        LedgerConflict, LedgerImportSummary, LedgerPolicy, OpportunityLedger, PurgeSummary,
+        restore_engine_ledger,
     },
-    ledger::{OpportunityLedger, restore_engine_ledger},
     market_state::MarketState,
+    model_diff::{DiffChange, DiffZoneKind, ModelDiff, OpportunitySummary},
     ohlcv::{LiveCandle, TimeSeriesSlice, find_matching_ohlcv},
+    opportunity_script::{OpportunityScript, ScriptExpr, ScriptFields, script_field_names},
     optimization_strategy::OptimizationStrategy,
     pair_analysis::pair_analysis_pure,
-    range_gap_finder::{DisplaySegment, GapReason, RangeGapFinder},
-    scenario_simulator::{DEFAULT_SIMILARITY, EmpiricalOutcomeStats, ScenarioSimulator},
+    range_gap_finder::{DisplaySegment, GapReason, RangeGapFinder, SegmentStats},
+    scenario_simulator::{
+        DEFAULT_SIMILARITY, EmpiricalOutcomeStats, ExcursionStats, HitTimes, JourneyReplay,
+        Outcome, ScenarioSimulator,
+    },
     trade_opportunity::{
-        DEFAULT_JOURNEY_SETTINGS, DEFAULT_ZONE_CONFIG, TradeDirection, TradeOpportunity,
+        DEFAULT_JOURNEY_SETTINGS, DEFAULT_ZONE_CONFIG, OPPORTUNITY_ID_PREFIX,
+        OpportunityAnnotation, TargetAnchor, TargetRung, TradeDirection, TradeOpportunity,
         TradeVariant, VisualFluff,
     },
-    trading_model::{SuperZone, TradingModel},
+    trading_model::{
+        Approach, ApproachStats, ClassifiedZones, SuperZone, TradingModel, ZoneCoverageStats,
+    },
+    vol_term::VolTermStructure,
+    zone_edits::{CustomZone, ZoneEdit},
 };
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -0,0 +1,245 @@
+use {
+    crate::{
+        app::{Price, PriceLike, Prob, RoiPct, TargetPrice},
+        models::{
+            ClassifiedZones, SuperZone, TradeDirection, TradeOpportunity, TradingModel,
+            ZoneCoverageStats,
+        },
+    },
+    serde::{Deserialize, Serialize},
+    std::collections::HashMap,
+};
+
+/// Relative edge shift below which a matched zone counts as unchanged.
+const ZONE_MOVE_TOLERANCE: f64 = 1e-6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum DiffZoneKind {
+    Sticky,
+    Support,
+    Resistance,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DiffChange {
+    Added,
+    Removed,
+    /// Still there (same id), but something about it changed
+    Changed,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct ZoneChange {
+    pub kind: DiffZoneKind,
+    pub id: u64,
+    pub change: DiffChange,
+    /// (bottom, top) before the recalculation; `None` when added
+    pub before: Option<(Price, Price)>,
+    /// (bottom, top) after the recalculation; `None` when removed
+    pub after: Option<(Price, Price)>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct OpportunitySummary {
+    pub target_price: TargetPrice,
+    pub success_rate: Prob,
+    pub expected_roi: RoiPct,
+}
+
+impl From<&TradeOpportunity> for OpportunitySummary {
+    fn from(op: &TradeOpportunity) -> Self {
+        Self {
+            target_price: op.target_price,
+            success_rate: op.simulation.success_rate,
+            expected_roi: op.expected_roi(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct OpportunityChange {
+    pub id: String,
+    pub direction: TradeDirection,
+    pub change: DiffChange,
+    pub before: Option<OpportunitySummary>,
+    pub after: Option<OpportunitySummary>,
+}
+
+/// What changed between two consecutive models of the same pair.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ModelDiff {
+    pub zones: Vec<ZoneChange>,
+    pub opportunities: Vec<OpportunityChange>,
+    pub coverage_before: ZoneCoverageStats,
+    pub coverage_after: ZoneCoverageStats,
+}
+
+impl ModelDiff {
+    /// Expects `next` to have inherited zone ids from `previous` already.
+    pub(crate) fn between(previous: &TradingModel, next: &TradingModel) -> Self {
+        Self {
+            zones: diff_classified_zones(&previous.zones, &next.zones),
+            opportunities: diff_opportunities(&previous.opportunities, &next.opportunities),
+            coverage_before: previous.coverage.clone(),
+            coverage_after: next.coverage.clone(),
+        }
+    }
+
+    pub(crate) fn coverage_delta(&self) -> ZoneCoverageStats {
+        ZoneCoverageStats {
+            sticky_pct: self.coverage_after.sticky_pct - self.coverage_before.sticky_pct,
+            resistance_pct: self.coverage_after.resistance_pct
+                - self.coverage_before.resistance_pct,
+            support_pct: self.coverage_after.support_pct - self.coverage_before.support_pct,
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        let delta = self.coverage_delta();
+        self.zones.is_empty()
+            && self.opportunities.is_empty()
+            && delta.sticky_pct == 0.0
+            && delta.resistance_pct == 0.0
+            && delta.support_pct == 0.0
+    }
+
+    /// (added, removed, changed)
+    pub(crate) fn zone_counts(&self) -> (usize, usize, usize) {
+        count_changes(self.zones.iter().map(|z| z.change))
+    }
+
+    /// (added, removed, changed)
+    pub(crate) fn opportunity_counts(&self) -> (usize, usize, usize) {
+        count_changes(self.opportunities.iter().map(|o| o.change))
+    }
+}
+
+fn count_changes(changes: impl Iterator<Item = DiffChange>) -> (usize, usize, usize) {
+    changes.fold((0, 0, 0), |(a, r, c), change| match change {
+        DiffChange::Added => (a + 1, r, c),
+        DiffChange::Removed => (a, r + 1, c),
+        DiffChange::Changed => (a, r, c + 1),
+    })
+}
+
+fn diff_classified_zones(previous: &ClassifiedZones, next: &ClassifiedZones) -> Vec<ZoneChange> {
+    let mut changes = diff_zones(
+        DiffZoneKind::Sticky,
+        &previous.sticky_superzones,
+        &next.sticky_superzones,
+    );
+    changes.extend(diff_zones(
+        DiffZoneKind::Support,
+        &previous.low_wicks_superzones,
+        &next.low_wicks_superzones,
+    ));
+    changes.extend(diff_zones(
+        DiffZoneKind::Resistance,
+        &previous.high_wicks_superzones,
+        &next.high_wicks_superzones,
+    ));
+    changes
+}
+
+/// Zones are matched by id, so a zone that drifted (and kept its id) shows as changed
+/// rather than removed + added.
+pub(crate) fn diff_zones(
+    kind: DiffZoneKind,
+    previous: &[SuperZone],
+    next: &[SuperZone],
+) -> Vec<ZoneChange> {
+    let bounds = |z: &SuperZone| (z.price_bottom, z.price_top);
+    let before: HashMap<u64, &SuperZone> = previous.iter().map(|z| (z.id, z)).collect();
+    let mut changes = Vec::new();
+    for zone in next {
+        match before.get(&zone.id) {
+            None => changes.push(ZoneChange {
+                kind,
+                id: zone.id,
+                change: DiffChange::Added,
+                before: None,
+                after: Some(bounds(zone)),
+            }),
+            Some(prev)
+                if edge_moved(prev.price_bottom, zone.price_bottom)
+                    || edge_moved(prev.price_top, zone.price_top) =>
+            {
+                changes.push(ZoneChange {
+                    kind,
+                    id: zone.id,
+                    change: DiffChange::Changed,
+                    before: Some(bounds(prev)),
+                    after: Some(bounds(zone)),
+                })
+            }
+            Some(_) => {}
+        }
+    }
+    for prev in previous {
+        if !next.iter().any(|z| z.id == prev.id) {
+            changes.push(ZoneChange {
+                kind,
+                id: prev.id,
+                change: DiffChange::Removed,
+                before: Some(bounds(prev)),
+                after: None,
+            });
+        }
+    }
+    changes
+}
+
+fn edge_moved(before: Price, after: Price) -> bool {
+    let scale = before.value().abs().max(f64::EPSILON);
+    (after.value() - before.value()).abs() / scale > ZONE_MOVE_TOLERANCE
+}
+
+/// Opportunity ids are stable across re-runs, so matching is by id. Matched opportunities
+/// count as changed when target, success rate or expected ROI moved.
+pub(crate) fn diff_opportunities(
+    previous: &[TradeOpportunity],
+    next: &[TradeOpportunity],
+) -> Vec<OpportunityChange> {
+    let before: HashMap<&str, &TradeOpportunity> =
+        previous.iter().map(|op| (op.id.as_str(), op)).collect();
+    let mut changes = Vec::new();
+    for op in next {
+        let after = OpportunitySummary::from(op);
+        match before.get(op.id.as_str()) {
+            None => changes.push(OpportunityChange {
+                id: op.id.clone(),
+                direction: op.direction,
+                change: DiffChange::Added,
+                before: None,
+                after: Some(after),
+            }),
+            Some(prev) => {
+                let prev_summary = OpportunitySummary::from(*prev);
+                let changed = prev_summary.target_price.value() != after.target_price.value()
+                    || prev_summary.success_rate.value() != after.success_rate.value()
+                    || prev_summary.expected_roi.value() != after.expected_roi.value();
+                if changed {
+                    changes.push(OpportunityChange {
+                        id: op.id.clone(),
+                        direction: op.direction,
+                        change: DiffChange::Changed,
+                        before: Some(prev_summary),
+                        after: Some(after),
+                    });
+                }
+            }
+        }
+    }
+    for prev in previous {
+        if !next.iter().any(|op| op.id == prev.id) {
+            changes.push(OpportunityChange {
+                id: prev.id.clone(),
+                direction: prev.direction,
+                change: DiffChange::Removed,
+                before: Some(OpportunitySummary::from(prev)),
+                after: None,
+            });
+        }
+    }
+    changes
+}
@@ -2,13 +2,15 @@ use {
     crate::{
         app::{
             BaseVol, ClosePrice, HighPrice, LowPrice, OpenPrice, Price, PriceLike, QuoteVol,
-            VolRatio, VolatilityPct,
+            TimestampMs, VolRatio, VolatilityPct,
         },
         domain::{Candle, PairInterval},
-        models::{CVACore, ScoreType},
+        models::{AggregateCache, AnomalyMask, CVACore, ScoreType},
+        utils::vec_heap_bytes,
     },
     anyhow::{Result, anyhow},
     serde::{Deserialize, Serialize},
+    std::ops::Range,
 };
 
 const RVOL_WINDOW: usize = 20;
@@ -16,28 +18,50 @@ const RVOL_WINDOW: usize = 20;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LiveCandle {
     pub symbol: String,
-    pub open_time: i64,
+    pub open_time: TimestampMs,
     pub open: OpenPrice,
     pub high: HighPrice,
     pub low: LowPrice,
     pub close: ClosePrice,
     pub volume: BaseVol,
     pub quote_vol: QuoteVol,
+    pub taker_buy_vol: BaseVol,
     pub is_closed: bool,
 }
 
+impl LiveCandle {
+    pub(crate) fn from_candle(symbol: &str, candle: Candle, is_closed: bool) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            open_time: candle.timestamp_ms,
+            open: candle.open_price,
+            high: candle.high_price,
+            low: candle.low_price,
+            close: candle.close_price,
+            volume: candle.base_asset_volume,
+            quote_vol: candle.quote_asset_volume,
+            taker_buy_vol: candle.taker_buy_base_volume,
+            is_closed,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct OhlcvTimeSeries {
     pub pair_interval: PairInterval,
-    pub first_kline_timestamp_ms: i64,
-    pub timestamps: Vec<i64>,
+    pub first_kline_timestamp_ms: TimestampMs,
+    pub timestamps: Vec<TimestampMs>,
     pub open_prices: Vec<OpenPrice>,
     pub high_prices: Vec<HighPrice>,
     pub low_prices: Vec<LowPrice>,
     pub close_prices: Vec<ClosePrice>,
     pub base_asset_volumes: Vec<BaseVol>,
     pub quote_asset_volumes: Vec<QuoteVol>,
+    pub taker_buy_volumes: Vec<BaseVol>,
     pub relative_volumes: Vec<VolRatio>,
+    /// Lazily built higher-timeframe candles (see `aggregated`)
+    #[serde(skip)]
+    pub(crate) aggregates: AggregateCache,
 }
 
 pub(crate) fn find_matching_ohlcv<'a>(
@@ -60,6 +84,20 @@ pub(crate) fn find_matching_ohlcv<'a>(
 }
 
 impl OhlcvTimeSeries {
+    /// Approximate heap footprint of the column buffers.
+    pub(crate) fn heap_bytes(&self) -> usize {
+        vec_heap_bytes(&self.timestamps)
+            + vec_heap_bytes(&self.open_prices)
+            + vec_heap_bytes(&self.high_prices)
+            + vec_heap_bytes(&self.low_prices)
+            + vec_heap_bytes(&self.close_prices)
+            + vec_heap_bytes(&self.base_asset_volumes)
+            + vec_heap_bytes(&self.quote_asset_volumes)
+            + vec_heap_bytes(&self.taker_buy_volumes)
+            + vec_heap_bytes(&self.relative_volumes)
+            + self.aggregates.heap_bytes()
+    }
+
     pub(crate) fn update_from_live(&mut self, candle: &LiveCandle) {
         if self.timestamps.is_empty() {
             return;
@@ -67,6 +105,10 @@ impl OhlcvTimeSeries {
 
         let last_idx = self.timestamps.len() - 1;
         let last_ts = self.timestamps[last_idx];
+        if candle.open_time < last_ts {
+            // Stale (e.g. a replayed candle the stream already delivered)
+            return;
+        }
         let is_update = candle.open_time == last_ts;
 
         if is_update {
@@ -75,6 +117,7 @@ impl OhlcvTimeSeries {
             self.close_prices[last_idx] = candle.close;
             self.base_asset_volumes[last_idx] = candle.volume;
             self.quote_asset_volumes[last_idx] = candle.quote_vol;
+            self.taker_buy_volumes[last_idx] = candle.taker_buy_vol;
 
             let rvol = self.calc_rvol_at_index(last_idx);
             if last_idx < self.relative_volumes.len() {
@@ -90,11 +133,16 @@ impl OhlcvTimeSeries {
             self.close_prices.push(candle.close);
             self.base_asset_volumes.push(candle.volume);
             self.quote_asset_volumes.push(candle.quote_vol);
+            self.taker_buy_volumes.push(candle.taker_buy_vol);
 
             let new_idx = self.timestamps.len() - 1;
             let rvol = self.calc_rvol_at_index(new_idx);
             self.relative_volumes.push(rvol);
         }
+
+        let mut aggregates = std::mem::take(&mut self.aggregates);
+        aggregates.refresh_tail(self);
+        self.aggregates = aggregates;
     }
 
     fn calc_rvol_at_index(&self, idx: usize) -> VolRatio {
@@ -112,7 +160,7 @@ impl OhlcvTimeSeries {
         if candles.is_empty() {
             return Self {
                 pair_interval,
-                first_kline_timestamp_ms: 0,
+                first_kline_timestamp_ms: TimestampMs::default(),
                 timestamps: vec![],
                 open_prices: vec![],
                 high_prices: vec![],
@@ -120,12 +168,14 @@ impl OhlcvTimeSeries {
                 close_prices: vec![],
                 base_asset_volumes: vec![],
                 quote_asset_volumes: vec![],
+                taker_buy_volumes: vec![],
                 relative_volumes: vec![],
+                aggregates: AggregateCache::default(),
             };
         }
 
         let len = candles.len();
-        let first_ts = candles.first().map(|c| c.timestamp_ms).unwrap_or(0);
+        let first_ts = candles.first().map(|c| c.timestamp_ms).unwrap_or_default();
 
         let mut ts_vec = Vec::with_capacity(len);
         let mut open_vec = Vec::with_capacity(len);
@@ -134,6 +184,7 @@ impl OhlcvTimeSeries {
         let mut close_vec = Vec::with_capacity(len);
         let mut base_vec = Vec::with_capacity(len);
         let mut quote_vec = Vec::with_capacity(len);
+        let mut taker_vec = Vec::with_capacity(len);
         let mut rvol_vec = Vec::with_capacity(len);
 
         let mut rolling_sum = 0.0;
@@ -147,6 +198,7 @@ impl OhlcvTimeSeries {
             close_vec.push(c.close_price);
             base_vec.push(c.base_asset_volume);
             quote_vec.push(c.quote_asset_volume);
+            taker_vec.push(c.taker_buy_base_volume);
 
             rolling_sum += c.base_asset_volume.value();
 
@@ -170,7 +222,9 @@ impl OhlcvTimeSeries {
             close_prices: close_vec,
             base_asset_volumes: base_vec,
             quote_asset_volumes: quote_vec,
+            taker_buy_volumes: taker_vec,
             relative_volumes: rvol_vec,
+            aggregates: AggregateCache::default(),
         }
     }
 
@@ -213,12 +267,40 @@ impl OhlcvTimeSeries {
             self.close_prices[idx],
             self.base_asset_volumes[idx],
             self.quote_asset_volumes[idx],
+            self.taker_buy_volumes[idx],
         )
     }
 
     pub(crate) fn klines(&self) -> usize {
         self.open_prices.len()
     }
+
+    /// Candles at indices `range`, in time order.
+    pub(crate) fn candles(
+        &self,
+        range: Range<usize>,
+    ) -> impl DoubleEndedIterator<Item = Candle> + ExactSizeIterator + '_ {
+        range.map(|i| self.get_candle(i))
+    }
+
+    /// As [`Self::candles`], paired with each candle's index in the series.
+    pub(crate) fn indexed_candles(
+        &self,
+        range: Range<usize>,
+    ) -> impl DoubleEndedIterator<Item = (usize, Candle)> + ExactSizeIterator + '_ {
+        range.map(|i| (i, self.get_candle(i)))
+    }
+
+    /// Candles opening in `[start, end)`.
+    pub(crate) fn candles_between(
+        &self,
+        start: TimestampMs,
+        end: TimestampMs,
+    ) -> impl DoubleEndedIterator<Item = Candle> + ExactSizeIterator + '_ {
+        let lo = self.timestamps.partition_point(|&t| t < start);
+        let hi = self.timestamps.partition_point(|&t| t < end).max(lo);
+        self.candles(lo..hi)
+    }
 }
 
 /// Windowed view into OhlcvTimeSeries for CVA generation.
@@ -226,9 +308,18 @@ impl OhlcvTimeSeries {
 pub(crate) struct TimeSeriesSlice<'a> {
     pub series_data: &'a OhlcvTimeSeries,
     pub ranges: Vec<(usize, usize)>,
+    /// Flagged candles are left out of the scores (they still advance time decay)
+    pub anomalies: Option<&'a AnomalyMask>,
 }
 
 impl TimeSeriesSlice<'_> {
+    /// Every candle in the slice's ranges, with its index in the underlying series.
+    pub(crate) fn indexed_candles(&self) -> impl Iterator<Item = (usize, Candle)> + '_ {
+        self.ranges
+            .iter()
+            .flat_map(|&(start, end)| self.series_data.indexed_candles(start..end))
+    }
+
     pub(crate) fn generate_cva_results(
         &self,
         n_chunks: usize,
@@ -239,16 +330,14 @@ impl TimeSeriesSlice<'_> {
         let (min_price, max_price) = price_range;
         let total_candles: usize = self.ranges.iter().map(|(start, end)| end - start).sum();
 
-        let mut volatility_sum = 0.0;
-        for (start, end) in &self.ranges {
-            for i in *start..*end {
-                let candle = self.series_data.get_candle(i);
-                if candle.close_price.is_positive() {
-                    volatility_sum += (candle.high_price.value() - candle.low_price.value())
-                        / candle.close_price.value();
-                }
-            }
-        }
+        let volatility_sum: f64 = self
+            .indexed_candles()
+            .map(|(_, candle)| candle)
+            .filter(|candle| candle.close_price.is_positive())
+            .map(|candle| {
+                (candle.high_price.value() - candle.low_price.value()) / candle.close_price.value()
+            })
+            .sum();
 
         let volatility_pct = if total_candles > 0 {
             volatility_sum / total_candles as f64
@@ -268,27 +357,25 @@ impl TimeSeriesSlice<'_> {
             VolatilityPct::new(volatility_pct),
         );
 
-        let mut position = 0;
         crate::trace_time!("CVA Math Loop", 8000, {
-            for (start_idx, end_idx) in &self.ranges {
-                for idx in *start_idx..*end_idx {
-                    let candle = self.series_data.get_candle(idx);
-
-                    let progress = if total_candles > 1 {
-                        position as f64 / (total_candles - 1) as f64
-                    } else {
-                        1.0
-                    };
-
-                    let decay_base = if time_decay_factor < 0.01 {
-                        0.01
-                    } else {
-                        time_decay_factor
-                    };
-                    let temporal_weight = decay_base.powf(progress);
-                    self.process_candle_scores(&mut cva_core, &candle, temporal_weight);
-                    position += 1;
+            for (position, (idx, candle)) in self.indexed_candles().enumerate() {
+                if self.anomalies.is_some_and(|m| m.get(idx).is_some()) {
+                    continue;
                 }
+
+                let progress = if total_candles > 1 {
+                    position as f64 / (total_candles - 1) as f64
+                } else {
+                    1.0
+                };
+
+                let decay_base = if time_decay_factor < 0.01 {
+                    0.01
+                } else {
+                    time_decay_factor
+                };
+                let temporal_weight = decay_base.powf(progress);
+                self.process_candle_scores(&mut cva_core, &candle, temporal_weight);
             }
         });
 
@@ -303,15 +390,61 @@ impl TimeSeriesSlice<'_> {
 
         let candle_low = clamp(Price::from(candle.low_price));
         let candle_high = clamp(Price::from(candle.high_price));
+        let weighted_volume = candle.base_asset_volume.value() * temporal_weight;
         cva_core.distribute_conserved_volume(
             ScoreType::FullCandleTVW,
             candle_low,
             candle_high,
-            candle.base_asset_volume.value() * temporal_weight,
+            weighted_volume,
+        );
+        cva_core.distribute_conserved_volume(
+            ScoreType::TakerDeltaTVW,
+            candle_low,
+            candle_high,
+            candle.taker_delta() * temporal_weight,
         );
 
+        cva_core.apply_point_score(
+            ScoreType::CloseOnlyTVW,
+            clamp(Price::from(candle.close_price)),
+            weighted_volume,
+        );
+
+        let body_low = clamp(Price::from(candle.low_wick_high()));
+        let body_high = clamp(Price::from(candle.high_wick_low()));
+        if body_low == body_high {
+            // Doji: the body collapses onto a single price
+            cva_core.apply_point_score(ScoreType::BodyOnlyTVW, body_low, weighted_volume);
+        } else {
+            cva_core.distribute_conserved_volume(
+                ScoreType::BodyOnlyTVW,
+                body_low,
+                body_high,
+                weighted_volume,
+            );
+        }
+
         let low_wick_start = clamp(Price::from(candle.low_wick_low()));
         let low_wick_end = clamp(Price::from(candle.low_wick_high()));
+        let high_wick_start = clamp(Price::from(candle.high_wick_low()));
+        let high_wick_end = clamp(Price::from(candle.high_wick_high()));
+
+        // Wick-only keeps the share of volume a uniform spread would have placed on each wick
+        let full_range = candle_high - candle_low;
+        if full_range > f64::EPSILON {
+            for (start, end) in [
+                (low_wick_start, low_wick_end),
+                (high_wick_start, high_wick_end),
+            ] {
+                cva_core.distribute_conserved_volume(
+                    ScoreType::WickOnlyTVW,
+                    start,
+                    end,
+                    weighted_volume * (end - start) / full_range,
+                );
+            }
+        }
+
         cva_core.apply_rejection_impact(
             ScoreType::LowWickCount,
             low_wick_start,
@@ -319,8 +452,6 @@ impl TimeSeriesSlice<'_> {
             temporal_weight,
         );
 
-        let high_wick_start = clamp(Price::from(candle.high_wick_low()));
-        let high_wick_end = clamp(Price::from(candle.high_wick_high()));
         cva_core.apply_rejection_impact(
             ScoreType::HighWickCount,
             high_wick_start,
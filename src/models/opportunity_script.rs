@@ -0,0 +1,308 @@
+use {
+    crate::{
+        app::Price,
+        domain::PairCategory,
+        models::{
+            MarketState, TradeDirection, TradeOpportunity,
+            script::{BinOp, Expr, Func, Kind, ScriptField, cmp_text, function_names, parse},
+        },
+    },
+    serde::{Deserialize, Serialize},
+};
+
+/// Names usable in filter and score expressions. Percentages read as shown in the Trade
+/// Finder (`aroi > 200` = above +200%).
+const FIELDS: [(&str, Field); 19] = [
+    ("pair", Field::Pair),
+    ("category", Field::Category),
+    ("direction", Field::Direction),
+    ("grade", Field::Grade),
+    ("roi", Field::Roi),
+    ("aroi", Field::Aroi),
+    ("expected_roi", Field::ExpectedRoi),
+    ("success_rate", Field::SuccessRate),
+    ("rr", Field::RiskReward),
+    ("expectancy", Field::Expectancy),
+    ("sample_size", Field::SampleSize),
+    ("duration_h", Field::DurationHours),
+    ("variants", Field::Variants),
+    ("score", Field::Score),
+    ("volume", Field::Volume),
+    ("volatility", Field::Volatility),
+    ("momentum", Field::Momentum),
+    ("path_density", Field::PathDensity),
+    ("custom", Field::Custom),
+];
+
+/// Everything an expression can read about one opportunity.
+pub(crate) struct ScriptFields<'a> {
+    pub op: &'a TradeOpportunity,
+    pub current_price: Price,
+    /// 24h volume in dollars (None when the quote asset can't be priced)
+    pub volume_usd: Option<f64>,
+    pub market_state: Option<MarketState>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Pair,
+    Category,
+    Direction,
+    Grade,
+    Roi,
+    Aroi,
+    ExpectedRoi,
+    SuccessRate,
+    RiskReward,
+    Expectancy,
+    SampleSize,
+    DurationHours,
+    Variants,
+    Score,
+    Volume,
+    Volatility,
+    Momentum,
+    PathDensity,
+    Custom,
+}
+
+impl ScriptField for Field {
+    fn kind(self) -> Kind {
+        match self {
+            Self::Pair | Self::Category | Self::Direction | Self::Grade => Kind::Str,
+            Self::Custom => Kind::Bool,
+            _ => Kind::Num,
+        }
+    }
+}
+
+impl Field {
+    /// Missing numbers are NaN, so every comparison against them fails.
+    fn value<'a>(self, f: &ScriptFields<'a>) -> Value<'a> {
+        let op = f.op;
+        let sim = &op.simulation;
+        match self {
+            Self::Pair => Value::Str(&op.pair_name),
+            Self::Category => Value::Str(PairCategory::of_pair(&op.pair_name).label()),
+            Self::Direction => Value::Str(match op.direction {
+                TradeDirection::Long => "long",
+                TradeDirection::Short => "short",
+            }),
+            Self::Grade => Value::Str(match op.grade() {
+                'A' => "A",
+                'B' => "B",
+                'C' => "C",
+                _ => "D",
+            }),
+            Self::Roi => Value::Num(op.live_roi(f.current_price).value() * 100.0),
+            Self::Aroi => Value::Num(op.live_annualized_roi(f.current_price).value() * 100.0),
+            Self::ExpectedRoi => Value::Num(op.expected_roi().value() * 100.0),
+            Self::SuccessRate => Value::Num(sim.success_rate.value() * 100.0),
+            Self::RiskReward => Value::Num(sim.risk_reward_ratio),
+            Self::Expectancy => Value::Num(sim.expectancy_r()),
+            Self::SampleSize => Value::Num(sim.sample_size as f64),
+            Self::DurationHours => Value::Num(op.avg_duration.to_hours()),
+            Self::Variants => Value::Num(op.variant_count() as f64),
+            Self::Score => Value::Num(op.calc_quality_score()),
+            Self::Volume => Value::Num(f.volume_usd.unwrap_or(f64::NAN)),
+            Self::Volatility => Value::Num(
+                f.market_state
+                    .map_or(f64::NAN, |m| m.volatility_pct.value() * 100.0),
+            ),
+            Self::Momentum => Value::Num(
+                f.market_state
+                    .map_or(f64::NAN, |m| m.momentum_pct.value() * 100.0),
+            ),
+            Self::PathDensity => Value::Num(op.path_density.unwrap_or(f64::NAN)),
+            Self::Custom => Value::Bool(op.custom_zone.is_some()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Value<'a> {
+    Num(f64),
+    Str(&'a str),
+    Bool(bool),
+}
+
+impl Value<'_> {
+    // Kinds are checked at parse time; the fallbacks are unreachable for parsed expressions.
+    fn as_bool(self) -> bool {
+        matches!(self, Self::Bool(true))
+    }
+
+    fn as_num(self) -> f64 {
+        match self {
+            Self::Num(n) => n,
+            _ => f64::NAN,
+        }
+    }
+}
+
+fn eval<'a>(expr: &'a Expr<Field>, f: &ScriptFields<'a>) -> Value<'a> {
+    match expr {
+        Expr::Num(n) => Value::Num(*n),
+        Expr::Str(s) => Value::Str(s),
+        Expr::Bool(b) => Value::Bool(*b),
+        Expr::Field(field) => field.value(f),
+        Expr::Not(e) => Value::Bool(!eval(e, f).as_bool()),
+        Expr::Neg(e) => Value::Num(-eval(e, f).as_num()),
+        Expr::Binary(BinOp::Or, l, r) => Value::Bool(eval(l, f).as_bool() || eval(r, f).as_bool()),
+        Expr::Binary(BinOp::And, l, r) => Value::Bool(eval(l, f).as_bool() && eval(r, f).as_bool()),
+        Expr::Binary(op, l, r) => match (eval(l, f), eval(r, f)) {
+            (Value::Num(a), Value::Num(b)) => match op {
+                BinOp::Add | BinOp::Sub | BinOp::Mul | BinOp::Div => {
+                    Value::Num(op.arithmetic(a, b))
+                }
+                _ => Value::Bool(op.holds(a.partial_cmp(&b))),
+            },
+            (Value::Str(a), Value::Str(b)) => Value::Bool(op.holds(Some(cmp_text(a, b)))),
+            (Value::Bool(a), Value::Bool(b)) => Value::Bool(op.holds(Some(a.cmp(&b)))),
+            _ => Value::Bool(op.holds(None)),
+        },
+        Expr::Call(func, args) => {
+            let arg = |i: usize| args.get(i).map_or(f64::NAN, |a| eval(a, f).as_num());
+            Value::Num(match func {
+                Func::Abs => arg(0).abs(),
+                Func::Min => arg(0).min(arg(1)),
+                Func::Max => arg(0).max(arg(1)),
+                _ => f64::NAN,
+            })
+        }
+        // Rejected by the parser outside indicators
+        Expr::Window(..) => Value::Num(f64::NAN),
+    }
+}
+
+/// A checked filter or score expression over opportunity fields.
+#[derive(Debug, Clone)]
+pub(crate) struct ScriptExpr(Expr<Field>);
+
+impl ScriptExpr {
+    /// Parses an expression that must come out true/false.
+    pub(crate) fn parse_filter(src: &str) -> Result<Self, String> {
+        parse(src, &FIELDS, false, Kind::Bool).map(Self)
+    }
+
+    /// Parses an expression that must come out a number.
+    pub(crate) fn parse_score(src: &str) -> Result<Self, String> {
+        parse(src, &FIELDS, false, Kind::Num).map(Self)
+    }
+
+    pub(crate) fn matches(&self, fields: &ScriptFields) -> bool {
+        eval(&self.0, fields).as_bool()
+    }
+
+    /// None when the result isn't a finite number (missing field, division by zero).
+    pub(crate) fn score(&self, fields: &ScriptFields) -> Option<f64> {
+        Some(eval(&self.0, fields).as_num()).filter(|n| n.is_finite())
+    }
+}
+
+/// Compiled forms of the sources they were built from (None = empty source).
+#[derive(Debug, Clone, Default)]
+struct CompiledScript {
+    filter_src: String,
+    score_src: String,
+    filter: Option<Result<ScriptExpr, String>>,
+    score: Option<Result<ScriptExpr, String>>,
+}
+
+fn compile(
+    src: &str,
+    parse: fn(&str) -> Result<ScriptExpr, String>,
+) -> Option<Result<ScriptExpr, String>> {
+    (!src.trim().is_empty()).then(|| parse(src))
+}
+
+/// User-written opportunity filter and score, editable from the Trade Finder. The filter
+/// hides rows and drives opportunity alerts; the score is a Trade Finder sort column.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct OpportunityScript {
+    pub filter: String,
+    pub score: String,
+    /// Hide Trade Finder rows the filter rejects
+    pub apply_filter: bool,
+    /// Alert when an opportunity starts matching the filter
+    pub alert: bool,
+    pub show_editor: bool,
+    #[serde(skip)]
+    compiled: CompiledScript,
+}
+
+impl Default for OpportunityScript {
+    fn default() -> Self {
+        Self {
+            filter: String::new(),
+            score: String::new(),
+            apply_filter: true,
+            alert: false,
+            show_editor: false,
+            compiled: CompiledScript::default(),
+        }
+    }
+}
+
+impl OpportunityScript {
+    /// Recompiles whichever source changed since the last call.
+    pub(crate) fn refresh(&mut self) {
+        let c = &mut self.compiled;
+        if c.filter_src != self.filter {
+            c.filter_src = self.filter.clone();
+            c.filter = compile(&self.filter, ScriptExpr::parse_filter);
+        }
+        if c.score_src != self.score {
+            c.score_src = self.score.clone();
+            c.score = compile(&self.score, ScriptExpr::parse_score);
+        }
+    }
+
+    /// The filter, if it is set and parses.
+    pub(crate) fn filter(&self) -> Option<&ScriptExpr> {
+        self.compiled.filter.as_ref().and_then(|r| r.as_ref().ok())
+    }
+
+    pub(crate) fn filter_error(&self) -> Option<&str> {
+        self.compiled
+            .filter
+            .as_ref()
+            .and_then(|r| r.as_ref().err())
+            .map(String::as_str)
+    }
+
+    pub(crate) fn score_expr(&self) -> Option<&ScriptExpr> {
+        self.compiled.score.as_ref().and_then(|r| r.as_ref().ok())
+    }
+
+    pub(crate) fn score_error(&self) -> Option<&str> {
+        self.compiled
+            .score
+            .as_ref()
+            .and_then(|r| r.as_ref().err())
+            .map(String::as_str)
+    }
+
+    /// Whether the Trade Finder is currently hiding rows with the filter.
+    pub(crate) fn is_filtering(&self) -> bool {
+        self.apply_filter && self.filter().is_some()
+    }
+
+    /// False only for rows a valid, applied filter rejects.
+    pub(crate) fn passes(&self, fields: &ScriptFields) -> bool {
+        !self.apply_filter || self.filter().is_none_or(|f| f.matches(fields))
+    }
+
+    pub(crate) fn score_of(&self, fields: &ScriptFields) -> Option<f64> {
+        self.score_expr().and_then(|s| s.score(fields))
+    }
+}
+
+/// Field and function names for the editor's help text.
+pub(crate) fn script_field_names() -> impl Iterator<Item = &'static str> {
+    FIELDS
+        .iter()
+        .map(|(name, _)| *name)
+        .chain(function_names(false))
+}
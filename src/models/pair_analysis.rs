@@ -1,12 +1,14 @@
 const ZONE_COUNT: usize = 256;
-const TIME_DECAY_FACTOR: f64 = 1.5;
 
 use {
     crate::{
         app::{BASE_INTERVAL, PhPct, Price},
         data::TimeSeriesCollection,
         domain::auto_select_ranges,
-        models::{CVACore, MIN_CANDLES_FOR_ANALYSIS, TimeSeriesSlice, find_matching_ohlcv},
+        models::{
+            AdaptiveCurves, AnomalyMask, CVACore, MIN_CANDLES_FOR_ANALYSIS, TimeSeriesSlice,
+            find_matching_ohlcv,
+        },
     },
     anyhow::{Context, Result, bail},
 };
@@ -18,6 +20,8 @@ pub(crate) fn pair_analysis_pure(
     timeseries_data: &TimeSeriesCollection,
     current_price: Price,
     ph_pct: PhPct,
+    adaptive: &AdaptiveCurves,
+    anomalies: Option<&AnomalyMask>,
 ) -> Result<CVACore> {
     let ohlcv_time_series = find_matching_ohlcv(
         &timeseries_data.series_data,
@@ -42,7 +46,8 @@ pub(crate) fn pair_analysis_pure(
     }
 
     // Calculate time-based decay factor using real timestamps (handles discontinuous ranges)
-    let dynamic_decay_factor = if (TIME_DECAY_FACTOR - 1.0).abs() < f64::EPSILON {
+    let time_decay_factor = adaptive.time_decay_factor(ph_pct);
+    let dynamic_decay_factor = if (time_decay_factor - 1.0).abs() < f64::EPSILON {
         1.0
     } else {
         let start_idx = slice_ranges.first().map(|r| r.0).unwrap_or(0);
@@ -53,11 +58,10 @@ pub(crate) fn pair_analysis_pure(
 
         let start_ts = ohlcv_time_series.get_candle(actual_start_idx).timestamp_ms;
         let end_ts = ohlcv_time_series.get_candle(actual_end_idx).timestamp_ms;
-        let duration_ms = end_ts.saturating_sub(start_ts);
-        let duration_years = duration_ms as f64 / 31_536_000_000.0;
+        let duration_years = (end_ts - start_ts).to_years();
 
         if duration_years > 0.0 {
-            TIME_DECAY_FACTOR.powf(duration_years).max(1.0)
+            time_decay_factor.powf(duration_years).max(1.0)
         } else {
             1.0
         }
@@ -66,6 +70,7 @@ pub(crate) fn pair_analysis_pure(
     let timeseries_slice = TimeSeriesSlice {
         series_data: ohlcv_time_series,
         ranges: slice_ranges.clone(),
+        anomalies,
     };
 
     let mut cva_results = timeseries_slice.generate_cva_results(
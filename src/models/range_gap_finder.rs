@@ -1,9 +1,15 @@
 use crate::{
-    app::{HighPrice, LowPrice, Price},
-    models::OhlcvTimeSeries,
+    app::{
+        BaseVol, DurationMs, HighPrice, LowPrice, Price, PriceLike, QuoteVol, TimestampMs,
+        VolatilityPct,
+    },
+    models::{ClassifiedZones, DiffZoneKind, OhlcvTimeSeries, SuperZone},
     utils::TimeUtils,
 };
 
+/// Zones listed per segment in the candle range panel's stats popover.
+const DOMINANT_ZONE_COUNT: usize = 3;
+
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) enum GapReason {
     None,
@@ -19,8 +25,8 @@ pub(crate) enum GapReason {
 pub(crate) struct DisplaySegment {
     pub start_idx: usize,
     pub end_idx: usize,
-    pub start_ts: i64,
-    pub end_ts: i64,
+    pub start_ts: TimestampMs,
+    pub end_ts: TimestampMs,
     pub candle_count: usize,
     pub low_price: LowPrice,
     pub high_price: HighPrice,
@@ -38,19 +44,19 @@ impl RangeGapFinder {
         timeseries: &OhlcvTimeSeries,
         ph_ranges: &[(usize, usize)],
         price_bounds: (Price, Price),
-        merge_tolerance_ms: i64,
+        merge_tolerance: DurationMs,
     ) -> Vec<DisplaySegment> {
         if ph_ranges.is_empty() || timeseries.timestamps.is_empty() {
             return Vec::new();
         }
 
         let interval_ms = timeseries.pair_interval.interval_ms;
-        let source_gap_tolerance = (interval_ms as f64 * 1.1) as i64;
+        let source_gap_tolerance = DurationMs::new((interval_ms as f64 * 1.1) as i64);
 
         // PASS 1: Generate raw segments
         let mut raw_segments = Vec::new();
         let mut prev_segment_end_idx = 0;
-        let mut prev_segment_end_ts = 0;
+        let mut prev_segment_end_ts = TimestampMs::default();
         let mut first_segment = true;
 
         for &(range_start, range_end) in ph_ranges {
@@ -117,7 +123,7 @@ impl RangeGapFinder {
             let gap_duration = next.start_ts - current.end_ts;
             let is_source_hole = matches!(next.gap_reason, GapReason::MissingSourceData);
 
-            if !is_source_hole && gap_duration <= merge_tolerance_ms {
+            if !is_source_hole && gap_duration <= merge_tolerance {
                 // Merge: price excursion was short enough to ignore
                 let skipped_count = next.start_idx.saturating_sub(current.end_idx);
 
@@ -159,7 +165,7 @@ impl RangeGapFinder {
         start: usize,
         end: usize,
         prev_end_idx: usize,
-        prev_end_ts: i64,
+        prev_end_ts: TimestampMs,
         is_first: bool,
         bounds: (Price, Price),
     ) -> DisplaySegment {
@@ -189,7 +195,7 @@ impl RangeGapFinder {
                 GapReason::PriceMixed
             };
 
-            (reason, TimeUtils::format_duration(time_gap))
+            (reason, TimeUtils::format_duration(time_gap.value()))
         };
 
         let mut seg_low = ts.low_prices[start];
@@ -219,3 +225,111 @@ impl RangeGapFinder {
         }
     }
 }
+
+/// One of the current model's zones and how much of a segment's quote volume traded in it.
+#[derive(Debug, Clone)]
+pub(crate) struct SegmentZoneShare {
+    pub kind: DiffZoneKind,
+    pub zone: SuperZone,
+    /// Share of the segment's quote volume (0..1)
+    pub volume_share: f64,
+}
+
+/// Statistics for one historical segment, computed on demand from the candles it covers.
+#[derive(Debug, Clone)]
+pub(crate) struct SegmentStats {
+    pub candle_count: usize,
+    pub high: HighPrice,
+    pub low: LowPrice,
+    /// Last close vs first open
+    pub change_pct: f64,
+    pub volatility_pct: VolatilityPct,
+    pub base_volume: BaseVol,
+    pub quote_volume: QuoteVol,
+    /// Taker buys as a share of base volume (0..1)
+    pub taker_buy_share: f64,
+    /// Zones that saw the most volume during the segment, largest share first. A candle's
+    /// volume counts towards a zone when its typical price ((H+L+C)/3) falls inside.
+    pub dominant_zones: Vec<SegmentZoneShare>,
+}
+
+impl SegmentStats {
+    pub(crate) fn compute(
+        ohlcv: &OhlcvTimeSeries,
+        segment: &DisplaySegment,
+        zones: &ClassifiedZones,
+    ) -> Option<Self> {
+        let (start, end) = (segment.start_idx, segment.end_idx.min(ohlcv.klines()));
+        if start >= end {
+            return None;
+        }
+        let range = start..end;
+        let high = range
+            .clone()
+            .map(|i| ohlcv.high_prices[i].value())
+            .fold(f64::MIN, f64::max);
+        let low = range
+            .clone()
+            .map(|i| ohlcv.low_prices[i].value())
+            .fold(f64::MAX, f64::min);
+        let base_volume: f64 = range
+            .clone()
+            .map(|i| ohlcv.base_asset_volumes[i].value())
+            .sum();
+        let quote_volume: f64 = range
+            .clone()
+            .map(|i| ohlcv.quote_asset_volumes[i].value())
+            .sum();
+        let taker_buy: f64 = range
+            .clone()
+            .map(|i| ohlcv.taker_buy_volumes[i].value())
+            .sum();
+        let first_open = ohlcv.open_prices[start].value();
+        let last_close = ohlcv.close_prices[end - 1].value();
+
+        let typical: Vec<(Price, f64)> = range
+            .map(|i| {
+                let tp = (ohlcv.high_prices[i].value()
+                    + ohlcv.low_prices[i].value()
+                    + ohlcv.close_prices[i].value())
+                    / 3.0;
+                (Price::new(tp), ohlcv.quote_asset_volumes[i].value())
+            })
+            .collect();
+        let mut dominant_zones: Vec<SegmentZoneShare> = [
+            (DiffZoneKind::Sticky, &zones.sticky_superzones),
+            (DiffZoneKind::Support, &zones.low_wicks_superzones),
+            (DiffZoneKind::Resistance, &zones.high_wicks_superzones),
+        ]
+        .into_iter()
+        .flat_map(|(kind, list)| list.iter().map(move |zone| (kind, zone)))
+        .map(|(kind, zone)| {
+            let in_zone: f64 = typical
+                .iter()
+                .filter(|(tp, _)| zone.contains(*tp))
+                .map(|(_, v)| v)
+                .sum();
+            SegmentZoneShare {
+                kind,
+                zone: zone.clone(),
+                volume_share: in_zone / quote_volume.max(f64::EPSILON),
+            }
+        })
+        .filter(|share| share.volume_share > 0.0)
+        .collect();
+        dominant_zones.sort_by(|a, b| b.volume_share.total_cmp(&a.volume_share));
+        dominant_zones.truncate(DOMINANT_ZONE_COUNT);
+
+        Some(Self {
+            candle_count: end - start,
+            high: HighPrice::new(high),
+            low: LowPrice::new(low),
+            change_pct: (last_close / first_open.max(f64::EPSILON) - 1.0) * 100.0,
+            volatility_pct: ohlcv.calc_volatility_in_range(start, end),
+            base_volume: BaseVol::new(base_volume),
+            quote_volume: QuoteVol::new(quote_volume),
+            taker_buy_share: taker_buy / base_volume.max(f64::EPSILON),
+            dominant_zones,
+        })
+    }
+}
@@ -10,8 +10,11 @@ pub(crate) const DEFAULT_SIMILARITY: SimilaritySettings = SimilaritySettings {
 
 use {
     crate::{
-        app::{Price, PriceLike, Prob, RoiPct, SimilaritySettings, StopPrice, TargetPrice, Weight},
-        models::{MarketState, OhlcvTimeSeries, TradeDirection},
+        app::{
+            Price, PriceLike, Prob, RoiPct, SimilaritySettings, StopManagement, StopPrice,
+            TargetPrice, TimestampMs, Weight,
+        },
+        models::{AnomalyMask, ApproachStats, MarketState, OhlcvTimeSeries, TradeDirection},
     },
     serde::{Deserialize, Serialize},
     std::cmp::Ordering,
@@ -257,7 +260,7 @@ fn generate_volatility_optimized(
     results
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub(crate) enum Outcome {
     TargetHit(usize), // Succeeded in N candles
     StopHit(usize),   // Failed in N candles
@@ -278,6 +281,195 @@ pub(crate) struct EmpiricalOutcomeStats {
     pub market_state: MarketState,
     /// Variance of replayed return distribution.
     pub return_variance: f64,
+    /// How long the journeys that reached target took (None when none did)
+    #[serde(default)]
+    pub hit_times: Option<HitTimes>,
+    /// Share of journeys a break-even stop closed at entry (zero with a fixed stop)
+    #[serde(default)]
+    pub break_even_rate: Prob,
+    /// Success rate had the stop been left in place, when the simulation managed it
+    #[serde(default)]
+    pub fixed_success_rate: Option<Prob>,
+    /// Tests of the strongest reversal zone on the way to target, from the side price
+    /// arrives at (None when the path is clear). Already priced into the success rates.
+    #[serde(default)]
+    pub barrier: Option<ApproachStats>,
+}
+
+impl EmpiricalOutcomeStats {
+    /// Records the barrier on the path and scales the success rates by `keep`, the share of
+    /// replayed wins still expected once that barrier's hold rate is taken into account.
+    pub(crate) fn discount_for_barrier(&mut self, stats: ApproachStats, keep: f64) {
+        self.barrier = Some(stats);
+        self.success_rate = Prob::new(self.success_rate.value() * keep);
+        self.fixed_success_rate = self.fixed_success_rate.map(|p| Prob::new(p.value() * keep));
+    }
+
+    /// Approximate 95% half-width on `success_rate`. Grows as `sample_size` shrinks.
+    pub(crate) fn success_rate_margin(&self) -> Prob {
+        if self.sample_size == 0 {
+            return Prob::new(1.0);
+        }
+        let p = self.success_rate.value();
+        let n = self.sample_size as f64;
+        // Floor p(1-p) so 0% / 100% on a handful of samples still shows uncertainty
+        let spread = (p * (1.0 - p)).max(1.0 / (4.0 * n));
+        Prob::new(1.96 * (spread / n).sqrt())
+    }
+
+    /// Average result per trade in units of risk (R): win `risk_reward_ratio` R with
+    /// probability `success_rate`, 0 R at a break-even exit, lose 1 R otherwise.
+    pub(crate) fn expectancy_r(&self) -> f64 {
+        let p = self.success_rate.value();
+        p * self.risk_reward_ratio - self.loss_rate()
+    }
+
+    /// [`Self::expectancy_r`] had the stop been left in place, when the simulation managed it.
+    pub(crate) fn fixed_expectancy_r(&self) -> Option<f64> {
+        self.fixed_success_rate
+            .map(|p| p.value() * self.risk_reward_ratio - (1.0 - p.value()))
+    }
+
+    /// Share of journeys that lost 1 R (neither won nor closed at entry).
+    fn loss_rate(&self) -> f64 {
+        (1.0 - self.success_rate.value() - self.break_even_rate.value()).max(0.0)
+    }
+
+    /// Kelly-optimal stake as a fraction of bankroll (0 = no edge, don't trade).
+    pub(crate) fn kelly_fraction(&self) -> f64 {
+        if self.risk_reward_ratio <= 0.0 {
+            return 0.0;
+        }
+        // Break-even exits neither win nor lose, so only wins and losses weigh in
+        let (p, q) = (self.success_rate.value(), self.loss_rate());
+        if p + q <= 0.0 {
+            return 0.0;
+        }
+        ((p * self.risk_reward_ratio - q) / (self.risk_reward_ratio * (p + q))).max(0.0)
+    }
+}
+
+/// Histogram slices of the time limit in [`HitTimes`].
+const HIT_TIME_BINS: usize = 12;
+
+/// Spread of candles-to-target across the journeys that hit, so "usually 6 hours, sometimes
+/// 5 days" reads differently from a plain average.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct HitTimes {
+    pub p25: f64,
+    pub median: f64,
+    pub p75: f64,
+    /// Hits per equal slice of the time limit, earliest first
+    pub histogram: Vec<u32>,
+}
+
+impl HitTimes {
+    /// None without any hits.
+    pub(crate) fn from_candles(
+        mut candles: Vec<usize>,
+        max_duration_candles: usize,
+    ) -> Option<Self> {
+        if candles.is_empty() {
+            return None;
+        }
+        candles.sort_unstable();
+        let mut histogram = vec![0; HIT_TIME_BINS];
+        let limit = max_duration_candles.max(1);
+        for &c in &candles {
+            histogram[(c * HIT_TIME_BINS / limit).min(HIT_TIME_BINS - 1)] += 1;
+        }
+        let sorted: Vec<f64> = candles.into_iter().map(|c| c as f64).collect();
+        Some(Self {
+            p25: quantile(&sorted, 0.25),
+            median: quantile(&sorted, 0.5),
+            p75: quantile(&sorted, 0.75),
+            histogram,
+        })
+    }
+
+    pub(crate) fn hits(&self) -> u32 {
+        self.histogram.iter().sum()
+    }
+}
+
+/// Linear-interpolated quantile of already sorted, non-empty `sorted`.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let pos = q * (sorted.len() - 1) as f64;
+    let (lo, hi) = (sorted[pos.floor() as usize], sorted[pos.ceil() as usize]);
+    lo + (hi - lo) * pos.fract()
+}
+
+/// Furthest a journey moved against (adverse) and for (favorable) the trade, as fractions of
+/// entry, until it reached target or ran out of time. The stop is ignored, so the adverse side
+/// reads as the heat a stop would have had to sit through.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Excursion {
+    pub adverse: f64,
+    pub favorable: f64,
+}
+
+/// MAE/MFE across an opportunity's matched journeys.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ExcursionStats {
+    pub adverse_median: f64,
+    /// Heat three in four journeys stayed within
+    pub adverse_p75: f64,
+    /// Typical heat on the way for journeys that reached target (None when none did)
+    pub winner_adverse_median: Option<f64>,
+    pub favorable_median: f64,
+    pub favorable_p75: f64,
+}
+
+impl ExcursionStats {
+    /// None without any journeys. `reached` marks the ones that hit target.
+    pub(crate) fn from_journeys(journeys: &[(Excursion, bool)]) -> Option<Self> {
+        if journeys.is_empty() {
+            return None;
+        }
+        let sorted = |pick: fn(&Excursion) -> f64, winners_only: bool| {
+            let mut xs: Vec<f64> = journeys
+                .iter()
+                .filter(|(_, reached)| *reached || !winners_only)
+                .map(|(e, _)| pick(e))
+                .collect();
+            xs.sort_by(f64::total_cmp);
+            xs
+        };
+        let adverse = sorted(|e| e.adverse, false);
+        let favorable = sorted(|e| e.favorable, false);
+        let winner_adverse = sorted(|e| e.adverse, true);
+        Some(Self {
+            adverse_median: quantile(&adverse, 0.5),
+            adverse_p75: quantile(&adverse, 0.75),
+            winner_adverse_median: (!winner_adverse.is_empty())
+                .then(|| quantile(&winner_adverse, 0.5)),
+            favorable_median: quantile(&favorable, 0.5),
+            favorable_p75: quantile(&favorable, 0.75),
+        })
+    }
+}
+
+/// A single matched historical analogue, replayed against a specific trade setup.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct JourneyReplay {
+    pub start_idx: usize,
+    pub start_ts: TimestampMs,
+    /// Distance to the live fingerprint (lower = closer match).
+    #[serde(alias = "similarity")]
+    pub distance: f64,
+    pub outcome: Outcome,
+    #[serde(default)]
+    pub excursion: Excursion,
+}
+
+impl JourneyReplay {
+    /// Candles elapsed until the journey resolved (timeouts run the full window).
+    pub(crate) fn candle_count(&self, max_duration_candles: usize) -> usize {
+        match self.outcome {
+            Outcome::TargetHit(n) | Outcome::StopHit(n) => n,
+            Outcome::TimedOut(_) => max_duration_candles,
+        }
+    }
 }
 
 pub(crate) struct ScenarioSimulator;
@@ -292,6 +484,7 @@ impl ScenarioSimulator {
         sample_count: usize,
         trend_lookback: usize,
         max_duration_candles: usize,
+        anomalies: Option<&AnomalyMask>,
     ) -> Option<(Vec<(usize, f64)>, MarketState)> {
         #[cfg(debug_assertions)]
         let t_start = AppInstant::now();
@@ -353,6 +546,11 @@ impl ScenarioSimulator {
             .iter()
             .zip(raw_scores.iter())
             .map(|(&idx, &score)| (idx, score as f64))
+            // A journey (or its lead-in) through a flash wick or outage isn't a real analogue
+            .filter(|&(idx, _)| {
+                anomalies
+                    .is_none_or(|m| !m.any_in(idx - trend_lookback, idx + max_duration_candles + 1))
+            })
             .collect();
 
         if candidates.len() > sample_count {
@@ -397,6 +595,7 @@ impl ScenarioSimulator {
         stop_price: StopPrice,
         max_duration_candles: usize, // Unit: Count
         direction: TradeDirection,
+        management: StopManagement,
     ) -> Option<EmpiricalOutcomeStats> {
         crate::trace_time!("Sim: Analyze Outcome (50 Matches)", 250, {
             if matches.is_empty() {
@@ -404,6 +603,9 @@ impl ScenarioSimulator {
             }
 
             let mut wins = 0;
+            let mut fixed_wins = 0;
+            let mut break_evens = 0;
+            let mut hit_candles = Vec::new();
             let mut accumulated_candle_count = 0.0;
             let mut valid_samples = 0;
             let mut total_pnl_pct = 0.0;
@@ -432,9 +634,34 @@ impl ScenarioSimulator {
                     direction,
                 );
 
+                if matches!(outcome, Outcome::TargetHit(_)) {
+                    fixed_wins += 1;
+                }
+                if management == StopManagement::BreakEvenAt1R {
+                    let until = match outcome {
+                        Outcome::TargetHit(n) | Outcome::StopHit(n) => n,
+                        Outcome::TimedOut(_) => max_duration_candles,
+                    };
+                    if let Some(candles_taken) = Self::break_even_exit(
+                        ts,
+                        start_idx,
+                        entry_price,
+                        stop_price,
+                        until,
+                        direction,
+                    ) {
+                        // Closed flat: no PnL to add
+                        break_evens += 1;
+                        accumulated_candle_count += candles_taken as f64;
+                        valid_samples += 1;
+                        continue;
+                    }
+                }
+
                 match outcome {
                     Outcome::TargetHit(candles_taken) => {
                         wins += 1;
+                        hit_candles.push(candles_taken);
                         accumulated_candle_count += candles_taken as f64;
                         valid_samples += 1;
                         let pnl = win_pnl_pct;
@@ -489,10 +716,145 @@ impl ScenarioSimulator {
                 avg_pnl_pct: RoiPct::new(avg_pnl_pct),
                 return_variance: variance, // NEW
                 market_state: current_market_state,
+                hit_times: HitTimes::from_candles(hit_candles, max_duration_candles),
+                break_even_rate: Prob::new(break_evens as f64 / valid_samples as f64),
+                fixed_success_rate: (management != StopManagement::Fixed)
+                    .then(|| Prob::new(fixed_wins as f64 / valid_samples as f64)),
+                barrier: None,
             })
         })
     }
 
+    /// Replays each match individually so callers can inspect per-journey outcomes (explainers, exports).
+    pub(crate) fn replay_journeys(
+        ts: &OhlcvTimeSeries,
+        matches: &[(usize, f64)],
+        entry_price: Price,
+        target_price: TargetPrice,
+        stop_price: StopPrice,
+        max_duration_candles: usize,
+        direction: TradeDirection,
+    ) -> Vec<JourneyReplay> {
+        matches
+            .iter()
+            .filter(|(idx, _)| *idx < ts.klines())
+            .map(|&(start_idx, distance)| JourneyReplay {
+                start_idx,
+                start_ts: ts.timestamps[start_idx],
+                distance,
+                outcome: Self::replay_path(
+                    ts,
+                    start_idx,
+                    entry_price,
+                    target_price,
+                    stop_price,
+                    max_duration_candles,
+                    direction,
+                ),
+                excursion: Self::replay_excursion(
+                    ts,
+                    start_idx,
+                    entry_price,
+                    target_price,
+                    max_duration_candles,
+                    direction,
+                )
+                .0,
+            })
+            .collect()
+    }
+
+    /// Candle at which a stop moved to entry after 1R closes the journey, scanning up to
+    /// `until` (where the fixed-stop replay resolved). The candle that reaches 1R only arms the
+    /// move, as there is no telling whether it came back to entry after or before.
+    fn break_even_exit(
+        ts: &OhlcvTimeSeries,
+        start_idx: usize,
+        current_price: Price,
+        stop: StopPrice,
+        until: usize,
+        direction: TradeDirection,
+    ) -> Option<usize> {
+        let hist_entry = ts.get_candle(start_idx).close_price.value();
+        let risk = (current_price.value() - stop.value()).abs() / current_price.value();
+        let mut armed = false;
+        for i in 1..=until {
+            let idx = start_idx + i;
+            if idx >= ts.klines() {
+                break;
+            }
+            let c = ts.get_candle(idx);
+            let low_change = (c.low_price.value() - hist_entry) / hist_entry;
+            let high_change = (c.high_price.value() - hist_entry) / hist_entry;
+            let (against, with) = match direction {
+                TradeDirection::Long => (-low_change, high_change),
+                TradeDirection::Short => (high_change, -low_change),
+            };
+            if armed && against >= 0.0 {
+                return Some(i);
+            }
+            armed |= with >= risk;
+        }
+        None
+    }
+
+    /// MAE/MFE over the matches, to target or the time limit (see [`Excursion`]).
+    pub(crate) fn estimate_excursions(
+        ts: &OhlcvTimeSeries,
+        matches: &[(usize, f64)],
+        entry_price: Price,
+        target_price: TargetPrice,
+        max_duration_candles: usize,
+        direction: TradeDirection,
+    ) -> Option<ExcursionStats> {
+        let journeys: Vec<_> = matches
+            .iter()
+            .filter(|(idx, _)| *idx < ts.klines())
+            .map(|&(start_idx, _)| {
+                Self::replay_excursion(
+                    ts,
+                    start_idx,
+                    entry_price,
+                    target_price,
+                    max_duration_candles,
+                    direction,
+                )
+            })
+            .collect();
+        ExcursionStats::from_journeys(&journeys)
+    }
+
+    /// Excursion of one journey, and whether it reached target. Both extremes of the candle
+    /// that reaches target count, in keeping with the pessimistic stop-first replay.
+    fn replay_excursion(
+        ts: &OhlcvTimeSeries,
+        start_idx: usize,
+        current_price: Price,
+        target: TargetPrice,
+        duration: usize,
+        direction: TradeDirection,
+    ) -> (Excursion, bool) {
+        let hist_entry = ts.get_candle(start_idx).close_price.value();
+        let target_move = (target.value() - current_price.value()).abs() / current_price.value();
+        let mut excursion = Excursion::default();
+        let end = (start_idx + duration).min(ts.klines().saturating_sub(1));
+        for idx in start_idx + 1..=end {
+            let c = ts.get_candle(idx);
+            let low_change = (c.low_price.value() - hist_entry) / hist_entry;
+            let high_change = (c.high_price.value() - hist_entry) / hist_entry;
+            let (against, with) = match direction {
+                TradeDirection::Long => (-low_change, high_change),
+                TradeDirection::Short => (high_change, -low_change),
+            };
+            excursion.adverse = excursion.adverse.max(against);
+            excursion.favorable = excursion.favorable.max(with);
+            if excursion.favorable >= target_move {
+                return (excursion, true);
+            }
+        }
+        (excursion, false)
+    }
+
     fn replay_path(
         ts: &OhlcvTimeSeries,
         start_idx: usize,
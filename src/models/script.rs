@@ -0,0 +1,505 @@
+use std::cmp::Ordering;
+
+/// Longest lookback a window function accepts, in bars.
+pub(crate) const MAX_WINDOW: usize = 1000;
+/// Nesting (brackets, calls, `!` / `-` chains) the recursive parser will follow.
+const MAX_DEPTH: usize = 64;
+/// Longer scripts are refused outright; operator chains become equally deep expressions.
+const MAX_TOKENS: usize = 2000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Kind {
+    Num,
+    Str,
+    Bool,
+}
+
+impl Kind {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Num => "a number",
+            Self::Str => "text",
+            Self::Bool => "true/false",
+        }
+    }
+}
+
+/// A name an expression can read; the caller's evaluator supplies its value.
+pub(crate) trait ScriptField: Copy {
+    fn kind(self) -> Kind;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BinOp {
+    Or,
+    And,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl BinOp {
+    fn symbol(self) -> &'static str {
+        match self {
+            Self::Or => "||",
+            Self::And => "&&",
+            Self::Eq => "==",
+            Self::Ne => "!=",
+            Self::Lt => "<",
+            Self::Le => "<=",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+            Self::Add => "+",
+            Self::Sub => "-",
+            Self::Mul => "*",
+            Self::Div => "/",
+        }
+    }
+
+    /// Result kind of `lhs op rhs`, or why the operand kinds don't fit.
+    fn check(self, lhs: Kind, rhs: Kind) -> Result<Kind, String> {
+        let fits = match self {
+            Self::Or | Self::And => lhs == Kind::Bool && rhs == Kind::Bool,
+            Self::Eq | Self::Ne => lhs == rhs,
+            Self::Lt | Self::Le | Self::Gt | Self::Ge => lhs == rhs && lhs != Kind::Bool,
+            Self::Add | Self::Sub | Self::Mul | Self::Div => lhs == Kind::Num && rhs == Kind::Num,
+        };
+        if !fits {
+            return Err(format!(
+                "'{}' can't combine {} with {}",
+                self.symbol(),
+                lhs.name(),
+                rhs.name()
+            ));
+        }
+        Ok(match self {
+            Self::Add | Self::Sub | Self::Mul | Self::Div => Kind::Num,
+            _ => Kind::Bool,
+        })
+    }
+
+    /// Whether a comparison holds for `ord` (None = unordered, e.g. NaN).
+    pub(crate) fn holds(self, ord: Option<Ordering>) -> bool {
+        match (self, ord) {
+            (Self::Ne, None) => true,
+            (_, None) => false,
+            (Self::Eq, Some(o)) => o == Ordering::Equal,
+            (Self::Ne, Some(o)) => o != Ordering::Equal,
+            (Self::Lt, Some(o)) => o == Ordering::Less,
+            (Self::Le, Some(o)) => o != Ordering::Greater,
+            (Self::Gt, Some(o)) => o == Ordering::Greater,
+            (Self::Ge, Some(o)) => o != Ordering::Less,
+            _ => false,
+        }
+    }
+
+    pub(crate) fn arithmetic(self, a: f64, b: f64) -> f64 {
+        match self {
+            Self::Add => a + b,
+            Self::Sub => a - b,
+            Self::Mul => a * b,
+            Self::Div => a / b,
+            _ => f64::NAN,
+        }
+    }
+}
+
+/// Case-insensitive text order, so `direction == "LONG"` matches `long`.
+pub(crate) fn cmp_text(a: &str, b: &str) -> Ordering {
+    a.bytes()
+        .map(|c| c.to_ascii_lowercase())
+        .cmp(b.bytes().map(|c| c.to_ascii_lowercase()))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Func {
+    Abs,
+    Min,
+    Max,
+    /// Window functions: `(series, bars)`, indicators only
+    Sma,
+    Ema,
+    Highest,
+    Lowest,
+    Stdev,
+    Prev,
+}
+
+const FUNCS: [(&str, Func); 9] = [
+    ("abs", Func::Abs),
+    ("min", Func::Min),
+    ("max", Func::Max),
+    ("sma", Func::Sma),
+    ("ema", Func::Ema),
+    ("highest", Func::Highest),
+    ("lowest", Func::Lowest),
+    ("stdev", Func::Stdev),
+    ("prev", Func::Prev),
+];
+
+impl Func {
+    fn arity(self) -> usize {
+        match self {
+            Self::Abs => 1,
+            _ => 2,
+        }
+    }
+
+    fn is_window(self) -> bool {
+        !matches!(self, Self::Abs | Self::Min | Self::Max)
+    }
+}
+
+/// Function names for editor help, window functions only when `series` is set.
+pub(crate) fn function_names(series: bool) -> impl Iterator<Item = &'static str> {
+    FUNCS
+        .iter()
+        .filter(move |(_, f)| series || !f.is_window())
+        .map(|(name, _)| *name)
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum Expr<F> {
+    Num(f64),
+    Str(String),
+    Bool(bool),
+    Field(F),
+    Not(Box<Self>),
+    Neg(Box<Self>),
+    Binary(BinOp, Box<Self>, Box<Self>),
+    /// `abs`, `min`, `max` over numbers
+    Call(Func, Vec<Self>),
+    /// Window function over the last `bars` values of a series
+    Window(Func, Box<Self>, usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Num(f64),
+    Str(String),
+    Ident(String),
+    Sym(&'static str),
+}
+
+const SYMBOLS: [&str; 16] = [
+    "&&", "||", "==", "!=", "<=", ">=", "<", ">", "!", "+", "-", "*", "/", "(", ")", ",",
+];
+
+/// Tokens with their byte offsets. `and`, `or`, `not` and a lone `=` are accepted as
+/// spellings of `&&`, `||`, `!` and `==`.
+fn tokenize(src: &str) -> Result<Vec<(Token, usize)>, String> {
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < src.len() {
+        let rest = &src[i..];
+        let c = rest.chars().next().unwrap_or(' ');
+        if c.is_whitespace() {
+            i += c.len_utf8();
+        } else if c.is_ascii_digit() || c == '.' {
+            let len = rest
+                .find(|ch: char| !(ch.is_ascii_digit() || ch == '.' || ch == '_'))
+                .unwrap_or(rest.len());
+            let text = rest[..len].replace('_', "");
+            let n = text
+                .parse::<f64>()
+                .map_err(|_| format!("'{}' at {} is not a number", &rest[..len], i))?;
+            tokens.push((Token::Num(n), i));
+            i += len;
+        } else if c == '"' || c == '\'' {
+            let Some(end) = rest[1..].find(c) else {
+                return Err(format!("Unclosed text starting at {}", i));
+            };
+            tokens.push((Token::Str(rest[1..=end].to_string()), i));
+            i += end + 2;
+        } else if c.is_alphabetic() || c == '_' {
+            let len = rest
+                .find(|ch: char| !(ch.is_alphanumeric() || ch == '_'))
+                .unwrap_or(rest.len());
+            let word = &rest[..len];
+            let token = match word.to_ascii_lowercase().as_str() {
+                "and" => Token::Sym("&&"),
+                "or" => Token::Sym("||"),
+                "not" => Token::Sym("!"),
+                _ => Token::Ident(word.to_string()),
+            };
+            tokens.push((token, i));
+            i += len;
+        } else if let Some(sym) = SYMBOLS.iter().find(|s| rest.starts_with(**s)) {
+            tokens.push((Token::Sym(sym), i));
+            i += sym.len();
+        } else if c == '=' {
+            tokens.push((Token::Sym("=="), i));
+            i += 1;
+        } else {
+            return Err(format!("Unexpected '{}' at {}", c, i));
+        }
+    }
+    Ok(tokens)
+}
+
+/// Recursive descent over `||` < `&&` < `!` < comparisons < `+ -` < `* /` < unary minus,
+/// checking operand kinds as it goes.
+struct Parser<F: 'static> {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    end: usize,
+    fields: &'static [(&'static str, F)],
+    /// Whether window functions are allowed (indicators)
+    series: bool,
+    depth: usize,
+}
+
+type Typed<F> = (Expr<F>, Kind);
+
+impl<F: ScriptField> Parser<F> {
+    fn peek_sym(&self) -> Option<&'static str> {
+        match self.tokens.get(self.pos) {
+            Some((Token::Sym(s), _)) => Some(s),
+            _ => None,
+        }
+    }
+
+    fn offset(&self) -> usize {
+        self.tokens.get(self.pos).map_or(self.end, |(_, at)| *at)
+    }
+
+    /// Runs `rule` one nesting level deeper, refusing past [`MAX_DEPTH`].
+    fn nested(
+        &mut self,
+        rule: fn(&mut Self) -> Result<Typed<F>, String>,
+    ) -> Result<Typed<F>, String> {
+        if self.depth >= MAX_DEPTH {
+            return Err(format!("Expression nests too deeply at {}", self.offset()));
+        }
+        self.depth += 1;
+        let result = rule(self);
+        self.depth -= 1;
+        result
+    }
+
+    fn expect_sym(&mut self, sym: &str, what: &str, at: usize) -> Result<(), String> {
+        if self.peek_sym() != Some(sym) {
+            return Err(format!("Expected '{}' {} at {}", sym, what, at));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn binary(
+        &mut self,
+        ops: &[(&str, BinOp)],
+        next: fn(&mut Self) -> Result<Typed<F>, String>,
+        chain: bool,
+    ) -> Result<Typed<F>, String> {
+        let (mut lhs, mut kind) = next(self)?;
+        while let Some(&(_, op)) = self
+            .peek_sym()
+            .and_then(|s| ops.iter().find(|(sym, _)| *sym == s))
+        {
+            let at = self.offset();
+            self.pos += 1;
+            let (rhs, rhs_kind) = next(self)?;
+            kind = op
+                .check(kind, rhs_kind)
+                .map_err(|e| format!("{} at {}", e, at))?;
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(rhs));
+            if !chain {
+                break;
+            }
+        }
+        Ok((lhs, kind))
+    }
+
+    fn or(&mut self) -> Result<Typed<F>, String> {
+        self.binary(&[("||", BinOp::Or)], Self::and, true)
+    }
+
+    fn and(&mut self) -> Result<Typed<F>, String> {
+        self.binary(&[("&&", BinOp::And)], Self::not, true)
+    }
+
+    fn not(&mut self) -> Result<Typed<F>, String> {
+        if self.peek_sym() == Some("!") {
+            let at = self.offset();
+            self.pos += 1;
+            let (e, kind) = self.nested(Self::not)?;
+            if kind != Kind::Bool {
+                return Err(format!(
+                    "'!' at {} needs true/false, got {}",
+                    at,
+                    kind.name()
+                ));
+            }
+            return Ok((Expr::Not(Box::new(e)), Kind::Bool));
+        }
+        self.comparison()
+    }
+
+    fn comparison(&mut self) -> Result<Typed<F>, String> {
+        self.binary(
+            &[
+                ("==", BinOp::Eq),
+                ("!=", BinOp::Ne),
+                ("<=", BinOp::Le),
+                (">=", BinOp::Ge),
+                ("<", BinOp::Lt),
+                (">", BinOp::Gt),
+            ],
+            Self::sum,
+            false,
+        )
+    }
+
+    fn sum(&mut self) -> Result<Typed<F>, String> {
+        self.binary(&[("+", BinOp::Add), ("-", BinOp::Sub)], Self::product, true)
+    }
+
+    fn product(&mut self) -> Result<Typed<F>, String> {
+        self.binary(&[("*", BinOp::Mul), ("/", BinOp::Div)], Self::unary, true)
+    }
+
+    fn unary(&mut self) -> Result<Typed<F>, String> {
+        if self.peek_sym() == Some("-") {
+            let at = self.offset();
+            self.pos += 1;
+            let (e, kind) = self.nested(Self::unary)?;
+            if kind != Kind::Num {
+                return Err(format!("'-' at {} needs a number, got {}", at, kind.name()));
+            }
+            return Ok((Expr::Neg(Box::new(e)), Kind::Num));
+        }
+        self.atom()
+    }
+
+    fn call(&mut self, name: &str, func: Func, at: usize) -> Result<Typed<F>, String> {
+        if func.is_window() && !self.series {
+            return Err(format!("'{}' at {} only works in indicators", name, at));
+        }
+        self.expect_sym("(", "after function name", self.offset())?;
+        let mut args = Vec::new();
+        loop {
+            let arg_at = self.offset();
+            let (arg, kind) = self.nested(Self::or)?;
+            if kind != Kind::Num {
+                return Err(format!(
+                    "'{}' at {} takes numbers, got {}",
+                    name,
+                    arg_at,
+                    kind.name()
+                ));
+            }
+            args.push(arg);
+            if self.peek_sym() != Some(",") {
+                break;
+            }
+            self.pos += 1;
+        }
+        self.expect_sym(")", "to close the call", self.offset())?;
+        if args.len() != func.arity() {
+            return Err(format!(
+                "'{}' at {} takes {} arguments, got {}",
+                name,
+                at,
+                func.arity(),
+                args.len()
+            ));
+        }
+        if !func.is_window() {
+            return Ok((Expr::Call(func, args), Kind::Num));
+        }
+        let bars = match args.pop() {
+            Some(Expr::Num(n)) if n.fract() == 0.0 && (1.0..=MAX_WINDOW as f64).contains(&n) => {
+                n as usize
+            }
+            _ => {
+                return Err(format!(
+                    "'{}' at {} needs a whole number of bars from 1 to {} as its second argument",
+                    name, at, MAX_WINDOW
+                ));
+            }
+        };
+        let series = Box::new(args.pop().unwrap_or(Expr::Num(f64::NAN)));
+        Ok((Expr::Window(func, series, bars), Kind::Num))
+    }
+
+    fn atom(&mut self) -> Result<Typed<F>, String> {
+        let at = self.offset();
+        let Some((token, _)) = self.tokens.get(self.pos).cloned() else {
+            return Err("Expression ends too early".to_string());
+        };
+        self.pos += 1;
+        match token {
+            Token::Num(n) => Ok((Expr::Num(n), Kind::Num)),
+            Token::Str(s) => Ok((Expr::Str(s), Kind::Str)),
+            Token::Ident(name) => {
+                let lower = name.to_ascii_lowercase();
+                if let Some(&(_, func)) = FUNCS.iter().find(|(n, _)| *n == lower) {
+                    return self.call(&lower, func, at);
+                }
+                match lower.as_str() {
+                    "true" => Ok((Expr::Bool(true), Kind::Bool)),
+                    "false" => Ok((Expr::Bool(false), Kind::Bool)),
+                    _ => self
+                        .fields
+                        .iter()
+                        .find(|(n, _)| *n == lower)
+                        .map(|&(_, field)| (Expr::Field(field), field.kind()))
+                        .ok_or_else(|| format!("Unknown field '{}' at {}", name, at)),
+                }
+            }
+            Token::Sym("(") => {
+                let inner = self.nested(Self::or)?;
+                self.expect_sym(")", &format!("for '(' at {}", at), self.offset())?;
+                Ok(inner)
+            }
+            Token::Sym(s) => Err(format!("Unexpected '{}' at {}", s, at)),
+        }
+    }
+}
+
+/// Parses `src` over `fields` into an expression of kind `want`: numbers, text, true/false,
+/// the caller's fields, arithmetic, comparisons, logic and a few functions. Kinds are
+/// checked here, so evaluation never fails. Window functions are only accepted when
+/// `series` is set.
+pub(crate) fn parse<F: ScriptField>(
+    src: &str,
+    fields: &'static [(&'static str, F)],
+    series: bool,
+    want: Kind,
+) -> Result<Expr<F>, String> {
+    let tokens = tokenize(src)?;
+    if tokens.len() > MAX_TOKENS {
+        return Err(format!(
+            "Expression is too long ({} tokens, at most {})",
+            tokens.len(),
+            MAX_TOKENS
+        ));
+    }
+    let mut parser = Parser {
+        tokens,
+        pos: 0,
+        end: src.len(),
+        fields,
+        series,
+        depth: 0,
+    };
+    let (expr, kind) = parser.or()?;
+    if parser.pos < parser.tokens.len() {
+        return Err(format!("Unexpected input at {}", parser.offset()));
+    }
+    if kind != want {
+        return Err(format!(
+            "Expression gives {}, expected {}",
+            kind.name(),
+            want.name()
+        ));
+    }
+    Ok(expr)
+}
@@ -3,10 +3,14 @@
 
 use crate::{
     app::{
-        AroiPct, DurationMs, HighPrice, JourneySettings, LowPrice, OptimalSearchSettings, Pct,
-        PhPct, RoiPct, TradeProfile, VolatilityPct,
+        AroiPct, DurationMs, EvidenceSettings, HighPrice, JourneySettings, LowPrice,
+        OptimalSearchSettings, Pct, PhPct, PriceLike, RoiPct, TradeProfile, VolatilityPct,
+    },
+    models::{
+        AdaptiveCurve, AdaptiveParameters, AnomalyMask, CVACore, CandleAnomaly, ClassifiedZones,
+        OhlcvTimeSeries, ScoreType, SuperZone, TradeDirection,
+        trading_model::{find_target_zones, inherit_zone_ids},
     },
-    models::{AdaptiveParameters, CVACore, ScoreType, trading_model::find_target_zones},
 };
 use std::time::Duration;
 
@@ -27,6 +31,58 @@ fn make_core(min: f64, max: f64, zones: usize) -> CVACore {
     )
 }
 
+/// Opportunity on `pair`: entry 100, target 110, stop 95 (2:1 reward / risk), 60% success
+/// over 40 samples. Tests override the fields they exercise.
+fn opportunity(pair: &str, direction: TradeDirection) -> crate::models::TradeOpportunity {
+    use crate::{
+        app::{MomentumPct, Price, Prob, StopPrice, TargetPrice, VolRatio},
+        engine::StationId,
+        models::{EmpiricalOutcomeStats, MarketState, OptimizationStrategy, TradeOpportunity},
+    };
+
+    let market_state = MarketState {
+        volatility_pct: VolatilityPct::new(0.01),
+        momentum_pct: MomentumPct::new(0.0),
+        relative_volume: VolRatio::new(1.0),
+    };
+    TradeOpportunity {
+        id: format!("{}-{}", pair, direction),
+        created_at: chrono::Utc::now(),
+        ph_pct: PhPct::new(0.05),
+        pair_name: pair.to_string(),
+        direction,
+        start_price: Price::new(100.0),
+        target_price: TargetPrice::new(110.0),
+        stop_price: StopPrice::new(95.0),
+        max_duration: DurationMs::new(3_600_000),
+        duration_vol_factor: 1.0,
+        avg_duration: DurationMs::new(1_800_000),
+        strategy: OptimizationStrategy::default(),
+        station_id: StationId::default(),
+        market_state,
+        visuals: None,
+        simulation: EmpiricalOutcomeStats {
+            success_rate: Prob::new(0.6),
+            avg_candle_count: 10.0,
+            risk_reward_ratio: 2.0,
+            sample_size: 40,
+            avg_pnl_pct: RoiPct::new(0.01),
+            market_state,
+            return_variance: 0.0,
+            hit_times: None,
+            break_even_rate: Prob::new(0.0),
+            fixed_success_rate: None,
+            barrier: None,
+        },
+        variants: Vec::new(),
+        ladder: Vec::new(),
+        custom_zone: None,
+        path_density: None,
+        excursions: None,
+        matches: Default::default(),
+    }
+}
+
 // ─── find_target_zones ───────────────────────────────────────────────────────
 
 #[test]
@@ -85,6 +141,30 @@ fn dcv_total_score_conserved_full_range() {
     assert!((sum - total).abs() < 1e-10, "sum={sum} expected={total}");
 }
 
+#[test]
+fn dcv_taker_delta_keeps_sign() {
+    let mut core = make_core(0.0, 100.0, 10);
+    core.distribute_conserved_volume(
+        ScoreType::TakerDeltaTVW,
+        crate::app::Price::new(0.0),
+        crate::app::Price::new(50.0),
+        -20.0,
+    );
+    let sum: f64 = core.taker_deltas_vw.iter().sum();
+    assert!((sum + 20.0).abs() < 1e-10, "sum={sum} expected=-20");
+    assert!(core.taker_deltas_vw[0] < 0.0);
+    assert_eq!(core.taker_deltas_vw[9], 0.0);
+}
+
+#[test]
+fn point_score_lands_in_single_zone() {
+    let mut core = make_core(0.0, 100.0, 10);
+    core.apply_point_score(ScoreType::CloseOnlyTVW, crate::app::Price::new(42.0), 5.0);
+    assert_eq!(core.closes_vw[4], 5.0);
+    let sum: f64 = core.closes_vw.iter().sum();
+    assert_eq!(sum, 5.0);
+}
+
 #[test]
 fn dcv_total_score_conserved_partial_range() {
     let zones = 10;
@@ -191,7 +271,7 @@ fn ari_score_not_diluted_unlike_conserved_volume() {
     assert!((vol_sum - score).abs() < 1e-10);
 }
 
-// ─── AdaptiveParameters::calc_trend_lookback_candles ─────────────────────────
+// ─── AdaptiveCurves::trend_lookback_candles (default curve) ─────────────────
 //
 // BASE_INTERVAL = 5 min → interval_ms = 300_000
 // day_candles  = 86400 / 300  =  288
@@ -225,7 +305,7 @@ fn expected_lookback(v: f64) -> usize {
 #[test]
 fn ctlc_scalp_lower_bound_gives_minimum_lookback() {
     // v = 0.005 → remap floor → should be 24 candles
-    let result = AdaptiveParameters::calc_trend_lookback_candles(PhPct::new(0.005));
+    let result = AdaptiveCurves::default().trend_lookback_candles(PhPct::new(0.005));
     assert_eq!(result, 24, "at v=0.005 expected 24, got {result}");
 }
 
@@ -233,31 +313,52 @@ fn ctlc_scalp_lower_bound_gives_minimum_lookback() {
 fn ctlc_scalp_upper_boundary_gives_day_candles() {
     // v just below 0.05 sits in the scalp segment; v = 0.05 crosses into swing
     // At exactly 0.05 the code uses the swing branch: remap(0.05, 0.05, 0.15, 288, 2016) = 288
-    let result = AdaptiveParameters::calc_trend_lookback_candles(PhPct::new(0.05));
+    let result = AdaptiveCurves::default().trend_lookback_candles(PhPct::new(0.05));
     assert_eq!(result, expected_lookback(0.05));
 }
 
 #[test]
 fn ctlc_swing_midpoint_is_between_day_and_week() {
     // v = 0.10 (midpoint of [0.05, 0.15]) → midpoint of [288, 2016] = 1152
-    let result = AdaptiveParameters::calc_trend_lookback_candles(PhPct::new(0.10));
+    let result = AdaptiveCurves::default().trend_lookback_candles(PhPct::new(0.10));
     assert_eq!(result, expected_lookback(0.10));
 }
 
 #[test]
 fn ctlc_macro_boundary_gives_week_candles() {
     // v = 0.15 enters the macro branch → remap(0.15, 0.15, 0.50, 2016, 8640) = 2016
-    let result = AdaptiveParameters::calc_trend_lookback_candles(PhPct::new(0.15));
+    let result = AdaptiveCurves::default().trend_lookback_candles(PhPct::new(0.15));
     assert_eq!(result, expected_lookback(0.15));
 }
 
 #[test]
 fn ctlc_macro_upper_bound_gives_month_candles() {
     // v = 0.50 → remap ceiling → 8640 candles
-    let result = AdaptiveParameters::calc_trend_lookback_candles(PhPct::new(0.50));
+    let result = AdaptiveCurves::default().trend_lookback_candles(PhPct::new(0.50));
     assert_eq!(result, 8640, "at v=0.50 expected 8640, got {result}");
 }
 
+#[test]
+fn adaptive_curve_normalize_sorts_and_interpolates() {
+    // Unsorted, duplicated breakpoints as a user might leave them in the editor
+    let mut curve = AdaptiveCurve {
+        points: vec![[0.20, 300.0], [0.10, 100.0], [0.10, 999.0], [0.0, 0.0]],
+    };
+    curve.normalize();
+    assert_eq!(curve.points.len(), 3);
+    assert!((curve.eval(0.05) - 50.0).abs() < 1e-9);
+    assert!((curve.eval(0.15) - 200.0).abs() < 1e-9);
+}
+
+#[test]
+fn adaptive_lookback_never_drops_below_one_candle() {
+    let mut curves = AdaptiveCurves::default();
+    curves.trend_lookback = AdaptiveCurve {
+        points: vec![[0.0, -50.0], [0.5, 10.0]],
+    };
+    assert_eq!(curves.trend_lookback_candles(PhPct::new(0.0)), 1);
+}
+
 // ─── AdaptiveParameters::calc_dynamic_journey_duration ───────────────────────
 //
 // Formula (before clamping): candles = (ratio + 3)^2,  ratio = ph_pct / vol_pct
@@ -275,6 +376,12 @@ fn unclamped_journey() -> JourneySettings {
         profile: TradeProfile {
             min_roi_pct: RoiPct::new(0.0),
             min_aroi_pct: AroiPct::new(0.0),
+            ..Default::default()
+        },
+        evidence: EvidenceSettings {
+            min_sample_size: 0,
+            low_evidence_sample_size: 0,
+            low_evidence_penalty: 1.0,
         },
         optimization: OptimalSearchSettings {
             volatility_lookback: 50,
@@ -381,8 +488,2196 @@ fn cdjd_clamp_enforces_minimum() {
     );
 }
 
+#[test]
+fn vol_duration_factor_shortens_fast_markets_and_clamps() {
+    let sample = VolatilityPct::new(0.01);
+    // Current vol twice the historical sample → half the time
+    let fast = AdaptiveParameters::vol_duration_factor(VolatilityPct::new(0.02), sample);
+    assert!((fast - 0.5).abs() < 1e-9, "got {fast}");
+    // Dead market: clamped rather than unbounded
+    let dead = AdaptiveParameters::vol_duration_factor(VolatilityPct::new(0.0001), sample);
+    assert!((dead - 2.0).abs() < 1e-9, "got {dead}");
+    // No sample volatility → no adjustment
+    let none = AdaptiveParameters::vol_duration_factor(sample, VolatilityPct::new(0.0));
+    assert!((none - 1.0).abs() < 1e-9, "got {none}");
+}
+
+// ─── EvidenceSettings::demote_score ──────────────────────────────────────────
+
+fn evidence() -> EvidenceSettings {
+    EvidenceSettings {
+        min_sample_size: 5,
+        low_evidence_sample_size: 20,
+        low_evidence_penalty: 0.5,
+    }
+}
+
+#[test]
+fn ev_thin_sample_positive_score_is_demoted() {
+    let ev = evidence();
+    assert!(ev.is_sufficient(10));
+    assert_eq!(ev.demote_score(2.0, 10), 1.0);
+}
+
+#[test]
+fn ev_negative_score_and_full_sample_untouched() {
+    let ev = evidence();
+    assert_eq!(
+        ev.demote_score(-2.0, 10),
+        -2.0,
+        "penalty must not flatter losers"
+    );
+    assert_eq!(ev.demote_score(2.0, 20), 2.0);
+    assert!(!ev.is_sufficient(4));
+}
+
 // #[test]
 // fn fail_please() {
 //     let condition = true;
 //     assert!(!condition, "The condition is true");
 // }
+
+// ─── inherit_zone_ids ────────────────────────────────────────────────────────
+
+fn superzone(id: u64, bottom: f64, top: f64) -> SuperZone {
+    SuperZone {
+        id,
+        price_bottom: crate::app::Price::new(bottom),
+        price_top: crate::app::Price::new(top),
+        price_center: crate::app::Price::new((bottom + top) / 2.0),
+        strength: Default::default(),
+    }
+}
+
+#[test]
+fn zone_ids_carry_over_on_substantial_overlap_only() {
+    let previous = vec![superzone(7, 100.0, 110.0), superzone(8, 200.0, 210.0)];
+    let mut current = vec![superzone(90, 101.0, 111.0), superzone(91, 208.0, 230.0)];
+    inherit_zone_ids(&mut current, &previous);
+    assert_eq!(current[0].id, 7);
+    assert_eq!(current[1].id, 91, "small overlap must not inherit");
+}
+
+#[test]
+fn zone_diff_matches_by_id_and_flags_moves() {
+    use crate::models::{DiffChange, DiffZoneKind, model_diff::diff_zones};
+
+    let previous = vec![superzone(1, 100.0, 110.0), superzone(2, 200.0, 210.0)];
+    let next = vec![
+        superzone(1, 100.0, 110.0),
+        superzone(2, 202.0, 212.0),
+        superzone(3, 300.0, 310.0),
+    ];
+    let changes = diff_zones(DiffZoneKind::Sticky, &previous, &next);
+    let change_of = |id| changes.iter().find(|c| c.id == id).map(|c| c.change);
+    assert_eq!(change_of(1), None, "unchanged zone must not be reported");
+    assert_eq!(change_of(2), Some(DiffChange::Changed));
+    assert_eq!(change_of(3), Some(DiffChange::Added));
+
+    let removed = diff_zones(DiffZoneKind::Sticky, &next, &previous);
+    assert!(
+        removed
+            .iter()
+            .any(|c| c.id == 3 && c.change == DiffChange::Removed && c.after.is_none())
+    );
+}
+
+#[test]
+fn strongest_barrier_only_considers_zones_between_price_and_target() {
+    let mut weak = superzone(1, 105.0, 106.0);
+    weak.strength.score = 0.2;
+    let mut strong = superzone(2, 108.0, 109.0);
+    strong.strength.score = 1.0;
+    let beyond = superzone(3, 120.0, 121.0);
+    let zones = ClassifiedZones {
+        high_wicks_superzones: vec![weak, strong, beyond],
+        low_wicks_superzones: vec![superzone(4, 90.0, 91.0)],
+        ..Default::default()
+    };
+    let price = crate::app::Price::new;
+    assert_eq!(
+        zones
+            .strongest_barrier(price(100.0), price(110.0))
+            .map(|z| z.id),
+        Some(2)
+    );
+    assert_eq!(
+        zones
+            .strongest_barrier(price(100.0), price(107.0))
+            .map(|z| z.id),
+        Some(1)
+    );
+    assert_eq!(
+        zones
+            .strongest_barrier(price(100.0), price(95.0))
+            .map(|z| z.id),
+        None
+    );
+}
+
+#[test]
+fn ladder_levels_take_nearest_zone_edges_in_trade_direction() {
+    let zones = ClassifiedZones {
+        sticky_superzones: vec![superzone(1, 104.0, 106.0), superzone(2, 94.0, 96.0)],
+        high_wicks_superzones: vec![superzone(3, 120.0, 121.0), superzone(4, 110.0, 111.0)],
+        low_wicks_superzones: vec![superzone(5, 80.0, 81.0)],
+    };
+    let price = crate::app::Price::new;
+    assert_eq!(
+        zones.ladder_levels(price(100.0), TradeDirection::Long, 2),
+        vec![price(104.0), price(110.0)]
+    );
+    assert_eq!(
+        zones.ladder_levels(price(100.0), TradeDirection::Short, 3),
+        vec![price(96.0), price(81.0)]
+    );
+}
+
+#[test]
+fn protective_stop_sits_beyond_far_edge_of_nearest_zone() {
+    let zones = ClassifiedZones {
+        sticky_superzones: vec![superzone(1, 97.0, 101.0)],
+        high_wicks_superzones: vec![superzone(2, 110.0, 112.0)],
+        low_wicks_superzones: vec![superzone(3, 90.0, 92.0)],
+    };
+    let price = crate::app::Price::new;
+    let stop = |dir| {
+        zones
+            .protective_stop(price(100.0), dir, Pct::new(0.01))
+            .map(|s| s.value())
+    };
+    // Entry inside the sticky zone: its bottom is the nearest far edge below
+    assert!((stop(TradeDirection::Long).unwrap() - 97.0 * 0.99).abs() < 1e-9);
+    assert!((stop(TradeDirection::Short).unwrap() - 101.0 * 1.01).abs() < 1e-9);
+}
+
+// ─── AnomalyMask ─────────────────────────────────────────────────────────────
+
+fn candle(i: i64, open: f64, high: f64, low: f64, close: f64, vol: f64) -> crate::domain::Candle {
+    use crate::app::{BaseVol, ClosePrice, OpenPrice, QuoteVol};
+    crate::domain::Candle::new(
+        i * 300_000,
+        OpenPrice::new(open),
+        HighPrice::new(high),
+        LowPrice::new(low),
+        ClosePrice::new(close),
+        BaseVol::new(vol),
+        QuoteVol::new(vol * close),
+        BaseVol::new(vol / 2.0),
+    )
+}
+
+#[test]
+fn anomaly_mask_flags_flash_wick_and_outage_candles() {
+    let mut candles: Vec<_> = (0..20)
+        .map(|i| candle(i, 100.0, 101.0, 99.0, 100.0, 10.0))
+        .collect();
+    candles.push(candle(20, 100.0, 101.0, 80.0, 100.0, 10.0)); // flash wick
+    candles.push(candle(21, 100.0, 100.0, 100.0, 100.0, 0.0)); // zero volume
+    for i in 22..25 {
+        candles.push(candle(i, 100.0, 100.0, 100.0, 100.0, 1.0)); // frozen
+    }
+    let pair = crate::domain::PairInterval {
+        name: "TESTUSDT".to_string(),
+        interval_ms: 300_000,
+    };
+    let mask = AnomalyMask::detect(&OhlcvTimeSeries::from_candles(pair, candles));
+
+    assert_eq!(mask.get(19), None);
+    assert_eq!(mask.get(20), Some(CandleAnomaly::FlashWick));
+    assert_eq!(mask.get(21), Some(CandleAnomaly::ZeroVolume));
+    assert_eq!(mask.get(24), Some(CandleAnomaly::FrozenPrice));
+    assert!(mask.any_in(15, 21));
+    assert!(!mask.any_in(0, 20));
+}
+
+// ─── HorizonProfile ──────────────────────────────────────────────────────────
+
+#[test]
+fn min_viable_ph_is_smallest_range_holding_enough_candles() {
+    use crate::{app::Price, domain::HorizonProfile};
+    // One candle per price step 100..=109, each ±0.1 wide
+    let candles: Vec<_> = (0..10)
+        .map(|i| {
+            let p = 100.0 + i as f64;
+            candle(i, p, p + 0.1, p - 0.1, p, 10.0)
+        })
+        .collect();
+    let pair = crate::domain::PairInterval {
+        name: "TESTUSDT".to_string(),
+        interval_ms: 300_000,
+    };
+    let ohlcv = OhlcvTimeSeries::from_candles(pair, candles);
+    let mut profile = HorizonProfile::default();
+    profile.sync(&ohlcv);
+    let price = Price::new(100.0);
+
+    // Five candles (100..=104) need the upper bound to reach 103.9
+    let ph = profile
+        .min_viable_ph(&ohlcv, price, 5, PhPct::new(0.5))
+        .unwrap();
+    assert!(ph.value() >= 0.039 && ph.value() < 0.0392, "{}", ph);
+    assert!(profile.candle_count(&ohlcv, price, ph) >= 5);
+    assert_eq!(
+        profile.min_viable_ph(&ohlcv, price, 11, PhPct::new(0.5)),
+        None
+    );
+}
+
+#[test]
+fn horizon_profile_rebuilds_when_the_series_is_replaced() {
+    use crate::{app::Price, domain::HorizonProfile};
+    let series = |first: i64, base: f64| {
+        let candles: Vec<_> = (first..first + 10)
+            .map(|i| {
+                let p = base + i as f64;
+                candle(i, p, p + 0.1, p - 0.1, p, 10.0)
+            })
+            .collect();
+        let pair = crate::domain::PairInterval {
+            name: "TESTUSDT".to_string(),
+            interval_ms: 300_000,
+        };
+        OhlcvTimeSeries::from_candles(pair, candles)
+    };
+    let mut profile = HorizonProfile::default();
+    profile.sync(&series(0, 100.0));
+
+    // Same length, different history: nothing of the old series may linger
+    let replaced = series(20, 200.0);
+    profile.sync(&replaced);
+    let price = Price::new(100.0);
+    assert_eq!(profile.candle_count(&replaced, price, PhPct::new(0.1)), 0);
+    assert_eq!(profile.candle_count(&replaced, price, PhPct::new(2.0)), 10);
+}
+
+// ─── Interval migration ──────────────────────────────────────────────────────
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn downsample_keeps_only_complete_buckets_and_sums_volume() {
+    use crate::{app::BaseVol, data::downsample_candles};
+    // candle() spaces candles 5m apart; aggregate 5m -> 15m
+    let candles: Vec<_> = (0..7)
+        .map(|i| {
+            let p = 100.0 + i as f64;
+            candle(i, p, p + 2.0, p - 1.0, p + 0.5, 10.0)
+        })
+        .collect();
+    let (out, incomplete) = downsample_candles(&candles, 300_000, 900_000).unwrap();
+
+    assert_eq!(out.len(), 2);
+    assert_eq!(incomplete, 1);
+    assert_eq!(out[1].timestamp_ms.value(), 900_000);
+    assert_eq!(out[1].open_price.value(), 103.0);
+    assert_eq!(out[1].close_price.value(), 105.5);
+    assert_eq!(out[1].high_price.value(), 107.0);
+    assert_eq!(out[1].low_price.value(), 102.0);
+    assert_eq!(out[1].base_asset_volume, BaseVol::new(30.0));
+    assert!(downsample_candles(&candles, 300_000, 400_000).is_err());
+}
+
+// ─── Aggregated candles ──────────────────────────────────────────────────────
+
+#[test]
+fn aggregated_candles_follow_live_updates() {
+    use crate::{
+        app::{BaseVol, CandleResolution, ClosePrice, OpenPrice, QuoteVol},
+        models::LiveCandle,
+    };
+    let candles: Vec<_> = (0..7)
+        .map(|i| {
+            let p = 100.0 + i as f64;
+            candle(i, p, p + 2.0, p - 1.0, p + 0.5, 10.0)
+        })
+        .collect();
+    let pair = crate::domain::PairInterval {
+        name: "TESTUSDT".to_string(),
+        interval_ms: 300_000,
+    };
+    let mut ohlcv = OhlcvTimeSeries::from_candles(pair, candles);
+
+    // 5m -> 15m: [0,1,2] [3,4,5] [6]
+    let m15 = ohlcv.aggregated(CandleResolution::M15);
+    assert_eq!(m15.len(), 3);
+    assert_eq!(m15[1].open_price.value(), 103.0);
+    assert_eq!(m15[1].high_price.value(), 107.0);
+    let seg = ohlcv.aggregated_range(CandleResolution::M15, 4, 5);
+    assert_eq!(seg.len(), 1);
+    assert_eq!(seg[0].timestamp_ms.value(), 900_000);
+
+    ohlcv.update_from_live(&LiveCandle {
+        symbol: "TESTUSDT".to_string(),
+        open_time: crate::app::TimestampMs::new(7 * 300_000),
+        open: OpenPrice::new(107.0),
+        high: HighPrice::new(120.0),
+        low: LowPrice::new(106.0),
+        close: ClosePrice::new(110.0),
+        volume: BaseVol::new(5.0),
+        quote_vol: QuoteVol::new(550.0),
+        taker_buy_vol: BaseVol::new(2.5),
+        is_closed: false,
+    });
+    let m15 = ohlcv.aggregated(CandleResolution::M15);
+    assert_eq!(m15.len(), 3);
+    assert_eq!(m15[2].high_price.value(), 120.0);
+    assert_eq!(m15[2].close_price.value(), 110.0);
+    assert_eq!(m15[2].base_asset_volume, BaseVol::new(15.0));
+}
+
+// ─── TimestampMs ─────────────────────────────────────────────────────────────
+
+#[test]
+fn timestamp_arithmetic_and_alignment() {
+    use crate::app::TimestampMs;
+
+    let t = TimestampMs::new(1_000_123);
+    let later = t + DurationMs::new(60_000);
+    assert_eq!(later - t, DurationMs::new(60_000));
+    assert_eq!(later - DurationMs::new(60_000), t);
+    assert!(later > t);
+    assert_eq!(t.align_down(60_000), TimestampMs::new(960_000));
+    assert_eq!(TimestampMs::from(t.to_datetime()), t);
+}
+
+#[test]
+fn candles_between_is_half_open_on_open_time() {
+    use crate::app::TimestampMs;
+
+    let candles: Vec<_> = (0..6)
+        .map(|i| candle(i, 100.0, 101.0, 99.0, 100.0, 10.0))
+        .collect();
+    let pair = crate::domain::PairInterval {
+        name: "TESTUSDT".to_string(),
+        interval_ms: 300_000,
+    };
+    let ohlcv = OhlcvTimeSeries::from_candles(pair, candles);
+
+    let opens: Vec<i64> = ohlcv
+        .candles_between(TimestampMs::new(300_000), TimestampMs::new(1_200_000))
+        .map(|c| c.timestamp_ms.value())
+        .collect();
+    assert_eq!(opens, vec![300_000, 600_000, 900_000]);
+    assert_eq!(
+        ohlcv
+            .candles_between(TimestampMs::new(1_000_000), TimestampMs::MAX)
+            .len(),
+        2
+    );
+    assert_eq!(
+        ohlcv
+            .candles(2..4)
+            .rev()
+            .next()
+            .unwrap()
+            .timestamp_ms
+            .value(),
+        900_000
+    );
+}
+
+#[test]
+fn stable_opportunity_ids_depend_only_on_trade_identity() {
+    use crate::{
+        app::TargetPrice,
+        engine::StationId,
+        models::{OPPORTUNITY_ID_PREFIX, OptimizationStrategy, TradeOpportunity},
+    };
+
+    let zones = ClassifiedZones {
+        sticky_superzones: vec![superzone(7, 49_000.0, 51_000.0)],
+        high_wicks_superzones: Vec::new(),
+        low_wicks_superzones: Vec::new(),
+    };
+    let id = |direction, target| {
+        let target = TargetPrice::new(target);
+        TradeOpportunity::stable_id(
+            "BTCUSDT",
+            direction,
+            OptimizationStrategy::Balanced,
+            StationId::Swing,
+            zones.target_anchor(target),
+        )
+    };
+    let base = id(TradeDirection::Long, 50_000.0);
+    assert!(base.starts_with(OPPORTUNITY_ID_PREFIX));
+    assert_eq!(base, id(TradeDirection::Long, 50_000.0));
+    // The target drifting inside its zone keeps the id
+    assert_eq!(base, id(TradeDirection::Long, 50_900.0));
+    assert_ne!(base, id(TradeDirection::Long, 52_000.0));
+    assert_ne!(base, id(TradeDirection::Short, 50_000.0));
+    // A restart numbers the zones afresh; the same zone still gives the same id
+    let renumbered = ClassifiedZones {
+        sticky_superzones: vec![superzone(1, 49_000.0, 51_000.0)],
+        high_wicks_superzones: Vec::new(),
+        low_wicks_superzones: Vec::new(),
+    };
+    assert_eq!(
+        base,
+        TradeOpportunity::stable_id(
+            "BTCUSDT",
+            TradeDirection::Long,
+            OptimizationStrategy::Balanced,
+            StationId::Swing,
+            renumbered.target_anchor(TargetPrice::new(50_000.0)),
+        )
+    );
+    // Outside every zone the target's 0.1% step identifies it
+    assert_eq!(
+        id(TradeDirection::Long, 60_000.0),
+        id(TradeDirection::Long, 60_010.0)
+    );
+    assert_ne!(
+        id(TradeDirection::Long, 60_000.0),
+        id(TradeDirection::Long, 60_200.0)
+    );
+}
+
+// ─── Correlation ─────────────────────────────────────────────────────────────
+
+#[test]
+fn beta_scales_with_follower_amplitude() {
+    use crate::models::correlation::beta_from_returns;
+
+    let returns: Vec<(f64, f64)> = (0..100)
+        .map(|i| {
+            let leader = ((i as f64) * 0.7).sin() * 0.01;
+            (leader, 2.0 * leader + 0.0001)
+        })
+        .collect();
+    let estimate = beta_from_returns(&returns).unwrap();
+    assert!((estimate.beta - 2.0).abs() < 1e-9);
+    assert!((estimate.correlation - 1.0).abs() < 1e-9);
+    assert!(estimate.is_meaningful());
+
+    assert!(beta_from_returns(&returns[..10]).is_some_and(|e| !e.is_meaningful()));
+    let flat: Vec<(f64, f64)> = returns.iter().map(|r| (0.0, r.1)).collect();
+    assert!(beta_from_returns(&flat).is_none());
+}
+
+// ─── Stress scenarios ────────────────────────────────────────────────────────
+
+#[test]
+fn stress_paths_start_flat_and_hit_their_extremes() {
+    use crate::engine::StressScenario;
+
+    let extremes = |s: StressScenario| {
+        let path = s.path();
+        assert_eq!(path[0], 0.0);
+        let min = path.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = path.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        (min, max, *path.last().unwrap())
+    };
+    let (min, _, last) = extremes(StressScenario::FlashCrash);
+    assert!((min + 0.10).abs() < 1e-12);
+    assert!((last + 0.04).abs() < 1e-12);
+    let (_, max, last) = extremes(StressScenario::Squeeze);
+    assert!((max - 0.05).abs() < 1e-12);
+    assert!((last - 0.03).abs() < 1e-12);
+    let (min, max, _) = extremes(StressScenario::WeekendChop);
+    assert!(min >= -0.015 - 1e-12 && max <= 0.015 + 1e-12);
+}
+
+// ─── Mock exchange ───────────────────────────────────────────────────────────
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn mock_history_is_seeded_contiguous_and_follows_regimes() {
+    use crate::data::mock_exchange::{MockRegime, MockSettings, generate_history};
+
+    let interval = 300_000;
+    let now = 1_700_000_123_456;
+    let settings =
+        MockSettings::from_args(Some(7), &["crash:12".to_string(), "up:12".to_string()]).unwrap();
+    let (a, _) = generate_history(&settings, "BTCUSDT", interval, now);
+    let (b, walk) = generate_history(&settings, "BTCUSDT", interval, now);
+    assert_eq!(a.len(), b.len());
+    assert!(
+        a.iter()
+            .zip(&b)
+            .all(|(x, y)| x.close_price == y.close_price)
+    );
+    assert_eq!(walk.price(), a.last().unwrap().close_price.value());
+    assert!(a.last().unwrap().timestamp_ms.value() + interval <= now);
+    assert!(a.windows(2).all(|w| {
+        w[1].timestamp_ms.value() - w[0].timestamp_ms.value() == interval
+            && w[1].open_price.value() == w[0].close_price.value()
+            && w[0].low_price.value() <= w[0].high_price.value()
+    }));
+
+    let crash_move: f64 = a
+        .iter()
+        .filter(|c| settings.regime_at(c.timestamp_ms.value(), interval) == MockRegime::Crash)
+        .map(|c| (c.close_price.value() / c.open_price.value()).ln())
+        .sum();
+    assert!(crash_move < 0.0);
+
+    let (other, _) = generate_history(&settings, "ETHUSDT", interval, now);
+    assert_ne!(other[0].close_price, a[0].close_price);
+    assert!(MockSettings::from_args(None, &["sideways:5".to_string()]).is_err());
+    assert!(MockSettings::from_args(None, &["up:0".to_string()]).is_err());
+}
+
+// ─── Candle cross-check ──────────────────────────────────────────────────────
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn cross_check_flags_field_mismatches_and_spreads_samples() {
+    use crate::data::cross_check::{CandleField, compare_candles, sample_indices};
+
+    let cached = candle(3, 100.0, 102.0, 99.0, 101.0, 10.0);
+    assert!(compare_candles("BTCUSDT", &cached, &cached).is_empty());
+    let reference = candle(3, 100.0, 102.5, 99.0, 101.0, 12.0);
+    let fields: Vec<_> = compare_candles("BTCUSDT", &cached, &reference)
+        .into_iter()
+        .map(|m| m.field)
+        .collect();
+    assert_eq!(
+        fields,
+        vec![
+            CandleField::High,
+            CandleField::Volume,
+            CandleField::QuoteVolume,
+            CandleField::TakerBuyVolume
+        ]
+    );
+
+    let picks = sample_indices(1_000, 8, 12345);
+    assert_eq!(picks.len(), 8);
+    assert!(
+        picks
+            .iter()
+            .enumerate()
+            .all(|(i, &idx)| idx >= i * 125 && idx < (i + 1) * 125)
+    );
+    assert_eq!(sample_indices(3, 8, 1).len(), 3);
+    assert!(sample_indices(0, 8, 1).is_empty());
+}
+
+// ─── Idle mode ───────────────────────────────────────────────────────────────
+
+#[test]
+fn idle_activity_follows_focus_and_input() {
+    use crate::app::{Activity, IdleSettings};
+
+    let settings = IdleSettings::default();
+    let recent = Duration::from_secs(1);
+    let quiet = Duration::from_secs(settings.idle_after_secs);
+    assert_eq!(
+        Activity::classify(&settings, true, false, recent),
+        Activity::Active
+    );
+    assert_eq!(
+        Activity::classify(&settings, true, false, quiet),
+        Activity::Idle
+    );
+    let background = Activity::classify(&settings, false, false, recent);
+    assert_eq!(background, Activity::Background);
+    assert_eq!(
+        Activity::classify(&settings, true, true, recent),
+        Activity::Background
+    );
+    assert!(background.holds_recalcs(&settings));
+    assert!(!Activity::Idle.holds_recalcs(&settings));
+    assert_eq!(Activity::Active.repaint_interval(&settings), None);
+
+    let disabled = IdleSettings {
+        enabled: false,
+        ..settings
+    };
+    assert_eq!(
+        Activity::classify(&disabled, false, true, quiet),
+        Activity::Active
+    );
+}
+
+// ─── Hotkey bindings ─────────────────────────────────────────────────────────
+
+#[test]
+fn keybindings_default_without_conflicts_and_flag_rebinds() {
+    use crate::app::{HotkeyAction, KeyBindings, KeyChord};
+    use eframe::egui::Key;
+    use strum::IntoEnumIterator;
+
+    let mut bindings = KeyBindings::default();
+    for action in HotkeyAction::iter() {
+        assert!(!bindings.chords(action).is_empty(), "{:?} unbound", action);
+        assert!(
+            bindings.conflicts(action).is_empty(),
+            "{:?} clashes",
+            action
+        );
+    }
+    assert_eq!(bindings.label(HotkeyAction::RedoParams), "Ctrl+Shift+Z");
+
+    bindings.bind(HotkeyAction::ToggleStress, KeyChord::plain(Key::D));
+    assert_eq!(
+        bindings.conflicts(HotkeyAction::ToggleStress),
+        vec![HotkeyAction::ToggleDiagnostics]
+    );
+    assert!(bindings.is_customized());
+
+    // Rebinding back to the default drops the override
+    bindings.bind(HotkeyAction::ToggleStress, KeyChord::plain(Key::X));
+    assert!(!bindings.is_customized());
+}
+
+// ─── Plot zoom ───────────────────────────────────────────────────────────────
+
+#[test]
+fn zoom_keeps_anchor_price_at_same_relative_position() {
+    use crate::ui::zoom_about;
+
+    let (min, max) = zoom_about(100.0, 200.0, 125.0, 0.5);
+    assert!((min - 112.5).abs() < 1e-9 && (max - 162.5).abs() < 1e-9);
+    // Anchor stays a quarter of the way up
+    assert!(((125.0 - min) / (max - min) - 0.25).abs() < 1e-9);
+    let (min, max) = zoom_about(min, max, 125.0, 2.0);
+    assert!((min - 100.0).abs() < 1e-9 && (max - 200.0).abs() < 1e-9);
+}
+
+// ─── Segment statistics ──────────────────────────────────────────────────────
+
+#[test]
+fn segment_stats_cover_only_the_segment_and_rank_zones_by_volume() {
+    use crate::{
+        app::TimestampMs,
+        models::{DiffZoneKind, DisplaySegment, GapReason, SegmentStats},
+    };
+
+    // 0..5 trade around 100, 5..10 around 120 with twice the volume
+    let candles: Vec<_> = (0..10)
+        .map(|i| {
+            let mid = if i < 5 { 100.0 } else { 120.0 };
+            let vol = if i < 5 { 10.0 } else { 20.0 };
+            candle(i, mid, mid + 1.0, mid - 1.0, mid, vol)
+        })
+        .collect();
+    let pair = crate::domain::PairInterval {
+        name: "TESTUSDT".to_string(),
+        interval_ms: 300_000,
+    };
+    let ohlcv = OhlcvTimeSeries::from_candles(pair, candles);
+    let segment = DisplaySegment {
+        start_idx: 2,
+        end_idx: 8,
+        start_ts: TimestampMs::new(2 * 300_000),
+        end_ts: TimestampMs::new(7 * 300_000),
+        candle_count: 6,
+        low_price: LowPrice::new(99.0),
+        high_price: HighPrice::new(121.0),
+        gap_reason: GapReason::None,
+        gap_duration_str: String::new(),
+    };
+    let zones = ClassifiedZones {
+        sticky_superzones: vec![superzone(1, 99.0, 101.0)],
+        low_wicks_superzones: vec![],
+        high_wicks_superzones: vec![superzone(2, 119.0, 121.0), superzone(3, 150.0, 160.0)],
+    };
+
+    let stats = SegmentStats::compute(&ohlcv, &segment, &zones).unwrap();
+    assert_eq!(stats.candle_count, 6);
+    assert_eq!(stats.low.value(), 99.0);
+    assert_eq!(stats.high.value(), 121.0);
+    assert!((stats.base_volume.value() - 90.0).abs() < 1e-9);
+    assert!((stats.change_pct - 20.0).abs() < 1e-9);
+    // 3 × 20 × 120 in the upper zone vs 3 × 10 × 100 in the lower; the untouched zone is left out
+    assert_eq!(stats.dominant_zones.len(), 2);
+    assert_eq!(stats.dominant_zones[0].kind, DiffZoneKind::Resistance);
+    assert!((stats.dominant_zones[0].volume_share - 7200.0 / 10200.0).abs() < 1e-9);
+}
+
+// ─── Time axis layout ────────────────────────────────────────────────────────
+
+fn display_segment(start_bucket: i64, end_bucket: i64) -> crate::models::DisplaySegment {
+    use crate::{app::TimestampMs, models::GapReason};
+    crate::models::DisplaySegment {
+        start_idx: 0,
+        end_idx: 0,
+        start_ts: TimestampMs::new(start_bucket * 300_000),
+        end_ts: TimestampMs::new(end_bucket * 300_000),
+        candle_count: (end_bucket - start_bucket + 1) as usize,
+        low_price: LowPrice::new(1.0),
+        high_price: HighPrice::new(2.0),
+        gap_reason: GapReason::None,
+        gap_duration_str: String::new(),
+    }
+}
+
+#[test]
+fn true_time_axis_sizes_gaps_by_duration_up_to_the_cap() {
+    use crate::{
+        app::{CandleResolution, TimestampMs},
+        ui::{PLOT_CONFIG, SegmentLayout, TimeAxisMode},
+    };
+
+    // Three 100-slot segments: a 20-slot gap, then a 1000-slot gap (capped at 10% of 300)
+    let segments = [
+        display_segment(0, 99),
+        display_segment(120, 219),
+        display_segment(1220, 1319),
+    ];
+    let at = |bucket: i64| TimestampMs::new(bucket * 300_000);
+
+    let compressed = SegmentLayout::new(&segments, CandleResolution::M5, TimeAxisMode::Compressed);
+    let gap = PLOT_CONFIG.segment_gap_width_px;
+    assert!((compressed.total_width() - (300.0 + 2.0 * gap)).abs() < 1e-9);
+    assert_eq!(compressed.x_for_timestamp(at(110)), None);
+    assert_eq!(compressed.timestamp_at(100.0 + gap / 2.0), None);
+
+    let true_time = SegmentLayout::new(&segments, CandleResolution::M5, TimeAxisMode::TrueTime);
+    assert!((true_time.total_width() - 350.0).abs() < 1e-9);
+    assert_eq!(true_time.segment_bounds(1), Some((120.0, 220.0)));
+    assert_eq!(true_time.segment_bounds(2), Some((250.0, 350.0)));
+    // Uncapped gaps stay linear in time, both ways
+    assert!((true_time.x_for_timestamp(at(110)).unwrap() - 110.0).abs() < 1e-9);
+    assert_eq!(true_time.timestamp_at(110.0), Some(at(110)));
+    assert_eq!(true_time.timestamp_at(260.5), Some(at(1230)));
+}
+
+// ─── Manual zone edits ───────────────────────────────────────────────────────
+
+#[test]
+fn zone_edits_split_and_merge_are_replayed_in_order() {
+    use crate::{
+        app::Price,
+        models::{DiffZoneKind, ZoneEdit},
+    };
+
+    let mut zones = ClassifiedZones {
+        sticky_superzones: vec![
+            superzone(1, 100.0, 110.0),
+            superzone(2, 120.0, 130.0),
+            superzone(3, 150.0, 160.0),
+        ],
+        low_wicks_superzones: vec![],
+        high_wicks_superzones: vec![],
+    };
+    let bounds = |z: &ClassifiedZones| -> Vec<(f64, f64)> {
+        z.sticky_superzones
+            .iter()
+            .map(|s| (s.price_bottom.value(), s.price_top.value()))
+            .collect()
+    };
+
+    zones.apply_edits(&[
+        ZoneEdit::Merge {
+            kind: DiffZoneKind::Sticky,
+            lower: Price::new(105.0),
+            upper: Price::new(125.0),
+        },
+        ZoneEdit::Split {
+            kind: DiffZoneKind::Sticky,
+            at: Price::new(155.0),
+        },
+        // No such zone any more: skipped
+        ZoneEdit::Split {
+            kind: DiffZoneKind::Sticky,
+            at: Price::new(140.0),
+        },
+    ]);
+    assert_eq!(
+        bounds(&zones),
+        vec![(100.0, 130.0), (150.0, 155.0), (155.0, 160.0)]
+    );
+}
+
+#[test]
+fn custom_zones_get_fresh_ids_per_pair_and_survive_a_config_round_trip() {
+    use crate::{
+        app::Price,
+        models::{CustomZone, DiffZoneKind},
+        shared::SharedConfiguration,
+    };
+
+    let zone = |label: &str| CustomZone {
+        id: 99,
+        label: label.to_string(),
+        kind: DiffZoneKind::Support,
+        price_bottom: Price::new(90.0),
+        price_top: Price::new(95.0),
+        alert: true,
+    };
+    let config = SharedConfiguration::new();
+    config.add_custom_zone("BTCUSDT", zone("a"));
+    config.add_custom_zone("BTCUSDT", zone("b"));
+    config.add_custom_zone("ETHUSDT", zone("c"));
+    config.remove_custom_zone("BTCUSDT", 1);
+
+    let restored: SharedConfiguration =
+        serde_json::from_str(&serde_json::to_string(&config).unwrap()).unwrap();
+    let btc = restored.get_custom_zones("BTCUSDT");
+    assert_eq!(btc.len(), 1);
+    assert_eq!((btc[0].id, btc[0].label.as_str()), (2, "b"));
+    assert_eq!(restored.get_custom_zones("ETHUSDT")[0].id, 1);
+    assert!(btc[0].contains(Price::new(92.0)) && !btc[0].contains(Price::new(96.0)));
+
+    // Deleting the newest zone must not hand its id to the next one
+    restored.remove_custom_zone("BTCUSDT", 2);
+    restored.add_custom_zone("BTCUSDT", zone("d"));
+    assert_eq!(restored.get_custom_zones("BTCUSDT")[0].id, 3);
+}
+
+#[test]
+fn custom_zones_reach_the_pathfinder_only_when_targeting_is_on() {
+    use crate::{
+        app::{Price, TradeProfile},
+        domain::PairInterval,
+        engine::{StationId, run_pathfinder_simulations},
+        models::{AdaptiveCurves, CustomZone, DiffZoneKind, OptimizationStrategy},
+        shared::SharedConfiguration,
+    };
+
+    // A clean 8h sine around 100 (+-5%) ending just after a trough: every matched history
+    // rises from here, so a zone a little overhead is reliably reached
+    let period = 96.0;
+    let candles: Vec<_> = (0..96 * 40 + 75)
+        .map(|i| {
+            let close = 100.0 * (1.0 + 0.05 * (std::f64::consts::TAU * i as f64 / period).sin());
+            candle(i, close, close * 1.001, close * 0.999, close, 10.0)
+        })
+        .collect();
+    let ohlcv = OhlcvTimeSeries::from_candles(
+        PairInterval {
+            name: "BTCUSDT".to_string(),
+            interval_ms: 300_000,
+        },
+        candles,
+    );
+    let current_price = Price::new(ohlcv.close_prices[ohlcv.klines() - 1].value());
+
+    let config = SharedConfiguration::new();
+    config.add_custom_zone(
+        "BTCUSDT",
+        CustomZone {
+            id: 0,
+            label: "weekly open".to_string(),
+            kind: DiffZoneKind::Resistance,
+            price_bottom: Price::new(current_price.value() * 1.015),
+            price_top: Price::new(current_price.value() * 1.025),
+            alert: false,
+        },
+    );
+    let run = |config: &SharedConfiguration| {
+        run_pathfinder_simulations(
+            &ohlcv,
+            current_price,
+            PhPct::new(0.05),
+            OptimizationStrategy::default(),
+            StationId::default(),
+            None,
+            &AdaptiveCurves::default(),
+            &TradeProfile::default(),
+            None,
+            None,
+            &config.get_custom_targets("BTCUSDT"),
+        )
+        .opportunities
+    };
+
+    assert!(run(&config).iter().all(|op| op.custom_zone.is_none()));
+
+    config.set_custom_zone_targets(true);
+    let opportunities = run(&config);
+    let custom = opportunities
+        .iter()
+        .find(|op| op.custom_zone.as_deref() == Some("weekly open"))
+        .expect("custom zone was simulated");
+    // Targets the near edge of the zone, as a long
+    assert_eq!(custom.target_price.value(), current_price.value() * 1.015);
+    assert_eq!(custom.direction, TradeDirection::Long);
+    assert!(custom.simulation.success_rate.value() > 0.5);
+}
+
+#[test]
+fn trade_profile_measures_aroi_against_the_benchmark_rate() {
+    use crate::app::{AroiPct, TradeProfile};
+
+    let profile = TradeProfile {
+        benchmark_aroi_pct: AroiPct::new(0.08),
+        ..Default::default()
+    };
+    assert!((profile.excess_aroi(AroiPct::new(0.30)).value() - 0.22).abs() < 1e-12);
+    assert!(profile.beats_benchmark(AroiPct::new(0.09)));
+    assert!(!profile.beats_benchmark(AroiPct::new(0.08)));
+
+    // Profiles saved before the benchmark existed pick up the default rate
+    let json = r#"{"min_roi_pct":0.002,"min_aroi_pct":0.3,"stop_mode":"RiskReward","zone_stop_buffer":0.002}"#;
+    let old: TradeProfile = serde_json::from_str(json).unwrap();
+    assert_eq!(old.min_aroi_pct, AroiPct::new(0.3));
+    assert_eq!(
+        old.benchmark_aroi_pct,
+        TradeProfile::default().benchmark_aroi_pct
+    );
+}
+
+#[test]
+fn expectancy_and_kelly_follow_success_rate_and_reward_risk() {
+    use crate::{
+        app::{MomentumPct, Prob, RoiPct, VolRatio, VolatilityPct},
+        models::{EmpiricalOutcomeStats, MarketState},
+    };
+
+    let stats = |p: f64, rr: f64| EmpiricalOutcomeStats {
+        success_rate: Prob::new(p),
+        avg_candle_count: 10.0,
+        risk_reward_ratio: rr,
+        sample_size: 50,
+        avg_pnl_pct: RoiPct::new(0.0),
+        market_state: MarketState {
+            volatility_pct: VolatilityPct::new(0.01),
+            momentum_pct: MomentumPct::new(0.0),
+            relative_volume: VolRatio::new(1.0),
+        },
+        return_variance: 0.0,
+        hit_times: None,
+        break_even_rate: Prob::new(0.0),
+        fixed_success_rate: None,
+        barrier: None,
+    };
+
+    // 50% at 2:1 -> +0.5R per trade, stake a quarter of the bankroll
+    let edge = stats(0.5, 2.0);
+    assert!((edge.expectancy_r() - 0.5).abs() < 1e-12);
+    assert!((edge.kelly_fraction() - 0.25).abs() < 1e-12);
+
+    // Break-even and losing setups get no stake
+    assert!(stats(1.0 / 3.0, 2.0).expectancy_r().abs() < 1e-12);
+    assert_eq!(stats(0.3, 2.0).kelly_fraction(), 0.0);
+    assert_eq!(stats(0.9, 0.0).kelly_fraction(), 0.0);
+}
+
+#[test]
+fn what_if_prefills_clicked_and_live_price_and_skips_junk() {
+    use crate::{app::Price, ui::PriceWhatIf};
+
+    let mut what_if = PriceWhatIf::new("BTCUSDT", Price::new(95.5), Some(Price::new(100.0)));
+    assert_eq!(what_if.input, "95.5, 100");
+    assert_eq!(what_if.prices(), vec![Price::new(95.5), Price::new(100.0)]);
+
+    what_if.input = "90,, 92 abc -5 0 110".to_string();
+    assert_eq!(
+        what_if.prices(),
+        vec![Price::new(90.0), Price::new(92.0), Price::new(110.0)]
+    );
+}
+
+#[test]
+fn ticker_badges_only_material_best_aroi_improvements() {
+    use crate::ui::is_material_improvement;
+
+    assert!(is_material_improvement(Some(1.0), Some(1.3), 0.25));
+    assert!(!is_material_improvement(Some(1.0), Some(1.2), 0.25));
+    assert!(!is_material_improvement(Some(1.0), Some(0.5), 0.25));
+    // Losing trades improving towards break-even scale by magnitude
+    assert!(is_material_improvement(Some(-0.4), Some(-0.2), 0.25));
+    // Models arriving (or opportunities vanishing) are not improvements
+    assert!(!is_material_improvement(None, Some(0.4), 0.25));
+    assert!(!is_material_improvement(Some(0.4), None, 0.25));
+}
+
+#[test]
+fn trade_finder_held_order_keeps_rows_in_place_and_appends_new_ones() {
+    use crate::{
+        app::{Price, QuoteVol, SortDirection},
+        ui::{SortColumn, TradeFinderRefresh, TradeFinderRow},
+    };
+
+    let row = |pair: &str| TradeFinderRow {
+        pair_name: pair.to_string(),
+        quote_volume_24h: QuoteVol::new(0.0),
+        quote_usd_rate: Some(1.0),
+        market_state: None,
+        opportunity: None,
+        current_price: Price::new(1.0),
+    };
+    let names = |rows: &[TradeFinderRow]| -> Vec<String> {
+        rows.iter().map(|r| r.pair_name.clone()).collect()
+    };
+
+    let mut refresh = TradeFinderRefresh::default();
+    refresh.record(
+        &[row("AAA"), row("BBB"), row("CCC")],
+        (SortColumn::LiveRoi, SortDirection::Descending),
+    );
+
+    // A later sort would put CCC first, DDD is new and BBB has dropped out
+    let mut rows = vec![row("CCC"), row("DDD"), row("AAA"), row("EEE")];
+    refresh.apply(&mut rows);
+    assert_eq!(names(&rows), ["AAA", "CCC", "DDD", "EEE"]);
+}
+
+#[test]
+fn quote_usd_rate_prices_non_dollar_quotes_via_usdt_markets() {
+    use crate::{app::Price, domain::PairInterval};
+
+    let prices = |pair: &str| match pair {
+        "BTCUSDT" => Some(Price::new(60_000.0)),
+        "USDTTRY" => Some(Price::new(40.0)),
+        _ => None,
+    };
+    assert_eq!(PairInterval::quote_usd_rate("SOLUSDC", prices), Some(1.0));
+    assert_eq!(
+        PairInterval::quote_usd_rate("ADABTC", prices),
+        Some(60_000.0)
+    );
+    assert_eq!(
+        PairInterval::quote_usd_rate("BTCTRY", prices),
+        Some(1.0 / 40.0)
+    );
+    // No ETH market to convert through
+    assert_eq!(PairInterval::quote_usd_rate("LINKETH", prices), None);
+}
+
+#[test]
+fn heat_week_tallies_resolved_trades_and_leaves_open_ones_out_of_rates() {
+    use crate::{
+        engine::{HeatCalendar, HeatWeek},
+        models::TradeOutcome,
+    };
+
+    let mut week = HeatWeek::default();
+    assert_eq!(week.win_rate(), None);
+    week.record(Some((TradeOutcome::TargetHit, 0.04)));
+    week.record(Some((TradeOutcome::StopHit, -0.02)));
+    week.record(Some((TradeOutcome::Timeout, 0.01)));
+    week.record(Some((TradeOutcome::TargetHit, 0.05)));
+    week.record(None);
+
+    assert_eq!(week.opportunities, 5);
+    assert_eq!(
+        (week.wins, week.losses, week.timeouts, week.open),
+        (2, 1, 1, 1)
+    );
+    assert_eq!(week.win_rate(), Some(0.5));
+    assert!((week.avg_return().unwrap() - 0.02).abs() < 1e-12);
+
+    let calendar = HeatCalendar {
+        pair: "BTCUSDT".to_string(),
+        weeks: vec![week.clone(), HeatWeek::default(), week],
+        trades: Vec::new(),
+    };
+    let total = calendar.total();
+    assert_eq!(total.opportunities, 10);
+    assert_eq!(total.resolved(), 8);
+    assert_eq!(total.win_rate(), Some(0.5));
+}
+
+#[test]
+fn tuner_fingerprint_reuses_only_while_settings_and_data_barely_change() {
+    use crate::{
+        app::{Price, TimestampMs},
+        engine::{StationId, TunerFingerprint, TunerWeights},
+        models::{AdaptiveCurves, OptimizationStrategy},
+    };
+
+    let saved = TunerFingerprint {
+        station: StationId::Swing,
+        strategy: OptimizationStrategy::default(),
+        adaptive: AdaptiveCurves::default(),
+        weights: TunerWeights::default(),
+        min_ph: None,
+        first_candle: TimestampMs::new(1_000),
+        candles: 10_000,
+        price: Price::new(100.0),
+    };
+    let now = |candles: usize, price: f64| TunerFingerprint {
+        candles,
+        price: Price::new(price),
+        ..saved.clone()
+    };
+
+    assert!(now(10_100, 101.0).matches(&saved));
+    // A couple of days of new candles, or a 5% move, needs a fresh scan
+    assert!(!now(10_600, 100.0).matches(&saved));
+    assert!(!now(10_000, 105.0).matches(&saved));
+
+    // Different station: neither reused nor warm-started
+    let other_station = TunerFingerprint {
+        station: StationId::Day,
+        ..now(10_000, 100.0)
+    };
+    assert!(!other_station.matches(&saved));
+    assert!(!other_station.same_settings(&saved));
+    assert!(now(10_600, 105.0).same_settings(&saved));
+}
+
+#[test]
+fn tuner_scans_stay_out_of_undo_snapshots() {
+    use crate::{
+        app::{PhPct, Price, TimestampMs},
+        engine::{StationId, TunerFingerprint, TunerScan, TunerWeights},
+        models::{AdaptiveCurves, OptimizationStrategy},
+        shared::SharedConfiguration,
+    };
+
+    let config = SharedConfiguration::new();
+    let scan = TunerScan {
+        fingerprint: TunerFingerprint {
+            station: StationId::Swing,
+            strategy: OptimizationStrategy::default(),
+            adaptive: AdaptiveCurves::default(),
+            weights: TunerWeights::default(),
+            min_ph: None,
+            first_candle: TimestampMs::new(1_000),
+            candles: 10_000,
+            price: Price::new(100.0),
+        },
+        probes: Vec::new(),
+        best: PhPct::new(0.05),
+    };
+    config.insert_tuner_scan("BTCUSDT".to_string(), scan.clone());
+
+    let before = config.snapshot();
+    assert!(before.tuner_scans.is_empty());
+    assert_eq!(config.get_tuner_scan("BTCUSDT"), Some(scan.clone()));
+
+    config.restore(before);
+    assert_eq!(config.get_tuner_scan("BTCUSDT"), Some(scan));
+}
+
+#[test]
+fn tuner_weights_decide_which_probe_scores_highest() {
+    use crate::{
+        app::PhPct,
+        engine::{TunerProbe, TunerWeights, score_probes},
+    };
+
+    let probe = |ph: f64, score: f64, expectancy_r: f64, sample_size: f64| TunerProbe {
+        ph: PhPct::new(ph),
+        score,
+        duration_hours: 48.0,
+        coverage: 0.5,
+        expectancy_r,
+        sample_size,
+    };
+    // High strategy score on thin evidence vs. a slightly weaker, well-sampled setup
+    let probes = [probe(0.05, 2.0, 0.1, 8.0), probe(0.10, 1.5, 0.6, 120.0)];
+
+    let quality_only = TunerWeights {
+        quality: 1.0,
+        coverage: 0.0,
+        expectancy: 0.0,
+        samples: 0.0,
+    };
+    let scores = score_probes(&probes, &quality_only);
+    assert!(scores[0].total > scores[1].total);
+    // Equal coverage normalizes to 1.0 for everyone
+    assert_eq!(scores[0].coverage, 1.0);
+
+    let evidence_first = TunerWeights {
+        quality: 0.5,
+        coverage: 0.0,
+        expectancy: 0.5,
+        samples: 0.5,
+    };
+    let scores = score_probes(&probes, &evidence_first);
+    assert!(scores[1].total > scores[0].total);
+    assert_eq!((scores[1].expectancy, scores[1].samples), (1.0, 1.0));
+}
+
+#[test]
+fn station_preview_steps_through_stations_without_leaving_the_ends() {
+    use crate::{engine::StationId, ui::StationPreviewState};
+
+    let mut preview = StationPreviewState::default();
+    preview.sync_pair("BTCUSDT");
+    let active = Some(StationId::Day);
+
+    preview.step(active, 1);
+    assert_eq!(preview.pending, Some(StationId::Swing));
+    assert_eq!(preview.wanted(), Some(StationId::Swing));
+    preview.step(active, 1);
+    preview.step(active, 1);
+    assert_eq!(preview.pending, Some(StationId::Macro));
+
+    // Hover wins over the pending pick; stepping back onto the live station clears the pick
+    preview.hovered = Some(StationId::Scalp);
+    assert_eq!(preview.wanted(), Some(StationId::Scalp));
+    preview.hovered = None;
+    preview.step(active, -1);
+    preview.step(active, -1);
+    assert_eq!(preview.pending, None);
+    preview.step(active, -1);
+    preview.step(active, -1);
+    assert_eq!(preview.pending, Some(StationId::Scalp));
+
+    // A failed tune is remembered instead of retried every frame
+    preview.finish(StationId::Scalp, None);
+    assert_eq!(preview.wanted(), None);
+    assert!(preview.ghost("BTCUSDT").is_none());
+    assert_eq!(preview.take_scan(StationId::Scalp), None);
+    assert_eq!(preview.pending, None);
+
+    preview.step(active, 1);
+    preview.sync_pair("ETHUSDT");
+    assert_eq!(preview.pending, None);
+}
+
+#[test]
+fn station_stats_and_notes_survive_param_undo() {
+    use crate::{
+        app::{PhPct, TimestampMs},
+        engine::{StationId, StationStats},
+        shared::SharedConfiguration,
+    };
+
+    let config = SharedConfiguration::new();
+    let before = config.snapshot();
+    let stats = StationStats {
+        ph: PhPct::new(0.04),
+        opportunities: 3,
+        coverage: 0.42,
+        updated_at: TimestampMs::new(1_000),
+    };
+    config.record_station_stats("BTCUSDT".to_string(), StationId::Day, stats);
+    config.set_station_note(
+        "BTCUSDT".to_string(),
+        StationId::Day,
+        "too slow".to_string(),
+    );
+    config.set_station_note("BTCUSDT".to_string(), StationId::Scalp, "noisy".to_string());
+    config.insert_station("BTCUSDT".to_string(), StationId::Day);
+
+    config.restore(before);
+    assert_eq!(config.get_station("BTCUSDT"), None);
+    assert_eq!(
+        config.get_station_stats("BTCUSDT").get(&StationId::Day),
+        Some(&stats)
+    );
+    assert!(config.get_station_stats("ETHUSDT").is_empty());
+    assert_eq!(config.get_station_notes("BTCUSDT").len(), 2);
+
+    // A blank note removes it
+    config.set_station_note("BTCUSDT".to_string(), StationId::Scalp, "  ".to_string());
+    let notes = config.get_station_notes("BTCUSDT");
+    assert_eq!(
+        notes.get(&StationId::Day).map(String::as_str),
+        Some("too slow")
+    );
+    assert!(!notes.contains_key(&StationId::Scalp));
+}
+
+#[test]
+fn ledger_import_merges_same_trades_and_renames_id_clashes() {
+    use crate::{
+        app::TargetPrice,
+        models::{LedgerConflict, LedgerImportSummary, OpportunityLedger},
+    };
+
+    let ledger_op = |id: &str, direction, target, days_ago| {
+        let mut op = opportunity("BTCUSDT", direction);
+        op.id = id.to_string();
+        op.target_price = TargetPrice::new(target);
+        op.created_at -= chrono::Duration::days(days_ago);
+        op
+    };
+    let mut ledger = OpportunityLedger::new();
+    ledger.opportunities.insert(
+        "a".to_string(),
+        ledger_op("a", TradeDirection::Long, 110.0, 1),
+    );
+    let imported = vec![
+        // Same trade, first seen earlier on the other machine
+        ledger_op("a", TradeDirection::Long, 112.0, 5),
+        ledger_op("b", TradeDirection::Long, 120.0, 2),
+    ];
+
+    let summary = ledger.import(imported.clone(), LedgerConflict::KeepLocal);
+    assert_eq!(
+        summary,
+        LedgerImportSummary {
+            added: 1,
+            merged: 1,
+            renamed: 0
+        }
+    );
+    let a = &ledger.opportunities["a"];
+    assert_eq!(a.target_price.value(), 110.0);
+    assert_eq!(a.created_at, imported[0].created_at);
+
+    ledger.import(vec![imported[0].clone()], LedgerConflict::PreferImported);
+    assert_eq!(ledger.opportunities["a"].target_price.value(), 112.0);
+
+    // Unrelated trade under a taken id: stored alongside, local entry untouched
+    let summary = ledger.import(
+        vec![ledger_op("a", TradeDirection::Short, 90.0, 0)],
+        LedgerConflict::PreferImported,
+    );
+    assert_eq!(summary.renamed, 1);
+    assert_eq!(ledger.opportunities.len(), 3);
+    assert_eq!(ledger.opportunities["a"].direction, TradeDirection::Long);
+}
+
+#[test]
+fn ledger_store_round_trips_and_migrates_unversioned_files() {
+    use crate::{
+        data::{ledger_from_bytes, write_ledger},
+        models::OpportunityLedger,
+    };
+    use std::collections::HashMap;
+
+    let mut op = opportunity("BTCUSDT", TradeDirection::Long);
+    op.id = "a".to_string();
+    op.duration_vol_factor = 1.3;
+    let mut ledger = OpportunityLedger::new();
+    ledger.opportunities.insert("a".to_string(), op.clone());
+    let mut bytes = Vec::new();
+    write_ledger(&mut bytes, &ledger).unwrap();
+    let restored = ledger_from_bytes(&bytes).unwrap();
+    assert_eq!(restored.opportunities["a"].duration_vol_factor, 1.3);
+
+    // A future layout is refused rather than misread
+    let mut future = bytes.clone();
+    future[8] += 1;
+    assert!(ledger_from_bytes(&future).is_err());
+
+    // Pre-versioning layout: bincode is positional, so tuples reproduce the old structs
+    let sim = &op.simulation;
+    let legacy_sim = (
+        sim.success_rate,
+        sim.avg_candle_count,
+        sim.risk_reward_ratio,
+        sim.sample_size,
+        sim.avg_pnl_pct,
+        sim.market_state,
+        sim.return_variance,
+    );
+    let legacy_variants: Vec<(f64, RoiPct, _, crate::app::StopPrice)> =
+        vec![(2.0, RoiPct::new(0.02), legacy_sim, op.stop_price)];
+    let legacy_op = (
+        op.id.clone(),
+        op.created_at,
+        op.ph_pct,
+        op.pair_name.clone(),
+        op.direction,
+        op.start_price,
+        op.target_price,
+        op.stop_price,
+        op.max_duration,
+        op.avg_duration,
+        op.strategy,
+        op.station_id,
+        op.market_state,
+        op.visuals.clone(),
+        legacy_sim,
+        legacy_variants,
+    );
+    let legacy_bytes =
+        bincode::serialize(&(HashMap::from([("a".to_string(), legacy_op)]),)).unwrap();
+    let migrated = ledger_from_bytes(&legacy_bytes).unwrap();
+    let a = &migrated.opportunities["a"];
+    assert_eq!(a.target_price.value(), 110.0);
+    assert_eq!(a.duration_vol_factor, 1.0);
+    assert_eq!(a.variants.len(), 1);
+    assert_eq!(a.simulation.break_even_rate.value(), 0.0);
+    assert_eq!(a.simulation.expectancy_r(), op.simulation.expectancy_r());
+
+    // Ids re-keyed on an earlier run still resolve after a restart
+    let mut ledger = OpportunityLedger::new();
+    ledger.opportunities.insert("a".to_string(), op);
+    assert_eq!(ledger.rekey_legacy_ids(), 1);
+    let mut bytes = Vec::new();
+    write_ledger(&mut bytes, &ledger).unwrap();
+    let restored = ledger_from_bytes(&bytes).unwrap();
+    assert!(restored.get("a").is_some());
+}
+
+#[test]
+fn data_dir_prefers_explicit_path_then_executable_directory() {
+    use crate::config::resolve_data_dir;
+    use std::path::{Path, PathBuf};
+
+    let exe = Path::new("/media/usb/sniper/zone_sniper");
+    assert_eq!(resolve_data_dir(None, false, Some(exe)), None);
+    assert_eq!(
+        resolve_data_dir(None, true, Some(exe)),
+        Some(PathBuf::from("/media/usb/sniper"))
+    );
+    assert_eq!(
+        resolve_data_dir(Some(Path::new("profiles/alt")), true, Some(exe)),
+        Some(PathBuf::from("profiles/alt"))
+    );
+    assert_eq!(resolve_data_dir(None, true, None), None);
+}
+
+#[test]
+fn profile_registry_rejects_bad_names_and_reuses_existing_spelling() {
+    use crate::data::{ProfileRegistry, validate_profile_name};
+
+    assert!(validate_profile_name("  ").is_err());
+    assert!(validate_profile_name("../work").is_err());
+    assert!(validate_profile_name(&"x".repeat(33)).is_err());
+    assert_eq!(validate_profile_name(" paper_2 ").unwrap(), "paper_2");
+
+    let mut registry = ProfileRegistry::default();
+    assert_eq!(registry.create("Work").unwrap(), "Work");
+    assert!(registry.create("work").is_err());
+    assert_eq!(registry.select("WORK"), "Work");
+    assert_eq!(registry.last, "Work");
+    assert_eq!(registry.select("paper"), "paper");
+    assert_eq!(registry.profiles, ["default", "Work", "paper"]);
+}
+
+#[test]
+fn running_instance_info_tolerates_missing_and_garbled_fields() {
+    use crate::data::RunningInstance;
+    use std::path::PathBuf;
+
+    let full = RunningInstance::from_info("4242 51515\n", PathBuf::from(".instance.lock"));
+    assert_eq!((full.pid, full.port), (Some(4242), Some(51515)));
+    let no_port = RunningInstance::from_info("4242", PathBuf::new());
+    assert_eq!((no_port.pid, no_port.port), (Some(4242), None));
+    let garbled = RunningInstance::from_info("pid? 99999999", PathBuf::new());
+    assert_eq!((garbled.pid, garbled.port), (None, None));
+    let empty = RunningInstance::from_info("", PathBuf::new());
+    assert_eq!((empty.pid, empty.port), (None, None));
+}
+
+#[test]
+fn housekeeping_keeps_everything_at_zero_days_and_only_drops_old_cache_versions() {
+    use crate::config::PERSISTENCE;
+    use crate::data::{is_stale_cache_file, retention_cutoff_ms};
+    use crate::utils::TimeUtils;
+
+    let now = 1_700_000_000_000;
+    assert_eq!(retention_cutoff_ms(now, 0), None);
+    assert_eq!(
+        retention_cutoff_ms(now, 30),
+        Some(now - 30 * TimeUtils::MS_IN_D)
+    );
+
+    let current = format!("kd_5m_v{}.bin", PERSISTENCE.kline.version);
+    assert!(!is_stale_cache_file(&current));
+    assert!(is_stale_cache_file("kd_5m_v1.bin"));
+    assert!(is_stale_cache_file("kd_1h_v4.5.bin"));
+    // Not kline caches: demo bundles and unrelated files stay
+    assert!(!is_stale_cache_file("demo_kd_5m_v4.bin"));
+    assert!(!is_stale_cache_file("kd_5m_v1.json"));
+    assert!(!is_stale_cache_file("notes.txt"));
+}
+
+#[test]
+fn cache_inspector_splits_database_size_by_candle_share() {
+    use crate::data::share_of_bytes;
+
+    assert_eq!(share_of_bytes(1_000, 250, 1_000), 250);
+    assert_eq!(share_of_bytes(1_000, 1_000, 1_000), 1_000);
+    // Empty database, and a count past the total (scan raced a write)
+    assert_eq!(share_of_bytes(1_000, 0, 0), 0);
+    assert_eq!(share_of_bytes(1_000, 2_000, 1_000), 1_000);
+    // No overflow on multi-GB databases with many rows
+    assert_eq!(
+        share_of_bytes(u64::MAX / 2, 3, 4),
+        ((u64::MAX / 2) as u128 * 3 / 4) as u64
+    );
+}
+
+#[test]
+fn external_signal_parses_alert_templates_and_rejects_incomplete_bodies() {
+    use crate::app::{Price, PriceLike};
+    use crate::data::{normalize_signal_pair, parse_external_signal, query_token};
+
+    assert_eq!(normalize_signal_pair("BINANCE:btcusdt.P"), "BTCUSDT");
+    assert_eq!(normalize_signal_pair("eth/usdt"), "ETHUSDT");
+
+    // TradingView-style: string prices, alias field names, default source and time
+    let signal = parse_external_signal(
+        r#"{"ticker": "BINANCE:SOLUSDT", "action": "SELL", "entry": "142.5", "tp": 130, "sl": ""}"#,
+        1_000,
+    )
+    .unwrap();
+    assert_eq!(signal.pair, "SOLUSDT");
+    assert_eq!(signal.direction, TradeDirection::Short);
+    assert_eq!(signal.price.map(|p| p.value()), Some(142.5));
+    assert_eq!(signal.target, Some(Price::new(130.0)));
+    assert_eq!(signal.stop, None);
+    assert_eq!((signal.time_ms, signal.source.as_str()), (1_000, "webhook"));
+
+    let explicit =
+        parse_external_signal(r#"{"pair": "BTCUSDT", "side": "buy", "time": "42"}"#, 1_000)
+            .unwrap();
+    assert_eq!(
+        (explicit.direction, explicit.time_ms),
+        (TradeDirection::Long, 42)
+    );
+
+    for bad in [
+        "not json",
+        "[1, 2]",
+        r#"{"side": "buy"}"#,
+        r#"{"pair": "BTCUSDT"}"#,
+        r#"{"pair": "BTCUSDT", "side": "hold"}"#,
+        r#"{"pair": "BTCUSDT", "side": "buy", "price": -1}"#,
+    ] {
+        assert!(parse_external_signal(bad, 0).is_err(), "{}", bad);
+    }
+
+    assert_eq!(query_token("/signal?a=1&token=abc"), Some("abc"));
+    assert_eq!(query_token("/signal"), None);
+}
+
+#[test]
+fn opportunity_script_filters_scores_and_rejects_ill_typed_expressions() {
+    use crate::app::Price;
+    use crate::models::{OpportunityScript, ScriptExpr, ScriptFields};
+
+    let op = opportunity("BTCUSDT", TradeDirection::Long);
+    let fields = ScriptFields {
+        op: &op,
+        current_price: Price::new(100.0),
+        volume_usd: None,
+        market_state: None,
+    };
+    let matches = |src: &str| ScriptExpr::parse_filter(src).unwrap().matches(&fields);
+
+    assert!(matches(r#"sample_size >= 40 && direction == "LONG""#));
+    assert!(matches("not (success_rate < 50) and rr * 2 = 4"));
+    assert!(!matches("sample_size > 40 or pair == 'ethusdt'"));
+    assert_eq!(matches(r#"grade <= "b" || custom"#), op.grade() <= 'B');
+    // Missing values fail every comparison
+    assert!(!matches("volume > 0") && !matches("volume <= 0"));
+
+    let score = ScriptExpr::parse_score("success_rate * rr").unwrap();
+    assert_eq!(score.score(&fields), Some(120.0));
+    assert_eq!(
+        ScriptExpr::parse_score("volume / 2")
+            .unwrap()
+            .score(&fields),
+        None
+    );
+
+    for bad in [
+        "aroi >",
+        "(aroi > 1",
+        "foo > 1",
+        "direction > 1",
+        "aroi",
+        r#""x" + 1 > 0"#,
+        "aroi > 1 aroi",
+        "'open",
+    ] {
+        assert!(ScriptExpr::parse_filter(bad).is_err(), "{}", bad);
+    }
+    assert!(ScriptExpr::parse_score("aroi > 1").is_err());
+    // Deep nesting and overlong scripts are refused instead of overflowing the stack
+    let deep = format!("{}aroi{} > 1", "(".repeat(10_000), ")".repeat(10_000));
+    assert!(ScriptExpr::parse_filter(&deep).is_err());
+    assert!(ScriptExpr::parse_filter(&format!("{}aroi > 1", "!".repeat(10_000))).is_err());
+    assert!(ScriptExpr::parse_filter(&format!("aroi{} > 1", " + 1".repeat(5_000))).is_err());
+
+    let mut script = OpportunityScript {
+        filter: "sample_size > 100".to_string(),
+        ..Default::default()
+    };
+    script.refresh();
+    assert!(script.is_filtering() && !script.passes(&fields));
+    // A broken filter shows its error and hides nothing
+    script.filter = "sample_size >".to_string();
+    script.refresh();
+    assert!(script.filter_error().is_some() && script.passes(&fields));
+}
+
+#[test]
+fn indicator_scripts_evaluate_window_functions_over_candles() {
+    use crate::models::{IndicatorExpr, ScriptExpr};
+
+    let candles: Vec<_> = (1..=6)
+        .map(|i| {
+            let c = i as f64;
+            candle(i, c, c + 1.0, c - 1.0, c, 10.0)
+        })
+        .collect();
+    let eval = |src: &str| IndicatorExpr::parse(src).unwrap().evaluate(&candles);
+    // Compares with NaN as missing (NaN != NaN)
+    let same = |a: Vec<f64>, b: &[f64]| {
+        a.len() == b.len()
+            && a.iter()
+                .zip(b)
+                .all(|(x, y)| (x.is_nan() && y.is_nan()) || (x - y).abs() < 1e-9)
+    };
+    let nan = f64::NAN;
+
+    assert!(same(eval("sma(close, 3)"), &[nan, nan, 2.0, 3.0, 4.0, 5.0]));
+    assert!(same(
+        eval("highest(high, 2)"),
+        &[nan, 3.0, 4.0, 5.0, 6.0, 7.0]
+    ));
+    assert!(same(
+        eval("lowest(low, 6)"),
+        &[nan, nan, nan, nan, nan, 0.0]
+    ));
+    assert!(same(
+        eval("close - prev(close, 1)"),
+        &[nan, 1.0, 1.0, 1.0, 1.0, 1.0]
+    ));
+    assert!(same(
+        eval("stdev(close, 2)"),
+        &[nan, 0.5, 0.5, 0.5, 0.5, 0.5]
+    ));
+    // Large offsets would cancel out a sum-of-squares variance
+    assert!(same(
+        eval("stdev(close + 100000000, 2)"),
+        &[nan, 0.5, 0.5, 0.5, 0.5, 0.5]
+    ));
+    assert!(same(
+        eval("ema(close, 1) * 2"),
+        &[2.0, 4.0, 6.0, 8.0, 10.0, 12.0]
+    ));
+    // Windows over a series that starts with gaps wait for a full window of values
+    assert!(same(
+        eval("sma(prev(close, 1), 2)"),
+        &[nan, nan, 1.5, 2.5, 3.5, 4.5]
+    ));
+
+    for bad in [
+        "close > open",
+        "sma(close)",
+        "sma(close, 0)",
+        "sma(close, volume)",
+        "sma(close, 2.5)",
+        "median(close, 3)",
+    ] {
+        assert!(IndicatorExpr::parse(bad).is_err(), "{}", bad);
+    }
+    // Window functions are for indicators only
+    assert!(ScriptExpr::parse_score("sma(aroi, 3)").is_err());
+    assert!(ScriptExpr::parse_score("max(aroi, 0)").is_ok());
+}
+
+#[test]
+fn rule_backtest_splits_walk_forward_trades_by_filter() {
+    use crate::{
+        app::Price,
+        engine::{HeatCalendar, WalkForwardTrade},
+        models::{ScriptExpr, TradeOutcome},
+    };
+
+    let trade = |direction, result| WalkForwardTrade {
+        opportunity: opportunity("BTCUSDT", direction),
+        entry: Price::new(100.0),
+        result,
+    };
+    let calendar = HeatCalendar {
+        pair: "BTCUSDT".to_string(),
+        weeks: Vec::new(),
+        trades: vec![
+            trade(TradeDirection::Long, Some((TradeOutcome::TargetHit, 0.10))),
+            trade(TradeDirection::Long, None),
+            trade(TradeDirection::Short, Some((TradeOutcome::StopHit, -0.05))),
+            trade(TradeDirection::Short, Some((TradeOutcome::Timeout, -0.01))),
+        ],
+    };
+
+    let filter = ScriptExpr::parse_filter(r#"direction == "long""#).unwrap();
+    let backtest = calendar.backtest_rule(&filter);
+    assert_eq!(backtest.all.opportunities, 4);
+    assert_eq!((backtest.matched.wins, backtest.matched.open), (1, 1));
+    assert_eq!(backtest.matched.avg_return(), Some(0.10));
+    assert_eq!(backtest.rejected.resolved(), 2);
+    assert!((backtest.rejected.avg_return().unwrap() + 0.03).abs() < 1e-12);
+    assert!((backtest.all.avg_return().unwrap() - 0.04 / 3.0).abs() < 1e-12);
+
+    // Volume is unknown historically, so a rule on it keeps nothing
+    let volume = ScriptExpr::parse_filter("volume > 0").unwrap();
+    assert_eq!(calendar.backtest_rule(&volume).matched.opportunities, 0);
+}
+
+#[test]
+fn zone_approaches_are_tallied_per_side_and_weight_barriers() {
+    use crate::{
+        domain::PairInterval,
+        models::{
+            Approach,
+            trading_model::{ApproachStats, tally_zone_approaches},
+        },
+    };
+
+    // Zone 100..102. From above: a wick in that closes back above (held), then a close
+    // inside followed by one below (broke). From below: a poke in that closes back below
+    // (held), then a close straight through (broke).
+    let closes = [105.0, 103.0, 101.0, 98.0, 97.0, 99.5, 104.0];
+    let lows = [104.0, 101.0, 100.5, 97.5, 96.0, 97.0, 99.0];
+    let highs = [106.0, 104.0, 103.5, 101.5, 98.0, 101.0, 104.5];
+    let candles: Vec<_> = (0..closes.len())
+        .map(|i| {
+            let open = if i == 0 { closes[0] } else { closes[i - 1] };
+            candle(i as i64, open, highs[i], lows[i], closes[i], 10.0)
+        })
+        .collect();
+    let ohlcv = OhlcvTimeSeries::from_candles(
+        PairInterval {
+            name: "TESTUSDT".to_string(),
+            interval_ms: 300_000,
+        },
+        candles,
+    );
+    let mut zones = vec![superzone(1, 100.0, 102.0)];
+    tally_zone_approaches(&mut zones, &ohlcv, &[(0, closes.len())]);
+    let strength = zones[0].strength;
+    assert_eq!(
+        (strength.from_above.tests, strength.from_above.held),
+        (2, 1)
+    );
+    assert_eq!(
+        (strength.from_below.tests, strength.from_below.held),
+        (2, 1)
+    );
+
+    let mut zone = superzone(2, 100.0, 102.0);
+    zone.strength.score = 0.8;
+    zone.strength.from_below = ApproachStats { tests: 4, held: 1 };
+    zone.strength.from_above = ApproachStats { tests: 2, held: 0 };
+    assert!((zone.barrier_score(Approach::FromBelow) - 0.2).abs() < 1e-12);
+    // Too few tests from above to judge: plain score
+    assert_eq!(zone.barrier_score(Approach::FromAbove), 0.8);
+}
+
+#[test]
+fn path_density_weighs_zone_volume_along_the_path() {
+    use crate::{app::Price, models::PATH_DENSITY_THIN};
+
+    // Ten 1-wide zones over 100..110 with an air pocket at 104..106 and a wall at 108..109
+    let mut core = make_core(100.0, 110.0, 10);
+    core.candle_bodies_vw = vec![1.0, 1.0, 1.0, 1.0, 0.0, 0.0, 1.0, 1.0, 3.0, 1.0];
+    let p = Price::new;
+
+    let profile = core.path_profile(p(102.5), p(108.5));
+    assert_eq!(profile.len(), 7);
+    assert_eq!(profile[0], (102.5, 103.0, 1.0));
+    assert_eq!(profile[2], (104.0, 105.0, 0.0));
+    assert_eq!(profile[6], (108.0, 108.5, 3.0));
+    assert!(profile[2].2 < PATH_DENSITY_THIN);
+
+    // (0.5 + 1 + 0 + 0 + 1 + 1 + 1.5) / 6, the same whichever way the trade runs
+    let up = core.path_density(p(102.5), p(108.5)).unwrap();
+    assert!((up - 5.0 / 6.0).abs() < 1e-12);
+    assert_eq!(core.path_density(p(108.5), p(102.5)), Some(up));
+
+    // Only the part inside the range counts; a path wholly outside has no density
+    assert_eq!(core.path_density(p(109.5), p(115.0)), Some(1.0));
+    assert_eq!(core.path_density(p(120.0), p(130.0)), None);
+}
+
+#[test]
+fn hit_times_summarize_a_long_tail() {
+    use crate::models::HitTimes;
+
+    // Four quick hits and one that took most of the 48-candle limit
+    let times = HitTimes::from_candles(vec![40, 6, 2, 8, 4], 48).unwrap();
+    assert_eq!((times.p25, times.median, times.p75), (4.0, 6.0, 8.0));
+    assert_eq!(times.histogram, vec![1, 2, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0]);
+    assert_eq!(times.hits(), 5);
+
+    // Quantiles interpolate between hits; a hit at the limit lands in the last slice
+    let times = HitTimes::from_candles(vec![1, 2, 48], 48).unwrap();
+    assert_eq!(times.p25, 1.5);
+    assert_eq!(times.histogram[11], 1);
+
+    assert!(HitTimes::from_candles(Vec::new(), 48).is_none());
+}
+
+#[test]
+fn excursions_measure_heat_and_run_up_to_target() {
+    use crate::{
+        app::{Price, StopPrice, TargetPrice},
+        domain::PairInterval,
+        models::{ExcursionStats, ScenarioSimulator, scenario_simulator::Excursion},
+    };
+
+    // Journey from 0 dips 3% then reaches +5%; journey from 4 dips 5% and runs out of time
+    let bars = [
+        (100.0, 100.0, 100.0, 100.0),
+        (100.0, 101.0, 98.0, 100.0),
+        (100.0, 103.0, 97.0, 102.0),
+        (102.0, 106.0, 99.0, 105.0),
+        (105.0, 105.0, 100.0, 100.0),
+        (100.0, 102.0, 95.0, 98.0),
+        (98.0, 101.0, 96.0, 99.0),
+    ];
+    let candles: Vec<_> = bars
+        .iter()
+        .enumerate()
+        .map(|(i, &(o, h, l, c))| candle(i as i64, o, h, l, c, 10.0))
+        .collect();
+    let ohlcv = OhlcvTimeSeries::from_candles(
+        PairInterval {
+            name: "TESTUSDT".to_string(),
+            interval_ms: 300_000,
+        },
+        candles,
+    );
+    let matches = [(0, 0.0), (4, 0.0)];
+    let entry = Price::new(200.0);
+
+    let stats = ScenarioSimulator::estimate_excursions(
+        &ohlcv,
+        &matches,
+        entry,
+        TargetPrice::new(210.0),
+        3,
+        TradeDirection::Long,
+    )
+    .unwrap();
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+    assert!(close(stats.adverse_median, 0.04));
+    assert!(close(stats.adverse_p75, 0.045));
+    assert!(close(stats.winner_adverse_median.unwrap(), 0.03));
+    assert!(close(stats.favorable_median, 0.04));
+    assert!(close(stats.favorable_p75, 0.05));
+
+    // The stop doesn't cut a journey's excursion short
+    let journeys = ScenarioSimulator::replay_journeys(
+        &ohlcv,
+        &matches,
+        entry,
+        TargetPrice::new(210.0),
+        StopPrice::new(198.0),
+        3,
+        TradeDirection::Long,
+    );
+    assert!(close(journeys[1].excursion.adverse, 0.05));
+
+    // Shorts flip the sides: the 5% drop from 4 reaches a -5% target after 2% of heat
+    let short = ScenarioSimulator::estimate_excursions(
+        &ohlcv,
+        &matches[1..],
+        entry,
+        TargetPrice::new(190.0),
+        3,
+        TradeDirection::Short,
+    )
+    .unwrap();
+    assert!(close(short.winner_adverse_median.unwrap(), 0.02));
+
+    assert!(
+        ExcursionStats::from_journeys(&[(Excursion::default(), false)])
+            .unwrap()
+            .winner_adverse_median
+            .is_none()
+    );
+}
+
+#[test]
+fn break_even_stop_closes_journeys_that_return_to_entry_after_1r() {
+    use crate::{
+        app::{
+            MomentumPct, Price, Prob, StopManagement, StopPrice, TargetPrice, VolRatio,
+            VolatilityPct,
+        },
+        domain::PairInterval,
+        models::{MarketState, ScenarioSimulator},
+    };
+
+    // Long from 100, stop 98 (1R = +2%), target 104. The journey from 0 reaches 1R and runs
+    // on to target; the one from 3 reaches 1R, dips back under entry, then hits target.
+    let bars = [
+        (100.0, 100.0, 100.0, 100.0),
+        (100.0, 102.5, 100.5, 102.0),
+        (102.0, 104.5, 101.0, 104.0),
+        (104.0, 104.0, 100.0, 100.0),
+        (100.0, 102.2, 100.2, 101.0),
+        (101.0, 101.0, 99.0, 99.5),
+        (99.5, 104.5, 99.4, 104.0),
+    ];
+    let candles: Vec<_> = bars
+        .iter()
+        .enumerate()
+        .map(|(i, &(o, h, l, c))| candle(i as i64, o, h, l, c, 10.0))
+        .collect();
+    let ohlcv = OhlcvTimeSeries::from_candles(
+        PairInterval {
+            name: "TESTUSDT".to_string(),
+            interval_ms: 300_000,
+        },
+        candles,
+    );
+    let market_state = MarketState {
+        volatility_pct: VolatilityPct::new(0.01),
+        momentum_pct: MomentumPct::new(0.0),
+        relative_volume: VolRatio::new(1.0),
+    };
+    let simulate = |management| {
+        ScenarioSimulator::estimate_empirical_outcome(
+            &ohlcv,
+            &[(0, 0.0), (3, 0.0)],
+            market_state,
+            Price::new(100.0),
+            TargetPrice::new(104.0),
+            StopPrice::new(98.0),
+            5,
+            TradeDirection::Long,
+            management,
+        )
+        .unwrap()
+    };
+
+    let fixed = simulate(StopManagement::Fixed);
+    assert_eq!(fixed.success_rate, Prob::new(1.0));
+    assert_eq!(fixed.break_even_rate, Prob::new(0.0));
+    assert!(fixed.fixed_success_rate.is_none());
+
+    let managed = simulate(StopManagement::BreakEvenAt1R);
+    assert_eq!(managed.success_rate, Prob::new(0.5));
+    assert_eq!(managed.break_even_rate, Prob::new(0.5));
+    assert_eq!(managed.fixed_success_rate, Some(Prob::new(1.0)));
+    // The exit at entry is neither a win nor a 1R loss
+    assert!((managed.expectancy_r() - 1.0).abs() < 1e-12);
+    assert!((managed.fixed_expectancy_r().unwrap() - 2.0).abs() < 1e-12);
+    assert!((managed.kelly_fraction() - 1.0).abs() < 1e-12);
+    assert!((managed.avg_pnl_pct.value() - 0.02).abs() < 1e-12);
+}
+
+/// 5m series whose hourly log returns are `returns` (the close only moves on the hour).
+fn hourly_return_series(pair: &str, returns: &[f64]) -> OhlcvTimeSeries {
+    let mut close = 100.0;
+    let mut hourly = vec![close];
+    for r in returns {
+        close *= r.exp();
+        hourly.push(close);
+    }
+    let candles = hourly
+        .iter()
+        .enumerate()
+        .flat_map(|(h, &c)| (0..12).map(move |k| candle(h as i64 * 12 + k, c, c, c, c, 10.0)))
+        .collect();
+    let pair = crate::domain::PairInterval {
+        name: pair.to_string(),
+        interval_ms: 300_000,
+    };
+    OhlcvTimeSeries::from_candles(pair, candles)
+}
+
+#[test]
+fn basket_skips_correlated_legs_and_respects_the_risk_budget() {
+    use crate::{
+        app::Prob,
+        engine::{
+            Basket, BasketConstraints, BasketSkip, CorrelationInput, CorrelationMatrix,
+            optimize_basket,
+        },
+    };
+
+    // Walsh sequences: mean zero and exactly orthogonal, so the correlations are known
+    let walsh = |k: u32| -> Vec<f64> {
+        (0..64u32)
+            .map(|h| {
+                if (h & k).count_ones() % 2 == 0 {
+                    0.01
+                } else {
+                    -0.01
+                }
+            })
+            .collect()
+    };
+    let mix =
+        |a: &[f64], b: &[f64]| -> Vec<f64> { a.iter().zip(b).map(|(a, b)| a + 0.25 * b).collect() };
+    let btc = walsh(1);
+    // ETH and SOL both track BTC; XRP, DOGE and ADA move on their own; NEW has no candles
+    let input = CorrelationInput {
+        pairs: [
+            "BTCUSDT", "ETHUSDT", "SOLUSDT", "XRPUSDT", "DOGEUSDT", "ADAUSDT", "NEWUSDT",
+        ]
+        .iter()
+        .map(|p| p.to_string())
+        .collect(),
+        series: vec![
+            hourly_return_series("BTCUSDT", &btc),
+            hourly_return_series("ETHUSDT", &mix(&btc, &walsh(2))),
+            hourly_return_series("SOLUSDT", &mix(&btc, &walsh(3))),
+            hourly_return_series("XRPUSDT", &walsh(4)),
+            hourly_return_series("DOGEUSDT", &walsh(5)),
+            hourly_return_series("ADAUSDT", &walsh(6)),
+        ],
+    };
+    let matrix = CorrelationMatrix::build(&input);
+    let tracking = 1.0 / 1.0625_f64.sqrt();
+    let btc_sol = matrix.get("SOLUSDT", "BTCUSDT").unwrap();
+    assert!((btc_sol - tracking).abs() < 1e-9);
+    assert!(matrix.get("XRPUSDT", "BTCUSDT").unwrap().abs() < 1e-9);
+    // Too little history is unknown, not unrelated
+    assert_eq!(matrix.get("NEWUSDT", "BTCUSDT"), None);
+    assert!(matrix.covers(["BTCUSDT", "NEWUSDT"].into_iter()));
+    assert!(!matrix.covers(["LTCUSDT"].into_iter()));
+
+    // 2:1 reward / risk, so each Kelly stake is (3p - 1) / 2
+    let basket_op = |pair: &str, direction, success_rate| {
+        let mut op = opportunity(pair, direction);
+        op.id = format!("{}-{}-{}", pair, direction, success_rate);
+        op.simulation.success_rate = Prob::new(success_rate);
+        op
+    };
+    let ops = vec![
+        basket_op("BTCUSDT", TradeDirection::Long, 0.7),
+        basket_op("BTCUSDT", TradeDirection::Long, 0.6),
+        basket_op("NEWUSDT", TradeDirection::Long, 0.68),
+        basket_op("ETHUSDT", TradeDirection::Long, 0.65),
+        basket_op("SOLUSDT", TradeDirection::Short, 0.62),
+        basket_op("XRPUSDT", TradeDirection::Long, 0.55),
+        basket_op("DOGEUSDT", TradeDirection::Long, 0.5),
+        basket_op("ADAUSDT", TradeDirection::Long, 0.3),
+    ];
+    let pairs = |basket: &Basket| -> Vec<String> {
+        basket
+            .legs
+            .iter()
+            .map(|leg| leg.op.pair_name.clone())
+            .collect()
+    };
+
+    let constraints = BasketConstraints {
+        positions: 3,
+        max_correlation: 0.8,
+        stake_budget: 1.0,
+    };
+    let basket = optimize_basket(&ops, &matrix, &constraints);
+    // The short SOL leg offsets the long BTC one instead of doubling it
+    assert_eq!(pairs(&basket), ["BTCUSDT", "SOLUSDT", "XRPUSDT"]);
+    assert!((basket.legs[0].op.simulation.success_rate.value() - 0.7).abs() < 1e-12);
+    let stakes: Vec<f64> = basket.legs.iter().map(|leg| leg.stake).collect();
+    for (stake, expected) in stakes.iter().zip([0.55, 0.43, 0.325]) {
+        assert!((stake - expected).abs() < 1e-12);
+    }
+    assert_eq!(
+        basket.legs[1].max_correlation,
+        Some(("BTCUSDT".to_string(), -btc_sol))
+    );
+    let skipped: Vec<(&str, &BasketSkip)> = basket
+        .skipped
+        .iter()
+        .map(|(op, skip)| (op.pair_name.as_str(), skip))
+        .collect();
+    assert_eq!(skipped.len(), 2);
+    assert_eq!(
+        skipped[0],
+        (
+            "NEWUSDT",
+            &BasketSkip::UnknownCorrelation {
+                with: "BTCUSDT".to_string()
+            }
+        )
+    );
+    assert!(matches!(
+        skipped[1],
+        ("ETHUSDT", BasketSkip::Correlated { with, correlation })
+            if with == "BTCUSDT" && (correlation - tracking).abs() < 1e-9
+    ));
+    let risk_sq =
+        0.55_f64.powi(2) + 0.43_f64.powi(2) + 0.325_f64.powi(2) - 2.0 * 0.55 * 0.43 * btc_sol;
+    assert!((basket.risk - risk_sq.sqrt()).abs() < 1e-9);
+    assert!((basket.expectancy - (1.1 * 0.55 + 0.86 * 0.43 + 0.65 * 0.325)).abs() < 1e-12);
+
+    // Every Kelly stake above the SOL one breaks a tight budget on its own
+    let tight = BasketConstraints {
+        stake_budget: 0.44,
+        ..constraints
+    };
+    let basket = optimize_basket(&ops, &matrix, &tight);
+    assert_eq!(pairs(&basket), ["SOLUSDT"]);
+    let over_budget: Vec<_> = basket
+        .skipped
+        .iter()
+        .filter(|(_, skip)| *skip == BasketSkip::OverBudget)
+        .map(|(op, _)| op.pair_name.as_str())
+        .collect();
+    assert_eq!(
+        over_budget,
+        ["BTCUSDT", "NEWUSDT", "ETHUSDT", "XRPUSDT", "DOGEUSDT"]
+    );
+}
+
+#[test]
+fn pair_categories_come_from_the_base_asset() {
+    use crate::{
+        app::Price,
+        domain::PairCategory,
+        models::{ScriptExpr, ScriptFields},
+    };
+
+    assert_eq!(PairCategory::of_pair("BTCUSDT"), PairCategory::Layer1);
+    assert_eq!(PairCategory::of_pair("ETHBTC"), PairCategory::Layer1);
+    assert_eq!(PairCategory::of_pair("PEPEFDUSD"), PairCategory::Meme);
+    assert_eq!(PairCategory::of_pair("MORPHOBNB"), PairCategory::DeFi);
+    // The quote is stripped first, so a stablecoin base still reads as a stablecoin
+    assert_eq!(PairCategory::of_pair("USDTBRL"), PairCategory::Stable);
+    assert_eq!(PairCategory::of_pair("NOSUCHUSDT"), PairCategory::Other);
+    assert_eq!(PairCategory::of_pair("GIBBERISH"), PairCategory::Other);
+
+    let op = opportunity("DOGEUSDT", TradeDirection::Long);
+    let fields = ScriptFields {
+        op: &op,
+        current_price: Price::new(100.0),
+        volume_usd: None,
+        market_state: None,
+    };
+    let matches = |src: &str| ScriptExpr::parse_filter(src).unwrap().matches(&fields);
+    assert!(matches(r#"category == "meme""#));
+    assert!(!matches(r#"category == "L1""#));
+}
+
+#[test]
+fn sector_strength_ranks_sectors_by_positive_expectancy_targets() {
+    use crate::{
+        app::MomentumPct,
+        domain::PairCategory,
+        engine::{PairPulse, build_sector_strength},
+    };
+
+    let pulse = |pair: &str, momentum: Option<f64>, evs: &[f64]| PairPulse {
+        pair: pair.to_string(),
+        trend: momentum.map(|m| (MomentumPct::new(m), VolatilityPct::new(0.01))),
+        opportunities: evs.len(),
+        positive_ev: evs.iter().filter(|&&e| e > 0.0).count(),
+        best_expectancy: evs.iter().copied().max_by(f64::total_cmp),
+    };
+    let sectors = build_sector_strength(&[
+        pulse("BTCUSDT", Some(0.02), &[0.5, -0.2]),
+        pulse("ETHUSDT", Some(-0.01), &[]),
+        pulse("SOLUSDT", None, &[0.1]),
+        pulse("PEPEUSDT", Some(0.05), &[0.3, 0.8]),
+        pulse("DOGEUSDT", Some(0.03), &[0.4]),
+        pulse("USDCUSDT", Some(0.0), &[]),
+    ]);
+
+    let order: Vec<PairCategory> = sectors.iter().map(|s| s.category).collect();
+    assert_eq!(
+        order,
+        [
+            PairCategory::Meme,
+            PairCategory::Layer1,
+            PairCategory::Stable
+        ]
+    );
+
+    let meme = &sectors[0];
+    assert_eq!(
+        (meme.pairs, meme.opportunities, meme.positive_ev),
+        (2, 3, 3)
+    );
+    assert!((meme.momentum.value() - 0.04).abs() < 1e-12);
+    assert_eq!(meme.best, Some(("PEPEUSDT".to_string(), 0.8)));
+
+    // SOL has no history yet: it counts towards pairs and targets but not the averages
+    let l1 = &sectors[1];
+    assert_eq!((l1.pairs, l1.opportunities, l1.positive_ev), (3, 3, 2));
+    assert!((l1.momentum.value() - 0.005).abs() < 1e-12);
+    assert!((l1.breadth - 0.5).abs() < 1e-12);
+    assert!((l1.volatility.value() - 0.01).abs() < 1e-12);
+
+    assert_eq!(sectors[2].best, None);
+
+    // A series that stopped updating has no trend rather than a stale one
+    let candles: Vec<_> = (0..40)
+        .map(|i| candle(i, 100.0, 101.0, 99.0, 100.0 + i as f64, 10.0))
+        .collect();
+    let ohlcv = OhlcvTimeSeries::from_candles(
+        crate::domain::PairInterval {
+            name: "PEPEUSDT".to_string(),
+            interval_ms: 300_000,
+        },
+        candles,
+    );
+    let last = ohlcv.timestamps[39];
+    assert!(PairPulse::trend_of(&ohlcv, last + DurationMs::new(300_000)).is_some());
+    assert!(PairPulse::trend_of(&ohlcv, last + DurationMs::new(3 * 3_600_000)).is_none());
+}
@@ -3,28 +3,50 @@ const RISK_REWARD_TESTS: &[f64] = &[1.0, 1.5, 2.0, 3.0, 4.0, 6.0, 10.0];
 const MIN_JOURNEY_TIME: Duration = Duration::from_mins(50);
 const MAX_JOURNEY_TIME: Duration = Duration::from_secs(86400 * 90);
 
+/// Marks ids built by [`TradeOpportunity::stable_id`]; older ledgers are re-keyed on load.
+pub(crate) const OPPORTUNITY_ID_PREFIX: &str = "op2-";
+/// Targets outside every zone are identified by their 0.1% log step instead.
+const ID_TARGET_STEP: f64 = 0.001;
+/// Zone bounds are bucketed coarser: reclassification nudges them by a bin or so.
+const ID_ZONE_STEP: f64 = 0.005;
+/// Pessimistic success rate must beat break-even by this much for an 'A'.
+const GRADE_A_EDGE: f64 = 0.10;
+
 use {
     crate::{
         app::{
-            AroiPct, DurationMs, JourneySettings, OptimalSearchSettings, Pct, PhPct, Price, RoiPct,
-            Sigma, StopPrice, TargetPrice, TradeProfile, ZoneClassificationConfig, ZoneParams,
+            AroiPct, BASE_INTERVAL, CoverageBand, CoverageTargets, DurationMs, EvidenceSettings,
+            JourneySettings, OptimalSearchSettings, Pct, PhPct, Price, PriceLike, RoiPct, Sigma,
+            StickyScore, StopManagement, StopMode, StopPrice, TargetPrice, TimestampMs,
+            TradeProfile, ZoneClassificationConfig, ZoneParams,
         },
         engine::StationId,
-        models::{EmpiricalOutcomeStats, MarketState, OptimizationStrategy},
+        models::{EmpiricalOutcomeStats, ExcursionStats, MarketState, OptimizationStrategy},
         ui::UI_TEXT,
+        utils::{TimeUtils, vec_heap_bytes},
     },
     chrono::{DateTime, Utc},
     serde::{Deserialize, Serialize},
-    std::{fmt, time::Duration},
+    std::{fmt, sync::Arc, time::Duration},
+    uuid::Uuid,
 };
 
-#[cfg(not(target_arch = "wasm32"))]
 use chrono::Duration as ChronoDuration;
 
 mod profile {
     use super::*;
+    pub const BENCHMARK_AROI: AroiPct = AroiPct::new(0.05);
     pub const MIN_AROI: AroiPct = AroiPct::new(0.20);
     pub const MIN_ROI: RoiPct = RoiPct::new(0.001);
+    pub const STOP_MANAGEMENT: StopManagement = StopManagement::Fixed;
+    pub const STOP_MODE: StopMode = StopMode::RiskReward;
+    pub const ZONE_STOP_BUFFER: Pct = Pct::new(0.002);
+}
+
+mod evidence {
+    pub const LOW_EVIDENCE_PENALTY: f64 = 0.5;
+    pub const LOW_EVIDENCE_SAMPLE_SIZE: usize = 20;
+    pub const MIN_SAMPLE_SIZE: usize = 8;
 }
 
 mod optimization {
@@ -50,6 +72,15 @@ pub(crate) const DEFAULT_JOURNEY_SETTINGS: JourneySettings = JourneySettings {
     profile: TradeProfile {
         min_roi_pct: profile::MIN_ROI,
         min_aroi_pct: profile::MIN_AROI,
+        stop_mode: profile::STOP_MODE,
+        stop_management: profile::STOP_MANAGEMENT,
+        zone_stop_buffer: profile::ZONE_STOP_BUFFER,
+        benchmark_aroi_pct: profile::BENCHMARK_AROI,
+    },
+    evidence: EvidenceSettings {
+        low_evidence_penalty: evidence::LOW_EVIDENCE_PENALTY,
+        low_evidence_sample_size: evidence::LOW_EVIDENCE_SAMPLE_SIZE,
+        min_sample_size: evidence::MIN_SAMPLE_SIZE,
     },
     optimization: OptimalSearchSettings {
         diversity_cut_off: optimization::DIVERSITY_CUT_OFF,
@@ -73,6 +104,10 @@ mod sticky {
     pub const SMOOTH_PCT: PhPct = PhPct::new(0.02);
     pub const VIABILITY_PCT: PhPct = PhPct::new(0.001);
 }
+mod coverage {
+    pub const MIN_PCT: f64 = 5.0;
+    pub const MAX_PCT: f64 = 30.0;
+}
 mod reversal {
     use super::*;
     pub const GAP_PCT: PhPct = PhPct::new(0.0);
@@ -88,14 +123,54 @@ pub(crate) const DEFAULT_ZONE_CONFIG: ZoneClassificationConfig = ZoneClassificat
         smooth_pct: sticky::SMOOTH_PCT,
         viability_pct: sticky::VIABILITY_PCT,
     },
-    reversal: ZoneParams {
+    low_wick: ZoneParams {
+        gap_pct: reversal::GAP_PCT,
+        sigma: reversal::SIGMA,
+        smooth_pct: reversal::SMOOTH_PCT,
+        viability_pct: reversal::VIABILITY_PCT,
+    },
+    high_wick: ZoneParams {
         gap_pct: reversal::GAP_PCT,
         sigma: reversal::SIGMA,
         smooth_pct: reversal::SMOOTH_PCT,
         viability_pct: reversal::VIABILITY_PCT,
     },
+    sticky_score: StickyScore::Volume,
+};
+
+impl Default for ZoneClassificationConfig {
+    fn default() -> Self {
+        DEFAULT_ZONE_CONFIG
+    }
+}
+
+pub(crate) const DEFAULT_COVERAGE_TARGETS: CoverageTargets = CoverageTargets {
+    sticky: CoverageBand {
+        min_pct: coverage::MIN_PCT,
+        max_pct: coverage::MAX_PCT,
+    },
+    support: CoverageBand {
+        min_pct: coverage::MIN_PCT,
+        max_pct: coverage::MAX_PCT,
+    },
+    resistance: CoverageBand {
+        min_pct: coverage::MIN_PCT,
+        max_pct: coverage::MAX_PCT,
+    },
 };
 
+impl Default for CoverageTargets {
+    fn default() -> Self {
+        DEFAULT_COVERAGE_TARGETS
+    }
+}
+
+impl Default for TradeProfile {
+    fn default() -> Self {
+        DEFAULT_JOURNEY_SETTINGS.profile
+    }
+}
+
 impl OptimizationStrategy {
     pub fn objective_score_simple(&self, avg_pnl_pct: RoiPct, duration: DurationMs) -> f64 {
         let mean = avg_pnl_pct.value();
@@ -150,6 +225,13 @@ pub(crate) struct TradeVariant {
     pub stop_price: StopPrice,
 }
 
+/// One take-profit level of a laddered target, simulated against the same stop and journeys.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct TargetRung {
+    pub target_price: TargetPrice,
+    pub simulation: EmpiricalOutcomeStats,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub(crate) enum TradeDirection {
     Long,
@@ -191,6 +273,28 @@ impl fmt::Display for TradeOutcome {
     }
 }
 
+/// User note pinned to an opportunity: text with an arrow to a price on the plot. Stored
+/// by opportunity id, so it comes back whenever that opportunity is selected.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct OpportunityAnnotation {
+    pub price: Price,
+    pub text: String,
+    pub created_at: TimestampMs,
+}
+
+/// What identifies a target in an opportunity id: the zone it lies in, or its price when it
+/// lies in none.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum TargetAnchor {
+    Zone { bottom: Price, top: Price },
+    Price(TargetPrice),
+}
+
+/// Index of the log-spaced bucket of width `step` that `price` falls in.
+fn log_step(price: f64, step: f64) -> i64 {
+    (price.ln() / step.ln_1p()).round() as i64
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct TradeOpportunity {
     pub id: String,
@@ -202,6 +306,8 @@ pub(crate) struct TradeOpportunity {
     pub target_price: TargetPrice,
     pub stop_price: StopPrice,
     pub max_duration: DurationMs,
+    /// Volatility adjustment already applied to `max_duration` (1.0 = none)
+    pub duration_vol_factor: f64,
     pub avg_duration: DurationMs,
     pub strategy: OptimizationStrategy,
     pub station_id: StationId,
@@ -209,9 +315,75 @@ pub(crate) struct TradeOpportunity {
     pub visuals: Option<VisualFluff>,
     pub simulation: EmpiricalOutcomeStats,
     pub variants: Vec<TradeVariant>,
+    /// Laddered targets across the next zones in the trade direction (empty unless enabled)
+    pub ladder: Vec<TargetRung>,
+    /// Label of the user-drawn zone this target was simulated for (None for engine-found targets)
+    #[serde(default)]
+    pub custom_zone: Option<String>,
+    /// Historical volume between start and target relative to the average zone (<1 thin)
+    #[serde(default)]
+    pub path_density: Option<f64>,
+    /// Heat and run-up of the matched journeys on the way to target
+    #[serde(default)]
+    pub excursions: Option<ExcursionStats>,
+    /// Historical analogues (candle index, distance) of the run that produced this
+    /// opportunity. Session-only: the indices point into this session's series.
+    #[serde(skip)]
+    pub matches: Arc<[(usize, f64)]>,
 }
 
 impl TradeOpportunity {
+    /// Deterministic id from what makes two trades the same trade: pair, direction, strategy,
+    /// station and target zone. PH, stop, timings and the target's drift inside its zone are
+    /// left out, so a re-run keeps the id, in this session or after a restart.
+    pub(crate) fn stable_id(
+        pair_name: &str,
+        direction: TradeDirection,
+        strategy: OptimizationStrategy,
+        station_id: StationId,
+        anchor: TargetAnchor,
+    ) -> String {
+        let target = match anchor {
+            // The zone's bounds rather than its id: ids are numbered afresh every session
+            TargetAnchor::Zone { bottom, top } => format!(
+                "zone{}-{}",
+                log_step(bottom.value(), ID_ZONE_STEP),
+                log_step(top.value(), ID_ZONE_STEP)
+            ),
+            TargetAnchor::Price(target_price) => {
+                log_step(target_price.value(), ID_TARGET_STEP).to_string()
+            }
+        };
+        let key = format!(
+            "{}|{}|{:?}|{:?}|{}",
+            pair_name, direction, strategy, station_id, target
+        );
+        let uuid = Uuid::new_v5(&Uuid::NAMESPACE_OID, key.as_bytes());
+        format!("{}{}", OPPORTUNITY_ID_PREFIX, uuid)
+    }
+
+    /// [`Self::stable_id`] anchored on the target price, for when the zones it was found
+    /// against are gone (legacy ledgers, id collisions).
+    pub(crate) fn identity_id(&self) -> String {
+        Self::stable_id(
+            &self.pair_name,
+            self.direction,
+            self.strategy,
+            self.station_id,
+            TargetAnchor::Price(self.target_price),
+        )
+    }
+
+    /// First 8 characters of the id after the scheme prefix, for display.
+    #[cfg(debug_assertions)]
+    pub(crate) fn short_id(&self) -> &str {
+        let id = self
+            .id
+            .strip_prefix(OPPORTUNITY_ID_PREFIX)
+            .unwrap_or(&self.id);
+        id.get(..8).unwrap_or(id)
+    }
+
     /// Returns true if opportunities can be compared/merged.
     /// Invariant: comparable iff same pair, direction, strategy, and station.
     pub(crate) fn is_comparable_to(&self, other: &Self) -> bool {
@@ -229,6 +401,21 @@ impl TradeOpportunity {
         );
     }
 
+    /// Approximate heap footprint (variants, ladder and plot volume profile).
+    pub(crate) fn heap_bytes(&self) -> usize {
+        vec_heap_bytes(&self.variants)
+            + vec_heap_bytes(&self.ladder)
+            + self
+                .visuals
+                .as_ref()
+                .map_or(0, |v| vec_heap_bytes(&v.volume_profile))
+    }
+
+    /// When the time limit runs out and the trade counts as timed out.
+    pub(crate) fn expires_at(&self) -> DateTime<Utc> {
+        self.created_at + ChronoDuration::from(self.max_duration)
+    }
+
     pub(crate) fn calc_quality_score(&self) -> f64 {
         self.strategy
             .objective_score_simple(self.expected_roi(), self.avg_duration)
@@ -242,7 +429,7 @@ impl TradeOpportunity {
         current_low: Price,
         current_time: DateTime<Utc>,
     ) -> Option<TradeOutcome> {
-        if current_time > self.created_at + ChronoDuration::from(self.max_duration) {
+        if current_time > self.expires_at() {
             return Some(TradeOutcome::Timeout);
         }
 
@@ -269,6 +456,14 @@ impl TradeOpportunity {
         None
     }
 
+    /// Simulation window expressed in base-interval candles.
+    pub(crate) fn max_duration_candles(&self) -> usize {
+        TimeUtils::duration_to_candles(
+            Duration::from_millis(self.max_duration.value().max(0) as u64),
+            BASE_INTERVAL.as_millis() as i64,
+        )
+    }
+
     pub(crate) fn variant_count(&self) -> usize {
         self.variants.len()
     }
@@ -279,6 +474,30 @@ impl TradeOpportunity {
         profile.is_worthwhile(roi, aroi)
     }
 
+    /// True when the backing simulation sample is too thin to trust at face value.
+    pub(crate) fn is_low_evidence(&self, evidence: &EvidenceSettings) -> bool {
+        evidence.is_low_evidence(self.simulation.sample_size)
+    }
+
+    /// Letter grade from how far the pessimistic success rate (rate minus its 95% margin)
+    /// clears the break-even rate implied by the reward-to-risk ratio. 'D' = negative expected
+    /// return.
+    pub(crate) fn grade(&self) -> char {
+        if self.expected_roi().value() <= 0.0 {
+            return 'D';
+        }
+        let sim = &self.simulation;
+        let break_even = 1.0 / (1.0 + sim.risk_reward_ratio.max(0.0));
+        let edge = sim.success_rate.value() - sim.success_rate_margin().value() - break_even;
+        if edge >= GRADE_A_EDGE {
+            'A'
+        } else if edge >= 0.0 {
+            'B'
+        } else {
+            'C'
+        }
+    }
+
     pub(crate) fn expected_roi(&self) -> RoiPct {
         self.simulation.avg_pnl_pct
     }
@@ -293,10 +512,24 @@ impl TradeOpportunity {
         RoiPct::new(base_roi.value() + price_drift_pct)
     }
 
+    /// Signed price move from entry to `price` as a fraction (negative = below entry). A short's
+    /// target is a negative move and its stop a positive one.
+    pub(crate) fn price_move<P: PriceLike>(&self, price: &P) -> f64 {
+        if !self.start_price.is_positive() {
+            return 0.0;
+        }
+        (price.value() - self.start_price.value()) / self.start_price.value()
+    }
+
     pub(crate) fn live_annualized_roi(&self, current_price: Price) -> AroiPct {
         let roi = self.live_roi(current_price);
         TradeProfile::calc_annualized_roi(roi, self.avg_duration)
     }
+
+    /// [`Self::live_annualized_roi`] above the profile's benchmark rate.
+    pub(crate) fn live_excess_aroi(&self, current_price: Price, profile: &TradeProfile) -> AroiPct {
+        profile.excess_aroi(self.live_annualized_roi(current_price))
+    }
 }
 
 impl fmt::Display for TradeOpportunity {
@@ -1,18 +1,46 @@
 use {
     crate::{
-        app::{Price, ZoneClassificationConfig, ZoneParams},
+        app::{
+            CoverageTargets, Pct, Price, PriceLike, Sigma, StickyScore, StopPrice, TargetPrice,
+            ZoneClassificationConfig, ZoneParams,
+        },
         models::{
-            CVACore, DEFAULT_ZONE_CONFIG, DisplaySegment, OhlcvTimeSeries, RangeGapFinder,
-            SEGMENT_MERGE_TOLERANCE_MS, ScoreType, TradeOpportunity,
+            AnomalyMask, CVACore, DisplaySegment, OhlcvTimeSeries, RangeGapFinder,
+            SEGMENT_MERGE_TOLERANCE, ScoreType, TargetAnchor, TradeDirection, TradeOpportunity,
+            VolTermStructure, ZoneEdit,
+        },
+        utils::{mean_and_stddev, normalize_max, smooth_data, vec_heap_bytes},
+    },
+    serde::{Deserialize, Serialize},
+    std::{
+        borrow::Cow,
+        sync::{
+            Arc,
+            atomic::{AtomicU64, Ordering},
         },
-        utils::{mean_and_stddev, normalize_max, smooth_data},
     },
-    std::sync::Arc,
 };
 
 #[cfg(debug_assertions)]
 use crate::config::DF;
 
+/// Minimum price overlap (intersection / union) for a recalculated zone to keep a previous zone's id.
+const ZONE_ID_MATCH_MIN_OVERLAP: f64 = 0.5;
+
+static NEXT_ZONE_ID: AtomicU64 = AtomicU64::new(1);
+
+const AUTO_BALANCE_MAX_ITERATIONS: usize = 50;
+const AUTO_BALANCE_SIGMA_STEP: f64 = 0.1;
+const AUTO_BALANCE_MAX_SIGMA: f64 = 5.0;
+
+/// Touching candles closer together than this count as a single test of a reversal zone.
+const ZONE_TOUCH_MERGE_CANDLES: usize = 3;
+/// A touch's weight halves for every this many days of age.
+const ZONE_TOUCH_HALF_LIFE_DAYS: f64 = 30.0;
+const MS_PER_DAY: f64 = 86_400_000.0;
+/// Tests from one side needed before that side's hold rate replaces the plain strength score.
+const ZONE_APPROACH_MIN_TESTS: usize = 3;
+
 /// Represents a clustered "Island" of activity.
 #[derive(Debug, Clone)]
 pub(crate) struct TargetZone {
@@ -70,11 +98,195 @@ pub struct Zone {
 }
 
 /// Aggregates one or more contiguous zones to reduce visual noise.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub(crate) struct SuperZone {
+    /// Stable across recalculations while the zone keeps substantially the same price range
+    pub id: u64,
     pub price_bottom: Price,
     pub price_top: Price,
     pub price_center: Price,
+    /// Touch and rejection stats for reversal (wick) zones; approach tallies for all zones
+    pub strength: ZoneStrength,
+}
+
+/// How convincingly price has been rejected from a reversal zone.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub(crate) struct ZoneStrength {
+    /// Distinct touch events (runs of touching candles count once)
+    pub touches: usize,
+    /// Touches weighted by age (see `ZONE_TOUCH_HALF_LIFE_DAYS`)
+    pub recency_weighted: f64,
+    /// Mean wick length of the touches, as a fraction of price
+    pub avg_rejection_pct: f64,
+    /// Recency-weighted touches × rejection size, relative to the strongest zone of the
+    /// same type (0..1)
+    pub score: f64,
+    /// Price coming down into the zone: how often it held as support
+    pub from_above: ApproachStats,
+    /// Price coming up into the zone: how often it held as resistance
+    pub from_below: ApproachStats,
+}
+
+impl ZoneStrength {
+    pub(crate) fn approach(&self, approach: Approach) -> ApproachStats {
+        match approach {
+            Approach::FromAbove => self.from_above,
+            Approach::FromBelow => self.from_below,
+        }
+    }
+}
+
+/// Side price reaches a zone from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Approach {
+    FromAbove,
+    FromBelow,
+}
+
+impl Approach {
+    /// Side of the zones between `from` and `to` that price meets on the way.
+    pub(crate) fn toward(from: Price, to: Price) -> Self {
+        if to > from {
+            Self::FromBelow
+        } else {
+            Self::FromAbove
+        }
+    }
+}
+
+/// Tests of a zone from one side. A test starts when a candle reaches into the zone from a
+/// close outside it and ends at the first close outside again: back on the starting side
+/// it held, on the far side it broke.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ApproachStats {
+    pub tests: usize,
+    pub held: usize,
+}
+
+impl ApproachStats {
+    pub(crate) fn hold_rate(&self) -> Option<f64> {
+        (self.tests > 0).then(|| self.held as f64 / self.tests as f64)
+    }
+}
+
+/// Tallies tests of each zone by approach side over the candles in `ranges`. A test still
+/// undecided at the end of a range is dropped.
+pub(crate) fn tally_zone_approaches(
+    zones: &mut [SuperZone],
+    ohlcv: &OhlcvTimeSeries,
+    ranges: &[(usize, usize)],
+) {
+    for zone in zones.iter_mut() {
+        let (bottom, top) = (zone.price_bottom.value(), zone.price_top.value());
+        let (mut from_above, mut from_below) = (ApproachStats::default(), ApproachStats::default());
+        for &(start, end) in ranges {
+            // Side of the last close outside the zone, and the test in progress
+            let mut side: Option<Approach> = None;
+            let mut test: Option<Approach> = None;
+            for i in start..end {
+                let close = ohlcv.close_prices[i].value();
+                if test.is_none() {
+                    test = match side {
+                        Some(Approach::FromAbove) if ohlcv.low_prices[i].value() <= top => side,
+                        Some(Approach::FromBelow) if ohlcv.high_prices[i].value() >= bottom => side,
+                        _ => None,
+                    };
+                }
+                let outside = if close > top {
+                    Some(Approach::FromAbove)
+                } else if close < bottom {
+                    Some(Approach::FromBelow)
+                } else {
+                    None
+                };
+                if let Some((approach, now)) = test.zip(outside) {
+                    let stats = match approach {
+                        Approach::FromAbove => &mut from_above,
+                        Approach::FromBelow => &mut from_below,
+                    };
+                    stats.tests += 1;
+                    if now == approach {
+                        stats.held += 1;
+                    }
+                    test = None;
+                }
+                side = outside.or(side);
+            }
+        }
+        zone.strength.from_above = from_above;
+        zone.strength.from_below = from_below;
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum WickSide {
+    Low,
+    High,
+}
+
+/// Fills in `strength` for each zone from the candles the CVA was built on.
+fn score_reversal_zones(
+    zones: &mut [SuperZone],
+    ohlcv: &OhlcvTimeSeries,
+    ranges: &[(usize, usize)],
+    side: WickSide,
+) {
+    let Some(&last_ts) = ohlcv.timestamps.last() else {
+        return;
+    };
+    for zone in zones.iter_mut() {
+        let (bottom, top) = (zone.price_bottom.value(), zone.price_top.value());
+        let mut strength = ZoneStrength::default();
+        let mut rejection_sum = 0.0;
+        // (last touching candle, weight, deepest rejection) of the event in progress
+        let mut event: Option<(usize, f64, f64)> = None;
+        let mut close_event = |strength: &mut ZoneStrength, e: (usize, f64, f64)| {
+            strength.touches += 1;
+            strength.recency_weighted += e.1;
+            rejection_sum += e.2;
+        };
+
+        for i in ranges.iter().flat_map(|&(start, end)| start..end) {
+            let open = ohlcv.open_prices[i].value();
+            let close = ohlcv.close_prices[i].value();
+            let (tip, body) = match side {
+                WickSide::Low => (ohlcv.low_prices[i].value(), open.min(close)),
+                WickSide::High => (ohlcv.high_prices[i].value(), open.max(close)),
+            };
+            let wick = (body - tip).abs();
+            if tip < bottom || tip > top || wick <= 0.0 || close <= 0.0 {
+                continue;
+            }
+            let age_days = (last_ts - ohlcv.timestamps[i]).value() as f64 / MS_PER_DAY;
+            let weight = 0.5_f64.powf(age_days / ZONE_TOUCH_HALF_LIFE_DAYS);
+            let rejection = wick / close;
+            event = match event {
+                Some((prev, _, deepest)) if i - prev <= ZONE_TOUCH_MERGE_CANDLES => {
+                    Some((i, weight, deepest.max(rejection)))
+                }
+                Some(done) => {
+                    close_event(&mut strength, done);
+                    Some((i, weight, rejection))
+                }
+                None => Some((i, weight, rejection)),
+            };
+        }
+        if let Some(done) = event {
+            close_event(&mut strength, done);
+        }
+        if strength.touches > 0 {
+            strength.avg_rejection_pct = rejection_sum / strength.touches as f64;
+        }
+        zone.strength = strength;
+    }
+
+    let raw = |s: &ZoneStrength| s.recency_weighted * s.avg_rejection_pct;
+    let max_raw = zones.iter().map(|z| raw(&z.strength)).fold(0.0, f64::max);
+    if max_raw > 0.0 {
+        for zone in zones.iter_mut() {
+            zone.strength.score = raw(&zone.strength) / max_raw;
+        }
+    }
 }
 
 impl Zone {
@@ -100,16 +312,71 @@ impl SuperZone {
         let price_bottom = zones.first().unwrap().price_bottom;
         let price_top = zones.last().unwrap().price_top;
         Self {
+            id: NEXT_ZONE_ID.fetch_add(1, Ordering::Relaxed),
             price_bottom,
             price_top,
 
             price_center: Price::new((price_bottom + price_top) / 2.0),
+            strength: ZoneStrength::default(),
+        }
+    }
+
+    /// Fresh zone covering `bottom..top` (manual edits).
+    pub(crate) fn spanning(bottom: Price, top: Price) -> Self {
+        Self {
+            id: NEXT_ZONE_ID.fetch_add(1, Ordering::Relaxed),
+            price_bottom: bottom,
+            price_top: top,
+            price_center: Price::new((bottom.value() + top.value()) / 2.0),
+            strength: ZoneStrength::default(),
         }
     }
 
     pub(crate) fn contains(&self, price: Price) -> bool {
         price >= self.price_bottom && price <= self.price_top
     }
+
+    /// How hard this zone is to get through for price arriving from `approach`: the strength
+    /// score scaled by the hold rate from that side once it has been tested enough, the plain
+    /// score before then.
+    pub(crate) fn barrier_score(&self, approach: Approach) -> f64 {
+        let stats = self.strength.approach(approach);
+        match stats.hold_rate() {
+            Some(rate) if stats.tests >= ZONE_APPROACH_MIN_TESTS => self.strength.score * rate,
+            _ => self.strength.score,
+        }
+    }
+
+    /// Jaccard overlap of the two price ranges (0.0 = disjoint, 1.0 = identical).
+    pub(crate) fn overlap_ratio(&self, other: &SuperZone) -> f64 {
+        let (bottom, top) = (self.price_bottom.value(), self.price_top.value());
+        let (other_bottom, other_top) = (other.price_bottom.value(), other.price_top.value());
+        let intersection = top.min(other_top) - bottom.max(other_bottom);
+        let union = top.max(other_top) - bottom.min(other_bottom);
+        if intersection <= 0.0 || union <= f64::EPSILON {
+            0.0
+        } else {
+            intersection / union
+        }
+    }
+}
+
+/// Gives each zone the id of the best-overlapping unclaimed previous zone, if it matches well enough.
+pub(crate) fn inherit_zone_ids(current: &mut [SuperZone], previous: &[SuperZone]) {
+    let mut claimed = vec![false; previous.len()];
+    for zone in current.iter_mut() {
+        let best = previous
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !claimed[*i])
+            .map(|(i, prev)| (i, zone.overlap_ratio(prev)))
+            .filter(|(_, ratio)| *ratio >= ZONE_ID_MATCH_MIN_OVERLAP)
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        if let Some((i, _)) = best {
+            claimed[i] = true;
+            zone.id = previous[i].id;
+        }
+    }
 }
 
 fn aggregate_zones(zones: &[Zone]) -> Vec<SuperZone> {
@@ -142,7 +409,136 @@ pub(crate) struct ClassifiedZones {
     pub low_wicks_superzones: Vec<SuperZone>,
 }
 
-#[derive(Debug, Clone, Default)]
+impl ClassifiedZones {
+    /// Zones only match within the same type (sticky ↔ sticky, etc.).
+    fn inherit_ids(&mut self, previous: &ClassifiedZones) {
+        inherit_zone_ids(&mut self.sticky_superzones, &previous.sticky_superzones);
+        inherit_zone_ids(
+            &mut self.low_wicks_superzones,
+            &previous.low_wicks_superzones,
+        );
+        inherit_zone_ids(
+            &mut self.high_wicks_superzones,
+            &previous.high_wicks_superzones,
+        );
+    }
+
+    /// Reversal strength, then approach tallies for every zone type.
+    fn score_zone_stats(&mut self, ohlcv: &OhlcvTimeSeries, ranges: &[(usize, usize)]) {
+        score_reversal_zones(&mut self.low_wicks_superzones, ohlcv, ranges, WickSide::Low);
+        score_reversal_zones(
+            &mut self.high_wicks_superzones,
+            ohlcv,
+            ranges,
+            WickSide::High,
+        );
+        for zones in [
+            &mut self.sticky_superzones,
+            &mut self.low_wicks_superzones,
+            &mut self.high_wicks_superzones,
+        ] {
+            tally_zone_approaches(zones, ohlcv, ranges);
+        }
+    }
+
+    /// Strongest reversal zone strictly between two prices that price would have to break
+    /// through: resistance (high wicks) going up, support (low wicks) going down. Ranked by
+    /// [`SuperZone::barrier_score`] from the side price arrives at.
+    pub(crate) fn strongest_barrier(&self, from: Price, to: Price) -> Option<&SuperZone> {
+        let approach = Approach::toward(from, to);
+        let (zones, lo, hi) = match approach {
+            Approach::FromBelow => (&self.high_wicks_superzones, from, to),
+            Approach::FromAbove => (&self.low_wicks_superzones, to, from),
+        };
+        zones
+            .iter()
+            .filter(|z| z.price_bottom > lo && z.price_top < hi)
+            .max_by(|a, b| {
+                a.barrier_score(approach)
+                    .total_cmp(&b.barrier_score(approach))
+            })
+    }
+
+    /// Stop just beyond the far edge of the nearest zone protecting an entry at `from`: the
+    /// deepest-reaching sticky / support zone below a long, sticky / resistance zone above a
+    /// short. A zone the entry sits inside counts (its far edge is still beyond the entry).
+    pub(crate) fn protective_stop(
+        &self,
+        from: Price,
+        direction: TradeDirection,
+        buffer: Pct,
+    ) -> Option<StopPrice> {
+        match direction {
+            TradeDirection::Long => self
+                .sticky_superzones
+                .iter()
+                .chain(&self.low_wicks_superzones)
+                .map(|z| z.price_bottom)
+                .filter(|p| *p < from)
+                .max_by(|a, b| a.value().total_cmp(&b.value()))
+                .map(|p| StopPrice::new(p.value() * (1.0 - buffer.value()))),
+            TradeDirection::Short => self
+                .sticky_superzones
+                .iter()
+                .chain(&self.high_wicks_superzones)
+                .map(|z| z.price_top)
+                .filter(|p| *p > from)
+                .min_by(|a, b| a.value().total_cmp(&b.value()))
+                .map(|p| StopPrice::new(p.value() * (1.0 + buffer.value()))),
+        }
+    }
+
+    /// The zone a target lies in (sticky first, then reversal zones), which is what keeps an
+    /// opportunity's id while its target drifts.
+    pub(crate) fn target_anchor(&self, target: TargetPrice) -> TargetAnchor {
+        let price = Price::from(target);
+        self.sticky_superzones
+            .iter()
+            .chain(&self.high_wicks_superzones)
+            .chain(&self.low_wicks_superzones)
+            .find(|z| z.contains(price))
+            .map_or(TargetAnchor::Price(target), |z| TargetAnchor::Zone {
+                bottom: z.price_bottom,
+                top: z.price_top,
+            })
+    }
+
+    /// Near edges of the next `max` sticky / reversal zones beyond `from` in the trade
+    /// direction, nearest first. These are the laddered take-profit levels.
+    pub(crate) fn ladder_levels(
+        &self,
+        from: Price,
+        direction: TradeDirection,
+        max: usize,
+    ) -> Vec<Price> {
+        let mut levels: Vec<Price> = match direction {
+            TradeDirection::Long => self
+                .sticky_superzones
+                .iter()
+                .chain(&self.high_wicks_superzones)
+                .map(|z| z.price_bottom)
+                .filter(|p| *p > from)
+                .collect(),
+            TradeDirection::Short => self
+                .sticky_superzones
+                .iter()
+                .chain(&self.low_wicks_superzones)
+                .map(|z| z.price_top)
+                .filter(|p| *p < from)
+                .collect(),
+        };
+        levels.sort_by(|a, b| {
+            let da = (a.value() - from.value()).abs();
+            let db = (b.value() - from.value()).abs();
+            da.total_cmp(&db)
+        });
+        levels.dedup();
+        levels.truncate(max);
+        levels
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
 pub(crate) struct ZoneCoverageStats {
     pub sticky_pct: f64,
     pub resistance_pct: f64,
@@ -156,29 +552,119 @@ pub(crate) struct TradingModel {
     pub coverage: ZoneCoverageStats,
     pub segments: Vec<DisplaySegment>,
     pub opportunities: Vec<TradeOpportunity>,
+    /// Historical analogues (candle index, similarity) the opportunities were simulated against.
+    pub matches: Vec<(usize, f64)>,
+    /// Realized volatility over 1d / 1w / 1m, to show whether vol is expanding or contracting.
+    pub vol_term: VolTermStructure,
+    /// Flash wicks / outage candles in the pair's history (always detected, marked on the plot)
+    pub anomalies: AnomalyMask,
 }
 
 impl TradingModel {
-    pub(crate) fn from_cva(cva: Arc<CVACore>, ohlcv: &OhlcvTimeSeries) -> Self {
-        let (zones, coverage) = Self::classify_zones(&cva, &DEFAULT_ZONE_CONFIG);
+    pub(crate) fn from_cva(
+        cva: Arc<CVACore>,
+        ohlcv: &OhlcvTimeSeries,
+        zone_config: &ZoneClassificationConfig,
+        zone_edits: &[ZoneEdit],
+        anomalies: AnomalyMask,
+    ) -> Self {
+        let (mut zones, coverage) = Self::classify_zones(&cva, zone_config);
+        zones.apply_edits(zone_edits);
+        zones.score_zone_stats(ohlcv, &cva.included_ranges);
         let (low, high) = cva.price_range.min_max();
 
         let bounds = (Price::new(low), Price::new(high));
-        let segments = RangeGapFinder::analyze(
-            ohlcv,
-            &cva.included_ranges,
-            bounds,
-            SEGMENT_MERGE_TOLERANCE_MS,
-        );
+        let segments =
+            RangeGapFinder::analyze(ohlcv, &cva.included_ranges, bounds, SEGMENT_MERGE_TOLERANCE);
         Self {
             cva,
             zones,
             coverage,
             segments,
             opportunities: Vec::new(),
+            matches: Vec::new(),
+            vol_term: VolTermStructure::calculate(ohlcv),
+            anomalies,
+        }
+    }
+
+    /// Approximate heap footprint of the model, including its (possibly shared) CVA.
+    pub(crate) fn heap_bytes(&self) -> usize {
+        let opportunities: usize = self.opportunities.iter().map(|op| op.heap_bytes()).sum();
+        self.cva.heap_bytes()
+            + vec_heap_bytes(&self.zones.sticky_superzones)
+            + vec_heap_bytes(&self.zones.low_wicks_superzones)
+            + vec_heap_bytes(&self.zones.high_wicks_superzones)
+            + vec_heap_bytes(&self.segments)
+            + vec_heap_bytes(&self.opportunities)
+            + opportunities
+            + vec_heap_bytes(&self.matches)
+            + self.anomalies.heap_bytes()
+    }
+
+    /// Re-runs zone classification on the existing CVA (cheap: no simulation).
+    pub(crate) fn with_zone_config(
+        &self,
+        zone_config: &ZoneClassificationConfig,
+        zone_edits: &[ZoneEdit],
+        ohlcv: &OhlcvTimeSeries,
+    ) -> Self {
+        let (mut zones, coverage) = Self::classify_zones(&self.cva, zone_config);
+        zones.apply_edits(zone_edits);
+        zones.score_zone_stats(ohlcv, &self.cva.included_ranges);
+        zones.inherit_ids(&self.zones);
+        Self {
+            zones,
+            coverage,
+            ..self.clone()
         }
     }
 
+    /// Nudges each layer's sigma until its coverage falls inside the target band
+    /// (higher sigma = fewer zones). Stops early once every layer is in band.
+    pub(crate) fn auto_balance_zone_config(
+        cva: &CVACore,
+        config: &ZoneClassificationConfig,
+        targets: &CoverageTargets,
+    ) -> ZoneClassificationConfig {
+        let mut config = config.clone();
+        for _ in 0..AUTO_BALANCE_MAX_ITERATIONS {
+            let (_, coverage) = Self::classify_zones(cva, &config);
+            let mut settled = true;
+            for (params, pct, band) in [
+                (&mut config.sticky, coverage.sticky_pct, targets.sticky),
+                (&mut config.low_wick, coverage.support_pct, targets.support),
+                (
+                    &mut config.high_wick,
+                    coverage.resistance_pct,
+                    targets.resistance,
+                ),
+            ] {
+                let sigma = params.sigma.value();
+                let next = if pct > band.max_pct {
+                    (sigma + AUTO_BALANCE_SIGMA_STEP).min(AUTO_BALANCE_MAX_SIGMA)
+                } else if pct < band.min_pct {
+                    (sigma - AUTO_BALANCE_SIGMA_STEP).max(0.0)
+                } else {
+                    sigma
+                };
+                if (next - sigma).abs() > f64::EPSILON {
+                    params.sigma = Sigma::new(next);
+                    settled = false;
+                }
+            }
+            if settled {
+                break;
+            }
+        }
+        config
+    }
+
+    /// Carries zone ids over from the model this one replaces.
+    pub(crate) fn inherit_zone_ids(&mut self, previous: &TradingModel) {
+        self.zones.inherit_ids(&previous.zones);
+    }
+
     fn classify_zones(
         cva: &CVACore,
         config: &ZoneClassificationConfig,
@@ -271,25 +757,31 @@ impl TradingModel {
                 (zones, superzones)
             };
 
-            let total_volume: f64 = cva.get_scores_ref(ScoreType::FullCandleTVW).iter().sum();
+            let sticky_scores: Cow<[f64]> = match config.sticky_score {
+                StickyScore::Volume => Cow::Borrowed(cva.get_scores_ref(ScoreType::FullCandleTVW)),
+                // Buying and selling pressure both make a level sticky
+                StickyScore::TakerDelta => Cow::Owned(
+                    cva.get_scores_ref(ScoreType::TakerDeltaTVW)
+                        .iter()
+                        .map(|delta| delta.abs())
+                        .collect(),
+                ),
+            };
+            let sticky_total: f64 = sticky_scores.iter().sum();
 
-            let (sticky, sticky_superzones) = process_layer(
-                cva.get_scores_ref(ScoreType::FullCandleTVW),
-                config.sticky,
-                total_volume,
-                "STICKY",
-            );
+            let (sticky, sticky_superzones) =
+                process_layer(&sticky_scores, config.sticky, sticky_total, "STICKY");
 
             let (low_wicks, low_wicks_superzones) = process_layer(
                 cva.get_scores_ref(ScoreType::LowWickCount),
-                config.reversal,
+                config.low_wick,
                 total_candles,
                 "LOW WICKS",
             );
 
             let (high_wicks, high_wicks_superzones) = process_layer(
                 cva.get_scores_ref(ScoreType::HighWickCount),
-                config.reversal,
+                config.high_wick,
                 total_candles,
                 "HIGH WICKS",
             );
@@ -0,0 +1,57 @@
+use {
+    crate::{app::VolatilityPct, models::OhlcvTimeSeries},
+    serde::Serialize,
+    std::time::Duration,
+};
+
+const DAY: Duration = Duration::from_secs(86_400);
+
+/// Trailing windows, shortest first.
+const VOL_TERM_WINDOWS: [(&str, Duration); 3] = [
+    ("1d", DAY),
+    ("1w", Duration::from_secs(DAY.as_secs() * 7)),
+    ("1m", Duration::from_secs(DAY.as_secs() * 30)),
+];
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub(crate) struct VolTermPoint {
+    pub label: &'static str,
+    pub volatility_pct: VolatilityPct,
+}
+
+/// Realized volatility (mean (High-Low)/Close, the `MarketState` measure) over trailing
+/// windows ending at the latest candle. Windows longer than the history are left out.
+#[derive(Debug, Clone, Default, Serialize)]
+pub(crate) struct VolTermStructure {
+    pub points: Vec<VolTermPoint>,
+}
+
+impl VolTermStructure {
+    pub(crate) fn calculate(ohlcv: &OhlcvTimeSeries) -> Self {
+        let len = ohlcv.close_prices.len();
+        let interval_ms = ohlcv.pair_interval.interval_ms.max(1);
+        let points = VOL_TERM_WINDOWS
+            .iter()
+            .filter_map(|(label, window)| {
+                let candles = (window.as_millis() as i64 / interval_ms) as usize;
+                if candles == 0 || candles > len {
+                    return None;
+                }
+                Some(VolTermPoint {
+                    label,
+                    volatility_pct: ohlcv.calc_volatility_in_range(len - candles, len),
+                })
+            })
+            .collect();
+        Self { points }
+    }
+
+    /// Shortest / longest window volatility. Above 1 = expanding, below 1 = contracting.
+    pub(crate) fn slope(&self) -> Option<f64> {
+        if self.points.len() < 2 {
+            return None;
+        }
+        let (first, last) = (self.points.first()?, self.points.last()?);
+        Some(first.volatility_pct.value() / last.volatility_pct.as_safe_divisor())
+    }
+}
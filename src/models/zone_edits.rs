@@ -0,0 +1,124 @@
+use {
+    crate::{
+        app::{Price, PriceLike},
+        models::{ClassifiedZones, DiffZoneKind, SuperZone},
+    },
+    serde::{Deserialize, Serialize},
+};
+
+/// A manual correction to the classifier's zones, kept per pair and replayed in order after
+/// every classification. Anchored by price rather than zone id, so edits survive restarts
+/// and recalculations that nudge zone edges.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub(crate) enum ZoneEdit {
+    /// Cut the zone containing `at` into a lower and an upper zone
+    Split { kind: DiffZoneKind, at: Price },
+    /// Join the zones containing `lower` and `upper` into one, absorbing any zone between them
+    Merge {
+        kind: DiffZoneKind,
+        lower: Price,
+        upper: Price,
+    },
+}
+
+impl ZoneEdit {
+    pub(crate) fn kind(&self) -> DiffZoneKind {
+        match self {
+            Self::Split { kind, .. } | Self::Merge { kind, .. } => *kind,
+        }
+    }
+}
+
+impl ClassifiedZones {
+    pub(crate) fn zones(&self, kind: DiffZoneKind) -> &[SuperZone] {
+        match kind {
+            DiffZoneKind::Sticky => &self.sticky_superzones,
+            DiffZoneKind::Support => &self.low_wicks_superzones,
+            DiffZoneKind::Resistance => &self.high_wicks_superzones,
+        }
+    }
+
+    fn zones_mut(&mut self, kind: DiffZoneKind) -> &mut Vec<SuperZone> {
+        match kind {
+            DiffZoneKind::Sticky => &mut self.sticky_superzones,
+            DiffZoneKind::Support => &mut self.low_wicks_superzones,
+            DiffZoneKind::Resistance => &mut self.high_wicks_superzones,
+        }
+    }
+
+    /// Replays `edits` in order. Edits whose zones no longer exist are skipped.
+    pub(crate) fn apply_edits(&mut self, edits: &[ZoneEdit]) {
+        for edit in edits {
+            let zones = self.zones_mut(edit.kind());
+            match *edit {
+                ZoneEdit::Split { at, .. } => split_zone(zones, at),
+                ZoneEdit::Merge { lower, upper, .. } => merge_zones(zones, lower, upper),
+            }
+        }
+    }
+}
+
+fn split_zone(zones: &mut Vec<SuperZone>, at: Price) {
+    let Some(idx) = zones
+        .iter()
+        .position(|z| z.price_bottom < at && at < z.price_top)
+    else {
+        return;
+    };
+    let zone = zones.remove(idx);
+    zones.insert(idx, SuperZone::spanning(at, zone.price_top));
+    zones.insert(idx, SuperZone::spanning(zone.price_bottom, at));
+}
+
+fn merge_zones(zones: &mut Vec<SuperZone>, lower: Price, upper: Price) {
+    let bottom = zones
+        .iter()
+        .find(|z| z.contains(lower))
+        .map(|z| z.price_bottom);
+    let top = zones
+        .iter()
+        .find(|z| z.contains(upper))
+        .map(|z| z.price_top);
+    let (Some(bottom), Some(top)) = (bottom, top) else {
+        return;
+    };
+    if bottom.value() >= top.value() {
+        return;
+    }
+    let Some(idx) = zones.iter().position(|z| z.price_top >= bottom) else {
+        return;
+    };
+    zones.retain(|z| z.price_top < bottom || z.price_bottom > top);
+    zones.insert(idx.min(zones.len()), SuperZone::spanning(bottom, top));
+}
+
+/// A zone the user drew from their own information. Plotted, snapped to and alerted on like
+/// the engine's zones, but never fed back into classification.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct CustomZone {
+    /// Unique within the pair
+    pub id: u64,
+    pub label: String,
+    pub kind: DiffZoneKind,
+    pub price_bottom: Price,
+    pub price_top: Price,
+    /// Raise an alert when the live price enters the zone
+    pub alert: bool,
+}
+
+impl CustomZone {
+    pub(crate) fn contains(&self, price: Price) -> bool {
+        price >= self.price_bottom && price <= self.price_top
+    }
+
+    /// Engine-shaped copy, for the shared zone drawing and snapping code.
+    pub(crate) fn to_superzone(&self) -> SuperZone {
+        SuperZone {
+            id: self.id,
+            price_bottom: self.price_bottom,
+            price_top: self.price_top,
+            price_center: Price::new((self.price_bottom.value() + self.price_top.value()) / 2.0),
+            strength: Default::default(),
+        }
+    }
+}
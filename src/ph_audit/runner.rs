@@ -3,7 +3,10 @@ use {
         app::{BASE_INTERVAL, PhPct, Price, PriceLike},
         data::TimeSeriesCollection,
         engine::{StationId, run_pathfinder_simulations},
-        models::{OptimizationStrategy, find_matching_ohlcv, pair_analysis_pure},
+        models::{
+            AdaptiveCurves, DEFAULT_JOURNEY_SETTINGS, OptimizationStrategy, find_matching_ohlcv,
+            pair_analysis_pure,
+        },
         ph_audit::{AUDIT_PAIRS, AuditReporter, PH_LEVELS},
         utils::AppInstant,
     },
@@ -79,7 +82,15 @@ fn run_single_simulation(
     .unwrap();
     let start_time = AppInstant::now();
 
-    let cva_res = pair_analysis_pure(pair.to_string(), ts_collection, price, ph_pct);
+    let adaptive = AdaptiveCurves::default();
+    let cva_res = pair_analysis_pure(
+        pair.to_string(),
+        ts_collection,
+        price,
+        ph_pct,
+        &adaptive,
+        None,
+    );
     let strat_name = format!("{:?}", strategy);
     if cva_res.is_err() {
         return;
@@ -95,6 +106,11 @@ fn run_single_simulation(
         *strategy,
         StationId::default(),
         Some(&cva),
+        &adaptive,
+        &DEFAULT_JOURNEY_SETTINGS.profile,
+        None,
+        None,
+        &[],
     );
     let elapsed = start_time.elapsed().as_millis();
     let opportunities = pf_result.opportunities;
@@ -1,3 +1,9 @@
+mod param_history;
+mod repaint;
 mod shared_engine_ui;
 
-pub(crate) use shared_engine_ui::SharedConfiguration;
+pub(crate) use {
+    param_history::ParamHistory,
+    repaint::RepaintSignal,
+    shared_engine_ui::{SharedConfiguration, UIEngineSharedData},
+};
@@ -0,0 +1,59 @@
+use crate::{shared::shared_engine_ui::UIEngineSharedData, utils::TimeUtils};
+
+const MAX_HISTORY_DEPTH: usize = 50;
+
+/// Repeated edits of the same kind within this window collapse into one undo step
+/// (e.g. a drag that fires a change every frame).
+const COALESCE_WINDOW_MS: i64 = 1_000;
+
+/// Undo/redo stacks of analysis parameter snapshots.
+#[derive(Debug, Default)]
+pub(crate) struct ParamHistory {
+    undo: Vec<UIEngineSharedData>,
+    redo: Vec<UIEngineSharedData>,
+    last_record: Option<(&'static str, i64)>,
+}
+
+impl ParamHistory {
+    /// Stores the parameters as they were *before* a change of kind `kind`.
+    pub(crate) fn record(&mut self, before: UIEngineSharedData, kind: &'static str) {
+        let now = TimeUtils::now_timestamp_ms();
+        let coalesce = matches!(
+            self.last_record,
+            Some((last_kind, at)) if last_kind == kind && now - at < COALESCE_WINDOW_MS
+        );
+        self.last_record = Some((kind, now));
+        if coalesce {
+            return;
+        }
+
+        self.undo.push(before);
+        if self.undo.len() > MAX_HISTORY_DEPTH {
+            self.undo.remove(0);
+        }
+        self.redo.clear();
+    }
+
+    /// Keeps an open edit of `kind` alive, so a slow drag stays one undo step however long
+    /// it pauses between commits.
+    pub(crate) fn extend(&mut self, kind: &'static str) {
+        if let Some((_, at)) = self.last_record.as_mut().filter(|(last, _)| *last == kind) {
+            *at = TimeUtils::now_timestamp_ms();
+        }
+    }
+
+    /// Returns the snapshot to restore; `current` moves onto the redo stack.
+    pub(crate) fn undo(&mut self, current: UIEngineSharedData) -> Option<UIEngineSharedData> {
+        let previous = self.undo.pop()?;
+        self.redo.push(current);
+        self.last_record = None;
+        Some(previous)
+    }
+
+    pub(crate) fn redo(&mut self, current: UIEngineSharedData) -> Option<UIEngineSharedData> {
+        let next = self.redo.pop()?;
+        self.undo.push(current);
+        self.last_record = None;
+        Some(next)
+    }
+}
@@ -0,0 +1,66 @@
+use {
+    eframe::egui::Context,
+    std::sync::{
+        Arc, Mutex, OnceLock,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+/// Lets the engine's worker and price stream wake the UI when something it shows changed,
+/// so the running loop can sleep between events instead of repainting every frame.
+#[derive(Clone, Default)]
+pub(crate) struct RepaintSignal {
+    inner: Arc<RepaintInner>,
+}
+
+#[derive(Default)]
+struct RepaintInner {
+    ctx: OnceLock<Context>,
+    /// Off while idle / in the background; the heartbeat repaint covers those
+    live: AtomicBool,
+    /// Pair on screen (lowercase, as the price stream keys it)
+    watched: Mutex<Option<String>>,
+}
+
+impl RepaintSignal {
+    /// Called every frame; only the first context is kept.
+    pub(crate) fn attach(&self, ctx: &Context) {
+        let _ = self.inner.ctx.set(ctx.clone());
+    }
+
+    pub(crate) fn set_live(&self, live: bool) {
+        self.inner.live.store(live, Ordering::Relaxed);
+    }
+
+    pub(crate) fn watch(&self, pair: Option<&str>) {
+        let pair = pair.map(str::to_lowercase);
+        let mut watched = self.inner.watched.lock().unwrap();
+        if *watched != pair {
+            *watched = pair;
+        }
+    }
+
+    /// Job finished, model changed, or anything else every view cares about.
+    pub(crate) fn request(&self) {
+        if !self.inner.live.load(Ordering::Relaxed) {
+            return;
+        }
+        if let Some(ctx) = self.inner.ctx.get() {
+            ctx.request_repaint();
+        }
+    }
+
+    /// New price for `symbol`; repaints only when it is the pair on screen.
+    pub(crate) fn price_changed(&self, symbol: &str) {
+        let watched = self
+            .inner
+            .watched
+            .lock()
+            .unwrap()
+            .as_deref()
+            .is_some_and(|w| w.eq_ignore_ascii_case(symbol));
+        if watched {
+            self.request();
+        }
+    }
+}
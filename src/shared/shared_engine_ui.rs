@@ -1,8 +1,12 @@
 use {
-    crate::{app::PhPct, engine::StationId, models::OptimizationStrategy},
+    crate::{
+        app::{CoverageTargets, PhPct, TradeProfile, ZoneClassificationConfig},
+        engine::{RecalcThrottle, StationId, StationStats, TunerScan, TunerWeights},
+        models::{AdaptiveCurves, CustomZone, LedgerPolicy, OptimizationStrategy, ZoneEdit},
+    },
     serde::{Deserialize, Deserializer, Serialize, Serializer},
     std::{
-        collections::HashMap,
+        collections::{BTreeMap, HashMap, HashSet},
         sync::{Arc, RwLock},
     },
 };
@@ -10,11 +14,66 @@ use {
 #[cfg(debug_assertions)]
 use crate::config::DF;
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+/// Scans are persisted per pair, including pairs since dropped from the watchlist
+const TUNER_SCANS_CAP: usize = 200;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub(crate) struct UIEngineSharedData {
     pub(crate) station_overrides: HashMap<String, StationId>,
     pub(crate) ph_overrides: HashMap<String, PhPct>,
     pub(crate) strategy: OptimizationStrategy,
+    #[serde(default)]
+    pub(crate) adaptive: AdaptiveCurves,
+    #[serde(default)]
+    pub(crate) zone_config: ZoneClassificationConfig,
+    /// Manual zone splits / merges per pair, replayed after every classification
+    #[serde(default)]
+    pub(crate) zone_edits: HashMap<String, Vec<ZoneEdit>>,
+    /// User-drawn zones per pair
+    #[serde(default)]
+    pub(crate) custom_zones: HashMap<String, Vec<CustomZone>>,
+    /// Next custom zone id per pair; never decreases, so a deleted zone's id is not reused
+    #[serde(default)]
+    pub(crate) next_custom_zone_ids: HashMap<String, u64>,
+    /// Coverage bands per strategy preset (missing = defaults)
+    #[serde(default)]
+    pub(crate) coverage_targets: BTreeMap<OptimizationStrategy, CoverageTargets>,
+    /// Drop flash wicks / outage candles from zone scoring and journey matching
+    #[serde(default)]
+    pub(crate) exclude_anomalies: bool,
+    /// Also simulate take-profit rungs at the next zones in the trade direction
+    #[serde(default)]
+    pub(crate) ladder_targets: bool,
+    /// Also simulate each user-drawn zone as a target
+    #[serde(default)]
+    pub(crate) custom_zone_targets: bool,
+    /// How the time tuner ranks the PHs it probes
+    #[serde(default)]
+    pub(crate) tuner_weights: TunerWeights,
+    /// Stop placement and minimum ROI / AROI (missing = defaults)
+    #[serde(default)]
+    pub(crate) trade_profile: TradeProfile,
+    /// Live recalculation throttling / pause
+    #[serde(default)]
+    pub(crate) throttle: RecalcThrottle,
+    /// Ledger pruning interval and limits
+    #[serde(default)]
+    pub(crate) ledger_policy: LedgerPolicy,
+    /// Opportunity ids the user marked as taken
+    #[serde(default)]
+    pub(crate) taken_opportunities: HashSet<String>,
+    /// Last tuner scan per pair, reused or warm-started by the next tune
+    #[serde(default)]
+    pub(crate) tuner_scans: HashMap<String, TunerScan>,
+    /// Latest yield of each station per pair, for the Time Tuner
+    #[serde(default)]
+    pub(crate) station_stats: HashMap<String, HashMap<StationId, StationStats>>,
+    /// The user's own notes on each station per pair, shown in the Time Tuner
+    #[serde(default)]
+    pub(crate) station_notes: HashMap<String, HashMap<StationId, String>>,
+    /// Unsaved curves applied to a single pair only (live preview)
+    #[serde(skip)]
+    pub(crate) adaptive_preview: Option<(String, AdaptiveCurves)>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -37,6 +96,250 @@ impl SharedConfiguration {
         self.inner.write().unwrap().strategy = strategy;
     }
 
+    /// Curves used for `pair`: the preview if one targets this pair, else the global curves.
+    pub(crate) fn get_adaptive(&self, pair: &str) -> AdaptiveCurves {
+        let data = self.inner.read().unwrap();
+        match &data.adaptive_preview {
+            Some((preview_pair, curves)) if preview_pair == pair => curves.clone(),
+            _ => data.adaptive.clone(),
+        }
+    }
+
+    pub(crate) fn get_global_adaptive(&self) -> AdaptiveCurves {
+        self.inner.read().unwrap().adaptive.clone()
+    }
+
+    pub(crate) fn set_adaptive_preview(&self, preview: Option<(String, AdaptiveCurves)>) {
+        self.inner.write().unwrap().adaptive_preview = preview;
+    }
+
+    /// Promotes curves to global and drops any preview.
+    pub(crate) fn set_adaptive(&self, curves: AdaptiveCurves) {
+        let mut data = self.inner.write().unwrap();
+        data.adaptive = curves;
+        data.adaptive_preview = None;
+    }
+
+    /// Copy of the committed parameters (any live preview excluded), for undo/redo.
+    /// Tuner scans are left out: `restore` keeps the live ones anyway, and their probe
+    /// curves would otherwise be cloned into every history entry.
+    pub(crate) fn snapshot(&self) -> UIEngineSharedData {
+        let mut inner = self.inner.write().unwrap();
+        let tuner_scans = std::mem::take(&mut inner.tuner_scans);
+        let mut data = inner.clone();
+        inner.tuner_scans = tuner_scans;
+        data.adaptive_preview = None;
+        data
+    }
+
+    /// Throttling, ledger policy, taken marks, cached tuner scans and station stats / notes are
+    /// engine or user state rather than analysis parameters, so undo/redo leaves them alone.
+    /// Custom zone id counters are kept too, so undoing an add cannot lead to a reused id.
+    pub(crate) fn restore(&self, data: UIEngineSharedData) {
+        let mut inner = self.inner.write().unwrap();
+        let throttle = std::mem::take(&mut inner.throttle);
+        let ledger_policy = std::mem::take(&mut inner.ledger_policy);
+        let taken = std::mem::take(&mut inner.taken_opportunities);
+        let tuner_scans = std::mem::take(&mut inner.tuner_scans);
+        let station_stats = std::mem::take(&mut inner.station_stats);
+        let station_notes = std::mem::take(&mut inner.station_notes);
+        let next_zone_ids = std::mem::take(&mut inner.next_custom_zone_ids);
+        *inner = data;
+        inner.throttle = throttle;
+        inner.ledger_policy = ledger_policy;
+        inner.taken_opportunities = taken;
+        inner.tuner_scans = tuner_scans;
+        inner.station_stats = station_stats;
+        inner.station_notes = station_notes;
+        inner.next_custom_zone_ids = next_zone_ids;
+    }
+
+    pub(crate) fn get_zone_config(&self) -> ZoneClassificationConfig {
+        self.inner.read().unwrap().zone_config.clone()
+    }
+
+    pub(crate) fn set_zone_config(&self, zone_config: ZoneClassificationConfig) {
+        self.inner.write().unwrap().zone_config = zone_config;
+    }
+
+    pub(crate) fn get_zone_edits(&self, pair: &str) -> Vec<ZoneEdit> {
+        self.inner
+            .read()
+            .unwrap()
+            .zone_edits
+            .get(pair)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn push_zone_edit(&self, pair: &str, edit: ZoneEdit) {
+        self.inner
+            .write()
+            .unwrap()
+            .zone_edits
+            .entry(pair.to_string())
+            .or_default()
+            .push(edit);
+    }
+
+    pub(crate) fn clear_zone_edits(&self, pair: &str) {
+        self.inner.write().unwrap().zone_edits.remove(pair);
+    }
+
+    pub(crate) fn get_custom_zones(&self, pair: &str) -> Vec<CustomZone> {
+        self.inner
+            .read()
+            .unwrap()
+            .custom_zones
+            .get(pair)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn get_all_custom_zones(&self) -> HashMap<String, Vec<CustomZone>> {
+        self.inner.read().unwrap().custom_zones.clone()
+    }
+
+    /// Stores `zone` under a fresh id (its own id is ignored).
+    pub(crate) fn add_custom_zone(&self, pair: &str, mut zone: CustomZone) {
+        let mut guard = self.inner.write().unwrap();
+        let data = &mut *guard;
+        let zones = data.custom_zones.entry(pair.to_string()).or_default();
+        let next = data
+            .next_custom_zone_ids
+            .entry(pair.to_string())
+            .or_default();
+        // Configs saved before the counter existed start past their highest id
+        zone.id = zones
+            .iter()
+            .map(|z| z.id + 1)
+            .fold((*next).max(1), u64::max);
+        *next = zone.id + 1;
+        zones.push(zone);
+    }
+
+    pub(crate) fn set_custom_zone_alert(&self, pair: &str, id: u64, alert: bool) {
+        let mut data = self.inner.write().unwrap();
+        let zones = data.custom_zones.get_mut(pair).into_iter().flatten();
+        for zone in zones.filter(|z| z.id == id) {
+            zone.alert = alert;
+        }
+    }
+
+    pub(crate) fn remove_custom_zone(&self, pair: &str, id: u64) {
+        let mut data = self.inner.write().unwrap();
+        if let Some(zones) = data.custom_zones.get_mut(pair) {
+            zones.retain(|z| z.id != id);
+            if zones.is_empty() {
+                data.custom_zones.remove(pair);
+            }
+        }
+    }
+
+    pub(crate) fn get_exclude_anomalies(&self) -> bool {
+        self.inner.read().unwrap().exclude_anomalies
+    }
+
+    pub(crate) fn set_exclude_anomalies(&self, exclude: bool) {
+        self.inner.write().unwrap().exclude_anomalies = exclude;
+    }
+
+    pub(crate) fn get_ladder_targets(&self) -> bool {
+        self.inner.read().unwrap().ladder_targets
+    }
+
+    pub(crate) fn set_ladder_targets(&self, ladder: bool) {
+        self.inner.write().unwrap().ladder_targets = ladder;
+    }
+
+    pub(crate) fn get_custom_zone_targets(&self) -> bool {
+        self.inner.read().unwrap().custom_zone_targets
+    }
+
+    pub(crate) fn set_custom_zone_targets(&self, enabled: bool) {
+        self.inner.write().unwrap().custom_zone_targets = enabled;
+    }
+
+    /// The pair's custom zones if they are simulated as targets, else nothing.
+    pub(crate) fn get_custom_targets(&self, pair: &str) -> Vec<CustomZone> {
+        if self.get_custom_zone_targets() {
+            self.get_custom_zones(pair)
+        } else {
+            Vec::new()
+        }
+    }
+
+    pub(crate) fn get_trade_profile(&self) -> TradeProfile {
+        self.inner.read().unwrap().trade_profile.clone()
+    }
+
+    pub(crate) fn set_trade_profile(&self, profile: TradeProfile) {
+        self.inner.write().unwrap().trade_profile = profile;
+    }
+
+    pub(crate) fn get_throttle(&self) -> RecalcThrottle {
+        self.inner.read().unwrap().throttle.clone()
+    }
+
+    pub(crate) fn set_throttle(&self, throttle: RecalcThrottle) {
+        self.inner.write().unwrap().throttle = throttle;
+    }
+
+    pub(crate) fn get_ledger_policy(&self) -> LedgerPolicy {
+        self.inner.read().unwrap().ledger_policy.clone()
+    }
+
+    pub(crate) fn set_ledger_policy(&self, policy: LedgerPolicy) {
+        self.inner.write().unwrap().ledger_policy = policy;
+    }
+
+    pub(crate) fn get_taken_opportunities(&self) -> HashSet<String> {
+        self.inner.read().unwrap().taken_opportunities.clone()
+    }
+
+    pub(crate) fn is_taken(&self, id: &str) -> bool {
+        self.inner.read().unwrap().taken_opportunities.contains(id)
+    }
+
+    pub(crate) fn set_taken(&self, id: &str, taken: bool) {
+        let mut data = self.inner.write().unwrap();
+        if taken {
+            data.taken_opportunities.insert(id.to_string());
+        } else {
+            data.taken_opportunities.remove(id);
+        }
+    }
+
+    /// Drops taken marks for opportunities that have left the ledger.
+    pub(crate) fn forget_taken(&self, ids: &[String]) {
+        let mut data = self.inner.write().unwrap();
+        for id in ids {
+            data.taken_opportunities.remove(id);
+        }
+    }
+
+    pub(crate) fn get_coverage_targets(&self, strategy: OptimizationStrategy) -> CoverageTargets {
+        self.inner
+            .read()
+            .unwrap()
+            .coverage_targets
+            .get(&strategy)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn set_coverage_targets(
+        &self,
+        strategy: OptimizationStrategy,
+        targets: CoverageTargets,
+    ) {
+        self.inner
+            .write()
+            .unwrap()
+            .coverage_targets
+            .insert(strategy, targets);
+    }
+
     pub(crate) fn ensure_all_stations_initialized(&self, pairs: &[String]) {
         let mut data = self.inner.write().unwrap();
         for pair in pairs {
@@ -85,6 +388,88 @@ impl SharedConfiguration {
         })
     }
 
+    pub(crate) fn get_tuner_weights(&self) -> TunerWeights {
+        self.inner.read().unwrap().tuner_weights
+    }
+
+    pub(crate) fn set_tuner_weights(&self, weights: TunerWeights) {
+        self.inner.write().unwrap().tuner_weights = weights;
+    }
+
+    pub(crate) fn get_tuner_scan(&self, pair: &str) -> Option<TunerScan> {
+        self.inner.read().unwrap().tuner_scans.get(pair).cloned()
+    }
+
+    /// Beyond `TUNER_SCANS_CAP` the scan over the stalest data goes first.
+    pub(crate) fn insert_tuner_scan(&self, pair: String, scan: TunerScan) {
+        let mut data = self.inner.write().unwrap();
+        data.tuner_scans.insert(pair, scan);
+        while data.tuner_scans.len() > TUNER_SCANS_CAP {
+            let Some(stalest) = data
+                .tuner_scans
+                .iter()
+                .min_by_key(|(_, scan)| scan.fingerprint.data_end())
+                .map(|(pair, _)| pair.clone())
+            else {
+                break;
+            };
+            data.tuner_scans.remove(&stalest);
+        }
+    }
+
+    pub(crate) fn get_station_stats(&self, pair: &str) -> HashMap<StationId, StationStats> {
+        self.inner
+            .read()
+            .unwrap()
+            .station_stats
+            .get(pair)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn record_station_stats(
+        &self,
+        pair: String,
+        station: StationId,
+        stats: StationStats,
+    ) {
+        self.inner
+            .write()
+            .unwrap()
+            .station_stats
+            .entry(pair)
+            .or_default()
+            .insert(station, stats);
+    }
+
+    pub(crate) fn get_station_notes(&self, pair: &str) -> HashMap<StationId, String> {
+        self.inner
+            .read()
+            .unwrap()
+            .station_notes
+            .get(pair)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Sets the note on `station` for `pair`; a blank note removes it.
+    pub(crate) fn set_station_note(&self, pair: String, station: StationId, note: String) {
+        let mut data = self.inner.write().unwrap();
+        if note.trim().is_empty() {
+            if let Some(notes) = data.station_notes.get_mut(&pair) {
+                notes.remove(&station);
+                if notes.is_empty() {
+                    data.station_notes.remove(&pair);
+                }
+            }
+        } else {
+            data.station_notes
+                .entry(pair)
+                .or_default()
+                .insert(station, note);
+        }
+    }
+
     pub(crate) fn get_ph(&self, key: &str) -> Option<PhPct> {
         self.inner.read().unwrap().ph_overrides.get(key).copied()
     }
@@ -0,0 +1,194 @@
+use {
+    crate::{
+        app::PhPct,
+        models::{AdaptiveCurve, AdaptiveCurves},
+        ui::{GlossaryTerm, PLOT_CONFIG, UI_TEXT, help_link},
+    },
+    eframe::egui::{Button, DragValue, Grid, RichText, Ui},
+    egui_plot::{Line, Plot, PlotPoints, Points, VLine},
+};
+
+const CURVE_SAMPLES: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum AdaptiveCurveKind {
+    #[default]
+    TrendLookback,
+    TimeDecay,
+}
+
+/// Draft curves being edited, plus the pair currently running them as a preview.
+#[derive(Debug, Default)]
+pub(crate) struct AdaptiveEditorState {
+    pub draft: Option<AdaptiveCurves>,
+    pub kind: AdaptiveCurveKind,
+    pub preview_pair: Option<String>,
+}
+
+#[derive(Debug)]
+pub(crate) enum AdaptiveEditorAction {
+    Preview(AdaptiveCurves),
+    Apply(AdaptiveCurves),
+    Discard,
+}
+
+pub(crate) fn render_adaptive_editor(
+    ui: &mut Ui,
+    state: &mut AdaptiveEditorState,
+    current_ph: Option<PhPct>,
+) -> Option<AdaptiveEditorAction> {
+    let draft = state.draft.get_or_insert_with(AdaptiveCurves::default);
+    let mut action = None;
+    let mut changed = false;
+
+    ui.horizontal(|ui| {
+        ui.selectable_value(
+            &mut state.kind,
+            AdaptiveCurveKind::TrendLookback,
+            &UI_TEXT.ae_curve_lookback,
+        );
+        ui.selectable_value(
+            &mut state.kind,
+            AdaptiveCurveKind::TimeDecay,
+            &UI_TEXT.ae_curve_decay,
+        );
+        help_link(ui, GlossaryTerm::TimeDecay);
+    });
+    ui.separator();
+
+    // Lookback below one candle (or negative) would leave the trend with nothing to read
+    let (curve, value_speed, value_min) = match state.kind {
+        AdaptiveCurveKind::TrendLookback => (&mut draft.trend_lookback, 1.0, 1.0),
+        AdaptiveCurveKind::TimeDecay => (&mut draft.time_decay, 0.01, 1.0),
+    };
+
+    render_curve_plot(ui, curve, current_ph);
+    ui.add_space(4.0);
+
+    let mut remove_idx = None;
+    let can_remove = curve.points.len() > 2;
+    Grid::new("ae_points_grid")
+        .num_columns(3)
+        .striped(true)
+        .spacing([10.0, 4.0])
+        .show(ui, |ui| {
+            ui.label(RichText::new(&UI_TEXT.ae_ph).strong().small());
+            ui.label(RichText::new(&UI_TEXT.ae_value).strong().small());
+            ui.end_row();
+
+            for (i, point) in curve.points.iter_mut().enumerate() {
+                let mut ph_display = point[0] * 100.0;
+                let ph_resp = ui.add(
+                    DragValue::new(&mut ph_display)
+                        .speed(0.05)
+                        .range(0.0..=100.0)
+                        .suffix("%"),
+                );
+                if ph_resp.changed() {
+                    point[0] = ph_display / 100.0;
+                }
+                let value_resp = ui.add(
+                    DragValue::new(&mut point[1])
+                        .speed(value_speed)
+                        .range(value_min..=f64::MAX),
+                );
+                changed |= ph_resp.changed() || value_resp.changed();
+                if ui
+                    .add_enabled(can_remove, Button::new(&UI_TEXT.icon_close).small())
+                    .clicked()
+                {
+                    remove_idx = Some(i);
+                }
+                ui.end_row();
+            }
+        });
+
+    if let Some(i) = remove_idx {
+        curve.points.remove(i);
+        changed = true;
+    }
+    if ui.small_button(&UI_TEXT.ae_add_point).clicked() {
+        let next = curve
+            .points
+            .last()
+            .map(|p| [p[0] * 1.2 + 0.01, p[1]])
+            .unwrap_or([0.05, 1.0]);
+        curve.points.push(next);
+        changed = true;
+    }
+
+    ui.separator();
+    ui.horizontal(|ui| {
+        if ui.button(&UI_TEXT.ae_apply).clicked() {
+            let mut curves = draft.clone();
+            curves.normalize();
+            action = Some(AdaptiveEditorAction::Apply(curves));
+        }
+        if ui
+            .add_enabled(
+                state.preview_pair.is_some(),
+                Button::new(&UI_TEXT.ae_discard),
+            )
+            .clicked()
+        {
+            action = Some(AdaptiveEditorAction::Discard);
+        }
+        if ui.button(&UI_TEXT.ae_reset).clicked() {
+            *draft = AdaptiveCurves::default();
+            changed = true;
+        }
+    });
+
+    if let Some(pair) = &state.preview_pair {
+        ui.label(
+            RichText::new(format!("{} {}", UI_TEXT.ae_previewing, pair))
+                .small()
+                .color(PLOT_CONFIG.color_warning),
+        );
+    }
+
+    if changed && action.is_none() {
+        let mut curves = draft.clone();
+        curves.normalize();
+        action = Some(AdaptiveEditorAction::Preview(curves));
+    }
+    action
+}
+
+fn render_curve_plot(ui: &mut Ui, curve: &AdaptiveCurve, current_ph: Option<PhPct>) {
+    let mut sorted = curve.clone();
+    sorted.normalize();
+    let (x_min, x_max) = match (sorted.points.first(), sorted.points.last()) {
+        (Some(first), Some(last)) if last[0] > first[0] => (first[0], last[0]),
+        _ => (0.0, 0.5),
+    };
+
+    let samples: Vec<[f64; 2]> = (0..=CURVE_SAMPLES)
+        .map(|i| {
+            let ph = x_min + (x_max - x_min) * i as f64 / CURVE_SAMPLES as f64;
+            [ph * 100.0, sorted.eval(ph)]
+        })
+        .collect();
+    let breakpoints: Vec<[f64; 2]> = sorted.points.iter().map(|p| [p[0] * 100.0, p[1]]).collect();
+
+    Plot::new("ae_curve_plot")
+        .height(160.0)
+        .allow_drag(false)
+        .allow_zoom(false)
+        .allow_scroll(false)
+        .show(ui, |plot_ui| {
+            plot_ui.line(
+                Line::new("", PlotPoints::new(samples))
+                    .color(PLOT_CONFIG.color_info)
+                    .width(1.5),
+            );
+            plot_ui.points(
+                Points::new("", PlotPoints::new(breakpoints))
+                    .radius(3.0)
+                    .color(PLOT_CONFIG.color_text_primary),
+            );
+            if let Some(ph) = current_ph {
+                plot_ui.vline(VLine::new("", ph.value() * 100.0).color(PLOT_CONFIG.color_warning));
+            }
+        });
+}
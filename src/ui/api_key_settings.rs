@@ -0,0 +1,162 @@
+use {
+    crate::{
+        data::{
+            AccountCheck, ApiCredentials, check_api_credentials, clear_api_credentials,
+            load_api_credentials, save_api_credentials,
+        },
+        ui::{PLOT_CONFIG, UI_TEXT},
+    },
+    eframe::egui::{Button, Grid, RichText, TextEdit, Ui},
+    std::{
+        sync::mpsc::{self, Receiver},
+        thread,
+    },
+    tokio::runtime::Runtime,
+};
+
+/// Engine settings form for the Binance API key. Inputs are cleared once saved; the stored
+/// pair lives only in the OS keychain.
+#[derive(Default)]
+pub(crate) struct ApiKeyForm {
+    api_key: String,
+    api_secret: String,
+    /// Masked key of the stored pair (None = public access); refreshed after each change
+    stored: Option<Option<String>>,
+    status: Option<(String, bool)>,
+    check_rx: Option<Receiver<Result<AccountCheck, String>>>,
+}
+
+impl ApiKeyForm {
+    fn refresh_stored(&mut self) {
+        self.stored = Some(match load_api_credentials() {
+            Ok(credentials) => credentials.map(|c| c.masked_key()),
+            Err(e) => {
+                self.status = Some((format!("{:#}", e), false));
+                None
+            }
+        });
+    }
+
+    fn set_result(&mut self, result: anyhow::Result<()>, ok_text: &str) {
+        self.status = Some(match result {
+            Ok(()) => (ok_text.to_string(), true),
+            Err(e) => (format!("{:#}", e), false),
+        });
+        self.refresh_stored();
+    }
+}
+
+pub(crate) fn render_api_key_section(ui: &mut Ui, form: &mut ApiKeyForm) {
+    if form.stored.is_none() {
+        form.refresh_stored();
+    }
+    if let Some(result) = form.check_rx.as_ref().and_then(|rx| rx.try_recv().ok()) {
+        form.check_rx = None;
+        form.status = Some(match result {
+            Ok(check) => (
+                format!(
+                    "{} {} · {} {}",
+                    UI_TEXT.ak_check_ok,
+                    if check.can_trade {
+                        &UI_TEXT.ak_can_trade
+                    } else {
+                        &UI_TEXT.ak_read_only
+                    },
+                    check.funded_assets,
+                    UI_TEXT.ak_funded_assets
+                ),
+                true,
+            ),
+            Err(e) => (e, false),
+        });
+    }
+
+    ui.label(RichText::new(&UI_TEXT.ak_heading).strong())
+        .on_hover_text(&UI_TEXT.ak_heading_hover);
+    let stored = form.stored.clone().flatten();
+    ui.label(
+        RichText::new(match &stored {
+            Some(masked) => format!("{} {}", UI_TEXT.ak_configured, masked),
+            None => UI_TEXT.ak_public.clone(),
+        })
+        .small()
+        .color(PLOT_CONFIG.color_text_subdued),
+    );
+    Grid::new("ak_grid")
+        .num_columns(2)
+        .spacing([10.0, 4.0])
+        .show(ui, |ui| {
+            ui.label(&UI_TEXT.ak_key);
+            ui.add(TextEdit::singleline(&mut form.api_key).desired_width(220.0));
+            ui.end_row();
+            ui.label(&UI_TEXT.ak_secret);
+            ui.add(
+                TextEdit::singleline(&mut form.api_secret)
+                    .password(true)
+                    .desired_width(220.0),
+            );
+            ui.end_row();
+        });
+    ui.horizontal(|ui| {
+        if ui
+            .button(&UI_TEXT.ak_save)
+            .on_hover_text(&UI_TEXT.ak_save_hover)
+            .clicked()
+        {
+            let credentials = ApiCredentials {
+                api_key: form.api_key.trim().to_string(),
+                api_secret: form.api_secret.trim().to_string(),
+            };
+            let result = save_api_credentials(&credentials);
+            if result.is_ok() {
+                form.api_key.clear();
+                form.api_secret.clear();
+            }
+            form.set_result(result, &UI_TEXT.ak_saved);
+        }
+        if ui
+            .add_enabled(stored.is_some(), Button::new(&UI_TEXT.ak_remove))
+            .clicked()
+        {
+            let result = clear_api_credentials();
+            form.set_result(result, &UI_TEXT.ak_removed);
+        }
+        let checking = form.check_rx.is_some();
+        if ui
+            .add_enabled(
+                stored.is_some() && !checking,
+                Button::new(&UI_TEXT.ak_check),
+            )
+            .on_hover_text(&UI_TEXT.ak_check_hover)
+            .clicked()
+        {
+            let (tx, rx) = mpsc::channel();
+            form.check_rx = Some(rx);
+            form.status = None;
+            thread::spawn(move || {
+                let result = Runtime::new()
+                    .map_err(anyhow::Error::from)
+                    .and_then(|rt| rt.block_on(check_api_credentials()))
+                    .map_err(|e| format!("{:#}", e));
+                let _ = tx.send(result);
+            });
+        }
+        if checking {
+            ui.spinner();
+            ui.ctx().request_repaint();
+        }
+    });
+    ui.label(
+        RichText::new(&UI_TEXT.ak_scope_note)
+            .small()
+            .color(PLOT_CONFIG.color_text_subdued),
+    );
+    if let Some((text, ok)) = &form.status {
+        let color = if *ok {
+            PLOT_CONFIG.color_profit
+        } else {
+            PLOT_CONFIG.color_loss
+        };
+        ui.label(RichText::new(text).small().color(color));
+    }
+}
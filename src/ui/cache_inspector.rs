@@ -0,0 +1,296 @@
+use {
+    crate::{
+        data::{
+            CacheAction, CacheActionOutcome, CacheInventory, apply_cache_action,
+            inspect_kline_cache, mock_exchange, shared_rate_limiter,
+        },
+        engine::format_bytes,
+        ui::{PLOT_CONFIG, UI_TEXT},
+        utils::TimeUtils,
+    },
+    eframe::egui::{Button, Context, Grid, RichText, ScrollArea, Ui, Window},
+    std::{
+        future::Future,
+        sync::mpsc::{self, Receiver},
+        thread,
+    },
+    tokio::runtime::Runtime,
+};
+
+type PendingAction = (CacheAction, String, String);
+
+/// Kline cache inspector window: what the database holds per (pair, interval), with
+/// refresh, delete and export per row. Scans when opened and after every action.
+#[derive(Default)]
+pub(crate) struct CacheInspector {
+    inventory: Option<Result<CacheInventory, String>>,
+    scan_rx: Option<Receiver<Result<CacheInventory, String>>>,
+    action_rx: Option<Receiver<Result<CacheActionOutcome, String>>>,
+    /// Row whose action is in flight
+    busy: Option<(String, String)>,
+    /// Row whose delete button waits for the confirming click
+    armed_delete: Option<(String, String)>,
+    status: Option<Result<String, String>>,
+}
+
+/// Runs `job` on its own runtime thread and hands back the receiver for its result.
+fn spawn_job<T, F>(job: impl FnOnce() -> F + Send + 'static) -> Receiver<Result<T, String>>
+where
+    T: Send + 'static,
+    F: Future<Output = anyhow::Result<T>>,
+{
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let result = Runtime::new()
+            .map_err(anyhow::Error::from)
+            .and_then(|rt| rt.block_on(job()))
+            .map_err(|e| format!("{:#}", e));
+        let _ = tx.send(result);
+    });
+    rx
+}
+
+impl CacheInspector {
+    fn is_busy(&self) -> bool {
+        self.scan_rx.is_some() || self.action_rx.is_some()
+    }
+
+    fn scan(&mut self) {
+        if self.scan_rx.is_none() {
+            self.scan_rx = Some(spawn_job(inspect_kline_cache));
+        }
+    }
+
+    fn start_action(&mut self, (action, pair, interval): PendingAction) {
+        self.busy = Some((pair.clone(), interval.clone()));
+        self.armed_delete = None;
+        self.action_rx = Some(spawn_job(move || async move {
+            apply_cache_action(action, &pair, &interval, &shared_rate_limiter()).await
+        }));
+    }
+
+    fn poll(&mut self) {
+        if let Some(result) = self.scan_rx.as_ref().and_then(|rx| rx.try_recv().ok()) {
+            self.scan_rx = None;
+            if let Err(e) = &result {
+                log::error!("Kline cache scan failed: {}", e);
+            }
+            self.inventory = Some(result);
+        }
+        let Some(result) = self.action_rx.as_ref().and_then(|rx| rx.try_recv().ok()) else {
+            return;
+        };
+        self.action_rx = None;
+        let (pair, interval) = self.busy.take().unwrap_or_default();
+        let series = format!("{} {}", pair, interval);
+        self.status = Some(match result {
+            Ok(CacheActionOutcome::Refreshed(count)) => {
+                Ok(format!("{} {} {}", count, UI_TEXT.ci_refreshed, series))
+            }
+            Ok(CacheActionOutcome::Deleted(count)) => {
+                Ok(format!("{} {} {}", count, UI_TEXT.ci_deleted, series))
+            }
+            Ok(CacheActionOutcome::Exported(path)) => {
+                Ok(format!("{} {}", UI_TEXT.label_exported_to, path.display()))
+            }
+            Err(e) => {
+                log::error!("Kline cache action on {} failed: {}", series, e);
+                Err(format!("{}: {}", UI_TEXT.ci_failed, e))
+            }
+        });
+        self.scan();
+    }
+}
+
+pub(crate) fn render_cache_inspector(ctx: &Context, open: &mut bool, state: &mut CacheInspector) {
+    if !*open {
+        return;
+    }
+    let mock = mock_exchange().is_some();
+    if !mock {
+        state.poll();
+        if state.inventory.is_none() {
+            state.scan();
+        }
+    }
+    if state.is_busy() {
+        ctx.request_repaint();
+    }
+    let mut pending: Option<PendingAction> = None;
+    Window::new(&UI_TEXT.ci_window_title)
+        .open(open)
+        .resizable(true)
+        .collapsible(false)
+        .default_width(640.0)
+        .show(ctx, |ui| {
+            if mock {
+                ui.label(RichText::new(&UI_TEXT.ci_mock).color(PLOT_CONFIG.color_text_subdued));
+                return;
+            }
+            ui.label(
+                RichText::new(&UI_TEXT.ci_intro)
+                    .small()
+                    .color(PLOT_CONFIG.color_text_subdued),
+            );
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(!state.is_busy(), Button::new(&UI_TEXT.ci_rescan))
+                    .clicked()
+                {
+                    state.scan();
+                }
+                if state.is_busy() {
+                    ui.spinner();
+                }
+            });
+            match &state.status {
+                Some(Ok(text)) => {
+                    ui.label(RichText::new(text).small());
+                }
+                Some(Err(text)) => {
+                    ui.label(RichText::new(text).small().color(PLOT_CONFIG.color_loss));
+                }
+                None => {}
+            }
+            ui.separator();
+            let busy = state.is_busy();
+            match &state.inventory {
+                None => {}
+                Some(Err(e)) => {
+                    ui.label(
+                        RichText::new(format!("{}: {}", UI_TEXT.ci_scan_failed, e))
+                            .color(PLOT_CONFIG.color_loss),
+                    );
+                }
+                Some(Ok(inventory)) => {
+                    pending = render_inventory(ui, inventory, busy, &mut state.armed_delete);
+                }
+            }
+        });
+    if let Some(action) = pending {
+        state.start_action(action);
+    }
+}
+
+fn render_inventory(
+    ui: &mut Ui,
+    inventory: &CacheInventory,
+    busy: bool,
+    armed_delete: &mut Option<(String, String)>,
+) -> Option<PendingAction> {
+    let mut pending = None;
+    if inventory.series.is_empty() {
+        ui.label(&UI_TEXT.ci_empty);
+    } else {
+        let now_ms = TimeUtils::now_timestamp_ms();
+        ScrollArea::vertical().max_height(360.0).show(ui, |ui| {
+            Grid::new("ci_series_grid")
+                .num_columns(7)
+                .striped(true)
+                .spacing([10.0, 2.0])
+                .show(ui, |ui| {
+                    ui.label(RichText::new(&UI_TEXT.ci_col_pair).small().strong());
+                    ui.label(RichText::new(&UI_TEXT.ci_col_interval).small().strong());
+                    ui.label(RichText::new(&UI_TEXT.ci_col_candles).small().strong());
+                    ui.label(RichText::new(&UI_TEXT.ci_col_range).small().strong());
+                    ui.label(RichText::new(&UI_TEXT.ci_col_size).small().strong())
+                        .on_hover_text(&UI_TEXT.ci_size_hover);
+                    ui.label(RichText::new(&UI_TEXT.ci_col_updated).small().strong())
+                        .on_hover_text(&UI_TEXT.ci_updated_hover);
+                    ui.label("");
+                    ui.end_row();
+                    for series in &inventory.series {
+                        let key = (series.pair.clone(), series.interval.clone());
+                        ui.label(RichText::new(&series.pair).strong());
+                        ui.label(&series.interval);
+                        ui.label(RichText::new(series.candles.to_string()).monospace());
+                        ui.label(format!(
+                            "{} → {}",
+                            TimeUtils::ms_to_datestring(series.first_ms),
+                            TimeUtils::ms_to_datestring(series.last_ms)
+                        ));
+                        ui.label(
+                            RichText::new(format!(
+                                "~{}",
+                                format_bytes(inventory.approx_bytes(series))
+                            ))
+                            .monospace(),
+                        );
+                        ui.label(format!(
+                            "{} {}",
+                            TimeUtils::format_duration((now_ms - series.last_ms).max(0)),
+                            UI_TEXT.ci_ago
+                        ));
+                        ui.horizontal(|ui| {
+                            ui.add_enabled_ui(!busy, |ui| {
+                                if ui
+                                    .small_button(&UI_TEXT.ci_refresh)
+                                    .on_hover_text(&UI_TEXT.ci_refresh_hover)
+                                    .clicked()
+                                {
+                                    pending =
+                                        Some((CacheAction::Refresh, key.0.clone(), key.1.clone()));
+                                }
+                                if ui
+                                    .small_button(&UI_TEXT.ci_export)
+                                    .on_hover_text(&UI_TEXT.ci_export_hover)
+                                    .clicked()
+                                {
+                                    pending =
+                                        Some((CacheAction::Export, key.0.clone(), key.1.clone()));
+                                }
+                                let armed = armed_delete.as_ref() == Some(&key);
+                                let label = if armed {
+                                    RichText::new(&UI_TEXT.ci_delete_confirm)
+                                        .color(PLOT_CONFIG.color_loss)
+                                } else {
+                                    RichText::new(&UI_TEXT.ci_delete)
+                                };
+                                if ui
+                                    .small_button(label)
+                                    .on_hover_text(&UI_TEXT.ci_delete_hover)
+                                    .clicked()
+                                {
+                                    if armed {
+                                        pending = Some((
+                                            CacheAction::Delete,
+                                            key.0.clone(),
+                                            key.1.clone(),
+                                        ));
+                                    } else {
+                                        *armed_delete = Some(key.clone());
+                                    }
+                                }
+                            });
+                        });
+                        ui.end_row();
+                    }
+                });
+        });
+    }
+    ui.separator();
+    ui.label(RichText::new(&UI_TEXT.ci_files_heading).strong());
+    Grid::new("ci_files_grid")
+        .num_columns(3)
+        .spacing([12.0, 2.0])
+        .show(ui, |ui| {
+            ui.label(&UI_TEXT.ci_database);
+            ui.label(RichText::new(format_bytes(inventory.db_bytes)).monospace());
+            ui.label("");
+            ui.end_row();
+            for file in &inventory.files {
+                ui.label(&file.name);
+                ui.label(RichText::new(format_bytes(file.bytes)).monospace());
+                ui.label(
+                    RichText::new(
+                        file.modified_ms
+                            .map(TimeUtils::ms_to_datestring)
+                            .unwrap_or_default(),
+                    )
+                    .color(PLOT_CONFIG.color_text_subdued),
+                );
+                ui.end_row();
+            }
+        });
+    pending
+}
@@ -0,0 +1,150 @@
+use {
+    crate::ui::{PLOT_CONFIG, UI_TEXT},
+    eframe::egui::{Context, Id, Order, RichText, ScrollArea, TextEdit, Ui, Window},
+    strum::IntoEnumIterator,
+    strum_macros::EnumIter,
+};
+
+/// Memory slot a "?" link writes to; the glossary window picks it up next frame.
+const GLOSSARY_REQUEST_ID: &str = "glossary_request";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter)]
+pub(crate) enum GlossaryTerm {
+    Roi,
+    Aroi,
+    SuccessRate,
+    RiskReward,
+    Grade,
+    PriceHorizon,
+    Coverage,
+    StickyZone,
+    RelativeVolume,
+    Momentum,
+    Volatility,
+    TimeDecay,
+}
+
+impl GlossaryTerm {
+    pub(crate) fn title(&self) -> &'static str {
+        match self {
+            Self::Roi => &UI_TEXT.gl_roi,
+            Self::Aroi => &UI_TEXT.gl_aroi,
+            Self::SuccessRate => &UI_TEXT.gl_success_rate,
+            Self::RiskReward => &UI_TEXT.gl_risk_reward,
+            Self::Grade => &UI_TEXT.gl_grade,
+            Self::PriceHorizon => &UI_TEXT.gl_ph,
+            Self::Coverage => &UI_TEXT.gl_coverage,
+            Self::StickyZone => &UI_TEXT.gl_sticky_zone,
+            Self::RelativeVolume => &UI_TEXT.gl_rel_volume,
+            Self::Momentum => &UI_TEXT.gl_momentum,
+            Self::Volatility => &UI_TEXT.gl_volatility,
+            Self::TimeDecay => &UI_TEXT.gl_decay,
+        }
+    }
+
+    pub(crate) fn definition(&self) -> &'static str {
+        match self {
+            Self::Roi => &UI_TEXT.gl_roi_def,
+            Self::Aroi => &UI_TEXT.gl_aroi_def,
+            Self::SuccessRate => &UI_TEXT.gl_success_rate_def,
+            Self::RiskReward => &UI_TEXT.gl_risk_reward_def,
+            Self::Grade => &UI_TEXT.gl_grade_def,
+            Self::PriceHorizon => &UI_TEXT.gl_ph_def,
+            Self::Coverage => &UI_TEXT.gl_coverage_def,
+            Self::StickyZone => &UI_TEXT.gl_sticky_zone_def,
+            Self::RelativeVolume => &UI_TEXT.gl_rel_volume_def,
+            Self::Momentum => &UI_TEXT.gl_momentum_def,
+            Self::Volatility => &UI_TEXT.gl_volatility_def,
+            Self::TimeDecay => &UI_TEXT.gl_decay_def,
+        }
+    }
+
+    fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        query.is_empty()
+            || self.title().to_lowercase().contains(&query)
+            || self.definition().to_lowercase().contains(&query)
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct GlossaryState {
+    pub open: bool,
+    search: String,
+    focus: Option<GlossaryTerm>,
+    /// Focused entry still needs scrolling into view
+    scroll_pending: bool,
+}
+
+/// Small "?" button that opens the glossary at `term`.
+pub(crate) fn help_link(ui: &mut Ui, term: GlossaryTerm) {
+    if ui
+        .small_button("?")
+        .on_hover_text(format!("{}: {}", UI_TEXT.gl_help_hover, term.title()))
+        .clicked()
+    {
+        ui.ctx()
+            .data_mut(|d| d.insert_temp(Id::new(GLOSSARY_REQUEST_ID), term));
+    }
+}
+
+pub(crate) fn render_glossary(ctx: &Context, state: &mut GlossaryState) {
+    if let Some(term) =
+        ctx.data_mut(|d| d.remove_temp::<GlossaryTerm>(Id::new(GLOSSARY_REQUEST_ID)))
+    {
+        state.open = true;
+        state.focus = Some(term);
+        state.scroll_pending = true;
+        state.search.clear();
+    }
+    if !state.open {
+        return;
+    }
+
+    let mut open = state.open;
+    Window::new(&UI_TEXT.gl_window_title)
+        .open(&mut open)
+        .resizable(true)
+        .order(Order::Tooltip)
+        .collapsible(false)
+        .default_width(420.0)
+        .default_height(420.0)
+        .show(ctx, |ui| {
+            ui.add(
+                TextEdit::singleline(&mut state.search)
+                    .hint_text(&UI_TEXT.gl_search)
+                    .desired_width(f32::INFINITY),
+            );
+            ui.separator();
+            ScrollArea::vertical().show(ui, |ui| {
+                let mut any = false;
+                for term in GlossaryTerm::iter().filter(|t| t.matches(&state.search)) {
+                    any = true;
+                    let focused = state.focus == Some(term);
+                    let mut title = RichText::new(term.title()).strong();
+                    if focused {
+                        title = title.color(PLOT_CONFIG.color_warning);
+                    }
+                    let response = ui.label(title);
+                    if focused && state.scroll_pending {
+                        if ui.is_rect_visible(response.rect) {
+                            state.scroll_pending = false;
+                        } else {
+                            response.scroll_to_me(None);
+                        }
+                    }
+                    ui.label(term.definition());
+                    ui.add_space(6.0);
+                }
+                if !any {
+                    ui.label(
+                        RichText::new(&UI_TEXT.gl_no_match).color(PLOT_CONFIG.color_text_subdued),
+                    );
+                }
+            });
+        });
+    state.open = open;
+    if !open {
+        state.focus = None;
+    }
+}
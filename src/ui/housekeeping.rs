@@ -0,0 +1,158 @@
+use {
+    crate::{
+        data::{HousekeepingReport, HousekeepingSettings, mock_exchange, run_housekeeping},
+        engine::format_bytes,
+        ui::{PLOT_CONFIG, UI_TEXT},
+    },
+    eframe::egui::{Button, DragValue, Grid, RichText, Ui},
+    std::{
+        sync::mpsc::{self, Receiver},
+        thread,
+    },
+    tokio::runtime::Runtime,
+};
+
+/// Background state of the kline housekeeping job started from engine settings or on startup.
+#[derive(Default)]
+pub(crate) struct HousekeepingJob {
+    rx: Option<Receiver<Result<HousekeepingReport, String>>>,
+    last: Option<Result<HousekeepingReport, String>>,
+}
+
+impl HousekeepingJob {
+    pub(crate) fn is_running(&self) -> bool {
+        self.rx.is_some()
+    }
+
+    /// Starts a run unless one is in flight. The mock exchange promises no DB writes.
+    pub(crate) fn start(&mut self, settings: HousekeepingSettings) {
+        if self.is_running() || mock_exchange().is_some() {
+            return;
+        }
+        let (tx, rx) = mpsc::channel();
+        self.rx = Some(rx);
+        thread::spawn(move || {
+            let result = Runtime::new()
+                .map_err(anyhow::Error::from)
+                .and_then(|rt| rt.block_on(run_housekeeping(&settings)))
+                .map_err(|e| format!("{:#}", e));
+            let _ = tx.send(result);
+        });
+    }
+
+    /// Collects a finished run. Intervals found in the database get a (keep-all) retention
+    /// entry, so each one can be configured.
+    pub(crate) fn poll(&mut self, settings: &mut HousekeepingSettings) {
+        let Some(result) = self.rx.as_ref().and_then(|rx| rx.try_recv().ok()) else {
+            return;
+        };
+        self.rx = None;
+        match &result {
+            Ok(report) => {
+                log::info!(
+                    "Housekeeping: {} expired + {} orphan candles removed, {} reclaimed",
+                    report.expired_candles,
+                    report.orphan_candles,
+                    format_bytes(report.reclaimed_bytes())
+                );
+                for interval in &report.intervals {
+                    settings.retention_days.entry(interval.clone()).or_insert(0);
+                }
+            }
+            Err(e) => log::error!("Housekeeping failed: {}", e),
+        }
+        self.last = Some(result);
+    }
+}
+
+fn format_report(report: &HousekeepingReport) -> String {
+    let mut text = format!(
+        "{} {} · {} {} · {} {}",
+        format_bytes(report.reclaimed_bytes()),
+        UI_TEXT.es_hk_reclaimed,
+        report.expired_candles,
+        UI_TEXT.es_hk_expired,
+        report.orphan_candles,
+        UI_TEXT.es_hk_orphaned,
+    );
+    if !report.orphan_pairs.is_empty() {
+        text.push_str(&format!(" ({})", report.orphan_pairs.join(", ")));
+    }
+    if report.stale_cache_files > 0 {
+        text.push_str(&format!(
+            " · {} {}",
+            report.stale_cache_files, UI_TEXT.es_hk_cache_files
+        ));
+    }
+    text
+}
+
+pub(crate) fn render_housekeeping_section(
+    ui: &mut Ui,
+    settings: &mut HousekeepingSettings,
+    job: &mut HousekeepingJob,
+) {
+    ui.label(RichText::new(&UI_TEXT.es_hk_heading).strong())
+        .on_hover_text(&UI_TEXT.es_hk_heading_hover);
+    Grid::new("es_hk_grid")
+        .num_columns(2)
+        .spacing([10.0, 4.0])
+        .show(ui, |ui| {
+            for (interval, days) in settings.retention_days.iter_mut() {
+                ui.label(format!("{} {}", UI_TEXT.es_hk_keep, interval))
+                    .on_hover_text(&UI_TEXT.es_hk_keep_hover);
+                ui.add(
+                    DragValue::new(days)
+                        .range(0..=3650)
+                        .custom_formatter(|d, _| {
+                            if d == 0.0 {
+                                UI_TEXT.es_hk_keep_all.clone()
+                            } else {
+                                format!("{} d", d)
+                            }
+                        }),
+                );
+                ui.end_row();
+            }
+        });
+    ui.checkbox(&mut settings.remove_orphans, &UI_TEXT.es_hk_orphans)
+        .on_hover_text(&UI_TEXT.es_hk_orphans_hover);
+    ui.checkbox(&mut settings.compact, &UI_TEXT.es_hk_compact)
+        .on_hover_text(&UI_TEXT.es_hk_compact_hover);
+    ui.checkbox(&mut settings.on_startup, &UI_TEXT.es_hk_on_startup);
+    ui.horizontal(|ui| {
+        let mock = mock_exchange().is_some();
+        if ui
+            .add_enabled(!job.is_running() && !mock, Button::new(&UI_TEXT.es_hk_run))
+            .on_hover_text(&UI_TEXT.es_hk_run_hover)
+            .on_disabled_hover_text(if mock {
+                &UI_TEXT.es_hk_mock
+            } else {
+                &UI_TEXT.es_hk_running
+            })
+            .clicked()
+        {
+            job.start(settings.clone());
+        }
+        if job.is_running() {
+            ui.spinner();
+        }
+    });
+    match &job.last {
+        Some(Ok(report)) => {
+            ui.label(
+                RichText::new(format_report(report))
+                    .small()
+                    .color(PLOT_CONFIG.color_text_subdued),
+            );
+        }
+        Some(Err(e)) => {
+            ui.label(
+                RichText::new(format!("{}: {}", UI_TEXT.es_hk_failed, e))
+                    .small()
+                    .color(PLOT_CONFIG.color_loss),
+            );
+        }
+        None => {}
+    }
+}
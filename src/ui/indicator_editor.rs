@@ -0,0 +1,111 @@
+use {
+    crate::{
+        models::{IndicatorExpr, IndicatorScript, indicator_field_names},
+        ui::{PLOT_CONFIG, UI_TEXT},
+    },
+    eframe::egui::{Button, Context, RichText, ScrollArea, TextEdit, Window},
+    std::collections::HashMap,
+};
+
+/// Per-pair list of scripted indicator lines. Scripts that don't parse say why here and are
+/// left off the plot.
+pub(crate) fn render_indicator_editor(
+    ctx: &Context,
+    open: &mut bool,
+    pair: Option<&str>,
+    scripts: &mut HashMap<String, Vec<IndicatorScript>>,
+) {
+    if !*open {
+        return;
+    }
+    let palette = &PLOT_CONFIG.indicator_palette;
+    Window::new(&UI_TEXT.ind_window_title)
+        .open(open)
+        .resizable(true)
+        .collapsible(false)
+        .default_width(460.0)
+        .show(ctx, |ui| {
+            let Some(pair) = pair else {
+                ui.label(RichText::new(&UI_TEXT.ind_no_pair).color(PLOT_CONFIG.color_text_subdued));
+                return;
+            };
+            ui.label(
+                RichText::new(&UI_TEXT.ind_intro)
+                    .small()
+                    .color(PLOT_CONFIG.color_text_subdued),
+            );
+            ui.heading(pair);
+            let fields_hover = format!(
+                "{}\n\n{}",
+                UI_TEXT.ind_fields_hover,
+                indicator_field_names().collect::<Vec<_>>().join(", ")
+            );
+            let list = scripts.entry(pair.to_string()).or_default();
+            let mut remove = None;
+            ScrollArea::vertical().max_height(360.0).show(ui, |ui| {
+                if list.is_empty() {
+                    ui.label(
+                        RichText::new(&UI_TEXT.ind_none).color(PLOT_CONFIG.color_text_subdued),
+                    );
+                }
+                for (idx, script) in list.iter_mut().enumerate() {
+                    ui.push_id(idx, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.checkbox(&mut script.enabled, "")
+                                .on_hover_text(&UI_TEXT.ind_enabled_hover);
+                            let swatch =
+                                RichText::new("■").color(palette[script.color % palette.len()]);
+                            if ui
+                                .add(Button::new(swatch).small())
+                                .on_hover_text(&UI_TEXT.ind_color_hover)
+                                .clicked()
+                            {
+                                script.color = (script.color + 1) % palette.len();
+                            }
+                            ui.add(TextEdit::singleline(&mut script.name).desired_width(120.0));
+                            if ui
+                                .small_button(&UI_TEXT.ind_delete)
+                                .on_hover_text(&UI_TEXT.ind_delete_hover)
+                                .clicked()
+                            {
+                                remove = Some(idx);
+                            }
+                        });
+                        let edited = ui
+                            .add(
+                                TextEdit::singleline(&mut script.source)
+                                    .code_editor()
+                                    .hint_text("(sma(close, 20) + ema(close, 50)) / 2")
+                                    .desired_width(f32::INFINITY),
+                            )
+                            .on_hover_text(&fields_hover)
+                            .changed();
+                        if edited || script.parsed.is_none() {
+                            script.parsed = Some(IndicatorExpr::parse(&script.source).map(|_| ()));
+                        }
+                        if let Some(Err(e)) = &script.parsed {
+                            ui.label(
+                                RichText::new(e.as_str())
+                                    .small()
+                                    .color(PLOT_CONFIG.color_loss),
+                            );
+                        }
+                    });
+                    ui.separator();
+                }
+            });
+            if let Some(idx) = remove {
+                list.remove(idx);
+            }
+            if ui.button(&UI_TEXT.ind_add).clicked() {
+                let color = list.len() % palette.len();
+                list.push(IndicatorScript {
+                    color,
+                    ..Default::default()
+                });
+            }
+            if list.is_empty() {
+                scripts.remove(pair);
+            }
+        });
+}
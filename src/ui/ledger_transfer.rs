@@ -0,0 +1,126 @@
+use {
+    crate::{
+        data::{export_ledger, import_ledger},
+        engine::SniperEngine,
+        models::{LedgerConflict, LedgerImportSummary},
+        ui::{PLOT_CONFIG, UI_TEXT},
+    },
+    eframe::egui::{Button, ComboBox, RichText, TextEdit, Ui},
+    std::path::Path,
+    strum::IntoEnumIterator,
+};
+
+/// Engine settings form for moving the ledger between machines or snapshotting it before a
+/// config experiment. The import path defaults to the last export.
+#[derive(Default)]
+pub(crate) struct LedgerTransferForm {
+    path: String,
+    conflict: LedgerConflict,
+    status: Option<(String, bool)>,
+}
+
+impl LedgerTransferForm {
+    fn export(&mut self, engine: &SniperEngine) {
+        self.status = Some(match export_ledger(&engine.engine_ledger) {
+            Ok(path) => {
+                self.path = path.display().to_string();
+                (format!("{} {}", UI_TEXT.label_exported_to, self.path), true)
+            }
+            Err(e) => {
+                log::error!("Failed to export ledger: {:#}", e);
+                (format!("{}: {:#}", UI_TEXT.error_export_failed, e), false)
+            }
+        });
+    }
+
+    fn import(&mut self, engine: &mut SniperEngine) {
+        let path = self.path.trim();
+        self.status = Some(match import_ledger(Path::new(path)) {
+            Ok(opportunities) => {
+                let summary = engine.import_ledger_opportunities(opportunities, self.conflict);
+                log::info!("Imported ledger from {}: {:?}", path, summary);
+                (format_import_summary(&summary), true)
+            }
+            Err(e) => {
+                log::error!("Failed to import ledger from {}: {:#}", path, e);
+                (
+                    format!("{}: {:#}", UI_TEXT.es_ledger_import_failed, e),
+                    false,
+                )
+            }
+        });
+    }
+}
+
+fn format_import_summary(summary: &LedgerImportSummary) -> String {
+    format!(
+        "{} {} · {} {} · {} {}",
+        UI_TEXT.es_ledger_added,
+        summary.added,
+        UI_TEXT.es_ledger_merged,
+        summary.merged,
+        UI_TEXT.es_ledger_renamed,
+        summary.renamed
+    )
+}
+
+pub(crate) fn render_ledger_transfer_section(
+    ui: &mut Ui,
+    form: &mut LedgerTransferForm,
+    engine: Option<&mut SniperEngine>,
+) {
+    ui.label(RichText::new(&UI_TEXT.es_ledger_transfer_heading).strong())
+        .on_hover_text(&UI_TEXT.es_ledger_transfer_hover);
+    let Some(engine) = engine else {
+        return;
+    };
+    ui.label(
+        RichText::new(format!(
+            "{} {}",
+            engine.engine_ledger.opportunities.len(),
+            UI_TEXT.es_ledger_count
+        ))
+        .small()
+        .color(PLOT_CONFIG.color_text_subdued),
+    );
+    if ui
+        .button(&UI_TEXT.es_ledger_export)
+        .on_hover_text(&UI_TEXT.es_ledger_export_hover)
+        .clicked()
+    {
+        form.export(engine);
+    }
+    ui.add(
+        TextEdit::singleline(&mut form.path)
+            .hint_text(&UI_TEXT.es_ledger_path_hint)
+            .desired_width(260.0),
+    );
+    ui.horizontal(|ui| {
+        ComboBox::from_id_salt("es_ledger_conflict")
+            .selected_text(form.conflict.label())
+            .show_ui(ui, |ui| {
+                for conflict in LedgerConflict::iter() {
+                    ui.selectable_value(&mut form.conflict, conflict, conflict.label());
+                }
+            })
+            .response
+            .on_hover_text(&UI_TEXT.es_ledger_conflict_hover);
+        if ui
+            .add_enabled(
+                !form.path.trim().is_empty(),
+                Button::new(&UI_TEXT.es_ledger_import),
+            )
+            .on_hover_text(&UI_TEXT.es_ledger_import_hover)
+            .clicked()
+        {
+            form.import(engine);
+        }
+    });
+    if let Some((text, ok)) = &form.status {
+        ui.label(RichText::new(text).small().color(if *ok {
+            PLOT_CONFIG.color_text_subdued
+        } else {
+            PLOT_CONFIG.color_loss
+        }));
+    }
+}
@@ -1,6 +1,24 @@
+mod adaptive_editor;
+#[cfg(not(target_arch = "wasm32"))]
+mod api_key_settings;
+#[cfg(not(target_arch = "wasm32"))]
+mod cache_inspector;
+mod glossary;
+#[cfg(not(target_arch = "wasm32"))]
+mod housekeeping;
+mod indicator_editor;
+#[cfg(not(target_arch = "wasm32"))]
+mod ledger_transfer;
+mod notifications;
+mod opportunity_script;
 mod plot;
 mod plot_layers;
+#[cfg(not(target_arch = "wasm32"))]
+mod profile_menu;
+mod recalc_history;
 mod screens;
+#[cfg(not(target_arch = "wasm32"))]
+mod signal_webhook;
 mod styles;
 mod ticker;
 mod time_tuner;
@@ -9,20 +27,65 @@ mod ui_panels;
 mod ui_plot_view;
 mod ui_render;
 mod ui_text;
+mod zone_settings;
 
 pub(crate) use {
+    adaptive_editor::{AdaptiveEditorAction, AdaptiveEditorState, render_adaptive_editor},
+    glossary::{GlossaryState, GlossaryTerm, help_link, render_glossary},
+    indicator_editor::render_indicator_editor,
+    notifications::{
+        NotificationCenter, NotificationLevel, render_notifications, request_notifications,
+    },
+    opportunity_script::{ScriptAlerts, render_opportunity_script_editor},
     plot::PLOT_CONFIG,
     plot_layers::{
-        BackgroundLayer, CandlestickLayer, HorizonLinesLayer, LayerContext, OpportunityLayer,
-        PlotLayer, PriceLineLayer, ReversalZoneLayer, SegmentSeparatorLayer, StickyZoneLayer,
+        AnnotationLayer, AnomalyLayer, BackgroundLayer, CandlestickLayer, CustomIndicatorLayer,
+        CustomZoneLayer, EconEventsLayer, ExternalSignalLayer, GhostModelLayer, HorizonLinesLayer,
+        IndicatorLine, JourneyLayer, JourneyOverlay, LayerContext, OpportunityLayer, PlotLayer,
+        PriceLineLayer, PriceMarksLayer, ReversalZoneLayer, SegmentSeparatorLayer,
+        SplitBackgroundLayer, StickyZoneLayer,
     },
+    recalc_history::{RecalcHistoryState, render_recalc_history, request_recalc_history},
     screens::render_bootstrap,
     styles::{DirectionColor, UiStyleExt, apply_opacity, get_momentum_color, get_outcome_color},
-    ticker::{TICKER, TickerState},
-    time_tuner::{TunerAction, render_time_tuner},
+    ticker::{TICKER, TickerState, is_material_improvement},
+    time_tuner::{
+        PH_SLIDER_MAX, PhSliderState, StationPreviewState, TunerAction, render_ph_slider,
+        render_time_tuner, render_tuner_scoring,
+    },
     ui_config::UI_CONFIG,
-    ui_panels::CandleRangePanel,
-    ui_plot_view::{PlotCache, PlotInteraction, PlotView, PlotVisibility},
-    ui_render::{NavigationState, NavigationTarget, ScrollBehavior, SortColumn, TradeFinderRow},
+    ui_panels::{
+        CandleRangePanel, JourneyBrowserPanel, SegmentStatsPopover, render_segment_stats_popover,
+    },
+    ui_plot_view::{
+        AnnotationDraft, CorrelatedWhatIf, CustomZoneDraft, PlotCache, PlotContextAction,
+        PlotInteraction, PlotView, PlotVisibility, PriceAlert, PriceMarks, PriceWhatIf,
+        SegmentLayout, TimeAxisMode, zoom_about,
+    },
+    ui_render::{
+        BasketState, HeatCalendarState, JourneyBrowserState, NavigationState, NavigationTarget,
+        PairMultiSelect, PanelLayout, ScrollBehavior, SortColumn, StatusBarSettings,
+        TradeFinderRefresh, TradeFinderRow,
+    },
     ui_text::UI_TEXT,
+    zone_settings::{
+        CoverageTargetsResponse, render_coverage_targets, render_trade_profile,
+        render_zone_settings,
+    },
+};
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) use screens::render_demo_select;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use screens::InstanceViewer;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use {
+    api_key_settings::{ApiKeyForm, render_api_key_section},
+    cache_inspector::{CacheInspector, render_cache_inspector},
+    housekeeping::{HousekeepingJob, render_housekeeping_section},
+    ledger_transfer::{LedgerTransferForm, render_ledger_transfer_section},
+    profile_menu::{ProfileMenu, render_profile_menu},
+    signal_webhook::{SignalWebhook, render_signal_webhook_section},
 };
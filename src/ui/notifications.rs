@@ -0,0 +1,131 @@
+use {
+    crate::{
+        app::TimestampMs,
+        ui::{PLOT_CONFIG, UI_TEXT},
+        utils::TimeUtils,
+    },
+    eframe::egui::{Color32, Context, Id, RichText, ScrollArea, Ui, Window},
+    std::collections::VecDeque,
+};
+
+/// Memory slot the status bar writes to; the notification window picks it up next frame.
+const NOTIFICATIONS_REQUEST_ID: &str = "notifications_request";
+
+/// Oldest entries are dropped beyond this
+const NOTIFICATIONS_CAP: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum NotificationLevel {
+    Info,
+    Warning,
+    Error,
+}
+
+impl NotificationLevel {
+    fn color(self) -> Color32 {
+        match self {
+            Self::Info => PLOT_CONFIG.color_text_subdued,
+            Self::Warning => PLOT_CONFIG.color_warning,
+            Self::Error => PLOT_CONFIG.color_loss,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Notification {
+    at: TimestampMs,
+    level: NotificationLevel,
+    text: String,
+    /// Consecutive identical reports are folded into one entry
+    repeats: usize,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct NotificationCenter {
+    pub open: bool,
+    entries: VecDeque<Notification>,
+    unread: usize,
+}
+
+impl NotificationCenter {
+    pub(crate) fn push(&mut self, level: NotificationLevel, text: impl Into<String>) {
+        let text = text.into();
+        self.unread += 1;
+        if let Some(last) = self
+            .entries
+            .back_mut()
+            .filter(|n| n.level == level && n.text == text)
+        {
+            last.at = TimestampMs::now();
+            last.repeats += 1;
+            return;
+        }
+        if self.entries.len() >= NOTIFICATIONS_CAP {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(Notification {
+            at: TimestampMs::now(),
+            level,
+            text,
+            repeats: 1,
+        });
+    }
+
+    pub(crate) fn unread(&self) -> usize {
+        self.unread
+    }
+}
+
+/// Asks the notification window to open (usable from `&self` status bar code).
+pub(crate) fn request_notifications(ui: &Ui) {
+    ui.ctx()
+        .data_mut(|d| d.insert_temp(Id::new(NOTIFICATIONS_REQUEST_ID), true));
+}
+
+pub(crate) fn render_notifications(ctx: &Context, center: &mut NotificationCenter) {
+    if ctx
+        .data_mut(|d| d.remove_temp::<bool>(Id::new(NOTIFICATIONS_REQUEST_ID)))
+        .is_some()
+    {
+        center.open = true;
+    }
+    if !center.open {
+        return;
+    }
+    center.unread = 0;
+
+    let mut open = center.open;
+    Window::new(&UI_TEXT.nc_title)
+        .open(&mut open)
+        .resizable(true)
+        .collapsible(false)
+        .default_width(420.0)
+        .default_height(300.0)
+        .show(ctx, |ui| {
+            if center.entries.is_empty() {
+                ui.label(RichText::new(&UI_TEXT.nc_empty).color(PLOT_CONFIG.color_text_subdued));
+                return;
+            }
+            if ui.small_button(&UI_TEXT.nc_clear).clicked() {
+                center.entries.clear();
+                return;
+            }
+            ui.separator();
+            ScrollArea::vertical()
+                .id_salt("notifications_list")
+                .show(ui, |ui| {
+                    for n in center.entries.iter().rev() {
+                        let mut text = format!(
+                            "{} {}",
+                            TimeUtils::ms_to_datetime(n.at.value()).format("%H:%M:%S"),
+                            n.text
+                        );
+                        if n.repeats > 1 {
+                            text.push_str(&format!(" (×{})", n.repeats));
+                        }
+                        ui.label(RichText::new(text).small().color(n.level.color()));
+                    }
+                });
+        });
+    center.open = open;
+}
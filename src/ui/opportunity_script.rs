@@ -0,0 +1,150 @@
+use {
+    crate::{
+        app::SortDirection,
+        models::{OpportunityScript, script_field_names},
+        ui::{PLOT_CONFIG, SortColumn, TradeFinderRow, UI_TEXT},
+        utils::AppInstant,
+    },
+    eframe::egui::{Grid, RichText, TextEdit, Ui},
+    std::{collections::HashSet, time::Duration},
+};
+
+/// Trade Finder rows are rebuilt for the alert check at most this often.
+const SCRIPT_ALERT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Opportunities matching the alert filter on the last check, so each alerts when it starts
+/// matching rather than on every check.
+#[derive(Default)]
+pub(crate) struct ScriptAlerts {
+    /// Filter the matches belong to; after a change the first check only records matches
+    filter: Option<String>,
+    matching: HashSet<String>,
+    last_check: Option<AppInstant>,
+}
+
+impl ScriptAlerts {
+    pub(crate) fn is_due(&self, script: &OpportunityScript) -> bool {
+        script.alert
+            && script.filter().is_some()
+            && self
+                .last_check
+                .is_none_or(|t| t.elapsed() >= SCRIPT_ALERT_INTERVAL)
+    }
+
+    /// Alert lines for rows that newly match the filter.
+    pub(crate) fn check(
+        &mut self,
+        script: &OpportunityScript,
+        rows: &[TradeFinderRow],
+    ) -> Vec<String> {
+        self.last_check = Some(AppInstant::now());
+        let Some(filter) = script.filter() else {
+            return Vec::new();
+        };
+        let seeding = self.filter.as_deref() != Some(script.filter.as_str());
+        let mut matching = HashSet::new();
+        let mut fired = Vec::new();
+        for row in rows {
+            let Some(fields) = row.script_fields() else {
+                continue;
+            };
+            if !filter.matches(&fields) {
+                continue;
+            }
+            let op = fields.op;
+            if !seeding && !self.matching.contains(&op.id) {
+                fired.push(format!(
+                    "{} {} · AROI {}",
+                    op.pair_name,
+                    op.direction,
+                    op.live_annualized_roi(row.current_price)
+                ));
+            }
+            matching.insert(op.id.clone());
+        }
+        self.filter = Some(script.filter.clone());
+        self.matching = matching;
+        fired
+    }
+}
+
+/// Editor row under the Trade Finder filters. Returns true when the visible rows may change.
+pub(crate) fn render_opportunity_script_editor(
+    ui: &mut Ui,
+    script: &mut OpportunityScript,
+    sort_col: &mut SortColumn,
+    sort_dir: &mut SortDirection,
+) -> bool {
+    let mut changed = false;
+    let fields_hover = format!(
+        "{}\n\n{}",
+        UI_TEXT.tf_script_fields_hover,
+        script_field_names().collect::<Vec<_>>().join(", ")
+    );
+    Grid::new("tf_script_grid")
+        .num_columns(3)
+        .spacing([6.0, 4.0])
+        .show(ui, |ui| {
+            ui.label(&UI_TEXT.tf_script_filter)
+                .on_hover_text(&fields_hover);
+            changed |= ui
+                .add(
+                    TextEdit::singleline(&mut script.filter)
+                        .code_editor()
+                        .hint_text(r#"aroi > 200 && sample_size >= 100 && direction == "long""#)
+                        .desired_width(360.0),
+                )
+                .changed();
+            ui.horizontal(|ui| {
+                changed |= ui
+                    .checkbox(&mut script.apply_filter, &UI_TEXT.tf_script_apply)
+                    .changed();
+                ui.checkbox(&mut script.alert, &UI_TEXT.tf_script_alert)
+                    .on_hover_text(&UI_TEXT.tf_script_alert_hover);
+            });
+            ui.end_row();
+            ui.label(&UI_TEXT.tf_script_score)
+                .on_hover_text(&fields_hover);
+            changed |= ui
+                .add(
+                    TextEdit::singleline(&mut script.score)
+                        .code_editor()
+                        .hint_text("aroi * success_rate / 100")
+                        .desired_width(360.0),
+                )
+                .changed();
+            let sorted = *sort_col == SortColumn::Script;
+            let clicked = ui
+                .add_enabled_ui(script.score_expr().is_some() || sorted, |ui| {
+                    ui.selectable_label(sorted, &UI_TEXT.tf_script_sort)
+                        .on_hover_text(&UI_TEXT.tf_script_sort_hover)
+                        .clicked()
+                })
+                .inner;
+            if clicked {
+                *sort_col = if sorted {
+                    SortColumn::default()
+                } else {
+                    SortColumn::Script
+                };
+                *sort_dir = SortDirection::Descending;
+                changed = true;
+            }
+            ui.end_row();
+        });
+    script.refresh();
+    let errors = [
+        (&UI_TEXT.tf_script_filter, script.filter_error()),
+        (&UI_TEXT.tf_script_score, script.score_error()),
+    ];
+    for (label, error) in errors {
+        if let Some(error) = error {
+            ui.label(
+                RichText::new(format!("{} {}", label, error))
+                    .small()
+                    .color(PLOT_CONFIG.color_loss),
+            );
+        }
+    }
+    changed
+}
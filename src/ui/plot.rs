@@ -3,6 +3,7 @@ use eframe::egui::Color32;
 pub struct PlotConfig {
     pub active_zone_stroke_color: Color32,
     pub active_zone_stroke_width: f32,
+    pub annotation_color: Color32,
     pub background_bar_intensity_pct: f32,
     pub candle_bearish_color: Color32,
     pub candle_bullish_color: Color32,
@@ -14,6 +15,10 @@ pub struct PlotConfig {
     pub color_info: Color32,
     pub color_long: Color32,
     pub color_loss: Color32,
+    /// Opportunity path through zones denser than `PATH_DENSITY_DENSE`
+    pub color_path_dense: Color32,
+    /// Opportunity path through zones thinner than `PATH_DENSITY_THIN`
+    pub color_path_thin: Color32,
     pub color_profit: Color32,
     pub color_separator: Color32,
     pub color_short: Color32,
@@ -26,7 +31,14 @@ pub struct PlotConfig {
     pub color_widget_border: Color32,
     pub current_price_color: Color32,
     pub current_price_line_width: f32,
+    /// Initial half-height of a custom zone added from the context menu, as a fraction of price
+    pub custom_zone_half_height_pct: f64,
+    /// Shift+drag pan speed relative to a plain drag
+    pub fine_pan_factor: f64,
     pub high_wicks_zone_color: Color32,
+    /// Colors a custom indicator can pick from, by index
+    pub indicator_palette: [Color32; 6],
+    pub indicator_line_width: f32,
     pub low_wicks_zone_color: Color32,
     pub opacity_path_line: f32,
     pub opacity_scope_base: f32,
@@ -38,13 +50,18 @@ pub struct PlotConfig {
     pub segment_gap_width_px: f64,
     pub sticky_zone_color: Color32,
     pub support_zone_color: Color32,
+    /// True-time axis: widest a single gap may get, as a fraction of the summed segment width
+    pub true_time_gap_max_pct: f64,
     pub zone_fill_opacity_pct: f32,
     pub zone_gradient_colors: &'static [&'static str],
+    /// Space above and below a double-clicked zone, as a fraction of its height
+    pub zone_snap_padding_pct: f64,
 }
 
 pub const PLOT_CONFIG: PlotConfig = PlotConfig {
     active_zone_stroke_color: Color32::from_rgb(255, 255, 255),
     active_zone_stroke_width: 1.5,
+    annotation_color: Color32::from_rgb(255, 182, 193),
     background_bar_intensity_pct: 0.5,
     candle_bearish_color: Color32::from_rgb(239, 83, 80),
     candle_bullish_color: Color32::from_rgb(38, 166, 154),
@@ -56,6 +73,8 @@ pub const PLOT_CONFIG: PlotConfig = PlotConfig {
     color_info: Color32::from_rgb(173, 216, 230),
     color_long: Color32::from_rgb(0, 191, 255),
     color_loss: Color32::from_rgb(255, 80, 80),
+    color_path_dense: Color32::from_rgb(255, 112, 67),
+    color_path_thin: Color32::from_rgb(100, 255, 218),
     color_profit: Color32::from_rgb(100, 255, 100),
     color_separator: Color32::from_gray(80),
     color_short: Color32::from_rgb(255, 165, 0),
@@ -68,7 +87,18 @@ pub const PLOT_CONFIG: PlotConfig = PlotConfig {
     color_widget_border: Color32::from_gray(60),
     current_price_color: Color32::from_rgb(255, 215, 0),
     current_price_line_width: 4.0,
+    custom_zone_half_height_pct: 0.0025,
+    fine_pan_factor: 0.2,
     high_wicks_zone_color: Color32::from_rgb(255, 0, 255),
+    indicator_palette: [
+        Color32::from_rgb(255, 213, 79),
+        Color32::from_rgb(79, 195, 247),
+        Color32::from_rgb(240, 98, 146),
+        Color32::from_rgb(174, 213, 129),
+        Color32::from_rgb(186, 104, 200),
+        Color32::from_rgb(255, 138, 101),
+    ],
+    indicator_line_width: 1.5,
     low_wicks_zone_color: Color32::from_rgb(0, 255, 255),
     opacity_path_line: 0.6,
     opacity_scope_base: 0.8,
@@ -80,8 +110,10 @@ pub const PLOT_CONFIG: PlotConfig = PlotConfig {
     segment_gap_width_px: 4.0,
     sticky_zone_color: Color32::from_rgb(148, 0, 211),
     support_zone_color: Color32::from_rgb(34, 139, 34),
+    true_time_gap_max_pct: 0.1,
     zone_fill_opacity_pct: 0.40,
     zone_gradient_colors: &[
         "#000080", "#4b0082", "#ffb703", "#ff8c00", "#ff4500", "#b22222", "#8b0000",
     ],
+    zone_snap_padding_pct: 0.5,
 };
@@ -2,17 +2,36 @@ use {
     crate::{
         app::{
             BASE_INTERVAL, CandleResolution, ClosePrice, HighPrice, LowPrice, OpenPrice, Price,
-            PriceLike,
+            PriceLike, TimestampMs,
+        },
+        data::{EconEvent, EventImpact, ExternalSignal},
+        models::{
+            CandleAnomaly, CustomZone, DiffZoneKind, GapReason, JourneyReplay, OhlcvTimeSeries,
+            OpportunityAnnotation, Outcome, PATH_DENSITY_DENSE, PATH_DENSITY_THIN, SuperZone,
+            TradeDirection, TradeOpportunity, TradingModel, merge_candles,
+        },
+        ui::{
+            DirectionColor, PLOT_CONFIG, PlotCache, PlotVisibility, PriceMarks, SegmentLayout,
+            UI_TEXT, apply_opacity,
         },
-        models::{GapReason, OhlcvTimeSeries, SuperZone, TradeOpportunity, TradingModel},
-        ui::{DirectionColor, PLOT_CONFIG, PlotCache, PlotVisibility, UI_TEXT, apply_opacity},
     },
     eframe::egui::{
-        Align2, Color32, FontId, Id, LayerId, Order, Painter, Pos2, Rect, Stroke, Vec2,
+        Align2, Color32, FontId, Id, LayerId, Order, Painter, Pos2, Rect, Shape, Stroke, Vec2,
     },
-    egui_plot::{Line, PlotPoint, PlotPoints, PlotUi, Polygon},
+    egui_plot::{Line, PlotPoint, PlotPoints, PlotUi, Polygon, VLine},
 };
 
+/// Highlight for a stretch of opportunity path with this density; None when it is unremarkable.
+fn path_density_color(density: f64) -> Option<Color32> {
+    if density < PATH_DENSITY_THIN {
+        Some(PLOT_CONFIG.color_path_thin)
+    } else if density > PATH_DENSITY_DENSE {
+        Some(PLOT_CONFIG.color_path_dense)
+    } else {
+        None
+    }
+}
+
 pub(crate) struct HorizonLinesLayer;
 
 pub(crate) struct OpportunityLayer;
@@ -55,18 +74,49 @@ impl PlotLayer for OpportunityLayer {
                     [current_pos_screen, target_pos_screen],
                     Stroke::new(2.0, path_color),
                 );
+                // Air pockets and congestion between price and target drawn over the path
+                let profile = ctx
+                    .trading_model
+                    .cva
+                    .path_profile(current_price, op.target_price.into());
+                for (low, high, density) in profile {
+                    let Some(color) = path_density_color(density) else {
+                        continue;
+                    };
+                    let [a, b] = [low, high].map(|price| {
+                        plot_ui.screen_from_plot(PlotPoint::new(x_center_plot, price))
+                    });
+                    painter.line_segment([a, b], Stroke::new(3.0, color));
+                }
                 let screen_rect = plot_ui.response().rect;
                 let sl_width_px = screen_rect.width() * 0.4;
                 let sl_left = sl_pos_screen - Vec2::new(sl_width_px / 2.0, 0.0);
                 let sl_right = sl_pos_screen + Vec2::new(sl_width_px / 2.0, 0.0);
                 painter.line_segment([sl_left, sl_right], Stroke::new(1.5, sl_color));
+                // Labels sit outside the entry..target / entry..stop bracket, which is
+                // upside down for shorts
+                let (stop_dy, stop_align, target_dy, target_align) = match op.direction {
+                    TradeDirection::Long => (4.0, Align2::LEFT_TOP, -18.0, Align2::LEFT_BOTTOM),
+                    TradeDirection::Short => (-4.0, Align2::LEFT_BOTTOM, 18.0, Align2::LEFT_TOP),
+                };
                 painter.text(
-                    sl_left + Vec2::new(0.0, -4.0),
-                    Align2::LEFT_BOTTOM,
-                    &UI_TEXT.label_stop_loss,
+                    sl_left + Vec2::new(0.0, stop_dy),
+                    stop_align,
+                    format!(
+                        "{} {:+.2}%",
+                        UI_TEXT.label_stop_loss,
+                        op.price_move(&op.stop_price) * 100.0
+                    ),
                     FontId::proportional(10.0),
                     sl_color,
                 );
+                painter.text(
+                    target_pos_screen + Vec2::new(18.0, target_dy),
+                    target_align,
+                    format!("{:+.2}%", op.price_move(&op.target_price) * 100.0),
+                    FontId::proportional(10.0),
+                    scope_color,
+                );
                 painter.circle_stroke(target_pos_screen, 15.0, Stroke::new(2.0, scope_color));
                 let hair_len = 20.0;
                 let faint_stroke = Stroke::new(1.0, crosshair_color);
@@ -140,6 +190,94 @@ impl PlotLayer for HorizonLinesLayer {
     }
 }
 
+/// User-placed horizontal lines (solid) and price alerts (dashed; dimmed once triggered).
+pub(crate) struct PriceMarksLayer;
+
+impl PlotLayer for PriceMarksLayer {
+    fn render(&self, plot_ui: &mut PlotUi, ctx: &LayerContext) {
+        let Some(marks) = ctx.price_marks else {
+            return;
+        };
+        let painter = plot_ui
+            .ctx()
+            .layer_painter(LayerId::new(Order::Foreground, Id::new("price_marks")))
+            .with_clip_rect(ctx.clip_rect);
+        let x_left = ctx.clip_rect.left();
+        let x_right = ctx.clip_rect.right();
+
+        for line in &marks.lines {
+            let y = plot_ui
+                .screen_from_plot(PlotPoint::new(0.0, line.value()))
+                .y;
+            painter.line_segment(
+                [Pos2::new(x_left, y), Pos2::new(x_right, y)],
+                Stroke::new(1.0, PLOT_CONFIG.color_info),
+            );
+        }
+        for alert in &marks.alerts {
+            let y = plot_ui
+                .screen_from_plot(PlotPoint::new(0.0, alert.price.value()))
+                .y;
+            let color = if alert.triggered {
+                PLOT_CONFIG.color_text_subdued
+            } else {
+                PLOT_CONFIG.color_warning
+            };
+            draw_dashed_line(
+                &painter,
+                Pos2::new(x_left, y),
+                Pos2::new(x_right, y),
+                Stroke::new(1.5, color),
+                6.0,
+                4.0,
+            );
+            painter.text(
+                Pos2::new(x_left + 4.0, y - 2.0),
+                Align2::LEFT_BOTTOM,
+                format!("{} {}", UI_TEXT.plot_alert_label, alert.price),
+                FontId::proportional(11.0),
+                color,
+            );
+        }
+    }
+}
+
+/// Notes on the selected opportunity: each label sits off to the left with an arrow to its
+/// price near the live edge. Labels flip below the arrow when they would leave the plot.
+pub(crate) struct AnnotationLayer;
+
+impl PlotLayer for AnnotationLayer {
+    fn render(&self, plot_ui: &mut PlotUi, ctx: &LayerContext) {
+        let painter = plot_ui
+            .ctx()
+            .layer_painter(LayerId::new(Order::Foreground, Id::new("annotations")))
+            .with_clip_rect(ctx.clip_rect);
+        let stroke = Stroke::new(1.5, PLOT_CONFIG.annotation_color);
+
+        for (i, note) in ctx.annotations.iter().enumerate() {
+            let y = plot_ui
+                .screen_from_plot(PlotPoint::new(0.0, note.price.value()))
+                .y;
+            // Stagger so notes on nearby prices don't draw over each other
+            let tip = Pos2::new(ctx.clip_rect.right() - 40.0 - 16.0 * i as f32, y);
+            let above = tip + Vec2::new(-50.0, -30.0);
+            let (origin, align) = if above.y < ctx.clip_rect.top() + 14.0 {
+                (tip + Vec2::new(-50.0, 30.0), Align2::RIGHT_TOP)
+            } else {
+                (above, Align2::RIGHT_BOTTOM)
+            };
+            painter.arrow(origin, tip - origin, stroke);
+            painter.text(
+                origin,
+                align,
+                &note.text,
+                FontId::proportional(12.0),
+                PLOT_CONFIG.annotation_color,
+            );
+        }
+    }
+}
+
 pub(crate) struct CandlestickLayer;
 
 impl PlotLayer for CandlestickLayer {
@@ -148,9 +286,6 @@ impl PlotLayer for CandlestickLayer {
             return;
         }
 
-        let mut segment_start_visual_x = 0.0;
-        let agg_interval_ms = ctx.resolution.duration().as_millis() as i64;
-
         let view_width_steps = (ctx.x_max - ctx.x_min).abs();
         let screen_width_px = plot_ui.response().rect.width() as f64;
 
@@ -165,78 +300,27 @@ impl PlotLayer for CandlestickLayer {
         let step = batch_size.max(1);
         let render_width = step as f64 * PLOT_CONFIG.candle_width_pct;
 
-        for segment in &ctx.trading_model.segments {
-            let seg_start_ts = ctx.ohlcv.get_candle(segment.start_idx).timestamp_ms;
-            let grid_start_ts = (seg_start_ts / agg_interval_ms) * agg_interval_ms;
-
-            let mut i = segment.start_idx;
-
-            while i < segment.end_idx {
-                let mut batch_open = 0.0;
-                let mut batch_high = f64::MIN;
-                let mut batch_low = f64::MAX;
-                let mut batch_close = 0.0;
-                let mut steps_processed = 0;
-
-                let first_candle_ts = ctx.ohlcv.get_candle(i).timestamp_ms;
-                let current_grid_ts = (first_candle_ts / agg_interval_ms) * agg_interval_ms;
-
-                while steps_processed < step && i < segment.end_idx {
-                    let first = ctx.ohlcv.get_candle(i);
-
-                    let boundary_start = (first.timestamp_ms / agg_interval_ms) * agg_interval_ms;
-                    let boundary_end = boundary_start + agg_interval_ms;
-                    let open = first.open_price.value();
-                    let mut close = first.close_price.value();
-                    let mut high = first.high_price.value();
-                    let mut low = first.low_price.value();
-
-                    let mut next_i = i + 1;
-                    while next_i < segment.end_idx {
-                        let c = ctx.ohlcv.get_candle(next_i);
-                        if c.timestamp_ms >= boundary_end {
-                            break;
-                        }
-                        high = high.max(c.high_price.value());
-                        low = low.min(c.low_price.value());
-                        close = c.close_price.value();
-                        next_i += 1;
-                    }
-
-                    if steps_processed == 0 {
-                        batch_open = open;
-                    }
-                    batch_high = batch_high.max(high);
-                    batch_low = batch_low.min(low);
-                    batch_close = close;
+        for (seg_idx, segment) in ctx.trading_model.segments.iter().enumerate() {
+            let bars = ctx.ohlcv.aggregated_segment(ctx.resolution, segment);
 
-                    i = next_i;
-                    steps_processed += 1;
-                }
+            // Zoomed out: fold `step` bars into one so candles stay at least a pixel wide
+            for batch in bars.chunks(step) {
+                let batch_ts = batch[0].timestamp_ms;
+                let merged = merge_candles(batch_ts, batch);
+                let draw_x = ctx.layout.slot_x(seg_idx, batch_ts) + 0.5; // +0.5 to center in slot
 
-                if steps_processed > 0 {
-                    let time_offset = (current_grid_ts - grid_start_ts) / agg_interval_ms;
-                    let draw_x = segment_start_visual_x + time_offset as f64 + 0.5; // +0.5 to center in slot
-
-                    draw_split_candle(
-                        plot_ui,
-                        draw_x,
-                        OpenPrice::new(batch_open),
-                        HighPrice::new(batch_high),
-                        LowPrice::new(batch_low),
-                        ClosePrice::new(batch_close),
-                        render_width,
-                        ctx.ph_bounds,
-                        ctx.x_min,
-                    );
-                }
+                draw_split_candle(
+                    plot_ui,
+                    draw_x,
+                    merged.open_price,
+                    merged.high_price,
+                    merged.low_price,
+                    merged.close_price,
+                    render_width,
+                    ctx.ph_bounds,
+                    ctx.x_min,
+                );
             }
-
-            let last_candle_ts = ctx.ohlcv.get_candle(segment.end_idx - 1).timestamp_ms;
-            let segment_duration = last_candle_ts - seg_start_ts;
-            let segment_width = (segment_duration / agg_interval_ms) as f64 + 1.0;
-
-            segment_start_visual_x += segment_width + PLOT_CONFIG.segment_gap_width_px;
         }
     }
 }
@@ -366,22 +450,345 @@ fn draw_body_rect(
     );
 }
 
+/// A matched historical journey mapped onto the chart, with the live trade's
+/// target/stop distances scaled to the historical entry price.
+pub(crate) struct JourneyOverlay {
+    pub start_idx: usize,
+    pub end_idx: usize,
+    pub entry: Price,
+    pub target: Price,
+    pub stop: Price,
+    pub outcome: Outcome,
+}
+
+impl JourneyOverlay {
+    pub(crate) fn new(
+        journey: &JourneyReplay,
+        op: &TradeOpportunity,
+        ohlcv: &OhlcvTimeSeries,
+    ) -> Option<Self> {
+        if journey.start_idx >= ohlcv.klines() || !op.start_price.is_positive() {
+            return None;
+        }
+        let entry = Price::new(ohlcv.get_candle(journey.start_idx).close_price.value());
+        let scale = entry.value() / op.start_price.value();
+        let end_idx = (journey.start_idx + journey.candle_count(op.max_duration_candles()))
+            .min(ohlcv.klines() - 1);
+        Some(Self {
+            start_idx: journey.start_idx,
+            end_idx,
+            entry,
+            target: Price::new(op.target_price.value() * scale),
+            stop: Price::new(op.stop_price.value() * scale),
+            outcome: journey.outcome,
+        })
+    }
+}
+
+pub(crate) struct JourneyLayer;
+
+impl PlotLayer for JourneyLayer {
+    fn render(&self, plot_ui: &mut PlotUi, ctx: &LayerContext) {
+        let Some(journey) = ctx.journey else {
+            return;
+        };
+        let Some(x_start) = visual_x_for_candle(ctx, journey.start_idx) else {
+            return;
+        };
+        // Journeys that run past a segment edge end in a gap; project by candle count instead
+        let candles_per_x =
+            ctx.resolution.duration().as_millis() as f64 / BASE_INTERVAL.as_millis() as f64;
+        let x_end = visual_x_for_candle(ctx, journey.end_idx)
+            .unwrap_or(x_start + (journey.end_idx - journey.start_idx) as f64 / candles_per_x)
+            .max(x_start + 1.0);
+
+        let outcome_color = match journey.outcome {
+            Outcome::TargetHit(_) => PLOT_CONFIG.color_profit,
+            Outcome::StopHit(_) => PLOT_CONFIG.color_loss,
+            Outcome::TimedOut(_) => PLOT_CONFIG.color_warning,
+        };
+        for (price, color, label) in [
+            (journey.entry, PLOT_CONFIG.color_text_primary, ""),
+            (journey.target, outcome_color, UI_TEXT.label_target.as_str()),
+            (
+                journey.stop,
+                PLOT_CONFIG.color_stop_loss,
+                UI_TEXT.label_stop_loss.as_str(),
+            ),
+        ] {
+            plot_ui.line(
+                Line::new(
+                    label,
+                    PlotPoints::new(vec![[x_start, price.value()], [x_end, price.value()]]),
+                )
+                .color(color)
+                .width(1.5),
+            );
+        }
+        plot_ui.line(
+            Line::new(
+                "",
+                PlotPoints::new(vec![
+                    [x_start, journey.stop.value()],
+                    [x_start, journey.target.value()],
+                ]),
+            )
+            .color(apply_opacity(outcome_color, PLOT_CONFIG.opacity_path_line))
+            .width(1.0),
+        );
+    }
+}
+
+/// Rings around anomalous candles at the price that makes them suspect (the wick tip for flash
+/// wicks, the close for outage candles).
+pub(crate) struct AnomalyLayer;
+
+impl PlotLayer for AnomalyLayer {
+    fn render(&self, plot_ui: &mut PlotUi, ctx: &LayerContext) {
+        let painter = plot_ui
+            .ctx()
+            .layer_painter(LayerId::new(Order::Foreground, Id::new("anomalies")))
+            .with_clip_rect(ctx.clip_rect);
+
+        for (idx, anomaly) in ctx.trading_model.anomalies.iter() {
+            let Some(x) = visual_x_for_candle(ctx, idx) else {
+                continue;
+            };
+            let (price, color) = match anomaly {
+                CandleAnomaly::FlashWick => {
+                    let open = ctx.ohlcv.open_prices[idx].value();
+                    let close = ctx.ohlcv.close_prices[idx].value();
+                    let high = ctx.ohlcv.high_prices[idx].value();
+                    let low = ctx.ohlcv.low_prices[idx].value();
+                    let tip = if high - open.max(close) >= open.min(close) - low {
+                        high
+                    } else {
+                        low
+                    };
+                    (tip, PLOT_CONFIG.color_warning)
+                }
+                CandleAnomaly::ZeroVolume | CandleAnomaly::FrozenPrice => (
+                    ctx.ohlcv.close_prices[idx].value(),
+                    PLOT_CONFIG.color_text_subdued,
+                ),
+            };
+            let pos = plot_ui.screen_from_plot(PlotPoint::new(x, price));
+            if ctx.clip_rect.contains(pos) {
+                painter.circle_stroke(pos, 4.0, Stroke::new(1.5, color));
+            }
+        }
+    }
+}
+
+/// Vertical dotted markers for scheduled macro events, labelled at the top of the plot.
+pub(crate) struct EconEventsLayer;
+
+impl PlotLayer for EconEventsLayer {
+    fn render(&self, plot_ui: &mut PlotUi, ctx: &LayerContext) {
+        let painter = plot_ui
+            .ctx()
+            .layer_painter(LayerId::new(Order::Foreground, Id::new("econ_events")))
+            .with_clip_rect(ctx.clip_rect);
+        let y_top = ctx.clip_rect.top();
+        let y_bot = ctx.clip_rect.bottom();
+
+        for event in ctx.econ_events {
+            let Some(x) = ctx.layout.x_for_timestamp(TimestampMs::new(event.time_ms)) else {
+                continue;
+            };
+            let x_screen = plot_ui.screen_from_plot(PlotPoint::new(x, 0.0)).x;
+            if x_screen < ctx.clip_rect.left() || x_screen > ctx.clip_rect.right() {
+                continue;
+            }
+            let color = match event.impact {
+                EventImpact::High => PLOT_CONFIG.color_warning,
+                EventImpact::Medium => PLOT_CONFIG.color_text_subdued,
+            };
+            draw_dashed_line(
+                &painter,
+                Pos2::new(x_screen, y_top),
+                Pos2::new(x_screen, y_bot),
+                Stroke::new(1.0, color),
+                2.0,
+                3.0,
+            );
+            painter.text(
+                Pos2::new(x_screen + 3.0, y_top + 2.0),
+                Align2::LEFT_TOP,
+                &event.title,
+                FontId::proportional(10.0),
+                color,
+            );
+        }
+    }
+}
+
+/// Triangles for signals POSTed to the webhook: pointing up under the price for longs, down
+/// over it for shorts, tagged with their source. Signals without a price aren't drawn.
+pub(crate) struct ExternalSignalLayer;
+
+impl PlotLayer for ExternalSignalLayer {
+    fn render(&self, plot_ui: &mut PlotUi, ctx: &LayerContext) {
+        const SIZE: f32 = 7.0;
+        let painter = plot_ui
+            .ctx()
+            .layer_painter(LayerId::new(Order::Foreground, Id::new("external_signals")))
+            .with_clip_rect(ctx.clip_rect);
+
+        for signal in ctx.external_signals {
+            let Some(price) = signal.price else {
+                continue;
+            };
+            let Some(x) = ctx.layout.x_for_timestamp(TimestampMs::new(signal.time_ms)) else {
+                continue;
+            };
+            let tip = plot_ui.screen_from_plot(PlotPoint::new(x, price.value()));
+            if !ctx.clip_rect.contains(tip) {
+                continue;
+            }
+            let color = signal.direction.color();
+            // Base away from the price, tip on it
+            let base = match signal.direction {
+                TradeDirection::Long => SIZE * 2.0,
+                TradeDirection::Short => -SIZE * 2.0,
+            };
+            let points = vec![
+                tip,
+                Pos2::new(tip.x - SIZE, tip.y + base),
+                Pos2::new(tip.x + SIZE, tip.y + base),
+            ];
+            painter.add(Shape::convex_polygon(
+                points,
+                apply_opacity(color, 0.6),
+                Stroke::new(1.5, color),
+            ));
+            let (label_pos, align) = match signal.direction {
+                TradeDirection::Long => (Pos2::new(tip.x, tip.y + base + 2.0), Align2::CENTER_TOP),
+                TradeDirection::Short => {
+                    (Pos2::new(tip.x, tip.y + base - 2.0), Align2::CENTER_BOTTOM)
+                }
+            };
+            painter.text(
+                label_pos,
+                align,
+                format!("{} {}", UI_TEXT.plot_external_tag, signal.source),
+                FontId::proportional(10.0),
+                color,
+            );
+        }
+    }
+}
+
+/// One evaluated custom indicator: a value per candle of the whole aggregated series.
+pub(crate) struct IndicatorLine {
+    pub name: String,
+    pub color: Color32,
+    pub values: Vec<f64>,
+}
+
+/// User-scripted indicator lines over the candles, folded to the candle layer's step and
+/// broken wherever the indicator has no value or a segment ends.
+pub(crate) struct CustomIndicatorLayer;
+
+impl PlotLayer for CustomIndicatorLayer {
+    fn render(&self, plot_ui: &mut PlotUi, ctx: &LayerContext) {
+        let all = ctx.ohlcv.aggregated(ctx.resolution);
+        let view_width_steps = (ctx.x_max - ctx.x_min).abs();
+        let screen_width_px = (plot_ui.response().rect.width() as f64).max(1.0);
+        let step = ((view_width_steps / screen_width_px).ceil() as usize).max(1);
+
+        for line in ctx.indicators {
+            let draw = |plot_ui: &mut PlotUi, run: Vec<[f64; 2]>| {
+                if run.len() > 1 {
+                    plot_ui.line(
+                        Line::new(line.name.as_str(), PlotPoints::new(run))
+                            .color(line.color)
+                            .width(PLOT_CONFIG.indicator_line_width),
+                    );
+                }
+            };
+            for (seg_idx, segment) in ctx.trading_model.segments.iter().enumerate() {
+                let bars = ctx.ohlcv.aggregated_segment(ctx.resolution, segment);
+                let Some(first) = bars.first() else {
+                    continue;
+                };
+                // Values index the whole series; find where this segment starts in it
+                let offset = all.partition_point(|c| c.timestamp_ms < first.timestamp_ms);
+                let mut run = Vec::new();
+                for (i, candle) in bars.iter().enumerate().step_by(step) {
+                    let value = line.values.get(offset + i).copied().unwrap_or(f64::NAN);
+                    if value.is_finite() {
+                        let x = ctx.layout.slot_x(seg_idx, candle.timestamp_ms) + 0.5;
+                        run.push([x, value]);
+                    } else {
+                        draw(plot_ui, std::mem::take(&mut run));
+                    }
+                }
+                draw(plot_ui, run);
+            }
+        }
+    }
+}
+
+/// Maps a candle index to its visual x position (same layout rules as CandlestickLayer).
+fn visual_x_for_candle(ctx: &LayerContext, idx: usize) -> Option<f64> {
+    let agg_interval_ms = ctx.resolution.duration().as_millis() as i64;
+    let seg_idx = ctx
+        .trading_model
+        .segments
+        .iter()
+        .position(|segment| idx >= segment.start_idx && idx < segment.end_idx)?;
+    let ts = ctx.ohlcv.get_candle(idx).timestamp_ms;
+    Some(ctx.layout.slot_x(seg_idx, ts.align_down(agg_interval_ms)) + 0.5)
+}
+
 pub(crate) struct LayerContext<'a> {
     pub trading_model: &'a TradingModel,
     pub ohlcv: &'a OhlcvTimeSeries,
     pub cache: &'a PlotCache,
+    pub compare_cache: Option<&'a PlotCache>,
     pub visibility: &'a PlotVisibility,
     pub x_min: f64,
     pub x_max: f64,
+    pub layout: &'a SegmentLayout,
     pub current_price: Option<Price>,
     pub resolution: CandleResolution,
     pub ph_bounds: (Price, Price),
     pub clip_rect: Rect,
     pub selected_opportunity: &'a Option<TradeOpportunity>,
+    pub journey: Option<&'a JourneyOverlay>,
+    pub price_marks: Option<&'a PriceMarks>,
+    /// User-drawn zones for this pair
+    pub custom_zones: &'a [CustomZone],
+    /// Notes on the selected opportunity
+    pub annotations: &'a [OpportunityAnnotation],
+    pub econ_events: &'a [EconEvent],
+    /// Webhook signals for this pair
+    pub external_signals: &'a [ExternalSignal],
+    /// Model of a Time Tuner station being previewed, drawn as outlines over the live one
+    pub ghost_model: Option<&'a TradingModel>,
+    /// Enabled custom indicators for this pair
+    pub indicators: &'a [IndicatorLine],
 }
 
 pub(crate) trait PlotLayer {
     fn render(&self, ui: &mut PlotUi, ctx: &LayerContext);
+
+    /// Zone drawn by this layer at `price`, for double-click snapping. Only zone layers
+    /// answer; overlapping zones resolve to the narrowest.
+    fn hit_zone(&self, _ctx: &LayerContext, _price: Price) -> Option<SuperZone> {
+        None
+    }
+}
+
+fn narrowest_zone_at<'a>(
+    zones: impl Iterator<Item = &'a SuperZone>,
+    price: Price,
+) -> Option<&'a SuperZone> {
+    let height = |z: &SuperZone| z.price_top.value() - z.price_bottom.value();
+    zones
+        .filter(|z| z.contains(price))
+        .min_by(|a, b| height(a).total_cmp(&height(b)))
 }
 
 pub(crate) struct BackgroundLayer;
@@ -420,6 +827,46 @@ impl PlotLayer for BackgroundLayer {
     }
 }
 
+/// A/B view: primary score type grows leftwards from the centre line, comparison rightwards.
+pub(crate) struct SplitBackgroundLayer;
+
+impl PlotLayer for SplitBackgroundLayer {
+    fn render(&self, plot_ui: &mut PlotUi, ctx: &LayerContext) {
+        let Some(compare) = ctx.compare_cache else {
+            return;
+        };
+        let half_width = (ctx.x_max - ctx.x_min) / 2.0;
+        if half_width <= f64::EPSILON {
+            return;
+        }
+        let x_mid = ctx.x_min + half_width;
+
+        for (cache, direction) in [(ctx.cache, -1.0), (compare, 1.0)] {
+            for bar in &cache.bars {
+                let half_h = bar.height / 2.0;
+                let rect_x_end = x_mid + direction * bar.x_max * half_width;
+                let points = PlotPoints::new(vec![
+                    [x_mid, bar.y_center - half_h],
+                    [rect_x_end, bar.y_center - half_h],
+                    [rect_x_end, bar.y_center + half_h],
+                    [x_mid, bar.y_center + half_h],
+                ]);
+                plot_ui.polygon(
+                    Polygon::new("", points)
+                        .fill_color(bar.color)
+                        .stroke(Stroke::NONE),
+                );
+            }
+        }
+
+        plot_ui.vline(
+            VLine::new("", x_mid)
+                .color(PLOT_CONFIG.color_text_subdued)
+                .width(1.0),
+        );
+    }
+}
+
 pub(crate) struct StickyZoneLayer;
 
 impl PlotLayer for StickyZoneLayer {
@@ -460,6 +907,13 @@ impl PlotLayer for StickyZoneLayer {
             );
         }
     }
+
+    fn hit_zone(&self, ctx: &LayerContext, price: Price) -> Option<SuperZone> {
+        if !ctx.visibility.sticky {
+            return None;
+        }
+        narrowest_zone_at(ctx.trading_model.zones.sticky_superzones.iter(), price).cloned()
+    }
 }
 
 // REVERSAL ZONE LAYER (Wicks)
@@ -468,46 +922,194 @@ pub(crate) struct ReversalZoneLayer;
 impl PlotLayer for ReversalZoneLayer {
     fn render(&self, plot_ui: &mut PlotUi, ctx: &LayerContext) {
         let current_price = ctx.current_price;
-
-        if ctx.visibility.low_wicks {
-            for superzone in &ctx.trading_model.zones.low_wicks_superzones {
-                let color = PLOT_CONFIG.low_wicks_zone_color;
+        let painter = plot_ui
+            .ctx()
+            .layer_painter(LayerId::new(Order::Foreground, Id::new("zone_strength")))
+            .with_clip_rect(ctx.clip_rect);
+        // Right edge of the half-width triangles
+        let label_x = ctx.x_min + (ctx.x_max - ctx.x_min) * 0.75;
+
+        for (visible, zones, color, shape) in [
+            (
+                ctx.visibility.low_wicks,
+                &ctx.trading_model.zones.low_wicks_superzones,
+                PLOT_CONFIG.low_wicks_zone_color,
+                ZoneShape::TriangleUp,
+            ),
+            (
+                ctx.visibility.high_wicks,
+                &ctx.trading_model.zones.high_wicks_superzones,
+                PLOT_CONFIG.high_wicks_zone_color,
+                ZoneShape::TriangleDown,
+            ),
+        ] {
+            if !visible {
+                continue;
+            }
+            for superzone in zones {
                 let stroke = get_stroke(superzone, current_price, color);
+                let strength = superzone.strength;
+                // Weak zones fade out; the strongest of each type keeps the full 1.5x fill
+                let opacity = 1.5 * (0.3 + 0.7 * strength.score as f32);
 
                 draw_superzone(
-                    plot_ui,
-                    superzone,
-                    ctx.x_min,
-                    ctx.x_max,
-                    "",
-                    color,
-                    stroke,
-                    0.5,
-                    1.5,
-                    ZoneShape::TriangleUp,
+                    plot_ui, superzone, ctx.x_min, ctx.x_max, "", color, stroke, 0.5, opacity,
+                    shape,
                 );
+
+                if strength.touches > 0 {
+                    let pos = plot_ui
+                        .screen_from_plot(PlotPoint::new(label_x, superzone.price_center.value()));
+                    let mut label = format!(
+                        "{}{} {:.1}%",
+                        strength.touches,
+                        UI_TEXT.plot_zone_touches,
+                        strength.avg_rejection_pct * 100.0
+                    );
+                    // Held / tested from each side
+                    for (arrow, stats) in [
+                        (&UI_TEXT.plot_zone_from_above, strength.from_above),
+                        (&UI_TEXT.plot_zone_from_below, strength.from_below),
+                    ] {
+                        if stats.tests > 0 {
+                            label.push_str(&format!(" {}{}/{}", arrow, stats.held, stats.tests));
+                        }
+                    }
+                    painter.text(
+                        pos + Vec2::new(4.0, 0.0),
+                        Align2::LEFT_CENTER,
+                        label,
+                        FontId::proportional(10.0),
+                        apply_opacity(color, 0.5 + 0.5 * strength.score as f32),
+                    );
+                }
             }
         }
+    }
 
-        if ctx.visibility.high_wicks {
-            for superzone in &ctx.trading_model.zones.high_wicks_superzones {
-                let color = PLOT_CONFIG.high_wicks_zone_color;
-                let stroke = get_stroke(superzone, current_price, color);
+    fn hit_zone(&self, ctx: &LayerContext, price: Price) -> Option<SuperZone> {
+        let zones = &ctx.trading_model.zones;
+        let low = zones
+            .low_wicks_superzones
+            .iter()
+            .filter(|_| ctx.visibility.low_wicks);
+        let high = zones
+            .high_wicks_superzones
+            .iter()
+            .filter(|_| ctx.visibility.high_wicks);
+        narrowest_zone_at(low.chain(high), price).cloned()
+    }
+}
 
-                draw_superzone(
-                    plot_ui,
-                    superzone,
-                    ctx.x_min,
-                    ctx.x_max,
-                    "",
-                    color,
-                    stroke,
-                    0.5,
-                    1.5,
-                    ZoneShape::TriangleDown,
-                );
-            }
+// CUSTOM ZONE LAYER (User-drawn)
+pub(crate) struct CustomZoneLayer;
+
+impl CustomZoneLayer {
+    fn color(kind: DiffZoneKind) -> Color32 {
+        match kind {
+            DiffZoneKind::Sticky => PLOT_CONFIG.sticky_zone_color,
+            DiffZoneKind::Support => PLOT_CONFIG.support_zone_color,
+            DiffZoneKind::Resistance => PLOT_CONFIG.resistance_zone_color,
+        }
+    }
+}
+
+impl PlotLayer for CustomZoneLayer {
+    fn render(&self, plot_ui: &mut PlotUi, ctx: &LayerContext) {
+        let painter = plot_ui
+            .ctx()
+            .layer_painter(LayerId::new(Order::Foreground, Id::new("custom_zones")))
+            .with_clip_rect(ctx.clip_rect);
+
+        for zone in ctx.custom_zones {
+            let color = Self::color(zone.kind);
+            let superzone = zone.to_superzone();
+            draw_superzone(
+                plot_ui,
+                &superzone,
+                ctx.x_min,
+                ctx.x_max,
+                &zone.label,
+                color,
+                Stroke::new(PLOT_CONFIG.active_zone_stroke_width, color),
+                1.0,
+                0.5,
+                ZoneShape::Rectangle,
+            );
+            let y = plot_ui
+                .screen_from_plot(PlotPoint::new(0.0, zone.price_top.value()))
+                .y;
+            let label = if zone.alert {
+                format!("{} {}", zone.label, UI_TEXT.plot_custom_zone_alert)
+            } else {
+                zone.label.clone()
+            };
+            painter.text(
+                Pos2::new(ctx.clip_rect.left() + 4.0, y + 2.0),
+                Align2::LEFT_TOP,
+                label,
+                FontId::proportional(11.0),
+                color,
+            );
+        }
+    }
+
+    fn hit_zone(&self, ctx: &LayerContext, price: Price) -> Option<SuperZone> {
+        let zones: Vec<SuperZone> = ctx.custom_zones.iter().map(|z| z.to_superzone()).collect();
+        narrowest_zone_at(zones.iter(), price).cloned()
+    }
+}
+
+/// Previewed station: its sticky zones as unfilled outlines and its price horizon as dashed
+/// lines, so the live model stays readable underneath.
+pub(crate) struct GhostModelLayer;
+
+impl PlotLayer for GhostModelLayer {
+    fn render(&self, plot_ui: &mut PlotUi, ctx: &LayerContext) {
+        let Some(ghost) = ctx.ghost_model else {
+            return;
+        };
+        let color = PLOT_CONFIG.color_warning;
+        for superzone in &ghost.zones.sticky_superzones {
+            draw_superzone(
+                plot_ui,
+                superzone,
+                ctx.x_min,
+                ctx.x_max,
+                "",
+                Color32::TRANSPARENT,
+                Stroke::new(1.5, color),
+                1.0,
+                0.0,
+                ZoneShape::Rectangle,
+            );
+        }
+
+        let painter = plot_ui
+            .ctx()
+            .layer_painter(LayerId::new(Order::Foreground, Id::new("ghost_model")))
+            .with_clip_rect(ctx.clip_rect);
+        let (ph_min, ph_max) = ghost.cva.price_range.min_max();
+        let stroke = Stroke::new(1.0, color);
+        for price in [ph_min, ph_max] {
+            let y = plot_ui.screen_from_plot(PlotPoint::new(0.0, price)).y;
+            draw_dashed_line(
+                &painter,
+                Pos2::new(ctx.clip_rect.left(), y),
+                Pos2::new(ctx.clip_rect.right(), y),
+                stroke,
+                4.0,
+                6.0,
+            );
         }
+        let y = plot_ui.screen_from_plot(PlotPoint::new(0.0, ph_max)).y;
+        painter.text(
+            Pos2::new(ctx.clip_rect.right() - 4.0, y - 2.0),
+            Align2::RIGHT_BOTTOM,
+            &UI_TEXT.plot_ghost_label,
+            FontId::proportional(11.0),
+            color,
+        );
     }
 }
 
@@ -520,10 +1122,6 @@ impl PlotLayer for SegmentSeparatorLayer {
             return;
         }
 
-        let gap_width = PLOT_CONFIG.segment_gap_width_px;
-        let mut visual_x = 0.0;
-        let step_size = ctx.resolution.steps_from(BASE_INTERVAL);
-
         let painter = plot_ui
             .ctx()
             .layer_painter(LayerId::new(Order::Foreground, Id::new("separators")))
@@ -532,42 +1130,35 @@ impl PlotLayer for SegmentSeparatorLayer {
         let y_top = ctx.clip_rect.top();
         let y_bot = ctx.clip_rect.bottom();
 
-        for (seg_idx, segment) in ctx.trading_model.segments.iter().enumerate() {
-            let seg_candles_vis =
-                ((segment.end_idx - segment.start_idx) as f64 / step_size as f64).ceil();
-            visual_x += seg_candles_vis;
-
-            if seg_idx < ctx.trading_model.segments.len() - 1 {
-                let line_plot_x = visual_x + (gap_width / 2.0);
-
-                let x_screen = plot_ui.screen_from_plot(PlotPoint::new(line_plot_x, 0.0)).x;
-                if x_screen >= ctx.clip_rect.left() && x_screen <= ctx.clip_rect.right() {
-                    let next_segment = &ctx.trading_model.segments[seg_idx + 1];
-
-                    let base_color = match next_segment.gap_reason {
-                        GapReason::PriceAbovePH => PLOT_CONFIG.color_gap_above,
-                        GapReason::PriceBelowPH => PLOT_CONFIG.color_gap_below,
-                        GapReason::MissingSourceData => PLOT_CONFIG.color_gap_missing,
-                        _ => PLOT_CONFIG.color_separator, // Mixed/Generic -> Default Gray
-                    };
+        for (seg_idx, next_segment) in ctx.trading_model.segments.iter().enumerate().skip(1) {
+            let Some(line_plot_x) = ctx.layout.gap_center(seg_idx - 1) else {
+                continue;
+            };
+            let x_screen = plot_ui.screen_from_plot(PlotPoint::new(line_plot_x, 0.0)).x;
+            if x_screen < ctx.clip_rect.left() || x_screen > ctx.clip_rect.right() {
+                continue;
+            }
 
-                    let stroke = Stroke::new(
-                        1.0,
-                        apply_opacity(base_color, PLOT_CONFIG.opacity_separator),
-                    );
+            let base_color = match next_segment.gap_reason {
+                GapReason::PriceAbovePH => PLOT_CONFIG.color_gap_above,
+                GapReason::PriceBelowPH => PLOT_CONFIG.color_gap_below,
+                GapReason::MissingSourceData => PLOT_CONFIG.color_gap_missing,
+                _ => PLOT_CONFIG.color_separator, // Mixed/Generic -> Default Gray
+            };
 
-                    draw_dashed_line(
-                        &painter,
-                        Pos2::new(x_screen, y_top),
-                        Pos2::new(x_screen, y_bot),
-                        stroke,
-                        5.0, // Dash
-                        5.0, // Gap
-                    );
-                }
+            let stroke = Stroke::new(
+                1.0,
+                apply_opacity(base_color, PLOT_CONFIG.opacity_separator),
+            );
 
-                visual_x += gap_width;
-            }
+            draw_dashed_line(
+                &painter,
+                Pos2::new(x_screen, y_top),
+                Pos2::new(x_screen, y_bot),
+                stroke,
+                5.0, // Dash
+                5.0, // Gap
+            );
         }
     }
 }
@@ -601,6 +1192,7 @@ impl PlotLayer for PriceLineLayer {
     }
 }
 
+#[derive(Clone, Copy)]
 enum ZoneShape {
     Rectangle,
     TriangleUp,
@@ -0,0 +1,76 @@
+use {
+    crate::{
+        config::active_profile,
+        data::ProfileRegistry,
+        ui::{PLOT_CONFIG, UI_TEXT},
+    },
+    eframe::egui::{Button, RichText, TextEdit, Ui},
+};
+
+/// Profile switcher in the top toolbar. A pick is only queued here: the switch itself needs
+/// eframe storage, which `App::update` hands over on the next frame.
+#[derive(Default)]
+pub(crate) struct ProfileMenu {
+    pub registry: ProfileRegistry,
+    new_name: String,
+    error: Option<String>,
+    pending: Option<String>,
+}
+
+impl ProfileMenu {
+    pub(crate) fn new(registry: ProfileRegistry) -> Self {
+        Self {
+            registry,
+            ..Self::default()
+        }
+    }
+
+    pub(crate) fn take_pending(&mut self) -> Option<String> {
+        self.pending.take()
+    }
+}
+
+pub(crate) fn render_profile_menu(ui: &mut Ui, menu: &mut ProfileMenu) {
+    let active = active_profile();
+    ui.menu_button(format!("{} {}", UI_TEXT.icon_profile, active), |ui| {
+        for name in &menu.registry.profiles {
+            if ui.selectable_label(*name == active, name).clicked() {
+                if *name != active {
+                    menu.pending = Some(name.clone());
+                }
+                ui.close();
+            }
+        }
+        ui.separator();
+        ui.horizontal(|ui| {
+            ui.add(
+                TextEdit::singleline(&mut menu.new_name)
+                    .hint_text(&UI_TEXT.pf_new_hint)
+                    .desired_width(140.0),
+            );
+            if ui
+                .add_enabled(
+                    !menu.new_name.trim().is_empty(),
+                    Button::new(&UI_TEXT.pf_create),
+                )
+                .on_hover_text(&UI_TEXT.pf_create_hover)
+                .clicked()
+            {
+                match menu.registry.create(&menu.new_name) {
+                    Ok(name) => {
+                        menu.new_name.clear();
+                        menu.error = None;
+                        menu.pending = Some(name);
+                        ui.close();
+                    }
+                    Err(e) => menu.error = Some(format!("{:#}", e)),
+                }
+            }
+        });
+        if let Some(error) = &menu.error {
+            ui.label(RichText::new(error).small().color(PLOT_CONFIG.color_loss));
+        }
+    })
+    .response
+    .on_hover_text(&UI_TEXT.pf_menu_hover);
+}
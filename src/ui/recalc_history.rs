@@ -0,0 +1,232 @@
+use {
+    crate::{
+        app::{Price, PriceLike, TimestampMs},
+        engine::{RecalcRecord, SniperEngine},
+        models::{DiffChange, DiffZoneKind, ModelDiff, OpportunitySummary},
+        ui::{PLOT_CONFIG, UI_TEXT},
+        utils::TimeUtils,
+    },
+    eframe::egui::{Color32, Context, Grid, Id, RichText, ScrollArea, Ui, Window},
+};
+
+/// Memory slot the status bar writes to; the history window picks it up next frame.
+const RECALC_HISTORY_REQUEST_ID: &str = "recalc_history_request";
+
+#[derive(Debug, Default)]
+pub(crate) struct RecalcHistoryState {
+    pub open: bool,
+    /// Identified by completion time, since the history drops its oldest entries
+    selected: Option<TimestampMs>,
+}
+
+/// Asks the recalc history window to open (usable from `&self` status bar code).
+pub(crate) fn request_recalc_history(ui: &Ui) {
+    ui.ctx()
+        .data_mut(|d| d.insert_temp(Id::new(RECALC_HISTORY_REQUEST_ID), true));
+}
+
+pub(crate) fn render_recalc_history(
+    ctx: &Context,
+    engine: Option<&SniperEngine>,
+    state: &mut RecalcHistoryState,
+) {
+    if ctx
+        .data_mut(|d| d.remove_temp::<bool>(Id::new(RECALC_HISTORY_REQUEST_ID)))
+        .is_some()
+    {
+        state.open = true;
+    }
+    if !state.open {
+        return;
+    }
+    let Some(engine) = engine else {
+        return;
+    };
+    let history = engine.recalc_history();
+
+    let mut open = state.open;
+    Window::new(&UI_TEXT.md_window_title)
+        .open(&mut open)
+        .resizable(true)
+        .collapsible(false)
+        .default_width(520.0)
+        .default_height(420.0)
+        .show(ctx, |ui| {
+            if history.is_empty() {
+                ui.label(
+                    RichText::new(&UI_TEXT.md_no_history).color(PLOT_CONFIG.color_text_subdued),
+                );
+                return;
+            }
+            ui.columns(2, |cols| {
+                ScrollArea::vertical()
+                    .id_salt("md_history_list")
+                    .show(&mut cols[0], |ui| {
+                        for record in history.iter().rev() {
+                            let selected = state.selected == Some(record.completed_at);
+                            if ui
+                                .selectable_label(selected, history_entry_label(record))
+                                .clicked()
+                            {
+                                state.selected = Some(record.completed_at);
+                            }
+                        }
+                    });
+                let ui = &mut cols[1];
+                match history
+                    .iter()
+                    .find(|r| Some(r.completed_at) == state.selected)
+                {
+                    Some(record) => {
+                        ScrollArea::vertical()
+                            .id_salt("md_history_detail")
+                            .show(ui, |ui| render_diff(ui, &record.diff));
+                    }
+                    None => {
+                        ui.label(
+                            RichText::new(&UI_TEXT.md_select).color(PLOT_CONFIG.color_text_subdued),
+                        );
+                    }
+                }
+            });
+        });
+    state.open = open;
+}
+
+fn history_entry_label(record: &RecalcRecord) -> String {
+    let (za, zr, zc) = record.diff.zone_counts();
+    let (oa, or, oc) = record.diff.opportunity_counts();
+    format!(
+        "{} {:<10} Z +{}/-{}/~{}  O +{}/-{}/~{}",
+        TimeUtils::ms_to_datetime(record.completed_at.value()).format("%H:%M:%S"),
+        record.pair,
+        za,
+        zr,
+        zc,
+        oa,
+        or,
+        oc
+    )
+}
+
+fn change_label(change: DiffChange) -> (&'static str, Color32) {
+    match change {
+        DiffChange::Added => (&UI_TEXT.md_added, PLOT_CONFIG.color_profit),
+        DiffChange::Removed => (&UI_TEXT.md_removed, PLOT_CONFIG.color_loss),
+        DiffChange::Changed => (&UI_TEXT.md_changed, PLOT_CONFIG.color_warning),
+    }
+}
+
+fn render_diff(ui: &mut Ui, diff: &ModelDiff) {
+    if diff.is_empty() {
+        ui.label(RichText::new(&UI_TEXT.md_empty).color(PLOT_CONFIG.color_text_subdued));
+        return;
+    }
+
+    ui.label(RichText::new(&UI_TEXT.md_coverage).strong().small());
+    let delta = diff.coverage_delta();
+    Grid::new("md_coverage_grid")
+        .num_columns(4)
+        .spacing([12.0, 2.0])
+        .show(ui, |ui| {
+            let rows = [
+                (
+                    &UI_TEXT.md_sticky,
+                    diff.coverage_before.sticky_pct,
+                    diff.coverage_after.sticky_pct,
+                    delta.sticky_pct,
+                ),
+                (
+                    &UI_TEXT.md_support,
+                    diff.coverage_before.support_pct,
+                    diff.coverage_after.support_pct,
+                    delta.support_pct,
+                ),
+                (
+                    &UI_TEXT.md_resistance,
+                    diff.coverage_before.resistance_pct,
+                    diff.coverage_after.resistance_pct,
+                    delta.resistance_pct,
+                ),
+            ];
+            for (label, before, after, change) in rows {
+                ui.label(label);
+                ui.label(RichText::new(format!("{:.1}%", before)).monospace());
+                ui.label(RichText::new(format!("{:.1}%", after)).monospace());
+                let color = if change > 0.0 {
+                    PLOT_CONFIG.color_profit
+                } else if change < 0.0 {
+                    PLOT_CONFIG.color_loss
+                } else {
+                    PLOT_CONFIG.color_text_subdued
+                };
+                ui.label(
+                    RichText::new(format!("{:+.1}%", change))
+                        .monospace()
+                        .color(color),
+                );
+                ui.end_row();
+            }
+        });
+
+    ui.separator();
+    ui.label(RichText::new(&UI_TEXT.md_zones).strong().small());
+    if diff.zones.is_empty() {
+        ui.label(RichText::new(&UI_TEXT.md_empty).color(PLOT_CONFIG.color_text_subdued));
+    }
+    for zone in &diff.zones {
+        let (text, color) = change_label(zone.change);
+        let kind = match zone.kind {
+            DiffZoneKind::Sticky => &UI_TEXT.md_sticky,
+            DiffZoneKind::Support => &UI_TEXT.md_support,
+            DiffZoneKind::Resistance => &UI_TEXT.md_resistance,
+        };
+        let bounds = |b: Option<(Price, Price)>| {
+            b.map_or_else(
+                || "-".to_string(),
+                |(bottom, top)| format!("{}-{}", bottom.format_price(), top.format_price()),
+            )
+        };
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(text).small().color(color));
+            ui.label(RichText::new(format!("{} #{}", kind, zone.id)).small());
+            ui.label(
+                RichText::new(format!("{} → {}", bounds(zone.before), bounds(zone.after)))
+                    .small()
+                    .monospace(),
+            );
+        });
+    }
+
+    ui.separator();
+    ui.label(RichText::new(&UI_TEXT.md_opportunities).strong().small());
+    if diff.opportunities.is_empty() {
+        ui.label(RichText::new(&UI_TEXT.md_empty).color(PLOT_CONFIG.color_text_subdued));
+    }
+    for op in &diff.opportunities {
+        let (text, color) = change_label(op.change);
+        let summary = |s: Option<OpportunitySummary>| {
+            s.map_or_else(
+                || "-".to_string(),
+                |s| {
+                    format!(
+                        "{} {} {}",
+                        s.target_price.format_price(),
+                        s.success_rate,
+                        s.expected_roi
+                    )
+                },
+            )
+        };
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(text).small().color(color));
+            ui.label(RichText::new(op.direction.to_string()).small())
+                .on_hover_text(&op.id);
+            ui.label(
+                RichText::new(format!("{} → {}", summary(op.before), summary(op.after)))
+                    .small()
+                    .monospace(),
+            );
+        });
+    }
+}
@@ -0,0 +1,67 @@
+use {
+    crate::{
+        config::DEMO,
+        ui::{PLOT_CONFIG, UI_TEXT},
+    },
+    eframe::egui::{Button, CentralPanel, Context, RichText, vec2},
+};
+
+/// Web demo start screen. Returns the id of the dataset the user picked this frame.
+/// `saved_import` adds an entry for the last drag & drop import kept in IndexedDB.
+pub(crate) fn render_demo_select(ctx: &Context, saved_import: bool) -> Option<&'static str> {
+    let mut picked = None;
+    CentralPanel::default().show(ctx, |ui| {
+        ui.vertical_centered(|ui| {
+            ui.add_space(40.0);
+            ui.heading(
+                RichText::new(&UI_TEXT.ds_title)
+                    .size(24.0)
+                    .strong()
+                    .color(PLOT_CONFIG.color_warning),
+            );
+            ui.label(
+                RichText::new(&UI_TEXT.ds_subtitle)
+                    .italics()
+                    .color(PLOT_CONFIG.color_text_neutral),
+            );
+            ui.add_space(20.0);
+
+            for dataset in DEMO.datasets {
+                let text = format!(
+                    "{}\n{} · {} {}",
+                    dataset.label,
+                    dataset.description,
+                    dataset.pairs.len(),
+                    UI_TEXT.ds_pairs
+                );
+                if ui
+                    .add(Button::new(text).min_size(vec2(280.0, 44.0)))
+                    .on_hover_text(dataset.pairs.join(", "))
+                    .clicked()
+                {
+                    picked = Some(dataset.id);
+                }
+                ui.add_space(6.0);
+            }
+            if saved_import {
+                let text = format!(
+                    "{}\n{}",
+                    UI_TEXT.ds_saved_import, UI_TEXT.ds_saved_import_desc
+                );
+                if ui
+                    .add(Button::new(text).min_size(vec2(280.0, 44.0)))
+                    .clicked()
+                {
+                    picked = Some(DEMO.saved_import_id);
+                }
+            }
+            ui.add_space(14.0);
+            ui.label(
+                RichText::new(&UI_TEXT.ds_drop_hint)
+                    .small()
+                    .color(PLOT_CONFIG.color_text_subdued),
+            );
+        });
+    });
+    picked
+}
@@ -0,0 +1,161 @@
+use {
+    crate::{
+        data::{InstanceStatus, RunningInstance, fetch_instance_status},
+        ui::{PLOT_CONFIG, UI_TEXT},
+        utils::AppInstant,
+    },
+    eframe::egui::{self, Button, CentralPanel, Context, Grid, RichText, ScrollArea},
+    std::{
+        sync::mpsc::{self, Receiver},
+        thread,
+        time::Duration,
+    },
+};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Opened instead of the main window when another instance holds the data directory. Explains
+/// why, and can follow the running instance's state over localhost without touching any files.
+pub struct InstanceViewer {
+    running: RunningInstance,
+    following: bool,
+    status: Option<Result<InstanceStatus, String>>,
+    rx: Option<Receiver<Result<InstanceStatus, String>>>,
+    last_poll: Option<AppInstant>,
+}
+
+impl InstanceViewer {
+    pub(crate) fn new(running: RunningInstance) -> Self {
+        Self {
+            running,
+            following: false,
+            status: None,
+            rx: None,
+            last_poll: None,
+        }
+    }
+
+    fn poll(&mut self, ctx: &Context) {
+        if let Some(rx) = &self.rx {
+            if let Ok(result) = rx.try_recv() {
+                self.status = Some(result);
+                self.rx = None;
+            }
+        }
+        let Some(port) = self.running.port else {
+            return;
+        };
+        let due = self.last_poll.is_none_or(|t| t.elapsed() >= POLL_INTERVAL);
+        if self.following && self.rx.is_none() && due {
+            self.last_poll = Some(AppInstant::now());
+            let (tx, rx) = mpsc::channel();
+            self.rx = Some(rx);
+            let ctx = ctx.clone();
+            thread::spawn(move || {
+                let _ = tx.send(fetch_instance_status(port).map_err(|e| format!("{:#}", e)));
+                ctx.request_repaint();
+            });
+        }
+        if self.following {
+            ctx.request_repaint_after(POLL_INTERVAL);
+        }
+    }
+}
+
+impl eframe::App for InstanceViewer {
+    // Nothing of the viewer should outlive it; the running instance owns all state
+    fn persist_egui_memory(&self) -> bool {
+        false
+    }
+
+    fn update(&mut self, ctx: &Context, _frame: &mut eframe::Frame) {
+        self.poll(ctx);
+        CentralPanel::default().show(ctx, |ui| {
+            ui.heading(
+                RichText::new(&UI_TEXT.iv_title)
+                    .strong()
+                    .color(PLOT_CONFIG.color_warning),
+            );
+            ui.add_space(6.0);
+            ui.label(&UI_TEXT.iv_body);
+            ui.add_space(6.0);
+            let pid = self
+                .running
+                .pid
+                .map_or_else(|| "?".to_string(), |p| p.to_string());
+            ui.label(
+                RichText::new(format!(
+                    "{} {} · {}",
+                    UI_TEXT.iv_pid,
+                    pid,
+                    self.running.lock_path.display()
+                ))
+                .small()
+                .color(PLOT_CONFIG.color_text_subdued),
+            );
+            ui.add_space(10.0);
+            ui.horizontal(|ui| {
+                let follow = ui
+                    .add_enabled(
+                        self.running.port.is_some() && !self.following,
+                        Button::new(&UI_TEXT.iv_follow),
+                    )
+                    .on_hover_text(&UI_TEXT.iv_follow_hover)
+                    .on_disabled_hover_text(&UI_TEXT.iv_no_port);
+                if follow.clicked() {
+                    self.following = true;
+                    ctx.request_repaint();
+                }
+                if ui.button(&UI_TEXT.iv_quit).clicked() {
+                    ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+                }
+            });
+            if !self.following {
+                return;
+            }
+            ui.separator();
+            match &self.status {
+                None => {
+                    ui.spinner();
+                }
+                Some(Err(e)) => {
+                    ui.label(
+                        RichText::new(format!("{}: {}", UI_TEXT.iv_unreachable, e))
+                            .color(PLOT_CONFIG.color_loss),
+                    );
+                }
+                Some(Ok(status)) => render_status(ui, status),
+            }
+        });
+    }
+}
+
+fn render_status(ui: &mut egui::Ui, status: &InstanceStatus) {
+    ui.label(format!(
+        "{} {} · {} {}",
+        UI_TEXT.iv_profile, status.profile, status.live_pairs, UI_TEXT.iv_live_pairs
+    ));
+    if status.opportunities.is_empty() {
+        ui.label(RichText::new(&UI_TEXT.label_no_targets).color(PLOT_CONFIG.color_text_subdued));
+        return;
+    }
+    ScrollArea::vertical().show(ui, |ui| {
+        Grid::new("iv_opportunities")
+            .striped(true)
+            .num_columns(4)
+            .show(ui, |ui| {
+                ui.strong(&UI_TEXT.label_pair);
+                ui.strong(&UI_TEXT.label_targets_text);
+                ui.strong(&UI_TEXT.label_roi);
+                ui.strong(&UI_TEXT.iv_quality);
+                ui.end_row();
+                for op in &status.opportunities {
+                    ui.label(format!("{} {}", op.pair, op.direction));
+                    ui.label(&op.target);
+                    ui.label(&op.roi);
+                    ui.label(format!("{:.2}", op.quality));
+                    ui.end_row();
+                }
+            });
+    });
+}
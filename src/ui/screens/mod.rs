@@ -1,3 +1,13 @@
 mod bootstrap;
+#[cfg(target_arch = "wasm32")]
+mod demo_select;
+#[cfg(not(target_arch = "wasm32"))]
+mod instance_viewer;
 
 pub(crate) use bootstrap::render_bootstrap;
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) use demo_select::render_demo_select;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use instance_viewer::InstanceViewer;
@@ -0,0 +1,190 @@
+use {
+    crate::{
+        data::{
+            ExternalSignal, SIGNAL_WEBHOOK, SignalListener, SignalWebhookSettings,
+            WEBHOOK_TOKEN_USER, load_local_token, new_local_token, save_local_token,
+        },
+        ui::{PLOT_CONFIG, UI_TEXT},
+    },
+    eframe::egui::{Button, Context, DragValue, Grid, RichText, TextEdit, Ui},
+};
+
+/// Runs the signal listener the webhook settings ask for and hands over what it receives.
+#[derive(Default)]
+pub(crate) struct SignalWebhook {
+    listener: Option<SignalListener>,
+    /// Shared secret from the OS keychain; generated there on first enable
+    token: Option<String>,
+    /// Port and error of the last failed start; not retried until the settings change
+    failed: Option<(u16, String)>,
+}
+
+impl SignalWebhook {
+    /// Starts or stops the listener to match `settings`. Cheap when nothing changed.
+    pub(crate) fn sync(&mut self, settings: &SignalWebhookSettings, ctx: &Context) {
+        if !settings.enabled {
+            if let Some(listener) = self.listener.take() {
+                listener.stop();
+                log::info!("Signal webhook stopped");
+            }
+            self.failed = None;
+            return;
+        }
+        if self
+            .listener
+            .as_ref()
+            .is_some_and(|listener| listener.port == settings.port)
+        {
+            return;
+        }
+        if let Some(listener) = self.listener.take() {
+            listener.stop();
+        }
+        if self
+            .failed
+            .as_ref()
+            .is_some_and(|(port, _)| *port == settings.port)
+        {
+            return;
+        }
+        let ctx = ctx.clone();
+        let started = self.token().and_then(|token| {
+            SignalListener::start(settings.port, &token, move || ctx.request_repaint())
+        });
+        match started {
+            Ok(listener) => {
+                self.listener = Some(listener);
+                self.failed = None;
+            }
+            Err(e) => {
+                log::error!(
+                    "Signal webhook could not listen on {}: {:#}",
+                    settings.port,
+                    e
+                );
+                self.failed = Some((settings.port, format!("{:#}", e)));
+            }
+        }
+    }
+
+    fn token(&mut self) -> anyhow::Result<String> {
+        if let Some(token) = &self.token {
+            return Ok(token.clone());
+        }
+        let token = load_local_token(WEBHOOK_TOKEN_USER)?;
+        self.token = Some(token.clone());
+        Ok(token)
+    }
+
+    /// Replaces the keychain token; senders using the old one are turned away from now on.
+    fn regenerate_token(&mut self) {
+        let token = match new_local_token() {
+            Ok(token) => token,
+            Err(e) => {
+                log::error!("Signal webhook token not replaced: {:#}", e);
+                return;
+            }
+        };
+        if let Err(e) = save_local_token(WEBHOOK_TOKEN_USER, &token) {
+            log::error!("Signal webhook token not replaced: {:#}", e);
+            return;
+        }
+        if let Some(listener) = &self.listener {
+            listener.set_token(&token);
+        }
+        self.token = Some(token);
+    }
+
+    /// Signals received since the last call.
+    pub(crate) fn drain(&self) -> Vec<ExternalSignal> {
+        self.listener
+            .as_ref()
+            .map(SignalListener::drain)
+            .unwrap_or_default()
+    }
+
+    fn is_listening(&self) -> bool {
+        self.listener.is_some()
+    }
+}
+
+pub(crate) fn render_signal_webhook_section(
+    ui: &mut Ui,
+    settings: &mut SignalWebhookSettings,
+    webhook: &mut SignalWebhook,
+    signals: &mut Vec<ExternalSignal>,
+) {
+    ui.label(RichText::new(&UI_TEXT.es_sw_heading).strong())
+        .on_hover_text(&UI_TEXT.es_sw_heading_hover);
+    ui.checkbox(&mut settings.enabled, &UI_TEXT.es_sw_enabled);
+    Grid::new("es_sw_grid")
+        .num_columns(2)
+        .spacing([10.0, 4.0])
+        .show(ui, |ui| {
+            ui.label(&UI_TEXT.es_sw_port);
+            ui.add_enabled(
+                !webhook.is_listening(),
+                DragValue::new(&mut settings.port).range(1024..=65535),
+            )
+            .on_disabled_hover_text(&UI_TEXT.es_sw_port_locked);
+            ui.end_row();
+        });
+    ui.checkbox(
+        &mut settings.in_trade_finder,
+        &UI_TEXT.es_sw_in_trade_finder,
+    );
+    if let (true, Some(token)) = (webhook.is_listening(), webhook.token.clone()) {
+        let url = format!("http://127.0.0.1:{}{}", settings.port, SIGNAL_WEBHOOK.path);
+        ui.horizontal(|ui| {
+            ui.label(
+                RichText::new(format!("{} {}", UI_TEXT.es_sw_listening, url))
+                    .small()
+                    .color(PLOT_CONFIG.color_text_subdued),
+            );
+            // Alert tools that can't set headers pass the token in the query
+            if ui
+                .small_button(&UI_TEXT.es_sw_copy_url)
+                .on_hover_text(&UI_TEXT.es_sw_copy_url_hover)
+                .clicked()
+            {
+                ui.ctx().copy_text(format!("{}?token={}", url, token));
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.label(&UI_TEXT.es_sw_token)
+                .on_hover_text(&UI_TEXT.es_sw_token_hover);
+            if ui.small_button(&UI_TEXT.es_sw_copy_token).clicked() {
+                ui.ctx().copy_text(token);
+            }
+            if ui
+                .small_button(&UI_TEXT.es_sw_regenerate_token)
+                .on_hover_text(&UI_TEXT.es_sw_regenerate_token_hover)
+                .clicked()
+            {
+                webhook.regenerate_token();
+            }
+        });
+    } else if let Some((port, e)) = &webhook.failed {
+        ui.label(
+            RichText::new(format!("{} {}: {}", UI_TEXT.es_sw_failed, port, e))
+                .small()
+                .color(PLOT_CONFIG.color_loss),
+        );
+    }
+    ui.horizontal(|ui| {
+        ui.label(
+            RichText::new(format!("{} {}", signals.len(), UI_TEXT.es_sw_received))
+                .small()
+                .color(PLOT_CONFIG.color_text_subdued),
+        );
+        if ui
+            .add_enabled(
+                !signals.is_empty(),
+                Button::new(&UI_TEXT.es_sw_clear).small(),
+            )
+            .clicked()
+        {
+            signals.clear();
+        }
+    });
+}
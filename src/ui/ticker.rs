@@ -1,23 +1,32 @@
 use {
     crate::{
-        app::{BASE_INTERVAL, Price, PriceLike},
+        app::{BASE_INTERVAL, DurationMs, Price, PriceLike, TimestampMs, TradeProfile},
         engine::SniperEngine,
         models::find_matching_ohlcv,
+        ui::UI_TEXT,
         utils::{AppInstant, TimeUtils},
     },
     eframe::egui::{Color32, FontId, OpenUrl, Pos2, Rect, Sense, Ui, Vec2},
+    std::time::Duration,
 };
 
 pub struct TickerConfig {
     pub background_color: Color32,
+    /// How long a pair stays badged after its best opportunity improved
+    pub badge_duration: Duration,
+    /// Relative best-AROI gain that counts as a material improvement
+    pub badge_min_improvement: f64,
     pub custom_messages: &'static [(&'static str, Option<&'static str>)],
     pub font_size: f32,
+    /// Frame cap for the scroll animation
+    pub frame_interval_ms: u64,
     pub height: f32,
     pub item_spacing: f32,
     pub min_change_pct_for_color: f64,
     pub rainbow_mode: bool,
     pub rainbow_speed: f64,
     pub speed_pixels_per_sec: f32,
+    pub text_color_badge: Color32,
     pub text_color_down: Color32,
     pub text_color_link: Color32,
     pub text_color_neutral: Color32,
@@ -26,6 +35,8 @@ pub struct TickerConfig {
 
 pub const TICKER: TickerConfig = TickerConfig {
     background_color: Color32::from_rgb(10, 10, 15),
+    badge_duration: Duration::from_secs(60),
+    badge_min_improvement: 0.25,
     custom_messages: &[
         ("🎄 MERRY CHRISTMAS 🎄", None),
         (
@@ -38,12 +49,14 @@ pub const TICKER: TickerConfig = TickerConfig {
         ),
     ],
     font_size: 10.0,
+    frame_interval_ms: 33,
     height: 18.0,
     item_spacing: 40.0,
     min_change_pct_for_color: 0.01,
     rainbow_mode: true,
     rainbow_speed: 2.0,
     speed_pixels_per_sec: 30.0,
+    text_color_badge: Color32::GOLD,
     text_color_down: Color32::RED,
     text_color_link: Color32::from_rgb(100, 200, 255),
     text_color_neutral: Color32::LIGHT_GRAY,
@@ -55,6 +68,42 @@ pub(crate) struct TickerItem {
     pub price: Price,
     pub change: f64,
     pub url: Option<String>,
+    /// Best opportunity AROI of the pair's current model (None = no opportunities yet)
+    pub best_aroi: Option<f64>,
+    /// Flash a badge until then: the best opportunity improved materially
+    pub badge_until: Option<AppInstant>,
+}
+
+impl TickerItem {
+    fn message(symbol: String, url: Option<String>) -> Self {
+        Self {
+            symbol,
+            price: Price::new(0.0),
+            change: 0.0,
+            url,
+            best_aroi: None,
+            badge_until: None,
+        }
+    }
+
+    fn is_badged(&self, now: AppInstant) -> bool {
+        self.badge_until.is_some_and(|until| now < until)
+    }
+}
+
+/// True when `after` beats `before` by at least `min_improvement` of its magnitude. Pairs
+/// gaining or losing their only opportunity don't count, so models arriving at startup stay quiet.
+pub(crate) fn is_material_improvement(
+    before: Option<f64>,
+    after: Option<f64>,
+    min_improvement: f64,
+) -> bool {
+    match (before, after) {
+        (Some(before), Some(after)) => {
+            after - before > min_improvement * before.abs().max(f64::EPSILON)
+        }
+        _ => false,
+    }
 }
 
 pub(crate) struct TickerState {
@@ -78,41 +127,34 @@ impl Default for TickerState {
 }
 
 impl TickerState {
-    pub(crate) fn update_data(&mut self, engine: &SniperEngine) {
+    /// `promote_improved` moves a pair whose best opportunity just improved to the front.
+    pub(crate) fn update_data(&mut self, engine: &SniperEngine, promote_improved: bool) {
         if cfg!(target_arch = "wasm32") {
             if self.items.is_empty() {
-                self.items.push(TickerItem {
-                    symbol: "ZONE SNIPER WEB DEMO".to_string(),
-                    price: Price::new(0.0),
-                    change: 0.0,
-                    url: None,
-                });
-                self.items.push(TickerItem {
-                    symbol: "VISIT US ON GITHUB".to_string(),
-                    price: Price::new(0.0),
-                    change: 0.0,
-                    url: Some("https://github.com/leemthai/sniper".to_string()),
-                });
-                self.items.push(TickerItem {
-                    symbol: "GET PRO VERSION FOR LIVE DATA, UNLIMITED TRADING PAIRS AND MUCH MORE"
+                self.items.push(TickerItem::message(
+                    "ZONE SNIPER WEB DEMO".to_string(),
+                    None,
+                ));
+                self.items.push(TickerItem::message(
+                    "VISIT US ON GITHUB".to_string(),
+                    Some("https://github.com/leemthai/sniper".to_string()),
+                ));
+                self.items.push(TickerItem::message(
+                    "GET PRO VERSION FOR LIVE DATA, UNLIMITED TRADING PAIRS AND MUCH MORE"
                         .to_string(),
-                    price: Price::new(0.0),
-                    change: 0.0,
-                    url: None,
-                });
+                    None,
+                ));
                 self.items.push(TickerItem {
-                    symbol: "BTCUSDT".to_string(),
                     price: Price::new(98000.0),
                     change: 120.5,
-                    url: None,
+                    ..TickerItem::message("BTCUSDT".to_string(), None)
                 });
             }
             return;
         }
 
         if cfg!(not(target_arch = "wasm32")) {
-            let now_ms = TimeUtils::now_timestamp_ms();
-            let day_ago_ms = now_ms - TimeUtils::MS_IN_D;
+            let day_ago = TimestampMs::now() - DurationMs::new(TimeUtils::MS_IN_D);
             let pairs = engine.get_all_pair_names();
             for pair in pairs {
                 if let Some(current_price) = engine.get_price(&pair) {
@@ -123,7 +165,7 @@ impl TickerState {
                         &pair,
                         BASE_INTERVAL.as_millis() as i64,
                     ) {
-                        let idx_result = ohlcv.timestamps.binary_search(&day_ago_ms);
+                        let idx_result = ohlcv.timestamps.binary_search(&day_ago);
                         let idx = match idx_result {
                             Ok(i) => i,
                             Err(i) => i.saturating_sub(1),
@@ -135,34 +177,71 @@ impl TickerState {
                             }
                         }
                     }
-                    if let Some(item) = self.items.iter_mut().find(|i| i.symbol == pair) {
-                        item.price = current_price;
-                        item.change = change_24h;
-                    } else {
-                        self.items.push(TickerItem {
-                            symbol: pair,
-                            price: current_price,
-                            change: change_24h,
-                            url: None,
-                        });
+                    let best_aroi = engine.get_model(&pair).and_then(|model| {
+                        model
+                            .opportunities
+                            .iter()
+                            .map(|op| {
+                                TradeProfile::calc_annualized_roi(
+                                    op.expected_roi(),
+                                    op.avg_duration,
+                                )
+                                .value()
+                            })
+                            .max_by(f64::total_cmp)
+                    });
+                    let idx = match self.items.iter().position(|i| i.symbol == pair) {
+                        Some(idx) => idx,
+                        None => {
+                            self.items.push(TickerItem {
+                                price: current_price,
+                                change: change_24h,
+                                best_aroi,
+                                ..TickerItem::message(pair, None)
+                            });
+                            continue;
+                        }
+                    };
+                    let item = &mut self.items[idx];
+                    item.price = current_price;
+                    item.change = change_24h;
+                    if best_aroi != item.best_aroi {
+                        let improved = is_material_improvement(
+                            item.best_aroi,
+                            best_aroi,
+                            TICKER.badge_min_improvement,
+                        );
+                        item.best_aroi = best_aroi;
+                        if improved {
+                            item.badge_until = Some(AppInstant::now() + TICKER.badge_duration);
+                            if promote_improved {
+                                let item = self.items.remove(idx);
+                                self.items.insert(0, item);
+                            }
+                        }
                     }
                 }
             }
             for (text, url) in TICKER.custom_messages {
                 let symbol_key = text.to_string();
                 if !self.items.iter().any(|i| i.symbol == symbol_key) {
-                    self.items.push(TickerItem {
-                        symbol: symbol_key,
-                        price: Price::new(0.0), // 0.0 marks as message/link
-                        change: 0.0,
-                        url: url.map(|s| s.to_string()),
-                    });
+                    // Zero price marks a message / link
+                    self.items
+                        .push(TickerItem::message(symbol_key, url.map(|s| s.to_string())));
                 }
             }
         }
     }
 
-    pub(crate) fn render(&mut self, ui: &mut Ui) -> Option<String> {
+    /// `animate` = false (idle / background) leaves the scroll where it is and stops the
+    /// per-frame repaint.
+    /// Right-click offers `promote_improved`.
+    pub(crate) fn render(
+        &mut self,
+        ui: &mut Ui,
+        animate: bool,
+        promote_improved: &mut bool,
+    ) -> Option<String> {
         let now = AppInstant::now();
         let dt = if let Some(last) = self.last_render_time {
             let duration = now.duration_since(last).as_secs_f32();
@@ -178,11 +257,15 @@ impl TickerState {
         let response = ui.allocate_rect(panel_rect, Sense::click_and_drag());
         ui.painter()
             .rect_filled(panel_rect, 0.0, TICKER.background_color);
+        response.context_menu(|ui| {
+            ui.checkbox(promote_improved, &UI_TEXT.ticker_promote_improved)
+                .on_hover_text(&UI_TEXT.ticker_promote_improved_hover);
+        });
         self.is_hovered = response.hovered();
         self.is_dragging = response.dragged();
         if self.is_dragging {
             self.offset += response.drag_delta().x;
-        } else if !self.is_hovered {
+        } else if animate && !self.is_hovered {
             self.offset -= TICKER.speed_pixels_per_sec * dt;
         }
         let painter = ui.painter().with_clip_rect(panel_rect);
@@ -190,7 +273,7 @@ impl TickerState {
         let mut total_width = 0.0;
         let mut clicked_pair = None;
         for item in &self.items {
-            let text = self.format_item(item);
+            let text = self.format_item(item, now);
             let galley = painter.layout_no_wrap(text, font_id.clone(), Color32::WHITE);
             total_width += galley.size().x + TICKER.item_spacing;
         }
@@ -208,7 +291,14 @@ impl TickerState {
         for loop_idx in 0..loops_needed {
             let mut loop_x = self.offset + (loop_idx as f32 * total_width);
             for item in &self.items {
-                let text_color = if item.url.is_some() {
+                let text_color = if item.is_badged(now) {
+                    // Blink at 1 Hz so the badge reads as news rather than a price move
+                    if TimeUtils::now_timestamp_ms() / 500 % 2 == 0 {
+                        TICKER.text_color_badge
+                    } else {
+                        TICKER.text_color_neutral
+                    }
+                } else if item.url.is_some() {
                     TICKER.text_color_link
                 } else if item.price.value() == 0.0 {
                     if TICKER.rainbow_mode {
@@ -227,7 +317,7 @@ impl TickerState {
                     }
                 };
 
-                let text_str = self.format_item(item);
+                let text_str = self.format_item(item, now);
                 let galley = painter.layout_no_wrap(text_str, font_id.clone(), text_color);
                 let w = galley.size().x;
                 let h = galley.size().y;
@@ -262,14 +352,27 @@ impl TickerState {
                 loop_x += w + TICKER.item_spacing;
             }
         }
-        if !self.is_hovered && !self.is_dragging {
-            ui.ctx().request_repaint();
+        if animate && !self.is_hovered && !self.is_dragging {
+            ui.ctx()
+                .request_repaint_after(Duration::from_millis(TICKER.frame_interval_ms));
+        } else if self.items.iter().any(|i| i.is_badged(now)) {
+            // Keep blinking while paused
+            ui.ctx().request_repaint_after(Duration::from_millis(500));
         }
 
         clicked_pair
     }
 
-    fn format_item(&self, item: &TickerItem) -> String {
+    fn format_item(&self, item: &TickerItem, now: AppInstant) -> String {
+        let text = self.format_plain_item(item);
+        if item.is_badged(now) {
+            format!("★ {}", text)
+        } else {
+            text
+        }
+    }
+
+    fn format_plain_item(&self, item: &TickerItem) -> String {
         if item.url.is_some() {
             return format!("{} 🔗", item.symbol);
         }
@@ -1,12 +1,190 @@
 use {
-    crate::engine::{StationId, TimeTunerConfig},
-    eframe::egui::{Align, Button, Layout, Ui, vec2},
+    crate::{
+        app::{PhPct, TimestampMs},
+        engine::{
+            PhPreview, StationId, StationPreview, StationStats, TUNER_CONFIG, TimeTunerConfig,
+            TunerScan, TunerWeights, score_probes,
+        },
+        ui::{GlossaryTerm, PLOT_CONFIG, UI_TEXT, help_link},
+        utils::TimeUtils,
+    },
+    eframe::egui::{
+        Align, Button, CollapsingHeader, DragValue, Grid, Layout, RichText, Slider, Stroke,
+        TextEdit, Ui, pos2, vec2,
+    },
+    std::{collections::HashMap, time::Duration},
 };
 
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::mpsc;
+
+/// Idle time after the last slider movement before the PH is committed (if still dragging).
+const PH_COMMIT_IDLE_MS: i64 = 300;
+const PH_SLIDER_MIN: f64 = 0.001;
+/// Pointer rest time before a hovered station is previewed (wasm tunes on the UI thread).
+#[cfg(target_arch = "wasm32")]
+const STATION_HOVER_SETTLE_MS: i64 = 250;
+pub(crate) const PH_SLIDER_MAX: f64 = 0.5;
+
+/// Manual PH slider. Edits stay local until release / idle, so a drag costs one recalc.
+#[derive(Debug, Default)]
+pub(crate) struct PhSliderState {
+    pair: Option<String>,
+    value: f64,
+    /// Set while an uncommitted edit exists (timestamp of the last movement)
+    pending_since_ms: Option<i64>,
+    /// The handle is held, possibly between commits
+    dragging: bool,
+}
+
+impl PhSliderState {
+    /// Uncommitted PH being dragged, if any (drives the live preview).
+    pub(crate) fn pending_ph(&self) -> Option<PhPct> {
+        self.pending_since_ms.map(|_| PhPct::new(self.value))
+    }
+
+    pub(crate) fn is_dragging(&self) -> bool {
+        self.dragging
+    }
+}
+
+/// Stations explored in the Time Tuner before one is applied. Each preview is tuned and analysed
+/// once in the background and drawn as a ghost on the plot; nothing reaches the engine until
+/// apply.
+#[derive(Debug, Default)]
+pub(crate) struct StationPreviewState {
+    pair: Option<String>,
+    /// Station picked with the arrow keys or a click, waiting for apply / cancel
+    pub pending: Option<StationId>,
+    /// Station under the pointer this frame (wins over `pending` for the ghost)
+    pub hovered: Option<StationId>,
+    /// When `hovered` last changed
+    hovered_since_ms: i64,
+    /// Finished previews; None when the tuner found no PH for that station
+    previews: HashMap<StationId, Option<StationPreview>>,
+    running: Option<StationId>,
+    #[cfg(not(target_arch = "wasm32"))]
+    rx: Option<mpsc::Receiver<Option<StationPreview>>>,
+}
+
+impl StationPreviewState {
+    /// Drops everything when the selected pair changes; previews are per pair.
+    pub(crate) fn sync_pair(&mut self, pair: &str) {
+        if self.pair.as_deref() != Some(pair) {
+            *self = Self {
+                pair: Some(pair.to_string()),
+                ..Self::default()
+            };
+        }
+    }
+
+    /// Station the ghost should show: hovered first, then the pending pick.
+    pub(crate) fn target(&self) -> Option<StationId> {
+        self.hovered.or(self.pending)
+    }
+
+    /// Finished preview to draw over `pair`'s plot.
+    pub(crate) fn ghost(&self, pair: &str) -> Option<&StationPreview> {
+        if self.pair.as_deref() != Some(pair) {
+            return None;
+        }
+        self.previews.get(&self.target()?)?.as_ref()
+    }
+
+    pub(crate) fn is_running(&self) -> bool {
+        self.running.is_some()
+    }
+
+    /// Target station that still needs a preview, if no other preview is in flight. One at a
+    /// time: sweeping across the stations costs at most one stale run.
+    pub(crate) fn wanted(&self) -> Option<StationId> {
+        let target = self.target()?;
+        (self.running.is_none() && !self.previews.contains_key(&target)).then_some(target)
+    }
+
+    /// Records the station under the pointer, restarting the rest timer when it changes.
+    pub(crate) fn set_hovered(&mut self, station: Option<StationId>) {
+        if station != self.hovered {
+            self.hovered = station;
+            self.hovered_since_ms = TimestampMs::now().value();
+        }
+    }
+
+    /// Time left before the hovered station has rested long enough to be previewed.
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) fn hover_wait_ms(&self) -> Option<u64> {
+        self.hovered?;
+        let left = STATION_HOVER_SETTLE_MS - (TimestampMs::now().value() - self.hovered_since_ms);
+        (left > 0).then_some(left as u64)
+    }
+
+    /// Moves the pending pick one station left / right of the pending (or `active`) station.
+    /// Landing back on `active` clears the pick.
+    pub(crate) fn step(&mut self, active: Option<StationId>, delta: i32) {
+        let stations = TUNER_CONFIG.stations;
+        let Some(from) = self.pending.or(active) else {
+            return;
+        };
+        let Some(idx) = stations.iter().position(|s| s.id == from) else {
+            return;
+        };
+        let next = (idx as i32 + delta).clamp(0, stations.len() as i32 - 1) as usize;
+        let id = stations[next].id;
+        self.pending = (Some(id) != active).then_some(id);
+    }
+
+    /// Drops the pick and the cached previews (they go stale as candles arrive).
+    pub(crate) fn cancel(&mut self) {
+        self.pending = None;
+        self.previews.clear();
+    }
+
+    /// Takes the finished scan for `station` so applying it skips a second tune.
+    pub(crate) fn take_scan(&mut self, station: StationId) -> Option<TunerScan> {
+        self.pending = None;
+        let scan = self.previews.remove(&station).flatten().map(|p| p.scan);
+        self.previews.clear();
+        scan
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn start(&mut self, station: StationId, rx: mpsc::Receiver<Option<StationPreview>>) {
+        self.running = Some(station);
+        self.rx = Some(rx);
+    }
+
+    /// Collects a finished background preview, if any.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn poll(&mut self) {
+        let Some(rx) = self.rx.as_ref() else {
+            return;
+        };
+        match rx.try_recv() {
+            Ok(result) => self.finish(self.running.unwrap_or_default(), result),
+            Err(mpsc::TryRecvError::Empty) => {}
+            Err(mpsc::TryRecvError::Disconnected) => {
+                self.rx = None;
+                self.running = None;
+            }
+        }
+    }
+
+    /// Stores a preview result. Failed tunes are kept too, so they are not retried every frame.
+    pub(crate) fn finish(&mut self, station: StationId, result: Option<StationPreview>) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.rx = None;
+        }
+        self.running = None;
+        self.previews.insert(station, result);
+    }
+}
+
 #[derive(Debug)]
 pub(crate) enum TunerAction {
     StationSelected(StationId),
     ConfigureTuner,
+    NoteEdited(StationId, String),
 }
 
 pub(crate) fn render_time_tuner(
@@ -14,8 +192,12 @@ pub(crate) fn render_time_tuner(
     time_tuner_config: &TimeTunerConfig,
     active_station_id: Option<StationId>,
     pair: Option<String>,
+    preview: &mut StationPreviewState,
+    stats: &HashMap<StationId, StationStats>,
+    notes: &HashMap<StationId, String>,
 ) -> Option<TunerAction> {
     let mut action = None;
+    let mut hovered = None;
 
     ui.vertical(|ui| {
         if let Some(name) = pair {
@@ -33,12 +215,33 @@ pub(crate) fn render_time_tuner(
                             Button::new(station.name)
                                 .fill(ui.visuals().selection.bg_fill)
                                 .stroke(ui.visuals().selection.stroke)
+                        } else if preview.pending == Some(station.id) {
+                            Button::new(station.name)
+                                .stroke(Stroke::new(2.0, PLOT_CONFIG.color_warning))
                         } else {
                             Button::new(station.name)
                         };
-                        let response = ui.add_sized(vec2(90.0, y_height), btn);
-                        if response.clicked() {
+                        let response = ui
+                            .vertical(|ui| {
+                                let hover = match notes.get(&station.id) {
+                                    Some(note) => {
+                                        format!("{}\n\n📝 {}", UI_TEXT.tt_station_hover, note)
+                                    }
+                                    None => UI_TEXT.tt_station_hover.clone(),
+                                };
+                                let response =
+                                    ui.add_sized(vec2(90.0, y_height), btn).on_hover_text(hover);
+                                render_station_stats(ui, stats.get(&station.id));
+                                response
+                            })
+                            .inner;
+                        if response.hovered() && !is_active {
+                            hovered = Some(station.id);
+                        }
+                        if response.double_clicked() {
                             action = Some(TunerAction::StationSelected(station.id));
+                        } else if response.clicked() {
+                            preview.pending = (!is_active).then_some(station.id);
                         }
                     }
                     ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
@@ -51,6 +254,21 @@ pub(crate) fn render_time_tuner(
                         }
                     });
                 });
+                let noted = preview.pending.unwrap_or(station_id);
+                if let Some(note) = render_station_note(ui, noted, notes.get(&noted)) {
+                    action = Some(TunerAction::NoteEdited(noted, note));
+                }
+                if let Some(pending) = preview.pending {
+                    if let Some(selected) = render_station_preview_bar(ui, preview, pending) {
+                        action = Some(selected);
+                    }
+                } else {
+                    ui.label(
+                        RichText::new(&UI_TEXT.tt_keys_hint)
+                            .small()
+                            .color(PLOT_CONFIG.color_text_subdued),
+                    );
+                }
             } else {
                 ui.heading(headline);
                 ui.add_space(4.0);
@@ -63,5 +281,336 @@ pub(crate) fn render_time_tuner(
         }
         ui.add_space(4.0);
     });
+    preview.set_hovered(hovered);
     action
 }
+
+/// One-line yield under a station button: opportunities and horizon coverage from the
+/// station's last live analysis for this pair, with PH and age on hover.
+fn render_station_stats(ui: &mut Ui, stats: Option<&StationStats>) {
+    let Some(stats) = stats else {
+        ui.label(
+            RichText::new(&UI_TEXT.tt_stats_unknown)
+                .small()
+                .color(PLOT_CONFIG.color_text_subdued),
+        )
+        .on_hover_text(&UI_TEXT.tt_stats_unknown_hover);
+        return;
+    };
+    let color = if stats.opportunities == 0 {
+        PLOT_CONFIG.color_text_subdued
+    } else {
+        PLOT_CONFIG.color_text_neutral
+    };
+    let age = TimestampMs::now().value() - stats.updated_at.value();
+    ui.label(
+        RichText::new(format!(
+            "{} {} · {:.0}%",
+            stats.opportunities,
+            UI_TEXT.tt_stats_ops,
+            stats.coverage * 100.0
+        ))
+        .small()
+        .color(color),
+    )
+    .on_hover_text(format!(
+        "PH {} · {:.1}% {}\n{} {} {}",
+        stats.ph,
+        stats.coverage * 100.0,
+        UI_TEXT.ph_slider_coverage,
+        UI_TEXT.tt_stats_updated,
+        TimeUtils::format_duration(age.max(0)),
+        UI_TEXT.tt_stats_ago
+    ));
+}
+
+/// Single-line note for the pending (or active) station. Returns the new text when edited.
+fn render_station_note(ui: &mut Ui, station: StationId, note: Option<&String>) -> Option<String> {
+    let mut text = note.cloned().unwrap_or_default();
+    let name = TUNER_CONFIG
+        .stations
+        .iter()
+        .find(|s| s.id == station)
+        .map_or("?", |s| s.name);
+    let changed = ui
+        .horizontal(|ui| {
+            ui.label(RichText::new("📝").small())
+                .on_hover_text(&UI_TEXT.tt_note_hover);
+            ui.add(
+                TextEdit::singleline(&mut text)
+                    .id_salt(("station_note", station))
+                    .hint_text(format!("{} {}", UI_TEXT.tt_note_hint, name))
+                    .desired_width(f32::INFINITY),
+            )
+            .changed()
+        })
+        .inner;
+    changed.then_some(text)
+}
+
+/// Pending station summary (tuned PH and trade count once the preview is in) with
+/// apply / cancel.
+fn render_station_preview_bar(
+    ui: &mut Ui,
+    preview: &mut StationPreviewState,
+    pending: StationId,
+) -> Option<TunerAction> {
+    let mut action = None;
+    let name = TUNER_CONFIG
+        .stations
+        .iter()
+        .find(|s| s.id == pending)
+        .map_or("?", |s| s.name);
+    ui.horizontal(|ui| {
+        ui.label(
+            RichText::new(format!("{} {}", UI_TEXT.tt_preview_label, name))
+                .small()
+                .strong()
+                .color(PLOT_CONFIG.color_warning),
+        );
+        match preview.previews.get(&pending) {
+            Some(Some(p)) => {
+                let ops = p.model.as_ref().map_or(0, |m| m.opportunities.len());
+                ui.label(
+                    RichText::new(format!(
+                        "PH {} · {} {}",
+                        p.scan.best, ops, UI_TEXT.tt_preview_trades
+                    ))
+                    .small()
+                    .monospace(),
+                );
+            }
+            Some(None) => {
+                ui.label(
+                    RichText::new(&UI_TEXT.tt_preview_no_fit)
+                        .small()
+                        .color(PLOT_CONFIG.color_text_subdued),
+                );
+            }
+            None if preview.is_running() => {
+                ui.spinner();
+            }
+            None => {}
+        }
+        ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+            if ui
+                .small_button(&UI_TEXT.tt_preview_cancel)
+                .on_hover_text(&UI_TEXT.tt_preview_cancel_hover)
+                .clicked()
+            {
+                preview.cancel();
+            }
+            if ui
+                .small_button(&UI_TEXT.tt_preview_apply)
+                .on_hover_text(&UI_TEXT.tt_preview_apply_hover)
+                .clicked()
+            {
+                action = Some(TunerAction::StationSelected(pending));
+            }
+        });
+    });
+    action
+}
+
+/// Objective weights plus the last scan's per-PH breakdown, re-scored with the weights shown.
+/// Returns true if a weight changed.
+pub(crate) fn render_tuner_scoring(
+    ui: &mut Ui,
+    weights: &mut TunerWeights,
+    scan: Option<&TunerScan>,
+) -> bool {
+    let mut changed = false;
+    CollapsingHeader::new(&UI_TEXT.tt_scoring)
+        .default_open(false)
+        .show(ui, |ui| {
+            Grid::new("tuner_weights")
+                .num_columns(2)
+                .spacing([10.0, 4.0])
+                .show(ui, |ui| {
+                    for (label, hover, weight) in [
+                        (
+                            &UI_TEXT.tt_weight_quality,
+                            &UI_TEXT.tt_weight_quality_hover,
+                            &mut weights.quality,
+                        ),
+                        (
+                            &UI_TEXT.tt_weight_coverage,
+                            &UI_TEXT.tt_weight_coverage_hover,
+                            &mut weights.coverage,
+                        ),
+                        (
+                            &UI_TEXT.tt_weight_expectancy,
+                            &UI_TEXT.tt_weight_expectancy_hover,
+                            &mut weights.expectancy,
+                        ),
+                        (
+                            &UI_TEXT.tt_weight_samples,
+                            &UI_TEXT.tt_weight_samples_hover,
+                            &mut weights.samples,
+                        ),
+                    ] {
+                        ui.label(label).on_hover_text(hover);
+                        changed |= ui
+                            .add(DragValue::new(weight).speed(0.05).range(0.0..=5.0))
+                            .changed();
+                        ui.end_row();
+                    }
+                });
+            if ui.small_button(&UI_TEXT.tt_weights_reset).clicked() {
+                *weights = TunerWeights::default();
+                changed = true;
+            }
+
+            let Some(scan) = scan else {
+                return;
+            };
+            ui.separator();
+            let station = TUNER_CONFIG
+                .stations
+                .iter()
+                .find(|s| s.id == scan.fingerprint.station);
+            ui.label(
+                RichText::new(format!(
+                    "{} {}",
+                    UI_TEXT.tt_last_scan,
+                    station.map_or("?", |s| s.name)
+                ))
+                .small()
+                .strong(),
+            );
+            if scan.fingerprint.weights != *weights {
+                ui.label(
+                    RichText::new(&UI_TEXT.tt_weights_stale)
+                        .small()
+                        .color(PLOT_CONFIG.color_warning),
+                );
+            }
+            let scores = score_probes(&scan.probes, weights);
+            Grid::new("tuner_breakdown")
+                .num_columns(7)
+                .striped(true)
+                .spacing([8.0, 2.0])
+                .show(ui, |ui| {
+                    for header in [
+                        &UI_TEXT.tt_col_ph,
+                        &UI_TEXT.tt_col_duration,
+                        &UI_TEXT.tt_weight_quality,
+                        &UI_TEXT.tt_weight_coverage,
+                        &UI_TEXT.tt_weight_expectancy,
+                        &UI_TEXT.tt_weight_samples,
+                        &UI_TEXT.tt_col_total,
+                    ] {
+                        ui.label(RichText::new(header).small().strong());
+                    }
+                    ui.end_row();
+                    for (probe, score) in scan.probes.iter().zip(&scores) {
+                        let color = if probe.ph == scan.best {
+                            PLOT_CONFIG.color_profit
+                        } else if station.is_some_and(|s| probe.fits(s)) {
+                            PLOT_CONFIG.color_text_neutral
+                        } else {
+                            PLOT_CONFIG.color_text_subdued
+                        };
+                        let cell = |ui: &mut Ui, text: String| {
+                            ui.label(RichText::new(text).small().monospace().color(color));
+                        };
+                        cell(ui, probe.ph.to_string());
+                        cell(ui, format!("{:.1}h", probe.duration_hours));
+                        cell(ui, format!("{:.2}", score.quality));
+                        cell(ui, format!("{:.2}", score.coverage));
+                        cell(ui, format!("{:.2}", score.expectancy));
+                        cell(ui, format!("{:.2}", score.samples));
+                        cell(ui, format!("{:.2}", score.total));
+                        ui.end_row();
+                    }
+                });
+            ui.label(
+                RichText::new(&UI_TEXT.tt_breakdown_hint)
+                    .small()
+                    .color(PLOT_CONFIG.color_text_subdued),
+            );
+        });
+    changed
+}
+
+/// Returns the PH to commit once the user releases the slider or pauses for [`PH_COMMIT_IDLE_MS`].
+/// `min_viable` is drawn as a marker on the track; anything left of it fails for lack of data.
+pub(crate) fn render_ph_slider(
+    ui: &mut Ui,
+    state: &mut PhSliderState,
+    pair: &str,
+    committed_ph: Option<PhPct>,
+    preview: Option<PhPreview>,
+    min_viable: Option<PhPct>,
+) -> Option<PhPct> {
+    // Follow the engine value unless the user is mid-edit
+    if state.pending_since_ms.is_none() || state.pair.as_deref() != Some(pair) {
+        state.pair = Some(pair.to_string());
+        state.value = committed_ph.unwrap_or_default().value();
+        state.pending_since_ms = None;
+    }
+
+    let now = TimeUtils::now_timestamp_ms();
+    let response = ui
+        .horizontal(|ui| {
+            let response = ui.add(
+                Slider::new(&mut state.value, PH_SLIDER_MIN..=PH_SLIDER_MAX)
+                    .logarithmic(true)
+                    .custom_formatter(|v, _| format!("{:.2}%", v * 100.0))
+                    .text(&UI_TEXT.ph_slider_label),
+            );
+            help_link(ui, GlossaryTerm::PriceHorizon);
+            response
+        })
+        .inner;
+    if response.changed() {
+        state.pending_since_ms = Some(now);
+    }
+    state.dragging = response.dragged();
+
+    if let Some(min_ph) = min_viable {
+        // Same log mapping as the slider; the rail is inset by the handle radius
+        let rect = response.rect;
+        let handle_radius = rect.height() / 2.5;
+        let left = rect.left() + handle_radius;
+        let right = rect.left() + ui.spacing().slider_width - handle_radius;
+        let t = ((min_ph.value().max(PH_SLIDER_MIN).ln() - PH_SLIDER_MIN.ln())
+            / (PH_SLIDER_MAX.ln() - PH_SLIDER_MIN.ln()))
+        .clamp(0.0, 1.0) as f32;
+        let x = left + t * (right - left);
+        ui.painter().line_segment(
+            [pos2(x, rect.top()), pos2(x, rect.bottom())],
+            Stroke::new(2.0, PLOT_CONFIG.color_warning),
+        );
+        if state.value < min_ph.value() {
+            ui.label(
+                RichText::new(format!("{} {}", UI_TEXT.ph_slider_below_min, min_ph))
+                    .small()
+                    .color(PLOT_CONFIG.color_warning),
+            );
+        }
+    }
+
+    let pending_since = state.pending_since_ms?;
+    if let Some(p) = preview {
+        ui.label(
+            RichText::new(format!(
+                "{} {} ({:.1}% {})",
+                p.candle_count,
+                UI_TEXT.ph_slider_candles,
+                p.coverage_pct,
+                UI_TEXT.ph_slider_coverage
+            ))
+            .small()
+            .color(PLOT_CONFIG.color_text_subdued),
+        );
+    }
+
+    if response.drag_stopped() || now - pending_since >= PH_COMMIT_IDLE_MS {
+        state.pending_since_ms = None;
+        return Some(PhPct::new(state.value));
+    }
+    ui.ctx()
+        .request_repaint_after(Duration::from_millis(PH_COMMIT_IDLE_MS as u64));
+    None
+}
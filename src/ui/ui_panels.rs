@@ -1,15 +1,18 @@
 use {
     crate::{
-        models::{DisplaySegment, GapReason},
+        app::BASE_INTERVAL,
+        models::{DiffZoneKind, DisplaySegment, GapReason, JourneyReplay, Outcome, SegmentStats},
         ui::{PLOT_CONFIG, UI_TEXT, UiStyleExt},
         utils::TimeUtils,
     },
-    eframe::egui::{Button, Grid, RichText, ScrollArea, Ui},
+    eframe::egui::{Button, Context, Grid, Id, Pos2, RichText, ScrollArea, Ui, Window},
 };
 
 pub struct CandleRangePanel<'a> {
     segments: &'a [DisplaySegment],
     current_range_idx: Option<usize>,
+    /// Segment whose info icon was clicked this frame, with the click position
+    info_request: Option<(usize, Pos2)>,
 }
 
 impl<'a> CandleRangePanel<'a> {
@@ -17,9 +20,15 @@ impl<'a> CandleRangePanel<'a> {
         Self {
             segments,
             current_range_idx: current_idx,
+            info_request: None,
         }
     }
 
+    /// Segment to show statistics for, set by the last `render`.
+    pub(crate) fn info_request(&self) -> Option<(usize, Pos2)> {
+        self.info_request
+    }
+
     pub(crate) fn render(&mut self, ui: &mut Ui, last_viewed_idx: usize) -> Option<Option<usize>> {
         let mut action = None;
         ui.add_space(5.0);
@@ -110,8 +119,8 @@ impl<'a> CandleRangePanel<'a> {
                                 ui.end_row();
                             }
 
-                            let start_date = TimeUtils::ms_to_datestring(seg.start_ts);
-                            let end_date = TimeUtils::ms_to_datestring(seg.end_ts);
+                            let start_date = seg.start_ts.to_datestring();
+                            let end_date = seg.end_ts.to_datestring();
 
                             let label_text =
                                 format!("{} - {} ({}c)", start_date, end_date, seg.candle_count);
@@ -121,20 +130,27 @@ impl<'a> CandleRangePanel<'a> {
                             {
                                 action = Some(Some(i));
                             }
-                            if i == self.segments.len() - 1 {
-                                ui.label(
-                                    RichText::new(&UI_TEXT.cr_label_live)
-                                        .color(PLOT_CONFIG.color_profit)
-                                        .strong()
-                                        .small(),
-                                );
-                            } else {
-                                ui.label(
-                                    RichText::new(&UI_TEXT.cr_label_historical)
-                                        .small()
-                                        .color(PLOT_CONFIG.color_text_subdued),
-                                );
-                            }
+                            ui.horizontal(|ui| {
+                                if i == self.segments.len() - 1 {
+                                    ui.label(
+                                        RichText::new(&UI_TEXT.cr_label_live)
+                                            .color(PLOT_CONFIG.color_profit)
+                                            .strong()
+                                            .small(),
+                                    );
+                                } else {
+                                    ui.label(
+                                        RichText::new(&UI_TEXT.cr_label_historical)
+                                            .small()
+                                            .color(PLOT_CONFIG.color_text_subdued),
+                                    );
+                                }
+                                let info =
+                                    ui.small_button("ℹ").on_hover_text(&UI_TEXT.cr_stats_hover);
+                                if info.clicked() {
+                                    self.info_request = Some((i, info.rect.left_bottom()));
+                                }
+                            });
                             ui.end_row();
                         }
                     });
@@ -143,3 +159,202 @@ impl<'a> CandleRangePanel<'a> {
         action
     }
 }
+
+/// Statistics window opened from a segment's info icon in the candle range panel.
+pub(crate) struct SegmentStatsPopover {
+    pub pair: String,
+    pub segment_idx: usize,
+    pub pos: Pos2,
+    pub stats: SegmentStats,
+}
+
+/// Returns false once the user closes it.
+pub(crate) fn render_segment_stats_popover(
+    ctx: &Context,
+    popover: &SegmentStatsPopover,
+    segment: &DisplaySegment,
+) -> bool {
+    let mut open = true;
+    let stats = &popover.stats;
+    Window::new(format!(
+        "{} {}",
+        UI_TEXT.cr_stats_title,
+        popover.segment_idx + 1
+    ))
+    .id(Id::new("segment_stats_popover"))
+    .open(&mut open)
+    .fixed_pos(popover.pos)
+    .collapsible(false)
+    .resizable(false)
+    .show(ctx, |ui| {
+        ui.label(
+            RichText::new(format!(
+                "{} - {} ({}c)",
+                segment.start_ts.to_datestring(),
+                segment.end_ts.to_datestring(),
+                stats.candle_count
+            ))
+            .small()
+            .color(PLOT_CONFIG.color_text_subdued),
+        );
+        Grid::new("segment_stats_grid")
+            .num_columns(2)
+            .spacing([12.0, 4.0])
+            .show(ui, |ui| {
+                ui.label(&UI_TEXT.cr_stats_range);
+                ui.label(format!("{} - {}", stats.low, stats.high));
+                ui.end_row();
+                ui.label(&UI_TEXT.cr_stats_change);
+                let color = if stats.change_pct >= 0.0 {
+                    PLOT_CONFIG.color_profit
+                } else {
+                    PLOT_CONFIG.color_loss
+                };
+                ui.colored_label(color, format!("{:+.2}%", stats.change_pct));
+                ui.end_row();
+                ui.label(&UI_TEXT.cr_stats_volatility);
+                ui.label(format!("{}", stats.volatility_pct));
+                ui.end_row();
+                ui.label(&UI_TEXT.cr_stats_volume);
+                ui.label(format!(
+                    "{:.2} / {}",
+                    stats.base_volume.value(),
+                    stats.quote_volume
+                ));
+                ui.end_row();
+                ui.label(&UI_TEXT.cr_stats_taker_buy);
+                ui.label(format!("{:.1}%", stats.taker_buy_share * 100.0));
+                ui.end_row();
+            });
+        ui.separator();
+        ui.label(RichText::new(&UI_TEXT.cr_stats_zones).strong().small());
+        if stats.dominant_zones.is_empty() {
+            ui.label(
+                RichText::new(&UI_TEXT.cr_stats_no_zones)
+                    .italics()
+                    .small()
+                    .color(PLOT_CONFIG.color_text_subdued),
+            );
+        }
+        for share in &stats.dominant_zones {
+            let kind = match share.kind {
+                DiffZoneKind::Sticky => &UI_TEXT.md_sticky,
+                DiffZoneKind::Support => &UI_TEXT.md_support,
+                DiffZoneKind::Resistance => &UI_TEXT.md_resistance,
+            };
+            ui.label(
+                RichText::new(format!(
+                    "{} {} - {}  {:.0}%",
+                    kind,
+                    share.zone.price_bottom,
+                    share.zone.price_top,
+                    share.volume_share * 100.0
+                ))
+                .small(),
+            );
+        }
+    });
+    open
+}
+
+pub struct JourneyBrowserPanel<'a> {
+    journeys: &'a [JourneyReplay],
+    max_duration_candles: usize,
+    selected_start_idx: Option<usize>,
+}
+
+impl<'a> JourneyBrowserPanel<'a> {
+    pub(crate) fn new(
+        journeys: &'a [JourneyReplay],
+        max_duration_candles: usize,
+        selected_start_idx: Option<usize>,
+    ) -> Self {
+        Self {
+            journeys,
+            max_duration_candles,
+            selected_start_idx,
+        }
+    }
+
+    /// Returns the journey the user clicked, if any.
+    pub(crate) fn render(&self, ui: &mut Ui) -> Option<&'a JourneyReplay> {
+        let mut clicked = None;
+        let wins = self
+            .journeys
+            .iter()
+            .filter(|j| matches!(j.outcome, Outcome::TargetHit(_)))
+            .count();
+        ui.label_subheader(format!(
+            "{} {} ({} {})",
+            self.journeys.len(),
+            UI_TEXT.jb_title,
+            wins,
+            UI_TEXT.jb_wins
+        ));
+        ui.separator();
+
+        ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                Grid::new("jb_grid")
+                    .striped(true)
+                    .num_columns(5)
+                    .spacing([10.0, 6.0])
+                    .show(ui, |ui| {
+                        ui.label(RichText::new(&UI_TEXT.jb_date).strong().small());
+                        ui.label(RichText::new(&UI_TEXT.jb_distance).strong().small());
+                        ui.label(RichText::new(&UI_TEXT.jb_outcome).strong().small());
+                        ui.label(RichText::new(&UI_TEXT.jb_duration).strong().small());
+                        ui.label(RichText::new(&UI_TEXT.jb_excursion).strong().small())
+                            .on_hover_text(&UI_TEXT.hover_excursions);
+                        ui.end_row();
+
+                        for journey in self.journeys {
+                            let is_selected = self.selected_start_idx == Some(journey.start_idx);
+                            if ui
+                                .selectable_label(
+                                    is_selected,
+                                    RichText::new(journey.start_ts.to_datestring()).small(),
+                                )
+                                .clicked()
+                            {
+                                clicked = Some(journey);
+                            }
+                            ui.label(RichText::new(format!("{:.3}", journey.distance)).small());
+                            let (text, color) = match journey.outcome {
+                                Outcome::TargetHit(_) => {
+                                    (UI_TEXT.jb_target.clone(), PLOT_CONFIG.color_profit)
+                                }
+                                Outcome::StopHit(_) => {
+                                    (UI_TEXT.jb_stop.clone(), PLOT_CONFIG.color_loss)
+                                }
+                                Outcome::TimedOut(roi) => (
+                                    format!("{} {}", UI_TEXT.jb_timeout, roi),
+                                    PLOT_CONFIG.color_warning,
+                                ),
+                            };
+                            ui.label(RichText::new(text).small().color(color));
+                            let candles = journey.candle_count(self.max_duration_candles);
+                            let ms = candles as i64 * BASE_INTERVAL.as_millis() as i64;
+                            ui.label(
+                                RichText::new(TimeUtils::format_duration(ms))
+                                    .small()
+                                    .color(PLOT_CONFIG.color_text_neutral),
+                            );
+                            ui.label(
+                                RichText::new(format!(
+                                    "-{:.2}% / +{:.2}%",
+                                    journey.excursion.adverse * 100.0,
+                                    journey.excursion.favorable * 100.0
+                                ))
+                                .small()
+                                .color(PLOT_CONFIG.color_text_subdued),
+                            );
+                            ui.end_row();
+                        }
+                    });
+            });
+
+        clicked
+    }
+}
@@ -1,14 +1,21 @@
 use {
     crate::{
-        app::{CandleResolution, Price, PriceLike},
-        engine::SniperEngine,
+        app::{CandleResolution, Price, PriceLike, TimestampMs},
+        data::{EconEvent, ExternalSignal},
+        domain::Candle,
+        engine::{PriceEvaluation, SniperEngine},
         models::{
-            CVACore, DisplaySegment, ScoreType, TradeOpportunity, TradingModel, find_matching_ohlcv,
+            CVACore, CustomZone, DiffZoneKind, DisplaySegment, IndicatorExpr, IndicatorScript,
+            JourneyReplay, OpportunityAnnotation, ScoreType, SuperZone, TradeOpportunity,
+            TradingModel, ZoneEdit, find_matching_ohlcv,
         },
         ui::{
-            BackgroundLayer, CandlestickLayer, HorizonLinesLayer, LayerContext, OpportunityLayer,
-            PLOT_CONFIG, PlotLayer, PriceLineLayer, ReversalZoneLayer, SegmentSeparatorLayer,
-            StickyZoneLayer, UI_TEXT,
+            AnnotationLayer, AnomalyLayer, BackgroundLayer, CandlestickLayer, CustomIndicatorLayer,
+            CustomZoneLayer, EconEventsLayer, ExternalSignalLayer, GhostModelLayer,
+            HorizonLinesLayer, IndicatorLine, JourneyLayer, JourneyOverlay, LayerContext,
+            OpportunityLayer, PLOT_CONFIG, PlotLayer, PriceLineLayer, PriceMarksLayer,
+            ReversalZoneLayer, SegmentSeparatorLayer, SplitBackgroundLayer, StickyZoneLayer,
+            UI_TEXT,
         },
         utils::{TimeUtils, normalize_max, smooth_data},
     },
@@ -19,16 +26,25 @@ use {
     },
     serde::{Deserialize, Serialize},
     std::{
-        collections::hash_map,
+        collections::{HashMap, hash_map},
         hash::{Hash, Hasher},
         ops,
     },
 };
 
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::mpsc::Receiver;
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
 pub(crate) struct PlotVisibility {
+    pub anomalies: bool,
     pub background: bool,
     pub candles: bool,
+    /// User-drawn zones
+    pub custom_zones: bool,
+    /// Webhook signal markers
+    pub external_signals: bool,
     pub high_wicks: bool,
     pub horizon_lines: bool,
     pub low_wicks: bool,
@@ -36,13 +52,18 @@ pub(crate) struct PlotVisibility {
     pub price_line: bool,
     pub separators: bool,
     pub sticky: bool,
+    /// Opportunity lifetime strip under the plot
+    pub timeline: bool,
 }
 
 impl Default for PlotVisibility {
     fn default() -> Self {
         Self {
+            anomalies: true,
             background: true,
             candles: true,
+            custom_zones: true,
+            external_signals: true,
             high_wicks: false,
             horizon_lines: true,
             low_wicks: false,
@@ -50,10 +71,160 @@ impl Default for PlotVisibility {
             price_line: true,
             separators: true,
             sticky: true,
+            timeline: true,
+        }
+    }
+}
+
+/// How the gaps between display segments are laid out on the x axis.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum TimeAxisMode {
+    /// Every gap is a fixed-width separator
+    #[default]
+    Compressed,
+    /// Gaps are as wide as the time they span (capped), so the axis reads linearly in time
+    TrueTime,
+}
+
+impl TimeAxisMode {
+    /// Visual width of a gap spanning `missing_slots` candle slots, given the summed width
+    /// of all segments.
+    fn gap_width(self, missing_slots: f64, data_width: f64) -> f64 {
+        match self {
+            Self::Compressed => PLOT_CONFIG.segment_gap_width_px,
+            Self::TrueTime => missing_slots
+                .min(data_width * PLOT_CONFIG.true_time_gap_max_pct)
+                .max(PLOT_CONFIG.segment_gap_width_px),
         }
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+struct SegmentSpan {
+    start_bucket: i64,
+    x_start: f64,
+    width: f64,
+}
+
+/// Visual x positions of the display segments at one resolution. One x unit is one candle
+/// slot; every plot layer and the time axis map through this so they agree on the gaps.
+#[derive(Debug, Clone)]
+pub(crate) struct SegmentLayout {
+    agg_interval_ms: i64,
+    mode: TimeAxisMode,
+    spans: Vec<SegmentSpan>,
+    total_width: f64,
+}
+
+impl SegmentLayout {
+    pub(crate) fn new(
+        segments: &[DisplaySegment],
+        resolution: CandleResolution,
+        mode: TimeAxisMode,
+    ) -> Self {
+        let agg_interval_ms = resolution.duration().as_millis() as i64;
+        let buckets: Vec<(i64, i64)> = segments
+            .iter()
+            .map(|s| {
+                (
+                    s.start_ts.value() / agg_interval_ms,
+                    s.end_ts.value() / agg_interval_ms,
+                )
+            })
+            .collect();
+        let data_width: f64 = buckets.iter().map(|(s, e)| (e - s + 1) as f64).sum();
+
+        let mut x = 0.0;
+        let mut spans = Vec::with_capacity(buckets.len());
+        for (i, &(start, end)) in buckets.iter().enumerate() {
+            if i > 0 {
+                let missing = (start - buckets[i - 1].1 - 1) as f64;
+                x += mode.gap_width(missing, data_width);
+            }
+            let width = (end - start + 1) as f64;
+            spans.push(SegmentSpan {
+                start_bucket: start,
+                x_start: x,
+                width,
+            });
+            x += width;
+        }
+        Self {
+            agg_interval_ms,
+            mode,
+            spans,
+            total_width: x,
+        }
+    }
+
+    pub(crate) fn total_width(&self) -> f64 {
+        self.total_width
+    }
+
+    /// `(start, end)` x of segment `idx`.
+    pub(crate) fn segment_bounds(&self, idx: usize) -> Option<(f64, f64)> {
+        self.spans
+            .get(idx)
+            .map(|span| (span.x_start, span.x_start + span.width))
+    }
+
+    /// Left edge of the slot holding `ts` within segment `idx`.
+    pub(crate) fn slot_x(&self, idx: usize, ts: TimestampMs) -> f64 {
+        let span = &self.spans[idx];
+        span.x_start + (ts.value() / self.agg_interval_ms - span.start_bucket) as f64
+    }
+
+    /// Centre of the gap between segment `idx` and the next one.
+    pub(crate) fn gap_center(&self, idx: usize) -> Option<f64> {
+        let (prev, next) = (self.spans.get(idx)?, self.spans.get(idx + 1)?);
+        Some((prev.x_start + prev.width + next.x_start) / 2.0)
+    }
+
+    /// Maps a timestamp onto the axis. In compressed mode times inside a gap have no position;
+    /// times after the last segment extend it (upcoming events).
+    pub(crate) fn x_for_timestamp(&self, ts: TimestampMs) -> Option<f64> {
+        let t = ts.value() as f64 / self.agg_interval_ms as f64;
+        let mut prev: Option<&SegmentSpan> = None;
+        for span in &self.spans {
+            let start = span.start_bucket as f64;
+            if t < start {
+                let prev = prev.filter(|_| self.mode == TimeAxisMode::TrueTime)?;
+                let gap_start_t = prev.start_bucket as f64 + prev.width;
+                let gap_start_x = prev.x_start + prev.width;
+                let frac = (t - gap_start_t) / (start - gap_start_t).max(f64::EPSILON);
+                return Some(gap_start_x + frac.max(0.0) * (span.x_start - gap_start_x));
+            }
+            if t < start + span.width {
+                return Some(span.x_start + t - start);
+            }
+            prev = Some(span);
+        }
+        let last = prev?;
+        Some(last.x_start + t - last.start_bucket as f64)
+    }
+
+    /// Inverse of `x_for_timestamp` inside `0..total_width`. None for a compressed gap.
+    pub(crate) fn timestamp_at(&self, x: f64) -> Option<TimestampMs> {
+        let mut prev: Option<&SegmentSpan> = None;
+        for span in &self.spans {
+            if x < span.x_start {
+                let prev = prev.filter(|_| self.mode == TimeAxisMode::TrueTime)?;
+                let gap_start_t = prev.start_bucket as f64 + prev.width;
+                let gap_start_x = prev.x_start + prev.width;
+                let frac = (x - gap_start_x) / (span.x_start - gap_start_x).max(f64::EPSILON);
+                let t = gap_start_t + frac * (span.start_bucket as f64 - gap_start_t);
+                return Some(TimestampMs::new((t * self.agg_interval_ms as f64) as i64));
+            }
+            if x < span.x_start + span.width {
+                let bucket = span.start_bucket + (x - span.x_start).floor() as i64;
+                return Some(TimestampMs::new(bucket * self.agg_interval_ms));
+            }
+            prev = Some(span);
+        }
+        None
+    }
+}
+
 #[derive(Clone)]
 pub(crate) struct BackgroundBar {
     pub x_max: f64,
@@ -68,9 +239,254 @@ pub(crate) struct PlotCache {
     pub bars: Vec<BackgroundBar>,
 }
 
+/// Price levels the user placed from the plot context menu. Persisted per pair.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct PriceMarks {
+    pub alerts: Vec<PriceAlert>,
+    pub lines: Vec<Price>,
+}
+
+impl PriceMarks {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.alerts.is_empty() && self.lines.is_empty()
+    }
+}
+
+/// Note being written from the plot context menu, before it is pinned to the opportunity.
+#[derive(Debug, Clone)]
+pub(crate) struct AnnotationDraft {
+    pub opportunity_id: String,
+    pub price: Price,
+    pub text: String,
+}
+
+/// Custom zone being drawn from the plot context menu, before it is stored for the pair.
+#[derive(Debug, Clone)]
+pub(crate) struct CustomZoneDraft {
+    pub pair: String,
+    pub zone: CustomZone,
+}
+
+impl CustomZoneDraft {
+    /// Support below the live price, resistance above, sized around `price`.
+    pub(crate) fn new(pair: &str, price: Price, current_price: Option<Price>) -> Self {
+        let half = price.value() * PLOT_CONFIG.custom_zone_half_height_pct;
+        let kind = match current_price {
+            Some(current) if current < price => DiffZoneKind::Resistance,
+            Some(_) => DiffZoneKind::Support,
+            None => DiffZoneKind::Sticky,
+        };
+        Self {
+            pair: pair.to_string(),
+            zone: CustomZone {
+                id: 0,
+                label: String::new(),
+                kind,
+                price_bottom: Price::new(price.value() - half),
+                price_top: Price::new(price.value() + half),
+                alert: false,
+            },
+        }
+    }
+}
+
+/// "What-if at prices" window: hypothetical prices for one pair and what the engine would
+/// recommend at each. Live models are never touched.
+#[derive(Debug)]
+pub(crate) struct PriceWhatIf {
+    pub pair: String,
+    /// Prices as typed, comma or space separated
+    pub input: String,
+    pub results: Vec<PriceEvaluation>,
+    /// Evaluate on the next frame without waiting for the button
+    pub run_pending: bool,
+    /// Evaluations running on a background thread
+    #[cfg(not(target_arch = "wasm32"))]
+    pub rx: Option<Receiver<Vec<PriceEvaluation>>>,
+}
+
+impl PriceWhatIf {
+    /// Prefilled with the clicked price, then the live price for comparison.
+    pub(crate) fn new(pair: &str, price: Price, current_price: Option<Price>) -> Self {
+        let input = std::iter::once(price)
+            .chain(current_price)
+            .map(|p| p.value().to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        Self {
+            pair: pair.to_string(),
+            input,
+            results: Vec::new(),
+            run_pending: false,
+            #[cfg(not(target_arch = "wasm32"))]
+            rx: None,
+        }
+    }
+
+    /// "Simulate price here": just `price`, evaluated straight away.
+    pub(crate) fn simulate(pair: &str, price: Price) -> Self {
+        Self {
+            run_pending: true,
+            ..Self::new(pair, price, None)
+        }
+    }
+
+    /// Positive prices in `input`; anything unparsable is ignored.
+    pub(crate) fn prices(&self) -> Vec<Price> {
+        self.input
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter_map(|token| token.parse::<f64>().ok())
+            .filter(|value| *value > 0.0)
+            .map(Price::new)
+            .collect()
+    }
+}
+
+/// "Correlated move" window: one pair moved to a price and every correlated pair moved by its
+/// beta, with what the engine would recommend for each. Live models are never touched.
+#[derive(Debug)]
+pub(crate) struct CorrelatedWhatIf {
+    pub leader: String,
+    pub price: Price,
+    /// Leader first, then each correlated pair
+    pub results: Vec<PriceEvaluation>,
+    /// Evaluations running on a background thread
+    #[cfg(not(target_arch = "wasm32"))]
+    pub rx: Option<Receiver<Vec<PriceEvaluation>>>,
+}
+
+/// Fires once when the live price crosses `price` from the side it was on when set.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub(crate) struct PriceAlert {
+    pub price: Price,
+    pub from_above: bool,
+    pub triggered: bool,
+}
+
+impl PriceAlert {
+    pub(crate) fn new(price: Price, current_price: Option<Price>) -> Self {
+        Self {
+            price,
+            from_above: current_price.is_some_and(|p| p > price),
+            triggered: false,
+        }
+    }
+
+    /// Returns true on the frame the alert fires.
+    pub(crate) fn check(&mut self, current_price: Price) -> bool {
+        if self.triggered {
+            return false;
+        }
+        let crossed = if self.from_above {
+            current_price <= self.price
+        } else {
+            current_price >= self.price
+        };
+        self.triggered = crossed;
+        crossed
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum PlotContextAction {
+    SetAlert(Price),
+    AddLine(Price),
+    CenterOn(Price),
+    CopyPrice(Price),
+    SimulatePrice(Price),
+    /// Simulate this pair at the price and move all other pairs by their beta to it
+    SimulateCorrelated(Price),
+    /// Pin a note at this price to the selected opportunity
+    Annotate(Price),
+    ClearMarks,
+    /// Manually split or merge a zone of the current pair
+    EditZone(ZoneEdit),
+    /// Open the custom zone editor around this price
+    AddCustomZone(Price),
+    /// Open the what-if window prefilled with this price
+    WhatIfPrices(Price),
+}
+
 #[derive(Default)]
 pub(crate) struct PlotView {
     cache: Option<PlotCache>,
+    /// Second background cache for the A/B split view
+    compare_cache: Option<PlotCache>,
+    /// Price under the pointer when the context menu was opened
+    context_price: Option<f64>,
+    /// Y centre to apply on the next frame (manual scale only)
+    pending_center: Option<f64>,
+    /// Y range multiplier to apply on the next frame (manual scale only)
+    pending_zoom_y: Option<f64>,
+    /// Exact Y range to apply on the next frame (manual scale only)
+    pending_y_range: Option<(f64, f64)>,
+    indicator_cache: IndicatorCache,
+}
+
+/// Custom indicator lines for the plotted pair, recomputed when the candles or scripts change.
+#[derive(Default)]
+struct IndicatorCache {
+    /// Parsed scripts by source, so a broken one is reported once rather than on every candle
+    compiled: HashMap<String, Result<IndicatorExpr, String>>,
+    /// Parse failures found since the app last collected them
+    errors: Vec<String>,
+    hash: Option<u64>,
+    lines: Vec<IndicatorLine>,
+}
+
+impl IndicatorCache {
+    fn lines(
+        &mut self,
+        pair: &str,
+        scripts: &[IndicatorScript],
+        candles: &[Candle],
+        resolution: CandleResolution,
+    ) -> &[IndicatorLine] {
+        let enabled = || scripts.iter().filter(|s| s.enabled);
+        let mut hasher = hash_map::DefaultHasher::new();
+        pair.hash(&mut hasher);
+        resolution.duration().as_millis().hash(&mut hasher);
+        candles.len().hash(&mut hasher);
+        if let Some(last) = candles.last() {
+            last.timestamp_ms.value().hash(&mut hasher);
+            last.close_price.value().to_bits().hash(&mut hasher);
+        }
+        for script in enabled() {
+            (&script.name, &script.source, script.color).hash(&mut hasher);
+        }
+        let hash = hasher.finish();
+        if self.hash == Some(hash) {
+            return &self.lines;
+        }
+        self.compiled
+            .retain(|src, _| scripts.iter().any(|s| s.source == *src));
+        let palette = &PLOT_CONFIG.indicator_palette;
+        let mut lines = Vec::new();
+        for script in enabled() {
+            let compiled = self
+                .compiled
+                .entry(script.source.clone())
+                .or_insert_with(|| {
+                    IndicatorExpr::parse(&script.source).inspect_err(|e| {
+                        let msg =
+                            format!("Indicator '{}' on {} not drawn: {}", script.name, pair, e);
+                        log::warn!("{}", msg);
+                        self.errors.push(msg);
+                    })
+                });
+            if let Ok(expr) = compiled {
+                lines.push(IndicatorLine {
+                    name: script.name.clone(),
+                    color: palette[script.color % palette.len()],
+                    values: expr.evaluate(candles),
+                });
+            }
+        }
+        self.hash = Some(hash);
+        self.lines = lines;
+        &self.lines
+    }
 }
 
 fn calc_adaptive_step(range: f64, target_count: f64) -> f64 {
@@ -90,33 +506,19 @@ fn calc_adaptive_step(range: f64, target_count: f64) -> f64 {
     nice_step * mag
 }
 
-fn create_time_axis(model: &TradingModel, resolution: CandleResolution) -> AxisHints<'static> {
-    let segments = model.segments.clone();
-    let gap_width = PLOT_CONFIG.segment_gap_width_px;
-    let agg_interval_ms = resolution.duration().as_millis() as i64;
+fn create_time_axis(layout: &SegmentLayout) -> AxisHints<'static> {
+    let layout = layout.clone();
 
     AxisHints::new(Axis::X)
         .label(&UI_TEXT.plot_x_axis)
         .formatter(move |mark, _range| {
-            let visual_x = mark.value;
-            let mut current_visual_start = 0.0;
-
-            for seg in &segments {
-                let start_bucket = seg.start_ts / agg_interval_ms;
-                let end_bucket = seg.end_ts / agg_interval_ms;
-                let seg_len_vis = (end_bucket - start_bucket + 1) as f64;
-                let current_visual_end = current_visual_start + seg_len_vis;
-                if visual_x >= current_visual_start && visual_x < current_visual_end {
-                    let local_offset = (visual_x - current_visual_start).floor() as i64;
-                    let bucket_ts = (start_bucket + local_offset) * agg_interval_ms;
-                    return TimeUtils::ms_to_datestring(bucket_ts);
-                }
-                current_visual_start = current_visual_end + gap_width;
-                if visual_x < current_visual_start {
-                    return UI_TEXT.plot_x_axis_gap.to_string();
-                }
+            if mark.value < 0.0 || mark.value >= layout.total_width() {
+                return String::new();
             }
-            String::new()
+            layout.timestamp_at(mark.value).map_or_else(
+                || UI_TEXT.plot_x_axis_gap.to_string(),
+                |ts| TimeUtils::ms_to_datestring(ts.value()),
+            )
         })
         .placement(VPlacement::Bottom)
 }
@@ -125,11 +527,44 @@ pub(crate) enum PlotInteraction {
     None,
     UserInteracted, // User dragged/zoomed
     RequestReset,   // User double-clicked
+    /// User double-clicked inside a zone
+    SnapToZone(SuperZone),
+    Context(PlotContextAction),
 }
 
 impl PlotView {
     pub(crate) fn new() -> Self {
-        Self { cache: None }
+        Self {
+            cache: None,
+            compare_cache: None,
+            context_price: None,
+            pending_center: None,
+            pending_zoom_y: None,
+            pending_y_range: None,
+            indicator_cache: IndicatorCache::default(),
+        }
+    }
+
+    pub(crate) fn center_on(&mut self, price: Price) {
+        self.pending_center = Some(price.value());
+    }
+
+    /// Frames `zone` vertically with `PLOT_CONFIG.zone_snap_padding_pct` of its height above
+    /// and below (takes effect on the next manual-scale frame).
+    pub(crate) fn frame_zone(&mut self, zone: &SuperZone) {
+        let (bottom, top) = (zone.price_bottom.value(), zone.price_top.value());
+        let pad = (top - bottom) * PLOT_CONFIG.zone_snap_padding_pct;
+        self.pending_y_range = Some((bottom - pad, top + pad));
+    }
+
+    /// Indicator parse errors found since the last call.
+    pub(crate) fn take_indicator_errors(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.indicator_cache.errors)
+    }
+
+    /// `factor` < 1 zooms in. Repeated calls before the next frame compound.
+    pub(crate) fn zoom_y(&mut self, factor: f64) {
+        self.pending_zoom_y = Some(self.pending_zoom_y.unwrap_or(1.0) * factor);
     }
 
     pub(crate) fn show_my_plot(
@@ -139,12 +574,22 @@ impl PlotView {
         trading_model: &TradingModel,
         current_pair_price: Option<Price>,
         background_score_type: ScoreType,
+        compare_score_type: Option<ScoreType>,
         visibility: &PlotVisibility,
         engine: &SniperEngine,
         resolution: CandleResolution,
+        time_axis_mode: TimeAxisMode,
         current_segment_idx: Option<usize>,
         auto_scale_y: bool,
         selected_opportunity: Option<TradeOpportunity>,
+        selected_journey: Option<&JourneyReplay>,
+        price_marks: Option<&PriceMarks>,
+        custom_zones: &[CustomZone],
+        annotations: &[OpportunityAnnotation],
+        econ_events: &[EconEvent],
+        external_signals: &[ExternalSignal],
+        ghost_model: Option<&TradingModel>,
+        indicator_scripts: &[IndicatorScript],
     ) -> PlotInteraction {
         let ts_guard = engine.timeseries.read().unwrap();
         let ohlcv = find_matching_ohlcv(
@@ -154,15 +599,30 @@ impl PlotView {
         )
         .expect(&UI_TEXT.plot_missing_klines);
 
-        let (view_min, view_max, total_visual_width) =
-            self.calc_view_bounds(trading_model, current_segment_idx, resolution);
+        let layout = SegmentLayout::new(&trading_model.segments, resolution, time_axis_mode);
+        let (view_min, view_max) = self.calc_view_bounds(&layout, current_segment_idx);
 
         // Y-Axis: CONDITIONAL LOCK. Do BEFORE plot so grid spacer knows real visual range
         let y_bounds_range = self.calc_y_bounds(cva_results, current_pair_price);
-        let cache = self.calc_plot_data(cva_results, background_score_type);
+        let cache = calc_plot_data(&mut self.cache, cva_results, background_score_type);
+        let compare_cache =
+            compare_score_type.map(|st| calc_plot_data(&mut self.compare_cache, cva_results, st));
         let (ph_min, ph_max) = cva_results.price_range.min_max();
-        let time_axis = create_time_axis(trading_model, resolution);
+        let journey_overlay = selected_journey
+            .zip(selected_opportunity.as_ref())
+            .and_then(|(j, op)| JourneyOverlay::new(j, op, ohlcv));
+        let time_axis = create_time_axis(&layout);
         let price_axis = create_y_axis(&cva_results.pair_name);
+        let pending_center = self.pending_center.take();
+        let pending_zoom_y = self.pending_zoom_y.take();
+        let pending_y_range = self.pending_y_range.take();
+        let fine_pan = ui.input(|i| i.modifiers.shift);
+        let indicators = self.indicator_cache.lines(
+            &cva_results.pair_name,
+            indicator_scripts,
+            ohlcv.aggregated(resolution),
+            resolution,
+        );
 
         let plot_response = Plot::new("my_plot")
             // .custom_x_axes(vec![create_x_axis(&cache)])
@@ -174,21 +634,46 @@ impl PlotView {
             .allow_scroll(false)
             .allow_boxed_zoom(false)
             .allow_double_click_reset(false)
-            .allow_drag(Vec2b { x: false, y: true })
-            .allow_zoom(Vec2b { x: false, y: true })
+            // Shift+drag pans at a finer rate in apply_gestures instead
+            .allow_drag(Vec2b {
+                x: false,
+                y: !fine_pan,
+            })
+            // Y zoom is handled in apply_gestures so pinch, ctrl+scroll and keys share one path
+            .allow_zoom(false)
             .show(ui, |plot_ui| {
                 let width = view_max - view_min;
                 let safe_width = width.max(10.0);
                 let pad_x = safe_width * PLOT_CONFIG.plot_x_padding_pct;
                 plot_ui.set_plot_bounds_x((view_min - pad_x)..=(view_max + pad_x));
+                // The Y range is settled once per frame: requested changes, then gestures,
+                // then the safety limits, so nothing set later can slip past the limits.
+                let bounds = plot_ui.plot_bounds();
+                let (mut min, mut max) = (bounds.min()[1], bounds.max()[1]);
                 if auto_scale_y {
-                    plot_ui.set_plot_bounds_y(y_bounds_range);
-                } else {
-                    Self::enforce_manual_safety_limits(
-                        plot_ui,
+                    (min, max) = (*y_bounds_range.start(), *y_bounds_range.end());
+                } else if let Some(range) = pending_y_range {
+                    (min, max) = range;
+                } else if pending_center.is_some() || pending_zoom_y.is_some() {
+                    let factor = pending_zoom_y.unwrap_or(1.0);
+                    // Recenter when asked to, otherwise keep the price under the pointer put
+                    (min, max) = match pending_center {
+                        Some(center) => {
+                            let half = (max - min) / 2.0 * factor;
+                            (center - half, center + half)
+                        }
+                        None => zoom_about(min, max, Self::zoom_anchor(plot_ui, min, max), factor),
+                    };
+                }
+                let gestured = Self::apply_gestures(plot_ui, fine_pan, &mut min, &mut max);
+                if !auto_scale_y || gestured {
+                    (min, max) = Self::enforce_manual_safety_limits(
+                        min,
+                        max,
                         current_pair_price.unwrap_or_default(),
                     );
                 }
+                plot_ui.set_plot_bounds_y(min..=max);
                 let (ph_min, ph_max) = cva_results.price_range.min_max();
                 let bounds = plot_ui.plot_bounds();
                 let min = bounds.min();
@@ -201,21 +686,35 @@ impl PlotView {
                     trading_model,
                     ohlcv,
                     cache: &cache,
+                    compare_cache: compare_cache.as_ref(),
                     visibility,
                     x_min: 0.0,
-                    x_max: total_visual_width,
+                    x_max: layout.total_width(),
+                    layout: &layout,
                     current_price: current_pair_price,
                     resolution,
                     ph_bounds: (Price::new(ph_min), Price::new(ph_max)),
                     clip_rect,
                     selected_opportunity: &selected_opportunity,
+                    journey: journey_overlay.as_ref(),
+                    price_marks,
+                    custom_zones,
+                    annotations,
+                    econ_events,
+                    external_signals,
+                    ghost_model,
+                    indicators,
                 };
 
                 let mut layers: Vec<Box<dyn PlotLayer>> = Vec::with_capacity(7);
                 let is_show_all = current_segment_idx.is_none();
                 if is_show_all {
                     if visibility.background {
-                        layers.push(Box::new(BackgroundLayer));
+                        if compare_cache.is_some() {
+                            layers.push(Box::new(SplitBackgroundLayer));
+                        } else {
+                            layers.push(Box::new(BackgroundLayer));
+                        }
                     }
                     if visibility.sticky {
                         layers.push(Box::new(StickyZoneLayer));
@@ -227,7 +726,13 @@ impl PlotView {
                         layers.push(Box::new(SegmentSeparatorLayer));
                     }
                 }
+                if visibility.custom_zones && !custom_zones.is_empty() {
+                    layers.push(Box::new(CustomZoneLayer));
+                }
 
+                if ghost_model.is_some() {
+                    layers.push(Box::new(GhostModelLayer));
+                }
                 if visibility.price_line {
                     layers.push(Box::new(PriceLineLayer));
                 }
@@ -237,20 +742,128 @@ impl PlotView {
                 if visibility.candles {
                     layers.push(Box::new(CandlestickLayer));
                 }
+                if !indicators.is_empty() {
+                    layers.push(Box::new(CustomIndicatorLayer));
+                }
+                if visibility.anomalies && trading_model.anomalies.count() > 0 {
+                    layers.push(Box::new(AnomalyLayer));
+                }
                 if visibility.opportunities {
                     layers.push(Box::new(OpportunityLayer));
                 }
-                for layer in layers {
+                if journey_overlay.is_some() {
+                    layers.push(Box::new(JourneyLayer));
+                }
+                if price_marks.is_some_and(|m| !m.is_empty()) {
+                    layers.push(Box::new(PriceMarksLayer));
+                }
+                if !annotations.is_empty() {
+                    layers.push(Box::new(AnnotationLayer));
+                }
+                if !econ_events.is_empty() {
+                    layers.push(Box::new(EconEventsLayer));
+                }
+                if visibility.external_signals && !external_signals.is_empty() {
+                    layers.push(Box::new(ExternalSignalLayer));
+                }
+                for layer in &layers {
                     layer.render(plot_ui, &ctx);
                 }
+                let hover_price = plot_ui.pointer_coordinate().map(|p| p.y);
+                // Topmost layer wins
+                let snap_zone = hover_price
+                    .filter(|_| plot_ui.response().double_clicked())
+                    .and_then(|y| {
+                        layers
+                            .iter()
+                            .rev()
+                            .find_map(|layer| layer.hit_zone(&ctx, Price::new(y)))
+                    });
+                (hover_price, gestured, snap_zone)
             });
 
+        let (hover_price, gestured, snap_zone) = plot_response.inner;
         let r = plot_response.response;
+        if r.secondary_clicked() {
+            self.context_price = hover_price;
+        }
+        if let Some(price) = self.context_price.map(Price::new) {
+            let mut action = None;
+            r.context_menu(|ui| {
+                ui.label(format!("{}", price));
+                ui.separator();
+                for (label, a) in [
+                    (
+                        &UI_TEXT.plot_ctx_set_alert,
+                        PlotContextAction::SetAlert(price),
+                    ),
+                    (
+                        &UI_TEXT.plot_ctx_add_line,
+                        PlotContextAction::AddLine(price),
+                    ),
+                    (
+                        &UI_TEXT.plot_ctx_add_zone,
+                        PlotContextAction::AddCustomZone(price),
+                    ),
+                    (&UI_TEXT.plot_ctx_center, PlotContextAction::CenterOn(price)),
+                    (
+                        &UI_TEXT.plot_ctx_copy_price,
+                        PlotContextAction::CopyPrice(price),
+                    ),
+                    (
+                        &UI_TEXT.plot_ctx_simulate,
+                        PlotContextAction::SimulatePrice(price),
+                    ),
+                    (
+                        &UI_TEXT.plot_ctx_simulate_correlated,
+                        PlotContextAction::SimulateCorrelated(price),
+                    ),
+                    (
+                        &UI_TEXT.plot_ctx_what_if,
+                        PlotContextAction::WhatIfPrices(price),
+                    ),
+                ] {
+                    if ui.button(label).clicked() {
+                        action = Some(a);
+                        ui.close();
+                    }
+                }
+                let zone_edits = zone_edit_options(trading_model, visibility, price);
+                if current_segment_idx.is_none() && !zone_edits.is_empty() {
+                    ui.separator();
+                    for (label, edit) in zone_edits {
+                        if ui.button(label).clicked() {
+                            action = Some(PlotContextAction::EditZone(edit));
+                            ui.close();
+                        }
+                    }
+                    ui.separator();
+                }
+                if selected_opportunity.is_some() && ui.button(&UI_TEXT.plot_ctx_annotate).clicked()
+                {
+                    action = Some(PlotContextAction::Annotate(price));
+                    ui.close();
+                }
+                if price_marks.is_some_and(|m| !m.is_empty()) {
+                    ui.separator();
+                    if ui.button(&UI_TEXT.plot_ctx_clear_marks).clicked() {
+                        action = Some(PlotContextAction::ClearMarks);
+                        ui.close();
+                    }
+                }
+            });
+            if let Some(action) = action {
+                return PlotInteraction::Context(action);
+            }
+        }
         if r.double_clicked() {
-            return PlotInteraction::RequestReset;
+            return snap_zone.map_or(PlotInteraction::RequestReset, PlotInteraction::SnapToZone);
         }
 
-        if r.dragged_by(PointerButton::Primary) || r.dragged_by(PointerButton::Secondary) {
+        if gestured
+            || r.dragged_by(PointerButton::Primary)
+            || r.dragged_by(PointerButton::Secondary)
+        {
             return PlotInteraction::UserInteracted;
         }
         if r.hovered() && ui.input(|i| i.raw_scroll_delta.y.abs() > 0.0) {
@@ -260,38 +873,56 @@ impl PlotView {
         PlotInteraction::None
     }
 
-    fn calc_view_bounds(
-        &self,
-        model: &TradingModel,
-        current_segment_idx: Option<usize>,
-        resolution: CandleResolution,
-    ) -> (f64, f64, f64) {
-        let gap_size = PLOT_CONFIG.segment_gap_width_px;
-        let agg_interval_ms = resolution.duration().as_millis() as i64;
-
-        let calc_width = |seg: &DisplaySegment| -> f64 {
-            let start_bucket = seg.start_ts / agg_interval_ms;
-            let end_bucket = seg.end_ts / agg_interval_ms;
-            let buckets = end_bucket - start_bucket + 1;
-            buckets as f64
-        };
+    /// Price under the pointer while it is over the plot, else the middle of the view.
+    fn zoom_anchor(plot_ui: &PlotUi, min: f64, max: f64) -> f64 {
+        plot_ui
+            .pointer_coordinate()
+            .filter(|_| plot_ui.response().hovered())
+            .map_or((min + max) / 2.0, |p| p.y)
+    }
 
-        let total_visual_candles: f64 = model.segments.iter().map(&calc_width).sum();
-        let gap_count = model.segments.len().saturating_sub(1);
-        let total_visual_width = total_visual_candles + (gap_count as f64 * gap_size);
+    /// Pinch / ctrl+scroll zooms the Y range `min..max` around the pointer; two-finger pan
+    /// (trackpad scroll or touch translation) moves it. Returns true if the range changed.
+    fn apply_gestures(plot_ui: &PlotUi, fine_pan: bool, min: &mut f64, max: &mut f64) -> bool {
+        if !plot_ui.response().hovered() {
+            return false;
+        }
+        let (zoom, mut pan_px) = plot_ui.ctx().input(|i| {
+            let pan = i
+                .multi_touch()
+                .map_or(i.smooth_scroll_delta.y, |t| t.translation_delta.y);
+            (i.zoom_delta_2d().y as f64, pan as f64)
+        });
+        if fine_pan && plot_ui.response().dragged_by(PointerButton::Primary) {
+            pan_px += plot_ui.response().drag_delta().y as f64 * PLOT_CONFIG.fine_pan_factor;
+        }
+        let zooming = (zoom - 1.0).abs() > f64::EPSILON;
+        if !zooming && pan_px.abs() < f64::EPSILON {
+            return false;
+        }
 
-        if let Some(target_idx) = current_segment_idx {
-            if target_idx < model.segments.len() {
-                let mut start_x = 0.0;
-                for i in 0..target_idx {
-                    start_x += calc_width(&model.segments[i]);
-                    start_x += gap_size;
-                }
-                let width = calc_width(&model.segments[target_idx]);
-                return (start_x, start_x + width, total_visual_width);
-            }
+        if zooming {
+            let anchor = Self::zoom_anchor(plot_ui, *min, *max);
+            (*min, *max) = zoom_about(*min, *max, anchor, 1.0 / zoom);
+        }
+        let rect_height = plot_ui.response().rect.height() as f64;
+        if rect_height > 0.0 {
+            // Screen y grows downward: dragging content down reveals higher prices
+            let shift = pan_px * (*max - *min) / rect_height;
+            *min += shift;
+            *max += shift;
         }
-        (0.0, total_visual_width, total_visual_width)
+        true
+    }
+
+    fn calc_view_bounds(
+        &self,
+        layout: &SegmentLayout,
+        current_segment_idx: Option<usize>,
+    ) -> (f64, f64) {
+        current_segment_idx
+            .and_then(|idx| layout.segment_bounds(idx))
+            .unwrap_or((0.0, layout.total_width()))
     }
 
     fn calc_y_bounds(
@@ -343,12 +974,12 @@ impl PlotView {
         marks
     }
 
-    fn enforce_manual_safety_limits(plot_ui: &mut PlotUi, current_price: Price) {
-        let bounds = plot_ui.plot_bounds();
-        let mut min = *bounds.range_y().start();
-        let mut max = *bounds.range_y().end();
+    fn enforce_manual_safety_limits(
+        mut min: f64,
+        mut max: f64,
+        current_price: Price,
+    ) -> (f64, f64) {
         let mut range = max - min;
-        let mut changed = false;
         let base_price = current_price.value().max(1.0);
         let min_allowed_range = base_price * 0.00001;
         let max_allowed_range = base_price * 2.0;
@@ -357,101 +988,136 @@ impl PlotView {
             range = min_allowed_range;
             min = center - range / 2.0;
             max = center + range / 2.0;
-            changed = true;
         } else if range > max_allowed_range {
             let center = (min + max) / 2.0;
             range = max_allowed_range;
             min = center - range / 2.0;
             max = center + range / 2.0;
-            changed = true;
         }
         if min < 0.0 {
             let diff = 0.0 - min;
             min += diff;
             max += diff;
-            changed = true;
         }
         let hard_ceiling = base_price * 5.0;
         if max > hard_ceiling {
             let diff = max - hard_ceiling;
             min -= diff;
             max -= diff;
-            changed = true;
-        }
-        if changed {
-            plot_ui.set_plot_bounds_y(min..=max);
         }
+        (min, max)
     }
+}
 
-    fn calc_plot_data(&mut self, cva_results: &CVACore, score_type: ScoreType) -> PlotCache {
-        let zone_count = cva_results.zone_count;
-        let time_decay_factor = cva_results.time_decay_factor;
-        let mut hasher = hash_map::DefaultHasher::new();
-        cva_results
-            .price_range
-            .min_max()
-            .0
-            .to_bits()
-            .hash(&mut hasher);
-        cva_results
-            .price_range
-            .min_max()
-            .1
-            .to_bits()
-            .hash(&mut hasher);
-        zone_count.hash(&mut hasher);
-        score_type.hash(&mut hasher);
-        time_decay_factor.to_bits().hash(&mut hasher);
-        cva_results
-            .get_scores_ref(score_type)
-            .len()
-            .hash(&mut hasher);
-        let current_hash = hasher.finish();
-        if let Some(cache) = &self.cache {
-            if cache.cva_hash == current_hash {
-                return cache.clone();
-            }
+/// Builds (or reuses) the background histogram for one score type, caching into `slot`.
+fn calc_plot_data(
+    slot: &mut Option<PlotCache>,
+    cva_results: &CVACore,
+    score_type: ScoreType,
+) -> PlotCache {
+    let zone_count = cva_results.zone_count;
+    let time_decay_factor = cva_results.time_decay_factor;
+    let mut hasher = hash_map::DefaultHasher::new();
+    cva_results
+        .price_range
+        .min_max()
+        .0
+        .to_bits()
+        .hash(&mut hasher);
+    cva_results
+        .price_range
+        .min_max()
+        .1
+        .to_bits()
+        .hash(&mut hasher);
+    zone_count.hash(&mut hasher);
+    score_type.hash(&mut hasher);
+    time_decay_factor.to_bits().hash(&mut hasher);
+    cva_results
+        .get_scores_ref(score_type)
+        .len()
+        .hash(&mut hasher);
+    let current_hash = hasher.finish();
+    if let Some(cache) = slot.as_ref() {
+        if cache.cva_hash == current_hash {
+            return cache.clone();
         }
+    }
 
-        crate::trace_time!("Rebuild Plot Cache", 500, {
-            let (y_min, y_max) = cva_results.price_range.min_max();
-            let bar_width = (y_max - y_min) / zone_count as f64;
-            let raw_data_vec = cva_results.get_scores_ref(score_type).clone();
-            let smoothing_window = ((zone_count as f64 * 0.02).ceil() as usize).max(1) | 1;
-            let smoothed_data = smooth_data(&raw_data_vec, smoothing_window);
-            let data_for_display = normalize_max(&smoothed_data);
-            let indices: Vec<usize> = (0..zone_count).collect();
-            let grad = colorgrad::GradientBuilder::new()
-                .html_colors(PLOT_CONFIG.zone_gradient_colors)
-                .build::<colorgrad::CatmullRomGradient>()
-                .expect("Failed to create color gradient");
-            let bars: Vec<BackgroundBar> = indices
-                .iter()
-                .map(|&original_index| {
-                    let zone_score = data_for_display[original_index];
-                    let (z_min, z_max) = cva_results.price_range.chunk_bounds(original_index);
-                    let center_price = (z_min + z_max) / 2.0;
-                    let color = to_egui_color(grad.at(zone_score as f32));
-                    let dimmed_color =
-                        color.linear_multiply(PLOT_CONFIG.background_bar_intensity_pct);
-                    BackgroundBar {
-                        x_max: zone_score,
-                        y_center: center_price,
-                        height: bar_width * 0.9,
-                        color: dimmed_color,
-                    }
-                })
-                .collect();
-
+    crate::trace_time!("Rebuild Plot Cache", 500, {
+        let (y_min, y_max) = cva_results.price_range.min_max();
+        let bar_width = (y_max - y_min) / zone_count as f64;
+        let raw_data_vec = cva_results.get_scores_ref(score_type).clone();
+        let smoothing_window = ((zone_count as f64 * 0.02).ceil() as usize).max(1) | 1;
+        let smoothed_data = smooth_data(&raw_data_vec, smoothing_window);
+        if score_type.is_signed() {
+            let bars = imbalance_bars(cva_results, &smoothed_data, bar_width);
             let cache = PlotCache {
                 cva_hash: current_hash,
                 bars,
             };
+            *slot = Some(cache.clone());
+            return cache;
+        }
+        let data_for_display = normalize_max(&smoothed_data);
+        let indices: Vec<usize> = (0..zone_count).collect();
+        let grad = colorgrad::GradientBuilder::new()
+            .html_colors(PLOT_CONFIG.zone_gradient_colors)
+            .build::<colorgrad::CatmullRomGradient>()
+            .expect("Failed to create color gradient");
+        let bars: Vec<BackgroundBar> = indices
+            .iter()
+            .map(|&original_index| {
+                let zone_score = data_for_display[original_index];
+                let (z_min, z_max) = cva_results.price_range.chunk_bounds(original_index);
+                let center_price = (z_min + z_max) / 2.0;
+                let color = to_egui_color(grad.at(zone_score as f32));
+                let dimmed_color = color.linear_multiply(PLOT_CONFIG.background_bar_intensity_pct);
+                BackgroundBar {
+                    x_max: zone_score,
+                    y_center: center_price,
+                    height: bar_width * 0.9,
+                    color: dimmed_color,
+                }
+            })
+            .collect();
 
-            self.cache = Some(cache.clone());
-            cache
+        let cache = PlotCache {
+            cva_hash: current_hash,
+            bars,
+        };
+
+        *slot = Some(cache.clone());
+        cache
+    })
+}
+
+/// Signed delta bars: length is |imbalance| relative to the largest, color is buy vs sell side.
+fn imbalance_bars(cva_results: &CVACore, deltas: &[f64], bar_width: f64) -> Vec<BackgroundBar> {
+    let max_abs = deltas.iter().fold(0.0_f64, |m, d| m.max(d.abs()));
+    deltas
+        .iter()
+        .enumerate()
+        .map(|(i, &delta)| {
+            let (z_min, z_max) = cva_results.price_range.chunk_bounds(i);
+            let x_max = if max_abs > f64::EPSILON {
+                delta.abs() / max_abs
+            } else {
+                0.0
+            };
+            let base = if delta >= 0.0 {
+                PLOT_CONFIG.color_profit
+            } else {
+                PLOT_CONFIG.color_loss
+            };
+            BackgroundBar {
+                x_max,
+                y_center: (z_min + z_max) / 2.0,
+                height: bar_width * 0.9,
+                color: base.linear_multiply(PLOT_CONFIG.background_bar_intensity_pct),
+            }
         })
-    }
+        .collect()
 }
 
 fn to_egui_color(colorgrad_color: colorgrad::Color) -> Color32 {
@@ -478,3 +1144,71 @@ fn create_y_axis(pair_name: &str) -> AxisHints<'static> {
         })
         .placement(HPlacement::Right)
 }
+
+/// Split / merge edits offered for the visible zones under `price`, with their menu labels.
+fn zone_edit_options(
+    model: &TradingModel,
+    visibility: &PlotVisibility,
+    price: Price,
+) -> Vec<(String, ZoneEdit)> {
+    let mut options = Vec::new();
+    for (kind, visible, name) in [
+        (DiffZoneKind::Sticky, visibility.sticky, &UI_TEXT.md_sticky),
+        (
+            DiffZoneKind::Support,
+            visibility.low_wicks,
+            &UI_TEXT.md_support,
+        ),
+        (
+            DiffZoneKind::Resistance,
+            visibility.high_wicks,
+            &UI_TEXT.md_resistance,
+        ),
+    ] {
+        let zones = model.zones.zones(kind);
+        let Some(idx) = zones
+            .iter()
+            .position(|z| z.contains(price))
+            .filter(|_| visible)
+        else {
+            continue;
+        };
+        let zone = &zones[idx];
+        if zone.price_bottom < price && price < zone.price_top {
+            options.push((
+                format!("{} ({})", UI_TEXT.plot_ctx_split_zone, name),
+                ZoneEdit::Split { kind, at: price },
+            ));
+        }
+        if let Some(below) = idx.checked_sub(1).map(|i| &zones[i]) {
+            options.push((
+                format!("{} ({})", UI_TEXT.plot_ctx_merge_below, name),
+                ZoneEdit::Merge {
+                    kind,
+                    lower: below.price_center,
+                    upper: zone.price_center,
+                },
+            ));
+        }
+        if let Some(above) = zones.get(idx + 1) {
+            options.push((
+                format!("{} ({})", UI_TEXT.plot_ctx_merge_above, name),
+                ZoneEdit::Merge {
+                    kind,
+                    lower: zone.price_center,
+                    upper: above.price_center,
+                },
+            ));
+        }
+    }
+    options
+}
+
+/// Scales the `min..max` range by `factor` (< 1 zooms in) keeping `anchor` at the same
+/// relative position, so the price under the cursor stays under the cursor.
+pub(crate) fn zoom_about(min: f64, max: f64, anchor: f64, factor: f64) -> (f64, f64) {
+    (
+        anchor - (anchor - min) * factor,
+        anchor + (max - anchor) * factor,
+    )
+}
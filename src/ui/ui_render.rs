@@ -1,38 +1,85 @@
 const CELL_PADDING_Y: f32 = 4.0;
+/// Idle time after the last zone config change before every pair is recalculated.
+const ZONE_RECALC_IDLE_MS: i64 = 300;
 
 use {
     crate::{
         app::{
-            App, AutoScaleY, CandleResolution, MomentumPct, Pct, Price, PriceLike, QuoteVol,
-            Selection, SortDirection, VolatilityPct,
+            Activity, App, AutoScaleY, BASE_INTERVAL, CandleResolution, CoverageBand, HotkeyAction,
+            KeyChord, Pct, PhPct, Price, PriceLike, Prob, QuoteVol, RoiPct, Selection,
+            SortDirection, TimestampMs, TradeProfile,
+        },
+        data::{BINANCE_API, EconEvent, ExternalSignal, events_in_window},
+        domain::{PairCategory, PairInterval},
+        engine::{
+            BASKET_MAX_POSITIONS, Basket, BasketLeg, BasketSkip, CorrelationMatrix, HeatCalendar,
+            HeatWeek, JobMode, PriceEvaluation, RuleBacktest, SectorStrength, StressOutcome,
+            StressReport, StressRow, StressScenario, TUNER_CONFIG, format_bytes, optimize_basket,
+            preview_station,
         },
-        domain::PairInterval,
-        engine::{JobMode, TUNER_CONFIG},
         models::{
-            DEFAULT_JOURNEY_SETTINGS, MarketState, OptimizationStrategy, ScoreType, TradeDirection,
-            TradeOpportunity,
+            DEFAULT_JOURNEY_SETTINGS, DiffZoneKind, HitTimes, JourneyReplay, MarketState,
+            OpportunityAnnotation, OpportunityScript, OptimizationStrategy, PATH_DENSITY_DENSE,
+            PATH_DENSITY_THIN, PurgeSummary, ScoreType, ScriptFields, TradeDirection,
+            TradeOpportunity, TradingModel, VolTermStructure,
         },
         ui::{
-            CandleRangePanel, DirectionColor, PLOT_CONFIG, PlotInteraction, TICKER, TunerAction,
-            UI_CONFIG, UI_TEXT, UiStyleExt, get_momentum_color, get_outcome_color,
-            render_time_tuner,
+            AdaptiveEditorAction, AnnotationDraft, CandleRangePanel, CorrelatedWhatIf,
+            CoverageTargetsResponse, CustomZoneDraft, DirectionColor, GlossaryTerm,
+            JourneyBrowserPanel, NotificationLevel, PH_SLIDER_MAX, PLOT_CONFIG, PlotContextAction,
+            PlotInteraction, PriceAlert, PriceWhatIf, ScriptAlerts, SegmentStatsPopover, TICKER,
+            TimeAxisMode, TunerAction, UI_CONFIG, UI_TEXT, UiStyleExt, apply_opacity,
+            get_momentum_color, get_outcome_color, help_link, render_adaptive_editor,
+            render_coverage_targets, render_opportunity_script_editor, render_ph_slider,
+            render_segment_stats_popover, render_time_tuner, render_trade_profile,
+            render_tuner_scoring, render_zone_settings, request_notifications,
+            request_recalc_history,
         },
-        utils::TimeUtils,
+        utils::{AppInstant, TimeUtils},
     },
-    chrono::Duration,
+    chrono::{DateTime, Duration, Utc},
     eframe::egui::{
-        Align, CentralPanel, Color32, ComboBox, Context, FontId, Frame, Grid, Layout, Order,
-        RichText, Sense, SidePanel, TopBottomPanel, Ui, Window,
+        Align, Align2, Button, CentralPanel, Color32, ComboBox, Context, DragValue, Event, FontId,
+        Frame, Grid, Id, Key, Label, Layout, Order, Rect, RichText, ScrollArea, Sense, SidePanel,
+        Stroke, StrokeKind, TextEdit, TopBottomPanel, Ui, UserAttentionType, ViewportCommand,
+        Window, pos2, vec2,
     },
     egui_extras::{Column, TableBuilder, TableRow},
     serde::{Deserialize, Serialize},
-    std::{cmp::Ordering, collections::HashMap},
+    std::{
+        cmp::Ordering,
+        collections::{BTreeSet, HashMap},
+    },
     strum::IntoEnumIterator,
+    strum_macros::EnumIter,
 };
 
 #[cfg(debug_assertions)]
 use crate::config::DF;
 
+#[cfg(target_arch = "wasm32")]
+use crate::engine::HeatCalendarBuilder;
+
+#[cfg(not(target_arch = "wasm32"))]
+use {
+    crate::{
+        data::{
+            CROSS_CHECK, CacheAction, CrossCheckReport, apply_cache_action, cross_check_candles,
+            export_analysis_report, export_csv, export_diagnostics_bundle, mock_exchange,
+            sample_indices, shared_rate_limiter,
+        },
+        engine::{build_heat_calendar, evaluate_requests},
+        models::ModelSnapshot,
+        ui::{
+            render_api_key_section, render_housekeeping_section, render_ledger_transfer_section,
+            render_profile_menu, render_signal_webhook_section,
+        },
+        utils::recent_log_lines,
+    },
+    std::{sync::mpsc, thread},
+    tokio::runtime::Runtime,
+};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub(crate) enum SortColumn {
     PairName,
@@ -40,12 +87,310 @@ pub(crate) enum SortColumn {
     #[default]
     LiveRoi,
     AnnualizedRoi,
+    Expectancy,
     AvgDuration,
     QuoteVolume24h,
     Volatility,
     Momentum,
     VariantCount,
     Score,
+    /// User score expression; keyed by the app's opportunity script, not by [`Self::row_value`]
+    Script,
+}
+
+impl SortColumn {
+    /// Numeric sort key for `row` (None = value unavailable). Pair name sorts as text.
+    fn row_value(self, row: &TradeFinderRow) -> Option<f64> {
+        let op = row.opportunity.as_ref();
+        match self {
+            Self::PairName => None,
+            Self::TargetPrice => op.map(|o| o.target_price.value()),
+            Self::LiveRoi => op.map(|o| o.live_roi(row.current_price).value()),
+            Self::AnnualizedRoi => op.map(|o| o.live_annualized_roi(row.current_price).value()),
+            Self::Expectancy => op.map(|o| o.simulation.expectancy_r()),
+            // Negated so that, as in every other column, descending puts the best (fastest) first
+            Self::AvgDuration => op.map(|o| -(o.avg_duration.value() as f64)),
+            Self::QuoteVolume24h => row.quote_volume_usd().map(|v| v.value()),
+            Self::Volatility => row.market_state.map(|m| m.volatility_pct.value()),
+            Self::Momentum => row.market_state.map(|m| m.momentum_pct.value()),
+            Self::VariantCount => op.map(|o| o.variant_count() as f64),
+            Self::Score => op.map(|o| o.calc_quality_score()),
+            Self::Script => None,
+        }
+    }
+}
+
+/// Orders present values with `cmp`; missing values go last.
+fn cmp_present_first(
+    a: Option<f64>,
+    b: Option<f64>,
+    cmp: impl Fn(f64, f64) -> Ordering,
+) -> Ordering {
+    match (a, b) {
+        (Some(x), Some(y)) => cmp(x, y),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+/// Re-sort intervals offered for the Trade Finder list, in seconds (0 = every frame).
+const TF_REFRESH_CHOICES: [u32; 5] = [0, 2, 5, 10, 30];
+/// Webhook signals shown as rows above the Trade Finder's own
+#[cfg(not(target_arch = "wasm32"))]
+const TF_EXTERNAL_ROWS: usize = 8;
+/// Quiet time after the last basket constraint edit before the basket is rebuilt.
+const BASKET_REBUILD_IDLE_MS: i64 = 300;
+
+/// How often the Trade Finder list re-sorts, and the order it is holding in the meantime.
+/// Row values (price, live ROI) always update in place; only their positions are held.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct TradeFinderRefresh {
+    /// Seconds between re-sorts (0 = every frame)
+    pub interval_secs: u32,
+    /// Hold the order while the pointer is over the list or Space is held down
+    pub hold_on_hover: bool,
+    /// Hold the order until unfrozen
+    #[serde(skip)]
+    pub frozen: bool,
+    #[serde(skip)]
+    keys: Vec<(String, Option<String>)>,
+    #[serde(skip)]
+    sort: Option<(SortColumn, SortDirection)>,
+    #[serde(skip)]
+    sorted_at: Option<AppInstant>,
+}
+
+impl Default for TradeFinderRefresh {
+    fn default() -> Self {
+        Self {
+            interval_secs: 0,
+            hold_on_hover: true,
+            frozen: false,
+            keys: Vec::new(),
+            sort: None,
+            sorted_at: None,
+        }
+    }
+}
+
+impl TradeFinderRefresh {
+    fn row_key(row: &TradeFinderRow) -> (String, Option<String>) {
+        (
+            row.pair_name.clone(),
+            row.opportunity.as_ref().map(|op| op.id.clone()),
+        )
+    }
+
+    /// True if the held order may be reused: nothing forced a re-sort, the sort key is unchanged
+    /// and the list is either held or not yet due.
+    fn can_hold(&self, sort: (SortColumn, SortDirection), held: bool, forced: bool) -> bool {
+        if forced || self.sort != Some(sort) {
+            return false;
+        }
+        let due = self
+            .sorted_at
+            .is_none_or(|at| at.elapsed().as_secs_f64() >= f64::from(self.interval_secs));
+        held || self.frozen || !due
+    }
+
+    /// Remembers the order of freshly sorted `rows`.
+    pub(crate) fn record(&mut self, rows: &[TradeFinderRow], sort: (SortColumn, SortDirection)) {
+        self.keys = rows.iter().map(Self::row_key).collect();
+        self.sort = Some(sort);
+        self.sorted_at = Some(AppInstant::now());
+    }
+
+    /// Puts sorted `rows` back into the held order. Rows that weren't in it go last, keeping
+    /// their sorted order; rows that have gone are simply absent.
+    pub(crate) fn apply(&self, rows: &mut [TradeFinderRow]) {
+        let positions: HashMap<_, _> = self
+            .keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| (key, i))
+            .collect();
+        rows.sort_by_key(|row| {
+            positions
+                .get(&Self::row_key(row))
+                .copied()
+                .unwrap_or(usize::MAX)
+        });
+    }
+}
+
+/// Status bar sections, in display order.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, EnumIter,
+)]
+pub(crate) enum StatusSegment {
+    Price,
+    ZoneSize,
+    Coverage,
+    Candles,
+    System,
+    Network,
+}
+
+impl StatusSegment {
+    /// Higher stays in the bar longer when space runs out.
+    fn priority(self) -> u8 {
+        match self {
+            Self::Price => 5,
+            Self::Network => 4,
+            Self::System => 3,
+            Self::Candles => 2,
+            Self::ZoneSize => 1,
+            Self::Coverage => 0,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Price => &UI_TEXT.sp_seg_price,
+            Self::ZoneSize => &UI_TEXT.sp_seg_zone_size,
+            Self::Coverage => &UI_TEXT.sp_seg_coverage,
+            Self::Candles => &UI_TEXT.sp_seg_candles,
+            Self::System => &UI_TEXT.sp_seg_system,
+            Self::Network => &UI_TEXT.sp_seg_network,
+        }
+    }
+}
+
+/// Width assumed for a status segment that hasn't been drawn yet.
+const STATUS_SEGMENT_DEFAULT_WIDTH: f32 = 150.0;
+/// Room kept at the right of the status bar for the "more…" and notification buttons.
+const STATUS_MORE_BUTTON_WIDTH: f32 = 100.0;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct StatusBarSettings {
+    pub hidden: BTreeSet<StatusSegment>,
+    /// Abbreviated labels
+    pub compact: bool,
+    /// Widths measured when last drawn, used to decide what fits
+    #[serde(skip)]
+    widths: HashMap<StatusSegment, f32>,
+}
+
+impl StatusBarSettings {
+    /// Splits the shown segments into those that fit in `available` px (highest priority
+    /// first, kept in display order) and the rest.
+    fn fit(&self, available: f32) -> (Vec<StatusSegment>, Vec<StatusSegment>) {
+        let mut by_priority: Vec<StatusSegment> = StatusSegment::iter()
+            .filter(|seg| !self.hidden.contains(seg))
+            .collect();
+        by_priority.sort_by_key(|seg| std::cmp::Reverse(seg.priority()));
+        let mut used = 0.0;
+        let mut inline = BTreeSet::new();
+        for seg in by_priority {
+            let width = self
+                .widths
+                .get(&seg)
+                .copied()
+                .unwrap_or(STATUS_SEGMENT_DEFAULT_WIDTH);
+            if used + width <= available {
+                used += width;
+                inline.insert(seg);
+            }
+        }
+        StatusSegment::iter()
+            .filter(|seg| !self.hidden.contains(seg))
+            .partition(|seg| inline.contains(seg))
+    }
+
+    fn label<'a>(&self, full: &'a str, short: &'a str) -> &'a str {
+        if self.compact { short } else { full }
+    }
+}
+
+/// Window width (px) below which the side panels collapse by default.
+const NARROW_LAYOUT_WIDTH: f32 = 1200.0;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub(crate) enum LayoutMode {
+    #[default]
+    Wide,
+    Narrow,
+}
+
+impl LayoutMode {
+    fn for_width(width: f32) -> Self {
+        if width < NARROW_LAYOUT_WIDTH {
+            Self::Narrow
+        } else {
+            Self::Wide
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct PanelSizes {
+    pub left_open: bool,
+    pub right_open: bool,
+    pub left_width: f32,
+    pub right_width: f32,
+}
+
+impl Default for PanelSizes {
+    fn default() -> Self {
+        Self {
+            left_open: true,
+            right_open: true,
+            left_width: 280.0,
+            right_width: 160.0,
+        }
+    }
+}
+
+/// Side panel visibility and widths, remembered separately for wide and narrow windows.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct PanelLayout {
+    pub wide: PanelSizes,
+    pub narrow: PanelSizes,
+    #[serde(skip)]
+    pub mode: LayoutMode,
+}
+
+impl Default for PanelLayout {
+    fn default() -> Self {
+        Self {
+            wide: PanelSizes::default(),
+            narrow: PanelSizes {
+                left_open: false,
+                right_open: false,
+                ..PanelSizes::default()
+            },
+            mode: LayoutMode::default(),
+        }
+    }
+}
+
+impl PanelLayout {
+    /// Picks the layout for the current window width. Returns true if it changed.
+    pub(crate) fn update(&mut self, width: f32) -> bool {
+        let mode = LayoutMode::for_width(width);
+        let changed = mode != self.mode;
+        self.mode = mode;
+        changed
+    }
+
+    pub(crate) fn current(&self) -> &PanelSizes {
+        match self.mode {
+            LayoutMode::Wide => &self.wide,
+            LayoutMode::Narrow => &self.narrow,
+        }
+    }
+
+    pub(crate) fn current_mut(&mut self) -> &mut PanelSizes {
+        match self.mode {
+            LayoutMode::Wide => &mut self.wide,
+            LayoutMode::Narrow => &mut self.narrow,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -66,53 +411,1914 @@ pub(crate) struct NavigationState {
     pub last_viewed_segment_idx: usize,
 }
 
+/// Matched journeys for the selected opportunity, replayed once per setup.
+#[derive(Debug, Default)]
+pub(crate) struct JourneyBrowserState {
+    pub key: Option<(String, u64)>,
+    pub journeys: Vec<JourneyReplay>,
+    pub selected: Option<JourneyReplay>,
+}
+
+/// Heat calendar window: the last calendar built and any run still in flight.
+#[derive(Debug, Default)]
+pub(crate) struct HeatCalendarState {
+    pub calendar: Option<HeatCalendar>,
+    #[cfg(not(target_arch = "wasm32"))]
+    pub rx: Option<mpsc::Receiver<HeatCalendar>>,
+    /// Run in progress, advanced one entry point per frame
+    #[cfg(target_arch = "wasm32")]
+    building: Option<HeatCalendarBuilder>,
+    /// Filter under backtest; seeded from the Trade Finder's, then edited independently
+    rule: Option<OpportunityScript>,
+    /// Backtest of `rule`'s filter source over the current calendar
+    backtest: Option<(String, RuleBacktest)>,
+}
+
+impl HeatCalendarState {
+    fn set_calendar(&mut self, calendar: HeatCalendar) {
+        self.calendar = Some(calendar);
+        self.backtest = None;
+    }
+}
+
+/// Basket panel: the last basket, the correlations it was built from and any pending rebuild.
+#[derive(Debug, Default)]
+pub(crate) struct BasketState {
+    pub basket: Option<Basket>,
+    correlations: Option<CorrelationMatrix>,
+    /// When the next rebuild is due; constraint edits push it back so a drag rebuilds once
+    rebuild_at_ms: Option<i64>,
+    #[cfg(not(target_arch = "wasm32"))]
+    rx: Option<mpsc::Receiver<CorrelationMatrix>>,
+}
+
+impl BasketState {
+    pub(crate) fn schedule(&mut self, delay_ms: i64) {
+        self.rebuild_at_ms = Some(TimestampMs::now().value() + delay_ms);
+    }
+
+    /// Milliseconds until the scheduled rebuild (0 once due), None with nothing scheduled.
+    fn rebuild_wait_ms(&self) -> Option<i64> {
+        self.rebuild_at_ms
+            .map(|at| (at - TimestampMs::now().value()).max(0))
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn is_computing(&self) -> bool {
+        self.rx.is_some()
+    }
+
+    /// Correlations are built synchronously in the browser.
+    #[cfg(target_arch = "wasm32")]
+    fn is_computing(&self) -> bool {
+        false
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct TradeFinderRow {
     pub pair_name: String,
+    /// 24h volume in the pair's own quote asset
     pub quote_volume_24h: QuoteVol,
+    /// Dollars per unit of the quote asset, None if it can't be priced
+    pub quote_usd_rate: Option<f64>,
     pub market_state: Option<MarketState>,
     pub opportunity: Option<TradeOpportunity>,
     pub current_price: Price,
 }
 
-impl App {
-    pub(crate) fn render_right_panel(&mut self, ctx: &Context) {
-        let frame = UI_CONFIG.side_panel_frame();
-        SidePanel::right("right_panel")
-            .min_width(160.0)
+impl TradeFinderRow {
+    /// 24h volume in dollars, so pairs quoted in BTC, ETH or fiat rank alongside USDT pairs.
+    pub(crate) fn quote_volume_usd(&self) -> Option<QuoteVol> {
+        self.quote_usd_rate
+            .map(|rate| QuoteVol::new(self.quote_volume_24h.value() * rate))
+    }
+
+    /// Expected profit in dollars per unit of the base asset bought now: the live ROI on the
+    /// current price, converted from the quote asset (None without a USD rate).
+    pub(crate) fn live_pnl_usd(&self) -> Option<f64> {
+        let op = self.opportunity.as_ref()?;
+        let rate = self.quote_usd_rate?;
+        Some(op.live_roi(self.current_price).value() * self.current_price.value() * rate)
+    }
+
+    /// What opportunity script expressions see for this row (None without an opportunity).
+    pub(crate) fn script_fields(&self) -> Option<ScriptFields<'_>> {
+        self.opportunity.as_ref().map(|op| ScriptFields {
+            op,
+            current_price: self.current_price,
+            volume_usd: self.quote_volume_usd().map(|v| v.value()),
+            market_state: self.market_state,
+        })
+    }
+
+    /// Volume label: dollars when the quote can be priced, otherwise tagged with the quote asset.
+    fn volume_label(&self) -> String {
+        match self.quote_volume_usd() {
+            Some(usd) => format!("${usd}"),
+            None => format!(
+                "{} {}",
+                self.quote_volume_24h,
+                PairInterval::get_quote(&self.pair_name).unwrap_or_default()
+            ),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl TradeFinderRow {
+    const CSV_HEADER: &'static str = "pair,price,quote_volume_24h,quote_volume_usd,volatility,momentum,direction,target,stop,live_roi,live_pnl_usd,live_aroi,excess_aroi";
+
+    fn to_csv_line(&self, profile: &TradeProfile) -> String {
+        let (vol, mom) = self
+            .market_state
+            .as_ref()
+            .map(|ms| (ms.volatility_pct.value(), ms.momentum_pct.value()))
+            .unwrap_or_default();
+        let op_cols = match &self.opportunity {
+            Some(op) => format!(
+                "{:?},{},{},{},{},{},{}",
+                op.direction,
+                op.target_price.value(),
+                op.stop_price.value(),
+                op.live_roi(self.current_price).value(),
+                self.live_pnl_usd()
+                    .map(|pnl| pnl.to_string())
+                    .unwrap_or_default(),
+                op.live_annualized_roi(self.current_price).value(),
+                op.live_excess_aroi(self.current_price, profile).value(),
+            ),
+            None => ",,,,,,".to_string(),
+        };
+        format!(
+            "{},{},{},{},{},{},{}",
+            self.pair_name,
+            self.current_price.value(),
+            self.quote_volume_24h.value(),
+            self.quote_volume_usd()
+                .map(|v| v.value().to_string())
+                .unwrap_or_default(),
+            vol,
+            mom,
+            op_cols
+        )
+    }
+}
+
+/// Checkbox selection in the trade finder, acted on as a batch.
+#[derive(Debug, Default)]
+pub(crate) struct PairMultiSelect {
+    pub pairs: BTreeSet<String>,
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BulkAction {
+    Recalc,
+    Retune,
+    ToggleWatchlist,
+    #[cfg(not(target_arch = "wasm32"))]
+    ExportCsv,
+    Purge,
+    Clear,
+}
+
+impl App {
+    pub(crate) fn render_right_panel(&mut self, ctx: &Context) {
+        let frame = UI_CONFIG.side_panel_frame();
+        let mode = self.panel_layout.mode;
+        let mut stats_request = None;
+        let response = SidePanel::right(Id::new(("right_panel", mode)))
+            .min_width(160.0)
+            .default_width(self.panel_layout.current().right_width)
+            .resizable(true)
+            .frame(frame)
+            .show(ctx, |ui| {
+                ui.add_space(5.0);
+
+                if let Some(engine) = &self.engine {
+                    if let Some(pair) = &self.selection.pair_owned() {
+                        if let Some(model) = engine.get_model(pair) {
+                            let mut nav = self.get_nav_state();
+                            let max_idx = model.segments.len().saturating_sub(1);
+                            let safe_last = nav.last_viewed_segment_idx.min(max_idx);
+                            let mut panel =
+                                CandleRangePanel::new(&model.segments, nav.current_segment_idx);
+                            let nav_action = panel.render(ui, safe_last);
+                            stats_request = panel
+                                .info_request()
+                                .map(|(idx, pos)| (pair.clone(), idx, pos));
+                            if let Some(new_idx) = nav_action {
+                                nav.current_segment_idx = new_idx;
+                                if let Some(idx) = new_idx {
+                                    nav.last_viewed_segment_idx = idx;
+                                }
+                                self.set_nav_state(nav);
+                                self.auto_scale_y = AutoScaleY(true);
+                                ctx.request_repaint();
+                            }
+                        } else {
+                            ui.label(&UI_TEXT.error_no_model);
+                        }
+                    } else {
+                        ui.label(&UI_TEXT.error_no_pair_selected);
+                    }
+                }
+            });
+        self.panel_layout.current_mut().right_width = response.response.rect.width();
+        if let Some((pair, segment_idx, pos)) = stats_request {
+            self.segment_stats = self
+                .engine
+                .as_ref()
+                .and_then(|e| e.segment_stats(&pair, segment_idx))
+                .map(|stats| SegmentStatsPopover {
+                    pair,
+                    segment_idx,
+                    pos,
+                    stats,
+                });
+        }
+        self.render_segment_stats(ctx);
+    }
+
+    /// Closes on request, or once the pair changes or the segment no longer exists.
+    fn render_segment_stats(&mut self, ctx: &Context) {
+        let Some(popover) = &self.segment_stats else {
+            return;
+        };
+        let model = self
+            .engine
+            .as_ref()
+            .filter(|_| self.selection.pair() == Some(popover.pair.as_str()))
+            .and_then(|e| e.get_model(&popover.pair));
+        let keep = model
+            .as_ref()
+            .and_then(|m| m.segments.get(popover.segment_idx))
+            .is_some_and(|segment| render_segment_stats_popover(ctx, popover, segment));
+        if !keep {
+            self.segment_stats = None;
+        }
+    }
+
+    pub(crate) fn render_journey_browser(&mut self, ctx: &Context) {
+        if !self.show_journey_browser {
+            self.journey_browser.selected = None;
+            return;
+        }
+        let Some(op) = self.selection.opportunity().cloned() else {
+            self.journey_browser = JourneyBrowserState::default();
+            return;
+        };
+
+        let key = (op.id.clone(), op.stop_price.value().to_bits());
+        if self.journey_browser.key.as_ref() != Some(&key) {
+            let journeys = self
+                .engine
+                .as_ref()
+                .map(|e| e.replay_journeys(&op))
+                .unwrap_or_default();
+            self.journey_browser = JourneyBrowserState {
+                key: Some(key),
+                journeys,
+                selected: None,
+            };
+        }
+
+        let selected_start_idx = self.journey_browser.selected.as_ref().map(|j| j.start_idx);
+        let mut clicked = None;
+        Window::new(&UI_TEXT.jb_window_title)
+            .open(&mut self.show_journey_browser)
+            .resizable(true)
+            .collapsible(false)
+            .default_width(360.0)
+            .default_height(320.0)
+            .show(ctx, |ui| {
+                let panel = JourneyBrowserPanel::new(
+                    &self.journey_browser.journeys,
+                    op.max_duration_candles(),
+                    selected_start_idx,
+                );
+                clicked = panel.render(ui).cloned();
+            });
+
+        if let Some(journey) = clicked {
+            self.jump_to_journey(&op, journey);
+        }
+    }
+
+    /// Navigates the plot to the segment containing the journey and overlays it.
+    fn jump_to_journey(&mut self, op: &TradeOpportunity, journey: JourneyReplay) {
+        let segment_idx = self
+            .engine
+            .as_ref()
+            .and_then(|e| e.get_model(&op.pair_name))
+            .and_then(|model| {
+                model
+                    .segments
+                    .iter()
+                    .position(|s| (s.start_idx..s.end_idx).contains(&journey.start_idx))
+            });
+        if let Some(idx) = segment_idx {
+            let mut nav = self.get_nav_state();
+            nav.current_segment_idx = Some(idx);
+            nav.last_viewed_segment_idx = idx;
+            self.set_nav_state(nav);
+            self.auto_scale_y = AutoScaleY(true);
+        } else {
+            #[cfg(debug_assertions)]
+            if DF.log_pathfinder {
+                log::info!(
+                    "Journey at idx {} is outside the PH segments of {}",
+                    journey.start_idx,
+                    op.pair_name
+                );
+            }
+        }
+        self.journey_browser.selected = Some(journey);
+    }
+
+    pub(crate) fn render_adaptive_editor_panel(&mut self, ctx: &Context) {
+        if !self.show_adaptive_editor {
+            if self.adaptive_editor.preview_pair.is_some() {
+                self.handle_adaptive_action(AdaptiveEditorAction::Discard);
+            }
+            return;
+        }
+        if self.adaptive_editor.draft.is_none() {
+            self.adaptive_editor.draft = Some(self.shared_config.get_global_adaptive());
+        }
+
+        let current_ph = self
+            .selection
+            .pair()
+            .and_then(|p| self.shared_config.get_ph(p));
+        let mut action = None;
+        Window::new(&UI_TEXT.ae_window_title)
+            .open(&mut self.show_adaptive_editor)
+            .resizable(false)
+            .collapsible(false)
+            .default_width(360.0)
+            .show(ctx, |ui| {
+                action = render_adaptive_editor(ui, &mut self.adaptive_editor, current_ph);
+            });
+
+        if let Some(action) = action {
+            self.handle_adaptive_action(action);
+        }
+    }
+
+    pub(crate) fn render_zone_settings_panel(&mut self, ctx: &Context) {
+        if !self.show_zone_settings {
+            return;
+        }
+        let mut config = self.shared_config.get_zone_config();
+        let strategy = self.shared_config.get_strategy();
+        let mut targets = self.shared_config.get_coverage_targets(strategy);
+        let mut changed = false;
+        let mut targets_response = CoverageTargetsResponse::default();
+        let mut exclude_anomalies = self.shared_config.get_exclude_anomalies();
+        let mut anomalies_changed = false;
+        let mut profile = self.shared_config.get_trade_profile();
+        let mut profile_changed = false;
+        let pair = self.selection.pair_owned();
+        let edit_count = pair
+            .as_deref()
+            .map_or(0, |p| self.shared_config.get_zone_edits(p).len());
+        let mut clear_edits = false;
+        let mut custom_zones = pair
+            .as_deref()
+            .map(|p| self.shared_config.get_custom_zones(p))
+            .unwrap_or_default();
+        let mut custom_zone_changes: Vec<(u64, Option<bool>)> = Vec::new();
+        Window::new(&UI_TEXT.zs_window_title)
+            .open(&mut self.show_zone_settings)
+            .resizable(false)
+            .collapsible(false)
+            .default_width(280.0)
+            .show(ctx, |ui| {
+                changed = render_zone_settings(ui, &mut config);
+                ui.separator();
+                if ui
+                    .checkbox(&mut exclude_anomalies, &UI_TEXT.zs_exclude_anomalies)
+                    .on_hover_text(&UI_TEXT.zs_exclude_anomalies_hover)
+                    .changed()
+                {
+                    anomalies_changed = true;
+                }
+                if let Some(pair) = pair.as_deref().filter(|_| edit_count > 0) {
+                    ui.horizontal(|ui| {
+                        ui.label(format!(
+                            "{} ({}): {}",
+                            UI_TEXT.zs_zone_edits, pair, edit_count
+                        ));
+                        clear_edits = ui.button(&UI_TEXT.zs_reset_zone_edits).clicked();
+                    });
+                }
+                if !custom_zones.is_empty() {
+                    ui.separator();
+                    ui.label(RichText::new(&UI_TEXT.zs_custom_zones).strong());
+                    for zone in &mut custom_zones {
+                        ui.horizontal(|ui| {
+                            if ui
+                                .checkbox(&mut zone.alert, "")
+                                .on_hover_text(&UI_TEXT.cz_alert)
+                                .changed()
+                            {
+                                custom_zone_changes.push((zone.id, Some(zone.alert)));
+                            }
+                            ui.label(format!(
+                                "{}  {} - {}",
+                                zone.label, zone.price_bottom, zone.price_top
+                            ));
+                            if ui.small_button(&UI_TEXT.zs_custom_zone_delete).clicked() {
+                                custom_zone_changes.push((zone.id, None));
+                            }
+                        });
+                    }
+                }
+                ui.separator();
+                profile_changed = render_trade_profile(ui, &mut profile);
+                ui.separator();
+                targets_response = render_coverage_targets(ui, &strategy.to_string(), &mut targets);
+            });
+
+        if let Some(pair) = pair.as_deref().filter(|_| !custom_zone_changes.is_empty()) {
+            self.record_params("custom_zone");
+            for (id, change) in custom_zone_changes {
+                match change {
+                    Some(alert) => self.shared_config.set_custom_zone_alert(pair, id, alert),
+                    None => self.shared_config.remove_custom_zone(pair, id),
+                }
+            }
+            if self.shared_config.get_custom_zone_targets() {
+                if let Some(engine) = &mut self.engine {
+                    engine.recalc_pair_from_config(pair, "CUSTOM ZONE");
+                }
+            }
+        }
+        if let Some(pair) = pair.filter(|_| clear_edits) {
+            self.record_params("zone_edit");
+            self.shared_config.clear_zone_edits(&pair);
+            if let Some(engine) = &mut self.engine {
+                engine.reclassify_zones(&pair);
+                engine.recalc_pair_from_config(&pair, "ZONE EDIT");
+            }
+        }
+        if anomalies_changed {
+            self.record_params("exclude_anomalies");
+            self.shared_config.set_exclude_anomalies(exclude_anomalies);
+        }
+        if profile_changed {
+            self.record_params("trade_profile");
+            self.shared_config.set_trade_profile(profile);
+        }
+        if anomalies_changed || profile_changed {
+            let priority_pair = self.selection.pair_owned();
+            if let Some(engine) = &mut self.engine {
+                engine.trigger_global_recalc(priority_pair);
+            }
+        }
+        if targets_response.changed {
+            self.record_params("coverage_targets");
+            self.shared_config
+                .set_coverage_targets(strategy, targets.clone());
+        }
+        if targets_response.auto_balance {
+            let model = self
+                .engine
+                .as_ref()
+                .zip(self.selection.pair())
+                .and_then(|(engine, pair)| engine.get_model(pair));
+            if let Some(model) = model {
+                config = TradingModel::auto_balance_zone_config(&model.cva, &config, &targets);
+                changed = true;
+            }
+        }
+
+        if changed {
+            self.record_params("zone_config");
+            self.shared_config.set_zone_config(config);
+            if let (Some(engine), Some(pair)) = (&mut self.engine, self.selection.pair()) {
+                engine.reclassify_zones(pair);
+            }
+            // Targets and ids depend on the zones, so every pair is re-analysed once the
+            // sliders settle
+            self.zone_recalc_since_ms = Some(TimestampMs::now().value());
+        }
+    }
+
+    /// Runs the global recalc for a zone config change after [`ZONE_RECALC_IDLE_MS`] without
+    /// further changes, so a slider drag costs one recalc.
+    pub(crate) fn drive_zone_recalc(&mut self, ctx: &Context) {
+        let Some(since) = self.zone_recalc_since_ms else {
+            return;
+        };
+        let idle_ms = TimestampMs::now().value() - since;
+        if idle_ms < ZONE_RECALC_IDLE_MS {
+            ctx.request_repaint_after(std::time::Duration::from_millis(
+                (ZONE_RECALC_IDLE_MS - idle_ms) as u64,
+            ));
+            return;
+        }
+        self.zone_recalc_since_ms = None;
+        if let Some(engine) = &mut self.engine {
+            engine.trigger_global_recalc(self.selection.pair_owned());
+        }
+    }
+
+    pub(crate) fn render_session_stats_panel(&mut self, ctx: &Context) {
+        if !self.show_session_stats {
+            return;
+        }
+        let Some(engine) = &self.engine else {
+            return;
+        };
+        let stats = &engine.session_stats;
+        let fmt_roi = |entry: &Option<(String, RoiPct)>| match entry {
+            Some((pair, roi)) => format!("{} {}", roi, pair),
+            None => UI_TEXT.ss_none.clone(),
+        };
+        Window::new(&UI_TEXT.ss_window_title)
+            .open(&mut self.show_session_stats)
+            .resizable(false)
+            .collapsible(false)
+            .default_width(260.0)
+            .show(ctx, |ui| {
+                Grid::new("ss_grid")
+                    .num_columns(2)
+                    .striped(true)
+                    .spacing([12.0, 4.0])
+                    .show(ui, |ui| {
+                        let rows = [
+                            (
+                                &UI_TEXT.ss_uptime,
+                                TimeUtils::format_duration(stats.uptime().as_millis() as i64),
+                            ),
+                            (&UI_TEXT.ss_created, stats.opportunities_created.to_string()),
+                            (&UI_TEXT.ss_expired, stats.opportunities_expired.to_string()),
+                            (&UI_TEXT.ss_recalcs, stats.recalcs.to_string()),
+                            (&UI_TEXT.ss_best_roi, fmt_roi(&stats.best_live_roi)),
+                            (&UI_TEXT.ss_worst_roi, fmt_roi(&stats.worst_live_roi)),
+                        ];
+                        for (label, value) in rows {
+                            ui.label(label);
+                            ui.label(RichText::new(value).monospace());
+                            ui.end_row();
+                        }
+                    });
+                ui.separator();
+                ui.label(RichText::new(&UI_TEXT.ss_top_churn).strong().small());
+                let churn = stats.top_churn(5);
+                if churn.is_empty() {
+                    ui.label(&UI_TEXT.ss_none);
+                }
+                for (pair, count) in churn {
+                    ui.label(RichText::new(format!("{:<10} {}", pair, count)).monospace());
+                }
+            });
+    }
+
+    pub(crate) fn render_stress_panel(&mut self, ctx: &Context) {
+        if !self.show_stress {
+            return;
+        }
+        let Some(engine) = &self.engine else {
+            return;
+        };
+        let mut run = None;
+        let mut copy = false;
+        let report = &self.stress_report;
+        Window::new(&UI_TEXT.st_window_title)
+            .open(&mut self.show_stress)
+            .resizable(true)
+            .collapsible(false)
+            .default_width(520.0)
+            .show(ctx, |ui| {
+                ui.label(
+                    RichText::new(&UI_TEXT.st_intro)
+                        .small()
+                        .color(PLOT_CONFIG.color_text_subdued),
+                );
+                ui.horizontal(|ui| {
+                    for scenario in StressScenario::iter() {
+                        let selected = report.as_ref().is_some_and(|r| r.scenario == scenario);
+                        if ui
+                            .selectable_label(selected, scenario.label())
+                            .on_hover_text(scenario.description())
+                            .clicked()
+                        {
+                            run = Some(scenario);
+                        }
+                    }
+                });
+                let Some(report) = report else {
+                    return;
+                };
+                ui.separator();
+                if report.rows.is_empty() {
+                    ui.label(&UI_TEXT.st_empty);
+                    return;
+                }
+                Grid::new("st_summary_grid")
+                    .num_columns(2)
+                    .spacing([12.0, 4.0])
+                    .show(ui, |ui| {
+                        for outcome in [
+                            StressOutcome::TargetHit,
+                            StressOutcome::StopHit,
+                            StressOutcome::Expired,
+                            StressOutcome::Open,
+                        ] {
+                            let (label, color) = stress_outcome_label(outcome);
+                            ui.label(RichText::new(label).color(color));
+                            ui.label(RichText::new(report.count(outcome).to_string()).monospace());
+                            ui.end_row();
+                        }
+                        ui.label(&UI_TEXT.st_avg_pnl);
+                        ui.label(
+                            RichText::new(format!("{:+.2}%", report.avg_pnl_pct() * 100.0))
+                                .monospace(),
+                        );
+                        ui.end_row();
+                    });
+                if ui
+                    .small_button(&UI_TEXT.st_copy)
+                    .on_hover_text(&UI_TEXT.st_copy_hover)
+                    .clicked()
+                {
+                    copy = true;
+                }
+                ui.separator();
+                ScrollArea::vertical().max_height(320.0).show(ui, |ui| {
+                    Grid::new("st_rows_grid")
+                        .num_columns(7)
+                        .striped(true)
+                        .spacing([10.0, 2.0])
+                        .show(ui, |ui| {
+                            for header in [
+                                &UI_TEXT.st_col_pair,
+                                &UI_TEXT.st_col_dir,
+                                &UI_TEXT.st_col_beta,
+                                &UI_TEXT.st_col_outcome,
+                                &UI_TEXT.st_col_after,
+                                &UI_TEXT.st_col_pnl,
+                                &UI_TEXT.st_col_worst,
+                            ] {
+                                ui.label(RichText::new(header).small().strong());
+                            }
+                            ui.end_row();
+                            for row in &report.rows {
+                                render_stress_row(ui, row);
+                            }
+                        });
+                });
+            });
+        if let Some(scenario) = run {
+            self.stress_report = Some(engine.run_stress_scenario(scenario));
+        }
+        if let Some(report) = self.stress_report.as_ref().filter(|_| copy) {
+            ctx.copy_text(stress_report_text(report));
+        }
+    }
+
+    pub(crate) fn render_basket_panel(&mut self, ctx: &Context) {
+        if !self.show_basket || self.engine.is_none() {
+            return;
+        }
+        let state = &mut self.basket;
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(matrix) = state.rx.as_ref().and_then(|rx| rx.try_recv().ok()) {
+            state.rx = None;
+            state.correlations = Some(matrix);
+        }
+        if state.basket.is_none() && state.rebuild_at_ms.is_none() {
+            state.schedule(0);
+        }
+        let computing = state.is_computing();
+        let mut suggest = false;
+        let mut edited = false;
+        let mut select = None;
+        let constraints = &mut self.basket_constraints;
+        let basket = &self.basket.basket;
+        Window::new(&UI_TEXT.bk_window_title)
+            .open(&mut self.show_basket)
+            .resizable(true)
+            .collapsible(false)
+            .default_width(520.0)
+            .show(ctx, |ui| {
+                ui.label(
+                    RichText::new(&UI_TEXT.bk_intro)
+                        .small()
+                        .color(PLOT_CONFIG.color_text_subdued),
+                );
+                Grid::new("bk_constraints_grid")
+                    .num_columns(2)
+                    .spacing([12.0, 4.0])
+                    .show(ui, |ui| {
+                        ui.label(&UI_TEXT.bk_positions)
+                            .on_hover_text(&UI_TEXT.bk_positions_hover);
+                        edited |= ui
+                            .add(
+                                DragValue::new(&mut constraints.positions)
+                                    .range(1..=BASKET_MAX_POSITIONS),
+                            )
+                            .changed();
+                        ui.end_row();
+                        ui.label(&UI_TEXT.bk_max_correlation)
+                            .on_hover_text(&UI_TEXT.bk_max_correlation_hover);
+                        edited |= ui
+                            .add(
+                                DragValue::new(&mut constraints.max_correlation)
+                                    .range(0.0..=1.0)
+                                    .speed(0.01)
+                                    .fixed_decimals(2),
+                            )
+                            .changed();
+                        ui.end_row();
+                        ui.label(&UI_TEXT.bk_risk_budget)
+                            .on_hover_text(&UI_TEXT.bk_risk_budget_hover);
+                        let mut pct = constraints.stake_budget * 100.0;
+                        if ui
+                            .add(
+                                DragValue::new(&mut pct)
+                                    .range(1.0..=100.0)
+                                    .speed(0.5)
+                                    .fixed_decimals(0)
+                                    .suffix("%"),
+                            )
+                            .changed()
+                        {
+                            constraints.stake_budget = pct / 100.0;
+                            edited = true;
+                        }
+                        ui.end_row();
+                    });
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(!computing, Button::new(&UI_TEXT.bk_suggest))
+                        .on_hover_text(&UI_TEXT.bk_suggest_hover)
+                        .clicked()
+                    {
+                        suggest = true;
+                    }
+                    if computing {
+                        ui.spinner();
+                        ui.label(
+                            RichText::new(&UI_TEXT.bk_correlating)
+                                .small()
+                                .color(PLOT_CONFIG.color_text_subdued),
+                        );
+                    }
+                });
+                let Some(basket) = basket else {
+                    return;
+                };
+                ui.separator();
+                if basket.legs.is_empty() && basket.skipped.is_empty() {
+                    ui.label(&UI_TEXT.bk_empty);
+                    return;
+                }
+                Grid::new("bk_summary_grid")
+                    .num_columns(2)
+                    .spacing([12.0, 4.0])
+                    .show(ui, |ui| {
+                        ui.label(&UI_TEXT.bk_expectancy)
+                            .on_hover_text(&UI_TEXT.bk_expectancy_hover);
+                        ui.label(
+                            RichText::new(format!("{:+.2}%", basket.expectancy * 100.0))
+                                .monospace()
+                                .color(PLOT_CONFIG.color_profit),
+                        );
+                        ui.end_row();
+                        ui.label(&UI_TEXT.bk_risk)
+                            .on_hover_text(&UI_TEXT.bk_risk_hover);
+                        ui.label(
+                            RichText::new(format!(
+                                "{:.2}% / {:.2}%",
+                                basket.risk * 100.0,
+                                basket.constraints.stake_budget * 100.0
+                            ))
+                            .monospace(),
+                        );
+                        ui.end_row();
+                        ui.label(&UI_TEXT.bk_built);
+                        ui.label(
+                            RichText::new(format!(
+                                "{} {}",
+                                TimeUtils::format_duration(
+                                    TimestampMs::now().value() - basket.built_at.value()
+                                ),
+                                UI_TEXT.bk_ago
+                            ))
+                            .monospace(),
+                        );
+                        ui.end_row();
+                    });
+                ui.separator();
+                ScrollArea::vertical().max_height(320.0).show(ui, |ui| {
+                    Grid::new("bk_legs_grid")
+                        .num_columns(6)
+                        .striped(true)
+                        .spacing([10.0, 2.0])
+                        .show(ui, |ui| {
+                            for header in [
+                                &UI_TEXT.bk_col_pair,
+                                &UI_TEXT.bk_col_dir,
+                                &UI_TEXT.bk_col_expectancy,
+                                &UI_TEXT.bk_col_success,
+                                &UI_TEXT.bk_col_stake,
+                                &UI_TEXT.bk_col_correlation,
+                            ] {
+                                ui.label(RichText::new(header).small().strong());
+                            }
+                            ui.end_row();
+                            for leg in &basket.legs {
+                                if render_basket_leg(ui, leg) {
+                                    select = Some(leg.op.clone());
+                                }
+                            }
+                        });
+                    if basket.skipped.is_empty() {
+                        return;
+                    }
+                    ui.add_space(6.0);
+                    ui.label(RichText::new(&UI_TEXT.bk_skipped).strong())
+                        .on_hover_text(&UI_TEXT.bk_skipped_hover);
+                    Grid::new("bk_skipped_grid")
+                        .num_columns(3)
+                        .striped(true)
+                        .spacing([10.0, 2.0])
+                        .show(ui, |ui| {
+                            for (op, reason) in &basket.skipped {
+                                if ui
+                                    .link(RichText::new(&op.pair_name).small())
+                                    .on_hover_text(&UI_TEXT.bk_select_hover)
+                                    .clicked()
+                                {
+                                    select = Some(op.clone());
+                                }
+                                ui.label(
+                                    RichText::new(format!("{:+.2}R", op.simulation.expectancy_r()))
+                                        .small()
+                                        .monospace(),
+                                );
+                                let reason = match reason {
+                                    BasketSkip::Correlated { with, correlation } => format!(
+                                        "{} {} ({:.2})",
+                                        UI_TEXT.bk_skip_correlated, with, correlation
+                                    ),
+                                    BasketSkip::UnknownCorrelation { with } => {
+                                        format!("{} {}", UI_TEXT.bk_skip_unknown, with)
+                                    }
+                                    BasketSkip::OverBudget => UI_TEXT.bk_skip_over_budget.clone(),
+                                };
+                                ui.label(
+                                    RichText::new(reason)
+                                        .small()
+                                        .color(PLOT_CONFIG.color_text_subdued),
+                                );
+                                ui.end_row();
+                            }
+                        });
+                });
+            });
+        if suggest {
+            self.basket.schedule(0);
+        } else if edited {
+            self.basket.schedule(BASKET_REBUILD_IDLE_MS);
+        }
+        self.drive_basket_rebuild(ctx);
+        if let Some(op) = select {
+            self.select_opportunity(op, ScrollBehavior::Center, "basket panel");
+        }
+    }
+
+    /// Rebuilds the basket once a scheduled rebuild is due. The pairwise correlations are
+    /// cached and only recomputed, in the background, when stale or missing a candidate pair.
+    fn drive_basket_rebuild(&mut self, ctx: &Context) {
+        let Some(engine) = &self.engine else {
+            return;
+        };
+        let state = &mut self.basket;
+        let Some(wait_ms) = state.rebuild_wait_ms() else {
+            return;
+        };
+        if wait_ms > 0 {
+            ctx.request_repaint_after(std::time::Duration::from_millis(wait_ms as u64));
+            return;
+        }
+        if state.is_computing() {
+            return;
+        }
+        let ops = engine.basket_candidates();
+        let mut pairs: Vec<&str> = ops.iter().map(|op| op.pair_name.as_str()).collect();
+        pairs.sort_unstable();
+        pairs.dedup();
+        if let Some(matrix) = state
+            .correlations
+            .as_ref()
+            .filter(|m| m.covers(pairs.iter().copied()))
+        {
+            state.basket = Some(optimize_basket(&ops, matrix, &self.basket_constraints));
+            state.rebuild_at_ms = None;
+            return;
+        }
+        let input = engine.correlation_input(&pairs);
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let (tx, rx) = mpsc::channel();
+            state.rx = Some(rx);
+            let repaint = ctx.clone();
+            thread::spawn(move || {
+                let _ = tx.send(CorrelationMatrix::build(&input));
+                repaint.request_repaint();
+            });
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            let matrix = CorrelationMatrix::build(&input);
+            state.basket = Some(optimize_basket(&ops, &matrix, &self.basket_constraints));
+            state.correlations = Some(matrix);
+            state.rebuild_at_ms = None;
+        }
+    }
+
+    pub(crate) fn render_sector_panel(&mut self, ctx: &Context) {
+        if !self.show_sectors {
+            return;
+        }
+        let Some(engine) = &self.engine else {
+            return;
+        };
+        if self.sector_report.as_ref().is_none_or(|r| r.is_stale()) {
+            self.sector_report = Some(engine.sector_report());
+        }
+        let mut filter = None;
+        let active = self.tf_category;
+        let report = &self.sector_report;
+        Window::new(&UI_TEXT.sec_window_title)
+            .open(&mut self.show_sectors)
+            .resizable(true)
+            .collapsible(false)
+            .default_width(560.0)
+            .show(ctx, |ui| {
+                ui.label(
+                    RichText::new(&UI_TEXT.sec_intro)
+                        .small()
+                        .color(PLOT_CONFIG.color_text_subdued),
+                );
+                let Some(report) = report else {
+                    return;
+                };
+                if let Some(top) = report.sectors.first().filter(|s| s.positive_ev > 0) {
+                    ui.label(
+                        RichText::new(format!(
+                            "{} {} ({} {})",
+                            UI_TEXT.sec_most_actionable,
+                            top.category,
+                            top.positive_ev,
+                            UI_TEXT.sec_positive_ev_targets
+                        ))
+                        .strong()
+                        .color(PLOT_CONFIG.color_info),
+                    );
+                }
+                ui.separator();
+                Grid::new("sec_grid")
+                    .num_columns(8)
+                    .striped(true)
+                    .spacing([10.0, 2.0])
+                    .show(ui, |ui| {
+                        for (header, hover) in [
+                            (&UI_TEXT.sec_col_sector, &UI_TEXT.sec_col_sector_hover),
+                            (&UI_TEXT.sec_col_pairs, &UI_TEXT.sec_col_pairs_hover),
+                            (&UI_TEXT.sec_col_momentum, &UI_TEXT.sec_col_momentum_hover),
+                            (
+                                &UI_TEXT.sec_col_volatility,
+                                &UI_TEXT.sec_col_volatility_hover,
+                            ),
+                            (&UI_TEXT.sec_col_rising, &UI_TEXT.sec_col_rising_hover),
+                            (&UI_TEXT.sec_col_targets, &UI_TEXT.sec_col_targets_hover),
+                            (
+                                &UI_TEXT.sec_col_positive_ev,
+                                &UI_TEXT.sec_col_positive_ev_hover,
+                            ),
+                            (&UI_TEXT.sec_col_best, &UI_TEXT.sec_col_best_hover),
+                        ] {
+                            ui.label(RichText::new(header).small().strong())
+                                .on_hover_text(hover);
+                        }
+                        ui.end_row();
+                        for sector in &report.sectors {
+                            let selected = active == Some(sector.category);
+                            if ui
+                                .selectable_label(selected, sector.category.label())
+                                .on_hover_text(&UI_TEXT.sec_col_sector_hover)
+                                .clicked()
+                            {
+                                filter = Some(sector.category);
+                            }
+                            render_sector_row(ui, sector);
+                        }
+                    });
+            });
+        if let Some(category) = filter {
+            self.tf_category = (self.tf_category != Some(category)).then_some(category);
+            self.update_scroll_to_selection();
+        }
+    }
+
+    pub(crate) fn render_heat_calendar(&mut self, ctx: &Context) {
+        if !self.show_heat_calendar {
+            return;
+        }
+        let state = &mut self.heat_calendar;
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(calendar) = state.rx.as_ref().and_then(|rx| rx.try_recv().ok()) {
+            state.rx = None;
+            state.set_calendar(calendar);
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        let running = state.rx.is_some();
+        #[cfg(target_arch = "wasm32")]
+        let progress = {
+            if let Some(builder) = state.building.as_mut() {
+                builder.step(1);
+            }
+            if let Some(builder) = state.building.take_if(|b| b.is_done()) {
+                state.set_calendar(builder.finish());
+            }
+            state.building.as_ref().map(HeatCalendarBuilder::progress)
+        };
+        #[cfg(target_arch = "wasm32")]
+        let running = progress.is_some();
+        let pair = self.selection.pair_owned();
+        let tf_filter = &self.opportunity_script.filter;
+        let rule = state.rule.get_or_insert_with(|| OpportunityScript {
+            filter: tf_filter.clone(),
+            ..Default::default()
+        });
+        let mut run = false;
+        Window::new(&UI_TEXT.hc_window_title)
+            .open(&mut self.show_heat_calendar)
+            .resizable(true)
+            .collapsible(false)
+            .default_width(460.0)
+            .show(ctx, |ui| {
+                ui.label(
+                    RichText::new(&UI_TEXT.hc_intro)
+                        .small()
+                        .color(PLOT_CONFIG.color_text_subdued),
+                );
+                ui.horizontal(|ui| {
+                    run = ui
+                        .add_enabled(!running && pair.is_some(), Button::new(&UI_TEXT.hc_run))
+                        .on_hover_text(&UI_TEXT.hc_run_hover)
+                        .clicked();
+                    if let Some(pair) = &pair {
+                        ui.label(RichText::new(pair).strong());
+                    }
+                    if running {
+                        ui.spinner();
+                        ui.ctx().request_repaint();
+                    }
+                    #[cfg(target_arch = "wasm32")]
+                    if let Some((done, total)) = progress {
+                        ui.label(
+                            RichText::new(format!("{}/{}", done, total))
+                                .small()
+                                .color(PLOT_CONFIG.color_text_subdued),
+                        );
+                    }
+                });
+                let Some(calendar) = &state.calendar else {
+                    return;
+                };
+                ui.separator();
+                if calendar.weeks.is_empty() {
+                    ui.label(&UI_TEXT.hc_empty);
+                    return;
+                }
+                ui.label(
+                    RichText::new(format!(
+                        "{} · {} {}",
+                        calendar.pair,
+                        calendar.weeks.len(),
+                        UI_TEXT.hc_weeks_label
+                    ))
+                    .small()
+                    .strong(),
+                );
+                let busiest = calendar
+                    .weeks
+                    .iter()
+                    .map(|w| w.opportunities)
+                    .max()
+                    .unwrap_or(0);
+                ui.horizontal_wrapped(|ui| {
+                    ui.spacing_mut().item_spacing = vec2(3.0, 3.0);
+                    for week in &calendar.weeks {
+                        render_heat_week_cell(ui, week, busiest);
+                    }
+                });
+                ui.separator();
+                let total = calendar.total();
+                Grid::new("hc_summary_grid")
+                    .num_columns(2)
+                    .spacing([12.0, 4.0])
+                    .show(ui, |ui| {
+                        for (label, value) in heat_week_lines(&total) {
+                            ui.label(label);
+                            ui.label(RichText::new(value).monospace());
+                            ui.end_row();
+                        }
+                    });
+                ui.separator();
+                render_rule_backtest(ui, calendar, rule, tf_filter, &mut state.backtest);
+            });
+        if run {
+            if let Some(input) = pair
+                .as_deref()
+                .zip(self.engine.as_ref())
+                .and_then(|(pair, engine)| engine.heat_calendar_input(pair))
+            {
+                #[cfg(not(target_arch = "wasm32"))]
+                {
+                    let (tx, rx) = mpsc::channel();
+                    self.heat_calendar.rx = Some(rx);
+                    thread::spawn(move || {
+                        let _ = tx.send(build_heat_calendar(&input));
+                    });
+                }
+                #[cfg(target_arch = "wasm32")]
+                {
+                    self.heat_calendar.building = Some(HeatCalendarBuilder::new(input));
+                }
+            }
+        }
+    }
+
+    /// Editor for a note started from the plot context menu; Enter or Save pins it.
+    pub(crate) fn render_annotation_editor(&mut self, ctx: &Context) {
+        let Some(draft) = &mut self.annotation_draft else {
+            return;
+        };
+        let mut open = true;
+        let mut save = false;
+        let mut cancel = false;
+        Window::new(&UI_TEXT.an_title)
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                ui.label(
+                    RichText::new(format!("@ {}", draft.price))
+                        .small()
+                        .color(PLOT_CONFIG.color_text_subdued),
+                );
+                let response = ui.add(
+                    TextEdit::singleline(&mut draft.text)
+                        .hint_text(&UI_TEXT.an_hint)
+                        .desired_width(300.0),
+                );
+                response.request_focus();
+                save = response.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter));
+                ui.horizontal(|ui| {
+                    save |= ui
+                        .add_enabled(!draft.text.trim().is_empty(), Button::new(&UI_TEXT.an_save))
+                        .clicked();
+                    cancel = ui.button(&UI_TEXT.an_cancel).clicked();
+                });
+            });
+        if save && !draft.text.trim().is_empty() {
+            let draft = self.annotation_draft.take().unwrap();
+            self.annotations
+                .entry(draft.opportunity_id)
+                .or_default()
+                .push(OpportunityAnnotation {
+                    price: draft.price,
+                    text: draft.text.trim().to_string(),
+                    created_at: TimestampMs::now(),
+                });
+        } else if cancel || !open {
+            self.annotation_draft = None;
+        }
+    }
+
+    pub(crate) fn render_custom_zone_editor(&mut self, ctx: &Context) {
+        let Some(draft) = &mut self.custom_zone_draft else {
+            return;
+        };
+        let mut open = true;
+        let mut save = false;
+        let mut cancel = false;
+        let zone = &mut draft.zone;
+        Window::new(&UI_TEXT.cz_title)
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                ui.add(
+                    TextEdit::singleline(&mut zone.label)
+                        .hint_text(&UI_TEXT.cz_label_hint)
+                        .desired_width(240.0),
+                );
+                ui.horizontal(|ui| {
+                    for (kind, name) in [
+                        (DiffZoneKind::Sticky, &UI_TEXT.md_sticky),
+                        (DiffZoneKind::Support, &UI_TEXT.md_support),
+                        (DiffZoneKind::Resistance, &UI_TEXT.md_resistance),
+                    ] {
+                        ui.selectable_value(&mut zone.kind, kind, name);
+                    }
+                });
+                Grid::new("custom_zone_grid")
+                    .num_columns(2)
+                    .spacing([10.0, 4.0])
+                    .show(ui, |ui| {
+                        for (label, price) in [
+                            (&UI_TEXT.cz_top, &mut zone.price_top),
+                            (&UI_TEXT.cz_bottom, &mut zone.price_bottom),
+                        ] {
+                            ui.label(label);
+                            let mut value = price.value();
+                            let speed = value.abs().max(1e-8) * 0.0005;
+                            if ui.add(DragValue::new(&mut value).speed(speed)).changed() {
+                                *price = Price::new(value.max(0.0));
+                            }
+                            ui.end_row();
+                        }
+                    });
+                ui.checkbox(&mut zone.alert, &UI_TEXT.cz_alert);
+                let valid = !zone.label.trim().is_empty() && zone.price_bottom < zone.price_top;
+                ui.horizontal(|ui| {
+                    save = ui
+                        .add_enabled(valid, Button::new(&UI_TEXT.an_save))
+                        .clicked();
+                    cancel = ui.button(&UI_TEXT.an_cancel).clicked();
+                });
+            });
+        if save {
+            let mut draft = self.custom_zone_draft.take().unwrap();
+            draft.zone.label = draft.zone.label.trim().to_string();
+            self.record_params("custom_zone");
+            self.shared_config.add_custom_zone(&draft.pair, draft.zone);
+            if self.shared_config.get_custom_zone_targets() {
+                if let Some(engine) = &mut self.engine {
+                    engine.recalc_pair_from_config(&draft.pair, "CUSTOM ZONE");
+                }
+            }
+        } else if cancel || !open {
+            self.custom_zone_draft = None;
+        }
+    }
+
+    pub(crate) fn render_price_what_if(&mut self, ctx: &Context) {
+        let Some(what_if) = &mut self.price_what_if else {
+            return;
+        };
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(results) = what_if.rx.as_ref().and_then(|rx| rx.try_recv().ok()) {
+            what_if.rx = None;
+            what_if.results = results;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        let running = what_if.rx.is_some();
+        #[cfg(target_arch = "wasm32")]
+        let running = false;
+        let live_price = self
+            .engine
+            .as_ref()
+            .and_then(|e| e.get_price(&what_if.pair));
+        let mut open = true;
+        let mut run = false;
+        Window::new(format!("{} · {}", UI_TEXT.wi_title, what_if.pair))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_width(460.0)
+            .show(ctx, |ui| {
+                ui.label(
+                    RichText::new(&UI_TEXT.wi_intro)
+                        .small()
+                        .color(PLOT_CONFIG.color_text_subdued),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(
+                        TextEdit::singleline(&mut what_if.input)
+                            .hint_text(&UI_TEXT.wi_prices_hint)
+                            .desired_width(300.0),
+                    );
+                    run = ui
+                        .add_enabled(!running, Button::new(&UI_TEXT.wi_run))
+                        .clicked();
+                    if running {
+                        ui.spinner();
+                        ui.ctx().request_repaint();
+                    }
+                });
+                if what_if.results.is_empty() {
+                    return;
+                }
+                ui.separator();
+                Grid::new("wi_results_grid")
+                    .num_columns(4)
+                    .striped(true)
+                    .spacing([12.0, 2.0])
+                    .show(ui, |ui| {
+                        for header in [
+                            &UI_TEXT.wi_col_price,
+                            &UI_TEXT.wi_col_move,
+                            &UI_TEXT.wi_col_count,
+                            &UI_TEXT.wi_col_best,
+                        ] {
+                            ui.label(RichText::new(header).small().strong());
+                        }
+                        ui.end_row();
+                        for evaluation in &what_if.results {
+                            render_price_evaluation_row(ui, evaluation, live_price);
+                        }
+                    });
+            });
+        if run || std::mem::take(&mut what_if.run_pending) {
+            let prices = what_if.prices();
+            if let Some(engine) = &self.engine {
+                #[cfg(not(target_arch = "wasm32"))]
+                {
+                    let requests = engine.price_evaluation_requests(&what_if.pair, &prices);
+                    let (tx, rx) = mpsc::channel();
+                    what_if.rx = Some(rx);
+                    thread::spawn(move || {
+                        let _ = tx.send(evaluate_requests(requests));
+                    });
+                }
+                #[cfg(target_arch = "wasm32")]
+                {
+                    what_if.results = engine.evaluate_at_prices(&what_if.pair, prices);
+                }
+            }
+        }
+        if !open {
+            self.price_what_if = None;
+        }
+    }
+
+    pub(crate) fn render_correlated_what_if(&mut self, ctx: &Context) {
+        let Some(what_if) = &mut self.correlated_what_if else {
+            return;
+        };
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(results) = what_if.rx.as_ref().and_then(|rx| rx.try_recv().ok()) {
+            what_if.rx = None;
+            what_if.results = results;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        let running = what_if.rx.is_some();
+        #[cfg(target_arch = "wasm32")]
+        let running = false;
+        let engine = self.engine.as_ref();
+        let mut open = true;
+        Window::new(format!(
+            "{} · {} @ {}",
+            UI_TEXT.cw_title, what_if.leader, what_if.price
+        ))
+        .open(&mut open)
+        .collapsible(false)
+        .resizable(true)
+        .default_width(520.0)
+        .show(ctx, |ui| {
+            ui.label(
+                RichText::new(&UI_TEXT.cw_intro)
+                    .small()
+                    .color(PLOT_CONFIG.color_text_subdued),
+            );
+            if running {
+                ui.spinner();
+                ui.ctx().request_repaint();
+                return;
+            }
+            if what_if.results.is_empty() {
+                ui.label(
+                    RichText::new(&UI_TEXT.cw_empty)
+                        .small()
+                        .color(PLOT_CONFIG.color_text_subdued),
+                );
+                return;
+            }
+            ui.separator();
+            ScrollArea::vertical().max_height(360.0).show(ui, |ui| {
+                Grid::new("cw_results_grid")
+                    .num_columns(5)
+                    .striped(true)
+                    .spacing([12.0, 2.0])
+                    .show(ui, |ui| {
+                        for header in [
+                            &UI_TEXT.cw_col_pair,
+                            &UI_TEXT.wi_col_price,
+                            &UI_TEXT.wi_col_move,
+                            &UI_TEXT.wi_col_count,
+                            &UI_TEXT.wi_col_best,
+                        ] {
+                            ui.label(RichText::new(header).small().strong());
+                        }
+                        ui.end_row();
+                        for evaluation in &what_if.results {
+                            ui.label(RichText::new(&evaluation.pair).small());
+                            let live = engine.and_then(|e| e.get_price(&evaluation.pair));
+                            render_price_evaluation_row(ui, evaluation, live);
+                        }
+                    });
+            });
+        });
+        if !open {
+            self.correlated_what_if = None;
+        }
+    }
+
+    pub(crate) fn render_engine_settings_panel(&mut self, ctx: &Context) {
+        if !self.show_engine_settings {
+            return;
+        }
+        let mut throttle = self.shared_config.get_throttle();
+        let before = throttle.clone();
+        let mut policy = self.shared_config.get_ledger_policy();
+        let policy_before = policy.clone();
+        let mut purge_requested = false;
+        let last_purge = &self.last_purge;
+        let idle = &mut self.idle_settings;
+        #[cfg(not(target_arch = "wasm32"))]
+        let api_key_form = &mut self.api_key_form;
+        #[cfg(not(target_arch = "wasm32"))]
+        let ledger_transfer = &mut self.ledger_transfer;
+        #[cfg(not(target_arch = "wasm32"))]
+        let (housekeeping_settings, housekeeping) =
+            (&mut self.housekeeping_settings, &mut self.housekeeping);
+        #[cfg(not(target_arch = "wasm32"))]
+        let (signal_webhook_settings, signal_webhook, external_signals) = (
+            &mut self.signal_webhook_settings,
+            &mut self.signal_webhook,
+            &mut self.external_signals,
+        );
+        #[cfg(not(target_arch = "wasm32"))]
+        let engine = self.engine.as_mut();
+        Window::new(&UI_TEXT.es_window_title)
+            .open(&mut self.show_engine_settings)
+            .resizable(false)
+            .collapsible(false)
+            .default_width(260.0)
+            .show(ctx, |ui| {
+                ui.checkbox(&mut throttle.paused, &UI_TEXT.es_pause)
+                    .on_hover_text(&UI_TEXT.es_pause_hover);
+                ui.separator();
+                Grid::new("es_grid")
+                    .num_columns(2)
+                    .spacing([10.0, 4.0])
+                    .show(ui, |ui| {
+                        ui.label(&UI_TEXT.es_min_interval)
+                            .on_hover_text(&UI_TEXT.es_min_interval_hover);
+                        ui.add(
+                            DragValue::new(&mut throttle.min_interval_secs)
+                                .range(0..=3600)
+                                .suffix(" s"),
+                        );
+                        ui.end_row();
+                        ui.label(&UI_TEXT.es_batch_window)
+                            .on_hover_text(&UI_TEXT.es_batch_window_hover);
+                        ui.add(
+                            DragValue::new(&mut throttle.batch_window_secs)
+                                .range(0..=600)
+                                .suffix(" s"),
+                        );
+                        ui.end_row();
+                    });
+                ui.separator();
+                ui.label(RichText::new(&UI_TEXT.es_idle_heading).strong());
+                ui.checkbox(&mut idle.enabled, &UI_TEXT.es_idle_enabled)
+                    .on_hover_text(&UI_TEXT.es_idle_enabled_hover);
+                ui.add_enabled_ui(idle.enabled, |ui| {
+                    Grid::new("es_idle_grid")
+                        .num_columns(2)
+                        .spacing([10.0, 4.0])
+                        .show(ui, |ui| {
+                            ui.label(&UI_TEXT.es_idle_after)
+                                .on_hover_text(&UI_TEXT.es_idle_after_hover);
+                            ui.add(
+                                DragValue::new(&mut idle.idle_after_secs)
+                                    .range(10..=3600)
+                                    .suffix(" s"),
+                            );
+                            ui.end_row();
+                            ui.label(&UI_TEXT.es_idle_repaint)
+                                .on_hover_text(&UI_TEXT.es_idle_repaint_hover);
+                            ui.add(
+                                DragValue::new(&mut idle.idle_repaint_ms)
+                                    .range(50..=5_000)
+                                    .suffix(" ms"),
+                            );
+                            ui.end_row();
+                            ui.label(&UI_TEXT.es_idle_background_repaint)
+                                .on_hover_text(&UI_TEXT.es_idle_background_repaint_hover);
+                            ui.add(
+                                DragValue::new(&mut idle.background_repaint_ms)
+                                    .range(100..=30_000)
+                                    .suffix(" ms"),
+                            );
+                            ui.end_row();
+                        });
+                    ui.checkbox(
+                        &mut idle.pause_recalcs_in_background,
+                        &UI_TEXT.es_idle_hold_recalcs,
+                    )
+                    .on_hover_text(&UI_TEXT.es_idle_hold_recalcs_hover);
+                });
+                ui.separator();
+                ui.label(RichText::new(&UI_TEXT.es_ledger_heading).strong());
+                Grid::new("es_ledger_grid")
+                    .num_columns(2)
+                    .spacing([10.0, 4.0])
+                    .show(ui, |ui| {
+                        ui.label(&UI_TEXT.es_prune_interval)
+                            .on_hover_text(&UI_TEXT.es_prune_interval_hover);
+                        ui.add(
+                            DragValue::new(&mut policy.prune_interval_secs)
+                                .range(1..=3600)
+                                .suffix(" s"),
+                        );
+                        ui.end_row();
+                        ui.label(&UI_TEXT.es_max_age)
+                            .on_hover_text(&UI_TEXT.es_max_age_hover);
+                        ui.add(
+                            DragValue::new(&mut policy.max_age_hours)
+                                .range(0..=24 * 90)
+                                .suffix(" h"),
+                        );
+                        ui.end_row();
+                        ui.label(&UI_TEXT.es_max_per_pair)
+                            .on_hover_text(&UI_TEXT.es_max_per_pair_hover);
+                        ui.add(DragValue::new(&mut policy.max_per_pair).range(0..=100));
+                        ui.end_row();
+                    });
+                ui.checkbox(&mut policy.keep_taken, &UI_TEXT.es_keep_taken)
+                    .on_hover_text(&UI_TEXT.es_keep_taken_hover);
+                ui.horizontal(|ui| {
+                    purge_requested = ui
+                        .button(&UI_TEXT.es_purge)
+                        .on_hover_text(&UI_TEXT.es_purge_hover)
+                        .clicked();
+                });
+                if let Some(summary) = last_purge {
+                    ui.label(
+                        RichText::new(format_purge_summary(summary))
+                            .small()
+                            .color(PLOT_CONFIG.color_text_subdued),
+                    );
+                }
+                #[cfg(not(target_arch = "wasm32"))]
+                {
+                    ui.separator();
+                    render_ledger_transfer_section(ui, ledger_transfer, engine);
+                    ui.separator();
+                    render_housekeeping_section(ui, housekeeping_settings, housekeeping);
+                    ui.separator();
+                    render_signal_webhook_section(
+                        ui,
+                        signal_webhook_settings,
+                        signal_webhook,
+                        external_signals,
+                    );
+                    ui.separator();
+                    render_api_key_section(ui, api_key_form);
+                }
+            });
+        if throttle != before {
+            self.shared_config.set_throttle(throttle);
+        }
+        if policy != policy_before {
+            self.shared_config.set_ledger_policy(policy);
+        }
+        if purge_requested {
+            if let Some(engine) = &mut self.engine {
+                let summary = engine.purge_stale_opportunities();
+                self.clear_selection_if_opportunity_removed(&summary.ids);
+                self.last_purge = Some(summary);
+            }
+        }
+    }
+
+    pub(crate) fn render_diagnostics_panel(&mut self, ctx: &Context) {
+        if !self.show_diagnostics {
+            return;
+        }
+        let Some(engine) = &self.engine else {
+            return;
+        };
+        let memory = engine.memory_report();
+        let timings = engine.job_timings();
+        let mut bundle_requested = false;
+        #[cfg(not(target_arch = "wasm32"))]
+        let mut cross_check_requested = false;
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(report) = self
+            .cross_check_rx
+            .as_ref()
+            .and_then(|rx| rx.try_recv().ok())
+        {
+            self.cross_check_rx = None;
+            self.cross_check_report = Some(report);
+        }
+        Window::new(&UI_TEXT.dg_window_title)
+            .open(&mut self.show_diagnostics)
             .resizable(false)
-            .frame(frame)
+            .collapsible(false)
+            .default_width(320.0)
             .show(ctx, |ui| {
-                ui.add_space(5.0);
-
-                if let Some(engine) = &self.engine {
-                    if let Some(pair) = &self.selection.pair_owned() {
-                        if let Some(model) = engine.get_model(pair) {
-                            let mut nav = self.get_nav_state();
-                            let max_idx = model.segments.len().saturating_sub(1);
-                            let safe_last = nav.last_viewed_segment_idx.min(max_idx);
-                            let mut panel =
-                                CandleRangePanel::new(&model.segments, nav.current_segment_idx);
-                            if let Some(new_idx) = panel.render(ui, safe_last) {
-                                nav.current_segment_idx = new_idx;
-                                if let Some(idx) = new_idx {
-                                    nav.last_viewed_segment_idx = idx;
-                                }
-                                self.set_nav_state(nav);
-                                self.auto_scale_y = AutoScaleY(true);
-                                ctx.request_repaint();
-                            }
-                        } else {
-                            ui.label(&UI_TEXT.error_no_model);
+                Grid::new("dg_memory_grid")
+                    .num_columns(2)
+                    .striped(true)
+                    .spacing([12.0, 4.0])
+                    .show(ui, |ui| {
+                        let rows = [
+                            (
+                                &UI_TEXT.dg_process_rss,
+                                memory
+                                    .process_rss
+                                    .map_or_else(|| UI_TEXT.ss_none.clone(), format_bytes),
+                            ),
+                            (
+                                &UI_TEXT.dg_timeseries,
+                                format_bytes(memory.timeseries_bytes as u64),
+                            ),
+                            (
+                                &UI_TEXT.dg_models,
+                                format!(
+                                    "{} ({})",
+                                    format_bytes(memory.models_bytes() as u64),
+                                    memory.models.len()
+                                ),
+                            ),
+                        ];
+                        for (label, value) in rows {
+                            ui.label(label);
+                            ui.label(RichText::new(value).monospace());
+                            ui.end_row();
                         }
-                    } else {
-                        ui.label(&UI_TEXT.error_no_pair_selected);
+                    });
+                ui.separator();
+                ui.label(RichText::new(&UI_TEXT.dg_largest_models).strong().small());
+                for (pair, bytes) in memory.models.iter().take(5) {
+                    ui.label(
+                        RichText::new(format!("{:<10} {}", pair, format_bytes(*bytes as u64)))
+                            .monospace(),
+                    );
+                }
+                ui.separator();
+                ui.label(RichText::new(&UI_TEXT.dg_worker_time).strong().small());
+                if timings.is_empty() {
+                    ui.label(&UI_TEXT.ss_none);
+                }
+                Grid::new("dg_jobs_grid")
+                    .num_columns(5)
+                    .striped(true)
+                    .spacing([12.0, 4.0])
+                    .show(ui, |ui| {
+                        if timings.is_empty() {
+                            return;
+                        }
+                        for header in [
+                            &UI_TEXT.dg_col_job,
+                            &UI_TEXT.dg_col_count,
+                            &UI_TEXT.dg_col_avg,
+                            &UI_TEXT.dg_col_max,
+                            &UI_TEXT.dg_col_cpu,
+                        ] {
+                            ui.label(RichText::new(header).small());
+                        }
+                        ui.end_row();
+                        for (mode, t) in &timings {
+                            ui.label(format!("{:?}", mode));
+                            ui.label(RichText::new(t.jobs.to_string()).monospace());
+                            ui.label(
+                                RichText::new(format!("{}ms", t.avg_wall().as_millis()))
+                                    .monospace(),
+                            );
+                            ui.label(
+                                RichText::new(format!("{}ms", t.max_wall.as_millis())).monospace(),
+                            );
+                            ui.label(
+                                RichText::new(format!("{:.1}s", t.cpu.as_secs_f64())).monospace(),
+                            );
+                            ui.end_row();
+                        }
+                    });
+                #[cfg(not(target_arch = "wasm32"))]
+                {
+                    ui.separator();
+                    bundle_requested = ui
+                        .button(&UI_TEXT.dg_bundle)
+                        .on_hover_text(&UI_TEXT.dg_bundle_hover)
+                        .clicked();
+                    if let Some(status) = &self.diagnostics_status {
+                        ui.label(
+                            RichText::new(status)
+                                .small()
+                                .color(PLOT_CONFIG.color_text_subdued),
+                        );
+                    }
+                    ui.separator();
+                    let running = self.cross_check_rx.is_some();
+                    ui.horizontal(|ui| {
+                        cross_check_requested = ui
+                            .add_enabled(
+                                !running && mock_exchange().is_none(),
+                                Button::new(&UI_TEXT.dg_cross_check),
+                            )
+                            .on_hover_text(format!(
+                                "{} ({} {})",
+                                UI_TEXT.dg_cross_check_hover,
+                                CROSS_CHECK.samples_per_pair,
+                                UI_TEXT.dg_cross_check_per_pair
+                            ))
+                            .clicked();
+                        if running {
+                            ui.spinner();
+                            ui.ctx().request_repaint();
+                        }
+                    });
+                    if let Some(report) = &self.cross_check_report {
+                        render_cross_check_report(ui, report);
                     }
                 }
             });
+        #[cfg(not(target_arch = "wasm32"))]
+        if bundle_requested {
+            self.create_diagnostics_bundle();
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        if cross_check_requested {
+            self.start_candle_cross_check();
+        }
+        #[cfg(target_arch = "wasm32")]
+        let _ = bundle_requested;
+    }
+
+    /// Samples cached candles from every pair and re-fetches them from Binance REST on a
+    /// background thread; the report lands in the diagnostics panel.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn start_candle_cross_check(&mut self) {
+        let Some(engine) = &self.engine else {
+            return;
+        };
+        let seed = TimeUtils::now_timestamp_ms() as u64;
+        let samples: Vec<_> = {
+            let ts = engine.timeseries.read().unwrap();
+            ts.series_data
+                .iter()
+                .map(|series| {
+                    // The newest candle may still be forming
+                    let closed = series.klines().saturating_sub(1);
+                    let candles = sample_indices(closed, CROSS_CHECK.samples_per_pair, seed)
+                        .into_iter()
+                        .map(|idx| series.get_candle(idx))
+                        .collect();
+                    (
+                        series.pair_interval.name.clone(),
+                        series.pair_interval.interval_ms,
+                        candles,
+                    )
+                })
+                .collect()
+        };
+        let (tx, rx) = mpsc::channel();
+        self.cross_check_rx = Some(rx);
+        thread::spawn(move || {
+            let rt = Runtime::new().expect("Failed to create runtime");
+            let _ = tx.send(rt.block_on(cross_check_candles(samples)));
+        });
+    }
+
+    /// Zips settings, engine state, recent logs, the selected pair's model and version info
+    /// for bug reports. Settings are the persisted app state, which holds no credentials.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn create_diagnostics_bundle(&mut self) {
+        let mut entries = vec![
+            (
+                "version.txt".to_string(),
+                format!(
+                    "zone-sniper {}\nos: {} ({})\nbuild: {}\ngenerated: {}\n",
+                    env!("CARGO_PKG_VERSION"),
+                    std::env::consts::OS,
+                    std::env::consts::ARCH,
+                    if cfg!(debug_assertions) {
+                        "debug"
+                    } else {
+                        "release"
+                    },
+                    Utc::now().to_rfc3339(),
+                ),
+            ),
+            (
+                "settings.json".to_string(),
+                serde_json::to_string_pretty(&*self).unwrap_or_else(|e| e.to_string()),
+            ),
+            (
+                "engine.txt".to_string(),
+                self.engine
+                    .as_ref()
+                    .map(|e| e.diagnostics_summary())
+                    .unwrap_or_default(),
+            ),
+            ("logs.txt".to_string(), recent_log_lines().join("\n")),
+        ];
+        if let Some(pair) = self.selection.pair_owned() {
+            if let Some(model) = self.engine.as_ref().and_then(|e| e.get_model(&pair)) {
+                entries.push((
+                    format!("model_{}.json", pair),
+                    ModelSnapshot::build(&pair, &model)
+                        .to_json()
+                        .unwrap_or_else(|e| e.to_string()),
+                ));
+            }
+        }
+        self.diagnostics_status = Some(match export_diagnostics_bundle(&entries) {
+            Ok(path) => format!("{} {}", UI_TEXT.label_exported_to, path.display()),
+            Err(e) => {
+                log::error!("Failed to write diagnostics bundle: {:#}", e);
+                format!("{}: {}", UI_TEXT.error_export_failed, e)
+            }
+        });
+    }
+
+    fn handle_adaptive_action(&mut self, action: AdaptiveEditorAction) {
+        let Some(engine) = &mut self.engine else {
+            return;
+        };
+        match action {
+            AdaptiveEditorAction::Preview(curves) => {
+                let Some(pair) = self.selection.pair_owned() else {
+                    return;
+                };
+                // Moving the preview to another pair restores the previous one
+                if let Some(old) = self
+                    .adaptive_editor
+                    .preview_pair
+                    .take()
+                    .filter(|p| p != &pair)
+                {
+                    engine.discard_adaptive_preview(&old);
+                }
+                engine.preview_adaptive(&pair, curves);
+                self.adaptive_editor.preview_pair = Some(pair);
+            }
+            AdaptiveEditorAction::Apply(curves) => {
+                self.param_history
+                    .record(self.shared_config.snapshot(), "adaptive");
+                self.adaptive_editor.preview_pair = None;
+                self.adaptive_editor.draft = Some(curves.clone());
+                engine.apply_adaptive(curves, self.selection.pair_owned());
+            }
+            AdaptiveEditorAction::Discard => {
+                if let Some(pair) = self.adaptive_editor.preview_pair.take() {
+                    engine.discard_adaptive_preview(&pair);
+                }
+                self.adaptive_editor.draft = Some(self.shared_config.get_global_adaptive());
+            }
+        }
     }
 
     pub(crate) fn render_help_panel(&mut self, ctx: &Context) {
+        let bindings = &mut self.keybindings;
+        if !self.show_debug_help {
+            // A capture left pending by closing the window would swallow the next key press
+            bindings.capturing = None;
+            return;
+        }
+        if let Some(action) = bindings.capturing {
+            let captured = ctx.input(|i| {
+                i.events.iter().find_map(|e| match e {
+                    Event::Key {
+                        key,
+                        pressed: true,
+                        modifiers,
+                        ..
+                    } => Some(KeyChord {
+                        key: *key,
+                        command: modifiers.command,
+                        shift: modifiers.shift && modifiers.command,
+                    }),
+                    _ => None,
+                })
+            });
+            match captured {
+                Some(chord) if chord.key == Key::Escape && !chord.command => {
+                    bindings.capturing = None;
+                }
+                Some(chord) => {
+                    bindings.bind(action, chord);
+                    bindings.capturing = None;
+                }
+                None => {}
+            }
+        }
         Window::new(&UI_TEXT.kbs_name_long)
             .open(&mut self.show_debug_help)
             .resizable(false)
@@ -121,29 +2327,51 @@ impl App {
             .default_width(400.0)
             .show(ctx, |ui| {
                 ui.heading("Press keys to execute commands");
+                ui.label(
+                    RichText::new(&UI_TEXT.kbs_rebind_hint)
+                        .small()
+                        .color(PLOT_CONFIG.color_text_subdued),
+                );
                 ui.add_space(10.0);
 
-                let mut _general_shortcuts = vec![
-                    ("ESC", UI_TEXT.kbs_close_all_panes.as_str()),
-                    ("K (or H)", UI_TEXT.kbs_open_close.as_str()),
-                    ("1", UI_TEXT.kbs_toolbar_shortcut_hvz.as_str()),
-                    ("2", UI_TEXT.kbs_toolbar_shortcut_low_wick.as_str()),
-                    ("3", UI_TEXT.kbs_toolbar_shortcut_high_wick.as_str()),
-                    ("4", UI_TEXT.kbs_toolbar_shortcut_histogram.as_str()),
-                    ("5", UI_TEXT.kbs_toolbar_shortcut_candles.as_str()),
-                    ("6", UI_TEXT.kbs_toolbar_shortcut_gap.as_str()),
-                    ("7", UI_TEXT.kbs_toolbar_shortcut_price_limits.as_str()),
-                    ("8", UI_TEXT.kbs_toolbar_shortcut_live_price.as_str()),
-                    ("9", UI_TEXT.kbs_toolbar_shortcut_targets.as_str()),
-                    ("T", UI_TEXT.kbs_view_time_machine.as_str()),
-                ];
-
                 Grid::new("general_shortcuts_grid")
-                    .num_columns(2)
+                    .num_columns(3)
                     .spacing([20.0, 8.0])
                     .striped(true)
                     .show(ui, |ui| {
-                        Self::render_shortcut_rows(ui, &_general_shortcuts);
+                        for action in HotkeyAction::iter() {
+                            let label = if bindings.capturing == Some(action) {
+                                UI_TEXT.kbs_capture_prompt.clone()
+                            } else {
+                                bindings.label(action)
+                            };
+                            if ui
+                                .button(RichText::new(label).monospace().strong())
+                                .on_hover_text(&UI_TEXT.kbs_rebind_hover)
+                                .clicked()
+                            {
+                                bindings.capturing = Some(action);
+                            }
+                            let conflicts = bindings.conflicts(action);
+                            if conflicts.is_empty() {
+                                ui.label(action.description());
+                            } else {
+                                ui.label(action.description()).on_hover_text(format!(
+                                    "{}: {}",
+                                    UI_TEXT.kbs_conflict,
+                                    conflicts
+                                        .iter()
+                                        .map(|a| a.description())
+                                        .collect::<Vec<_>>()
+                                        .join(", ")
+                                ));
+                                ui.colored_label(
+                                    PLOT_CONFIG.color_warning,
+                                    format!("⚠ {}", UI_TEXT.kbs_conflict),
+                                );
+                            }
+                            ui.end_row();
+                        }
                     });
 
                 #[cfg(debug_assertions)]
@@ -171,26 +2399,69 @@ impl App {
                 ui.add_space(10.0);
                 ui.separator();
                 ui.add_space(5.0);
+                if ui
+                    .add_enabled(
+                        bindings.is_customized(),
+                        Button::new(&UI_TEXT.kbs_reset_defaults),
+                    )
+                    .clicked()
+                {
+                    bindings.reset();
+                }
             });
     }
 
     pub(crate) fn render_left_panel(&mut self, ctx: &Context) {
         let frame = UI_CONFIG.side_panel_frame();
 
-        SidePanel::left("left_panel")
+        let mode = self.panel_layout.mode;
+        let response = SidePanel::left(Id::new(("left_panel", mode)))
             .min_width(280.0)
-            .resizable(false)
+            .default_width(self.panel_layout.current().left_width)
+            .resizable(true)
             .frame(frame)
             .show(ctx, |ui| {
                 if let Some(pair) = self.selection.pair_owned() {
+                    self.station_preview.sync_pair(&pair);
                     if let Some(action) = render_time_tuner(
                         ui,
                         &TUNER_CONFIG,
                         self.shared_config.get_station_opt(Some(pair.clone())),
-                        Some(pair),
+                        Some(pair.clone()),
+                        &mut self.station_preview,
+                        &self.shared_config.get_station_stats(&pair),
+                        &self.shared_config.get_station_notes(&pair),
                     ) {
                         self.handle_tuner_action(action);
                     }
+                    self.drive_station_preview(ui.ctx(), &pair);
+                    let mut weights = self.shared_config.get_tuner_weights();
+                    let scan = self.shared_config.get_tuner_scan(&pair);
+                    if render_tuner_scoring(ui, &mut weights, scan.as_ref()) {
+                        self.record_params("tuner_weights");
+                        self.shared_config.set_tuner_weights(weights);
+                    }
+                    let preview = self
+                        .ph_slider
+                        .pending_ph()
+                        .zip(self.engine.as_mut())
+                        .and_then(|(ph, engine)| engine.preview_ph(&pair, ph));
+                    if let Some(ph) = render_ph_slider(
+                        ui,
+                        &mut self.ph_slider,
+                        &pair,
+                        self.shared_config.get_ph(&pair),
+                        preview,
+                        self.engine
+                            .as_ref()
+                            .and_then(|e| e.get_min_viable_ph(&pair)),
+                    ) {
+                        self.commit_manual_ph(&pair, ph);
+                    }
+                    // Every commit of one drag joins the undo step its first commit opened
+                    if self.ph_slider.is_dragging() {
+                        self.param_history.extend("ph");
+                    }
                 }
                 ui.add_space(10.0);
                 ui.separator();
@@ -198,6 +2469,7 @@ impl App {
                 ui.separator();
                 self.render_trade_finder_content(ui);
             });
+        self.panel_layout.current_mut().left_width = response.response.rect.width();
     }
 
     pub(crate) fn render_top_panel(&mut self, ctx: &Context) {
@@ -209,6 +2481,21 @@ impl App {
             .resizable(false)
             .show(ctx, |ui| {
                 ui.horizontal(|ui| {
+                    #[cfg(not(target_arch = "wasm32"))]
+                    {
+                        render_profile_menu(ui, &mut self.profiles);
+                        ui.separator();
+                    }
+                    let panels = self.panel_layout.current_mut();
+                    if ui
+                        .selectable_label(panels.left_open, &UI_TEXT.tb_panels)
+                        .on_hover_text(&UI_TEXT.tb_panels_hover)
+                        .clicked()
+                    {
+                        panels.left_open = !panels.left_open;
+                        panels.right_open = panels.left_open;
+                    }
+                    ui.separator();
                     ui.label(
                         RichText::new(&UI_TEXT.tb_time)
                             .size(16.0)
@@ -221,21 +2508,67 @@ impl App {
                     ui.separator();
                     self.render_optimization_strategy(ui);
                     ui.checkbox(&mut self.plot_visibility.sticky, &UI_TEXT.tb_sticky);
+                    help_link(ui, GlossaryTerm::StickyZone);
                     ui.checkbox(&mut self.plot_visibility.low_wicks, &UI_TEXT.tb_low_wicks);
                     ui.checkbox(&mut self.plot_visibility.high_wicks, &UI_TEXT.tb_high_wicks);
                     ui.checkbox(
                         &mut self.plot_visibility.background,
                         &UI_TEXT.tb_volume_hist,
                     );
+                    ui.add_enabled_ui(self.plot_visibility.background, |ui| {
+                        score_type_combo(
+                            ui,
+                            "Background score type",
+                            &mut self.background_score_type,
+                        );
+                        let mut split = self.compare_score_type.is_some();
+                        if ui
+                            .checkbox(&mut split, &UI_TEXT.tb_split_compare)
+                            .on_hover_text(&UI_TEXT.tb_split_compare_hover)
+                            .changed()
+                        {
+                            self.compare_score_type = split.then_some(ScoreType::CloseOnlyTVW);
+                        }
+                        if let Some(compare) = &mut self.compare_score_type {
+                            score_type_combo(ui, "Compare score type", compare);
+                        }
+                    });
                     ui.checkbox(&mut self.plot_visibility.candles, &UI_TEXT.tb_candles);
+                    ui.checkbox(
+                        &mut self.plot_visibility.custom_zones,
+                        &UI_TEXT.tb_custom_zones,
+                    )
+                    .on_hover_text(&UI_TEXT.tb_custom_zones_hover);
+                    ui.checkbox(&mut self.plot_visibility.anomalies, &UI_TEXT.tb_anomalies)
+                        .on_hover_text(&UI_TEXT.tb_anomalies_hover);
+                    #[cfg(not(target_arch = "wasm32"))]
+                    ui.checkbox(
+                        &mut self.plot_visibility.external_signals,
+                        &UI_TEXT.tb_external_signals,
+                    )
+                    .on_hover_text(&UI_TEXT.tb_external_signals_hover);
                     ui.separator();
                     ui.checkbox(&mut self.plot_visibility.separators, &UI_TEXT.tb_gaps);
+                    let mut true_time = self.time_axis_mode == TimeAxisMode::TrueTime;
+                    if ui
+                        .checkbox(&mut true_time, &UI_TEXT.tb_true_time)
+                        .on_hover_text(&UI_TEXT.tb_true_time_hover)
+                        .changed()
+                    {
+                        self.time_axis_mode = if true_time {
+                            TimeAxisMode::TrueTime
+                        } else {
+                            TimeAxisMode::Compressed
+                        };
+                    }
                     ui.checkbox(
                         &mut self.plot_visibility.horizon_lines,
                         &UI_TEXT.tb_price_limits,
                     );
                     ui.checkbox(&mut self.plot_visibility.price_line, &UI_TEXT.tb_live_price);
                     ui.checkbox(&mut self.plot_visibility.opportunities, &UI_TEXT.tb_targets);
+                    ui.checkbox(&mut self.plot_visibility.timeline, &UI_TEXT.tb_timeline)
+                        .on_hover_text(&UI_TEXT.tb_timeline_hover);
                     if self.auto_scale_y.value() {
                         ui.label(
                             RichText::new(&UI_TEXT.tb_y_locked)
@@ -262,9 +2595,14 @@ impl App {
             .resizable(false)
             .show(ctx, |ui| {
                 if let Some(engine) = &self.engine {
-                    self.ticker_state.update_data(engine);
+                    self.ticker_state
+                        .update_data(engine, self.ticker_promote_improved);
                 }
-                if let Some(pair) = self.ticker_state.render(ui) {
+                let animate = self.idle.activity() == Activity::Active;
+                if let Some(pair) =
+                    self.ticker_state
+                        .render(ui, animate, &mut self.ticker_promote_improved)
+                {
                     #[cfg(not(target_arch = "wasm32"))]
                     {
                         self.jump_to_pair(pair);
@@ -279,6 +2617,10 @@ impl App {
 
     pub(crate) fn render_central_panel(&mut self, ctx: &Context) {
         let central_panel_frame = UI_CONFIG.central_panel_frame();
+        let mut context_action = None;
+        let mut apply_ph = None;
+        let mut timeline_pick = None;
+        let mut zone_pick = None;
 
         CentralPanel::default()
             .frame(central_panel_frame)
@@ -308,26 +2650,86 @@ impl App {
                 let current_price = engine.get_price(&pair);
                 let (is_calculating, last_error) = engine.get_pair_status(&pair);
                 if let Some(err_msg) = last_error {
-                    let body = if err_msg.contains("Insufficient data") {
+                    let insufficient_data = err_msg.contains("Insufficient data");
+                    let mut body = if insufficient_data {
                         format!("{}\n\n{}", UI_TEXT.error_insufficient_data_body, err_msg)
                     } else {
                         err_msg.to_string()
                     };
+                    if let Some((failures, retry_secs)) =
+                        engine.get_pair_backoff(&pair).filter(|(n, _)| *n > 1)
+                    {
+                        body = format!(
+                            "{}\n\n{} {} ({} {}s)",
+                            body,
+                            failures,
+                            UI_TEXT.error_failures_in_row,
+                            UI_TEXT.error_retry_in,
+                            retry_secs
+                        );
+                    }
                     render_fullscreen_message(ui, &UI_TEXT.error_analysis_failed, &body, true);
+                    let viable_ph = engine
+                        .get_min_viable_ph(&pair)
+                        .filter(|ph| insufficient_data && ph.value() <= PH_SLIDER_MAX);
+                    if let Some(ph) = viable_ph {
+                        ui.vertical_centered(|ui| {
+                            ui.add_space(12.0);
+                            let label = format!("{} {}", UI_TEXT.error_apply_viable_ph, ph);
+                            if ui.button(label).clicked() {
+                                apply_ph = Some((pair.clone(), ph));
+                            }
+                        });
+                    }
                 } else if let Some(model) = engine.get_model(&pair) {
+                    let pair_ops = engine.get_pair_opportunities(&pair);
+                    if self.plot_visibility.timeline && !pair_ops.is_empty() {
+                        let selected_id = self.selection.opportunity().map(|op| op.id.as_str());
+                        TopBottomPanel::bottom("opportunity_timeline")
+                            .resizable(false)
+                            .show_inside(ui, |ui| {
+                                timeline_pick =
+                                    render_opportunity_timeline(ui, &pair_ops, selected_id);
+                            });
+                    }
+                    let custom_zones = self.shared_config.get_custom_zones(&pair);
+                    let external_signals: Vec<ExternalSignal> = self
+                        .external_signals
+                        .iter()
+                        .filter(|s| s.pair == pair)
+                        .cloned()
+                        .collect();
                     let interaction = self.plot_view.show_my_plot(
                         ui,
                         &model.cva,
                         &model,
                         current_price,
-                        ScoreType::FullCandleTVW,
+                        self.background_score_type,
+                        self.compare_score_type,
                         &self.plot_visibility,
                         engine,
                         self.candle_resolution,
+                        self.time_axis_mode,
                         nav_state.current_segment_idx,
                         self.auto_scale_y.value(),
                         self.selection.opportunity().cloned(),
+                        self.journey_browser.selected.as_ref(),
+                        self.price_marks.get(&pair),
+                        &custom_zones,
+                        self.selection
+                            .opportunity()
+                            .and_then(|op| self.annotations.get(&op.id))
+                            .map_or(&[], Vec::as_slice),
+                        &self.econ_events,
+                        &external_signals,
+                        self.station_preview
+                            .ghost(&pair)
+                            .and_then(|p| p.model.as_deref().ok()),
+                        self.indicator_scripts.get(&pair).map_or(&[], Vec::as_slice),
                     );
+                    for msg in self.plot_view.take_indicator_errors() {
+                        self.notifications.push(NotificationLevel::Error, msg);
+                    }
 
                     match interaction {
                         PlotInteraction::UserInteracted => {
@@ -338,6 +2740,21 @@ impl App {
                             // User requested reset. Re-enable auto-scale.
                             self.auto_scale_y = AutoScaleY(true);
                         }
+                        PlotInteraction::SnapToZone(zone) => {
+                            self.auto_scale_y = AutoScaleY(false);
+                            self.plot_view.frame_zone(&zone);
+                            zone_pick = pair_ops
+                                .iter()
+                                .copied()
+                                .filter(|op| zone.contains(Price::new(op.target_price.value())))
+                                .max_by(|a, b| {
+                                    a.calc_quality_score().total_cmp(&b.calc_quality_score())
+                                })
+                                .cloned();
+                        }
+                        PlotInteraction::Context(action) => {
+                            context_action = Some((pair.clone(), current_price, action));
+                        }
                         PlotInteraction::None => {}
                     }
                 } else if is_calculating {
@@ -363,6 +2780,162 @@ impl App {
                     );
                 }
             });
+        if let Some((pair, current_price, action)) = context_action {
+            self.handle_plot_context_action(ctx, &pair, current_price, action);
+        }
+        if let Some(op) = timeline_pick {
+            self.select_opportunity(op, ScrollBehavior::Center, "opportunity timeline");
+        }
+        if let Some(op) = zone_pick {
+            self.select_opportunity(op, ScrollBehavior::Center, "zone double-click");
+        }
+        if let Some((pair, ph)) = apply_ph {
+            self.commit_manual_ph(&pair, ph);
+        }
+    }
+
+    fn handle_plot_context_action(
+        &mut self,
+        ctx: &Context,
+        pair: &str,
+        current_price: Option<Price>,
+        action: PlotContextAction,
+    ) {
+        match action {
+            PlotContextAction::SetAlert(price) => {
+                self.price_marks
+                    .entry(pair.to_string())
+                    .or_default()
+                    .alerts
+                    .push(PriceAlert::new(price, current_price));
+            }
+            PlotContextAction::AddLine(price) => {
+                self.price_marks
+                    .entry(pair.to_string())
+                    .or_default()
+                    .lines
+                    .push(price);
+            }
+            PlotContextAction::CenterOn(price) => {
+                self.auto_scale_y = AutoScaleY(false);
+                self.plot_view.center_on(price);
+            }
+            PlotContextAction::CopyPrice(price) => {
+                ctx.copy_text(format!("{}", price.value()));
+            }
+            PlotContextAction::SimulatePrice(price) => {
+                self.price_what_if = Some(PriceWhatIf::simulate(pair, price));
+            }
+            PlotContextAction::SimulateCorrelated(price) => {
+                if let Some(engine) = &self.engine {
+                    let mut what_if = CorrelatedWhatIf {
+                        leader: pair.to_string(),
+                        price,
+                        results: Vec::new(),
+                        #[cfg(not(target_arch = "wasm32"))]
+                        rx: None,
+                    };
+                    #[cfg(not(target_arch = "wasm32"))]
+                    {
+                        let requests = engine.correlated_move_requests(pair, price);
+                        let (tx, rx) = mpsc::channel();
+                        what_if.rx = Some(rx);
+                        thread::spawn(move || {
+                            let _ = tx.send(evaluate_requests(requests));
+                        });
+                    }
+                    #[cfg(target_arch = "wasm32")]
+                    {
+                        what_if.results = engine.simulate_correlated_move(pair, price);
+                    }
+                    self.correlated_what_if = Some(what_if);
+                }
+            }
+            PlotContextAction::Annotate(price) => {
+                if let Some(op) = self.selection.opportunity() {
+                    self.annotation_draft = Some(AnnotationDraft {
+                        opportunity_id: op.id.clone(),
+                        price,
+                        text: String::new(),
+                    });
+                }
+            }
+            PlotContextAction::ClearMarks => {
+                self.price_marks.remove(pair);
+            }
+            PlotContextAction::AddCustomZone(price) => {
+                self.custom_zone_draft = Some(CustomZoneDraft::new(pair, price, current_price));
+            }
+            PlotContextAction::WhatIfPrices(price) => {
+                self.price_what_if = Some(PriceWhatIf::new(pair, price, current_price));
+            }
+            PlotContextAction::EditZone(edit) => {
+                self.record_params("zone_edit");
+                self.shared_config.push_zone_edit(pair, edit);
+                if let Some(engine) = &mut self.engine {
+                    engine.reclassify_zones(pair);
+                    engine.recalc_pair_from_config(pair, "ZONE EDIT");
+                }
+            }
+        }
+    }
+
+    /// Marks price alerts whose level the live price has crossed; fired alerts stay on the
+    /// plot (dimmed) until cleared. Also alerts on opportunities that start matching the
+    /// opportunity script filter, when that is switched on.
+    pub(crate) fn check_price_alerts(&mut self, ctx: &Context) {
+        let Some(engine) = &self.engine else {
+            return;
+        };
+        let mut hits = Vec::new();
+        for (pair, zones) in self.shared_config.get_all_custom_zones() {
+            let Some(price) = engine.get_price(&pair) else {
+                continue;
+            };
+            for zone in zones.iter().filter(|z| z.alert) {
+                let inside = zone.contains(price);
+                let was_inside = self
+                    .custom_zone_inside
+                    .insert((pair.clone(), zone.id), inside);
+                if inside && was_inside == Some(false) {
+                    hits.push(format!(
+                        "{} {} {} @ {}",
+                        UI_TEXT.nc_zone_entered, pair, zone.label, price
+                    ));
+                }
+            }
+        }
+        for (pair, marks) in self.price_marks.iter_mut() {
+            let Some(price) = engine.get_price(pair) else {
+                continue;
+            };
+            for alert in marks.alerts.iter_mut() {
+                if alert.check(price) {
+                    hits.push(format!(
+                        "{} {} @ {}",
+                        UI_TEXT.nc_price_alert, pair, alert.price
+                    ));
+                }
+            }
+        }
+        if !self.opportunity_script.alert {
+            self.script_alerts = ScriptAlerts::default();
+        } else if self.script_alerts.is_due(&self.opportunity_script) {
+            let rows = engine.get_trade_finder_rows();
+            for hit in self.script_alerts.check(&self.opportunity_script, &rows) {
+                hits.push(format!("{} {}", UI_TEXT.nc_filter_matched, hit));
+            }
+        }
+        if hits.is_empty() {
+            return;
+        }
+        for hit in hits {
+            log::info!("{}", hit);
+            self.notifications.push(NotificationLevel::Warning, hit);
+        }
+        ctx.send_viewport_cmd(ViewportCommand::RequestUserAttention(
+            UserAttentionType::Informational,
+        ));
     }
 
     pub(crate) fn render_status_panel(&mut self, ctx: &Context) {
@@ -373,19 +2946,125 @@ impl App {
             .show(ctx, |ui| {
                 ui.vertical(|ui| {
                     ui.horizontal(|ui| {
-                        self.render_price(ui);
-                        self.render_status_zone_info(ui);
-                        ui.separator();
-                        self.render_status_coverage(ui);
-                        self.render_status_candles(ui);
-                        self.render_status_system(ui);
-                        ui.separator();
-                        self.render_status_network(ui);
+                        let (inline, overflow) = self
+                            .status_bar
+                            .fit(ui.available_width() - STATUS_MORE_BUTTON_WIDTH);
+                        let mut measured = Vec::new();
+                        for (i, seg) in inline.iter().enumerate() {
+                            if i > 0 {
+                                ui.separator();
+                            }
+                            let width = ui
+                                .horizontal(|ui| self.render_status_segment(ui, *seg))
+                                .response
+                                .rect
+                                .width();
+                            measured.push((*seg, width));
+                        }
+                        let mut settings = self.status_bar.clone();
+                        ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                            ui.menu_button(&UI_TEXT.sp_more, |ui| {
+                                for seg in &overflow {
+                                    let width = ui
+                                        .horizontal(|ui| self.render_status_segment(ui, *seg))
+                                        .response
+                                        .rect
+                                        .width();
+                                    measured.push((*seg, width));
+                                }
+                                if !overflow.is_empty() {
+                                    ui.separator();
+                                }
+                                ui.label(RichText::new(&UI_TEXT.sp_seg_heading).small().strong());
+                                for seg in StatusSegment::iter() {
+                                    let mut shown = !settings.hidden.contains(&seg);
+                                    if ui.checkbox(&mut shown, seg.label()).changed() {
+                                        if shown {
+                                            settings.hidden.remove(&seg);
+                                        } else {
+                                            settings.hidden.insert(seg);
+                                        }
+                                    }
+                                }
+                                ui.separator();
+                                ui.checkbox(&mut settings.compact, &UI_TEXT.sp_compact)
+                                    .on_hover_text(&UI_TEXT.sp_compact_hover);
+                            })
+                            .response
+                            .on_hover_text(&UI_TEXT.sp_more_hover);
+                            let unread = self.notifications.unread();
+                            let bell = if unread > 0 {
+                                RichText::new(format!("{} {}", UI_TEXT.nc_button, unread))
+                                    .color(PLOT_CONFIG.color_warning)
+                            } else {
+                                RichText::new(&UI_TEXT.nc_button)
+                            };
+                            if ui
+                                .small_button(bell)
+                                .on_hover_text(&UI_TEXT.nc_hover)
+                                .clicked()
+                            {
+                                request_notifications(ui);
+                            }
+                        });
+                        if settings.compact != self.status_bar.compact {
+                            // Label widths change with the abbreviation setting
+                            settings.widths.clear();
+                        }
+                        self.status_bar = settings;
+                        self.status_bar.widths.extend(measured);
                     });
                 });
             });
     }
 
+    fn render_status_segment(&self, ui: &mut Ui, seg: StatusSegment) {
+        match seg {
+            StatusSegment::Price => self.render_price(ui),
+            StatusSegment::ZoneSize => self.render_status_zone_info(ui),
+            StatusSegment::Coverage => self.render_status_coverage(ui),
+            StatusSegment::Candles => self.render_status_candles(ui),
+            StatusSegment::System => self.render_status_system(ui),
+            StatusSegment::Network => self.render_status_network(ui),
+        }
+    }
+
+    /// Sector filter and grouping combo; true when either changed.
+    fn render_tf_category_filter(&mut self, ui: &mut Ui) -> bool {
+        let mut changed = false;
+        let selected = match self.tf_category {
+            Some(category) => RichText::new(category.label()).color(PLOT_CONFIG.color_info),
+            None => RichText::new(&UI_TEXT.tf_category_all),
+        };
+        ComboBox::from_id_salt("tf_category")
+            .width(80.0)
+            .selected_text(selected)
+            .show_ui(ui, |ui| {
+                changed |= ui
+                    .selectable_value(&mut self.tf_category, None, &UI_TEXT.tf_category_all)
+                    .changed();
+                for category in PairCategory::iter() {
+                    changed |= ui
+                        .selectable_value(
+                            &mut self.tf_category,
+                            Some(category),
+                            category.to_string(),
+                        )
+                        .changed();
+                }
+                ui.separator();
+                changed |= ui
+                    .checkbox(
+                        &mut self.tf_group_by_category,
+                        &UI_TEXT.tf_group_by_category,
+                    )
+                    .changed();
+            })
+            .response
+            .on_hover_text(&UI_TEXT.tf_category_hover);
+        changed
+    }
+
     fn render_trade_finder_filters(&mut self, ui: &mut Ui, count: usize) -> bool {
         // Renders the Header, Scope, and Direction controls
         let mut filter_changed = false;
@@ -436,8 +3115,91 @@ impl App {
                 filter_changed = true;
                 self.update_scroll_to_selection();
             }
-            ui.add_space(10.0);
+            ui.separator();
+            if ui
+                .selectable_label(self.tf_beats_benchmark, &UI_TEXT.tf_beats_benchmark)
+                .on_hover_text(&UI_TEXT.tf_beats_benchmark_hover)
+                .clicked()
+            {
+                self.tf_beats_benchmark = !self.tf_beats_benchmark;
+                filter_changed = true;
+                self.update_scroll_to_selection();
+            }
+            if ui
+                .selectable_label(self.tf_positive_expectancy, &UI_TEXT.tf_positive_expectancy)
+                .on_hover_text(&UI_TEXT.tf_positive_expectancy_hover)
+                .clicked()
+            {
+                self.tf_positive_expectancy = !self.tf_positive_expectancy;
+                filter_changed = true;
+                self.update_scroll_to_selection();
+            }
+            if self.render_tf_category_filter(ui) {
+                filter_changed = true;
+                self.update_scroll_to_selection();
+            }
+            let script_label = if self.opportunity_script.is_filtering() {
+                RichText::new(&UI_TEXT.tf_script).color(PLOT_CONFIG.color_info)
+            } else {
+                RichText::new(&UI_TEXT.tf_script)
+            };
+            if ui
+                .selectable_label(self.opportunity_script.show_editor, script_label)
+                .on_hover_text(&UI_TEXT.tf_script_hover)
+                .clicked()
+            {
+                self.opportunity_script.show_editor = !self.opportunity_script.show_editor;
+            }
+            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                if ui
+                    .selectable_label(self.tf_refresh.frozen, &UI_TEXT.tf_freeze)
+                    .on_hover_text(&UI_TEXT.tf_freeze_hover)
+                    .clicked()
+                {
+                    self.tf_refresh.frozen = !self.tf_refresh.frozen;
+                }
+                ComboBox::from_id_salt("tf_refresh_interval")
+                    .width(50.0)
+                    .selected_text(refresh_interval_label(self.tf_refresh.interval_secs))
+                    .show_ui(ui, |ui| {
+                        for secs in TF_REFRESH_CHOICES {
+                            ui.selectable_value(
+                                &mut self.tf_refresh.interval_secs,
+                                secs,
+                                refresh_interval_label(secs),
+                            );
+                        }
+                        ui.separator();
+                        ui.checkbox(
+                            &mut self.tf_refresh.hold_on_hover,
+                            &UI_TEXT.tf_hold_on_hover,
+                        );
+                    })
+                    .response
+                    .on_hover_text(&UI_TEXT.tf_refresh_hover);
+                if ui
+                    .selectable_label(self.tf_compact, &UI_TEXT.tf_compact)
+                    .on_hover_text(&UI_TEXT.tf_compact_hover)
+                    .clicked()
+                {
+                    self.tf_compact = !self.tf_compact;
+                    self.update_scroll_to_selection();
+                }
+                help_link(ui, GlossaryTerm::Grade);
+                help_link(ui, GlossaryTerm::Aroi);
+            });
         });
+        if self.opportunity_script.show_editor
+            && render_opportunity_script_editor(
+                ui,
+                &mut self.opportunity_script,
+                &mut self.tf_sort_col,
+                &mut self.tf_sort_dir,
+            )
+        {
+            filter_changed = true;
+            self.update_scroll_to_selection();
+        }
         ui.separator();
 
         filter_changed
@@ -523,10 +3285,26 @@ impl App {
         }
 
         self.sort_trade_finder_rows(&mut rows);
+        let sort = (self.tf_sort_col, self.tf_sort_dir);
+        let held = self.tf_refresh.hold_on_hover
+            && (ui.rect_contains_pointer(ui.available_rect_before_wrap())
+                || (ui.input(|i| i.key_down(Key::Space)) && !ui.ctx().wants_keyboard_input()));
+        if self.tf_refresh.can_hold(sort, held, filter_changed) {
+            self.tf_refresh.apply(&mut rows);
+        } else {
+            self.tf_refresh.record(&rows, sort);
+        }
+        self.tf_hovered_pair = None;
         if rows.is_empty() {
             ui.centered_and_justified(|ui| ui.label("Loading Market Data..."));
             return;
         }
+        if let Some(action) = self.render_tf_bulk_actions(ui) {
+            self.apply_bulk_action(action);
+        }
+        if let Some(delta) = self.tf_row_step.take() {
+            self.step_tf_row(&rows, delta);
+        }
 
         let mut target_index = None;
         if let Some(target) = &self.scroll_target {
@@ -537,6 +3315,11 @@ impl App {
                 NavigationTarget::Pair(name) => r.pair_name == *name,
             });
         }
+        let external = self.tf_external_signals();
+        // External rows sit above the engine's
+        let target_index = target_index.map(|i| i + external.len());
+        let now_ms = TimeUtils::now_timestamp_ms();
+        let mut jump = None;
         let available_height = ui.available_height();
         let mut sort_changed = false;
         ui.scope(|ui| {
@@ -544,29 +3327,67 @@ impl App {
             visuals.selection.bg_fill = PLOT_CONFIG.color_tf_selected;
             visuals.faint_bg_color = Color32::from_white_alpha(15);
 
+            let compact = self.tf_compact;
+            let columns: &[Column] = if compact {
+                &[
+                    Column::exact(120.0).clip(true), // Pair + direction
+                    Column::exact(60.0).clip(true),  // ROI
+                    Column::exact(60.0).clip(true),  // AROI
+                    Column::exact(50.0).clip(true),  // Expectancy
+                    Column::exact(80.0).clip(true),  // Target
+                    Column::exact(50.0).clip(true),  // Vol
+                    Column::exact(50.0).clip(true),  // Mom
+                    Column::exact(55.0).clip(true),  // Time
+                    Column::exact(65.0).clip(true),  // Volume
+                    Column::exact(35.0).clip(true),  // Variants
+                ]
+            } else {
+                &[
+                    Column::exact(140.0).clip(false), // Pair
+                    Column::exact(70.0).clip(true),   // ROI/AROI
+                    Column::exact(55.0).clip(true),   // Vol/Mom
+                    Column::exact(55.0).clip(true),   // Time/Expectancy
+                    Column::exact(55.0).clip(true),   // Volume
+                    Column::exact(70.0).clip(true),   // Variant
+                ]
+            };
+            let (header_height, row_height) = if compact { (22.0, 20.0) } else { (48.0, 55.0) };
+
             let mut builder = TableBuilder::new(ui)
                 .striped(true)
                 .resizable(false)
                 .sense(Sense::click()) // Enable row clicks
-                .cell_layout(Layout::left_to_right(Align::Min))
-                .column(Column::exact(140.0).clip(false)) // Pair
-                .column(Column::exact(70.0).clip(true)) // ROI/AROI
-                .column(Column::exact(55.0).clip(true)) // Vol/Mom
-                .column(Column::exact(55.0).clip(true)) // Time/Ops
-                .column(Column::exact(55.0).clip(true)) // Volume
-                .column(Column::exact(70.0).clip(true)) // Variant
+                .cell_layout(Layout::left_to_right(if compact {
+                    Align::Center
+                } else {
+                    Align::Min
+                }))
                 .min_scrolled_height(0.0)
                 .max_scroll_height(available_height);
+            for column in columns {
+                builder = builder.column(*column);
+            }
             if let Some(idx) = target_index {
                 builder = builder.scroll_to_row(idx, Some(Align::Center));
             }
             builder
-                .header(48.0, |mut header| {
-                    self.render_tf_table_header(&mut header, &mut sort_changed);
+                .header(header_height, |mut header| {
+                    if compact {
+                        self.render_tf_compact_header(&mut header, &mut sort_changed);
+                    } else {
+                        self.render_tf_table_header(&mut header, &mut sort_changed);
+                    }
                 })
                 .body(|mut body| {
+                    for signal in &external {
+                        body.row(row_height, |mut table_row| {
+                            if self.render_tf_external_row(&mut table_row, signal, now_ms) {
+                                jump = Some(signal.pair.clone());
+                            }
+                        });
+                    }
                     for (i, row) in rows.iter().enumerate() {
-                        body.row(55.0, |mut table_row| {
+                        body.row(row_height, |mut table_row| {
                             self.render_tf_table_row(&mut table_row, row, i);
                         });
                     }
@@ -577,6 +3398,242 @@ impl App {
                 }
             }
         });
+
+        if let Some(pair) = jump {
+            self.tf_nav_ahead = None;
+            self.jump_to_pair(pair);
+        }
+
+        let selected = self.selection.pair_owned();
+        if let Some(engine) = self.engine.as_mut() {
+            // The pointer wins; otherwise the row the arrow keys are heading for
+            let likely_next = self.tf_hovered_pair.as_ref().or(self.tf_nav_ahead.as_ref());
+            engine.update_prefetch(likely_next.map(String::as_str), selected.as_deref());
+        }
+    }
+
+    /// Newest webhook signals, shown as rows above the engine's when the webhook settings ask.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn tf_external_signals(&self) -> Vec<ExternalSignal> {
+        if !self.signal_webhook_settings.in_trade_finder {
+            return Vec::new();
+        }
+        self.external_signals
+            .iter()
+            .rev()
+            .take(TF_EXTERNAL_ROWS)
+            .cloned()
+            .collect()
+    }
+
+    /// The browser build never receives webhook signals.
+    #[cfg(target_arch = "wasm32")]
+    fn tf_external_signals(&self) -> Vec<ExternalSignal> {
+        Vec::new()
+    }
+
+    /// A webhook signal as a Trade Finder row, tagged external: the sender's target under
+    /// Target, its age under Time, the sender under Volume, and whether the engine's own
+    /// opportunities on the pair agree under ROI. Returns whether the row was clicked.
+    fn render_tf_external_row(
+        &mut self,
+        table_row: &mut TableRow,
+        signal: &ExternalSignal,
+        now_ms: i64,
+    ) -> bool {
+        let ops = self
+            .engine
+            .as_ref()
+            .map(|e| e.get_pair_opportunities(&signal.pair))
+            .unwrap_or_default();
+        let agreement = if ops.iter().any(|op| op.direction == signal.direction) {
+            (UI_TEXT.tf_ext_agrees.clone(), PLOT_CONFIG.color_profit)
+        } else if ops.is_empty() {
+            (
+                UI_TEXT.tf_ext_no_engine.clone(),
+                PLOT_CONFIG.color_text_subdued,
+            )
+        } else {
+            (UI_TEXT.tf_ext_opposes.clone(), PLOT_CONFIG.color_loss)
+        };
+        let age = (
+            TimeUtils::format_duration((now_ms - signal.time_ms).max(0)),
+            PLOT_CONFIG.color_text_neutral,
+        );
+        let source = (signal.source.clone(), PLOT_CONFIG.color_text_subdued);
+        let arrow = match signal.direction {
+            TradeDirection::Long => &UI_TEXT.icon_long,
+            TradeDirection::Short => &UI_TEXT.icon_short,
+        };
+
+        table_row.col(|ui| {
+            ui.spacing_mut().item_spacing.x = 4.0;
+            ui.label(
+                RichText::new(&UI_TEXT.plot_external_tag)
+                    .small()
+                    .strong()
+                    .color(PLOT_CONFIG.color_info),
+            )
+            .on_hover_text(&UI_TEXT.tf_ext_tag_hover);
+            ui.label(
+                RichText::new(&signal.pair)
+                    .strong()
+                    .color(PLOT_CONFIG.color_text_primary),
+            );
+            ui.label(RichText::new(arrow).color(signal.direction.color()));
+        });
+        let cells = if self.tf_compact {
+            vec![
+                Some(agreement),
+                None,
+                None,
+                signal
+                    .target
+                    .map(|t| (t.to_string(), PLOT_CONFIG.color_info)),
+                None,
+                None,
+                Some(age),
+                Some(source),
+                None,
+            ]
+        } else {
+            vec![Some(agreement), None, Some(age), Some(source), None]
+        };
+        for cell in cells {
+            table_row.col(|ui| {
+                if let Some((text, color)) = cell {
+                    ui.label(RichText::new(text).small().color(color));
+                }
+            });
+        }
+
+        let response = table_row.response();
+        if response.hovered() {
+            self.tf_hovered_pair = Some(signal.pair.clone());
+        }
+        let prices = external_signal_prices(signal);
+        response
+            .on_hover_text(if signal.note.is_empty() {
+                prices
+            } else {
+                format!("{}\n{}", prices, signal.note)
+            })
+            .clicked()
+    }
+
+    fn render_tf_bulk_actions(&mut self, ui: &mut Ui) -> Option<BulkAction> {
+        if self.tf_multi_select.pairs.is_empty() {
+            return None;
+        }
+        let mut action = None;
+        ui.horizontal_wrapped(|ui| {
+            ui.label(
+                RichText::new(format!(
+                    "{} {}",
+                    self.tf_multi_select.pairs.len(),
+                    UI_TEXT.tf_bulk_selected
+                ))
+                .strong()
+                .color(PLOT_CONFIG.color_info),
+            );
+            for (label, bulk) in [
+                (&UI_TEXT.tf_bulk_recalc, BulkAction::Recalc),
+                (&UI_TEXT.tf_bulk_retune, BulkAction::Retune),
+                (&UI_TEXT.tf_bulk_watchlist, BulkAction::ToggleWatchlist),
+                #[cfg(not(target_arch = "wasm32"))]
+                (&UI_TEXT.tf_bulk_export_csv, BulkAction::ExportCsv),
+                (&UI_TEXT.tf_bulk_purge, BulkAction::Purge),
+                (&UI_TEXT.tf_bulk_clear, BulkAction::Clear),
+            ] {
+                if ui.small_button(label).clicked() {
+                    action = Some(bulk);
+                }
+            }
+        });
+        if let Some(status) = &self.tf_multi_select.status {
+            ui.label(
+                RichText::new(status)
+                    .small()
+                    .color(PLOT_CONFIG.color_text_subdued),
+            );
+        }
+        ui.separator();
+        action
+    }
+
+    fn apply_bulk_action(&mut self, action: BulkAction) {
+        let pairs: Vec<String> = self.tf_multi_select.pairs.iter().cloned().collect();
+        #[cfg(debug_assertions)]
+        if DF.log_selection {
+            log::info!("BULK {:?} on {} pairs", action, pairs.len());
+        }
+        self.tf_multi_select.status = None;
+        match action {
+            BulkAction::Recalc => {
+                if let Some(engine) = &mut self.engine {
+                    engine.recalc_pairs(&pairs, "BULK RECALC");
+                }
+            }
+            BulkAction::Retune => {
+                self.record_params("ph");
+                if let Some(engine) = &mut self.engine {
+                    let tuned = engine.retune_pairs(&pairs);
+                    self.tf_multi_select.status = Some(format!(
+                        "{} {}/{}",
+                        UI_TEXT.tf_bulk_retuned,
+                        tuned,
+                        pairs.len()
+                    ));
+                }
+            }
+            BulkAction::ToggleWatchlist => {
+                // All already watched => unwatch, otherwise watch all
+                if pairs.iter().all(|p| self.watchlist.contains(p)) {
+                    for p in &pairs {
+                        self.watchlist.remove(p);
+                    }
+                } else {
+                    self.watchlist.extend(pairs.iter().cloned());
+                }
+            }
+            #[cfg(not(target_arch = "wasm32"))]
+            BulkAction::ExportCsv => self.export_bulk_csv(),
+            BulkAction::Purge => {
+                if let Some(engine) = &mut self.engine {
+                    engine.purge_pairs(&pairs);
+                }
+                #[cfg(not(target_arch = "wasm32"))]
+                purge_kline_cache(pairs);
+            }
+            BulkAction::Clear => self.tf_multi_select.pairs.clear(),
+        }
+    }
+
+    /// Every checked pair, including those the current filters hide.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn export_bulk_csv(&mut self) {
+        let profile = self.shared_config.get_trade_profile();
+        let rows = self
+            .engine
+            .as_ref()
+            .map(|e| e.get_trade_finder_rows())
+            .unwrap_or_default();
+        let mut csv = String::from(TradeFinderRow::CSV_HEADER);
+        csv.push('\n');
+        for row in rows
+            .iter()
+            .filter(|r| self.tf_multi_select.pairs.contains(&r.pair_name))
+        {
+            csv.push_str(&row.to_csv_line(&profile));
+            csv.push('\n');
+        }
+        self.tf_multi_select.status = Some(match export_csv("trade_finder", &csv) {
+            Ok(path) => format!("{} {}", UI_TEXT.label_exported_to, path.display()),
+            Err(e) => {
+                log::error!("Failed to export trade finder CSV: {:#}", e);
+                format!("{}: {}", UI_TEXT.error_export_failed, e)
+            }
+        });
     }
 
     fn render_header_stack(
@@ -627,7 +3684,7 @@ impl App {
                 sort_changed,
                 SortColumn::AvgDuration,
                 &UI_TEXT.tf_time,
-                None,
+                Some((SortColumn::Expectancy, &UI_TEXT.tf_col_expectancy)),
             );
         });
         header.col(|ui| {
@@ -652,6 +3709,115 @@ impl App {
         });
     }
 
+    fn render_tf_compact_header(&mut self, header: &mut TableRow, sort_changed: &mut bool) {
+        let cols = [
+            (SortColumn::PairName, &UI_TEXT.label_pair),
+            (SortColumn::LiveRoi, &UI_TEXT.label_roi),
+            (SortColumn::AnnualizedRoi, &UI_TEXT.tf_col_aroi),
+            (SortColumn::Expectancy, &UI_TEXT.tf_col_expectancy),
+            (SortColumn::TargetPrice, &UI_TEXT.label_target),
+            (SortColumn::Volatility, &UI_TEXT.label_volatility_short),
+            (SortColumn::Momentum, &UI_TEXT.label_momentum_short),
+            (SortColumn::AvgDuration, &UI_TEXT.tf_time),
+            (SortColumn::QuoteVolume24h, &UI_TEXT.tf_col_volume),
+            (SortColumn::VariantCount, &UI_TEXT.label_stop_loss_short),
+        ];
+        for (col, text) in cols {
+            header.col(|ui| {
+                if self.render_stable_sort_label(ui, col, text) {
+                    *sort_changed = true;
+                }
+            });
+        }
+    }
+
+    /// One line per row: pair and direction, then one value per sortable column.
+    fn render_tf_compact_cols(&mut self, table_row: &mut TableRow, row: &TradeFinderRow) {
+        let op = row.opportunity.as_ref();
+        table_row.col(|ui| {
+            ui.spacing_mut().item_spacing.x = 4.0;
+            let mut checked = self.tf_multi_select.pairs.contains(&row.pair_name);
+            if ui.checkbox(&mut checked, "").changed() {
+                if checked {
+                    self.tf_multi_select.pairs.insert(row.pair_name.clone());
+                } else {
+                    self.tf_multi_select.pairs.remove(&row.pair_name);
+                }
+            }
+            ui.label(
+                RichText::new(&row.pair_name)
+                    .strong()
+                    .color(PLOT_CONFIG.color_text_primary),
+            );
+            render_category_tag(ui, &row.pair_name, 9.0);
+            if let Some(op) = op {
+                let arrow = match op.direction {
+                    TradeDirection::Long => &UI_TEXT.icon_long,
+                    TradeDirection::Short => &UI_TEXT.icon_short,
+                };
+                ui.label(RichText::new(arrow).color(op.direction.color()));
+            }
+            if self.watchlist.contains(&row.pair_name) {
+                ui.label(
+                    RichText::new(&UI_TEXT.icon_watchlist)
+                        .small()
+                        .color(PLOT_CONFIG.color_warning),
+                );
+            }
+        });
+
+        let roi = op.map(|op| op.live_roi(row.current_price));
+        let roi_color = roi.map_or(PLOT_CONFIG.color_text_neutral, |r| {
+            get_outcome_color(r.value())
+        });
+        let cells = [
+            roi.map(|r| (r.to_string(), roi_color)),
+            op.map(|op| {
+                (
+                    op.live_annualized_roi(row.current_price).to_string(),
+                    roi_color.linear_multiply(0.7),
+                )
+            }),
+            op.map(|op| {
+                let expectancy = op.simulation.expectancy_r();
+                (
+                    format!("{:+.2}R", expectancy),
+                    get_outcome_color(expectancy),
+                )
+            }),
+            op.map(|op| (op.target_price.to_string(), PLOT_CONFIG.color_info)),
+            row.market_state
+                .map(|ms| (ms.volatility_pct.to_string(), PLOT_CONFIG.color_info)),
+            row.market_state.map(|ms| {
+                (
+                    ms.momentum_pct.to_string(),
+                    get_momentum_color(ms.momentum_pct.value()),
+                )
+            }),
+            op.map(|op| {
+                (
+                    TimeUtils::format_duration(op.avg_duration.value()),
+                    PLOT_CONFIG.color_text_neutral,
+                )
+            }),
+            Some((row.volume_label(), PLOT_CONFIG.color_text_subdued)),
+            op.map(|op| {
+                (
+                    op.variants.len().to_string(),
+                    PLOT_CONFIG.color_text_neutral,
+                )
+            }),
+        ];
+        for cell in cells {
+            table_row.col(|ui| match cell {
+                Some((text, color)) => {
+                    ui.label(RichText::new(text).small().color(color));
+                }
+                None => self.display_no_data(ui),
+            });
+        }
+    }
+
     fn render_tf_table_row(
         &mut self,
         table_row: &mut TableRow,
@@ -667,40 +3833,79 @@ impl App {
 
         table_row.set_selected(is_selected);
 
-        self.col_pair_name(table_row, row, index);
-        self.col_strategy_metrics(table_row, row);
-        self.col_market_state(table_row, row);
-        self.col_time(table_row, row);
-        self.col_volume_24h(table_row, row);
-        self.col_sl_variants(table_row, row);
+        if self.tf_compact {
+            self.render_tf_compact_cols(table_row, row);
+        } else {
+            self.col_pair_name(table_row, row, index);
+            self.col_strategy_metrics(table_row, row);
+            self.col_market_state(table_row, row);
+            self.col_time(table_row, row);
+            self.col_volume_24h(table_row, row);
+            self.col_sl_variants(table_row, row);
+        }
 
-        let response = table_row.response();
+        let mut response = table_row.response();
+        if response.hovered() {
+            self.tf_hovered_pair = Some(row.pair_name.clone());
+        }
+        if let Some(op) = &row.opportunity {
+            response = response.on_hover_ui(|ui| {
+                let profile = self.shared_config.get_trade_profile();
+                render_opportunity_tooltip(ui, op, row.current_price, &profile);
+            });
+        }
 
         if response.clicked() {
-            match &row.opportunity {
-                Some(op) => {
-                    self.select_opportunity(
-                        op.clone(),
-                        ScrollBehavior::None,
-                        "clicked in render_tf_table_row",
-                    );
-                }
-                None => {
-                    // Case where we start as NOPP
-                    #[cfg(debug_assertions)]
-                    if DF.log_selection {
-                        log::info!(
-                            "Started as NOPP. So need make a selection based on new pair name "
-                        );
-                    }
-                    self.selection = Selection::Pair(row.pair_name.clone());
+            self.tf_nav_ahead = None;
+            self.select_tf_row(row, "clicked in render_tf_table_row");
+        }
+    }
+
+    fn select_tf_row(&mut self, row: &TradeFinderRow, reason: &str) {
+        match &row.opportunity {
+            Some(op) => {
+                self.select_opportunity(op.clone(), ScrollBehavior::None, reason);
+            }
+            None => {
+                // Case where we start as NOPP
+                #[cfg(debug_assertions)]
+                if DF.log_selection {
+                    log::info!("Started as NOPP. So need make a selection based on new pair name ");
                 }
+                self.selection = Selection::Pair(row.pair_name.clone());
             }
         }
     }
 
+    /// Moves the selection one row up / down for the arrow keys and remembers the row after it
+    /// in the direction of travel, so its model can be prefetched before the next key press.
+    fn step_tf_row(&mut self, rows: &[TradeFinderRow], delta: i32) {
+        let current = rows
+            .iter()
+            .position(|r| match (&self.selection, &r.opportunity) {
+                (Selection::Opportunity(sel), Some(op)) => sel.id == op.id,
+                (Selection::Pair(pair), None) => pair == &r.pair_name,
+                _ => false,
+            });
+        let next = match current {
+            Some(i) => (i as i32 + delta).clamp(0, rows.len() as i32 - 1) as usize,
+            None if delta > 0 => 0,
+            None => rows.len() - 1,
+        };
+        let row = &rows[next];
+        self.select_tf_row(row, "arrow keys in render_trade_finder");
+        self.scroll_target = Some(match &row.opportunity {
+            Some(op) => NavigationTarget::Opportunity(op.id.clone()),
+            None => NavigationTarget::Pair(row.pair_name.clone()),
+        });
+        self.tf_nav_ahead = usize::try_from(next as i32 + delta)
+            .ok()
+            .and_then(|i| rows.get(i))
+            .map(|r| r.pair_name.clone());
+    }
+
     fn col_pair_name(
-        &self,
+        &mut self,
         table_row: &mut egui_extras::TableRow,
         row: &TradeFinderRow,
         index: usize,
@@ -709,6 +3914,14 @@ impl App {
             ui.vertical(|ui| {
                 ui.horizontal(|ui| {
                     ui.style_mut().spacing.item_spacing.x = 4.0;
+                    let mut checked = self.tf_multi_select.pairs.contains(&row.pair_name);
+                    if ui.checkbox(&mut checked, "").changed() {
+                        if checked {
+                            self.tf_multi_select.pairs.insert(row.pair_name.clone());
+                        } else {
+                            self.tf_multi_select.pairs.remove(&row.pair_name);
+                        }
+                    }
                     ui.label(
                         RichText::new(format!("{}.", index))
                             .size(10.0)
@@ -720,6 +3933,14 @@ impl App {
                             .size(14.0)
                             .color(PLOT_CONFIG.color_text_primary),
                     );
+                    render_category_tag(ui, &row.pair_name, 10.0);
+                    if self.watchlist.contains(&row.pair_name) {
+                        ui.label(
+                            RichText::new(&UI_TEXT.icon_watchlist)
+                                .size(12.0)
+                                .color(PLOT_CONFIG.color_warning),
+                        );
+                    }
                     if let Some(op) = &row.opportunity {
                         ui.label(
                             RichText::new(op.station_id.short_name())
@@ -746,6 +3967,10 @@ impl App {
                                 .size(10.0)
                                 .color(PLOT_CONFIG.color_info),
                         );
+                        if op.is_low_evidence(&DEFAULT_JOURNEY_SETTINGS.evidence) {
+                            render_low_evidence_badge(ui, op, 9.0);
+                        }
+                        render_econ_event_badge(ui, op, &self.econ_events, 9.0);
                         ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
                             let now = TimeUtils::now_utc();
                             let age = now - op.created_at;
@@ -763,10 +3988,8 @@ impl App {
                     });
                     #[cfg(debug_assertions)]
                     {
-                        let uuid = &op.id;
-                        let short_id = if uuid.len() > 8 { &uuid[..8] } else { uuid };
                         ui.label(
-                            RichText::new(format!("ID: {} (PH: {})", short_id, op.ph_pct))
+                            RichText::new(format!("ID: {} (PH: {})", op.short_id(), op.ph_pct))
                                 .size(9.0)
                                 .color(Color32::from_rgb(255, 0, 255)), // Magenta
                         );
@@ -781,6 +4004,7 @@ impl App {
             if let Some(op) = &row.opportunity {
                 let roi_pct = op.live_roi(row.current_price);
                 let aroi_pct = op.live_annualized_roi(row.current_price);
+                let excess_pct = self.shared_config.get_trade_profile().excess_aroi(aroi_pct);
                 let roi_color = get_outcome_color(roi_pct.value());
 
                 ui.vertical(|ui| {
@@ -788,11 +4012,19 @@ impl App {
                     ui.horizontal(|ui| {
                         ui.spacing_mut().item_spacing.x = 2.0;
                         ui.label(RichText::new(&UI_TEXT.icon_strategy_roi).size(10.0)); // Mountain
-                        ui.label(
+                        let roi_label = ui.label(
                             RichText::new(format!("{}", roi_pct))
                                 .strong()
                                 .color(roi_color),
                         );
+                        if let Some(pnl) = row.live_pnl_usd() {
+                            roi_label.on_hover_text(format!(
+                                "{} ${:.4} / {}",
+                                UI_TEXT.tf_tt_pnl_usd,
+                                pnl,
+                                PairInterval::get_base(&row.pair_name).unwrap_or_default()
+                            ));
+                        }
                     });
 
                     ui.horizontal(|ui| {
@@ -803,6 +4035,15 @@ impl App {
                                 .size(10.0)
                                 .color(roi_color.linear_multiply(0.7)),
                         );
+                        ui.label(
+                            RichText::new(format!("({})", excess_pct))
+                                .size(9.0)
+                                .color(PLOT_CONFIG.color_text_subdued),
+                        )
+                        .on_hover_text(format!(
+                            "{} {}",
+                            UI_TEXT.label_excess_aroi, UI_TEXT.label_vs_benchmark
+                        ));
                     });
                     let show_score = self.tf_sort_col == SortColumn::Score
                         || op.strategy == OptimizationStrategy::Balanced;
@@ -856,7 +4097,20 @@ impl App {
                         RichText::new(TimeUtils::format_duration(op.avg_duration.value()))
                             .small()
                             .color(PLOT_CONFIG.color_text_neutral),
-                    );
+                    )
+                    .on_hover_text(format!(
+                        "{}: {} (volatility ×{:.2})",
+                        UI_TEXT.hover_time_limit,
+                        TimeUtils::format_duration(op.max_duration.value()),
+                        op.duration_vol_factor
+                    ));
+                    let expectancy = op.simulation.expectancy_r();
+                    ui.label(
+                        RichText::new(format!("{:+.2}R", expectancy))
+                            .small()
+                            .color(get_outcome_color(expectancy)),
+                    )
+                    .on_hover_text(&UI_TEXT.hover_expectancy);
                 });
             } else {
                 self.display_no_data(ui);
@@ -868,7 +4122,7 @@ impl App {
         table_row.col(|ui| {
             ui.vertical(|ui| {
                 self.down_from_top(ui);
-                let val_str = format!("{}", row.quote_volume_24h);
+                let val_str = row.volume_label();
                 ui.label(
                     RichText::new(val_str)
                         .small()
@@ -898,6 +4152,7 @@ impl App {
         };
 
         let selected_op_id = self.selection.opportunity().map(|o| &o.id);
+        let profile = self.shared_config.get_trade_profile();
 
         let base_asset = self
             .selection
@@ -906,13 +4161,16 @@ impl App {
             .unwrap_or("");
 
         let is_in_scope = |pair: &str| -> bool {
-            if !self.tf_scope_match_base {
-                return true;
-            }
             if self.selection.pair() == Some(pair) {
                 return true;
             }
-            !base_asset.is_empty() && pair.starts_with(base_asset)
+            if self
+                .tf_category
+                .is_some_and(|category| PairCategory::of_pair(pair) != category)
+            {
+                return false;
+            }
+            !self.tf_scope_match_base || (!base_asset.is_empty() && pair.starts_with(base_asset))
         };
 
         let mut pair_groups: HashMap<String, Vec<TradeFinderRow>> = HashMap::new();
@@ -937,7 +4195,16 @@ impl App {
                     if !op.is_worthwhile(&DEFAULT_JOURNEY_SETTINGS.profile) {
                         return false;
                     }
-                    true
+                    if self.tf_positive_expectancy && op.simulation.expectancy_r() <= 0.0 {
+                        return false;
+                    }
+                    if r.script_fields()
+                        .is_some_and(|f| !self.opportunity_script.passes(&f))
+                    {
+                        return false;
+                    }
+                    !self.tf_beats_benchmark
+                        || profile.beats_benchmark(op.live_annualized_roi(r.current_price))
                 } else {
                     false
                 }
@@ -949,6 +4216,7 @@ impl App {
                 final_rows.push(TradeFinderRow {
                     pair_name: sample.pair_name,
                     quote_volume_24h: sample.quote_volume_24h,
+                    quote_usd_rate: sample.quote_usd_rate,
                     market_state: sample.market_state,
                     opportunity: None,
                     current_price: sample.current_price,
@@ -1021,7 +4289,7 @@ impl App {
             } else {
                 self.tf_sort_col = col;
                 self.tf_sort_dir = match col {
-                    SortColumn::PairName | SortColumn::AvgDuration => SortDirection::Ascending,
+                    SortColumn::PairName => SortDirection::Ascending,
                     _ => SortDirection::Descending,
                 };
             }
@@ -1033,6 +4301,10 @@ impl App {
     fn render_active_target_panel(&mut self, ui: &mut Ui) {
         let pair_opt = self.selection.pair_owned();
         let opp_opt = self.selection.opportunity();
+        #[cfg_attr(target_arch = "wasm32", allow(unused_mut))]
+        let mut export_requested = false;
+        let mut rung_pick = None;
+        let mut delete_note = None;
 
         Frame::group(ui.style())
             .fill(Color32::from_white_alpha(5))
@@ -1075,6 +4347,21 @@ impl App {
                                         RichText::new(format!("{} {}", UI_TEXT.label_roi, roi_pct))
                                             .color(color),
                                     );
+                                    help_link(ui, GlossaryTerm::Roi);
+                                    let profile = self.shared_config.get_trade_profile();
+                                    ui.label(
+                                        RichText::new(format!(
+                                            "{} {}",
+                                            UI_TEXT.label_excess_aroi,
+                                            op.live_excess_aroi(current_price, &profile)
+                                        ))
+                                        .small()
+                                        .color(PLOT_CONFIG.color_text_subdued),
+                                    )
+                                    .on_hover_text(format!(
+                                        "{} {}",
+                                        UI_TEXT.label_vs_benchmark, profile.benchmark_aroi_pct
+                                    ));
                                 } else {
                                     log::info!("No price available for {}", pair);
                                 }
@@ -1082,6 +4369,50 @@ impl App {
                                 log::info!("No engine available for {}", pair);
                             }
                         });
+                        ui.horizontal(|ui| {
+                            render_trade_distances(ui, op);
+                            help_link(ui, GlossaryTerm::RiskReward);
+                        });
+                        ui.label(
+                            RichText::new(format!(
+                                "{} {:+.2}R · {} {:.0}%",
+                                UI_TEXT.label_expectancy,
+                                op.simulation.expectancy_r(),
+                                UI_TEXT.label_kelly,
+                                op.simulation.kelly_fraction() * 100.0
+                            ))
+                            .small()
+                            .color(get_outcome_color(op.simulation.expectancy_r())),
+                        )
+                        .on_hover_text(&UI_TEXT.hover_expectancy);
+                        if let Some(times) = &op.simulation.hit_times {
+                            render_hit_times(ui, times, op.max_duration_candles());
+                        }
+                        if let Some(excursions) = &op.excursions {
+                            render_excursions(ui, excursions);
+                        }
+                        if let Some(fixed_rate) = op.simulation.fixed_success_rate {
+                            render_stop_management_effect(ui, op, fixed_rate);
+                        }
+                        if op.is_low_evidence(&DEFAULT_JOURNEY_SETTINGS.evidence) {
+                            ui.horizontal(|ui| {
+                                render_low_evidence_badge(ui, op, 11.0);
+                            });
+                        }
+                        render_econ_event_badge(ui, op, &self.econ_events, 11.0);
+                        if let Some(label) = &op.custom_zone {
+                            ui.label(
+                                RichText::new(format!(
+                                    "{}: {}",
+                                    UI_TEXT.label_custom_zone_target, label
+                                ))
+                                .small()
+                                .color(PLOT_CONFIG.color_text_subdued),
+                            );
+                        }
+                        if !op.ladder.is_empty() {
+                            rung_pick = Self::render_target_ladder(ui, op);
+                        }
                         ui.horizontal(|ui| {
                             ui.label(
                                 RichText::new(format!("{} {}", UI_TEXT.label_source_ph, op.ph_pct))
@@ -1090,11 +4421,74 @@ impl App {
                             );
                             #[cfg(debug_assertions)]
                             {
-                                let short_id = if op.id.len() > 8 { &op.id[..8] } else { &op.id };
                                 ui.label(
-                                    RichText::new(format!("{}: {}", UI_TEXT.label_id, short_id))
+                                    RichText::new(format!(
+                                        "{}: {}",
+                                        UI_TEXT.label_id,
+                                        op.short_id()
+                                    ))
+                                    .small()
+                                    .color(Color32::from_rgb(255, 0, 255)),
+                                );
+                            }
+                        });
+                        ui.horizontal(|ui| {
+                            let journeys_label = format!(
+                                "{} ({})",
+                                UI_TEXT.label_journeys, op.simulation.sample_size
+                            );
+                            if ui
+                                .selectable_label(self.show_journey_browser, journeys_label)
+                                .clicked()
+                            {
+                                self.show_journey_browser = !self.show_journey_browser;
+                            }
+                            let mut taken = self.shared_config.is_taken(&op.id);
+                            if ui
+                                .checkbox(&mut taken, &UI_TEXT.label_taken)
+                                .on_hover_text(&UI_TEXT.hover_taken)
+                                .changed()
+                            {
+                                self.shared_config.set_taken(&op.id, taken);
+                            }
+                        });
+                        if let Some(notes) = self.annotations.get(&op.id).filter(|n| !n.is_empty())
+                        {
+                            ui.label(
+                                RichText::new(&UI_TEXT.an_notes)
+                                    .small()
+                                    .color(PLOT_CONFIG.color_text_subdued),
+                            );
+                            for (i, note) in notes.iter().enumerate() {
+                                ui.horizontal(|ui| {
+                                    if ui
+                                        .small_button("✖")
+                                        .on_hover_text(&UI_TEXT.an_delete_hover)
+                                        .clicked()
+                                    {
+                                        delete_note = Some((op.id.clone(), i));
+                                    }
+                                    ui.label(
+                                        RichText::new(format!("{} @ {}", note.text, note.price))
+                                            .color(PLOT_CONFIG.annotation_color),
+                                    );
+                                });
+                            }
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        ui.horizontal(|ui| {
+                            if ui
+                                .small_button(&UI_TEXT.label_export_analysis)
+                                .on_hover_text(&UI_TEXT.hover_export_analysis)
+                                .clicked()
+                            {
+                                export_requested = true;
+                            }
+                            if let Some(status) = &self.export_status {
+                                ui.label(
+                                    RichText::new(status)
                                         .small()
-                                        .color(Color32::from_rgb(255, 0, 255)),
+                                        .color(PLOT_CONFIG.color_text_subdued),
                                 );
                             }
                         });
@@ -1118,14 +4512,120 @@ impl App {
                 }
             });
 
+        if let Some(op) = rung_pick {
+            self.select_opportunity(op, ScrollBehavior::None, "render_target_ladder");
+        }
+        if let Some((id, i)) = delete_note {
+            self.annotations.entry(id).and_modify(|notes| {
+                notes.remove(i);
+            });
+            self.annotations.retain(|_, notes| !notes.is_empty());
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        if export_requested {
+            self.export_selected_analysis();
+        }
+        #[cfg(target_arch = "wasm32")]
+        let _ = export_requested;
+
         ui.add_space(10.0);
     }
 
-    fn handle_tuner_action(&mut self, action: TunerAction) {
+    #[cfg(not(target_arch = "wasm32"))]
+    fn export_selected_analysis(&mut self) {
+        let Some(op) = self.selection.opportunity() else {
+            return;
+        };
+        let report = self
+            .engine
+            .as_ref()
+            .and_then(|e| e.build_analysis_report(op))
+            .map(|mut report| {
+                report.annotations = self.annotations.get(&op.id).cloned().unwrap_or_default();
+                report
+            });
+        self.export_status = Some(match report {
+            Some(report) => match export_analysis_report(&report, true) {
+                Ok(path) => format!("{} {}", UI_TEXT.label_exported_to, path.display()),
+                Err(e) => {
+                    log::error!("Failed to export analysis for {}: {:#}", op.pair_name, e);
+                    format!("{}: {}", UI_TEXT.error_export_failed, e)
+                }
+            },
+            None => format!(
+                "{}: {}",
+                UI_TEXT.error_export_failed, UI_TEXT.error_no_model
+            ),
+        });
+    }
+
+    fn commit_manual_ph(&mut self, pair: &str, ph: PhPct) {
+        if self.shared_config.get_ph(pair) == Some(ph) {
+            return;
+        }
+        self.record_params("ph");
+        self.shared_config.insert_ph(pair.to_string(), ph);
+        #[cfg(debug_assertions)]
+        if DF.log_ph_overrides {
+            log::info!(
+                "SETTING PH_OVERRIDES for {} to be {} in commit_manual_ph",
+                pair,
+                ph
+            );
+        }
+        if let Some(engine) = &mut self.engine {
+            engine.recalc_pair_from_config(pair, "MANUAL PH SLIDER");
+        }
+    }
+
+    /// Collects finished station previews and starts the next one the tuner wants, in the
+    /// background so sweeping across stations never blocks the UI or queues engine work.
+    /// Previews stay out of the station stats, which only reflect live analyses.
+    fn drive_station_preview(&mut self, ctx: &Context, pair: &str) {
+        #[cfg(not(target_arch = "wasm32"))]
+        self.station_preview.poll();
+        // A sweep across the stations would run one blocking tune per button
+        #[cfg(target_arch = "wasm32")]
+        if let Some(wait_ms) = self.station_preview.hover_wait_ms() {
+            ctx.request_repaint_after(std::time::Duration::from_millis(wait_ms));
+            return;
+        }
+        let Some(input) = self
+            .station_preview
+            .wanted()
+            .and_then(|station| self.engine.as_ref()?.station_preview_input(pair, station))
+        else {
+            return;
+        };
+        let station = input.station.id;
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let (tx, rx) = mpsc::channel();
+            self.station_preview.start(station, rx);
+            let repaint = ctx.clone();
+            thread::spawn(move || {
+                let _ = tx.send(preview_station(input));
+                repaint.request_repaint();
+            });
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = ctx;
+            self.station_preview.finish(station, preview_station(input));
+        }
+    }
+
+    pub(crate) fn handle_tuner_action(&mut self, action: TunerAction) {
         match action {
             TunerAction::StationSelected(station_id) => {
                 if let Some(pair) = self.selection.pair_owned() {
                     let pair_name = pair.clone();
+                    // A finished preview already holds this tune; the tuner reuses it
+                    if let Some(scan) = self.station_preview.take_scan(station_id) {
+                        self.shared_config
+                            .insert_tuner_scan(pair_name.clone(), scan);
+                    }
+                    self.record_params("station");
                     self.shared_config
                         .insert_station(pair_name.clone(), station_id);
                     #[cfg(debug_assertions)]
@@ -1177,15 +4677,24 @@ impl App {
                 #[cfg(debug_assertions)]
                 log::info!("TODO: Open Config Modal for Time Tuner");
             }
+            TunerAction::NoteEdited(station_id, note) => {
+                if let Some(pair) = self.selection.pair_owned() {
+                    self.shared_config.set_station_note(pair, station_id, note);
+                }
+            }
         }
     }
 
     fn render_price(&self, ui: &mut Ui) {
         if let Some(pair) = &self.selection.pair_owned() {
             ui.label(
-                RichText::new(format!("{} ", &UI_TEXT.sp_live_mode))
-                    .small()
-                    .color(PLOT_CONFIG.color_profit),
+                RichText::new(format!(
+                    "{} ",
+                    self.status_bar
+                        .label(&UI_TEXT.sp_live_mode, &UI_TEXT.sp_live_mode_short)
+                ))
+                .small()
+                .color(PLOT_CONFIG.color_profit),
             );
             ui.separator();
 
@@ -1209,11 +4718,11 @@ impl App {
                     let zone_size =
                         (cva.price_range.end - cva.price_range.start) / cva.zone_count as f64;
                     ui.metric(
-                        &UI_TEXT.sp_zone_size,
+                        self.status_bar
+                            .label(&UI_TEXT.sp_zone_size, &UI_TEXT.sp_zone_size_short),
                         &format!("{}", zone_size),
                         PLOT_CONFIG.color_info,
                     );
-                    ui.separator();
                 }
             }
         }
@@ -1223,32 +4732,45 @@ impl App {
         if let Some(engine) = &self.engine {
             if let Some(pair) = &self.selection.pair_owned() {
                 if let Some(model) = engine.get_model(pair) {
-                    let cov_color = |pct: f64| {
-                        if pct > 30.0 {
+                    let targets = self
+                        .shared_config
+                        .get_coverage_targets(self.shared_config.get_strategy());
+                    let cov_color = |pct: f64, band: CoverageBand| {
+                        if pct > band.max_pct {
                             PLOT_CONFIG.color_loss
-                        } else if pct < 5.0 {
+                        } else if pct < band.min_pct {
                             PLOT_CONFIG.color_warning
                         } else {
                             PLOT_CONFIG.color_profit
                         }
                     };
-                    ui.label_subdued(&UI_TEXT.sp_coverage);
+                    let sb = &self.status_bar;
+                    ui.label_subdued(sb.label(&UI_TEXT.sp_coverage, &UI_TEXT.sp_coverage_short));
+                    help_link(ui, GlossaryTerm::Coverage);
                     ui.metric(
-                        &UI_TEXT.sp_coverage_sticky,
+                        sb.label(
+                            &UI_TEXT.sp_coverage_sticky,
+                            &UI_TEXT.sp_coverage_sticky_short,
+                        ),
                         &format!("{:.1}%", model.coverage.sticky_pct),
-                        cov_color(model.coverage.sticky_pct),
+                        cov_color(model.coverage.sticky_pct, targets.sticky),
                     );
                     ui.metric(
-                        &UI_TEXT.sp_coverage_support,
+                        sb.label(
+                            &UI_TEXT.sp_coverage_support,
+                            &UI_TEXT.sp_coverage_support_short,
+                        ),
                         &format!("{:.1}%", model.coverage.support_pct),
-                        cov_color(model.coverage.support_pct),
+                        cov_color(model.coverage.support_pct, targets.support),
                     );
                     ui.metric(
-                        &UI_TEXT.sp_coverage_resistance,
+                        sb.label(
+                            &UI_TEXT.sp_coverage_resistance,
+                            &UI_TEXT.sp_coverage_resistance_short,
+                        ),
                         &format!("{:.1}%", model.coverage.resistance_pct),
-                        cov_color(model.coverage.resistance_pct),
+                        cov_color(model.coverage.resistance_pct, targets.resistance),
                     );
-                    ui.separator();
                 }
             }
         }
@@ -1258,8 +4780,6 @@ impl App {
         if let Some(engine) = &self.engine {
             if let Some(pair) = &self.selection.pair_owned() {
                 if let Some(model) = engine.get_model(pair) {
-                    ui.separator();
-
                     let relevant = model.cva.relevant_candle_count;
                     let total = model.cva.total_candles;
                     let pct = if total > 0 {
@@ -1274,25 +4794,71 @@ impl App {
                     );
                     ui.separator();
                     ui.metric(
-                        &UI_TEXT.label_volatility,
+                        self.status_bar
+                            .label(&UI_TEXT.label_volatility, &UI_TEXT.sp_volatility_short),
                         &format!("{}", model.cva.volatility_pct),
                         PLOT_CONFIG.color_warning,
                     );
+                    render_vol_term_sparkline(ui, &model.vol_term);
                 }
             }
         }
     }
 
     fn render_status_system(&self, ui: &mut Ui) {
+        let mut throttle = self.shared_config.get_throttle();
+        let toggled = if throttle.paused {
+            ui.button(
+                RichText::new(&UI_TEXT.sp_analysis_paused)
+                    .small()
+                    .color(PLOT_CONFIG.color_warning),
+            )
+            .on_hover_text(&UI_TEXT.sp_resume_hover)
+            .clicked()
+        } else {
+            ui.small_button(&UI_TEXT.sp_pause)
+                .on_hover_text(&UI_TEXT.es_pause_hover)
+                .clicked()
+        };
+        if toggled {
+            throttle.paused = !throttle.paused;
+            self.shared_config.set_throttle(throttle);
+        }
         if let Some(engine) = &self.engine {
-            if let Some(msg) = engine.get_worker_status_msg() {
-                ui.separator();
+            let batched = engine.get_batched_count();
+            if batched > 0 {
                 ui.label(
-                    RichText::new(format!("{} {}", UI_TEXT.label_working, msg))
+                    RichText::new(format!("{}: {}", UI_TEXT.sp_batched, batched))
                         .small()
-                        .color(PLOT_CONFIG.color_short),
+                        .color(PLOT_CONFIG.color_text_subdued),
                 );
             }
+            if let Some(msg) = engine.get_worker_status_msg() {
+                ui.separator();
+                if ui
+                    .add(
+                        Label::new(
+                            RichText::new(format!("{} {}", UI_TEXT.label_working, msg))
+                                .small()
+                                .color(PLOT_CONFIG.color_short),
+                        )
+                        .sense(Sense::click()),
+                    )
+                    .on_hover_text(&UI_TEXT.md_history_hover)
+                    .clicked()
+                {
+                    request_recalc_history(ui);
+                }
+            }
+            let recalcs = engine.recalc_history().len();
+            if recalcs > 0
+                && ui
+                    .small_button(format!("{} {}", UI_TEXT.md_history, recalcs))
+                    .on_hover_text(&UI_TEXT.md_history_hover)
+                    .clicked()
+            {
+                request_recalc_history(ui);
+            }
             let q_len = engine.get_queue_len();
             if q_len > 0 {
                 ui.separator();
@@ -1306,6 +4872,16 @@ impl App {
     }
 
     fn render_status_network(&self, ui: &mut Ui) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if mock_exchange().is_some() {
+            ui.label(
+                RichText::new(&UI_TEXT.sp_mock_exchange)
+                    .small()
+                    .strong()
+                    .color(PLOT_CONFIG.color_warning),
+            )
+            .on_hover_text(&UI_TEXT.sp_mock_exchange_hover);
+        }
         if let Some(engine) = &self.engine {
             let health: Pct = engine.price_stream.connection_health();
             let color = if health >= Pct::new(0.9) {
@@ -1315,12 +4891,31 @@ impl App {
             } else {
                 PLOT_CONFIG.color_loss
             };
+            let value = if self.status_bar.compact {
+                health.to_string()
+            } else {
+                format!("{} {}", health, UI_TEXT.label_connected)
+            };
             ui.metric(
-                &UI_TEXT.sp_stream_status,
-                &format!("{} {}", health, UI_TEXT.label_connected),
+                self.status_bar
+                    .label(&UI_TEXT.sp_stream_status, &UI_TEXT.sp_stream_status_short),
+                &value,
                 color,
             );
         }
+        let skew_ms = -TimeUtils::clock_offset_ms();
+        if skew_ms.abs() > BINANCE_API.clock.warn_skew_ms {
+            ui.label(
+                RichText::new(format!(
+                    "{} {:+.1}s",
+                    UI_TEXT.sp_clock_skew,
+                    skew_ms as f64 / 1000.0
+                ))
+                .small()
+                .color(PLOT_CONFIG.color_warning),
+            )
+            .on_hover_text(&UI_TEXT.sp_clock_skew_hover);
+        }
     }
 
     fn render_card_variants(&mut self, ui: &mut Ui, op: &TradeOpportunity) {
@@ -1350,18 +4945,25 @@ impl App {
             ui.custom_dropdown(&id_source, &label_text, |ui| {
                 let mut should_close = false;
                 for (i, variant) in op.variants.iter().enumerate() {
-                    let risk_pct =
-                        Pct::new(variant.stop_price.percent_diff_from_0_1(&op.start_price));
-                    let win_rate = variant.simulation.success_rate;
+                    let stop_move = op.price_move(&variant.stop_price);
+                    let win_rate = if op.is_low_evidence(&DEFAULT_JOURNEY_SETTINGS.evidence) {
+                        format!(
+                            "{} ±{}",
+                            variant.simulation.success_rate,
+                            variant.simulation.success_rate_margin()
+                        )
+                    } else {
+                        variant.simulation.success_rate.to_string()
+                    };
                     let text = format!(
-                        "{}. {} {}   {} {}   {} -{}",
+                        "{}. {} {}   {} {}   {} {:+.2}%",
                         i + 1,
                         UI_TEXT.label_roi,
                         variant.roi_pct,
                         UI_TEXT.label_success_rate_short,
                         win_rate,
                         UI_TEXT.label_stop_loss_short,
-                        risk_pct
+                        stop_move * 100.0
                     );
 
                     let is_current = variant.stop_price == active_stop_price;
@@ -1384,6 +4986,41 @@ impl App {
         });
     }
 
+    /// Ladder rungs of the selected opportunity; clicking one re-targets the selection to that
+    /// rung (same stop, rung's own simulation), mirroring stop-loss variant selection.
+    fn render_target_ladder(ui: &mut Ui, op: &TradeOpportunity) -> Option<TradeOpportunity> {
+        let mut picked = None;
+        ui.label(
+            RichText::new(&UI_TEXT.label_target_ladder)
+                .small()
+                .color(PLOT_CONFIG.color_text_subdued),
+        );
+        for (i, rung) in op.ladder.iter().enumerate() {
+            let text = format!(
+                "{}. {} {}   {} {}   {} {}",
+                i + 1,
+                UI_TEXT.label_target,
+                rung.target_price,
+                UI_TEXT.label_success_rate_short,
+                rung.simulation.success_rate,
+                UI_TEXT.label_roi,
+                rung.simulation.avg_pnl_pct
+            );
+            let is_current = rung.target_price == op.target_price;
+            if ui
+                .selectable_label(is_current, RichText::new(text).small())
+                .clicked()
+                && !is_current
+            {
+                let mut new_selected = op.clone();
+                new_selected.target_price = rung.target_price;
+                new_selected.simulation = rung.simulation.clone();
+                picked = Some(new_selected);
+            }
+        }
+        picked
+    }
+
     fn render_optimization_strategy(&mut self, ui: &mut Ui) {
         ui.label(&UI_TEXT.label_goal);
 
@@ -1409,10 +5046,40 @@ impl App {
                     selected_strategy
                 );
             }
+            self.record_params("strategy");
             self.shared_config.set_strategy(selected_strategy);
             self.handle_strategy_selection();
         }
 
+        let mut ladder_targets = self.shared_config.get_ladder_targets();
+        if ui
+            .checkbox(&mut ladder_targets, &UI_TEXT.label_ladder_targets)
+            .on_hover_text(&UI_TEXT.hover_ladder_targets)
+            .changed()
+        {
+            self.record_params("ladder_targets");
+            self.shared_config.set_ladder_targets(ladder_targets);
+            let priority_pair = self.selection.pair_owned();
+            if let Some(engine) = &mut self.engine {
+                engine.trigger_global_recalc(priority_pair);
+            }
+        }
+
+        let mut custom_zone_targets = self.shared_config.get_custom_zone_targets();
+        if ui
+            .checkbox(&mut custom_zone_targets, &UI_TEXT.label_custom_zone_targets)
+            .on_hover_text(&UI_TEXT.hover_custom_zone_targets)
+            .changed()
+        {
+            self.record_params("custom_zone_targets");
+            self.shared_config
+                .set_custom_zone_targets(custom_zone_targets);
+            let priority_pair = self.selection.pair_owned();
+            if let Some(engine) = &mut self.engine {
+                engine.trigger_global_recalc(priority_pair);
+            }
+        }
+
         ui.separator();
     }
 
@@ -1424,156 +5091,51 @@ impl App {
         }
     }
 
+    /// Sorts by the chosen column; ties fall back to quality score (best first), then pair
+    /// name. Rows without a target, or missing the sorted value, stay at the bottom in
+    /// either direction (of their sector, when grouping by sector).
     fn sort_trade_finder_rows(&self, rows: &mut [TradeFinderRow]) {
+        let col = self.tf_sort_col;
+        let dir = self.tf_sort_dir;
+        let key = |row: &TradeFinderRow| match col {
+            SortColumn::Script => row
+                .script_fields()
+                .and_then(|f| self.opportunity_script.score_of(&f)),
+            _ => col.row_value(row),
+        };
+        let group = |row: &TradeFinderRow| {
+            self.tf_group_by_category
+                .then(|| PairCategory::of_pair(&row.pair_name))
+        };
         rows.sort_by(|a, b| {
-            let a_has = a.opportunity.is_some();
-            let b_has = b.opportunity.is_some();
-
-            if a_has != b_has {
-                if a_has {
-                    return Ordering::Less;
-                } else {
-                    return Ordering::Greater;
-                }
-            }
-
-            let cmp = match self.tf_sort_col {
-                SortColumn::PairName => a.pair_name.cmp(&b.pair_name),
-                SortColumn::TargetPrice => {
-                    let val_a = a
-                        .opportunity
-                        .as_ref()
-                        .map(|o| o.target_price)
-                        .unwrap_or_default();
-                    let val_b = b
-                        .opportunity
-                        .as_ref()
-                        .map(|o| o.target_price)
-                        .unwrap_or_default();
-                    val_a
-                        .value()
-                        .total_cmp(&val_b.value())
-                        .then_with(|| a.pair_name.cmp(&b.pair_name))
-                }
-                SortColumn::QuoteVolume24h => a
-                    .quote_volume_24h
-                    .value()
-                    .total_cmp(&b.quote_volume_24h.value())
-                    .then_with(|| a.pair_name.cmp(&b.pair_name)),
-                SortColumn::Volatility => {
-                    let va = a
-                        .market_state
-                        .as_ref()
-                        .map(|m| m.volatility_pct)
-                        .unwrap_or(VolatilityPct::new(0.0));
-                    let vb = b
-                        .market_state
-                        .as_ref()
-                        .map(|m| m.volatility_pct)
-                        .unwrap_or(VolatilityPct::new(0.0));
-                    va.value()
-                        .total_cmp(&vb.value())
-                        .then_with(|| a.pair_name.cmp(&b.pair_name))
-                }
-                SortColumn::Momentum => {
-                    let ma = a
-                        .market_state
-                        .as_ref()
-                        .map(|m| m.momentum_pct)
-                        .unwrap_or(MomentumPct::new(0.0));
-                    let mb = b
-                        .market_state
-                        .as_ref()
-                        .map(|m| m.momentum_pct)
-                        .unwrap_or(MomentumPct::new(0.0));
-                    ma.value()
-                        .total_cmp(&mb.value())
-                        .then_with(|| a.pair_name.cmp(&b.pair_name))
-                }
-                SortColumn::LiveRoi => {
-                    let val_a = a
-                        .opportunity
-                        .as_ref()
-                        .map(|o| o.live_roi(a.current_price).value())
-                        .unwrap_or(f64::NEG_INFINITY);
-                    let val_b = b
-                        .opportunity
-                        .as_ref()
-                        .map(|o| o.live_roi(b.current_price).value())
-                        .unwrap_or(f64::NEG_INFINITY);
-                    val_a
-                        .total_cmp(&val_b)
-                        .then_with(|| a.pair_name.cmp(&b.pair_name))
-                }
-                SortColumn::AnnualizedRoi => {
-                    let val_a = a
-                        .opportunity
-                        .as_ref()
-                        .map(|o| o.live_annualized_roi(a.current_price).value())
-                        .unwrap_or(f64::NEG_INFINITY);
-                    let val_b = b
-                        .opportunity
-                        .as_ref()
-                        .map(|o| o.live_annualized_roi(b.current_price).value())
-                        .unwrap_or(f64::NEG_INFINITY);
-                    val_a
-                        .total_cmp(&val_b)
-                        .then_with(|| a.pair_name.cmp(&b.pair_name))
-                }
-                SortColumn::AvgDuration => {
-                    let val_a = a
-                        .opportunity
-                        .as_ref()
-                        .map(|o| o.avg_duration.value())
-                        .unwrap_or(i64::MAX);
-                    let val_b = b
-                        .opportunity
-                        .as_ref()
-                        .map(|o| o.avg_duration.value())
-                        .unwrap_or(i64::MAX);
-                    val_b
-                        .cmp(&val_a)
-                        .then_with(|| a.pair_name.cmp(&b.pair_name))
-                }
-                SortColumn::Score => {
-                    let val_a = a
-                        .opportunity
-                        .as_ref()
-                        .map(|o| o.calc_quality_score())
-                        .unwrap_or(f64::NEG_INFINITY);
-                    let val_b = b
-                        .opportunity
-                        .as_ref()
-                        .map(|o| o.calc_quality_score())
-                        .unwrap_or(f64::NEG_INFINITY);
-
-                    val_a
-                        .total_cmp(&val_b)
-                        .then_with(|| a.pair_name.cmp(&b.pair_name))
-                }
-                SortColumn::VariantCount => {
-                    let va = a
-                        .opportunity
-                        .as_ref()
-                        .map(|o| o.variant_count())
-                        .unwrap_or(0);
-                    let vb = b
-                        .opportunity
-                        .as_ref()
-                        .map(|o| o.variant_count())
-                        .unwrap_or(0);
-                    va.cmp(&vb).then_with(|| a.pair_name.cmp(&b.pair_name))
-                }
-            };
-
-            match self.tf_sort_dir {
-                SortDirection::Ascending => cmp,
-                SortDirection::Descending => cmp.reverse(),
-            }
+            group(a)
+                .cmp(&group(b))
+                .then_with(|| b.opportunity.is_some().cmp(&a.opportunity.is_some()))
+                .then_with(|| match col {
+                    SortColumn::PairName => dir.apply(a.pair_name.cmp(&b.pair_name)),
+                    _ => cmp_present_first(key(a), key(b), |x, y| dir.apply(x.total_cmp(&y))),
+                })
+                .then_with(|| {
+                    cmp_present_first(
+                        SortColumn::Score.row_value(a),
+                        SortColumn::Score.row_value(b),
+                        |x, y| y.total_cmp(&x),
+                    )
+                })
+                .then_with(|| a.pair_name.cmp(&b.pair_name))
         });
     }
 }
 
+/// Combo label for a Trade Finder re-sort interval.
+fn refresh_interval_label(secs: u32) -> String {
+    if secs == 0 {
+        UI_TEXT.tf_refresh_live.clone()
+    } else {
+        format!("{secs}s")
+    }
+}
+
 fn render_fullscreen_message(ui: &mut Ui, title: &str, subtitle: &str, is_error: bool) {
     ui.vertical_centered(|ui| {
         ui.add_space(40.0);
@@ -1594,3 +5156,963 @@ fn render_fullscreen_message(ui: &mut Ui, title: &str, subtitle: &str, is_error:
         ui.label(text);
     });
 }
+
+fn format_purge_summary(summary: &PurgeSummary) -> String {
+    if summary.is_empty() {
+        return UI_TEXT.es_purge_none.clone();
+    }
+    let pairs: Vec<&str> = summary.pairs.iter().map(String::as_str).collect();
+    format!(
+        "{} {}: {} {}, {} {}, {} {} ({})",
+        UI_TEXT.es_purge_removed,
+        summary.ids.len(),
+        summary.expired,
+        UI_TEXT.es_purge_expired,
+        summary.over_limit,
+        UI_TEXT.es_purge_over_limit,
+        summary.collisions,
+        UI_TEXT.es_purge_collisions,
+        pairs.join(", ")
+    )
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn render_cross_check_report(ui: &mut Ui, report: &CrossCheckReport) {
+    const MAX_LISTED: usize = 12;
+    let clean = report.mismatches.is_empty() && report.errors.is_empty();
+    let color = if !report.mismatches.is_empty() {
+        PLOT_CONFIG.color_loss
+    } else if !report.errors.is_empty() {
+        PLOT_CONFIG.color_warning
+    } else {
+        PLOT_CONFIG.color_profit
+    };
+    ui.label(
+        RichText::new(format!(
+            "{} {} / {} {} · {} {} · {} {} ({})",
+            report.candles_checked,
+            UI_TEXT.dg_cross_check_candles,
+            report.pairs_checked,
+            UI_TEXT.dg_cross_check_pairs,
+            report.mismatches.len(),
+            UI_TEXT.dg_cross_check_mismatches,
+            report.errors.len(),
+            UI_TEXT.dg_cross_check_errors,
+            report.ran_at.to_datetime().format("%H:%M:%S"),
+        ))
+        .small()
+        .color(color),
+    );
+    if clean {
+        ui.label(
+            RichText::new(&UI_TEXT.dg_cross_check_clean)
+                .small()
+                .color(PLOT_CONFIG.color_text_subdued),
+        );
+        return;
+    }
+    let suspects = report.suspect_pairs();
+    if !suspects.is_empty() {
+        ui.label(
+            RichText::new(format!(
+                "{} {}",
+                UI_TEXT.dg_cross_check_suspects,
+                suspects.join(", ")
+            ))
+            .small()
+            .strong(),
+        );
+    }
+    ScrollArea::vertical()
+        .id_salt("dg_cross_check_scroll")
+        .max_height(160.0)
+        .show(ui, |ui| {
+            for m in report.mismatches.iter().take(MAX_LISTED) {
+                ui.label(
+                    RichText::new(format!(
+                        "{:<10} {} {:<16} {} ≠ {}",
+                        m.pair,
+                        m.open_time.to_datetime().format("%Y-%m-%d %H:%M"),
+                        m.field.name(),
+                        m.cached,
+                        m.reference
+                    ))
+                    .monospace()
+                    .small(),
+                );
+            }
+            if report.mismatches.len() > MAX_LISTED {
+                ui.label(
+                    RichText::new(format!(
+                        "+{} {}",
+                        report.mismatches.len() - MAX_LISTED,
+                        UI_TEXT.sp_more
+                    ))
+                    .small(),
+                );
+            }
+            for (pair, error) in report.errors.iter().take(MAX_LISTED) {
+                ui.label(
+                    RichText::new(format!("{:<10} {}", pair, error))
+                        .small()
+                        .color(PLOT_CONFIG.color_warning),
+                );
+            }
+        });
+}
+
+/// Reads a path density against the thin/dense thresholds the plot colors by.
+fn path_density_label(density: f64) -> &'static str {
+    if density < PATH_DENSITY_THIN {
+        &UI_TEXT.tf_tt_path_thin
+    } else if density > PATH_DENSITY_DENSE {
+        &UI_TEXT.tf_tt_path_dense
+    } else {
+        &UI_TEXT.tf_tt_path_normal
+    }
+}
+
+/// Simulation context for a Trade Finder row, so candidates can be compared without opening
+/// the explainer.
+fn render_opportunity_tooltip(
+    ui: &mut Ui,
+    op: &TradeOpportunity,
+    current_price: Price,
+    profile: &TradeProfile,
+) {
+    let sim = &op.simulation;
+    let ms = &op.market_state;
+    ui.label(
+        RichText::new(format!(
+            "{} {} · {}",
+            op.pair_name,
+            op.direction.to_string().to_uppercase(),
+            op.strategy
+        ))
+        .strong(),
+    );
+    let sections = [
+        (
+            None,
+            vec![
+                (&UI_TEXT.tf_tt_grade, op.grade().to_string()),
+                (&UI_TEXT.tf_tt_samples, sim.sample_size.to_string()),
+                (
+                    &UI_TEXT.tf_tt_target_stop,
+                    format!(
+                        "{:+.2}% / {:+.2}%",
+                        op.price_move(&op.target_price) * 100.0,
+                        op.price_move(&op.stop_price) * 100.0
+                    ),
+                ),
+                (
+                    &UI_TEXT.label_success_rate,
+                    format!("{} ±{}", sim.success_rate, sim.success_rate_margin()),
+                ),
+                (
+                    &UI_TEXT.tf_tt_avg_duration,
+                    TimeUtils::format_duration(op.avg_duration.value()),
+                ),
+                (
+                    &UI_TEXT.label_expectancy,
+                    format!(
+                        "{:+.2}R · {} {:.0}%",
+                        sim.expectancy_r(),
+                        UI_TEXT.label_kelly,
+                        sim.kelly_fraction() * 100.0
+                    ),
+                ),
+                (
+                    &UI_TEXT.label_excess_aroi,
+                    format!(
+                        "{} ({} {})",
+                        op.live_excess_aroi(current_price, profile),
+                        UI_TEXT.label_vs_benchmark,
+                        profile.benchmark_aroi_pct
+                    ),
+                ),
+                (
+                    &UI_TEXT.hover_time_limit,
+                    format!(
+                        "{} (×{:.2})",
+                        TimeUtils::format_duration(op.max_duration.value()),
+                        op.duration_vol_factor
+                    ),
+                ),
+                (
+                    &UI_TEXT.tf_tt_path_density,
+                    op.path_density.map_or_else(
+                        || "-".to_string(),
+                        |d| format!("{:.2}× ({})", d, path_density_label(d)),
+                    ),
+                ),
+            ],
+        ),
+        (
+            Some(&UI_TEXT.tf_tt_fingerprint),
+            vec![
+                (&UI_TEXT.tf_tt_volatility, ms.volatility_pct.to_string()),
+                (&UI_TEXT.tf_tt_momentum, ms.momentum_pct.to_string()),
+                (
+                    &UI_TEXT.tf_tt_rel_volume,
+                    format!("{:.2}×", ms.relative_volume.value()),
+                ),
+            ],
+        ),
+    ];
+    for (i, (heading, rows)) in sections.into_iter().enumerate() {
+        if let Some(heading) = heading {
+            ui.separator();
+            ui.label(RichText::new(heading).small().strong());
+        }
+        Grid::new(("tf_tooltip_grid", i))
+            .num_columns(2)
+            .spacing([12.0, 2.0])
+            .show(ui, |ui| {
+                for (label, value) in rows {
+                    ui.label(label);
+                    ui.label(RichText::new(value).monospace());
+                    ui.end_row();
+                }
+            });
+    }
+    ui.label(
+        RichText::new(&UI_TEXT.tf_tt_grade_note)
+            .small()
+            .color(PLOT_CONFIG.color_text_subdued),
+    );
+}
+
+/// Target and stop as signed price moves from entry (a short's target reads negative), plus
+/// the reward-to-risk ratio.
+fn render_trade_distances(ui: &mut Ui, op: &TradeOpportunity) {
+    ui.label(
+        RichText::new(format!(
+            "{} {:+.2}%",
+            UI_TEXT.label_target,
+            op.price_move(&op.target_price) * 100.0
+        ))
+        .small()
+        .color(op.direction.color()),
+    );
+    ui.label(
+        RichText::new(format!(
+            "{} {:+.2}%",
+            UI_TEXT.label_stop_loss_short,
+            op.price_move(&op.stop_price) * 100.0
+        ))
+        .small()
+        .color(PLOT_CONFIG.color_stop_loss),
+    );
+    ui.label(
+        RichText::new(format!(
+            "{} {:.2}",
+            UI_TEXT.label_risk_reward, op.simulation.risk_reward_ratio
+        ))
+        .small()
+        .color(PLOT_CONFIG.color_text_subdued),
+    );
+}
+
+/// Most opportunities drawn in the timeline strip; the ones closest to timing out win.
+const TIMELINE_MAX_ROWS: usize = 8;
+
+/// One bar per opportunity from when it was found to when it times out, on a shared real-time
+/// axis with a "now" marker. The remaining part turns amber in the last fifth of its life.
+/// Returns the opportunity clicked, if any.
+fn render_opportunity_timeline(
+    ui: &mut Ui,
+    ops: &[&TradeOpportunity],
+    selected_id: Option<&str>,
+) -> Option<TradeOpportunity> {
+    let mut ops = ops.to_vec();
+    ops.sort_by_key(|op| op.expires_at());
+    let hidden = ops.len().saturating_sub(TIMELINE_MAX_ROWS);
+    ops.truncate(TIMELINE_MAX_ROWS);
+
+    let now = TimeUtils::now_utc();
+    let t0 = ops
+        .iter()
+        .map(|op| op.created_at)
+        .min()
+        .unwrap_or(now)
+        .min(now);
+    let t1 = ops
+        .iter()
+        .map(|op| op.expires_at())
+        .max()
+        .unwrap_or(now)
+        .max(now);
+    let span_ms = (t1 - t0).num_milliseconds().max(1) as f32;
+
+    let row_h = 12.0;
+    let row_gap = 3.0;
+    let axis_h = 12.0;
+    let height = ops.len() as f32 * (row_h + row_gap) + axis_h;
+    let (rect, _) = ui.allocate_exact_size(vec2(ui.available_width(), height), Sense::hover());
+    let painter = ui.painter_at(rect);
+    let x_at = |t: DateTime<Utc>| {
+        rect.left() + (t - t0).num_milliseconds() as f32 / span_ms * rect.width()
+    };
+    let font = FontId::proportional(9.0);
+
+    let mut picked = None;
+    for (i, op) in ops.iter().enumerate() {
+        let top = rect.top() + i as f32 * (row_h + row_gap);
+        let expires = op.expires_at();
+        let bar = Rect::from_min_max(
+            pos2(x_at(op.created_at), top),
+            pos2(x_at(expires).max(x_at(op.created_at) + 2.0), top + row_h),
+        );
+        let split_x = x_at(now).clamp(bar.left(), bar.right());
+        let dir_color = op.direction.color();
+        let total_ms = (expires - op.created_at).num_milliseconds().max(1);
+        let left_ms = (expires - now).num_milliseconds();
+        let remaining_color = if left_ms * 5 < total_ms {
+            PLOT_CONFIG.color_warning
+        } else {
+            dir_color
+        };
+        painter.rect_filled(
+            Rect::from_min_max(bar.min, pos2(split_x, bar.bottom())),
+            2.0,
+            dir_color.linear_multiply(0.25),
+        );
+        painter.rect_filled(
+            Rect::from_min_max(pos2(split_x, bar.top()), bar.max),
+            2.0,
+            remaining_color.linear_multiply(0.7),
+        );
+        if selected_id == Some(op.id.as_str()) {
+            painter.rect_stroke(
+                bar,
+                2.0,
+                Stroke::new(1.5, PLOT_CONFIG.color_text_primary),
+                StrokeKind::Outside,
+            );
+        }
+        let remaining = if left_ms > 0 {
+            format!(
+                "{} {}",
+                TimeUtils::format_duration(left_ms),
+                UI_TEXT.tl_remaining
+            )
+        } else {
+            UI_TEXT.tl_expired.clone()
+        };
+        painter.text(
+            pos2(bar.left() + 3.0, bar.center().y),
+            Align2::LEFT_CENTER,
+            format!("{} {}", op.strategy.icon(), remaining),
+            font.clone(),
+            PLOT_CONFIG.color_text_primary,
+        );
+
+        let response = ui
+            .interact(bar, ui.id().with(("timeline_bar", &op.id)), Sense::click())
+            .on_hover_text(format!(
+                "{} {} {}\n{}: {}\n{}: {}",
+                op.direction.to_string().to_uppercase(),
+                UI_TEXT.label_target,
+                op.target_price,
+                UI_TEXT.tl_created,
+                op.created_at.format("%Y-%m-%d %H:%M"),
+                UI_TEXT.tl_expires,
+                expires.format("%Y-%m-%d %H:%M"),
+            ));
+        if response.clicked() {
+            picked = Some((*op).clone());
+        }
+    }
+
+    let now_x = x_at(now);
+    painter.line_segment(
+        [pos2(now_x, rect.top()), pos2(now_x, rect.bottom() - axis_h)],
+        Stroke::new(1.0, PLOT_CONFIG.color_text_neutral),
+    );
+    for (t, align) in [(t0, Align2::LEFT_BOTTOM), (t1, Align2::RIGHT_BOTTOM)] {
+        painter.text(
+            pos2(x_at(t), rect.bottom()),
+            align,
+            t.format("%m-%d %H:%M").to_string(),
+            font.clone(),
+            PLOT_CONFIG.color_text_subdued,
+        );
+    }
+    if hidden > 0 {
+        ui.label(
+            RichText::new(format!("+{} {}", hidden, UI_TEXT.tl_hidden))
+                .small()
+                .color(PLOT_CONFIG.color_text_subdued),
+        )
+        .on_hover_text(&UI_TEXT.tl_hidden_hover);
+    }
+    picked
+}
+
+/// Sector tag of the pair's base asset.
+fn render_category_tag(ui: &mut Ui, pair: &str, size: f32) {
+    ui.label(
+        RichText::new(PairCategory::of_pair(pair).label())
+            .size(size)
+            .color(PLOT_CONFIG.color_text_subdued),
+    )
+    .on_hover_text(&UI_TEXT.tf_category_tag_hover);
+}
+
+fn render_low_evidence_badge(ui: &mut Ui, op: &TradeOpportunity, size: f32) {
+    ui.label(
+        RichText::new(&UI_TEXT.label_low_evidence)
+            .size(size)
+            .strong()
+            .color(PLOT_CONFIG.color_warning),
+    )
+    .on_hover_text(format!(
+        "{}\n{} {} ({} ±{})",
+        UI_TEXT.hover_low_evidence,
+        op.simulation.sample_size,
+        UI_TEXT.label_samples,
+        op.simulation.success_rate,
+        op.simulation.success_rate_margin()
+    ));
+}
+
+/// Warns when a scheduled macro event lands between the target's creation and its time limit.
+fn render_econ_event_badge(ui: &mut Ui, op: &TradeOpportunity, events: &[EconEvent], size: f32) {
+    let start_ms = op.created_at.timestamp_millis();
+    let hits = events_in_window(events, start_ms, start_ms + op.max_duration.value());
+    if hits.is_empty() {
+        return;
+    }
+    let mut hover = UI_TEXT.hover_econ_event.clone();
+    for event in hits {
+        hover.push_str(&format!(
+            "\n{}  {}",
+            TimeUtils::ms_to_datestring(event.time_ms),
+            event.title
+        ));
+    }
+    ui.label(
+        RichText::new(&UI_TEXT.label_econ_event)
+            .size(size)
+            .strong()
+            .color(PLOT_CONFIG.color_warning),
+    )
+    .on_hover_text(hover);
+}
+
+/// Deletes the pairs' stored candles at the base interval so the next start downloads them
+/// afresh. The series loaded this session are kept.
+#[cfg(not(target_arch = "wasm32"))]
+fn purge_kline_cache(pairs: Vec<String>) {
+    let interval = TimeUtils::interval_to_string(BASE_INTERVAL.as_millis() as i64);
+    thread::spawn(move || {
+        let rt = Runtime::new().expect("Failed to create runtime");
+        let limiter = shared_rate_limiter();
+        for pair in pairs {
+            let action = apply_cache_action(CacheAction::Delete, &pair, interval, &limiter);
+            if let Err(e) = rt.block_on(action) {
+                log::error!("Failed to purge cached klines for {}: {:#}", pair, e);
+            }
+        }
+    });
+}
+
+/// `@ entry → target SL stop`, leaving out what the sender didn't give.
+fn external_signal_prices(signal: &ExternalSignal) -> String {
+    let mut parts = Vec::new();
+    if let Some(price) = signal.price {
+        parts.push(format!("@ {}", price));
+    }
+    if let Some(target) = signal.target {
+        parts.push(format!("→ {}", target));
+    }
+    if let Some(stop) = signal.stop {
+        parts.push(format!("{} {}", UI_TEXT.tf_ext_stop, stop));
+    }
+    parts.join(" ")
+}
+
+fn score_type_combo(ui: &mut Ui, id: &str, score_type: &mut ScoreType) {
+    ComboBox::from_id_salt(id)
+        .selected_text(score_type.label())
+        .width(90.0)
+        .show_ui(ui, |ui| {
+            for st in ScoreType::iter() {
+                ui.selectable_value(score_type, st, st.label())
+                    .on_hover_text(st.to_string());
+            }
+        })
+        .response
+        .on_hover_text(&UI_TEXT.tb_score_type_hover);
+}
+
+/// Ratio of short- to long-window volatility beyond which the term structure counts as tilted.
+const VOL_TERM_TILT: f64 = 1.15;
+
+/// Tiny bar chart of 1d / 1w / 1m realized volatility, colored by expansion / contraction.
+fn render_vol_term_sparkline(ui: &mut Ui, term: &VolTermStructure) {
+    let Some(slope) = term.slope() else {
+        return;
+    };
+    let (color, trend) = if slope > VOL_TERM_TILT {
+        (PLOT_CONFIG.color_short, &UI_TEXT.label_vol_expanding)
+    } else if slope < 1.0 / VOL_TERM_TILT {
+        (PLOT_CONFIG.color_info, &UI_TEXT.label_vol_contracting)
+    } else {
+        (PLOT_CONFIG.color_text_neutral, &UI_TEXT.label_vol_steady)
+    };
+
+    let bar_w = 4.0;
+    let height = 12.0;
+    let size = vec2((bar_w + 2.0) * term.points.len() as f32, height);
+    let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+    let max = term
+        .points
+        .iter()
+        .map(|p| p.volatility_pct.value())
+        .fold(f64::EPSILON, f64::max);
+    let painter = ui.painter();
+    for (i, point) in term.points.iter().enumerate() {
+        let h = (point.volatility_pct.value() / max) as f32 * height;
+        let x = rect.left() + i as f32 * (bar_w + 2.0);
+        painter.rect_filled(
+            Rect::from_min_max(pos2(x, rect.bottom() - h), pos2(x + bar_w, rect.bottom())),
+            0.0,
+            color,
+        );
+    }
+
+    let detail: Vec<String> = term
+        .points
+        .iter()
+        .map(|p| format!("{} {}", p.label, p.volatility_pct))
+        .collect();
+    response.on_hover_text(format!("{}\n{}", trend, detail.join(" · ")));
+}
+
+/// Median and middle-half time to target over the journeys that hit, with a histogram of hit
+/// times across the time limit.
+fn render_hit_times(ui: &mut Ui, times: &HitTimes, max_duration_candles: usize) {
+    let format_candles = |candles: f64| {
+        TimeUtils::format_duration((candles * BASE_INTERVAL.as_millis() as f64) as i64)
+    };
+    ui.horizontal(|ui| {
+        ui.label(
+            RichText::new(format!(
+                "{} {} · {} {}–{}",
+                UI_TEXT.label_time_to_target,
+                format_candles(times.median),
+                UI_TEXT.label_middle_half,
+                format_candles(times.p25),
+                format_candles(times.p75)
+            ))
+            .small()
+            .color(PLOT_CONFIG.color_text_subdued),
+        );
+
+        let bar_w = 3.0;
+        let height = 12.0;
+        let size = vec2((bar_w + 1.0) * times.histogram.len() as f32, height);
+        let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+        let busiest = times.histogram.iter().copied().max().unwrap_or(0).max(1);
+        let painter = ui.painter();
+        for (i, &hits) in times.histogram.iter().enumerate() {
+            let h = (hits as f32 / busiest as f32 * height).max(1.0);
+            let x = rect.left() + i as f32 * (bar_w + 1.0);
+            painter.rect_filled(
+                Rect::from_min_max(pos2(x, rect.bottom() - h), pos2(x + bar_w, rect.bottom())),
+                0.0,
+                PLOT_CONFIG.color_profit,
+            );
+        }
+        response.on_hover_text(format!(
+            "{}\n{} {} · 0 – {}",
+            UI_TEXT.hover_hit_times,
+            times.hits(),
+            UI_TEXT.label_hits,
+            format_candles(max_duration_candles as f64)
+        ));
+    });
+}
+
+/// Success rate and expectancy under the managed stop next to a fixed one.
+fn render_stop_management_effect(ui: &mut Ui, op: &TradeOpportunity, fixed_rate: Prob) {
+    let sim = &op.simulation;
+    let managed = sim.expectancy_r();
+    let fixed = sim.fixed_expectancy_r().unwrap_or(managed);
+    ui.label(
+        RichText::new(format!(
+            "{} {} ({} {}) · {:+.2}R ({} {:+.2}R) · {} {}",
+            UI_TEXT.label_break_even_stop,
+            sim.success_rate,
+            UI_TEXT.label_fixed_stop,
+            fixed_rate,
+            managed,
+            UI_TEXT.label_fixed_stop,
+            fixed,
+            sim.break_even_rate,
+            UI_TEXT.label_closed_at_entry
+        ))
+        .small()
+        .color(get_outcome_color(managed - fixed)),
+    )
+    .on_hover_text(&UI_TEXT.hover_break_even_stop);
+}
+
+/// Typical heat (MAE) and run-up (MFE) of the matched journeys, for sizing the stop.
+fn render_excursions(ui: &mut Ui, excursions: &ExcursionStats) {
+    let pct = |v: f64| format!("{:.2}%", v * 100.0);
+    let winners = excursions
+        .winner_adverse_median
+        .map(|v| format!(" · {} -{}", UI_TEXT.label_winners, pct(v)))
+        .unwrap_or_default();
+    ui.label(
+        RichText::new(format!(
+            "{} -{} (p75 -{}){} · {} +{} (p75 +{})",
+            UI_TEXT.label_mae,
+            pct(excursions.adverse_median),
+            pct(excursions.adverse_p75),
+            winners,
+            UI_TEXT.label_mfe,
+            pct(excursions.favorable_median),
+            pct(excursions.favorable_p75)
+        ))
+        .small()
+        .color(PLOT_CONFIG.color_text_subdued),
+    )
+    .on_hover_text(&UI_TEXT.hover_excursions);
+}
+
+/// One week of the heat calendar: brighter = more opportunities, green/red = average outcome.
+fn render_heat_week_cell(ui: &mut Ui, week: &HeatWeek, busiest: usize) {
+    let (rect, response) = ui.allocate_exact_size(vec2(30.0, 30.0), Sense::hover());
+    let fill = match week.avg_return() {
+        Some(avg) if busiest > 0 => apply_opacity(
+            get_outcome_color(avg),
+            0.25 + 0.75 * week.opportunities as f32 / busiest as f32,
+        ),
+        _ if week.opportunities > 0 => PLOT_CONFIG.color_text_subdued,
+        _ => Color32::from_white_alpha(15),
+    };
+    let painter = ui.painter();
+    painter.rect_filled(rect, 3.0, fill);
+    painter.text(
+        rect.center(),
+        Align2::CENTER_CENTER,
+        week.opportunities.to_string(),
+        FontId::monospace(10.0),
+        Color32::BLACK,
+    );
+    response.on_hover_ui(|ui| {
+        ui.label(
+            RichText::new(format!(
+                "{} {}",
+                UI_TEXT.hc_week_of,
+                week.start.to_datetime().format("%Y-%m-%d")
+            ))
+            .strong(),
+        );
+        Grid::new("hc_week_grid")
+            .num_columns(2)
+            .spacing([12.0, 2.0])
+            .show(ui, |ui| {
+                for (label, value) in heat_week_lines(week) {
+                    ui.label(label);
+                    ui.label(RichText::new(value).monospace());
+                    ui.end_row();
+                }
+            });
+    });
+}
+
+/// A filter replayed over the calendar's opportunities, kept vs all vs rejected. Rerun only
+/// when the filter text or the calendar changes.
+fn render_rule_backtest(
+    ui: &mut Ui,
+    calendar: &HeatCalendar,
+    script: &mut OpportunityScript,
+    tf_filter: &str,
+    cache: &mut Option<(String, RuleBacktest)>,
+) {
+    ui.horizontal(|ui| {
+        ui.label(RichText::new(&UI_TEXT.hc_rule_heading).strong())
+            .on_hover_text(&UI_TEXT.hc_rule_hover);
+        if ui
+            .add_enabled(
+                script.filter != tf_filter,
+                Button::new(&UI_TEXT.hc_rule_copy).small(),
+            )
+            .on_hover_text(&UI_TEXT.hc_rule_copy_hover)
+            .clicked()
+        {
+            script.filter = tf_filter.to_string();
+        }
+    });
+    ui.add(
+        TextEdit::singleline(&mut script.filter)
+            .code_editor()
+            .hint_text("aroi > 200 && rr >= 2")
+            .desired_width(f32::INFINITY),
+    );
+    script.refresh();
+    if let Some(error) = script.filter_error() {
+        ui.label(RichText::new(error).small().color(PLOT_CONFIG.color_loss));
+        return;
+    }
+    let Some(filter) = script.filter() else {
+        ui.label(
+            RichText::new(&UI_TEXT.hc_rule_empty)
+                .small()
+                .color(PLOT_CONFIG.color_text_subdued),
+        );
+        return;
+    };
+    if cache.as_ref().is_none_or(|(src, _)| *src != script.filter) {
+        *cache = Some((script.filter.clone(), calendar.backtest_rule(filter)));
+    }
+    let Some((_, backtest)) = cache.as_ref() else {
+        return;
+    };
+    Grid::new("hc_rule_grid")
+        .num_columns(4)
+        .spacing([12.0, 4.0])
+        .striped(true)
+        .show(ui, |ui| {
+            ui.label("");
+            for header in [
+                &UI_TEXT.hc_rule_all,
+                &UI_TEXT.hc_rule_kept,
+                &UI_TEXT.hc_rule_rejected,
+            ] {
+                ui.label(RichText::new(header).strong());
+            }
+            ui.end_row();
+            let columns =
+                [&backtest.all, &backtest.matched, &backtest.rejected].map(heat_week_lines);
+            for (row, (label, _)) in columns[0].iter().enumerate() {
+                ui.label(*label);
+                for column in &columns {
+                    ui.label(RichText::new(&column[row].1).monospace());
+                }
+                ui.end_row();
+            }
+        });
+    if let Some((kept, all)) = backtest.matched.avg_return().zip(backtest.all.avg_return()) {
+        let lift = kept - all;
+        let color = if lift >= 0.0 {
+            PLOT_CONFIG.color_profit
+        } else {
+            PLOT_CONFIG.color_loss
+        };
+        ui.label(
+            RichText::new(format!("{} {:+.2}%", UI_TEXT.hc_rule_lift, lift * 100.0)).color(color),
+        );
+    }
+}
+
+fn heat_week_lines(week: &HeatWeek) -> [(&'static str, String); 4] {
+    let pct = |v: Option<f64>| v.map_or("-".to_string(), |v| format!("{:+.2}%", v * 100.0));
+    [
+        (&UI_TEXT.hc_opportunities, week.opportunities.to_string()),
+        (
+            &UI_TEXT.hc_outcomes,
+            format!(
+                "{} / {} / {} / {}",
+                week.wins, week.losses, week.timeouts, week.open
+            ),
+        ),
+        (
+            &UI_TEXT.hc_win_rate,
+            week.win_rate()
+                .map_or("-".to_string(), |r| format!("{:.0}%", r * 100.0)),
+        ),
+        (&UI_TEXT.hc_avg_return, pct(week.avg_return())),
+    ]
+}
+
+fn stress_outcome_label(outcome: StressOutcome) -> (&'static str, Color32) {
+    match outcome {
+        StressOutcome::TargetHit => (&UI_TEXT.st_target_hit, PLOT_CONFIG.color_profit),
+        StressOutcome::StopHit => (&UI_TEXT.st_stop_hit, PLOT_CONFIG.color_loss),
+        StressOutcome::Expired => (&UI_TEXT.st_expired, PLOT_CONFIG.color_warning),
+        StressOutcome::Open => (&UI_TEXT.st_open, PLOT_CONFIG.color_text_subdued),
+    }
+}
+
+fn render_price_evaluation_row(ui: &mut Ui, evaluation: &PriceEvaluation, live: Option<Price>) {
+    ui.label(RichText::new(evaluation.price.to_string()).monospace());
+    match live.filter(|p| p.is_positive()) {
+        Some(live) => ui.label(
+            RichText::new(format!(
+                "{:+.2}%",
+                (evaluation.price.value() / live.value() - 1.0) * 100.0
+            ))
+            .monospace(),
+        ),
+        None => ui.label("-"),
+    };
+    match &evaluation.result {
+        Ok(opportunities) => {
+            ui.label(RichText::new(opportunities.len().to_string()).monospace());
+            match evaluation.best() {
+                Some(op) => {
+                    let roi = op.expected_roi();
+                    ui.label(
+                        RichText::new(format!(
+                            "{} → {} · {} · {} · {:+.2}R",
+                            op.direction,
+                            op.target_price,
+                            roi,
+                            TradeProfile::calc_annualized_roi(roi, op.avg_duration),
+                            op.simulation.expectancy_r()
+                        ))
+                        .small()
+                        .color(get_outcome_color(roi.value())),
+                    );
+                }
+                None => {
+                    ui.label(
+                        RichText::new(&UI_TEXT.wi_none)
+                            .small()
+                            .color(PLOT_CONFIG.color_text_subdued),
+                    );
+                }
+            }
+        }
+        Err(e) => {
+            ui.label("-");
+            ui.label(RichText::new(e).small().color(PLOT_CONFIG.color_warning));
+        }
+    }
+    ui.end_row();
+}
+
+/// Every column after the sector name.
+fn render_sector_row(ui: &mut Ui, sector: &SectorStrength) {
+    let cell = |text: String| RichText::new(text).small().monospace();
+    ui.label(cell(sector.pairs.to_string()));
+    ui.label(cell(sector.momentum.to_string()).color(get_momentum_color(sector.momentum.value())));
+    ui.label(cell(sector.volatility.to_string()));
+    ui.label(cell(format!("{:.0}%", sector.breadth * 100.0)));
+    ui.label(cell(sector.opportunities.to_string()));
+    let ev_color = if sector.positive_ev > 0 {
+        PLOT_CONFIG.color_profit
+    } else {
+        PLOT_CONFIG.color_text_subdued
+    };
+    ui.label(cell(sector.positive_ev.to_string()).color(ev_color));
+    match &sector.best {
+        Some((pair, expectancy)) => ui.label(cell(format!("{} {:+.2}R", pair, expectancy))),
+        None => ui.label(cell("-".to_string())),
+    };
+    ui.end_row();
+}
+
+/// One basket leg row; true when the pair was clicked.
+fn render_basket_leg(ui: &mut Ui, leg: &BasketLeg) -> bool {
+    let op = &leg.op;
+    let clicked = ui
+        .link(RichText::new(&op.pair_name).small())
+        .on_hover_text(&UI_TEXT.bk_select_hover)
+        .clicked();
+    ui.label(
+        RichText::new(op.direction.to_string())
+            .small()
+            .color(op.direction.color()),
+    );
+    ui.label(
+        RichText::new(format!("{:+.2}R", op.simulation.expectancy_r()))
+            .small()
+            .monospace(),
+    );
+    ui.label(
+        RichText::new(format!(
+            "{:.0}%",
+            op.simulation.success_rate.value() * 100.0
+        ))
+        .small()
+        .monospace(),
+    );
+    ui.label(
+        RichText::new(format!("{:.1}%", leg.stake * 100.0))
+            .small()
+            .monospace(),
+    );
+    match &leg.max_correlation {
+        Some((with, c)) => ui
+            .label(RichText::new(format!("{:+.2}", c)).small().monospace())
+            .on_hover_text(format!("{} {}", UI_TEXT.bk_correlation_with, with)),
+        None => ui.label(RichText::new("-").small()),
+    };
+    ui.end_row();
+    clicked
+}
+
+fn render_stress_row(ui: &mut Ui, row: &StressRow) {
+    let (outcome, color) = stress_outcome_label(row.outcome);
+    let pnl_color = if row.pnl_pct >= 0.0 {
+        PLOT_CONFIG.color_profit
+    } else {
+        PLOT_CONFIG.color_loss
+    };
+    ui.label(RichText::new(&row.pair).small())
+        .on_hover_text(&row.opportunity_id);
+    ui.label(RichText::new(row.direction.to_string()).small());
+    ui.label(
+        RichText::new(format!("{:.2}", row.beta))
+            .small()
+            .monospace(),
+    );
+    ui.label(RichText::new(outcome).small().color(color));
+    ui.label(
+        RichText::new(TimeUtils::format_duration(
+            row.decided_after.as_millis() as i64
+        ))
+        .small()
+        .monospace(),
+    );
+    ui.label(
+        RichText::new(format!("{:+.2}%", row.pnl_pct * 100.0))
+            .small()
+            .monospace()
+            .color(pnl_color),
+    );
+    ui.label(
+        RichText::new(format!("{:+.2}%", row.worst_pct * 100.0))
+            .small()
+            .monospace(),
+    );
+    ui.end_row();
+}
+
+fn stress_report_text(report: &StressReport) -> String {
+    let mut text = format!(
+        "{} ({})\n{}: {}  {}: {}  {}: {}  {}: {}  {}: {:+.2}%\n",
+        report.scenario.label(),
+        TimeUtils::ms_to_datetime(report.ran_at.value()).format("%Y-%m-%d %H:%M UTC"),
+        UI_TEXT.st_target_hit,
+        report.count(StressOutcome::TargetHit),
+        UI_TEXT.st_stop_hit,
+        report.count(StressOutcome::StopHit),
+        UI_TEXT.st_expired,
+        report.count(StressOutcome::Expired),
+        UI_TEXT.st_open,
+        report.count(StressOutcome::Open),
+        UI_TEXT.st_avg_pnl,
+        report.avg_pnl_pct() * 100.0
+    );
+    for row in &report.rows {
+        text.push_str(&format!(
+            "{:<12} {:<5} beta {:>5.2} {:<10} after {:>4} pnl {:+.2}% worst {:+.2}%\n",
+            row.pair,
+            row.direction.to_string(),
+            row.beta,
+            stress_outcome_label(row.outcome).0,
+            TimeUtils::format_duration(row.decided_after.as_millis() as i64),
+            row.pnl_pct * 100.0,
+            row.worst_pct * 100.0
+        ));
+    }
+    text
+}
@@ -1,16 +1,22 @@
 use std::sync::LazyLock;
 
+pub const ICON_BELL: &str = "\u{f0f3}";
 pub const ICON_CANDLE: &str = "\u{f11c9}";
 pub const ICON_CLOCK: &str = "\u{f0954}";
 pub const ICON_CLOSE_ALL: &str = "\u{eac1}";
 pub const ICON_CLOSE: &str = "\u{f00d}";
 pub const ICON_COG: &str = "\u{f013}";
+pub const ICON_DATABASE: &str = "\u{f01bc}";
 pub const ICON_DOLLAR_BAG: &str = "\u{ef8d}";
 pub const ICON_EYE: &str = "\u{f0208}";
 pub const ICON_KEYBOARD: &str = "\u{f0313}";
 pub const ICON_LOCKED: &str = "\u{ea75}";
+pub const ICON_MENU: &str = "\u{f0c9}";
 pub const ICON_ONE_HORIZONTAL: &str = "\u{f45b}";
+pub const ICON_PAUSE: &str = "\u{f04c}";
+pub const ICON_PLAY: &str = "\u{f04b}";
 pub const ICON_POINT_RIGHT: &str = "\u{f02c7}";
+pub const ICON_PROFILE: &str = "\u{f007}";
 pub const ICON_PULSE: &str = "\u{e234}";
 pub const ICON_QUEUE: &str = "\u{f1571}";
 pub const ICON_RECENTER: &str = "\u{f0622}";
@@ -18,10 +24,12 @@ pub const ICON_RULER: &str = "\u{e21b}";
 pub const ICON_SEGMENTED_TIME: &str = "\u{f084e}";
 pub const ICON_SORT_ASC: &str = "\u{f0de}";
 pub const ICON_SORT_DESC: &str = "\u{f0dd}";
+pub const ICON_STAR: &str = "\u{f005}";
 pub const ICON_STRATEGY_AROI: &str = "\u{f046e}";
 pub const ICON_STRATEGY_BALANCED: &str = "\u{f24e}";
 pub const ICON_STRATEGY_LOG_GROWTH: &str = "\u{f148}";
 pub const ICON_STRATEGY_ROI: &str = "\u{ef08}";
+pub const ICON_TABLE: &str = "\u{f04eb}";
 pub const ICON_TARGET: &str = "\u{f04fe}";
 pub const ICON_TIME_MACHINE: &str = "\u{f11ef}";
 pub const ICON_TREND_DOWN: &str = "\u{f0533}";
@@ -32,6 +40,98 @@ pub const ICON_WARNING: &str = "\u{ea6c}";
 pub const ICON_Y_AXIS: &str = "\u{f0e79}";
 
 pub struct UiText {
+    pub ae_add_point: String,
+    pub ae_apply: String,
+    pub ae_curve_decay: String,
+    pub ae_curve_lookback: String,
+    pub ae_discard: String,
+    pub ae_ph: String,
+    pub ae_previewing: String,
+    pub ae_reset: String,
+    pub ae_value: String,
+    pub ae_window_title: String,
+    pub ak_can_trade: String,
+    pub ak_check: String,
+    pub ak_check_hover: String,
+    pub ak_check_ok: String,
+    pub ak_configured: String,
+    pub ak_funded_assets: String,
+    pub ak_heading: String,
+    pub ak_heading_hover: String,
+    pub ak_key: String,
+    pub ak_public: String,
+    pub ak_read_only: String,
+    pub ak_remove: String,
+    pub ak_removed: String,
+    pub ak_save: String,
+    pub ak_save_hover: String,
+    pub ak_saved: String,
+    pub ak_scope_note: String,
+    pub ak_secret: String,
+    pub an_cancel: String,
+    pub an_delete_hover: String,
+    pub an_hint: String,
+    pub an_notes: String,
+    pub an_save: String,
+    pub an_title: String,
+    pub bk_ago: String,
+    pub bk_built: String,
+    pub bk_col_correlation: String,
+    pub bk_col_dir: String,
+    pub bk_col_expectancy: String,
+    pub bk_col_pair: String,
+    pub bk_col_stake: String,
+    pub bk_col_success: String,
+    pub bk_correlating: String,
+    pub bk_correlation_with: String,
+    pub bk_empty: String,
+    pub bk_expectancy: String,
+    pub bk_expectancy_hover: String,
+    pub bk_intro: String,
+    pub bk_max_correlation: String,
+    pub bk_max_correlation_hover: String,
+    pub bk_positions: String,
+    pub bk_positions_hover: String,
+    pub bk_risk: String,
+    pub bk_risk_budget: String,
+    pub bk_risk_budget_hover: String,
+    pub bk_risk_hover: String,
+    pub bk_select_hover: String,
+    pub bk_skip_correlated: String,
+    pub bk_skip_over_budget: String,
+    pub bk_skip_unknown: String,
+    pub bk_skipped: String,
+    pub bk_skipped_hover: String,
+    pub bk_suggest: String,
+    pub bk_suggest_hover: String,
+    pub bk_window_title: String,
+    pub ci_ago: String,
+    pub ci_col_candles: String,
+    pub ci_col_interval: String,
+    pub ci_col_pair: String,
+    pub ci_col_range: String,
+    pub ci_col_size: String,
+    pub ci_col_updated: String,
+    pub ci_database: String,
+    pub ci_delete: String,
+    pub ci_delete_confirm: String,
+    pub ci_delete_hover: String,
+    pub ci_deleted: String,
+    pub ci_empty: String,
+    pub ci_export: String,
+    pub ci_export_hover: String,
+    pub ci_failed: String,
+    pub ci_files_heading: String,
+    pub ci_intro: String,
+    pub ci_mock: String,
+    pub ci_refresh: String,
+    pub ci_refresh_hover: String,
+    pub ci_refreshed: String,
+    pub ci_rescan: String,
+    pub ci_scan_failed: String,
+    pub ci_size_hover: String,
+    pub ci_updated_hover: String,
+    pub ci_window_title: String,
     pub cp_analyzing: String,
     pub cp_calculating_zones: String,
     pub cp_init_engine: String,
@@ -54,15 +154,211 @@ pub struct UiText {
     pub cr_nav_return_prefix: String,
     pub cr_nav_show_all: String,
     pub cr_price: String,
+    pub cr_stats_change: String,
+    pub cr_stats_hover: String,
+    pub cr_stats_no_zones: String,
+    pub cr_stats_range: String,
+    pub cr_stats_taker_buy: String,
+    pub cr_stats_title: String,
+    pub cr_stats_volatility: String,
+    pub cr_stats_volume: String,
+    pub cr_stats_zones: String,
     pub cr_title_1: String,
     pub cr_title_2: String,
+    pub cw_col_pair: String,
+    pub cw_empty: String,
+    pub cw_intro: String,
+    pub cw_title: String,
+    pub cz_alert: String,
+    pub cz_bottom: String,
+    pub cz_label_hint: String,
+    pub cz_title: String,
+    pub cz_top: String,
+    pub dg_bundle: String,
+    pub dg_bundle_hover: String,
+    pub dg_col_avg: String,
+    pub dg_col_count: String,
+    pub dg_col_cpu: String,
+    pub dg_col_job: String,
+    pub dg_col_max: String,
+    pub dg_cross_check: String,
+    pub dg_cross_check_candles: String,
+    pub dg_cross_check_clean: String,
+    pub dg_cross_check_errors: String,
+    pub dg_cross_check_hover: String,
+    pub dg_cross_check_mismatches: String,
+    pub dg_cross_check_pairs: String,
+    pub dg_cross_check_per_pair: String,
+    pub dg_cross_check_suspects: String,
+    pub dg_largest_models: String,
+    pub dg_models: String,
+    pub dg_process_rss: String,
+    pub dg_timeseries: String,
+    pub dg_window_title: String,
+    pub dg_worker_time: String,
+    pub ds_drop_hint: String,
+    pub ds_import_failed: String,
+    pub ds_pairs: String,
+    pub ds_saved_import: String,
+    pub ds_saved_import_desc: String,
+    pub ds_subtitle: String,
+    pub ds_title: String,
     pub error_analysis_failed: String,
+    pub error_apply_viable_ph: String,
+    pub error_export_failed: String,
+    pub error_failures_in_row: String,
     pub error_insufficient_data_body: String,
     pub error_no_model: String,
     pub error_no_pair_selected: String,
+    pub error_retry_in: String,
+    pub es_batch_window: String,
+    pub es_batch_window_hover: String,
+    pub es_hk_cache_files: String,
+    pub es_hk_compact: String,
+    pub es_hk_compact_hover: String,
+    pub es_hk_expired: String,
+    pub es_hk_failed: String,
+    pub es_hk_heading: String,
+    pub es_hk_heading_hover: String,
+    pub es_hk_keep: String,
+    pub es_hk_keep_all: String,
+    pub es_hk_keep_hover: String,
+    pub es_hk_mock: String,
+    pub es_hk_on_startup: String,
+    pub es_hk_orphaned: String,
+    pub es_hk_orphans: String,
+    pub es_hk_orphans_hover: String,
+    pub es_hk_reclaimed: String,
+    pub es_hk_run: String,
+    pub es_hk_run_hover: String,
+    pub es_hk_running: String,
+    pub es_idle_after: String,
+    pub es_idle_after_hover: String,
+    pub es_idle_background_repaint: String,
+    pub es_idle_background_repaint_hover: String,
+    pub es_idle_enabled: String,
+    pub es_idle_enabled_hover: String,
+    pub es_idle_heading: String,
+    pub es_idle_hold_recalcs: String,
+    pub es_idle_hold_recalcs_hover: String,
+    pub es_idle_repaint: String,
+    pub es_idle_repaint_hover: String,
+    pub es_keep_taken: String,
+    pub es_keep_taken_hover: String,
+    pub es_ledger_added: String,
+    pub es_ledger_conflict_hover: String,
+    pub es_ledger_conflict_keep_local: String,
+    pub es_ledger_conflict_prefer_imported: String,
+    pub es_ledger_count: String,
+    pub es_ledger_export: String,
+    pub es_ledger_export_hover: String,
+    pub es_ledger_heading: String,
+    pub es_ledger_import: String,
+    pub es_ledger_import_failed: String,
+    pub es_ledger_import_hover: String,
+    pub es_ledger_merged: String,
+    pub es_ledger_path_hint: String,
+    pub es_ledger_renamed: String,
+    pub es_ledger_transfer_heading: String,
+    pub es_ledger_transfer_hover: String,
+    pub es_max_age: String,
+    pub es_max_age_hover: String,
+    pub es_max_per_pair: String,
+    pub es_max_per_pair_hover: String,
+    pub es_min_interval: String,
+    pub es_min_interval_hover: String,
+    pub es_pause: String,
+    pub es_pause_hover: String,
+    pub es_prune_interval: String,
+    pub es_prune_interval_hover: String,
+    pub es_purge: String,
+    pub es_purge_collisions: String,
+    pub es_purge_expired: String,
+    pub es_purge_hover: String,
+    pub es_purge_none: String,
+    pub es_purge_over_limit: String,
+    pub es_purge_removed: String,
+    pub es_sw_clear: String,
+    pub es_sw_copy_token: String,
+    pub es_sw_copy_url: String,
+    pub es_sw_copy_url_hover: String,
+    pub es_sw_enabled: String,
+    pub es_sw_failed: String,
+    pub es_sw_heading: String,
+    pub es_sw_heading_hover: String,
+    pub es_sw_in_trade_finder: String,
+    pub es_sw_listening: String,
+    pub es_sw_port: String,
+    pub es_sw_port_locked: String,
+    pub es_sw_received: String,
+    pub es_sw_regenerate_token: String,
+    pub es_sw_regenerate_token_hover: String,
+    pub es_sw_token: String,
+    pub es_sw_token_hover: String,
+    pub es_window_title: String,
+    pub gl_aroi: String,
+    pub gl_aroi_def: String,
+    pub gl_coverage: String,
+    pub gl_coverage_def: String,
+    pub gl_decay: String,
+    pub gl_decay_def: String,
+    pub gl_grade: String,
+    pub gl_grade_def: String,
+    pub gl_help_hover: String,
+    pub gl_momentum: String,
+    pub gl_momentum_def: String,
+    pub gl_no_match: String,
+    pub gl_ph: String,
+    pub gl_ph_def: String,
+    pub gl_rel_volume: String,
+    pub gl_rel_volume_def: String,
+    pub gl_risk_reward: String,
+    pub gl_risk_reward_def: String,
+    pub gl_roi: String,
+    pub gl_roi_def: String,
+    pub gl_search: String,
+    pub gl_sticky_zone: String,
+    pub gl_sticky_zone_def: String,
+    pub gl_success_rate: String,
+    pub gl_success_rate_def: String,
+    pub gl_volatility: String,
+    pub gl_volatility_def: String,
+    pub gl_window_title: String,
+    pub hc_avg_return: String,
+    pub hc_empty: String,
+    pub hc_intro: String,
+    pub hc_opportunities: String,
+    pub hc_outcomes: String,
+    pub hc_rule_all: String,
+    pub hc_rule_copy: String,
+    pub hc_rule_copy_hover: String,
+    pub hc_rule_empty: String,
+    pub hc_rule_heading: String,
+    pub hc_rule_hover: String,
+    pub hc_rule_kept: String,
+    pub hc_rule_lift: String,
+    pub hc_rule_rejected: String,
+    pub hc_run: String,
+    pub hc_run_hover: String,
+    pub hc_week_of: String,
+    pub hc_weeks_label: String,
+    pub hc_win_rate: String,
+    pub hc_window_title: String,
+    pub hover_break_even_stop: String,
+    pub hover_custom_zone_targets: String,
+    pub hover_econ_event: String,
+    pub hover_excursions: String,
+    pub hover_expectancy: String,
+    pub hover_export_analysis: String,
+    pub hover_hit_times: String,
+    pub hover_ladder_targets: String,
+    pub hover_low_evidence: String,
     pub hover_scroll_to_selected_target: String,
+    pub hover_taken: String,
+    pub hover_time_limit: String,
     pub icon_close: String,
     pub icon_long: String,
+    pub icon_profile: String,
     pub icon_short: String,
     pub icon_sort_asc: String,
     pub icon_sort_desc: String,
@@ -70,9 +366,55 @@ pub struct UiText {
     pub icon_strategy_balanced: String,
     pub icon_strategy_log_growth: String,
     pub icon_strategy_roi: String,
+    pub icon_watchlist: String,
+    pub ind_add: String,
+    pub ind_color_hover: String,
+    pub ind_delete: String,
+    pub ind_delete_hover: String,
+    pub ind_enabled_hover: String,
+    pub ind_fields_hover: String,
+    pub ind_intro: String,
+    pub ind_no_pair: String,
+    pub ind_none: String,
+    pub ind_window_title: String,
+    pub iv_body: String,
+    pub iv_follow: String,
+    pub iv_follow_hover: String,
+    pub iv_live_pairs: String,
+    pub iv_no_port: String,
+    pub iv_pid: String,
+    pub iv_profile: String,
+    pub iv_quality: String,
+    pub iv_quit: String,
+    pub iv_title: String,
+    pub iv_unreachable: String,
+    pub jb_date: String,
+    pub jb_distance: String,
+    pub jb_duration: String,
+    pub jb_excursion: String,
+    pub jb_outcome: String,
+    pub jb_stop: String,
+    pub jb_target: String,
+    pub jb_timeout: String,
+    pub jb_title: String,
+    pub jb_window_title: String,
+    pub jb_wins: String,
+    pub kbs_capture_prompt: String,
     pub kbs_close_all_panes: String,
+    pub kbs_conflict: String,
     pub kbs_name_long: String,
     pub kbs_open_close: String,
+    pub kbs_plot_reset: String,
+    pub kbs_plot_segment_next: String,
+    pub kbs_plot_segment_prev: String,
+    pub kbs_plot_zoom_in_y: String,
+    pub kbs_plot_zoom_out_y: String,
+    pub kbs_rebind_hint: String,
+    pub kbs_rebind_hover: String,
+    pub kbs_redo_params: String,
+    pub kbs_reset_defaults: String,
+    pub kbs_tf_next_row: String,
+    pub kbs_tf_prev_row: String,
     pub kbs_toolbar_shortcut_candles: String,
     pub kbs_toolbar_shortcut_gap: String,
     pub kbs_toolbar_shortcut_high_wick: String,
@@ -82,21 +424,58 @@ pub struct UiText {
     pub kbs_toolbar_shortcut_low_wick: String,
     pub kbs_toolbar_shortcut_price_limits: String,
     pub kbs_toolbar_shortcut_targets: String,
+    pub kbs_tuner_apply_station: String,
+    pub kbs_tuner_cancel_station: String,
+    pub kbs_tuner_next_station: String,
+    pub kbs_tuner_prev_station: String,
+    pub kbs_undo_params: String,
+    pub kbs_view_adaptive_editor: String,
+    pub kbs_view_basket: String,
+    pub kbs_view_cache_inspector: String,
+    pub kbs_view_diagnostics: String,
+    pub kbs_view_engine_settings: String,
+    pub kbs_view_glossary: String,
+    pub kbs_view_heat_calendar: String,
+    pub kbs_view_indicator_editor: String,
+    pub kbs_view_sectors: String,
+    pub kbs_view_session_stats: String,
+    pub kbs_view_stress: String,
     pub kbs_view_time_machine: String,
+    pub kbs_view_zone_settings: String,
     pub label_active_target_text: String,
+    pub label_break_even_stop: String,
     pub label_candle: String,
+    pub label_closed_at_entry: String,
     pub label_connected: String,
     pub label_connecting: String,
+    pub label_custom_zone_target: String,
+    pub label_custom_zone_targets: String,
+    pub label_econ_event: String,
+    pub label_excess_aroi: String,
+    pub label_expectancy: String,
+    pub label_export_analysis: String,
+    pub label_exported_to: String,
     pub label_failures: String,
+    pub label_fixed_stop: String,
     pub label_goal: String,
+    pub label_hits: String,
+    pub label_journeys: String,
+    pub label_kelly: String,
+    pub label_ladder_targets: String,
     pub label_long: String,
+    pub label_low_evidence: String,
+    pub label_mae: String,
+    pub label_mfe: String,
+    pub label_middle_half: String,
     pub label_momentum_short: String,
     pub label_no_targets: String,
     pub label_pair: String,
     pub label_queue: String,
     pub label_recenter: String,
+    pub label_risk_reward: String,
     pub label_risk_select: String,
     pub label_roi: String,
+    pub label_samples: String,
     pub label_select_pair: String,
     pub label_short: String,
     pub label_sl_variants_short: String,
@@ -104,44 +483,336 @@ pub struct UiText {
     pub label_stop_loss_short: String,
     pub label_stop_loss: String,
     pub label_success_rate_short: String,
+    pub label_taken: String,
     pub label_target: String,
+    pub label_target_ladder: String,
     pub label_targets_text: String,
+    pub label_time_to_target: String,
+    pub label_vol_contracting: String,
+    pub label_vol_expanding: String,
+    pub label_vol_steady: String,
     pub label_volatility_short: String,
     pub label_volatility: String,
     pub label_volume_24h: String,
+    pub label_vs_benchmark: String,
     pub label_warning: String,
+    pub label_winners: String,
     pub label_working: String,
     pub ls_failed: String,
     pub ls_main: String,
     pub ls_syncing: String,
     pub ls_title: String,
+    pub md_added: String,
+    pub md_changed: String,
+    pub md_coverage: String,
+    pub md_empty: String,
+    pub md_history: String,
+    pub md_history_hover: String,
+    pub md_no_history: String,
+    pub md_opportunities: String,
+    pub md_removed: String,
+    pub md_resistance: String,
+    pub md_select: String,
+    pub md_sticky: String,
+    pub md_support: String,
+    pub md_window_title: String,
+    pub md_zones: String,
+    pub nc_button: String,
+    pub nc_clear: String,
+    pub nc_empty: String,
+    pub nc_filter_matched: String,
+    pub nc_hover: String,
+    pub nc_price_alert: String,
+    pub nc_title: String,
+    pub nc_zone_entered: String,
+    pub pf_create: String,
+    pub pf_create_hover: String,
+    pub pf_menu_hover: String,
+    pub pf_new_hint: String,
+    pub ph_slider_below_min: String,
+    pub ph_slider_candles: String,
+    pub ph_slider_coverage: String,
+    pub ph_slider_label: String,
+    pub plot_alert_label: String,
+    pub plot_ctx_add_line: String,
+    pub plot_ctx_add_zone: String,
+    pub plot_ctx_annotate: String,
+    pub plot_ctx_center: String,
+    pub plot_ctx_clear_marks: String,
+    pub plot_ctx_copy_price: String,
+    pub plot_ctx_merge_above: String,
+    pub plot_ctx_merge_below: String,
+    pub plot_ctx_set_alert: String,
+    pub plot_ctx_simulate: String,
+    pub plot_ctx_simulate_correlated: String,
+    pub plot_ctx_split_zone: String,
+    pub plot_ctx_what_if: String,
+    pub plot_custom_zone_alert: String,
+    pub plot_external_tag: String,
+    pub plot_ghost_label: String,
     pub plot_missing_klines: String,
     pub plot_x_axis_gap: String,
     pub plot_x_axis: String,
     pub plot_y_axis: String,
+    pub plot_zone_from_above: String,
+    pub plot_zone_from_below: String,
+    pub plot_zone_touches: String,
+    pub score_body_only: String,
+    pub score_close_only: String,
+    pub score_full_candle: String,
+    pub score_high_wick_count: String,
+    pub score_low_wick_count: String,
+    pub score_taker_delta: String,
+    pub score_wick_only: String,
+    pub sec_col_best: String,
+    pub sec_col_best_hover: String,
+    pub sec_col_momentum: String,
+    pub sec_col_momentum_hover: String,
+    pub sec_col_pairs: String,
+    pub sec_col_pairs_hover: String,
+    pub sec_col_positive_ev: String,
+    pub sec_col_positive_ev_hover: String,
+    pub sec_col_rising: String,
+    pub sec_col_rising_hover: String,
+    pub sec_col_sector: String,
+    pub sec_col_sector_hover: String,
+    pub sec_col_targets: String,
+    pub sec_col_targets_hover: String,
+    pub sec_col_volatility: String,
+    pub sec_col_volatility_hover: String,
+    pub sec_intro: String,
+    pub sec_most_actionable: String,
+    pub sec_positive_ev_targets: String,
+    pub sec_window_title: String,
+    pub sp_analysis_paused: String,
+    pub sp_batched: String,
+    pub sp_clock_skew: String,
+    pub sp_clock_skew_hover: String,
+    pub sp_compact: String,
+    pub sp_compact_hover: String,
     pub sp_coverage_resistance: String,
+    pub sp_coverage_resistance_short: String,
     pub sp_coverage_sticky: String,
+    pub sp_coverage_sticky_short: String,
     pub sp_coverage_support: String,
+    pub sp_coverage_support_short: String,
     pub sp_coverage: String,
+    pub sp_coverage_short: String,
     pub sp_live_mode: String,
+    pub sp_live_mode_short: String,
+    pub sp_mock_exchange: String,
+    pub sp_mock_exchange_hover: String,
+    pub sp_more: String,
+    pub sp_more_hover: String,
+    pub sp_pause: String,
     pub sp_price: String,
+    pub sp_resume_hover: String,
+    pub sp_seg_candles: String,
+    pub sp_seg_coverage: String,
+    pub sp_seg_heading: String,
+    pub sp_seg_network: String,
+    pub sp_seg_price: String,
+    pub sp_seg_system: String,
+    pub sp_seg_zone_size: String,
     pub sp_stream_status: String,
+    pub sp_stream_status_short: String,
+    pub sp_volatility_short: String,
     pub sp_zone_size: String,
+    pub sp_zone_size_short: String,
+    pub ss_best_roi: String,
+    pub ss_created: String,
+    pub ss_expired: String,
+    pub ss_none: String,
+    pub ss_recalcs: String,
+    pub ss_top_churn: String,
+    pub ss_uptime: String,
+    pub ss_window_title: String,
+    pub ss_worst_roi: String,
+    pub st_avg_pnl: String,
+    pub st_col_after: String,
+    pub st_col_beta: String,
+    pub st_col_dir: String,
+    pub st_col_outcome: String,
+    pub st_col_pair: String,
+    pub st_col_pnl: String,
+    pub st_col_worst: String,
+    pub st_copy: String,
+    pub st_copy_hover: String,
+    pub st_empty: String,
+    pub st_expired: String,
+    pub st_flash_crash: String,
+    pub st_flash_crash_hover: String,
+    pub st_intro: String,
+    pub st_open: String,
+    pub st_squeeze: String,
+    pub st_squeeze_hover: String,
+    pub st_stop_hit: String,
+    pub st_target_hit: String,
+    pub st_weekend_chop: String,
+    pub st_weekend_chop_hover: String,
+    pub st_window_title: String,
+    pub tb_anomalies: String,
+    pub tb_anomalies_hover: String,
     pub tb_candles: String,
+    pub tb_custom_zones: String,
+    pub tb_custom_zones_hover: String,
+    pub tb_external_signals: String,
+    pub tb_external_signals_hover: String,
     pub tb_gaps: String,
     pub tb_high_wicks: String,
     pub tb_live_price: String,
     pub tb_low_wicks: String,
+    pub tb_panels: String,
+    pub tb_panels_hover: String,
     pub tb_price_limits: String,
+    pub tb_score_type_hover: String,
+    pub tb_split_compare: String,
+    pub tb_split_compare_hover: String,
     pub tb_sticky: String,
     pub tb_targets: String,
     pub tb_time: String,
+    pub tb_timeline: String,
+    pub tb_timeline_hover: String,
+    pub tb_true_time: String,
+    pub tb_true_time_hover: String,
     pub tb_volume_hist: String,
     pub tb_y_locked: String,
     pub tb_y_unlocked: String,
+    pub tf_beats_benchmark: String,
+    pub tf_beats_benchmark_hover: String,
+    pub tf_bulk_clear: String,
+    pub tf_bulk_export_csv: String,
+    pub tf_bulk_purge: String,
+    pub tf_bulk_recalc: String,
+    pub tf_bulk_retune: String,
+    pub tf_bulk_retuned: String,
+    pub tf_bulk_selected: String,
+    pub tf_bulk_watchlist: String,
+    pub tf_category_all: String,
+    pub tf_category_hover: String,
+    pub tf_category_tag_hover: String,
+    pub tf_col_aroi: String,
+    pub tf_col_expectancy: String,
+    pub tf_col_volume: String,
+    pub tf_compact: String,
+    pub tf_compact_hover: String,
+    pub tf_ext_agrees: String,
+    pub tf_ext_no_engine: String,
+    pub tf_ext_opposes: String,
+    pub tf_ext_stop: String,
+    pub tf_ext_tag_hover: String,
+    pub tf_freeze: String,
+    pub tf_freeze_hover: String,
+    pub tf_group_by_category: String,
+    pub tf_hold_on_hover: String,
+    pub tf_positive_expectancy: String,
+    pub tf_positive_expectancy_hover: String,
+    pub tf_refresh_hover: String,
+    pub tf_refresh_live: String,
     pub tf_scope_all: String,
     pub tf_scope_selected: String,
+    pub tf_script: String,
+    pub tf_script_alert: String,
+    pub tf_script_alert_hover: String,
+    pub tf_script_apply: String,
+    pub tf_script_fields_hover: String,
+    pub tf_script_filter: String,
+    pub tf_script_hover: String,
+    pub tf_script_score: String,
+    pub tf_script_sort: String,
+    pub tf_script_sort_hover: String,
     pub tf_time: String,
+    pub tf_tt_avg_duration: String,
+    pub tf_tt_fingerprint: String,
+    pub tf_tt_grade: String,
+    pub tf_tt_grade_note: String,
+    pub tf_tt_momentum: String,
+    pub tf_tt_path_dense: String,
+    pub tf_tt_path_density: String,
+    pub tf_tt_path_normal: String,
+    pub tf_tt_path_thin: String,
+    pub tf_tt_pnl_usd: String,
+    pub tf_tt_rel_volume: String,
+    pub tf_tt_samples: String,
+    pub tf_tt_target_stop: String,
+    pub tf_tt_volatility: String,
+    pub ticker_promote_improved: String,
+    pub ticker_promote_improved_hover: String,
+    pub tl_created: String,
+    pub tl_expired: String,
+    pub tl_expires: String,
+    pub tl_hidden: String,
+    pub tl_hidden_hover: String,
+    pub tl_remaining: String,
+    pub tt_breakdown_hint: String,
+    pub tt_col_duration: String,
+    pub tt_col_ph: String,
+    pub tt_col_total: String,
+    pub tt_keys_hint: String,
+    pub tt_last_scan: String,
+    pub tt_note_hint: String,
+    pub tt_note_hover: String,
+    pub tt_preview_apply: String,
+    pub tt_preview_apply_hover: String,
+    pub tt_preview_cancel: String,
+    pub tt_preview_cancel_hover: String,
+    pub tt_preview_label: String,
+    pub tt_preview_no_fit: String,
+    pub tt_preview_trades: String,
+    pub tt_scoring: String,
+    pub tt_station_hover: String,
+    pub tt_stats_ago: String,
+    pub tt_stats_ops: String,
+    pub tt_stats_unknown: String,
+    pub tt_stats_unknown_hover: String,
+    pub tt_stats_updated: String,
+    pub tt_weight_coverage: String,
+    pub tt_weight_coverage_hover: String,
+    pub tt_weight_expectancy: String,
+    pub tt_weight_expectancy_hover: String,
+    pub tt_weight_quality: String,
+    pub tt_weight_quality_hover: String,
+    pub tt_weight_samples: String,
+    pub tt_weight_samples_hover: String,
+    pub tt_weights_reset: String,
+    pub tt_weights_stale: String,
+    pub wi_col_best: String,
+    pub wi_col_count: String,
+    pub wi_col_move: String,
+    pub wi_col_price: String,
+    pub wi_intro: String,
+    pub wi_none: String,
+    pub wi_prices_hint: String,
+    pub wi_run: String,
+    pub wi_title: String,
+    pub zs_auto_balance: String,
+    pub zs_auto_balance_hover: String,
+    pub zs_benchmark_rate: String,
+    pub zs_benchmark_rate_hover: String,
+    pub zs_coverage_targets: String,
+    pub zs_custom_zone_delete: String,
+    pub zs_custom_zones: String,
+    pub zs_exclude_anomalies: String,
+    pub zs_exclude_anomalies_hover: String,
+    pub zs_sticky_score: String,
+    pub zs_sticky_score_hover: String,
+    pub zs_stop_management: String,
+    pub zs_stop_management_hover: String,
+    pub zs_zone_edits: String,
+    pub zs_gap: String,
+    pub zs_high_wick: String,
+    pub zs_low_wick: String,
+    pub zs_reset: String,
+    pub zs_reset_zone_edits: String,
+    pub zs_sigma: String,
+    pub zs_smoothing: String,
+    pub zs_sticky: String,
+    pub zs_stop_buffer: String,
+    pub zs_stop_mode: String,
+    pub zs_stop_mode_hover: String,
+    pub zs_trade_profile: String,
+    pub zs_viability: String,
+    pub zs_window_title: String,
     #[cfg(debug_assertions)]
     pub label_id: String,
     #[cfg(debug_assertions)]
@@ -150,6 +821,98 @@ pub struct UiText {
 
 pub static UI_TEXT: LazyLock<UiText> = LazyLock::new(|| {
     UiText {
+        ae_add_point: "+ Add point".to_string(),
+        ae_apply: "Apply to all pairs".to_string(),
+        ae_curve_decay: "Time Decay".to_string(),
+        ae_curve_lookback: "Trend Lookback (candles)".to_string(),
+        ae_discard: "Discard".to_string(),
+        ae_ph: "PH %".to_string(),
+        ae_previewing: "Previewing on".to_string(),
+        ae_reset: "Reset to defaults".to_string(),
+        ae_value: "Value".to_string(),
+        ae_window_title: format!("{} Adaptive Parameters", ICON_COG),
+        ak_can_trade: "trading enabled".to_string(),
+        ak_check: "Test".to_string(),
+        ak_check_hover: "Make a signed account request with the stored key".to_string(),
+        ak_check_ok: "Key OK:".to_string(),
+        ak_configured: "Using API key".to_string(),
+        ak_funded_assets: "funded assets".to_string(),
+        ak_heading: "Binance API Key".to_string(),
+        ak_heading_hover: "Optional. A key only unlocks account (user-data) endpoints; the request-weight budget is counted per IP either way. The key is stored in the OS keychain, never in app settings.".to_string(),
+        ak_key: "API key".to_string(),
+        ak_public: "Public access (no API key)".to_string(),
+        ak_read_only: "read-only".to_string(),
+        ak_remove: "Remove".to_string(),
+        ak_removed: "API key removed".to_string(),
+        ak_save: "Save".to_string(),
+        ak_save_hover: "Store the key pair in the OS keychain. Read-only keys are enough for market data.".to_string(),
+        ak_saved: "API key saved to OS keychain".to_string(),
+        ak_scope_note: "A key only unlocks account endpoints; market data shares the same per-IP request budget.".to_string(),
+        ak_secret: "Secret".to_string(),
+        an_cancel: "Cancel".to_string(),
+        an_delete_hover: "Delete this note".to_string(),
+        an_hint: "What should future you know about this trade?".to_string(),
+        an_notes: "Notes".to_string(),
+        an_save: "Save".to_string(),
+        an_title: "Annotate opportunity".to_string(),
+        bk_ago: "ago".to_string(),
+        bk_built: "Suggested".to_string(),
+        bk_col_correlation: "Max ρ".to_string(),
+        bk_col_dir: "Dir".to_string(),
+        bk_col_expectancy: "Exp.".to_string(),
+        bk_col_pair: "Pair".to_string(),
+        bk_col_stake: "Stake".to_string(),
+        bk_col_success: "Success".to_string(),
+        bk_correlating: "Correlating pairs…".to_string(),
+        bk_correlation_with: "Most correlated with".to_string(),
+        bk_empty: "No opportunity in the book has a positive expectancy.".to_string(),
+        bk_expectancy: "Expected return".to_string(),
+        bk_expectancy_hover: "Sum of each leg's expectancy (in R) times its Kelly stake, as a share of the account.".to_string(),
+        bk_intro: "Takes the best-expectancy opportunity per pair and adds them in order, skipping any that is too correlated with a leg already in or that would push the basket past its risk budget. Each leg is staked at its Kelly fraction. Correlations come from the last 30 days of hourly returns; a pair without enough shared history is left out rather than assumed unrelated.".to_string(),
+        bk_max_correlation: "Max correlation".to_string(),
+        bk_max_correlation_hover: "Highest correlation allowed between two legs that move the account the same way. A short against a correlated long offsets it and counts as negative.".to_string(),
+        bk_positions: "Positions".to_string(),
+        bk_positions_hover: "Most legs the basket may hold".to_string(),
+        bk_risk: "Risk / budget".to_string(),
+        bk_risk_budget: "Risk budget".to_string(),
+        bk_risk_budget_hover: "Most of the account the basket may put at risk. Correlated legs add up, offsetting ones partly cancel out.".to_string(),
+        bk_risk_hover: "Combined risk of the legs, accounting for their correlations, against the budget".to_string(),
+        bk_select_hover: "Select this opportunity".to_string(),
+        bk_skip_correlated: "Correlated with".to_string(),
+        bk_skip_over_budget: "Over the risk budget".to_string(),
+        bk_skip_unknown: "No correlation estimate against".to_string(),
+        bk_skipped: "Left out".to_string(),
+        bk_skipped_hover: "Opportunities with an edge that did not fit the constraints".to_string(),
+        bk_suggest: "Suggest again".to_string(),
+        bk_suggest_hover: "Rebuild the basket from the current opportunities".to_string(),
+        bk_window_title: "Suggested Basket".to_string(),
+        ci_ago: "ago".to_string(),
+        ci_col_candles: "Candles".to_string(),
+        ci_col_interval: "Interval".to_string(),
+        ci_col_pair: "Pair".to_string(),
+        ci_col_range: "Range".to_string(),
+        ci_col_size: "Size".to_string(),
+        ci_col_updated: "Newest".to_string(),
+        ci_database: "Database".to_string(),
+        ci_delete: "Delete".to_string(),
+        ci_delete_confirm: "Confirm".to_string(),
+        ci_delete_hover: "Remove the stored candles of this pair and interval. Click again to confirm".to_string(),
+        ci_deleted: "candles deleted from".to_string(),
+        ci_empty: "The kline database is empty".to_string(),
+        ci_export: "Export".to_string(),
+        ci_export_hover: "Write the stored candles to a CSV in the export folder".to_string(),
+        ci_failed: "Cache action failed".to_string(),
+        ci_files_heading: "Cache files".to_string(),
+        ci_intro: "Candles stored per pair and interval. Actions change the database only; loaded charts keep their candles until the next start".to_string(),
+        ci_mock: "Unavailable with the mock exchange, which never writes to the kline database".to_string(),
+        ci_refresh: "Refresh".to_string(),
+        ci_refresh_hover: "Fetch the candles newer than the stored ones from Binance".to_string(),
+        ci_refreshed: "new candles for".to_string(),
+        ci_rescan: "Rescan".to_string(),
+        ci_scan_failed: "Could not read the kline cache".to_string(),
+        ci_size_hover: "Share of the database by candle count; SQLite doesn't report exact per-pair sizes".to_string(),
+        ci_updated_hover: "Time since the newest stored candle opened".to_string(),
+        ci_window_title: format!("{} Kline Cache", ICON_DATABASE),
         cp_analyzing: "Analyzing".to_string(),
         cp_calculating_zones: "Engine is busy calculating... please wait...".to_string(),
         cp_init_engine: "Initializing Engine".to_string(),
@@ -172,14 +935,210 @@ pub static UI_TEXT: LazyLock<UiText> = LazyLock::new(|| {
         cr_nav_return_prefix: "RETURN TO SEGMENT".to_string(),
         cr_nav_show_all: "SHOW ALL RANGES".to_string(),
         cr_price: "Price".to_string(),
+        cr_stats_change: "Change".to_string(),
+        cr_stats_hover: "Statistics for this segment".to_string(),
+        cr_stats_no_zones: "No current zone traded in this segment".to_string(),
+        cr_stats_range: "Range (low - high)".to_string(),
+        cr_stats_taker_buy: "Taker buy share".to_string(),
+        cr_stats_title: "Segment".to_string(),
+        cr_stats_volatility: "Realized volatility".to_string(),
+        cr_stats_volume: "Volume (base / quote)".to_string(),
+        cr_stats_zones: "Dominant zones (share of volume)".to_string(),
         cr_title_1: "Time Machine".to_string(),
         cr_title_2: "Candle Ranges".to_string(),
+        cw_col_pair: "Pair".to_string(),
+        cw_empty: "No correlated pairs to move.".to_string(),
+        cw_intro: "The leader at the chosen price and every correlated pair moved by its beta to it, each analysed with the current settings. Live models, the ledger and the Trade Finder are not changed.".to_string(),
+        cw_title: "Correlated move".to_string(),
+        cz_alert: "Alert when price enters".to_string(),
+        cz_bottom: "Bottom".to_string(),
+        cz_label_hint: "Label, e.g. weekly open".to_string(),
+        cz_title: "Custom zone".to_string(),
+        cz_top: "Top".to_string(),
+        dg_bundle: "Create diagnostics bundle".to_string(),
+        dg_bundle_hover: "Zip settings, engine state, recent logs, the selected pair's model and version info into the export folder for a bug report. Contains no API keys.".to_string(),
+        dg_col_avg: "Avg".to_string(),
+        dg_col_count: "Jobs".to_string(),
+        dg_col_cpu: "CPU".to_string(),
+        dg_col_job: "Job".to_string(),
+        dg_col_max: "Max".to_string(),
+        dg_cross_check: "Cross-check candles".to_string(),
+        dg_cross_check_candles: "candles".to_string(),
+        dg_cross_check_clean: "Every sampled candle matches Binance".to_string(),
+        dg_cross_check_errors: "errors".to_string(),
+        dg_cross_check_hover: "Re-fetch random cached candles from Binance REST and compare them field by field, to catch cache corruption or aggregation bugs".to_string(),
+        dg_cross_check_mismatches: "mismatches".to_string(),
+        dg_cross_check_pairs: "pairs".to_string(),
+        dg_cross_check_per_pair: "candles per pair".to_string(),
+        dg_cross_check_suspects: "Suspect pairs:".to_string(),
+        dg_largest_models: "Largest models".to_string(),
+        dg_models: "Models (pairs)".to_string(),
+        dg_process_rss: "Process memory".to_string(),
+        dg_timeseries: "Timeseries".to_string(),
+        dg_window_title: format!("{} Diagnostics", ICON_PULSE),
+        dg_worker_time: "Worker time by job type (CPU = whole process while running)".to_string(),
+        ds_drop_hint: "Drop Binance kline CSVs (PAIR-5m-*.csv) or a kline cache .bin to load them".to_string(),
+        ds_import_failed: "Import failed".to_string(),
+        ds_pairs: "pairs".to_string(),
+        ds_saved_import: "Last imported data".to_string(),
+        ds_saved_import_desc: "Kline files dropped on a previous visit, kept in browser storage".to_string(),
+        ds_subtitle: "Pick a bundled dataset. Links can skip this with ?dataset=<id>&pair=<PAIR>&ph=<0.1>".to_string(),
+        ds_title: "ZONE SNIPER WEB DEMO".to_string(),
         error_analysis_failed: "Analysis Failed".to_string(),
+        error_apply_viable_ph: "Raise PH to".to_string(),
+        error_export_failed: "Export failed".to_string(),
+        error_failures_in_row: "failures in a row".to_string(),
         error_no_model: "No model loaded.".to_string(),
         error_no_pair_selected: "No pair selected.".to_string(),
+        error_retry_in: "automatic retry in".to_string(),
+        es_batch_window: "Candle batch window".to_string(),
+        es_batch_window_hover: "Hold closed live candles this long so several closing together cost one recalculation (0 = off)".to_string(),
+        es_hk_cache_files: "old cache files".to_string(),
+        es_hk_compact: "Compact".to_string(),
+        es_hk_compact_hover: "VACUUM the database so deleted rows free disk space, and delete cache files from older cache versions".to_string(),
+        es_hk_expired: "expired candles".to_string(),
+        es_hk_failed: "Housekeeping failed".to_string(),
+        es_hk_heading: "Kline housekeeping".to_string(),
+        es_hk_heading_hover: "Trims the candle database and cache files. Shared by all profiles in this data directory.".to_string(),
+        es_hk_keep: "Keep".to_string(),
+        es_hk_keep_all: "all".to_string(),
+        es_hk_keep_hover: "Days of candles kept for this interval; older ones are deleted. Analysis needs enough history for the longest price horizon.".to_string(),
+        es_hk_mock: "Not available with the mock exchange".to_string(),
+        es_hk_on_startup: "Run on startup".to_string(),
+        es_hk_orphaned: "orphaned candles".to_string(),
+        es_hk_orphans: "Remove pairs not in pairs.txt".to_string(),
+        es_hk_orphans_hover: "Deletes every candle of pairs no longer listed in pairs.txt. Skipped when pairs.txt is missing.".to_string(),
+        es_hk_reclaimed: "reclaimed".to_string(),
+        es_hk_run: "Run now".to_string(),
+        es_hk_run_hover: "Runs in the background; analysis keeps working".to_string(),
+        es_hk_running: "Housekeeping is running".to_string(),
+        es_idle_after: "Idle after".to_string(),
+        es_idle_after_hover: "Focused with no mouse or keyboard input for this long counts as idle".to_string(),
+        es_idle_background_repaint: "Background repaint".to_string(),
+        es_idle_background_repaint_hover: "Repaint interval while the window is unfocused or minimized".to_string(),
+        es_idle_enabled: "Slow down when idle or in the background".to_string(),
+        es_idle_enabled_hover: "Stop the ticker animation and repaint at a low rate when nobody is using the window. Any input resumes instantly.".to_string(),
+        es_idle_heading: "Idle mode".to_string(),
+        es_idle_hold_recalcs: "Hold automatic recalcs in the background".to_string(),
+        es_idle_hold_recalcs_hover: "Price- and candle-triggered recalcs wait while the window is unfocused or minimized, then catch up on focus".to_string(),
+        es_idle_repaint: "Idle repaint".to_string(),
+        es_idle_repaint_hover: "Repaint interval while focused but idle".to_string(),
+        es_keep_taken: "Keep taken".to_string(),
+        es_keep_taken_hover: "Opportunities marked as taken are never removed by the age or per-pair limits".to_string(),
+        es_ledger_added: "Added".to_string(),
+        es_ledger_conflict_hover: "When the file holds a trade this ledger already tracks: keep the local figures or take the imported ones. The earlier first-seen time is kept either way; unrelated trades sharing an id are imported under a new id.".to_string(),
+        es_ledger_conflict_keep_local: "Keep local".to_string(),
+        es_ledger_conflict_prefer_imported: "Prefer imported".to_string(),
+        es_ledger_count: "opportunities in the ledger".to_string(),
+        es_ledger_export: "Export ledger (JSON)".to_string(),
+        es_ledger_export_hover: "Write every tracked opportunity to a JSON file in the export folder".to_string(),
+        es_ledger_heading: "Opportunity ledger".to_string(),
+        es_ledger_import: "Import".to_string(),
+        es_ledger_import_failed: "Import failed".to_string(),
+        es_ledger_import_hover: "Merge the opportunities in this file into the ledger. Nothing local is removed.".to_string(),
+        es_ledger_merged: "merged".to_string(),
+        es_ledger_path_hint: "Path to a ledger_*.json export".to_string(),
+        es_ledger_renamed: "renamed".to_string(),
+        es_ledger_transfer_heading: "Ledger transfer".to_string(),
+        es_ledger_transfer_hover: "Move tracked opportunities to another machine, or snapshot them before changing settings that trigger pruning".to_string(),
+        es_max_age: "Max. age".to_string(),
+        es_max_age_hover: "Remove opportunities first found longer ago than this (0 = no limit)".to_string(),
+        es_max_per_pair: "Max. per pair".to_string(),
+        es_max_per_pair_hover: "Keep only this many of the best scoring opportunities per pair (0 = no limit)".to_string(),
+        es_min_interval: "Min. interval per pair".to_string(),
+        es_min_interval_hover: "Minimum time between price-triggered recalculations of the same pair (0 = no limit)".to_string(),
+        es_pause: "Pause analysis".to_string(),
+        es_pause_hover: "Stop automatic recalculation on price moves and candle closes. Changing parameters still recalculates.".to_string(),
+        es_prune_interval: "Prune interval".to_string(),
+        es_prune_interval_hover: "How often overlapping opportunities are merged and the limits above applied".to_string(),
+        es_purge: "Purge stale opportunities".to_string(),
+        es_purge_collisions: "merged".to_string(),
+        es_purge_expired: "expired".to_string(),
+        es_purge_hover: "Apply the ledger limits and merge overlapping opportunities now".to_string(),
+        es_purge_none: "Nothing to purge".to_string(),
+        es_purge_over_limit: "over limit".to_string(),
+        es_purge_removed: "Removed".to_string(),
+        es_sw_clear: "Clear".to_string(),
+        es_sw_copy_token: "Copy token".to_string(),
+        es_sw_copy_url: "Copy URL".to_string(),
+        es_sw_copy_url_hover: "Copies the URL with the token as ?token=...".to_string(),
+        es_sw_enabled: "Listen for signals".to_string(),
+        es_sw_failed: "Could not listen on port".to_string(),
+        es_sw_heading: "Signal webhook".to_string(),
+        es_sw_heading_hover: "POST JSON like {\"pair\": \"BTCUSDT\", \"side\": \"buy\", \"price\": 65000, \"target\": 68000, \"stop\": 63500, \"source\": \"tradingview\"} to mark external signals on the plot. Only localhost can connect; use a tunnel for TradingView alerts".to_string(),
+        es_sw_in_trade_finder: "Show signals as Trade Finder rows".to_string(),
+        es_sw_listening: "Listening on".to_string(),
+        es_sw_port: "Port".to_string(),
+        es_sw_port_locked: "Stop listening to change the port".to_string(),
+        es_sw_received: "signals received this session".to_string(),
+        es_sw_regenerate_token: "Regenerate".to_string(),
+        es_sw_regenerate_token_hover: "Replace the token; senders using the old one are rejected".to_string(),
+        es_sw_token: "Token".to_string(),
+        es_sw_token_hover: "Kept in the OS keychain. Requests must carry it as ?token=... or an X-Sniper-Token header, with Content-Type: application/json".to_string(),
+        es_window_title: format!("{} Engine Settings", ICON_COG),
+        gl_aroi: "AROI (annualized ROI)".to_string(),
+        gl_aroi_def: "Expected ROI scaled up to a yearly rate using the average trade duration. Lets a quick 1% trade be compared fairly with a slow 5% one; short trades inflate it, so read it next to ROI.".to_string(),
+        gl_coverage: "Coverage".to_string(),
+        gl_coverage_def: "Share of the price range (as a % of zone buckets) classified as high-volume, support or resistance zones. Green is inside the target band for the current strategy, orange too little, red too much.".to_string(),
+        gl_decay: "Time decay".to_string(),
+        gl_decay_def: "How strongly older candles are down-weighted when building the volume profile, as a factor per year. Set per price horizon in the adaptive editor; 1.0 means no decay.".to_string(),
+        gl_grade: "Grade".to_string(),
+        gl_grade_def: "A-D summary of an opportunity: how far the (pessimistic) success rate clears the break-even rate implied by its risk:reward. D means the expected ROI is not positive.".to_string(),
+        gl_help_hover: "Open glossary".to_string(),
+        gl_momentum: "Momentum".to_string(),
+        gl_momentum_def: "Percentage price change over the trend lookback window. Part of the market fingerprint used to find similar historical moments.".to_string(),
+        gl_no_match: "No matching terms".to_string(),
+        gl_ph: "Price horizon (PH)".to_string(),
+        gl_ph_def: "Half-width of the price band analysed around the current price, as a % of price. Only history that traded within the band feeds the zones and simulations; wider horizons suit slower trades.".to_string(),
+        gl_rel_volume: "Relative volume".to_string(),
+        gl_rel_volume_def: "Candle volume divided by its recent average. Above 1× the market is busier than usual. Part of the market fingerprint.".to_string(),
+        gl_risk_reward: "Risk:reward (R:R)".to_string(),
+        gl_risk_reward_def: "Distance to target divided by distance to stop. A 2:1 trade only needs to win a third of the time to break even.".to_string(),
+        gl_roi: "ROI".to_string(),
+        gl_roi_def: "Average % profit or loss per trade across the simulated historical journeys. On an active target it shows the move from entry to the live price.".to_string(),
+        gl_search: "Search terms…".to_string(),
+        gl_sticky_zone: "High-volume (sticky) zone".to_string(),
+        gl_sticky_zone_def: "Price band where unusually much volume traded. Price tends to stall or consolidate there, so these zones anchor targets and stops.".to_string(),
+        gl_success_rate: "Success rate".to_string(),
+        gl_success_rate_def: "Share of similar historical journeys that hit the target before the stop or time limit. Grading subtracts its 95% margin, which grows on small samples.".to_string(),
+        gl_volatility: "Volatility".to_string(),
+        gl_volatility_def: "Candle high-low range as a % of close. Part of the market fingerprint and used to size adaptive horizons.".to_string(),
+        gl_window_title: "Glossary".to_string(),
+        hc_avg_return: "Avg return".to_string(),
+        hc_empty: "Not enough history for this pair yet".to_string(),
+        hc_intro: "Walk-forward replay of the last weeks: a daily entry point with the current settings, how many opportunities the engine would have raised and how they ended".to_string(),
+        hc_opportunities: "Opportunities".to_string(),
+        hc_outcomes: "Target / stop / timeout / open".to_string(),
+        hc_rule_all: "All".to_string(),
+        hc_rule_copy: "Use Trade Finder filter".to_string(),
+        hc_rule_copy_hover: "Replace this filter with the one the Trade Finder is using now".to_string(),
+        hc_rule_empty: "Enter a filter to see how the opportunities it keeps would have done against the rest".to_string(),
+        hc_rule_heading: "Filter backtest".to_string(),
+        hc_rule_hover: "Replays a filter over these walk-forward opportunities, judged as they stood at entry. It starts as a copy of the Trade Finder filter; edits here stay here. 24h volume is not known historically, so rules on volume match nothing.".to_string(),
+        hc_rule_kept: "Kept".to_string(),
+        hc_rule_lift: "Kept vs all, avg return".to_string(),
+        hc_rule_rejected: "Rejected".to_string(),
+        hc_run: "Build calendar".to_string(),
+        hc_run_hover: "Replays the selected pair week by week. Takes a while: one full analysis per day".to_string(),
+        hc_week_of: "Week of".to_string(),
+        hc_weeks_label: "weeks, oldest → newest".to_string(),
+        hc_win_rate: "Win rate".to_string(),
+        hc_window_title: "Opportunity Heat Calendar".to_string(),
+        hover_break_even_stop: "Success rate and expectancy with the stop moved to entry after 1R, against leaving it in place. Trades closed at entry count as neither win nor loss.".to_string(),
+        hover_custom_zone_targets: "Also simulate the near edge of each zone you drew as a target, with the same journeys, success rate and ROI as engine-found targets".to_string(),
+        hover_econ_event: "Scheduled macro events inside this target's time limit:".to_string(),
+        hover_excursions: "Maximum adverse / favorable excursion: how far matched journeys moved against and for the trade before reaching target or running out of time, ignoring the stop. A stop inside the typical heat gets shaken out.".to_string(),
+        hover_expectancy: "Average result per trade in units of risk (R), from the success rate and reward:risk. Kelly is the bankroll fraction that maximises long-run growth; most traders stake a fraction of it".to_string(),
+        hover_export_analysis: "Write a JSON + HTML audit report (fingerprint, matched journeys, outcomes, zones)".to_string(),
+        hover_hit_times: "How long the matched journeys that reached target took. Each bar is one slice of the time limit; a long right tail means the average hides slow fills.".to_string(),
+        hover_ladder_targets: "Also simulate take-profit rungs at the next zones in the trade direction, each with its own hit rate".to_string(),
+        hover_low_evidence: "Few similar historical situations back this target. Treat the numbers as rough.".to_string(),
         hover_scroll_to_selected_target: "Scroll to Selected Target".to_string(),
+        hover_taken: "You entered this trade. With \"Keep taken\" on, ledger limits never remove it.".to_string(),
+        hover_time_limit: "Time limit".to_string(),
         icon_close: ICON_CLOSE.to_string(),
         icon_long: ICON_TREND_UP.to_string(),
+        icon_profile: ICON_PROFILE.to_string(),
         icon_short: ICON_TREND_DOWN.to_string(),
         icon_sort_asc: ICON_SORT_ASC.to_string(),
         icon_sort_desc: ICON_SORT_DESC.to_string(),
@@ -187,9 +1146,55 @@ pub static UI_TEXT: LazyLock<UiText> = LazyLock::new(|| {
         icon_strategy_balanced: ICON_STRATEGY_BALANCED.to_string(),
         icon_strategy_log_growth: ICON_STRATEGY_LOG_GROWTH.to_string(),
         icon_strategy_roi: ICON_STRATEGY_ROI.to_string(),
+        icon_watchlist: ICON_STAR.to_string(),
+        ind_add: "+ Add indicator".to_string(),
+        ind_color_hover: "Click to cycle the line color".to_string(),
+        ind_delete: "Delete".to_string(),
+        ind_delete_hover: "Remove this indicator".to_string(),
+        ind_enabled_hover: "Draw this indicator on the plot".to_string(),
+        ind_fields_hover: "One number per candle at the plot resolution. Combine columns with + - * / and functions; window lengths count candles and must be constants. Candles without a value (still warming up) leave a gap. Columns and functions:".to_string(),
+        ind_intro: "Lines computed from the candles of the selected pair. Each pair keeps its own list.".to_string(),
+        ind_no_pair: "Select a pair to edit its indicators.".to_string(),
+        ind_none: "No indicators for this pair yet.".to_string(),
+        ind_window_title: "Custom Indicators".to_string(),
+        iv_body: "Another instance is using this data directory. Two instances sharing one kline cache and ledger overwrite each other's files and subscribe every price stream twice. Close the other instance, or start this one with --data-dir to keep its data separate.".to_string(),
+        iv_follow: "Show its state".to_string(),
+        iv_follow_hover: "Read-only view of the running instance's best opportunities, refreshed every few seconds".to_string(),
+        iv_live_pairs: "live pairs".to_string(),
+        iv_no_port: "The running instance does not share its state".to_string(),
+        iv_pid: "Process".to_string(),
+        iv_profile: "Profile".to_string(),
+        iv_quality: "Quality".to_string(),
+        iv_quit: "Quit".to_string(),
+        iv_title: "Zone Sniper is already running".to_string(),
+        iv_unreachable: "Cannot reach the running instance".to_string(),
+        jb_date: "Date".to_string(),
+        jb_distance: "Distance".to_string(),
+        jb_duration: "Path".to_string(),
+        jb_excursion: "MAE / MFE".to_string(),
+        jb_outcome: "Outcome".to_string(),
+        jb_stop: "STOP".to_string(),
+        jb_target: "TARGET".to_string(),
+        jb_timeout: "TIMEOUT".to_string(),
+        jb_title: "matched journeys".to_string(),
+        jb_window_title: format!("{} Journey Browser", ICON_TIME_MACHINE),
+        jb_wins: "hit target".to_string(),
+        kbs_capture_prompt: "Press a key... (Esc cancels)".to_string(),
         kbs_close_all_panes: format!("{} Close all open overlay panes", ICON_CLOSE_ALL),
+        kbs_conflict: "Conflicts with".to_string(),
         kbs_name_long: ICON_KEYBOARD.to_string() + " Keyboard Shortcuts",
         kbs_open_close: format!("{} Keyboard Shortcuts", ICON_KEYBOARD),
+        kbs_plot_reset: "Show all segments, auto-scale price axis".to_string(),
+        kbs_plot_segment_next: "Next segment".to_string(),
+        kbs_plot_segment_prev: "Previous segment".to_string(),
+        kbs_plot_zoom_in_y: "Zoom price axis in".to_string(),
+        kbs_plot_zoom_out_y: "Zoom price axis out".to_string(),
+        kbs_rebind_hint: "Click a key to rebind it.".to_string(),
+        kbs_rebind_hover: "Click, then press the new key (with Ctrl / Ctrl+Shift if wanted)".to_string(),
+        kbs_redo_params: "Redo analysis parameter change".to_string(),
+        kbs_reset_defaults: "Reset to default keys".to_string(),
+        kbs_tf_next_row: "Trade Finder: select the row below".to_string(),
+        kbs_tf_prev_row: "Trade Finder: select the row above".to_string(),
         kbs_toolbar_shortcut_candles: format!("{} {}", ICON_EYE, ICON_CANDLE),
         kbs_toolbar_shortcut_gap: format!("{} Data Gap", ICON_EYE),
         kbs_toolbar_shortcut_high_wick: format!("{} Higher Wick Zones", ICON_EYE),
@@ -199,21 +1204,58 @@ pub static UI_TEXT: LazyLock<UiText> = LazyLock::new(|| {
         kbs_toolbar_shortcut_low_wick: format!("{} Lower Wick Zones", ICON_EYE),
         kbs_toolbar_shortcut_price_limits: format!("{} PH Boundary", ICON_EYE),
         kbs_toolbar_shortcut_targets: format!("{} Targets", ICON_EYE),
+        kbs_tuner_apply_station: "Time Tuner: apply previewed style".to_string(),
+        kbs_tuner_cancel_station: "Time Tuner: cancel style preview".to_string(),
+        kbs_tuner_next_station: "Time Tuner: preview next style".to_string(),
+        kbs_tuner_prev_station: "Time Tuner: preview previous style".to_string(),
+        kbs_undo_params: "Undo analysis parameter change (PH, strategy, decay, zones)".to_string(),
+        kbs_view_adaptive_editor: format!("{} Adaptive Parameters Pane", ICON_COG),
+        kbs_view_basket: format!("{} Suggested Basket Pane", ICON_DOLLAR_BAG),
+        kbs_view_cache_inspector: format!("{} Kline Cache Inspector", ICON_DATABASE),
+        kbs_view_diagnostics: format!("{} Diagnostics Pane", ICON_PULSE),
+        kbs_view_engine_settings: format!("{} Engine Settings Pane", ICON_COG),
+        kbs_view_glossary: "Glossary of metrics".to_string(),
+        kbs_view_heat_calendar: format!("{} Opportunity Heat Calendar", ICON_CLOCK),
+        kbs_view_indicator_editor: format!("{} Custom Indicators", ICON_TREND_UP),
+        kbs_view_sectors: format!("{} Sector Strength Pane", ICON_PULSE),
+        kbs_view_session_stats: format!("{} Session Stats Pane", ICON_PULSE),
+        kbs_view_stress: format!("{} Stress Scenarios Pane", ICON_TREND_DOWN),
         kbs_view_time_machine: format!("{} Time Machine Pane", ICON_TIME_MACHINE),
+        kbs_view_zone_settings: format!("{} Zone Settings Pane", ICON_COG),
         label_active_target_text: "Active Target".to_string(),
+        label_break_even_stop: "Break-even after 1R: success".to_string(),
         label_candle: ICON_CANDLE.to_string(),
+        label_closed_at_entry: "closed at entry".to_string(),
         label_connected: "connected".to_string(),
         label_connecting: "Connecting".to_string(),
+        label_custom_zone_target: "My zone".to_string(),
+        label_custom_zone_targets: "Target my zones".to_string(),
+        label_econ_event: format!("{} EVENT", ICON_WARNING),
+        label_excess_aroi: "Excess AROI".to_string(),
+        label_expectancy: "Expectancy".to_string(),
+        label_export_analysis: "Export analysis".to_string(),
+        label_exported_to: "Exported to".to_string(),
         label_failures: "failures".to_string(),
+        label_fixed_stop: "fixed".to_string(),
         label_goal: "Trading Goal".to_string(),
+        label_hits: "hits".to_string(),
+        label_journeys: "Journeys".to_string(),
+        label_kelly: "Kelly".to_string(),
+        label_ladder_targets: "Ladder targets".to_string(),
         label_long: format!("LONG {}", ICON_TREND_UP),
+        label_low_evidence: format!("{} LOW EVIDENCE", ICON_WARNING),
+        label_mae: "Heat (MAE)".to_string(),
+        label_mfe: "Run-up (MFE)".to_string(),
+        label_middle_half: "middle half".to_string(),
         label_momentum_short: "Mom.".to_string(),
         label_no_targets: "No Active Targets".to_string(),
         label_pair: "Pair".to_string(),
         label_queue: ICON_QUEUE.to_string(),
         label_recenter: ICON_RECENTER.to_string(),
+        label_risk_reward: "R:R".to_string(),
         label_risk_select: "Stop Loss Variants".to_string(),
         label_roi: "ROI".to_string(),
+        label_samples: "samples".to_string(),
         label_select_pair: "Select a pair from the list below".to_string(),
         label_short: format!("SHORT {}", ICON_TREND_DOWN),
         label_sl_variants_short: "Vrts.".to_string(),
@@ -221,44 +1263,340 @@ pub static UI_TEXT: LazyLock<UiText> = LazyLock::new(|| {
         label_stop_loss_short: "S/L".to_string(),
         label_stop_loss: "Stop Loss".to_string(),
         label_success_rate_short: "Succ.".to_string(),
+        label_taken: "Taken".to_string(),
         label_target: ICON_TARGET.to_string(),
+        label_target_ladder: "Target ladder".to_string(),
         label_targets_text: "Targets".to_string(),
+        label_time_to_target: "Time to target".to_string(),
+        label_vol_contracting: "Volatility contracting".to_string(),
+        label_vol_expanding: "Volatility expanding".to_string(),
+        label_vol_steady: "Volatility steady".to_string(),
         label_volatility_short: "VL".to_string(),
         label_volatility: "Volatility".to_string(),
         label_volume_24h: format!("{}\n{}", "24h", "Vol."),
+        label_vs_benchmark: "vs benchmark".to_string(),
         label_warning: ICON_WARNING.to_string(),
+        label_winners: "winners".to_string(),
         label_working: ICON_COG.to_string(),
         ls_failed: "FAILED".to_string(),
         ls_main: "klines from Binance Public API. Initial or large syncs take time; subsequent runs are faster.".to_string(),
         ls_syncing: "Syncing".to_string(),
         ls_title: "ZONE SNIPER INITIALIZATION".to_string(),
+        md_added: "Added".to_string(),
+        md_changed: "Changed".to_string(),
+        md_coverage: "Coverage".to_string(),
+        md_empty: "No changes against the previous model".to_string(),
+        md_history: ICON_CLOCK.to_string(),
+        md_history_hover: "Recent recalculations: click to see what changed".to_string(),
+        md_no_history: "No recalculations yet. Diffs appear once a pair is recalculated.".to_string(),
+        md_opportunities: "Opportunities".to_string(),
+        md_removed: "Removed".to_string(),
+        md_resistance: "Resistance".to_string(),
+        md_select: "Select a recalculation to see what changed".to_string(),
+        md_sticky: "Sticky".to_string(),
+        md_support: "Support".to_string(),
+        md_window_title: "Recalculation History".to_string(),
+        md_zones: "Zones".to_string(),
+        nc_button: ICON_BELL.to_string(),
+        nc_clear: "Clear".to_string(),
+        nc_empty: "No notifications.".to_string(),
+        nc_filter_matched: "Opportunity filter matched:".to_string(),
+        nc_hover: "Alerts and errors reported while the app runs".to_string(),
+        nc_price_alert: "Price alert hit:".to_string(),
+        nc_title: "Notifications".to_string(),
+        nc_zone_entered: "Custom zone entered:".to_string(),
+        pf_create: "Create".to_string(),
+        pf_create_hover: "Create an empty profile and switch to it".to_string(),
+        pf_menu_hover: "Profile: settings, watchlist and ledger are kept separately per profile. Switching reloads the engine with the chosen profile.".to_string(),
+        pf_new_hint: "New profile name".to_string(),
+        ph_slider_below_min: "Too few candles below the minimum viable PH of".to_string(),
+        ph_slider_candles: "candles in range".to_string(),
+        ph_slider_coverage: "of history".to_string(),
+        ph_slider_label: "PH".to_string(),
+        plot_alert_label: "ALERT".to_string(),
+        plot_ctx_add_line: "Add horizontal line".to_string(),
+        plot_ctx_add_zone: "Add custom zone here".to_string(),
+        plot_ctx_annotate: "Annotate selected opportunity here...".to_string(),
+        plot_ctx_center: "Center view here".to_string(),
+        plot_ctx_clear_marks: "Clear lines & alerts".to_string(),
+        plot_ctx_copy_price: "Copy price".to_string(),
+        plot_ctx_merge_above: "Merge with zone above".to_string(),
+        plot_ctx_merge_below: "Merge with zone below".to_string(),
+        plot_ctx_set_alert: "Set alert at this price".to_string(),
+        plot_ctx_simulate: "Simulate price here".to_string(),
+        plot_ctx_simulate_correlated: "Simulate correlated move here (all pairs by beta)".to_string(),
+        plot_ctx_split_zone: "Split zone here".to_string(),
+        plot_ctx_what_if: "What-if at several prices...".to_string(),
+        plot_custom_zone_alert: "(alert)".to_string(),
+        plot_external_tag: "EXT".to_string(),
+        plot_ghost_label: "Style preview".to_string(),
         plot_missing_klines: "OHLCV kline data missing for current model".to_string(),
         plot_x_axis_gap: "GAP".to_string(),
         plot_x_axis: "Segmented Time ".to_string() + ICON_SEGMENTED_TIME,
         plot_y_axis: "Price".to_string(),
+        plot_zone_from_above: "↓".to_string(),
+        plot_zone_from_below: "↑".to_string(),
+        plot_zone_touches: "×".to_string(),
+        score_body_only: "Body Only".to_string(),
+        score_close_only: "Close Only".to_string(),
+        score_full_candle: "Full Candle".to_string(),
+        score_high_wick_count: "High Wicks".to_string(),
+        score_low_wick_count: "Low Wicks".to_string(),
+        score_taker_delta: "Taker Delta".to_string(),
+        score_wick_only: "Wick Only".to_string(),
+        sec_col_best: "Best".to_string(),
+        sec_col_best_hover: "Pair with the highest expectancy in the sector".to_string(),
+        sec_col_momentum: "Mom".to_string(),
+        sec_col_momentum_hover: "Average close-to-close move of the sector's pairs over the last 2 hours".to_string(),
+        sec_col_pairs: "Pairs".to_string(),
+        sec_col_pairs_hover: "Tracked pairs whose base asset is in the sector".to_string(),
+        sec_col_positive_ev: "+EV".to_string(),
+        sec_col_positive_ev_hover: "Live targets with positive expectancy".to_string(),
+        sec_col_rising: "Rising".to_string(),
+        sec_col_rising_hover: "Share of the sector's pairs that moved up over the last 2 hours".to_string(),
+        sec_col_sector: "Sector".to_string(),
+        sec_col_sector_hover: "Click to show only this sector in the Trade Finder (click again to show all)".to_string(),
+        sec_col_targets: "Targets".to_string(),
+        sec_col_targets_hover: "Live targets on the sector's pairs".to_string(),
+        sec_col_volatility: "Vol".to_string(),
+        sec_col_volatility_hover: "Average candle range (high - low) / close over the last 2 hours".to_string(),
+        sec_intro: "Tracked pairs grouped by the sector of their base asset, most actionable first (most positive-expectancy targets). Refreshes every 10 seconds while open.".to_string(),
+        sec_most_actionable: "Most actionable:".to_string(),
+        sec_positive_ev_targets: "+EV targets".to_string(),
+        sec_window_title: "Sector Strength".to_string(),
+        sp_analysis_paused: format!("{} Analysis paused", ICON_PAUSE),
+        sp_batched: "Batched".to_string(),
+        sp_clock_skew: format!("{} Clock skew", ICON_WARNING),
+        sp_clock_skew_hover:
+            "System clock differs from Binance time. Times shown are corrected; consider syncing your clock."
+                .to_string(),
+        sp_compact: "Compact labels".to_string(),
+        sp_compact_hover: "Abbreviate status bar labels to fit more on small windows".to_string(),
         sp_coverage_resistance: "Resist.".to_string(),
+        sp_coverage_resistance_short: "R".to_string(),
         sp_coverage_sticky: "High Volume".to_string(),
+        sp_coverage_sticky_short: "HV".to_string(),
         sp_coverage_support: "Support".to_string(),
+        sp_coverage_support_short: "S".to_string(),
         sp_coverage: "Coverage".to_string(),
+        sp_coverage_short: "Cov.".to_string(),
         sp_live_mode: ICON_PULSE.to_string() + " LIVE MODE",
+        sp_live_mode_short: ICON_PULSE.to_string(),
+        sp_mock_exchange: "MOCK EXCHANGE".to_string(),
+        sp_mock_exchange_hover:
+            "Synthetic candles and prices (--mock-exchange); nothing here is real market data"
+                .to_string(),
+        sp_more: "more…".to_string(),
+        sp_more_hover: "Status sections that don't fit, and which sections to show".to_string(),
+        sp_pause: ICON_PAUSE.to_string(),
         sp_price: ICON_DOLLAR_BAG.to_string(),
+        sp_resume_hover: format!("{} Click to resume analysis", ICON_PLAY),
+        sp_seg_candles: "Candles & volatility".to_string(),
+        sp_seg_coverage: "Zone coverage".to_string(),
+        sp_seg_heading: "Show in status bar".to_string(),
+        sp_seg_network: "Stream & clock".to_string(),
+        sp_seg_price: "Live price".to_string(),
+        sp_seg_system: "Engine activity".to_string(),
+        sp_seg_zone_size: "Zone size".to_string(),
         sp_stream_status: "Stream Status".to_string(),
+        sp_stream_status_short: "Net".to_string(),
+        sp_volatility_short: "Vol.".to_string(),
         sp_zone_size: ICON_RULER.to_string() + " Zone Size",
+        sp_zone_size_short: ICON_RULER.to_string(),
+        ss_best_roi: "Best live ROI".to_string(),
+        ss_created: "Opportunities created".to_string(),
+        ss_expired: "Opportunities expired".to_string(),
+        ss_none: "-".to_string(),
+        ss_recalcs: "Engine recalculations".to_string(),
+        ss_top_churn: "Most signal churn".to_string(),
+        ss_uptime: "Uptime".to_string(),
+        ss_window_title: format!("{} Session Stats", ICON_PULSE),
+        ss_worst_roi: "Worst live ROI".to_string(),
+        st_avg_pnl: "Avg PnL".to_string(),
+        st_col_after: "After".to_string(),
+        st_col_beta: "β".to_string(),
+        st_col_dir: "Dir".to_string(),
+        st_col_outcome: "Outcome".to_string(),
+        st_col_pair: "Pair".to_string(),
+        st_col_pnl: "PnL".to_string(),
+        st_col_worst: "Worst".to_string(),
+        st_copy: "Copy report".to_string(),
+        st_copy_hover: "Copy the scenario summary and per-opportunity results as text".to_string(),
+        st_empty: "No opportunities in the book to stress.".to_string(),
+        st_expired: "Expired".to_string(),
+        st_flash_crash: "−10% flash crash".to_string(),
+        st_flash_crash_hover: "BTC drops 10% over 30 minutes, then bounces back to −4% over the next 90 minutes. Other pairs move by their beta to BTC.".to_string(),
+        st_intro: "Replays a price path over simulated time against every opportunity in the book, entering at the live price.".to_string(),
+        st_open: "Still open".to_string(),
+        st_squeeze: "+5% squeeze".to_string(),
+        st_squeeze_hover: "BTC rallies 5% over an hour, then fades to +3% over the next hour. Other pairs move by their beta to BTC.".to_string(),
+        st_stop_hit: "Stop hit".to_string(),
+        st_target_hit: "Target hit".to_string(),
+        st_weekend_chop: "Weekend chop".to_string(),
+        st_weekend_chop_hover: "Two days of ±1.5% swings around the current price with no trend. Other pairs move by their beta to BTC.".to_string(),
+        st_window_title: "Stress Scenarios".to_string(),
+        tb_anomalies: "Anomalies".to_string(),
+        tb_anomalies_hover: "Mark flash wicks (orange) and zero-volume / frozen-price outage candles (grey)".to_string(),
         tb_candles: ICON_CANDLE.to_string(),
+        tb_custom_zones: "My Zones".to_string(),
+        tb_custom_zones_hover: "Show the zones you drew for this pair (right-click the plot to add one)".to_string(),
+        tb_external_signals: "External signals".to_string(),
+        tb_external_signals_hover: "Mark signals received by the webhook (engine settings) on the plot".to_string(),
         tb_gaps: "Data Gap".to_string(),
         tb_high_wicks: "Higher Wicks".to_string(),
         tb_live_price: "Live Price".to_string() + " " + ICON_ONE_HORIZONTAL,
         tb_low_wicks: "Lower Wicks".to_string(),
+        tb_panels: ICON_MENU.to_string(),
+        tb_panels_hover: "Show or hide the side panels (remembered separately for narrow and wide windows)".to_string(),
         tb_price_limits: "PH Boundary".to_string() + " " + ICON_TWO_HORIZONTAL,
+        tb_score_type_hover: "What the volume histogram measures. Taker Delta colors buy (green) / sell (red) imbalance.".to_string(),
+        tb_split_compare: "A/B".to_string(),
+        tb_split_compare_hover: "Split the histogram: left half shows the first score type, right half the second".to_string(),
         tb_sticky: "High Volume Zones".to_string(),
         tb_targets: ICON_TARGET.to_string(),
         tb_time: ICON_CLOCK.to_string(),
+        tb_timeline: ICON_SEGMENTED_TIME.to_string(),
+        tb_timeline_hover: "Show when each opportunity for this pair was found and when it times out".to_string(),
+        tb_true_time: "True Time".to_string(),
+        tb_true_time_hover: "Give data gaps width in proportion to how long they last (capped) so the time axis stays linear".to_string(),
         tb_volume_hist: "Volume Hist.".to_string(),
         tb_y_locked: ICON_Y_AXIS.to_string() + " " + ICON_LOCKED,
         tb_y_unlocked: ICON_Y_AXIS.to_string() + " " + ICON_UNLOCKED,
+        tf_beats_benchmark: "Beats benchmark".to_string(),
+        tf_beats_benchmark_hover: "Only show opportunities whose live AROI is above the benchmark rate set in the trade profile".to_string(),
+        tf_bulk_clear: "Clear".to_string(),
+        tf_bulk_export_csv: "Export CSV".to_string(),
+        tf_bulk_purge: "Purge cache".to_string(),
+        tf_bulk_recalc: "Recalc".to_string(),
+        tf_bulk_retune: "Re-tune".to_string(),
+        tf_bulk_retuned: "Re-tune queued for".to_string(),
+        tf_bulk_selected: "selected".to_string(),
+        tf_bulk_watchlist: ICON_STAR.to_string() + " Watchlist",
+        tf_category_all: "All sectors".to_string(),
+        tf_category_hover: "Only show pairs whose base asset is in this sector, or group rows by sector. Sectors come from a bundled list of well-known assets; the rest are Other.".to_string(),
+        tf_category_tag_hover: "Sector of the base asset".to_string(),
+        tf_col_aroi: "AROI".to_string(),
+        tf_col_expectancy: "Exp".to_string(),
+        tf_col_volume: "24h Vol.".to_string(),
+        tf_compact: ICON_TABLE.to_string(),
+        tf_compact_hover: "Compact table: one line per opportunity".to_string(),
+        tf_ext_agrees: "Engine agrees".to_string(),
+        tf_ext_no_engine: "No engine opportunity".to_string(),
+        tf_ext_opposes: "Engine opposes".to_string(),
+        tf_ext_stop: "SL".to_string(),
+        tf_ext_tag_hover: "Received by the signal webhook; not an engine opportunity".to_string(),
+        tf_freeze: "Freeze".to_string(),
+        tf_freeze_hover: "Hold the current row order; values keep updating in place".to_string(),
+        tf_group_by_category: "Group by sector".to_string(),
+        tf_hold_on_hover: "Hold order while hovering or holding Space".to_string(),
+        tf_positive_expectancy: "+EV".to_string(),
+        tf_positive_expectancy_hover: "Only show opportunities with positive expectancy (success rate beats the break-even rate for their reward:risk)".to_string(),
+        tf_refresh_hover: "How often the list re-sorts. Prices and ROI update in place every frame".to_string(),
+        tf_refresh_live: "Live".to_string(),
         tf_scope_all: "ALL PAIRS".to_string(),
         tf_scope_selected: "ONLY".to_string(),
+        tf_script: "Script".to_string(),
+        tf_script_alert: "Alert".to_string(),
+        tf_script_alert_hover: "Request attention when an opportunity starts matching the filter (checked every few seconds)".to_string(),
+        tf_script_apply: "Apply".to_string(),
+        tf_script_fields_hover: "Combine fields with && || ! and == != < <= > >=, numbers with + - * /, text in quotes. Percentages read as shown (aroi > 200 = above +200%). Fields:".to_string(),
+        tf_script_filter: "Filter".to_string(),
+        tf_script_hover: "Custom filter and score expressions over opportunity fields".to_string(),
+        tf_script_score: "Score".to_string(),
+        tf_script_sort: "Sort by score".to_string(),
+        tf_script_sort_hover: "Sort the Trade Finder by the score expression, highest first".to_string(),
         tf_time: ICON_CLOCK.to_string(),
+        tf_tt_avg_duration: "Avg. duration".to_string(),
+        tf_tt_fingerprint: "Market fingerprint".to_string(),
+        tf_tt_grade: "Grade".to_string(),
+        tf_tt_grade_note: "A/B: success rate clears break-even even at the low end of its margin. C: only on average. D: negative expected return.".to_string(),
+        tf_tt_momentum: "Momentum".to_string(),
+        tf_tt_path_dense: "dense, tends to stall".to_string(),
+        tf_tt_path_density: "Path density".to_string(),
+        tf_tt_path_normal: "average".to_string(),
+        tf_tt_path_thin: "thin, tends to travel fast".to_string(),
+        tf_tt_pnl_usd: "Expected profit in dollars".to_string(),
+        tf_tt_rel_volume: "Relative volume".to_string(),
+        tf_tt_samples: "Samples".to_string(),
+        tf_tt_target_stop: "Target / stop move".to_string(),
+        tf_tt_volatility: "Volatility".to_string(),
+        ticker_promote_improved: "Move improved pairs to the front".to_string(),
+        ticker_promote_improved_hover: "When a pair's best opportunity improves materially after a recalculation, show it next in the ticker".to_string(),
+        tl_created: "Found".to_string(),
+        tl_expired: "timed out".to_string(),
+        tl_expires: "Times out".to_string(),
+        tl_hidden: "later to time out, not shown".to_string(),
+        tl_hidden_hover: "Only the opportunities closest to timing out are drawn here".to_string(),
+        tl_remaining: "left".to_string(),
+        tt_breakdown_hint: "Objectives are scaled 0–1 across the PHs probed. Green won; grey missed the style's time range".to_string(),
+        tt_col_duration: "Dur".to_string(),
+        tt_col_ph: "PH".to_string(),
+        tt_col_total: "Total".to_string(),
+        tt_keys_hint: "Ctrl+←/→ preview a style · Ctrl+Enter apply · Ctrl+Backspace cancel".to_string(),
+        tt_last_scan: "Last scan:".to_string(),
+        tt_note_hint: "Note on".to_string(),
+        tt_note_hover: "Your own note on this style for this pair (shown when hovering its button)".to_string(),
+        tt_preview_apply: "Apply".to_string(),
+        tt_preview_apply_hover: "Switch to this style and retune (Ctrl+Enter)".to_string(),
+        tt_preview_cancel: "Cancel".to_string(),
+        tt_preview_cancel_hover: "Keep the current style (Ctrl+Backspace)".to_string(),
+        tt_preview_label: "Preview:".to_string(),
+        tt_preview_no_fit: "no PH fits this style".to_string(),
+        tt_preview_trades: "trades".to_string(),
+        tt_scoring: "Tuner scoring".to_string(),
+        tt_station_hover: "Click to preview this style as a ghost on the chart; double-click to apply it straight away".to_string(),
+        tt_stats_ago: "ago".to_string(),
+        tt_stats_ops: "ops".to_string(),
+        tt_stats_unknown: "not run yet".to_string(),
+        tt_stats_unknown_hover: "No live analysis for this style on this pair yet. Apply it to find out what it yields; the ghost preview shows a one-off estimate.".to_string(),
+        tt_stats_updated: "Updated".to_string(),
+        tt_weight_coverage: "Coverage".to_string(),
+        tt_weight_coverage_hover: "Share of price history inside the horizon".to_string(),
+        tt_weight_expectancy: "Exp".to_string(),
+        tt_weight_expectancy_hover: "Mean expectancy (R) of the opportunities found".to_string(),
+        tt_weight_quality: "Quality".to_string(),
+        tt_weight_quality_hover: "Strategy score of the best opportunity (the original tuner objective)".to_string(),
+        tt_weight_samples: "Samples".to_string(),
+        tt_weight_samples_hover: "Matched historical scenarios behind the opportunities (diminishing returns)".to_string(),
+        tt_weights_reset: "Reset weights".to_string(),
+        tt_weights_stale: "Weights changed since this scan; re-select the style to retune".to_string(),
+        wi_col_best: "Best".to_string(),
+        wi_col_count: "Opps".to_string(),
+        wi_col_move: "vs live".to_string(),
+        wi_col_price: "Price".to_string(),
+        wi_intro: "Runs the full analysis at each price with the current settings. Live models and the Trade Finder are not changed.".to_string(),
+        wi_none: "No opportunities".to_string(),
+        wi_prices_hint: "Prices, comma separated".to_string(),
+        wi_run: "Evaluate".to_string(),
+        wi_title: "What-if prices".to_string(),
+        zs_auto_balance: "Auto-balance".to_string(),
+        zs_auto_balance_hover: "Adjust each zone type's sigma until coverage for the selected pair falls inside its band".to_string(),
+        zs_benchmark_rate: "Benchmark rate".to_string(),
+        zs_benchmark_rate_hover: "Yearly return you could earn risk-free instead (e.g. stablecoin yield). Excess AROI is measured against it".to_string(),
+        zs_coverage_targets: "Coverage Targets".to_string(),
+        zs_custom_zone_delete: "Delete".to_string(),
+        zs_custom_zones: "Custom zones".to_string(),
+        zs_exclude_anomalies: "Exclude anomalous candles".to_string(),
+        zs_exclude_anomalies_hover: "Leave flash wicks, zero-volume and frozen-price (outage) candles out of zone scoring and journey matching".to_string(),
+        zs_sticky_score: "Sticky zones from".to_string(),
+        zs_sticky_score_hover: "What makes a price level sticky. Volume uses all traded volume; taker delta uses the net imbalance between aggressive buyers and sellers, so levels fought over by one side stand out.".to_string(),
+        zs_stop_management: "Stop management".to_string(),
+        zs_stop_management_hover: "How the simulations manage the stop once a trade is open. Break-even after 1R moves it to entry when the trade is as far in profit as the stop is away.".to_string(),
+        zs_zone_edits: "Manual zone edits".to_string(),
+        zs_gap: "Merge gap".to_string(),
+        zs_high_wick: "High Wick Zones".to_string(),
+        zs_low_wick: "Low Wick Zones".to_string(),
+        zs_reset: "Reset to defaults".to_string(),
+        zs_reset_zone_edits: "Clear".to_string(),
+        zs_sigma: "Threshold (σ)".to_string(),
+        zs_smoothing: "Smoothing".to_string(),
+        zs_sticky: "Sticky Zones".to_string(),
+        zs_stop_buffer: "Zone stop buffer".to_string(),
+        zs_stop_mode: "Stop placement".to_string(),
+        zs_stop_mode_hover: "R:R sweep tries several stop distances per target. Zone anchored puts the stop just beyond the far edge of the nearest zone protecting the entry (falls back to the sweep when there is none).".to_string(),
+        zs_trade_profile: "Trade Profile".to_string(),
+        zs_viability: "Viability floor".to_string(),
+        zs_window_title: format!("{} Zone Settings", ICON_COG),
         #[cfg(debug_assertions)]
         label_id: "ID".to_string(),
         #[cfg(debug_assertions)]
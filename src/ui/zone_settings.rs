@@ -0,0 +1,237 @@
+use {
+    crate::{
+        app::{
+            AroiPct, CoverageBand, CoverageTargets, Pct, PhPct, Sigma, StickyScore, StopManagement,
+            StopMode, TradeProfile, ZoneClassificationConfig, ZoneParams,
+        },
+        ui::UI_TEXT,
+    },
+    eframe::egui::{CollapsingHeader, ComboBox, DragValue, Grid, Ui},
+    strum::IntoEnumIterator,
+};
+
+/// Edits zone classification parameters in place. Returns true if anything changed.
+pub(crate) fn render_zone_settings(ui: &mut Ui, config: &mut ZoneClassificationConfig) -> bool {
+    let mut changed = false;
+
+    for (title, params) in [
+        (&UI_TEXT.zs_sticky, &mut config.sticky),
+        (&UI_TEXT.zs_low_wick, &mut config.low_wick),
+        (&UI_TEXT.zs_high_wick, &mut config.high_wick),
+    ] {
+        CollapsingHeader::new(title)
+            .default_open(true)
+            .show(ui, |ui| {
+                changed |= render_zone_params(ui, title, params);
+            });
+    }
+
+    ui.horizontal(|ui| {
+        ui.label(&UI_TEXT.zs_sticky_score)
+            .on_hover_text(&UI_TEXT.zs_sticky_score_hover);
+        let before = config.sticky_score;
+        ComboBox::from_id_salt("sticky_score")
+            .selected_text(config.sticky_score.to_string())
+            .show_ui(ui, |ui| {
+                for score in StickyScore::iter() {
+                    ui.selectable_value(&mut config.sticky_score, score, score.to_string());
+                }
+            });
+        changed |= config.sticky_score != before;
+    });
+
+    ui.separator();
+    if ui.button(&UI_TEXT.zs_reset).clicked() {
+        *config = ZoneClassificationConfig::default();
+        changed = true;
+    }
+    changed
+}
+
+fn render_zone_params(ui: &mut Ui, id: &str, params: &mut ZoneParams) -> bool {
+    let mut changed = false;
+    Grid::new(id)
+        .num_columns(2)
+        .spacing([10.0, 4.0])
+        .show(ui, |ui| {
+            changed |= pct_row(ui, &UI_TEXT.zs_smoothing, &mut params.smooth_pct, 0.05);
+            changed |= pct_row(ui, &UI_TEXT.zs_gap, &mut params.gap_pct, 0.05);
+            changed |= pct_row(ui, &UI_TEXT.zs_viability, &mut params.viability_pct, 0.005);
+
+            ui.label(&UI_TEXT.zs_sigma);
+            let mut sigma = params.sigma.value();
+            if ui
+                .add(DragValue::new(&mut sigma).speed(0.05).range(0.0..=5.0))
+                .changed()
+            {
+                params.sigma = Sigma::new(sigma);
+                changed = true;
+            }
+            ui.end_row();
+        });
+    changed
+}
+
+/// Edits stop placement in place. Returns true if anything changed.
+pub(crate) fn render_trade_profile(ui: &mut Ui, profile: &mut TradeProfile) -> bool {
+    let mut changed = false;
+    CollapsingHeader::new(&UI_TEXT.zs_trade_profile)
+        .default_open(true)
+        .show(ui, |ui| {
+            Grid::new("trade_profile")
+                .num_columns(2)
+                .spacing([10.0, 4.0])
+                .show(ui, |ui| {
+                    ui.label(&UI_TEXT.zs_stop_mode)
+                        .on_hover_text(&UI_TEXT.zs_stop_mode_hover);
+                    let before = profile.stop_mode;
+                    ComboBox::from_id_salt("stop_mode")
+                        .selected_text(profile.stop_mode.to_string())
+                        .show_ui(ui, |ui| {
+                            for mode in StopMode::iter() {
+                                ui.selectable_value(&mut profile.stop_mode, mode, mode.to_string());
+                            }
+                        });
+                    changed |= profile.stop_mode != before;
+                    ui.end_row();
+
+                    ui.label(&UI_TEXT.zs_stop_management)
+                        .on_hover_text(&UI_TEXT.zs_stop_management_hover);
+                    let before = profile.stop_management;
+                    ComboBox::from_id_salt("stop_management")
+                        .selected_text(profile.stop_management.to_string())
+                        .show_ui(ui, |ui| {
+                            for management in StopManagement::iter() {
+                                ui.selectable_value(
+                                    &mut profile.stop_management,
+                                    management,
+                                    management.to_string(),
+                                );
+                            }
+                        });
+                    changed |= profile.stop_management != before;
+                    ui.end_row();
+
+                    ui.label(&UI_TEXT.zs_stop_buffer);
+                    let mut display = profile.zone_stop_buffer.value() * 100.0;
+                    let response = ui.add_enabled(
+                        profile.stop_mode == StopMode::ZoneAnchored,
+                        DragValue::new(&mut display)
+                            .speed(0.01)
+                            .range(0.0..=5.0)
+                            .max_decimals(2)
+                            .suffix("%"),
+                    );
+                    if response.changed() {
+                        profile.zone_stop_buffer = Pct::new(display / 100.0);
+                        changed = true;
+                    }
+                    ui.end_row();
+
+                    ui.label(&UI_TEXT.zs_benchmark_rate)
+                        .on_hover_text(&UI_TEXT.zs_benchmark_rate_hover);
+                    let mut display = profile.benchmark_aroi_pct.value() * 100.0;
+                    if ui
+                        .add(
+                            DragValue::new(&mut display)
+                                .speed(0.1)
+                                .range(0.0..=50.0)
+                                .max_decimals(1)
+                                .suffix("%"),
+                        )
+                        .changed()
+                    {
+                        profile.benchmark_aroi_pct = AroiPct::new(display / 100.0);
+                        changed = true;
+                    }
+                    ui.end_row();
+                });
+        });
+    changed
+}
+
+/// Percentages are edited as % of the zone count / resource total, stored as fractions.
+fn pct_row(ui: &mut Ui, label: &str, value: &mut PhPct, speed: f64) -> bool {
+    ui.label(label);
+    let mut display = value.value() * 100.0;
+    let changed = ui
+        .add(
+            DragValue::new(&mut display)
+                .speed(speed)
+                .range(0.0..=100.0)
+                .max_decimals(3)
+                .suffix("%"),
+        )
+        .changed();
+    if changed {
+        *value = PhPct::new(display / 100.0);
+    }
+    ui.end_row();
+    changed
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct CoverageTargetsResponse {
+    pub changed: bool,
+    pub auto_balance: bool,
+}
+
+/// Edits the coverage bands for one strategy preset, plus the auto-balance trigger.
+pub(crate) fn render_coverage_targets(
+    ui: &mut Ui,
+    strategy_label: &str,
+    targets: &mut CoverageTargets,
+) -> CoverageTargetsResponse {
+    let mut response = CoverageTargetsResponse::default();
+
+    CollapsingHeader::new(format!(
+        "{} ({})",
+        UI_TEXT.zs_coverage_targets, strategy_label
+    ))
+    .id_salt("zs_coverage_targets")
+    .default_open(true)
+    .show(ui, |ui| {
+        Grid::new("zs_coverage_grid")
+            .num_columns(3)
+            .spacing([10.0, 4.0])
+            .show(ui, |ui| {
+                for (label, band) in [
+                    (&UI_TEXT.zs_sticky, &mut targets.sticky),
+                    (&UI_TEXT.zs_low_wick, &mut targets.support),
+                    (&UI_TEXT.zs_high_wick, &mut targets.resistance),
+                ] {
+                    response.changed |= band_row(ui, label, band);
+                }
+            });
+        if ui
+            .button(&UI_TEXT.zs_auto_balance)
+            .on_hover_text(&UI_TEXT.zs_auto_balance_hover)
+            .clicked()
+        {
+            response.auto_balance = true;
+        }
+    });
+    response
+}
+
+fn band_row(ui: &mut Ui, label: &str, band: &mut CoverageBand) -> bool {
+    ui.label(label);
+    let mut changed = ui
+        .add(
+            DragValue::new(&mut band.min_pct)
+                .speed(0.1)
+                .range(0.0..=band.max_pct)
+                .suffix("%"),
+        )
+        .changed();
+    changed |= ui
+        .add(
+            DragValue::new(&mut band.max_pct)
+                .speed(0.1)
+                .range(band.min_pct..=100.0)
+                .suffix("%"),
+        )
+        .changed();
+    ui.end_row();
+    changed
+}
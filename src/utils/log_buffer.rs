@@ -0,0 +1,27 @@
+use std::{
+    collections::VecDeque,
+    sync::{LazyLock, Mutex},
+};
+
+/// Log lines kept in memory for diagnostics bundles.
+const RECENT_LOG_CAPACITY: usize = 1000;
+
+static RECENT_LOGS: LazyLock<Mutex<VecDeque<String>>> =
+    LazyLock::new(|| Mutex::new(VecDeque::with_capacity(RECENT_LOG_CAPACITY)));
+
+/// Called by the logger for every formatted line. Oldest lines are dropped past capacity.
+pub fn record_log_line(line: String) {
+    if let Ok(mut logs) = RECENT_LOGS.lock() {
+        if logs.len() == RECENT_LOG_CAPACITY {
+            logs.pop_front();
+        }
+        logs.push_back(line);
+    }
+}
+
+pub(crate) fn recent_log_lines() -> Vec<String> {
+    RECENT_LOGS
+        .lock()
+        .map(|logs| logs.iter().cloned().collect())
+        .unwrap_or_default()
+}
@@ -1,6 +1,15 @@
+#[cfg(not(target_arch = "wasm32"))]
+mod log_buffer;
 mod maths_utils;
 mod perf;
 mod time_utils;
 
-pub(crate) use maths_utils::{mean_and_stddev, normalize_max, remap, smooth_data};
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use log_buffer::recent_log_lines;
+#[cfg(not(target_arch = "wasm32"))]
+pub use log_buffer::record_log_line;
 pub use time_utils::{AppInstant, TimeUtils};
+pub(crate) use {
+    maths_utils::{mean_and_stddev, normalize_max, remap, smooth_data},
+    perf::{process_cpu_time, process_rss_bytes, vec_heap_bytes},
+};
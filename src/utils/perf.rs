@@ -27,3 +27,49 @@ macro_rules! trace_time {
         }
     }};
 }
+
+/// Heap bytes reserved by a vector's buffer (element heap data not included).
+pub(crate) fn vec_heap_bytes<T>(v: &Vec<T>) -> usize {
+    v.capacity() * std::mem::size_of::<T>()
+}
+
+/// CPU time (user + system) consumed by the whole process so far. Linux only; `/proc`
+/// reports clock ticks of USER_HZ per second.
+pub(crate) fn process_cpu_time() -> Option<std::time::Duration> {
+    #[cfg(target_os = "linux")]
+    {
+        let stat = std::fs::read_to_string("/proc/self/stat").ok()?;
+        // Fields after the parenthesised command name; utime / stime are fields 14 / 15
+        let rest = &stat[stat.rfind(')')? + 2..];
+        let mut fields = rest.split_whitespace().skip(11);
+        let utime: u64 = fields.next()?.parse().ok()?;
+        let stime: u64 = fields.next()?.parse().ok()?;
+        // SAFETY: sysconf only reads a system constant
+        let ticks_per_sec = u64::try_from(unsafe { libc::sysconf(libc::_SC_CLK_TCK) })
+            .ok()
+            .filter(|&hz| hz > 0)?;
+        Some(std::time::Duration::from_micros(
+            (utime + stime) * 1_000_000 / ticks_per_sec,
+        ))
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+/// Resident set size of the process in bytes. Linux only.
+pub(crate) fn process_rss_bytes() -> Option<u64> {
+    #[cfg(target_os = "linux")]
+    {
+        let statm = std::fs::read_to_string("/proc/self/statm").ok()?;
+        let pages: u64 = statm.split_whitespace().nth(1)?.parse().ok()?;
+        // SAFETY: sysconf only reads a system constant
+        let page_size = u64::try_from(unsafe { libc::sysconf(libc::_SC_PAGESIZE) }).ok()?;
+        Some(pages * page_size)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
@@ -1,8 +1,14 @@
 use {
-    chrono::{DateTime, TimeZone, Utc},
-    std::time::Duration,
+    chrono::{DateTime, Duration as ChronoDuration, TimeZone, Utc},
+    std::{
+        sync::atomic::{AtomicI64, Ordering},
+        time::Duration,
+    },
 };
 
+/// Exchange time minus local time, kept current by the clock skew check (0 until measured).
+static CLOCK_OFFSET_MS: AtomicI64 = AtomicI64::new(0);
+
 #[cfg(not(target_arch = "wasm32"))]
 pub type AppInstant = std::time::Instant;
 
@@ -53,14 +59,50 @@ impl TimeUtils {
         }
     }
 
-    /// Returns current UTC time in milliseconds.
+    /// Inverse of [`Self::interval_to_string`], e.g. "15m" -> 900_000.
+    pub fn string_to_interval(interval: &str) -> Option<i64> {
+        [
+            Self::MS_IN_S,
+            Self::MS_IN_MIN,
+            Self::MS_IN_3_MIN,
+            Self::MS_IN_5_MIN,
+            Self::MS_IN_15_MIN,
+            Self::MS_IN_30_MIN,
+            Self::MS_IN_H,
+            Self::MS_IN_2_H,
+            Self::MS_IN_4_H,
+            Self::MS_IN_6_H,
+            Self::MS_IN_8_H,
+            Self::MS_IN_12_H,
+            Self::MS_IN_D,
+            Self::MS_IN_3_D,
+            Self::MS_IN_W,
+            Self::MS_IN_1_M,
+            Self::MS_IN_YEAR,
+        ]
+        .into_iter()
+        .find(|&ms| Self::interval_to_string(ms) == interval)
+    }
+
+    /// Returns current UTC time in milliseconds, corrected to exchange time.
     /// Unlike Instant::now(), this *is* WASM safe
     pub fn now_timestamp_ms() -> i64 {
-        Utc::now().timestamp_millis()
+        Utc::now().timestamp_millis() + Self::clock_offset_ms()
     }
 
+    /// Current UTC time, corrected to exchange time.
     pub fn now_utc() -> DateTime<Utc> {
-        Utc::now()
+        Utc::now() + ChronoDuration::milliseconds(Self::clock_offset_ms())
+    }
+
+    /// How far the local clock trails exchange time (negative if it runs ahead).
+    pub(crate) fn clock_offset_ms() -> i64 {
+        CLOCK_OFFSET_MS.load(Ordering::Relaxed)
+    }
+
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    pub(crate) fn set_clock_offset_ms(offset_ms: i64) {
+        CLOCK_OFFSET_MS.store(offset_ms, Ordering::Relaxed);
     }
 
     pub(crate) fn duration_to_candles(duration: Duration, interval_ms: i64) -> usize {